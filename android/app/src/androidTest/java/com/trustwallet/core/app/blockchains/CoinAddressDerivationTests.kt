@@ -156,5 +156,8 @@ class CoinAddressDerivationTests {
         NATIVEZETACHAIN -> assertEquals("zeta13u6g7vqgw074mgmf2ze2cadzvkz9snlwywj304", address)
         DYDX -> assertEquals("dydx142j9u5eaduzd7faumygud6ruhdwme98qeayaky", address)
         PACTUS -> assertEquals("pc1r7ys2g5a4xc2qtm0t4q987m4mvs57w5g0v4pvzg", address)
+        LIQUID -> assertEquals("ex1qzn3tl7cvw7e9u03snfjnyp5vh5ge8vphmr88w6", address)
+        AVALANCHEXCHAIN -> assertEquals("X-avax1x8nslqfqha3aywycds3jfnykp3khffrk2scya2", address)
+        AVALANCHEPCHAIN -> assertEquals("P-avax1x8nslqfqha3aywycds3jfnykp3khffrk2scya2", address)
     }
 }
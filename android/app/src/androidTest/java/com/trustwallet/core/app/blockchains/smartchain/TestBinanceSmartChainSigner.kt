@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+package com.trustwallet.core.app.blockchains.binancesmartchain
+
+import com.google.protobuf.ByteString
+import com.trustwallet.core.app.utils.Numeric
+import com.trustwallet.core.app.utils.toHexByteArray
+import org.junit.Assert.assertEquals
+import org.junit.Test
+import wallet.core.java.AnySigner
+import wallet.core.jni.proto.Common.SigningError
+import wallet.core.jni.proto.Ethereum
+import wallet.core.jni.proto.Ethereum.SigningOutput
+import wallet.core.jni.proto.Ethereum.TransactionMode
+
+class TestBinanceSmartChainSigner {
+
+    init {
+        System.loadLibrary("TrustWalletCore")
+    }
+
+    @Test
+    fun testSignEvmNetworkDefault() {
+        val signingInput = Ethereum.SigningInput.newBuilder()
+        signingInput.apply {
+            privateKey = ByteString.copyFrom("0x4646464646464646464646464646464646464646464646464646464646464646".toHexByteArray())
+            toAddress = "0x3535353535353535353535353535353535353535"
+            chainId = ByteString.copyFrom("0x38".toHexByteArray()) // 56
+            nonce = ByteString.copyFrom("0x9".toHexByteArray())
+            txMode = TransactionMode.NetworkDefault
+            gasPrice = ByteString.copyFrom("0x04a817c800".toHexByteArray())
+            gasLimit = ByteString.copyFrom("0x5208".toHexByteArray())
+            transaction = Ethereum.Transaction.newBuilder().apply {
+                transfer = Ethereum.Transaction.Transfer.newBuilder().apply {
+                    amount = ByteString.copyFrom("0x0de0b6b3a7640000".toHexByteArray())
+                }.build()
+            }.build()
+        }
+
+        // Smart Chain doesn't support EIP-1559, so a Legacy transaction is signed.
+        val output = AnySigner.signEvm(signingInput.build(), SigningOutput.parser())
+
+        assertEquals(SigningError.OK, output.error)
+        assertEquals(Numeric.toHexString(output.encoded.toByteArray()), "0xf86d098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000808194a007764d8e7d4ce45afb36cb2f5bec741f96eee3b8f23304e4d35b0f389912e7f7a047c14849f32c33c269dec152d168ddeca152fec8ceeb7369177ff3a06dc72c48")
+    }
+
+    @Test
+    fun testSignEvmUserAddedChainId() {
+        val signingInput = Ethereum.SigningInput.newBuilder()
+        signingInput.apply {
+            privateKey = ByteString.copyFrom("0x4646464646464646464646464646464646464646464646464646464646464646".toHexByteArray())
+            toAddress = "0x3535353535353535353535353535353535353535"
+            chainId = ByteString.copyFrom("0x075bcd15".toHexByteArray()) // 123456789
+            nonce = ByteString.copyFrom("0x9".toHexByteArray())
+            txMode = TransactionMode.NetworkDefault
+            gasLimit = ByteString.copyFrom("0x5208".toHexByteArray())
+            maxInclusionFeePerGas = ByteString.copyFrom("0x77359400".toHexByteArray())
+            maxFeePerGas = ByteString.copyFrom("0x04a817c800".toHexByteArray())
+            transaction = Ethereum.Transaction.newBuilder().apply {
+                transfer = Ethereum.Transaction.Transfer.newBuilder().apply {
+                    amount = ByteString.copyFrom("0x0de0b6b3a7640000".toHexByteArray())
+                }.build()
+            }.build()
+        }
+
+        // Networks unknown to the library are signed as EIP-1559 transactions.
+        val output = AnySigner.signEvm(signingInput.build(), SigningOutput.parser())
+
+        assertEquals(SigningError.OK, output.error)
+        assertEquals(Numeric.toHexString(output.encoded.toByteArray()), "0x02f87784075bcd150984773594008504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080c001a06bd34f982436d714110467b33799f34bc1292032e1b1f6f728a9fdb53319e890a07963f6fea7f4ce08608fe9f908cd8990fc161ad45933b654d06fabce8e88f324")
+    }
+}
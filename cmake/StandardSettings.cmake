@@ -67,6 +67,7 @@ endif ()
 #
 option(TW_UNIT_TESTS "Enable the unit tests of the project" ON)
 option(TW_BUILD_EXAMPLES "Enable the examples builds of the project" ON)
+option(TW_FUZZ_TARGETS "Build the libFuzzer targets of the project, requires Clang" OFF)
 
 if (ANDROID OR IOS_PLATFORM OR TW_COMPILE_WASM OR TW_COMPILE_JAVA OR FLUTTER)
     set(TW_UNIT_TESTS OFF)
@@ -14,6 +14,7 @@ use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_planner::NoTransactionPlanner;
 use tw_coin_entry::modules::transaction_util::NoTransactionUtil;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
@@ -37,6 +38,7 @@ impl CoinEntry for {BLOCKCHAIN}Entry {
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = NoTransactionUtil;
+    type TransactionPlanner = NoTransactionPlanner;
 
     #[inline]
     fn parse_address(
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include "Mnemonic.h"
+
+#include <cstdlib>
+
+using namespace TW;
+
+// Validates an arbitrary mnemonic phrase, and checks that the entropy of a valid one round-trips.
+extern "C" int LLVMFuzzerTestOneInput(const uint8_t* data, size_t size) {
+    const auto mnemonic = std::string(reinterpret_cast<const char*>(data), size);
+
+    Mnemonic::validate(mnemonic);
+    if (!Mnemonic::isValid(mnemonic)) {
+        return 0;
+    }
+
+    const auto entropy = Mnemonic::toEntropy(mnemonic);
+    if (!entropy.has_value()) {
+        std::abort();
+    }
+    const auto restored = Mnemonic::fromEntropy(*entropy);
+    if (!restored.has_value() || !Mnemonic::isValid(*restored) || Mnemonic::toEntropy(*restored) != entropy) {
+        std::abort();
+    }
+    return 0;
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWAddressFormat.h"
+#include "TWBase.h"
+#include "TWString.h"
+
+TW_EXTERN_C_BEGIN
+
+/// Converts addresses between the formats of the same key hash.
+TW_EXPORT_STRUCT
+struct TWAddressConverter;
+
+/// Converts an address between the formats of the same key hash,
+/// e.g. Evmos bech32 to `0x` hex, Tron base58 to `0x` hex, or TON raw to user-friendly.
+///
+/// \param address address to be converted, it must match the `from` format.
+/// \param from format of the given address.
+/// \param to format of the result address.
+/// \param hrp human-readable part of the result address, required if `to` is `TWAddressFormatCosmosBech32`.
+/// \return the converted address. Null if the address is invalid, or the formats cannot be converted to each other.
+TW_EXPORT_STATIC_METHOD
+TWString* _Nullable TWAddressConverterConvert(TWString* _Nonnull address, enum TWAddressFormat from, enum TWAddressFormat to, TWString* _Nullable hrp);
+
+TW_EXTERN_C_END
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+
+TW_EXTERN_C_BEGIN
+
+/// Address format, used by `TWAddressConverter` to convert an address between the formats of the same key hash.
+/// `EvmHex`, `CosmosBech32`, `TronBase58` and `FilecoinDelegated` can be converted to each other,
+/// as well as the TON formats, and the XRP formats.
+/// The TON formats are converted as \TWTONAddressConverterToUserFriendly does, the result is a mainnet address.
+TW_EXPORT_ENUM(uint32_t)
+enum TWAddressFormat {
+    TWAddressFormatEvmHex = 0,            // 0x8dbD6c7Ede90646a61Bbc649831b7c298BFd37A0
+    TWAddressFormatCosmosBech32 = 1,      // evmos13k7kclk7jpjx5cdmceycxxmu9x9l6daqepprmh
+    TWAddressFormatTronBase58 = 2,        // TNtfCWCPgcNvouNuGVP8fs2iJzi8nnbX8N
+    TWAddressFormatFilecoinDelegated = 3, // f410frw6wy7w6sbsguyn3yzeygg34fgf72n5ao5sxyky
+    TWAddressFormatTonRaw = 4,            // 0:8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28ae
+    TWAddressFormatTonBounceable = 5,     // EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorkdl
+    TWAddressFormatTonNonBounceable = 6,  // UQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorhqg
+    TWAddressFormatXrpClassic = 7,        // rnBFvgZphmN39GWzUJeUitaP22Fr9be75H
+    TWAddressFormatXrpXAddress = 8,       // X76UnYEMbQfEs3mUqgtjp4zFy9exgSxWAqcQwu9z2r5d7Tm
+};
+
+TW_EXTERN_C_END
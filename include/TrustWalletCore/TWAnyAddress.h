@@ -35,6 +35,15 @@ bool TWAnyAddressEqual(struct TWAnyAddress* _Nonnull lhs, struct TWAnyAddress* _
 TW_EXPORT_STATIC_METHOD
 bool TWAnyAddressIsValid(TWString* _Nonnull string, enum TWCoinType coin);
 
+/// Validates the string as an address of the given coin, and tells if it belongs to the test network or to another chain.
+/// The test network and the reason of an invalid address are detected by the address formats declared in `registry.json`.
+///
+/// \param string address to validate.
+/// \param coin coin type of the address.
+/// \return serialized `TW.AddressValidation.Proto.ValidationResult`.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWAnyAddressValidate(TWString* _Nonnull string, enum TWCoinType coin);
+
 /// Determines if the string is a valid Any address with the given hrp.
 ///
 /// \param string address to validate.
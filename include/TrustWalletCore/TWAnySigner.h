@@ -41,4 +41,11 @@ extern bool TWAnySignerSupportsJSON(enum TWCoinType coin);
 /// \return The serialized data of a `TransactionPlan` proto object.
 extern TWData *_Nonnull TWAnySignerPlan(TWData *_Nonnull input, enum TWCoinType coin);
 
+/// Signs an EVM transaction for the network specified by the chain ID of the signing input.
+/// Allows to sign transactions for any EVM-compatible network, including the networks unknown to the library.
+///
+/// \param input The serialized data of a `TW.Ethereum.Proto.SigningInput`.
+/// \return The serialized data of a `TW.Ethereum.Proto.SigningOutput` proto object. Empty on invalid input.
+extern TWData *_Nonnull TWAnySignerSignEvm(TWData *_Nonnull input);
+
 TW_EXTERN_C_END
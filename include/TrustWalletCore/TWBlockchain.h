@@ -69,6 +69,9 @@ enum TWBlockchain {
     TWBlockchainPactus = 56,
     TWBlockchainKomodo = 57,
     TWBlockchainPolymesh = 58, // Substrate
+    TWBlockchainLiquid = 59,
+    TWBlockchainAvalancheX = 60,
+    TWBlockchainAvalancheP = 61,
 };
 
 TW_EXTERN_C_END
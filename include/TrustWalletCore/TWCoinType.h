@@ -189,6 +189,9 @@ enum TWCoinType {
     TWCoinTypePactus = 21888,
     TWCoinTypeSonic = 10000146,
     TWCoinTypePolymesh = 595,
+    TWCoinTypeLiquid = 1776,
+    TWCoinTypeAvalancheXChain = 9000,
+    TWCoinTypeAvalanchePChain = 20009000,
     // end_of_tw_coin_type_marker_do_not_modify
 };
 
@@ -5,20 +5,62 @@
 #pragma once
 
 #include "TWBase.h"
+#include "TWData.h"
+#include "TWSigningHash.h"
+#include "TWString.h"
 
 TW_EXTERN_C_BEGIN
 
 /// Elliptic cruves
 TW_EXPORT_ENUM()
 enum TWCurve {
-    TWCurveSECP256k1              /* "secp256k1" */,
-    TWCurveED25519                /* "ed25519" */,
-    TWCurveED25519Blake2bNano     /* "ed25519-blake2b-nano" */,
-    TWCurveCurve25519             /* "curve25519" */,
-    TWCurveNIST256p1              /* "nist256p1" */,
-    TWCurveED25519ExtendedCardano /* "ed25519-cardano-seed" */,
-    TWCurveStarkex                /* "starkex" */,
-    TWCurveNone
+    TWCurveSECP256k1 = 0              /* "secp256k1" */,
+    TWCurveED25519 = 1                /* "ed25519" */,
+    TWCurveED25519Blake2bNano = 2     /* "ed25519-blake2b-nano" */,
+    TWCurveCurve25519 = 3             /* "curve25519" */,
+    TWCurveNIST256p1 = 4              /* "nist256p1" */,
+    TWCurveED25519ExtendedCardano = 5 /* "ed25519-cardano-seed" */,
+    TWCurveStarkex = 6                /* "starkex" */,
+    TWCurveNone = 7,
+    /// BIP-340 Schnorr signatures over secp256k1.
+    TWCurveSchnorr = 8                /* "schnorr" */,
 };
 
+/// Returns the curve name as it's used in `registry.json`, e.g. `secp256k1`.
+///
+/// \param curve Elliptic curve.
+/// \return the curve name, null if the curve is not supported.
+TW_EXPORT_PROPERTY
+TWString* _Nullable TWCurveName(enum TWCurve curve);
+
+/// Returns the minimum length of a message to be signed with the given curve.
+///
+/// \param curve Elliptic curve.
+/// \return the length in bytes, 0 if the curve is not supported.
+TW_EXPORT_PROPERTY
+uint32_t TWCurveSigningHashMinLength(enum TWCurve curve);
+
+/// Returns the maximum length of a message to be signed with the given curve.
+///
+/// \param curve Elliptic curve.
+/// \return the length in bytes, 0 if a message of any length can be signed or the curve is not supported.
+TW_EXPORT_PROPERTY
+uint32_t TWCurveSigningHashMaxLength(enum TWCurve curve);
+
+/// Whether the digests of the given hash algorithm are signed with the curve by the supported chains.
+///
+/// \param curve Elliptic curve.
+/// \param hash hash algorithm.
+/// \return true if the hash is expected by the curve.
+TW_EXPORT_METHOD
+bool TWCurveSupportsSigningHash(enum TWCurve curve, enum TWSigningHash hash);
+
+/// Checks if the given message can be signed with the curve before signing it.
+///
+/// \param curve Elliptic curve.
+/// \param message Non-null message to be signed.
+/// \return true if the message can be signed.
+TW_EXPORT_METHOD
+bool TWCurveValidateSigningMessage(enum TWCurve curve, TWData* _Nonnull message);
+
 TW_EXTERN_C_END
@@ -113,6 +113,7 @@ void TWDataReverse(TWData *_Nonnull data) TW_VISIBILITY_DEFAULT;
 void TWDataReset(TWData *_Nonnull data) TW_VISIBILITY_DEFAULT;
 
 /// Deletes a block of data created with a `TWDataCreate*` method.
+/// The contents are wiped before the memory is released.
 ///
 /// \param data A non-null valid block of data
 void TWDataDelete(TWData *_Nonnull data) TW_VISIBILITY_DEFAULT;
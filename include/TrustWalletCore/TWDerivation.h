@@ -27,6 +27,20 @@ enum TWDerivation {
     TWDerivationBitcoinTaproot = 8,
     TWDerivationPactusMainnet = 9,
     TWDerivationPactusTestnet = 10,
+    TWDerivationCoreumFallback = 11,
+    TWDerivationTerraFallback = 12,
+    TWDerivationTerraV2Fallback = 13,
+    TWDerivationKavaFallback = 14,
+    TWDerivationBluzelleFallback = 15,
+    TWDerivationBandChainFallback = 16,
+    TWDerivationTHORChainFallback = 17,
+    TWDerivationCryptoOrgFallback = 18,
+    TWDerivationSecretFallback = 19,
+    TWDerivationNativeEvmosFallback = 20,
+    TWDerivationAgoricFallback = 21,
+    TWDerivationNativeInjectiveFallback = 22,
+    TWDerivationNativeCantoFallback = 23,
+    TWDerivationNativeZetaChainFallback = 24,
     // end_of_derivation_enum - USED TO GENERATE CODE
 };
 
@@ -25,6 +25,15 @@ struct TWEthereumAbi;
 TW_EXPORT_STATIC_METHOD
 TWData* _Nonnull TWEthereumAbiDecodeContractCall(enum TWCoinType coin, TWData* _Nonnull input);
 
+/// Decode a contract call (function input) according to a user-supplied ABI json,
+/// or the built-in ERC-20, ERC-721 and ERC-1155 ABIs.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.CalldataDecodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.CalldataDecodingOutput` proto object.
+TW_EXPORT_STATIC_METHOD
+TWData* _Nonnull TWEthereumAbiDecodeCalldata(enum TWCoinType coin, TWData* _Nonnull input);
+
 /// Decode a function input or output data according to a given ABI.
 ///
 /// \param coin EVM-compatible coin type.
@@ -100,6 +100,27 @@ TWData* _Nonnull TWHDWalletEntropy(struct TWHDWallet* _Nonnull wallet);
 TW_EXPORT_METHOD
 struct TWPrivateKey* _Nonnull TWHDWalletGetMasterKey(struct TWHDWallet* _Nonnull wallet, enum TWCurve curve);
 
+/// Returns the BIP-32 fingerprint of the secp256k1 master key, e.g. used in PSBT key origins.
+///
+/// \param wallet non-null TWHDWallet
+/// \return The master fingerprint as a big-endian integer
+TW_EXPORT_PROPERTY
+uint32_t TWHDWalletMasterFingerprint(struct TWHDWallet* _Nonnull wallet);
+
+/// Returns a hex encoded identifier of the specified account, that can be used as a database key.
+/// It's equal to \TWPublicKeyAccountIdentifier of the account-level key `m/purpose'/coin'/account'`,
+/// i.e. of the key of the account extended public key, so a watch-only account gets the same one.
+/// For Cardano, it's the identifier of the account-level ed25519 public key.
+///
+/// \see TWPublicKeyAccountIdentifier
+/// \param wallet non-null TWHDWallet
+/// \param coin  a coin type
+/// \param derivation  a (custom) derivation to use
+/// \param account  the account index
+/// \return return the account identifier as a non-null TWString
+TW_EXPORT_METHOD
+TWString* _Nonnull TWHDWalletGetAccountIdentifier(struct TWHDWallet* _Nonnull wallet, enum TWCoinType coin, enum TWDerivation derivation, uint32_t account);
+
 /// Generates the default private key for the specified coin, using default derivation.
 ///
 /// \see TWHDWalletGetKey
@@ -149,4 +149,11 @@ TWData *_Nonnull TWHashBlake256RIPEMD(TWData *_Nonnull data);
 TW_EXPORT_STATIC_METHOD
 TWData *_Nonnull TWHashGroestl512Groestl512(TWData *_Nonnull data);
 
+/// Computes the circomlib compatible Poseidon hash of BN254 field elements.
+///
+/// \param data Non-null concatenated 32-byte big-endian field elements, from 1 to 16 elements
+/// \return Computed 32-byte Poseidon hash, or null if the data is not a sequence of valid field elements
+TW_EXPORT_STATIC_METHOD
+TWData *_Nullable TWHashPoseidon(TWData *_Nonnull data);
+
 TW_EXTERN_C_END
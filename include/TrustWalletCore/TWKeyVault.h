@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+#include "TWCurve.h"
+#include "TWData.h"
+#include "TWPublicKey.h"
+#include "TWPublicKeyType.h"
+#include "TWString.h"
+
+TW_EXTERN_C_BEGIN
+
+/// Represents an in-memory vault of private keys and seeds.
+/// The secrets are encrypted with a random session key and never leave the vault,
+/// they are referenced by opaque handles instead.
+TW_EXPORT_CLASS
+struct TWKeyVault;
+
+/// Creates an empty key vault with a random session key.
+/// Must be deleted with \TWKeyVaultDelete after use.
+///
+/// \return A new key vault.
+TW_EXPORT_STATIC_METHOD
+struct TWKeyVault* _Nonnull TWKeyVaultCreate(void);
+
+/// Deletes the given key vault and all its secrets.
+///
+/// \param vault Non-null pointer to the key vault.
+TW_EXPORT_METHOD
+void TWKeyVaultDelete(struct TWKeyVault* _Nonnull vault);
+
+/// Imports a private key into the vault.
+///
+/// \param vault Non-null pointer to the key vault.
+/// \param key Non-null block of data of the private key.
+/// \return Handle of the private key, or 0 if the private key is invalid.
+TW_EXPORT_METHOD
+uint64_t TWKeyVaultImportPrivateKey(struct TWKeyVault* _Nonnull vault, TWData* _Nonnull key);
+
+/// Imports a BIP-32 seed of 16 to 64 bytes into the vault.
+///
+/// \param vault Non-null pointer to the key vault.
+/// \param seed Non-null block of data of the seed.
+/// \return Handle of the seed, or 0 if the seed is invalid.
+TW_EXPORT_METHOD
+uint64_t TWKeyVaultImportSeed(struct TWKeyVault* _Nonnull vault, TWData* _Nonnull seed);
+
+/// Derives a BIP-32 `secp256k1` private key from the seed, and keeps it in the vault.
+///
+/// \param vault Non-null pointer to the key vault.
+/// \param seed Handle of the seed.
+/// \param derivationPath Non-null derivation path, e.g. "m/44'/60'/0'/0/0".
+/// \return Handle of the derived private key, or 0 on error.
+TW_EXPORT_METHOD
+uint64_t TWKeyVaultDeriveSecp256k1(struct TWKeyVault* _Nonnull vault, uint64_t seed, TWString* _Nonnull derivationPath);
+
+/// Signs a digest by the private key referenced by the handle with the given curve.
+///
+/// \param vault Non-null pointer to the key vault.
+/// \param handle Handle of the private key.
+/// \param digest Non-null digest to sign.
+/// \param curve Elliptic curve.
+/// \return The signature, or null if the handle is unknown or the digest is invalid.
+TW_EXPORT_METHOD
+TWData* _Nullable TWKeyVaultSign(struct TWKeyVault* _Nonnull vault, uint64_t handle, TWData* _Nonnull digest, enum TWCurve curve);
+
+/// Returns the public key of the private key referenced by the handle.
+///
+/// \param vault Non-null pointer to the key vault.
+/// \param handle Handle of the private key.
+/// \param type Type of the public key to return.
+/// \return The public key, or null if the handle is unknown.
+TW_EXPORT_METHOD
+struct TWPublicKey* _Nullable TWKeyVaultGetPublicKey(struct TWKeyVault* _Nonnull vault, uint64_t handle, enum TWPublicKeyType type);
+
+/// Removes the secret referenced by the handle from the vault.
+///
+/// \param vault Non-null pointer to the key vault.
+/// \param handle Handle of the secret.
+/// \return true if the secret has been removed, false if the handle is unknown.
+TW_EXPORT_METHOD
+bool TWKeyVaultRemove(struct TWKeyVault* _Nonnull vault, uint64_t handle);
+
+TW_EXTERN_C_END
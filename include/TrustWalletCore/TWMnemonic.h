@@ -5,6 +5,10 @@
 #pragma once
 
 #include "TWBase.h"
+#include "TWData.h"
+#include "TWMnemonicError.h"
+#include "TWMnemonicLanguage.h"
+#include "TWMnemonicWordStatus.h"
 #include "TWString.h"
 
 TW_EXTERN_C_BEGIN
@@ -34,4 +38,58 @@ bool TWMnemonicIsValidWord(TWString *_Nonnull word);
 TW_EXPORT_STATIC_METHOD
 TWString* _Nonnull TWMnemonicSuggest(TWString *_Nonnull prefix);
 
+/// Return up to `maxCount` BIP39 words of the given language that match the given prefix.
+///
+/// \param prefix Non-null string prefix, case insensitive
+/// \param language wordlist language
+/// \param maxCount maximum number of the returned words
+/// \return Single non-null string, space-separated list of the matching words in alphabetical order.
+TW_EXPORT_STATIC_METHOD
+TWString* _Nonnull TWMnemonicSuggestWords(TWString *_Nonnull prefix, enum TWMnemonicLanguage language, uint32_t maxCount);
+
+/// Looks up a word as it's being typed.
+///
+/// \param word Non-null (partially typed) word, case insensitive
+/// \param language wordlist language
+/// \return whether the word is valid, a prefix of valid words or invalid
+TW_EXPORT_STATIC_METHOD
+enum TWMnemonicWordStatus TWMnemonicSearch(TWString *_Nonnull word, enum TWMnemonicLanguage language);
+
+/// Returns the candidates of a word as it's being typed:
+/// the words that start with it, or the most similar words if there are no such words (e.g. the word is mistyped).
+///
+/// \param word Non-null (partially typed) word, case insensitive
+/// \param language wordlist language
+/// \return Single non-null string, space-separated list of up to 10 words.
+TW_EXPORT_STATIC_METHOD
+TWString* _Nonnull TWMnemonicSearchCandidates(TWString *_Nonnull word, enum TWMnemonicLanguage language);
+
+/// Validates a BIP39 English mnemonic phrase, reporting the reason of a failure.
+///
+/// \param mnemonic Non-null BIP39 english mnemonic
+/// \return `TWMnemonicErrorNone` if the mnemonic is valid, the reason of the failure otherwise
+TW_EXPORT_STATIC_METHOD
+enum TWMnemonicError TWMnemonicValidate(TWString *_Nonnull mnemonic);
+
+/// Returns the index of the first word that is not a BIP39 English word.
+///
+/// \param mnemonic Non-null BIP39 english mnemonic
+/// \return Zero-based index of the invalid word, or -1 if all words are valid
+TW_EXPORT_STATIC_METHOD
+int32_t TWMnemonicInvalidWordIndex(TWString *_Nonnull mnemonic);
+
+/// Converts a valid BIP39 English mnemonic phrase to the entropy it encodes.
+///
+/// \param mnemonic Non-null BIP39 english mnemonic
+/// \return Entropy of 16 to 32 bytes, or null if the mnemonic is invalid
+TW_EXPORT_STATIC_METHOD
+TWData* _Nullable TWMnemonicToEntropy(TWString *_Nonnull mnemonic);
+
+/// Converts an entropy to a BIP39 English mnemonic phrase.
+///
+/// \param entropy Non-null entropy of 16, 20, 24, 28 or 32 bytes
+/// \return BIP39 English mnemonic, or null if the entropy size is invalid
+TW_EXPORT_STATIC_METHOD
+TWString* _Nullable TWMnemonicFromEntropy(TWData *_Nonnull entropy);
+
 TW_EXTERN_C_END
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+
+TW_EXTERN_C_BEGIN
+
+/// Reason of a BIP39 mnemonic phrase validation failure.
+TW_EXPORT_ENUM(uint32_t)
+enum TWMnemonicError {
+    TWMnemonicErrorNone = 0,             // the mnemonic is valid
+    TWMnemonicErrorInvalidWordCount = 1, // expected 12, 15, 18, 21 or 24 words
+    TWMnemonicErrorInvalidWord = 2,      // a word is not a BIP39 English word, see `TWMnemonicInvalidWordIndex`
+    TWMnemonicErrorInvalidChecksum = 3,  // the checksum encoded by the last word doesn't match
+};
+
+TW_EXTERN_C_END
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+
+TW_EXTERN_C_BEGIN
+
+/// Language of a BIP39 wordlist.
+TW_EXPORT_ENUM(uint32_t)
+enum TWMnemonicLanguage {
+    TWMnemonicLanguageEnglish = 0,
+};
+
+TW_EXTERN_C_END
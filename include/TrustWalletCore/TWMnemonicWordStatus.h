@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+
+TW_EXTERN_C_BEGIN
+
+/// Status of a (partially typed) mnemonic word.
+TW_EXPORT_ENUM(uint32_t)
+enum TWMnemonicWordStatus {
+    TWMnemonicWordStatusInvalid = 0, // neither a word nor a prefix of a word
+    TWMnemonicWordStatusPrefix = 1,  // a prefix of one or more words, but not a word itself
+    TWMnemonicWordStatusValid = 2,   // a word of the wordlist, that may be a prefix of other words too
+};
+
+TW_EXTERN_C_END
@@ -8,6 +8,8 @@
 #include "TWCurve.h"
 #include "TWData.h"
 #include "TWPublicKey.h"
+#include "TWStarkExHashMode.h"
+#include "TWStarkExNonceMode.h"
 #include "TWCoinType.h"
 
 TW_EXTERN_C_BEGIN
@@ -58,10 +60,25 @@ bool TWPrivateKeyIsValid(TWData* _Nonnull data, enum TWCurve curve);
 /// Convert the given private key to raw-bytes block of data
 ///
 /// \param pk Non-null pointer to the private key
-/// \return Non-null block of data (raw bytes) of the given private key
+/// \return Non-null block of data (raw bytes) of the given private key, empty if the private key is non-exportable
 TW_EXPORT_PROPERTY
 TWData* _Nonnull TWPrivateKeyData(struct TWPrivateKey* _Nonnull pk);
 
+/// Forbids exporting the private key bytes, so \TWPrivateKeyData returns empty data.
+/// The private key can still be used for signing, and copies made with \TWPrivateKeyCreateCopy stay non-exportable.
+/// The export can't be enabled again.
+///
+/// \param pk Non-null pointer to the private key
+TW_EXPORT_METHOD
+void TWPrivateKeyDisableExport(struct TWPrivateKey* _Nonnull pk);
+
+/// Determines if the private key bytes can be exported with \TWPrivateKeyData.
+///
+/// \param pk Non-null pointer to the private key
+/// \return true if the private key is exportable, false otherwise
+TW_EXPORT_PROPERTY
+bool TWPrivateKeyIsExportable(struct TWPrivateKey* _Nonnull pk);
+
 /// Returns the public key associated with the given coinType and privateKey
 ///
 /// \param pk Non-null pointer to the private key
@@ -146,4 +163,15 @@ TWData* _Nullable TWPrivateKeySignAsDER(struct TWPrivateKey* _Nonnull pk, TWData
 TW_EXPORT_METHOD
 TWData* _Nullable TWPrivateKeySignZilliqaSchnorr(struct TWPrivateKey* _Nonnull pk, TWData* _Nonnull message);
 
+/// Signs a digest with the StarkEx curve, allowing to choose how the digest is converted and how the nonce is generated.
+/// `TWPrivateKeySign` with `TWCurveStarkex` is equivalent to `TWStarkExHashModeStrict` and `TWStarkExNonceModeGrindExtraEntropy`.
+///
+/// \param pk Non-null pointer to a Private key
+/// \param digest Non-null digest block of data
+/// \param hashMode how the digest is converted to a field element
+/// \param nonceMode how the signature nonce is generated
+/// \return Signature as a block of data, null if the digest can't be signed
+TW_EXPORT_METHOD
+TWData* _Nullable TWPrivateKeySignStarkex(struct TWPrivateKey* _Nonnull pk, TWData* _Nonnull digest, enum TWStarkExHashMode hashMode, enum TWStarkExNonceMode nonceMode);
+
 TW_EXTERN_C_END
@@ -110,6 +110,30 @@ enum TWPublicKeyType TWPublicKeyKeyType(struct TWPublicKey *_Nonnull publicKey);
 TW_EXPORT_PROPERTY
 TWString *_Nonnull TWPublicKeyDescription(struct TWPublicKey *_Nonnull publicKey);
 
+/// Returns the BIP-32 fingerprint of a given public key: the first 4 bytes of `RIPEMD160(SHA256(public key))`.
+/// ECDSA public keys are hashed in the compressed form.
+///
+/// \param publicKey Non-null pointer to a public key
+/// \return The fingerprint as a big-endian integer
+TW_EXPORT_PROPERTY
+uint32_t TWPublicKeyFingerprint(struct TWPublicKey *_Nonnull publicKey);
+
+/// Returns the 20-byte key identifier of a given public key: `RIPEMD160(SHA256(public key))`.
+/// ECDSA public keys are hashed in the compressed form.
+///
+/// \param publicKey Non-null pointer to a public key
+/// \return Non-null pointer to the key identifier
+TW_EXPORT_PROPERTY
+TWData *_Nonnull TWPublicKeyKeyIdentifier(struct TWPublicKey *_Nonnull publicKey);
+
+/// Returns a hex encoded 32-byte identifier of the account a given public key belongs to.
+/// It's the same for the compressed and uncompressed forms of the key, and can be used as a database key.
+///
+/// \param publicKey Non-null pointer to a public key
+/// \return Non-null pointer to a string representing the account identifier
+TW_EXPORT_PROPERTY
+TWString *_Nonnull TWPublicKeyAccountIdentifier(struct TWPublicKey *_Nonnull publicKey);
+
 /// Try to get a public key from a given signature and a message
 ///
 /// \param signature Non-null pointer to a block of data corresponding to the signature
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+
+TW_EXTERN_C_BEGIN
+
+/// Hash algorithms which digests are signed with a curve.
+TW_EXPORT_ENUM(uint32_t)
+enum TWSigningHash {
+    /// The message is signed as is, the curve hashes it internally.
+    TWSigningHashRaw = 0,
+    TWSigningHashSha256 = 1,
+    /// SHA256 hash of the SHA256 hash, e.g. Bitcoin legacy and segwit sighash.
+    TWSigningHashSha256d = 2,
+    TWSigningHashKeccak256 = 3,
+    TWSigningHashBlake2b256 = 4,
+    TWSigningHashBlake256 = 5,
+    /// BIP-340 tagged SHA256 hash, e.g. Taproot sighash.
+    TWSigningHashTaggedSha256 = 6,
+    TWSigningHashPedersen = 7,
+    TWSigningHashPoseidon = 8,
+};
+
+TW_EXTERN_C_END
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+
+TW_EXTERN_C_BEGIN
+
+/// Defines how a message hash is converted to a StarkEx field element to be signed.
+TW_EXPORT_ENUM(uint32_t)
+enum TWStarkExHashMode {
+    /// The hash is signed as is, it must be less than 2^251 (default).
+    TWStarkExHashModeStrict = 0,
+    /// A 32-byte hash is truncated to its 251 most significant bits (shifted right by 5 bits), shorter hashes are signed as is.
+    /// This is not the starkware SDKs behavior, the signature must be verified against the truncated hash.
+    TWStarkExHashModeTruncate = 1,
+};
+
+TW_EXTERN_C_END
@@ -26,6 +26,17 @@ struct TWStarkExMessageSigner;
 TW_EXPORT_STATIC_METHOD
 TWString* _Nonnull TWStarkExMessageSignerSignMessage(const struct TWPrivateKey* _Nonnull privateKey, TWString* _Nonnull message);
 
+/// Sign a message with the given hash and nonce modes.
+/// \TWStarkExMessageSignerSignMessage is equivalent to `TWStarkExHashModeStrict` and `TWStarkExNonceModeGrindExtraEntropy`.
+///
+/// \param privateKey: the private key used for signing
+/// \param message: A custom hex message which is input to the signing.
+/// \param hashMode: how the message is converted to a field element.
+/// \param nonceMode: how the signature nonce is generated.
+/// \returns the signature, Hex-encoded. On invalid input empty string is returned. Returned object needs to be deleted after use.
+TW_EXPORT_STATIC_METHOD
+TWString* _Nonnull TWStarkExMessageSignerSignMessageWithModes(const struct TWPrivateKey* _Nonnull privateKey, TWString* _Nonnull message, enum TWStarkExHashMode hashMode, enum TWStarkExNonceMode nonceMode);
+
 /// Verify signature for a message.
 ///
 /// \param pubKey: pubKey that will verify and recover the message from the signature
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+
+TW_EXTERN_C_BEGIN
+
+/// Defines how the StarkEx signature nonce is generated.
+/// Both modes start with the same RFC6979 nonce, but differ if it doesn't produce a valid signature.
+TW_EXPORT_ENUM(uint32_t)
+enum TWStarkExNonceMode {
+    /// The nonce is regenerated with an incremented extra entropy, as the `cairo-lang` reference does (default).
+    TWStarkExNonceModeGrindExtraEntropy = 0,
+    /// The next nonce is taken from the same RFC6979 stream, as the `elliptic` based JS SDK does.
+    TWStarkExNonceModeRfc6979 = 1,
+};
+
+TW_EXTERN_C_END
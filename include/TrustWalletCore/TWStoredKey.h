@@ -347,6 +347,32 @@ struct TWHDWallet* _Nullable TWStoredKeyWallet(struct TWStoredKey* _Nonnull key,
 TW_EXPORT_METHOD
 TWData* _Nullable TWStoredKeyExportJSON(struct TWStoredKey* _Nonnull key);
 
+/// Exports the key as a password-protected backup (Argon2id + XChaCha20-Poly1305).
+///
+/// \param key Non-null pointer to a stored key
+/// \param password Non-null block of data, password of the backup
+/// \param metadata Non-null block of data, plaintext wallet metadata authenticated along with the backup
+/// \return Null pointer on failure, pointer to a block of data containing the backup otherwise
+TW_EXPORT_METHOD
+TWData* _Nullable TWStoredKeyExportBackup(struct TWStoredKey* _Nonnull key, TWData* _Nonnull password, TWData* _Nonnull metadata);
+
+/// Imports a key from a password-protected backup.
+///
+/// \param backup Non-null block of data, backup created with \TWStoredKeyExportBackup
+/// \param password Non-null block of data, password of the backup
+/// \note Returned object needs to be deleted with \TWStoredKeyDelete
+/// \return Nullptr if the password is wrong or the backup is invalid, the stored key otherwise
+TW_EXPORT_STATIC_METHOD
+struct TWStoredKey* _Nullable TWStoredKeyImportBackup(TWData* _Nonnull backup, TWData* _Nonnull password);
+
+/// Reads the plaintext metadata of a backup without decrypting it.
+/// The metadata can be trusted only after the backup has been imported successfully.
+///
+/// \param backup Non-null block of data, backup created with \TWStoredKeyExportBackup
+/// \return Null pointer if the backup is invalid, the metadata otherwise
+TW_EXPORT_STATIC_METHOD
+TWData* _Nullable TWStoredKeyBackupMetadata(TWData* _Nonnull backup);
+
 /// Fills in empty and invalid addresses.
 /// This method needs the encryption password to re-derive addresses from private keys.
 ///
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "TWBase.h"
+#include "TWCoinType.h"
+#include "TWData.h"
+
+TW_EXTERN_C_BEGIN
+
+TW_EXPORT_STRUCT
+struct TWTransactionPlanner;
+
+/// Plans a transaction in the chain-agnostic format: amount, fee, change, effective fee rate and the max amount.
+/// Used to preview the fee and to implement "send max".
+/// Only Bitcoin-family and EVM chains are supported, other chains return `Error_not_supported`.
+///
+/// \param coinType coin type.
+/// \param input The serialized data of a signing input (e.g. TW.BitcoinV2.Proto.SigningInput).
+/// \return The serialized data of a `TW.TransactionPlanner.Proto.TransactionPlan` proto object.
+TW_EXPORT_STATIC_METHOD
+TWData *_Nonnull TWTransactionPlannerPlan(enum TWCoinType coinType, TWData *_Nonnull input);
+
+TW_EXTERN_C_END
@@ -37,3 +37,11 @@ jbyteArray JNICALL Java_wallet_core_java_AnySigner_nativePlan(JNIEnv *env, jclas
     TWDataDelete(inputData);
     return resultData;
 }
+
+jbyteArray JNICALL Java_wallet_core_java_AnySigner_nativeSignEvm(JNIEnv *env, jclass thisClass, jbyteArray input) {
+    TWData *inputData = TWDataCreateWithJByteArray(env, input);
+    TWData *outputData = TWAnySignerSignEvm(inputData);
+    jbyteArray resultData = TWDataJByteArray(outputData, env);
+    TWDataDelete(inputData);
+    return resultData;
+}
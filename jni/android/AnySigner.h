@@ -22,6 +22,9 @@ jbyteArray JNICALL Java_wallet_core_java_AnySigner_signJSON(JNIEnv *env, jclass
 JNIEXPORT
 jbyteArray JNICALL Java_wallet_core_java_AnySigner_nativePlan(JNIEnv *env, jclass thisClass, jbyteArray input, jint coin);
 
+JNIEXPORT
+jbyteArray JNICALL Java_wallet_core_java_AnySigner_nativeSignEvm(JNIEnv *env, jclass thisClass, jbyteArray input);
+
 TW_EXTERN_C_END
 
 #endif // JNI_TW_ANYSIGNER_H
@@ -31,4 +31,13 @@ public class AnySigner {
         return output;
     }
     public static native byte[] nativePlan(byte[] data, int coin);
+
+    public static <T extends MessageLite> T signEvm(MessageLite input, Parser<T> parser) throws Exception {
+        byte[] data = input.toByteArray();
+        byte[] outputData = nativeSignEvm(data);
+        T output = parser.parseFrom(outputData);
+        outputData = null;
+        return output;
+    }
+    public static native byte[] nativeSignEvm(byte[] data);
 }
@@ -149,5 +149,8 @@ class CoinAddressDerivationTests {
         NativeZetaChain -> "zeta13u6g7vqgw074mgmf2ze2cadzvkz9snlwywj304"
         Dydx -> "dydx142j9u5eaduzd7faumygud6ruhdwme98qeayaky"
         Pactus -> "pc1r7ys2g5a4xc2qtm0t4q987m4mvs57w5g0v4pvzg"
+        Liquid -> "ex1qzn3tl7cvw7e9u03snfjnyp5vh5ge8vphmr88w6"
+        AvalancheXChain -> "X-avax1x8nslqfqha3aywycds3jfnykp3khffrk2scya2"
+        AvalanchePChain -> "P-avax1x8nslqfqha3aywycds3jfnykp3khffrk2scya2"
     }
 }
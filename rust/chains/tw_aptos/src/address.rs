@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::multisig::MultiEd25519PublicKey;
 use move_core_types::account_address::{AccountAddress, AccountAddressParseError};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
@@ -14,6 +15,7 @@ use tw_memory::Data;
 #[repr(u8)]
 pub enum Scheme {
     Ed25519 = 0,
+    MultiEd25519 = 1,
 }
 
 #[derive(Clone)]
@@ -35,6 +37,17 @@ impl Address {
         Ok(Address { addr })
     }
 
+    /// Initializes an address with a `MultiEd25519` public key.
+    pub fn with_multi_ed25519_pubkey(
+        pubkey: &MultiEd25519PublicKey,
+    ) -> Result<Address, AddressError> {
+        let mut to_hash = pubkey.to_bytes();
+        to_hash.push(Scheme::MultiEd25519 as u8);
+        let hashed = sha3_256(to_hash.as_slice());
+        let addr = AccountAddress::from_bytes(hashed).map_err(from_account_error)?;
+        Ok(Address { addr })
+    }
+
     pub fn inner(&self) -> AccountAddress {
         self.addr
     }
@@ -1,9 +1,12 @@
 use crate::address::Address;
+use crate::multisig::{MultiEd25519PublicKey, MultiEd25519Signature};
+use crate::transaction::{RawTransaction, SignedTransaction};
 use crate::transaction_builder;
 use std::str::FromStr;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
+use tw_keypair::ed25519;
 use tw_proto::Aptos::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
 
@@ -22,9 +25,7 @@ impl Compiler {
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
-        let sender = Address::from_str(&input.sender)
-            .into_tw()
-            .context("Invalid sender address")?;
+        let sender = Self::sender_address(&input)?;
         let signed_tx = builder
             .sender(sender.inner())
             .sequence_number(input.sequence_number as u64)
@@ -52,19 +53,22 @@ impl Compiler {
         public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
         let builder = transaction_builder::TransactionFactory::new_from_protobuf(input.clone())?;
-        let sender = Address::from_str(&input.sender)?;
-        let signature = signatures
-            .first()
-            .or_tw_err(SigningErrorType::Error_signatures_count)?;
-        let public_key = public_keys
-            .first()
-            .or_tw_err(SigningErrorType::Error_signatures_count)?;
-
-        let signed_tx = builder
+        let sender = Self::sender_address(&input)?;
+        let raw_tx = builder
             .sender(sender.inner())
             .sequence_number(input.sequence_number as u64)
-            .build()?
-            .compile(signature.to_vec(), public_key.to_vec())?;
+            .build()?;
+
+        let signed_tx = match input.multisig {
+            Some(ref multisig) => {
+                let public_key = MultiEd25519PublicKey::from_proto(multisig)?;
+                let signature =
+                    Self::multi_ed25519_signature(&public_key, signatures, public_keys)?;
+                raw_tx.compile_multi_ed25519(signature, public_key)?
+            },
+            None => Self::compile_ed25519(&raw_tx, signatures, public_keys)?,
+        };
+
         Ok(Proto::SigningOutput {
             raw_txn: signed_tx.raw_txn_bytes().clone().into(),
             encoded: signed_tx.encoded().clone().into(),
@@ -73,4 +77,69 @@ impl Compiler {
             ..Proto::SigningOutput::default()
         })
     }
+
+    /// Returns the address of the MultiEd25519 account if `multisig` is set,
+    /// the `sender` address otherwise.
+    /// `sender` can be omitted for a MultiEd25519 account, but must match the account address if set.
+    fn sender_address(input: &Proto::SigningInput<'_>) -> SigningResult<Address> {
+        let Some(ref multisig) = input.multisig else {
+            return Address::from_str(&input.sender)
+                .into_tw()
+                .context("Invalid sender address");
+        };
+
+        let multisig_address = MultiEd25519PublicKey::from_proto(multisig)?
+            .address()
+            .into_tw()
+            .context("Invalid MultiEd25519 account")?;
+        if !input.sender.is_empty() {
+            let sender = Address::from_str(&input.sender)
+                .into_tw()
+                .context("Invalid sender address")?;
+            if sender.inner() != multisig_address.inner() {
+                return SigningError::err(SigningErrorType::Error_invalid_address)
+                    .context("Sender doesn't match the MultiEd25519 account address");
+            }
+        }
+        Ok(multisig_address)
+    }
+
+    fn compile_ed25519(
+        raw_tx: &RawTransaction,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<SignedTransaction> {
+        let signature = signatures
+            .first()
+            .or_tw_err(SigningErrorType::Error_signatures_count)?;
+        let public_key = public_keys
+            .first()
+            .or_tw_err(SigningErrorType::Error_signatures_count)?;
+        raw_tx.compile(signature.to_vec(), public_key.to_vec())
+    }
+
+    /// Assembles the signatures of the MultiEd25519 account keys.
+    /// The `signatures` and `public_keys` are expected to be in the same order.
+    fn multi_ed25519_signature(
+        multisig: &MultiEd25519PublicKey,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<MultiEd25519Signature> {
+        if signatures.len() != public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Expected the same number of signatures and public keys");
+        }
+
+        let key_signatures = signatures
+            .iter()
+            .zip(public_keys.iter())
+            .map(|(signature, public_key)| {
+                let signature = ed25519::Signature::try_from(signature.as_slice())?;
+                let public_key = ed25519::sha512::PublicKey::try_from(public_key.as_slice())?;
+                Ok((public_key, signature))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        MultiEd25519Signature::new(multisig, key_signatures)
+    }
 }
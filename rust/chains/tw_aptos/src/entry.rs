@@ -15,6 +15,7 @@ use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_planner::NoTransactionPlanner;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
@@ -37,6 +38,7 @@ impl CoinEntry for AptosEntry {
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = AptosTransactionUtil;
+    type TransactionPlanner = NoTransactionPlanner;
 
     #[inline]
     fn parse_address(
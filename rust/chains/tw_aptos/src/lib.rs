@@ -14,6 +14,7 @@ pub mod nft;
 pub mod compiler;
 pub mod liquid_staking;
 pub mod modules;
+pub mod multisig;
 pub mod signer;
 pub mod transaction;
 pub mod transaction_builder;
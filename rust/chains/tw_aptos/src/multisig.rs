@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! K-of-N MultiEd25519 accounts.
+//!
+//! See https://aptos.dev/en/build/sdks/ts-sdk/account#multi-ed25519
+
+use crate::address::Address;
+use serde::{Serialize, Serializer};
+use serde_json::{json, Value};
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::encode;
+use tw_keypair::ed25519;
+use tw_memory::Data;
+use tw_proto::Aptos::Proto;
+
+pub const MAX_NUM_OF_KEYS: usize = 32;
+const BITMAP_NUM_OF_BYTES: usize = 4;
+
+#[derive(Clone)]
+pub struct MultiEd25519PublicKey {
+    public_keys: Vec<ed25519::sha512::PublicKey>,
+    threshold: u8,
+}
+
+impl MultiEd25519PublicKey {
+    pub fn new(
+        public_keys: Vec<ed25519::sha512::PublicKey>,
+        threshold: u8,
+    ) -> SigningResult<MultiEd25519PublicKey> {
+        if public_keys.is_empty() || public_keys.len() > MAX_NUM_OF_KEYS {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context(format!("Expected 1 to {MAX_NUM_OF_KEYS} public keys"));
+        }
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Threshold must be from 1 to the number of public keys");
+        }
+
+        Ok(MultiEd25519PublicKey {
+            public_keys,
+            threshold,
+        })
+    }
+
+    pub fn from_proto(
+        multisig: &Proto::MultiEd25519PublicKey<'_>,
+    ) -> SigningResult<MultiEd25519PublicKey> {
+        let public_keys = multisig
+            .public_keys
+            .iter()
+            .map(|public_key| {
+                ed25519::sha512::PublicKey::try_from(public_key.as_ref())
+                    .into_tw()
+                    .context("Invalid MultiEd25519 public key")
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+        let threshold = u8::try_from(multisig.threshold)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Threshold must fit u8")?;
+
+        MultiEd25519PublicKey::new(public_keys, threshold)
+    }
+
+    pub fn public_keys(&self) -> &[ed25519::sha512::PublicKey] {
+        &self.public_keys
+    }
+
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    #[inline]
+    pub fn address(&self) -> AddressResult<Address> {
+        Address::with_multi_ed25519_pubkey(self)
+    }
+
+    /// Returns the public keys followed by the threshold.
+    pub fn to_bytes(&self) -> Data {
+        let mut bytes: Data = self
+            .public_keys
+            .iter()
+            .flat_map(|public_key| public_key.as_slice().to_vec())
+            .collect();
+        bytes.push(self.threshold);
+        bytes
+    }
+}
+
+/// Serialized as bytes the same way as `aptos-crypto` does.
+impl Serialize for MultiEd25519PublicKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+#[derive(Clone)]
+pub struct MultiEd25519Signature {
+    /// Signatures ordered by the signer index.
+    signatures: Vec<ed25519::Signature>,
+    /// The signer at index `i` is denoted by the `i`-th bit, most significant bit first.
+    bitmap: [u8; BITMAP_NUM_OF_BYTES],
+}
+
+impl MultiEd25519Signature {
+    /// Assembles the signatures, that can be given in any order.
+    /// Returns an error if there are less signatures than the threshold.
+    pub fn new(
+        public_key: &MultiEd25519PublicKey,
+        signatures: Vec<(ed25519::sha512::PublicKey, ed25519::Signature)>,
+    ) -> SigningResult<MultiEd25519Signature> {
+        let mut indexed = Vec::with_capacity(signatures.len());
+        for (signer, signature) in signatures {
+            let index = public_key
+                .public_keys
+                .iter()
+                .position(|public_key| public_key.as_slice() == signer.as_slice())
+                .or_tw_err(SigningErrorType::Error_invalid_params)
+                .context("Signer is not a key of the MultiEd25519 account")?;
+            indexed.push((index, signature));
+        }
+        indexed.sort_by_key(|(index, _)| *index);
+
+        let mut bitmap = [0_u8; BITMAP_NUM_OF_BYTES];
+        for (index, _) in indexed.iter() {
+            let mask = 0x80 >> (index % 8);
+            if bitmap[index / 8] & mask != 0 {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Duplicate MultiEd25519 signature");
+            }
+            bitmap[index / 8] |= mask;
+        }
+
+        if indexed.len() < public_key.threshold as usize {
+            return SigningError::err(SigningErrorType::Error_signatures_count).context(format!(
+                "Expected at least {} signatures",
+                public_key.threshold
+            ));
+        }
+
+        Ok(MultiEd25519Signature {
+            signatures: indexed
+                .into_iter()
+                .map(|(_, signature)| signature)
+                .collect(),
+            bitmap,
+        })
+    }
+
+    /// Returns the signatures followed by the bitmap.
+    pub fn to_bytes(&self) -> Data {
+        let mut bytes: Data = self
+            .signatures
+            .iter()
+            .flat_map(|signature| signature.to_bytes().into_vec())
+            .collect();
+        bytes.extend_from_slice(&self.bitmap);
+        bytes
+    }
+
+    pub fn to_json(&self, public_key: &MultiEd25519PublicKey) -> Value {
+        let public_keys: Vec<_> = public_key
+            .public_keys
+            .iter()
+            .map(|public_key| encode(public_key.as_slice(), true))
+            .collect();
+        let signatures: Vec<_> = self
+            .signatures
+            .iter()
+            .map(|signature| encode(signature.to_bytes().as_slice(), true))
+            .collect();
+
+        json!({
+            "bitmap": encode(&self.bitmap, true),
+            "public_keys": public_keys,
+            "signatures": signatures,
+            "threshold": public_key.threshold,
+            "type": "multi_ed25519_signature",
+        })
+    }
+}
+
+impl Serialize for MultiEd25519Signature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
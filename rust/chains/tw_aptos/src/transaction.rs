@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::constants::APTOS_SALT;
+use crate::multisig::{MultiEd25519PublicKey, MultiEd25519Signature};
 use crate::transaction_payload::TransactionPayload;
 use move_core_types::account_address::AccountAddress;
 use serde::Serialize;
@@ -23,6 +24,11 @@ pub enum TransactionAuthenticator {
         public_key: Vec<u8>,
         signature: Vec<u8>,
     },
+    /// K-of-N multisignature
+    MultiEd25519 {
+        public_key: MultiEd25519PublicKey,
+        signature: MultiEd25519Signature,
+    },
 }
 
 impl From<TransactionAuthenticator> for Proto::TransactionAuthenticator<'_> {
@@ -41,6 +47,7 @@ impl TransactionAuthenticator {
                 public_key: _public_key,
                 signature,
             } => signature.clone(),
+            TransactionAuthenticator::MultiEd25519 { signature, .. } => signature.to_bytes(),
         }
     }
 
@@ -50,6 +57,7 @@ impl TransactionAuthenticator {
                 public_key,
                 signature: _signature,
             } => public_key.clone(),
+            TransactionAuthenticator::MultiEd25519 { public_key, .. } => public_key.to_bytes(),
         }
     }
 
@@ -63,6 +71,10 @@ impl TransactionAuthenticator {
                        "signature": encode(signature, true),
                        "type": "ed25519_signature"})
             },
+            TransactionAuthenticator::MultiEd25519 {
+                public_key,
+                signature,
+            } => signature.to_json(public_key),
         }
     }
 }
@@ -146,11 +158,28 @@ impl RawTransaction {
         signature: Vec<u8>,
         public_key: Vec<u8>,
     ) -> SigningResult<SignedTransaction> {
-        let serialized = self.serialize()?;
-        let auth = TransactionAuthenticator::Ed25519 {
+        self.compile_with_authenticator(TransactionAuthenticator::Ed25519 {
             public_key,
             signature,
-        };
+        })
+    }
+
+    pub fn compile_multi_ed25519(
+        &self,
+        signature: MultiEd25519Signature,
+        public_key: MultiEd25519PublicKey,
+    ) -> SigningResult<SignedTransaction> {
+        self.compile_with_authenticator(TransactionAuthenticator::MultiEd25519 {
+            public_key,
+            signature,
+        })
+    }
+
+    fn compile_with_authenticator(
+        &self,
+        auth: TransactionAuthenticator,
+    ) -> SigningResult<SignedTransaction> {
+        let serialized = self.serialize()?;
         let mut encoded = serialized.clone();
         encoded.extend_from_slice(bcs::encode(&auth)?.as_slice());
         Ok(SignedTransaction {
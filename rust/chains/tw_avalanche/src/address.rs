@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use std::str::FromStr;
+use tw_bech32_address::Bech32Address;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::ripemd::sha256_ripemd;
+use tw_hash::H160;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+
+const CHAIN_ALIAS_SEPARATOR: char = '-';
+
+/// X-Chain or P-Chain address, e.g. `X-avax1...`.
+/// The key hash is `RIPEMD160(SHA256(compressed public key))` encoded in Bech32,
+/// prefixed with the alias of the chain.
+#[derive(Debug, PartialEq)]
+pub struct AvalancheAddress {
+    chain_alias: String,
+    bech32: Bech32Address,
+}
+
+impl AvalancheAddress {
+    pub fn with_secp256k1_pubkey(
+        chain_alias: &str,
+        hrp: String,
+        public_key: &secp256k1::PublicKey,
+    ) -> AddressResult<AvalancheAddress> {
+        let key_hash = public_key_hash(public_key);
+        Ok(AvalancheAddress {
+            chain_alias: chain_alias.to_string(),
+            bech32: Bech32Address::new(hrp, key_hash.to_vec())?,
+        })
+    }
+
+    pub fn chain_alias(&self) -> &str {
+        &self.chain_alias
+    }
+
+    pub fn hrp(&self) -> &str {
+        self.bech32.hrp()
+    }
+
+    pub fn key_hash(&self) -> H160 {
+        H160::try_from(self.bech32.key_hash()).expect("Key hash length is checked on parsing")
+    }
+}
+
+/// Returns `RIPEMD160(SHA256(compressed public key))`.
+pub fn public_key_hash(public_key: &secp256k1::PublicKey) -> H160 {
+    let key_hash = sha256_ripemd(public_key.compressed().as_slice());
+    H160::try_from(key_hash.as_slice()).expect("Expected 20 bytes")
+}
+
+impl CoinAddress for AvalancheAddress {
+    #[inline]
+    fn data(&self) -> Data {
+        self.bech32.key_hash().to_vec()
+    }
+}
+
+impl FromStr for AvalancheAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (chain_alias, bech32) = s
+            .split_once(CHAIN_ALIAS_SEPARATOR)
+            .ok_or(AddressError::InvalidInput)?;
+        if chain_alias.is_empty() {
+            return Err(AddressError::InvalidInput);
+        }
+
+        let bech32 = Bech32Address::from_str(bech32)?;
+        if bech32.key_hash().len() != H160::LEN {
+            return Err(AddressError::InvalidInput);
+        }
+
+        Ok(AvalancheAddress {
+            chain_alias: chain_alias.to_string(),
+            bech32,
+        })
+    }
+}
+
+impl fmt::Display for AvalancheAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{CHAIN_ALIAS_SEPARATOR}{}",
+            self.chain_alias, self.bech32
+        )
+    }
+}
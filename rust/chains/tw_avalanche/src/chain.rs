@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+/// Type IDs of the `secp256k1fx` types, the same on both X-Chain and P-Chain.
+pub const SECP256K1_TRANSFER_INPUT_TYPE_ID: u32 = 0x05;
+pub const SECP256K1_TRANSFER_OUTPUT_TYPE_ID: u32 = 0x07;
+pub const SECP256K1_CREDENTIAL_TYPE_ID: u32 = 0x09;
+
+/// Aliases of the Primary Network chains, that atomic transactions move funds between.
+pub const PRIMARY_NETWORK_ALIASES: [&str; 3] = [XChain::ALIAS, PChain::ALIAS, "C"];
+
+/// Returns the HRP of the addresses on the given network, the same as `avalanchego` does.
+pub fn network_hrp(network_id: u32) -> &'static str {
+    match network_id {
+        1 => "avax",
+        2 => "cascade",
+        3 => "denali",
+        4 => "everest",
+        5 => "fuji",
+        10 => "testing",
+        12345 => "local",
+        _ => "custom",
+    }
+}
+
+/// Chain specific parameters. The chains share the transaction format, but register
+/// the transaction types under different type IDs.
+pub trait AvalancheChain {
+    /// The alias the addresses of the chain are prefixed with.
+    const ALIAS: &'static str;
+
+    const BASE_TX_TYPE_ID: u32;
+    const IMPORT_TX_TYPE_ID: u32;
+    const EXPORT_TX_TYPE_ID: u32;
+}
+
+/// Exchange chain, runs the Avalanche Virtual Machine (AVM).
+/// https://docs.avax.network/reference/avalanchego/x-chain/txn-format
+pub struct XChain;
+
+impl AvalancheChain for XChain {
+    const ALIAS: &'static str = "X";
+
+    const BASE_TX_TYPE_ID: u32 = 0x00;
+    const IMPORT_TX_TYPE_ID: u32 = 0x03;
+    const EXPORT_TX_TYPE_ID: u32 = 0x04;
+}
+
+/// Platform chain, coordinates validators and staking.
+/// https://docs.avax.network/reference/avalanchego/p-chain/txn-format
+pub struct PChain;
+
+impl AvalancheChain for PChain {
+    const ALIAS: &'static str = "P";
+
+    const BASE_TX_TYPE_ID: u32 = 0x22;
+    const IMPORT_TX_TYPE_ID: u32 = 0x11;
+    const EXPORT_TX_TYPE_ID: u32 = 0x12;
+}
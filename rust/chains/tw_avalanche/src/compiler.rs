@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::public_key_hash;
+use crate::chain::AvalancheChain;
+use crate::encode::{cb58_encode, AvalancheEncode};
+use crate::modules::tx_builder::TxBuilder;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ecdsa::secp256k1;
+use tw_proto::Avalanche::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+pub struct AvalancheCompiler<Chain: AvalancheChain> {
+    _phantom: PhantomData<Chain>,
+}
+
+impl<Chain: AvalancheChain> AvalancheCompiler<Chain> {
+    #[inline]
+    pub fn preimage_hashes(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> CompilerProto::PreSigningOutput<'static> {
+        Self::preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    /// Every input owner signs the same hash.
+    fn preimage_hashes_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let unsigned_tx = TxBuilder::<Chain>::unsigned_tx_from_proto(&input)?;
+
+        Ok(CompilerProto::PreSigningOutput {
+            data_hash: unsigned_tx.sighash().to_vec().into(),
+            data: unsigned_tx.to_bytes().into(),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    #[inline]
+    pub fn compile(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::compile_impl(coin, input, signatures, public_keys)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    /// `signatures` and `public_keys` are expected to be in the same order.
+    fn compile_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        if signatures.len() != public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Expected the same number of signatures and public keys");
+        }
+
+        let mut signatures_by_signer = HashMap::with_capacity(signatures.len());
+        for (signature, public_key) in signatures.iter().zip(public_keys.iter()) {
+            let signature = secp256k1::Signature::from_bytes(signature)?;
+            let public_key = secp256k1::PublicKey::try_from(public_key.as_slice())?;
+            signatures_by_signer.insert(public_key_hash(&public_key), signature.to_bytes());
+        }
+
+        let unsigned_tx = TxBuilder::<Chain>::unsigned_tx_from_proto(&input)?;
+        let signed_tx = unsigned_tx.into_signed(|signer| {
+            signatures_by_signer
+                .get(signer)
+                .copied()
+                .or_tw_err(SigningErrorType::Error_signatures_count)
+                .context("No signature of an input owner provided")
+        })?;
+
+        Ok(Proto::SigningOutput {
+            encoded: signed_tx.to_bytes().into(),
+            transaction_id: cb58_encode(signed_tx.id().as_slice()).into(),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
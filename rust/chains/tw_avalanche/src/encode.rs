@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Avalanche codec: big-endian integers, and arrays prefixed with a 4-byte length.
+//! https://docs.avax.network/reference/standards/serialization-primitives
+
+use tw_encoding::base58::{self, Alphabet};
+use tw_hash::sha2::sha256;
+use tw_memory::Data;
+
+/// The only codec version in use.
+pub const CODEC_VERSION: u16 = 0;
+
+const CB58_CHECKSUM_LEN: usize = 4;
+
+pub trait AvalancheEncode {
+    fn encode(&self, encoder: &mut Encoder);
+
+    fn to_bytes(&self) -> Data {
+        let mut encoder = Encoder::default();
+        self.encode(&mut encoder);
+        encoder.finish()
+    }
+}
+
+#[derive(Default)]
+pub struct Encoder {
+    buffer: Data,
+}
+
+impl Encoder {
+    pub fn push_u16(&mut self, value: u16) {
+        self.buffer.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn push_u32(&mut self, value: u32) {
+        self.buffer.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn push_u64(&mut self, value: u64) {
+        self.buffer.extend_from_slice(&value.to_be_bytes());
+    }
+
+    /// Pushes fixed-length bytes, e.g. an ID or an address.
+    pub fn push_raw(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Pushes variable-length bytes prefixed with the length.
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        self.push_u32(bytes.len() as u32);
+        self.push_raw(bytes);
+    }
+
+    /// Pushes an array of items prefixed with the number of items.
+    pub fn push_array<T: AvalancheEncode>(&mut self, items: &[T]) {
+        self.push_u32(items.len() as u32);
+        for item in items {
+            item.encode(self);
+        }
+    }
+
+    pub fn finish(self) -> Data {
+        self.buffer
+    }
+}
+
+/// Encodes the bytes in CB58: Base58 with the last 4 bytes of SHA256 as the checksum.
+/// Used to represent IDs, e.g. transaction IDs.
+pub fn cb58_encode(bytes: &[u8]) -> String {
+    let checksum = sha256(bytes);
+    let mut data = bytes.to_vec();
+    data.extend_from_slice(&checksum[checksum.len() - CB58_CHECKSUM_LEN..]);
+    base58::encode(&data, Alphabet::Bitcoin)
+}
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::AvalancheAddress;
+use crate::chain::AvalancheChain;
+use crate::compiler::AvalancheCompiler;
+use crate::signer::AvalancheSigner;
+use std::str::FromStr;
+use tw_bech32_address::bech32_prefix::Bech32Prefix;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::json_signer::NoJsonSigner;
+use tw_coin_entry::modules::message_signer::NoMessageSigner;
+use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
+use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_planner::NoTransactionPlanner;
+use tw_coin_entry::modules::transaction_util::NoTransactionUtil;
+use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
+use tw_keypair::tw::PublicKey;
+use tw_proto::Avalanche::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+/// X-Chain and P-Chain entry, e.g. `AvalancheEntry(XChain)`.
+pub struct AvalancheEntry<Chain: AvalancheChain>(pub Chain);
+
+impl<Chain: AvalancheChain> AvalancheEntry<Chain> {
+    fn hrp(coin: &dyn CoinContext, prefix: Option<Bech32Prefix>) -> AddressResult<String> {
+        match prefix {
+            Some(Bech32Prefix { hrp }) => Ok(hrp),
+            None => coin.hrp().ok_or(AddressError::InvalidHrp),
+        }
+    }
+}
+
+impl<Chain: AvalancheChain> CoinEntry for AvalancheEntry<Chain> {
+    type AddressPrefix = Bech32Prefix;
+    type Address = AvalancheAddress;
+    type SigningInput<'a> = Proto::SigningInput<'a>;
+    type SigningOutput = Proto::SigningOutput<'static>;
+    type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+
+    // Optional modules:
+    type JsonSigner = NoJsonSigner;
+    type PlanBuilder = NoPlanBuilder;
+    type MessageSigner = NoMessageSigner;
+    type WalletConnector = NoWalletConnector;
+    type TransactionDecoder = NoTransactionDecoder;
+    type TransactionUtil = NoTransactionUtil;
+    type TransactionPlanner = NoTransactionPlanner;
+
+    #[inline]
+    fn parse_address(
+        &self,
+        coin: &dyn CoinContext,
+        address: &str,
+        prefix: Option<Self::AddressPrefix>,
+    ) -> AddressResult<Self::Address> {
+        let hrp = Self::hrp(coin, prefix)?;
+        let address = AvalancheAddress::from_str(address)?;
+        if address.chain_alias() != Chain::ALIAS {
+            return Err(AddressError::InvalidInput);
+        }
+        if address.hrp() != hrp {
+            return Err(AddressError::InvalidHrp);
+        }
+        Ok(address)
+    }
+
+    #[inline]
+    fn parse_address_unchecked(&self, address: &str) -> AddressResult<Self::Address> {
+        AvalancheAddress::from_str(address)
+    }
+
+    #[inline]
+    fn derive_address(
+        &self,
+        coin: &dyn CoinContext,
+        public_key: PublicKey,
+        _derivation: Derivation,
+        prefix: Option<Self::AddressPrefix>,
+    ) -> AddressResult<Self::Address> {
+        let public_key = public_key
+            .to_secp256k1()
+            .ok_or(AddressError::PublicKeyTypeMismatch)?;
+        AvalancheAddress::with_secp256k1_pubkey(Chain::ALIAS, Self::hrp(coin, prefix)?, public_key)
+    }
+
+    #[inline]
+    fn sign(&self, coin: &dyn CoinContext, input: Self::SigningInput<'_>) -> Self::SigningOutput {
+        AvalancheSigner::<Chain>::sign(coin, input)
+    }
+
+    #[inline]
+    fn preimage_hashes(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+    ) -> Self::PreSigningOutput {
+        AvalancheCompiler::<Chain>::preimage_hashes(coin, input)
+    }
+
+    #[inline]
+    fn compile(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Self::SigningOutput {
+        AvalancheCompiler::<Chain>::compile(coin, input, signatures, public_keys)
+    }
+}
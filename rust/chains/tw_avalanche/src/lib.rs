@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Avalanche X-Chain (AVM) and P-Chain (PlatformVM) support.
+//! C-Chain is an EVM chain and is supported by `tw_ethereum`.
+
+pub mod address;
+pub mod chain;
+pub mod compiler;
+pub mod encode;
+pub mod entry;
+pub mod modules;
+pub mod signer;
+pub mod transaction;
@@ -0,0 +1,304 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::AvalancheAddress;
+use crate::chain::{network_hrp, AvalancheChain, PRIMARY_NETWORK_ALIASES};
+use crate::transaction::transferable::{
+    sort_inputs, sort_outputs, TransferableInput, TransferableOutput,
+};
+use crate::transaction::{AtomicTx, BaseTx, UnsignedTransaction};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::{H160, H256};
+use tw_proto::Avalanche::Proto;
+use Proto::mod_SigningInput::OneOftransaction as TransactionType;
+
+const MAX_MEMO_LEN: usize = 256;
+const DEFAULT_THRESHOLD: u32 = 1;
+const DEFAULT_ADDRESS_INDEX: u32 = 0;
+
+/// The chain aliases and the HRP the addresses of a transaction part must be prefixed with.
+#[derive(Clone, Copy)]
+struct AddressFormat<'a> {
+    chain_aliases: &'a [&'static str],
+    hrp: &'static str,
+}
+
+pub struct TxBuilder<Chain: AvalancheChain> {
+    _phantom: PhantomData<Chain>,
+}
+
+impl<Chain: AvalancheChain> TxBuilder<Chain> {
+    pub fn unsigned_tx_from_proto(
+        input: &Proto::SigningInput<'_>,
+    ) -> SigningResult<UnsignedTransaction> {
+        if input.network_id == 0 {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Network ID must be set, e.g. 1 for Mainnet");
+        }
+        let blockchain_id = parse_id(&input.blockchain_id, "blockchain")?;
+
+        let hrp = network_hrp(input.network_id);
+        let chain_aliases = [Chain::ALIAS];
+        let chain_format = AddressFormat {
+            chain_aliases: &chain_aliases,
+            hrp,
+        };
+        // Atomic UTXOs are owned by addresses of the source or destination chain.
+        let atomic_format = AddressFormat {
+            chain_aliases: &PRIMARY_NETWORK_ALIASES,
+            hrp,
+        };
+
+        let tx = match input.transaction {
+            TransactionType::base_tx(ref tx) => UnsignedTransaction {
+                type_id: Chain::BASE_TX_TYPE_ID,
+                base: Self::base_tx_from_proto(input.network_id, blockchain_id, chain_format, tx)?,
+                atomic: AtomicTx::None,
+            },
+            TransactionType::export_tx(ref tx) => {
+                let base = tx
+                    .base
+                    .as_ref()
+                    .or_tw_err(SigningErrorType::Error_invalid_params)?;
+                let mut outputs = tx
+                    .exported_outputs
+                    .iter()
+                    .map(|output| output_from_proto(output, atomic_format))
+                    .collect::<SigningResult<Vec<_>>>()?;
+                if outputs.is_empty() {
+                    return SigningError::err(SigningErrorType::Error_invalid_params)
+                        .context("No exported outputs provided");
+                }
+                sort_outputs(&mut outputs);
+
+                UnsignedTransaction {
+                    type_id: Chain::EXPORT_TX_TYPE_ID,
+                    base: Self::base_tx_from_proto(
+                        input.network_id,
+                        blockchain_id,
+                        chain_format,
+                        base,
+                    )?,
+                    atomic: AtomicTx::Export {
+                        destination_chain: parse_id(&tx.destination_chain, "destination chain")?,
+                        outputs,
+                    },
+                }
+            },
+            TransactionType::import_tx(ref tx) => {
+                let base = tx
+                    .base
+                    .as_ref()
+                    .or_tw_err(SigningErrorType::Error_invalid_params)?;
+                let mut inputs = tx
+                    .imported_inputs
+                    .iter()
+                    .map(|input| input_from_proto(input, atomic_format))
+                    .collect::<SigningResult<Vec<_>>>()?;
+                if inputs.is_empty() {
+                    return SigningError::err(SigningErrorType::Error_missing_input_utxos)
+                        .context("No imported inputs provided");
+                }
+                sort_inputs(&mut inputs);
+
+                UnsignedTransaction {
+                    type_id: Chain::IMPORT_TX_TYPE_ID,
+                    base: Self::base_tx_from_proto(
+                        input.network_id,
+                        blockchain_id,
+                        chain_format,
+                        base,
+                    )?,
+                    atomic: AtomicTx::Import {
+                        source_chain: parse_id(&tx.source_chain, "source chain")?,
+                        inputs,
+                    },
+                }
+            },
+            TransactionType::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("No transaction provided")
+            },
+        };
+
+        check_balance(&tx)?;
+        Ok(tx)
+    }
+
+    fn base_tx_from_proto(
+        network_id: u32,
+        blockchain_id: H256,
+        address_format: AddressFormat<'_>,
+        tx: &Proto::BaseTx<'_>,
+    ) -> SigningResult<BaseTx> {
+        if tx.memo.len() > MAX_MEMO_LEN {
+            return SigningError::err(SigningErrorType::Error_invalid_memo)
+                .context(format!("Memo must not exceed {MAX_MEMO_LEN} bytes"));
+        }
+
+        let mut outputs = tx
+            .outputs
+            .iter()
+            .map(|output| output_from_proto(output, address_format))
+            .collect::<SigningResult<Vec<_>>>()?;
+        sort_outputs(&mut outputs);
+
+        let mut inputs = tx
+            .inputs
+            .iter()
+            .map(|input| input_from_proto(input, address_format))
+            .collect::<SigningResult<Vec<_>>>()?;
+        sort_inputs(&mut inputs);
+
+        Ok(BaseTx {
+            network_id,
+            blockchain_id,
+            outputs,
+            inputs,
+            memo: tx.memo.to_vec(),
+        })
+    }
+}
+
+fn output_from_proto(
+    output: &Proto::TransferableOutput<'_>,
+    address_format: AddressFormat<'_>,
+) -> SigningResult<TransferableOutput> {
+    if output.amount == 0 {
+        return SigningError::err(SigningErrorType::Error_zero_amount_requested)
+            .context("Output amount must be positive");
+    }
+
+    let mut addresses = output
+        .addresses
+        .iter()
+        .map(|address| parse_key_hash(address, address_format))
+        .collect::<SigningResult<Vec<_>>>()?;
+    addresses.sort();
+    addresses.dedup();
+
+    let threshold = if output.threshold == 0 {
+        DEFAULT_THRESHOLD
+    } else {
+        output.threshold
+    };
+    if threshold as usize > addresses.len() {
+        return SigningError::err(SigningErrorType::Error_invalid_params)
+            .context("Output threshold exceeds the number of addresses");
+    }
+
+    Ok(TransferableOutput {
+        asset_id: parse_id(&output.asset_id, "asset")?,
+        amount: output.amount,
+        locktime: output.locktime,
+        threshold,
+        addresses,
+    })
+}
+
+fn input_from_proto(
+    input: &Proto::TransferableInput<'_>,
+    address_format: AddressFormat<'_>,
+) -> SigningResult<TransferableInput> {
+    if input.amount == 0 {
+        return SigningError::err(SigningErrorType::Error_invalid_utxo_amount)
+            .context("Input amount must be positive");
+    }
+
+    let owners = input
+        .owners
+        .iter()
+        .map(|owner| parse_key_hash(owner, address_format))
+        .collect::<SigningResult<Vec<_>>>()?;
+
+    let mut address_indices = if input.address_indices.is_empty() {
+        vec![DEFAULT_ADDRESS_INDEX]
+    } else {
+        input.address_indices.clone()
+    };
+    address_indices.sort_unstable();
+    address_indices.dedup();
+
+    let signers = address_indices
+        .iter()
+        .map(|index| owners.get(*index as usize).copied())
+        .collect::<Option<Vec<_>>>()
+        .or_tw_err(SigningErrorType::Error_invalid_utxo)
+        .context("Address index is out of the UTXO owners range")?;
+
+    Ok(TransferableInput {
+        tx_id: parse_id(&input.tx_id, "transaction")?,
+        output_index: input.output_index,
+        asset_id: parse_id(&input.asset_id, "asset")?,
+        amount: input.amount,
+        address_indices,
+        signers,
+    })
+}
+
+/// Checks that the inputs cover the outputs of every asset.
+fn check_balance(tx: &UnsignedTransaction) -> SigningResult<()> {
+    let (imported_inputs, exported_outputs): (&[_], &[_]) = match tx.atomic {
+        AtomicTx::None => (&[], &[]),
+        AtomicTx::Import { ref inputs, .. } => (inputs, &[]),
+        AtomicTx::Export { ref outputs, .. } => (&[], outputs),
+    };
+
+    let mut balances: HashMap<H256, (u64, u64)> = HashMap::new();
+    for input in tx.base.inputs.iter().chain(imported_inputs) {
+        let (consumed, _) = balances.entry(input.asset_id).or_default();
+        *consumed = consumed
+            .checked_add(input.amount)
+            .or_tw_err(SigningErrorType::Error_invalid_utxo_amount)
+            .context("Inputs amount overflow")?;
+    }
+    for output in tx.base.outputs.iter().chain(exported_outputs) {
+        let (_, produced) = balances.entry(output.asset_id).or_default();
+        *produced = produced
+            .checked_add(output.amount)
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("Outputs amount overflow")?;
+    }
+
+    if balances
+        .values()
+        .any(|(consumed, produced)| consumed < produced)
+    {
+        return SigningError::err(SigningErrorType::Error_not_enough_utxos)
+            .context("Inputs don't cover the outputs");
+    }
+    Ok(())
+}
+
+fn parse_key_hash(address_str: &str, address_format: AddressFormat<'_>) -> SigningResult<H160> {
+    let address = AvalancheAddress::from_str(address_str)
+        .into_tw()
+        .with_context(|| format!("Invalid address: {address_str}"))?;
+    if !address_format
+        .chain_aliases
+        .iter()
+        .any(|alias| *alias == address.chain_alias())
+    {
+        return SigningError::err(SigningErrorType::Error_invalid_address)
+            .with_context(|| format!("Unexpected chain alias of the address: {address_str}"));
+    }
+    if address.hrp() != address_format.hrp {
+        return SigningError::err(SigningErrorType::Error_invalid_address).with_context(|| {
+            format!(
+                "Expected '{}' HRP of the network, found: {address_str}",
+                address_format.hrp
+            )
+        });
+    }
+    Ok(address.key_hash())
+}
+
+fn parse_id(id: &[u8], name: &str) -> SigningResult<H256> {
+    H256::try_from(id)
+        .tw_err(SigningErrorType::Error_invalid_params)
+        .with_context(|| format!("Invalid {name} ID, expected 32 bytes"))
+}
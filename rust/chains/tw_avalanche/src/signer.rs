@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::public_key_hash;
+use crate::chain::AvalancheChain;
+use crate::encode::{cb58_encode, AvalancheEncode};
+use crate::modules::tx_builder::TxBuilder;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_proto::Avalanche::Proto;
+
+pub struct AvalancheSigner<Chain: AvalancheChain> {
+    _phantom: PhantomData<Chain>,
+}
+
+impl<Chain: AvalancheChain> AvalancheSigner<Chain> {
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::sign_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let unsigned_tx = TxBuilder::<Chain>::unsigned_tx_from_proto(&input)?;
+
+        let mut private_keys = HashMap::with_capacity(input.private_keys.len());
+        for private_key in input.private_keys.iter() {
+            let private_key = secp256k1::PrivateKey::try_from(private_key.as_ref())?;
+            private_keys.insert(public_key_hash(&private_key.public()), private_key);
+        }
+
+        let sighash = unsigned_tx.sighash();
+        let signed_tx = unsigned_tx.into_signed(|signer| {
+            let private_key = private_keys
+                .get(signer)
+                .or_tw_err(SigningErrorType::Error_missing_private_key)
+                .context("No private key of an input owner provided")?;
+            Ok(private_key.sign(sighash)?.to_bytes())
+        })?;
+
+        Ok(Proto::SigningOutput {
+            encoded: signed_tx.to_bytes().into(),
+            transaction_id: cb58_encode(signed_tx.id().as_slice()).into(),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
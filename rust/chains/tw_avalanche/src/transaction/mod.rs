@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chain::SECP256K1_CREDENTIAL_TYPE_ID;
+use crate::encode::{AvalancheEncode, Encoder, CODEC_VERSION};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::sha2::sha256;
+use tw_hash::{H160, H256, H520};
+use tw_memory::Data;
+
+pub mod transferable;
+
+use transferable::{TransferableInput, TransferableOutput};
+
+/// Fields common to all transaction types.
+pub struct BaseTx {
+    pub network_id: u32,
+    pub blockchain_id: H256,
+    pub outputs: Vec<TransferableOutput>,
+    pub inputs: Vec<TransferableInput>,
+    pub memo: Data,
+}
+
+impl AvalancheEncode for BaseTx {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.push_u32(self.network_id);
+        encoder.push_raw(self.blockchain_id.as_slice());
+        encoder.push_array(&self.outputs);
+        encoder.push_array(&self.inputs);
+        encoder.push_bytes(&self.memo);
+    }
+}
+
+/// Fields specific to the atomic (cross-chain) transactions.
+pub enum AtomicTx {
+    None,
+    Import {
+        source_chain: H256,
+        inputs: Vec<TransferableInput>,
+    },
+    Export {
+        destination_chain: H256,
+        outputs: Vec<TransferableOutput>,
+    },
+}
+
+pub struct UnsignedTransaction {
+    pub type_id: u32,
+    pub base: BaseTx,
+    pub atomic: AtomicTx,
+}
+
+impl UnsignedTransaction {
+    /// Returns the hash to be signed by every signer.
+    pub fn sighash(&self) -> H256 {
+        H256::try_from(sha256(&self.to_bytes()).as_slice()).expect("Expected 32 bytes")
+    }
+
+    /// Returns the signers of every input in the order of the credentials.
+    pub fn signers(&self) -> impl Iterator<Item = &[H160]> {
+        let imported_inputs: &[TransferableInput] = match self.atomic {
+            AtomicTx::Import { ref inputs, .. } => inputs,
+            _ => &[],
+        };
+        self.base
+            .inputs
+            .iter()
+            .chain(imported_inputs)
+            .map(|input| input.signers.as_slice())
+    }
+
+    /// Creates a credential for every input using the signatures returned by `get_signature`.
+    pub fn into_signed<F>(self, mut get_signature: F) -> SigningResult<SignedTransaction>
+    where
+        F: FnMut(&H160) -> SigningResult<H520>,
+    {
+        let credentials = self
+            .signers()
+            .map(|signers| {
+                let signatures = signers
+                    .iter()
+                    .map(&mut get_signature)
+                    .collect::<SigningResult<Vec<_>>>()?;
+                Ok(Credential { signatures })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        Ok(SignedTransaction {
+            unsigned: self,
+            credentials,
+        })
+    }
+}
+
+impl AvalancheEncode for UnsignedTransaction {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.push_u16(CODEC_VERSION);
+        encoder.push_u32(self.type_id);
+        self.base.encode(encoder);
+
+        match self.atomic {
+            AtomicTx::None => (),
+            AtomicTx::Import {
+                ref source_chain,
+                ref inputs,
+            } => {
+                encoder.push_raw(source_chain.as_slice());
+                encoder.push_array(inputs);
+            },
+            AtomicTx::Export {
+                ref destination_chain,
+                ref outputs,
+            } => {
+                encoder.push_raw(destination_chain.as_slice());
+                encoder.push_array(outputs);
+            },
+        }
+    }
+}
+
+/// Signatures of an input in the order of its address indices.
+pub struct Credential {
+    pub signatures: Vec<H520>,
+}
+
+impl AvalancheEncode for Credential {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.push_u32(SECP256K1_CREDENTIAL_TYPE_ID);
+        encoder.push_u32(self.signatures.len() as u32);
+        for signature in self.signatures.iter() {
+            encoder.push_raw(signature.as_slice());
+        }
+    }
+}
+
+pub struct SignedTransaction {
+    pub unsigned: UnsignedTransaction,
+    pub credentials: Vec<Credential>,
+}
+
+impl SignedTransaction {
+    /// Transaction ID is the hash of the signed transaction.
+    pub fn id(&self) -> H256 {
+        H256::try_from(sha256(&self.to_bytes()).as_slice()).expect("Expected 32 bytes")
+    }
+}
+
+impl AvalancheEncode for SignedTransaction {
+    fn encode(&self, encoder: &mut Encoder) {
+        self.unsigned.encode(encoder);
+        encoder.push_array(&self.credentials);
+    }
+}
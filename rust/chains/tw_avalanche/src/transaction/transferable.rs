@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chain::{SECP256K1_TRANSFER_INPUT_TYPE_ID, SECP256K1_TRANSFER_OUTPUT_TYPE_ID};
+use crate::encode::{AvalancheEncode, Encoder};
+use tw_hash::{H160, H256};
+
+/// An asset output owned by a set of addresses.
+/// https://docs.avax.network/reference/avalanchego/x-chain/txn-format#transferable-output
+pub struct TransferableOutput {
+    pub asset_id: H256,
+    pub amount: u64,
+    pub locktime: u64,
+    pub threshold: u32,
+    /// Key hashes of the owners, sorted.
+    pub addresses: Vec<H160>,
+}
+
+impl AvalancheEncode for TransferableOutput {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.push_raw(self.asset_id.as_slice());
+        encoder.push_u32(SECP256K1_TRANSFER_OUTPUT_TYPE_ID);
+        encoder.push_u64(self.amount);
+        encoder.push_u64(self.locktime);
+        encoder.push_u32(self.threshold);
+        encoder.push_u32(self.addresses.len() as u32);
+        for address in self.addresses.iter() {
+            encoder.push_raw(address.as_slice());
+        }
+    }
+}
+
+/// A spent UTXO.
+/// https://docs.avax.network/reference/avalanchego/x-chain/txn-format#transferable-input
+pub struct TransferableInput {
+    pub tx_id: H256,
+    pub output_index: u32,
+    pub asset_id: H256,
+    pub amount: u64,
+    /// Indices of the UTXO owners signing the input, sorted.
+    pub address_indices: Vec<u32>,
+    /// Key hashes of the UTXO owners at `address_indices`. Not encoded.
+    pub signers: Vec<H160>,
+}
+
+impl AvalancheEncode for TransferableInput {
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.push_raw(self.tx_id.as_slice());
+        encoder.push_u32(self.output_index);
+        encoder.push_raw(self.asset_id.as_slice());
+        encoder.push_u32(SECP256K1_TRANSFER_INPUT_TYPE_ID);
+        encoder.push_u64(self.amount);
+        encoder.push_u32(self.address_indices.len() as u32);
+        for index in self.address_indices.iter() {
+            encoder.push_u32(*index);
+        }
+    }
+}
+
+/// Sorts the outputs by their encoding as required by the codec.
+pub fn sort_outputs(outputs: &mut [TransferableOutput]) {
+    outputs.sort_by_cached_key(|output| output.to_bytes());
+}
+
+/// Sorts the inputs by the UTXO ID as required by the codec.
+pub fn sort_inputs(inputs: &mut [TransferableInput]) {
+    inputs.sort_by_key(|input| (input.tx_id, input.output_index));
+}
@@ -16,6 +16,7 @@ use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_planner::NoTransactionPlanner;
 use tw_coin_entry::modules::transaction_util::NoTransactionUtil;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Binance::Proto;
@@ -37,6 +38,7 @@ impl CoinEntry for BinanceEntry {
     type WalletConnector = BinanceWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = NoTransactionUtil;
+    type TransactionPlanner = NoTransactionPlanner;
 
     #[inline]
     fn parse_address(
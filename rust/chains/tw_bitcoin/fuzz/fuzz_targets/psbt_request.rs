@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tw_bitcoin::context::StandardBitcoinContext;
+use tw_bitcoin::modules::psbt_request::standard_psbt_request_builder::StandardPsbtRequestBuilder;
+use tw_bitcoin::modules::psbt_request::PsbtRequestBuilder;
+use tw_proto::BitcoinV2::Proto;
+
+fuzz_target!(|data: &[u8]| {
+    let input = Proto::SigningInput::default();
+    let psbt = Proto::Psbt { psbt: data.into() };
+    let _ = <StandardPsbtRequestBuilder as PsbtRequestBuilder<StandardBitcoinContext>>::build(
+        &input, &psbt,
+    );
+});
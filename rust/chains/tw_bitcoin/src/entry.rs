@@ -1,5 +1,6 @@
 use crate::context::StandardBitcoinContext;
 use crate::modules::compiler::BitcoinCompiler;
+use crate::modules::message_signer::BitcoinMessageSigner;
 use crate::modules::planner::BitcoinPlanner;
 use crate::modules::signer::BitcoinSigner;
 use crate::modules::transaction_util::BitcoinTransactionUtil;
@@ -9,7 +10,6 @@ use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_keypair::tw::PublicKey;
@@ -28,10 +28,11 @@ impl CoinEntry for BitcoinEntry {
     // Optional modules:
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = BitcoinPlanner<StandardBitcoinContext>;
-    type MessageSigner = NoMessageSigner;
+    type MessageSigner = BitcoinMessageSigner;
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = BitcoinTransactionUtil;
+    type TransactionPlanner = BitcoinPlanner<StandardBitcoinContext>;
 
     #[inline]
     fn parse_address(
@@ -89,8 +90,18 @@ impl CoinEntry for BitcoinEntry {
         Some(BitcoinPlanner::<StandardBitcoinContext>::default())
     }
 
+    #[inline]
+    fn message_signer(&self) -> Option<Self::MessageSigner> {
+        Some(BitcoinMessageSigner)
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(BitcoinTransactionUtil)
     }
+
+    #[inline]
+    fn transaction_planner(&self) -> Option<Self::TransactionPlanner> {
+        Some(BitcoinPlanner::<StandardBitcoinContext>::default())
+    }
 }
@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! BIP-322 "simple" message signatures of P2WPKH addresses.
+//!
+//! The message is committed to by a virtual `to_spend` transaction that pays to the address,
+//! and the signature is the witness stack of a virtual `to_sign` transaction that spends it.
+//!
+//! See https://github.com/bitcoin/bips/blob/master/bip-0322.mediawiki
+
+use std::borrow::Cow;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base64::{self, STANDARD};
+use tw_hash::ripemd::sha256_ripemd;
+use tw_hash::sha2::{sha256, sha256_d};
+use tw_hash::{H160, H256, H264};
+use tw_keypair::ecdsa::der;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const MESSAGE_TAG: &[u8] = b"BIP0322-signed-message";
+const SIGHASH_ALL: u8 = 0x01;
+/// The P2WPKH witness consists of a signature and a public key.
+const WITNESS_ITEMS: u8 = 2;
+
+const OP_0: u8 = 0x00;
+const OP_RETURN: u8 = 0x6a;
+const OP_DUP: u8 = 0x76;
+const OP_HASH160: u8 = 0xa9;
+const OP_EQUALVERIFY: u8 = 0x88;
+const OP_CHECKSIG: u8 = 0xac;
+
+pub struct BitcoinMessageSigner;
+
+impl MessageSigner for BitcoinMessageSigner {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        _coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(input).unwrap_or_default()
+    }
+}
+
+impl BitcoinMessageSigner {
+    fn message_preimage_hashes_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
+        let sighash = Self::sighash(&private_key.public(), &input.message);
+
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::Owned(sighash.to_vec()),
+            data_hash: Cow::Owned(sighash.to_vec()),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
+        let public_key = private_key.public();
+
+        let sighash = Self::sighash(&public_key, &input.message);
+        let der_signature = private_key.sign(sighash)?.to_der()?.der_bytes();
+
+        let mut witness = vec![WITNESS_ITEMS];
+        push_item(
+            &mut witness,
+            &[der_signature.as_slice(), &[SIGHASH_ALL]].concat(),
+        );
+        push_item(&mut witness, public_key.compressed().as_slice());
+
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::Owned(base64::encode(&witness, STANDARD)),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(input: Proto::MessageVerifyingInput<'_>) -> SigningResult<bool> {
+        let public_key = secp256k1::PublicKey::try_from(input.public_key.as_ref())?;
+
+        let witness = base64::decode(&input.signature, STANDARD)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid base64 signature")?;
+        let (signature, witness_public_key) = parse_witness(&witness)
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("Expected a P2WPKH witness stack")?;
+
+        if witness_public_key != public_key.compressed().as_slice() {
+            return Ok(false);
+        }
+        let Some((&SIGHASH_ALL, der_signature)) = signature.split_last() else {
+            return Ok(false);
+        };
+
+        let der_signature = der::Signature::from_bytes(der_signature)?;
+        let verify_signature = secp256k1::VerifySignature::from_der(der_signature)?;
+
+        let sighash = Self::sighash(&public_key, &input.message);
+        Ok(public_key.verify(verify_signature, sighash))
+    }
+
+    /// Computes the BIP-143 sighash of the `to_sign` transaction input.
+    fn sighash(public_key: &secp256k1::PublicKey, message: &str) -> H256 {
+        let pubkey_hash =
+            H160::try_from(sha256_ripemd(public_key.compressed().as_slice()).as_slice())
+                .expect("Expected 20 byte public key hash");
+        let to_spend_txid = to_spend_txid(&pubkey_hash, message.as_bytes());
+
+        // The only input of `to_sign` spends the first output of `to_spend` with zero sequence.
+        let mut outpoint = to_spend_txid.to_vec();
+        outpoint.extend_from_slice(&0_u32.to_le_bytes());
+        let sequence = 0_u32.to_le_bytes();
+
+        // P2PKH script code of the P2WPKH output.
+        let mut script_code = vec![OP_DUP, OP_HASH160, H160::LEN as u8];
+        script_code.extend_from_slice(pubkey_hash.as_slice());
+        script_code.extend_from_slice(&[OP_EQUALVERIFY, OP_CHECKSIG]);
+
+        // The only output of `to_sign` is a zero-value `OP_RETURN`.
+        let mut outputs = 0_u64.to_le_bytes().to_vec();
+        push_item(&mut outputs, &[OP_RETURN]);
+
+        let mut preimage = Vec::new();
+        // Version.
+        preimage.extend_from_slice(&0_u32.to_le_bytes());
+        preimage.extend_from_slice(&sha256_d(&outpoint));
+        preimage.extend_from_slice(&sha256_d(&sequence));
+        preimage.extend_from_slice(&outpoint);
+        push_item(&mut preimage, &script_code);
+        // Amount of the spent output.
+        preimage.extend_from_slice(&0_u64.to_le_bytes());
+        preimage.extend_from_slice(&sequence);
+        preimage.extend_from_slice(&sha256_d(&outputs));
+        // Lock time.
+        preimage.extend_from_slice(&0_u32.to_le_bytes());
+        preimage.extend_from_slice(&(SIGHASH_ALL as u32).to_le_bytes());
+
+        H256::try_from(sha256_d(&preimage).as_slice()).expect("Expected 32 byte hash")
+    }
+}
+
+/// Returns the txid of the `to_spend` transaction in the internal byte order.
+fn to_spend_txid(pubkey_hash: &H160, message: &[u8]) -> H256 {
+    let mut message_challenge = vec![OP_0, H160::LEN as u8];
+    message_challenge.extend_from_slice(pubkey_hash.as_slice());
+
+    let mut script_sig = vec![OP_0, H256::LEN as u8];
+    script_sig.extend_from_slice(&tagged_hash(MESSAGE_TAG, message));
+
+    let mut tx = Vec::new();
+    // Version.
+    tx.extend_from_slice(&0_u32.to_le_bytes());
+    // A single input spending the `0000...0000:0xFFFFFFFF` outpoint.
+    tx.push(1);
+    tx.extend_from_slice(H256::default().as_slice());
+    tx.extend_from_slice(&u32::MAX.to_le_bytes());
+    push_item(&mut tx, &script_sig);
+    tx.extend_from_slice(&0_u32.to_le_bytes());
+    // A single zero-value output paying to the address.
+    tx.push(1);
+    tx.extend_from_slice(&0_u64.to_le_bytes());
+    push_item(&mut tx, &message_challenge);
+    // Lock time.
+    tx.extend_from_slice(&0_u32.to_le_bytes());
+
+    H256::try_from(sha256_d(&tx).as_slice()).expect("Expected 32 byte hash")
+}
+
+/// BIP-340 tagged hash `sha256(sha256(tag) || sha256(tag) || data)`.
+fn tagged_hash(tag: &[u8], data: &[u8]) -> Vec<u8> {
+    let tag_hash = sha256(tag);
+    sha256(&[tag_hash.as_slice(), tag_hash.as_slice(), data].concat())
+}
+
+/// Pushes a length-prefixed `item`. All items are expected to be shorter than `0xFD` bytes.
+fn push_item(dest: &mut Vec<u8>, item: &[u8]) {
+    dest.push(item.len() as u8);
+    dest.extend_from_slice(item);
+}
+
+/// Parses a two item witness stack, returns the signature and the public key items.
+fn parse_witness(witness: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (&items, rest) = witness.split_first()?;
+    if items != WITNESS_ITEMS {
+        return None;
+    }
+
+    let (&signature_len, rest) = rest.split_first()?;
+    if rest.len() < signature_len as usize {
+        return None;
+    }
+    let (signature, rest) = rest.split_at(signature_len as usize);
+
+    let (&public_key_len, public_key) = rest.split_first()?;
+    if public_key_len as usize != H264::LEN || public_key.len() != H264::LEN {
+        return None;
+    }
+    Some((signature, public_key))
+}
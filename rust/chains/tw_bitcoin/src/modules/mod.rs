@@ -3,6 +3,8 @@
 // Copyright © 2017 Trust Wallet.
 
 pub mod compiler;
+pub mod message_signer;
+pub mod payment_code;
 pub mod planner;
 pub mod protobuf_builder;
 pub mod psbt;
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! BIP-47 reusable payment codes (version 1).
+//!
+//! A payment code is an extended public key of the `m/47'/coin'/account'` HD node.
+//! * The notification address is P2PKH of the first child key `m/47'/coin'/account'/0`.
+//! * Alice sends the `i`-th payment to Bob at `B_i + s * G`, where `B_i` is Bob's `i`-th child key,
+//!   `a` is Alice's notification private key and `s = sha256(x(a * B_i))`.
+//! * Bob spends the payment with `b_i + s`, where `b_i` is his `i`-th child private key
+//!   and `s = sha256(x(b_i * A))`, where `A` is Alice's notification public key.
+//! * Before the first payment, Alice sends her payment code to Bob's notification address
+//!   in an `OP_RETURN` output. The payment code is blinded with `HMAC-SHA512(o, x(a * B))`,
+//!   where `o` is the outpoint of the designated input, `a` is the private key of the input and
+//!   `B` is Bob's notification public key.
+//!
+//! See https://github.com/bitcoin/bips/blob/master/bip-0047.mediawiki
+
+use std::fmt;
+use std::str::FromStr;
+use tw_base58_address::Base58Address;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::base58::Alphabet;
+use tw_hash::hmac::hmac_sha512;
+use tw_hash::sha2::{sha256, Sha256d};
+use tw_hash::{H256, H264};
+use tw_keypair::ecdsa::secp256k1::{ExtendedPublicKey, PrivateKey, PublicKey};
+use tw_utxo::address::legacy::LegacyAddress;
+use tw_utxo::encode;
+use tw_utxo::script::standard_script::conditions;
+use tw_utxo::script::Script;
+use tw_utxo::transaction::transaction_parts::OutPoint;
+
+/// BIP-47 purpose `m/47'`.
+pub const BIP47_PURPOSE: u32 = 47;
+/// The version byte of a base58 encoded payment code. Payment codes start with `PM8T`.
+pub const PAYMENT_CODE_PREFIX: u8 = 0x47;
+/// The only supported payment code version.
+pub const PAYMENT_CODE_VERSION: u8 = 0x01;
+/// The number of bytes of a binary payment code.
+pub const PAYMENT_CODE_LEN: usize = 80;
+
+const VERSION_IDX: usize = 0;
+const FEATURES_IDX: usize = 1;
+const PUBLIC_KEY_BEGIN: usize = 2;
+const CHAIN_CODE_BEGIN: usize = PUBLIC_KEY_BEGIN + H264::LEN;
+const CHAIN_CODE_END: usize = CHAIN_CODE_BEGIN + H256::LEN;
+/// The notification key is the first child of the payment code node.
+const NOTIFICATION_INDEX: u32 = 0;
+
+type PaymentCodeBase58 = Base58Address<{ PAYMENT_CODE_LEN + 1 }, 4, Sha256d>;
+
+/// A BIP-47 version 1 payment code.
+#[derive(Clone)]
+pub struct PaymentCode {
+    node: ExtendedPublicKey,
+    features: u8,
+}
+
+impl PaymentCode {
+    /// Creates a payment code from the extended public key of the `m/47'/coin'/account'` node.
+    pub fn new(node: ExtendedPublicKey) -> PaymentCode {
+        PaymentCode { node, features: 0 }
+    }
+
+    /// Parses an 80 byte binary payment code.
+    pub fn from_bytes(bytes: &[u8]) -> SigningResult<PaymentCode> {
+        if bytes.len() != PAYMENT_CODE_LEN {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Invalid payment code length");
+        }
+        if bytes[VERSION_IDX] != PAYMENT_CODE_VERSION {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Only version 1 payment codes are supported");
+        }
+
+        let public = PublicKey::try_from(&bytes[PUBLIC_KEY_BEGIN..CHAIN_CODE_BEGIN])
+            .into_tw()
+            .context("Invalid payment code public key")?;
+        let chain_code = H256::try_from(&bytes[CHAIN_CODE_BEGIN..CHAIN_CODE_END])
+            .expect("Expected 32 byte chain code");
+
+        Ok(PaymentCode {
+            node: ExtendedPublicKey::new(public, chain_code),
+            features: bytes[FEATURES_IDX],
+        })
+    }
+
+    /// Returns an 80 byte binary payment code.
+    pub fn to_bytes(&self) -> [u8; PAYMENT_CODE_LEN] {
+        let mut bytes = [0; PAYMENT_CODE_LEN];
+        bytes[VERSION_IDX] = PAYMENT_CODE_VERSION;
+        bytes[FEATURES_IDX] = self.features;
+        bytes[PUBLIC_KEY_BEGIN..CHAIN_CODE_BEGIN]
+            .copy_from_slice(self.node.public().compressed().as_slice());
+        bytes[CHAIN_CODE_BEGIN..CHAIN_CODE_END].copy_from_slice(self.node.chain_code().as_slice());
+        bytes
+    }
+
+    /// Returns the payment code node.
+    pub fn node(&self) -> &ExtendedPublicKey {
+        &self.node
+    }
+
+    /// Returns the public key of the notification address.
+    pub fn notification_public_key(&self) -> SigningResult<PublicKey> {
+        self.payment_public_key(NOTIFICATION_INDEX)
+    }
+
+    /// Returns the notification P2PKH address.
+    pub fn notification_address(&self, p2pkh_prefix: u8) -> SigningResult<LegacyAddress> {
+        let public_key = self.notification_public_key()?;
+        Ok(LegacyAddress::p2pkh_with_public_key(
+            p2pkh_prefix,
+            &public_key,
+        )?)
+    }
+
+    /// Returns the `index`-th child public key of the payment code node without the shared secret applied.
+    pub fn payment_public_key(&self, index: u32) -> SigningResult<PublicKey> {
+        Ok(self.node.derive_child(index)?.public().clone())
+    }
+
+    /// Computes the public key of the `index`-th payment to the owner of this payment code.
+    ///
+    /// `sender` is the notification private key of the sender, i.e. `m/47'/coin'/account'/0`.
+    /// Per BIP-47, the sender should try the next `index` if the function fails.
+    pub fn sending_public_key(&self, sender: &PrivateKey, index: u32) -> SigningResult<PublicKey> {
+        let recipient = self.payment_public_key(index)?;
+        let secret = shared_secret(sender, &recipient);
+        Ok(recipient.tweak_add(secret)?)
+    }
+
+    /// Computes the P2PKH address of the `index`-th payment to the owner of this payment code.
+    pub fn sending_address(
+        &self,
+        sender: &PrivateKey,
+        index: u32,
+        p2pkh_prefix: u8,
+    ) -> SigningResult<LegacyAddress> {
+        let public_key = self.sending_public_key(sender, index)?;
+        Ok(LegacyAddress::p2pkh_with_public_key(
+            p2pkh_prefix,
+            &public_key,
+        )?)
+    }
+
+    /// Returns the payment code blinded for the notification transaction to `recipient`.
+    ///
+    /// `designated` is the private key of the designated input of the notification transaction,
+    /// and `outpoint` is the outpoint spent by the input.
+    pub fn blinded_payload(
+        &self,
+        recipient: &PaymentCode,
+        designated: &PrivateKey,
+        outpoint: &OutPoint,
+    ) -> SigningResult<[u8; PAYMENT_CODE_LEN]> {
+        let notification = recipient.notification_public_key()?;
+        let x = designated.shared_secret_x(&notification);
+
+        let mut payload = self.to_bytes();
+        blind(&mut payload, &x, outpoint);
+        Ok(payload)
+    }
+
+    /// Returns the `OP_RETURN` script of the notification transaction to `recipient`.
+    /// See [`PaymentCode::blinded_payload`].
+    pub fn notification_script(
+        &self,
+        recipient: &PaymentCode,
+        designated: &PrivateKey,
+        outpoint: &OutPoint,
+    ) -> SigningResult<Script> {
+        let payload = self.blinded_payload(recipient, designated, outpoint)?;
+        Ok(conditions::new_op_return(&payload))
+    }
+
+    /// Unblinds the sender's payment code received in a notification transaction.
+    ///
+    /// `notification` is the recipient's notification private key `m/47'/coin'/account'/0`,
+    /// `designated` is the public key of the designated input of the notification transaction,
+    /// and `outpoint` is the outpoint spent by the input.
+    pub fn from_notification_payload(
+        payload: &[u8],
+        notification: &PrivateKey,
+        designated: &PublicKey,
+        outpoint: &OutPoint,
+    ) -> SigningResult<PaymentCode> {
+        let mut payload: [u8; PAYMENT_CODE_LEN] = payload
+            .try_into()
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid notification payload length")?;
+        let x = notification.shared_secret_x(designated);
+
+        blind(&mut payload, &x, outpoint);
+        PaymentCode::from_bytes(&payload)
+    }
+
+    /// Computes the private key that spends a payment sent by the owner of this payment code.
+    ///
+    /// `recipient` is the recipient's child private key `m/47'/coin'/account'/index`
+    /// corresponding to the payment index.
+    pub fn receiving_private_key(&self, recipient: &PrivateKey) -> SigningResult<PrivateKey> {
+        let sender = self.notification_public_key()?;
+        let secret = shared_secret(recipient, &sender);
+        Ok(recipient.tweak_add(secret)?)
+    }
+}
+
+impl FromStr for PaymentCode {
+    type Err = SigningError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let decoded = PaymentCodeBase58::from_str_with_alphabet(s, Alphabet::Bitcoin)
+            .into_tw()
+            .context("Invalid base58 payment code")?;
+        if decoded.bytes[0] != PAYMENT_CODE_PREFIX {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Invalid payment code prefix");
+        }
+        PaymentCode::from_bytes(&decoded.bytes[1..])
+    }
+}
+
+impl fmt::Display for PaymentCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut bytes = Vec::with_capacity(PAYMENT_CODE_LEN + 1);
+        bytes.push(PAYMENT_CODE_PREFIX);
+        bytes.extend_from_slice(&self.to_bytes());
+
+        let encoded = PaymentCodeBase58::new(&bytes, Alphabet::Bitcoin).map_err(|_| fmt::Error)?;
+        write!(f, "{encoded}")
+    }
+}
+
+/// XORs the x-coordinate of the public key and the chain code with `HMAC-SHA512(outpoint, x)`.
+/// Blinding is an involution, so the same function unblinds the payload.
+fn blind(payload: &mut [u8; PAYMENT_CODE_LEN], x: &H256, outpoint: &OutPoint) {
+    let mask = hmac_sha512(&encode::encode(outpoint), x.as_slice());
+    // The sign byte of the public key is not blinded.
+    let blinded = &mut payload[PUBLIC_KEY_BEGIN + 1..CHAIN_CODE_END];
+    for (byte, mask_byte) in blinded.iter_mut().zip(mask) {
+        *byte ^= mask_byte;
+    }
+}
+
+/// `sha256(x(private * public))`.
+fn shared_secret(private: &PrivateKey, public: &PublicKey) -> H256 {
+    let x = private.shared_secret_x(public);
+    H256::try_from(sha256(x.as_slice()).as_slice()).expect("Expected 32 byte sha256 hash")
+}
@@ -11,11 +11,13 @@ use std::marker::PhantomData;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::plan_builder::PlanBuilder;
+use tw_coin_entry::modules::transaction_planner::TransactionPlanner;
 use tw_coin_entry::signing_output_error;
 use tw_proto::BitcoinV2::Proto;
+use tw_proto::TransactionPlanner::Proto as PlannerProto;
 use tw_utxo::context::UtxoContext;
 use tw_utxo::modules::tx_planner::TxPlanner;
-use tw_utxo::modules::utxo_selector::SelectResult;
+use tw_utxo::modules::utxo_selector::{SelectPlan, SelectResult};
 use tw_utxo::transaction::transaction_interface::{
     TransactionInterface, TxInputInterface, TxOutputInterface,
 };
@@ -100,6 +102,105 @@ impl<Context: BitcoinSigningContext> BitcoinPlanner<Context> {
             ..Proto::TransactionPlan::default()
         })
     }
+
+    /// Plans a transaction in the chain-agnostic format.
+    /// Only `TransactionBuilder` is supported, as a PSBT has all inputs and outputs specified already.
+    pub fn transaction_plan_impl(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput<'_>,
+    ) -> SigningResult<PlannerProto::TransactionPlan<'static>> {
+        use PlannerProto::PlanWarning;
+        use Proto::mod_SigningInput::OneOftransaction as TransactionType;
+
+        let TransactionType::builder(ref tx_builder) = input.transaction else {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Only `TransactionBuilder` can be planned in the chain-agnostic format");
+        };
+
+        let request = Context::SigningRequestBuilder::build(coin, input, tx_builder)?;
+        let dust_threshold = request.dust_policy.dust_threshold();
+        let SelectResult { plan, .. } = TxPlanner::plan(request)?;
+
+        let max_amount = if tx_builder.max_amount_output.is_some() {
+            Some(plan.total_send)
+        } else {
+            Self::max_amount(coin, input, tx_builder)
+        };
+
+        let mut warnings = Vec::new();
+        if plan.dust_change > 0 {
+            warnings.push(PlanWarning::DustChangeAddedToFee);
+        }
+        if tx_builder
+            .inputs
+            .iter()
+            .any(|utxo| utxo.value < dust_threshold)
+        {
+            warnings.push(PlanWarning::DustInputsSkipped);
+        }
+
+        let SelectPlan {
+            total_send,
+            vsize_estimate,
+            fee_estimate,
+            change,
+            ..
+        } = plan;
+        let fee_per_vb = fee_estimate
+            .checked_div(vsize_estimate as i64)
+            .unwrap_or_default();
+
+        Ok(PlannerProto::TransactionPlan {
+            amount: (total_send - change).to_string().into(),
+            fee: fee_estimate.to_string().into(),
+            change: change.to_string().into(),
+            max_amount: max_amount
+                .map(|amount| amount.to_string())
+                .unwrap_or_default()
+                .into(),
+            fee_rate: Some(PlannerProto::FeeRate {
+                unit: PlannerProto::FeeUnit::PerVirtualByte,
+                units: vsize_estimate as u64,
+                amount_per_unit: fee_per_vb.to_string().into(),
+            }),
+            warnings,
+            ..PlannerProto::TransactionPlan::default()
+        })
+    }
+
+    /// Calculates the max amount that can be sent to the only recipient by spending all UTXOs.
+    /// Returns `None` if there are multiple recipients.
+    fn max_amount(
+        coin: &dyn CoinContext,
+        input: &Proto::SigningInput<'_>,
+        tx_builder: &Proto::TransactionBuilder<'_>,
+    ) -> Option<i64> {
+        let [recipient] = tx_builder.outputs.as_slice() else {
+            return None;
+        };
+
+        let max_tx_builder = Proto::TransactionBuilder {
+            max_amount_output: Some(recipient.clone()),
+            ..tx_builder.clone()
+        };
+        let max_amount = Context::SigningRequestBuilder::build(coin, input, &max_tx_builder)
+            .and_then(TxPlanner::plan)
+            // The UTXOs can't cover the fee, so nothing can be sent.
+            .map_or(0, |SelectResult { plan, .. }| plan.total_send);
+        Some(max_amount)
+    }
+}
+
+impl<Context: BitcoinSigningContext> TransactionPlanner for BitcoinPlanner<Context> {
+    type SigningInput<'a> = Proto::SigningInput<'a>;
+
+    fn plan_transaction(
+        &self,
+        coin: &dyn CoinContext,
+        input: &Self::SigningInput<'_>,
+    ) -> SigningResult<PlannerProto::TransactionPlan<'static>> {
+        Self::transaction_plan_impl(coin, input)
+    }
 }
 
 impl<Context: BitcoinSigningContext> PlanBuilder for BitcoinPlanner<Context> {
@@ -58,14 +58,7 @@ impl<Context: BitcoinSigningContext> BitcoinSigner<Context> {
         let request = Context::SigningRequestBuilder::build(coin, input, tx_builder_input)?;
         let SelectResult { unsigned_tx, plan } = TxPlanner::plan(request)?;
 
-        let keys_manager = Self::keys_manager_for_tx(
-            &input.private_keys,
-            &unsigned_tx,
-            input.dangerous_use_fixed_schnorr_rng,
-        )?;
-
-        let signed_tx =
-            TxSigner::sign_tx(unsigned_tx, &keys_manager).context("Error signing transaction")?;
+        let signed_tx = Self::sign_tx(input, unsigned_tx)?;
 
         Ok(Proto::SigningOutput {
             transaction: Context::ProtobufBuilder::tx_to_proto(&signed_tx),
@@ -93,14 +86,7 @@ impl<Context: BitcoinSigningContext> BitcoinSigner<Context> {
 
         let fee = unsigned_tx.fee()?;
 
-        let keys_manager = Self::keys_manager_for_tx(
-            &input.private_keys,
-            &unsigned_tx,
-            input.dangerous_use_fixed_schnorr_rng,
-        )?;
-
-        let signed_tx =
-            TxSigner::sign_tx(unsigned_tx, &keys_manager).context("Error signing transaction")?;
+        let signed_tx = Self::sign_tx(input, unsigned_tx)?;
 
         update_psbt_signed(&mut psbt, &signed_tx);
 
@@ -119,6 +105,27 @@ impl<Context: BitcoinSigningContext> BitcoinSigner<Context> {
         })
     }
 
+    /// Signs the transaction by the private keys,
+    /// or fills the signatures with the placeholders if [`Proto::SigningInput::dummy_signatures`] is set.
+    fn sign_tx(
+        input: &Proto::SigningInput,
+        unsigned_tx: UnsignedTransaction<Context::Transaction>,
+    ) -> SigningResult<Context::Transaction> {
+        if input.dummy_signatures {
+            return unsigned_tx
+                .dummy_signed_transaction()
+                .context("Error filling dummy signatures");
+        }
+
+        let keys_manager = Self::keys_manager_for_tx(
+            &input.private_keys,
+            &unsigned_tx,
+            input.dangerous_use_fixed_schnorr_rng,
+        )?;
+
+        TxSigner::sign_tx(unsigned_tx, &keys_manager).context("Error signing transaction")
+    }
+
     fn keys_manager_for_tx<P>(
         private_keys: &[P],
         unsigned_tx: &UnsignedTransaction<Context::Transaction>,
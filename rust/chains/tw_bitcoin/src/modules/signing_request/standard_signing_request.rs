@@ -62,7 +62,8 @@ where
 
         // If `max_amount_output` is set, construct a transaction with only one output.
         if let Some(max_output_proto) = transaction_builder.max_amount_output.as_ref() {
-            let output_builder = OutputProtobuf::<Context>::new(&chain_info, max_output_proto);
+            let output_builder =
+                OutputProtobuf::<Context>::new(&chain_info, max_output_proto).outputs_count(1);
 
             let max_output = output_builder
                 .output_from_proto()
@@ -77,9 +78,14 @@ where
             });
         }
 
+        // The change output is appended after the other outputs if provided.
+        let outputs_count = transaction_builder.outputs.len()
+            + transaction_builder.change_output.is_some() as usize;
+
         // `max_amount_output` isn't set, parse all Outputs.
         for output_proto in transaction_builder.outputs.iter() {
             let output = OutputProtobuf::<Context>::new(&chain_info, output_proto)
+                .outputs_count(outputs_count)
                 .output_from_proto()
                 .context("Error creating Output from Proto")?;
             builder.push_output(output);
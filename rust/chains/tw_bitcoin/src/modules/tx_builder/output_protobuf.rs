@@ -15,12 +15,15 @@ use tw_memory::Data;
 use tw_proto::BitcoinV2::Proto;
 use tw_utxo::context::UtxoContext;
 use tw_utxo::script::Script;
+use tw_utxo::transaction::asset::runes::{Edict, RuneId, Runestone};
 use tw_utxo::transaction::standard_transaction::builder::OutputBuilder;
 use tw_utxo::transaction::standard_transaction::TransactionOutput;
 
 pub struct OutputProtobuf<'a, Context: UtxoContext> {
     chain_info: &'a BitcoinChainInfo,
     output: &'a Proto::Output<'a>,
+    /// Number of the transaction outputs, used to check the runestone output indices.
+    outputs_count: Option<usize>,
     _phantom: PhantomData<Context>,
 }
 
@@ -29,10 +32,18 @@ impl<'a, Context: UtxoContext> OutputProtobuf<'a, Context> {
         OutputProtobuf {
             chain_info,
             output,
+            outputs_count: None,
             _phantom: PhantomData,
         }
     }
 
+    /// Sets the number of the transaction outputs, so the runestone edicts and pointer
+    /// can't refer to a non-existing output.
+    pub fn outputs_count(mut self, outputs_count: usize) -> Self {
+        self.outputs_count = Some(outputs_count);
+        self
+    }
+
     pub fn output_from_proto(self) -> SigningResult<TransactionOutput> {
         use Proto::mod_Output::mod_OutputBuilder::OneOfvariant as BuilderType;
         use Proto::mod_Output::OneOfto_recipient as RecipientType;
@@ -50,7 +61,11 @@ impl<'a, Context: UtxoContext> OutputProtobuf<'a, Context> {
                     self.p2tr_dangerous_assume_tweaked(pubkey)
                 },
                 BuilderType::brc20_inscribe(ref inscription) => self.brc20_inscribe(inscription),
+                BuilderType::ordinal_inscribe(ref inscription) => {
+                    self.ordinal_inscribe(inscription)
+                },
                 BuilderType::op_return(ref data) => self.op_return(data),
+                BuilderType::runestone(ref runestone) => self.runestone(runestone),
                 BuilderType::babylon_staking(ref staking) => self.babylon_staking(staking),
                 BuilderType::babylon_staking_op_return(ref op_return) => {
                     self.babylon_staking_op_return(op_return)
@@ -157,6 +172,18 @@ impl<'a, Context: UtxoContext> OutputProtobuf<'a, Context> {
         )
     }
 
+    pub fn ordinal_inscribe(
+        &self,
+        inscription: &Proto::mod_Output::OutputOrdinalInscription,
+    ) -> SigningResult<TransactionOutput> {
+        let public_key = schnorr::PublicKey::try_from(inscription.inscribe_to.as_ref())?;
+        self.prepare_builder()?.ordinal_nft(
+            &public_key,
+            inscription.mime_type.as_bytes(),
+            &inscription.payload,
+        )
+    }
+
     pub fn custom_script(&self, script_data: Data) -> SigningResult<TransactionOutput> {
         let script = Script::from(script_data);
         Ok(self.prepare_builder()?.custom_script_pubkey(script))
@@ -176,6 +203,43 @@ impl<'a, Context: UtxoContext> OutputProtobuf<'a, Context> {
         self.prepare_builder()?.op_return(op_return_data)
     }
 
+    pub fn runestone(
+        &self,
+        runestone: &Proto::mod_Output::OutputRunestone,
+    ) -> SigningResult<TransactionOutput> {
+        let edicts = runestone
+            .edicts
+            .iter()
+            .map(|edict| {
+                let id = edict
+                    .id
+                    .as_ref()
+                    .or_tw_err(SigningErrorType::Error_invalid_params)
+                    .context("No rune ID provided")?;
+                let amount = u128::from_str(&edict.amount)
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Invalid rune edict amount")?;
+                Ok(Edict {
+                    id: rune_id_from_proto(id),
+                    amount,
+                    output: edict.output,
+                })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let runestone = Runestone {
+            edicts,
+            mint: runestone.mint.as_ref().map(rune_id_from_proto),
+            pointer: runestone.pointer.as_ref().map(|pointer| pointer.output),
+        };
+        if let Some(outputs_count) = self.outputs_count {
+            runestone.check_outputs(outputs_count)?;
+        }
+        self.prepare_builder()?
+            .runestone(&runestone)
+            .context("Invalid runestone")
+    }
+
     /// Tries to convert [`Proto::RedeemScriptOrHash`] to [`Hash<N>`] using a specific `hasher` function.
     /// Please note `P2SH` and `P2WSH` use different hashing functions.
     pub fn redeem_hash_from_proto<const N: usize, F>(
@@ -233,3 +297,10 @@ impl<'a, Context: UtxoContext> OutputProtobuf<'a, Context> {
             .with_context(|| format!("Expected exactly {N} bytes public key hash"))
     }
 }
+
+fn rune_id_from_proto(id: &Proto::mod_Output::RuneId) -> RuneId {
+    RuneId {
+        block: id.block,
+        tx: id.tx,
+    }
+}
@@ -58,6 +58,9 @@ impl<'a, Context: UtxoContext> UtxoProtobuf<'a, Context> {
                 BuilderType::p2tr_key_path(ref key_path) => self.p2tr_key_path(key_path),
                 // BuilderType::p2tr_script_path(ref script) => self.p2tr_script_path(script),
                 BuilderType::brc20_inscribe(ref inscription) => self.brc20_inscribe(inscription),
+                BuilderType::ordinal_inscribe(ref inscription) => {
+                    self.ordinal_inscribe(inscription)
+                },
                 BuilderType::babylon_staking_timelock_path(ref timelock) => {
                     self.babylon_staking_timelock(timelock)
                 },
@@ -147,6 +150,18 @@ impl<'a, Context: UtxoContext> UtxoProtobuf<'a, Context> {
         )
     }
 
+    pub fn ordinal_inscribe(
+        &self,
+        inscription: &Proto::mod_Input::InputOrdinalInscription,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        let public_key = schnorr::PublicKey::try_from(inscription.inscribe_to.as_ref())?;
+        self.prepare_builder()?.ordinal_nft(
+            &public_key,
+            inscription.mime_type.as_bytes(),
+            &inscription.payload,
+        )
+    }
+
     pub fn custom_script(
         &self,
         script_data: Data,
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::str::FromStr;
+use tw_bitcoin::modules::payment_code::PaymentCode;
+use tw_encoding::hex::ToHex;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1::PrivateKey;
+use tw_misc::traits::ToBytesZeroizing;
+use tw_utxo::transaction::transaction_parts::OutPoint;
+
+/// Test vectors from BIP-47.
+const ALICE_PAYMENT_CODE: &str = "PM8TJTLJbPRGxSbc8EJi42Wrr6QbNSaSSVJ5Y3E4pbCYiTHUskHg13935Ubb7q8tx9GVbh2UuRnBc3WSyJHhUrw8KhprKnn9eDznYGieTzFcwQRya4GA";
+const BOB_PAYMENT_CODE: &str = "PM8TJS2JxQ5ztXUpBBRnpTbcUXbUHy2T1abfrb3KkAAtMEGNbey4oumH7Hc578WgQJhPjBxteQ5GHHToTYHE3A1w6p7tU6KSoFmWBVbFGjKPisZDbP97";
+/// Alice's notification private key `m/47'/0'/0'/0`.
+const ALICE_NOTIFICATION_PRIVATE: &str =
+    "8d6a8ecd8ee5e0042ad0cb56e3a971c760b5145c3917a8e7beaf0ed92d7a520c";
+/// Bob's private key `m/47'/0'/0'/0`.
+const BOB_PRIVATE_0: &str = "04448fd1be0c9c13a5ca0b530e464b619dc091b299b98c5cab9978b32b4a1b8b";
+const BITCOIN_P2PKH_PREFIX: u8 = 0;
+/// The private key of the designated input of Alice's notification transaction.
+const DESIGNATED_PRIVATE: &str = "1b7a10f45118e2519a8dd46ef81591c1ae501d082b6610fdda3de7a3c932880d";
+/// The blinded payload of Alice's notification transaction to Bob.
+const ALICE_BLINDED_PAYLOAD: &str = "010002063e4eb95e62791b06c50e1a3a942e1ecaaa9afbbeb324d16ae6821e091611fa96c0cf048f607fe51a0327f5e2528979311c78cb2de0d682c61e1180fc3d543b00000000000000000000000000";
+
+/// The outpoint spent by the designated input of Alice's notification transaction.
+fn designated_outpoint() -> OutPoint {
+    OutPoint {
+        hash: H256::from("86f411ab1c8e70ae8a0795ab7a6757aea6e4d5ae1826fc7b8f00c597d500609c"),
+        index: 1,
+    }
+}
+
+#[test]
+fn test_payment_code_parse() {
+    let alice = PaymentCode::from_str(ALICE_PAYMENT_CODE).unwrap();
+    assert_eq!(
+        alice.to_bytes().to_hex(),
+        "010002b85034fb08a8bfefd22848238257b252721454bbbfba2c3667f168837ea2cdad671af9f65904632e2dcc0c6ad314e11d53fc82fa4c4ea27a4a14eccecc478fee00000000000000000000000000"
+    );
+    assert_eq!(alice.to_string(), ALICE_PAYMENT_CODE);
+
+    let restored = PaymentCode::from_bytes(&alice.to_bytes()).unwrap();
+    assert_eq!(restored.to_string(), ALICE_PAYMENT_CODE);
+}
+
+#[test]
+fn test_payment_code_invalid() {
+    // Invalid checksum.
+    let mut invalid = ALICE_PAYMENT_CODE.to_string();
+    invalid.pop();
+    invalid.push('B');
+    assert!(PaymentCode::from_str(&invalid).is_err());
+
+    // Not a payment code.
+    assert!(PaymentCode::from_str("1JDdmqFLhpzcUwPeinhJbUPw4Co3aWLyzW").is_err());
+
+    // Unsupported version.
+    let mut bytes = PaymentCode::from_str(ALICE_PAYMENT_CODE)
+        .unwrap()
+        .to_bytes();
+    bytes[0] = 2;
+    assert!(PaymentCode::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_payment_code_notification_address() {
+    let alice = PaymentCode::from_str(ALICE_PAYMENT_CODE).unwrap();
+    assert_eq!(
+        alice
+            .notification_address(BITCOIN_P2PKH_PREFIX)
+            .unwrap()
+            .to_string(),
+        "1JDdmqFLhpzcUwPeinhJbUPw4Co3aWLyzW"
+    );
+    assert_eq!(
+        alice
+            .notification_public_key()
+            .unwrap()
+            .compressed()
+            .to_hex(),
+        "0353883a146a23f988e0f381a9507cbdb3e3130cd81b3ce26daf2af088724ce683"
+    );
+
+    let bob = PaymentCode::from_str(BOB_PAYMENT_CODE).unwrap();
+    assert_eq!(
+        bob.notification_address(BITCOIN_P2PKH_PREFIX)
+            .unwrap()
+            .to_string(),
+        "1ChvUUvht2hUQufHBXF8NgLhW8SwE2ecGV"
+    );
+}
+
+#[test]
+fn test_payment_code_send_receive() {
+    let bob = PaymentCode::from_str(BOB_PAYMENT_CODE).unwrap();
+    let alice_notification = PrivateKey::try_from(ALICE_NOTIFICATION_PRIVATE).unwrap();
+
+    let addresses: Vec<_> = (0..2)
+        .map(|index| {
+            bob.sending_address(&alice_notification, index, BITCOIN_P2PKH_PREFIX)
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(
+        addresses,
+        [
+            "141fi7TY3h936vRUKh1qfUZr8rSBuYbVBK",
+            "12u3Uued2fuko2nY4SoSFGCoGLCBUGPkk6"
+        ]
+    );
+
+    // Bob restores the private key of the first payment from Alice.
+    let alice = PaymentCode::from_str(ALICE_PAYMENT_CODE).unwrap();
+    let bob_private = PrivateKey::try_from(BOB_PRIVATE_0).unwrap();
+    let receiving = alice.receiving_private_key(&bob_private).unwrap();
+    assert_eq!(
+        receiving.to_zeroizing_vec().as_slice().to_hex(),
+        "d687f6b820e6e3d47296b01f3b73ccdc930eded39d559921a7dd8ed81b2c8f82"
+    );
+    assert_eq!(
+        receiving.public().compressed(),
+        bob.sending_public_key(&alice_notification, 0)
+            .unwrap()
+            .compressed()
+    );
+}
+
+#[test]
+fn test_payment_code_notification_payload() {
+    let alice = PaymentCode::from_str(ALICE_PAYMENT_CODE).unwrap();
+    let bob = PaymentCode::from_str(BOB_PAYMENT_CODE).unwrap();
+    let designated = PrivateKey::try_from(DESIGNATED_PRIVATE).unwrap();
+    let outpoint = designated_outpoint();
+
+    let payload = alice.blinded_payload(&bob, &designated, &outpoint).unwrap();
+    assert_eq!(payload.to_hex(), ALICE_BLINDED_PAYLOAD);
+
+    let script = alice
+        .notification_script(&bob, &designated, &outpoint)
+        .unwrap();
+    assert_eq!(
+        script.as_slice().to_hex(),
+        format!("6a4c50{ALICE_BLINDED_PAYLOAD}")
+    );
+
+    // Bob unblinds Alice's payment code with his notification private key.
+    let bob_notification = PrivateKey::try_from(BOB_PRIVATE_0).unwrap();
+    let unblinded = PaymentCode::from_notification_payload(
+        &payload,
+        &bob_notification,
+        &designated.public(),
+        &outpoint,
+    )
+    .unwrap();
+    assert_eq!(unblinded.to_string(), ALICE_PAYMENT_CODE);
+}
+
+#[test]
+fn test_payment_code_notification_payload_invalid() {
+    let bob_notification = PrivateKey::try_from(BOB_PRIVATE_0).unwrap();
+    let designated = PrivateKey::try_from(DESIGNATED_PRIVATE).unwrap();
+    let outpoint = designated_outpoint();
+
+    let payload = tw_encoding::hex::decode(ALICE_BLINDED_PAYLOAD).unwrap();
+    assert!(PaymentCode::from_notification_payload(
+        &payload[1..],
+        &bob_notification,
+        &designated.public(),
+        &outpoint,
+    )
+    .is_err());
+
+    // Unblinding with another outpoint gives a random public key x-coordinate,
+    // or a payment code that differs from Alice's one.
+    let other_outpoint = OutPoint {
+        index: 0,
+        ..designated_outpoint()
+    };
+    let unblinded = PaymentCode::from_notification_payload(
+        &payload,
+        &bob_notification,
+        &designated.public(),
+        &other_outpoint,
+    );
+    assert!(unblinded.map_or(true, |code| code.to_string() != ALICE_PAYMENT_CODE));
+}
@@ -38,6 +38,7 @@ impl CoinEntry for BitcoinCashEntry {
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = BitcoinTransactionUtil;
+    type TransactionPlanner = BitcoinPlanner<BitcoinCashContext>;
 
     #[inline]
     fn parse_address(
@@ -102,4 +103,9 @@ impl CoinEntry for BitcoinCashEntry {
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(BitcoinTransactionUtil)
     }
+
+    #[inline]
+    fn transaction_planner(&self) -> Option<Self::TransactionPlanner> {
+        Some(BitcoinPlanner::<BitcoinCashContext>::default())
+    }
 }
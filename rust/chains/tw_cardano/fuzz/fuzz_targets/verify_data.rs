@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![no_main]
+
+use libfuzzer_sys::{arbitrary, fuzz_target};
+use tw_cardano::modules::message_signer::CardanoMessageSigner;
+
+/// A valid address, so the CBOR decoding of `signature` and `key` is reached.
+const ADDRESS: &str = "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23";
+
+#[derive(arbitrary::Arbitrary, Debug)]
+struct VerifyDataInput<'a> {
+    payload: &'a [u8],
+    /// CBOR encoded `COSE_Sign1`.
+    signature: &'a [u8],
+    /// CBOR encoded `COSE_Key`.
+    key: &'a [u8],
+}
+
+fuzz_target!(|input: VerifyDataInput<'_>| {
+    let _ = CardanoMessageSigner::verify_data(ADDRESS, input.payload, input.signature, input.key);
+});
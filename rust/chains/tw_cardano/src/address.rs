@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::bech32;
+use tw_memory::Data;
+
+/// Kind of the Shelley address, the high nibble of the header byte.
+/// https://cips.cardano.org/cip/CIP-0019
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AddressKind {
+    /// Payment and staking credentials.
+    Base,
+    /// Payment credential and a pointer to the stake registration certificate.
+    Pointer,
+    /// Payment credential only.
+    Enterprise,
+    /// Staking credential only.
+    Reward,
+}
+
+/// A Shelley era Cardano address encoded with bech32, e.g. `addr1...` or `stake1...`.
+/// Byron addresses are not supported.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CardanoAddress {
+    kind: AddressKind,
+    /// The header byte followed by the credentials.
+    bytes: Data,
+}
+
+impl CardanoAddress {
+    /// The length of a credential hash (blake2b-224).
+    pub const KEY_HASH_LEN: usize = 28;
+
+    const HRPS: [&'static str; 4] = ["addr", "addr_test", "stake", "stake_test"];
+
+    pub fn kind(&self) -> AddressKind {
+        self.kind
+    }
+
+    /// Returns the hash of the first credential:
+    /// the payment credential, or the staking credential of a reward address.
+    pub fn key_hash(&self) -> &[u8] {
+        &self.bytes[1..=Self::KEY_HASH_LEN]
+    }
+
+    /// Returns the raw address bytes, including the header byte.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl FromStr for CardanoAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let decoded = bech32::decode(s).map_err(|_| AddressError::FromBech32Error)?;
+        if !Self::HRPS.contains(&decoded.hrp.as_str()) {
+            return Err(AddressError::InvalidHrp);
+        }
+
+        let header = *decoded.bytes.first().ok_or(AddressError::InvalidInput)?;
+        let credentials_len = decoded.bytes.len() - 1;
+        let kind = match header >> 4 {
+            0..=3 if credentials_len == Self::KEY_HASH_LEN * 2 => AddressKind::Base,
+            4 | 5 if credentials_len > Self::KEY_HASH_LEN => AddressKind::Pointer,
+            6 | 7 if credentials_len == Self::KEY_HASH_LEN => AddressKind::Enterprise,
+            14 | 15 if credentials_len == Self::KEY_HASH_LEN => AddressKind::Reward,
+            _ => return Err(AddressError::InvalidInput),
+        };
+
+        Ok(CardanoAddress {
+            kind,
+            bytes: decoded.bytes,
+        })
+    }
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Cardano blockchain is implemented in C++.
+//! This crate contains the modules that are available through the chain-agnostic Rust API,
+//! e.g. CIP-8 message signing used by `TWMessageSigner`.
+
+pub mod address;
+pub mod modules;
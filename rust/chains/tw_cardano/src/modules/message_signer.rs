@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::{AddressKind, CardanoAddress};
+use ciborium::Value;
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::cbor;
+use tw_hash::blake2::blake2_b;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
+use tw_misc::try_or_false;
+use zeroize::Zeroizing;
+
+/// COSE header and key labels, see https://www.rfc-editor.org/rfc/rfc9052
+const HEADER_ALGORITHM: i64 = 1;
+const KEY_TYPE: i64 = 1;
+const KEY_ALGORITHM: i64 = 3;
+const KEY_CURVE: i64 = -1;
+const KEY_X: i64 = -2;
+const KEY_TYPE_OKP: i64 = 1;
+const CURVE_ED25519: i64 = 6;
+/// COSE algorithm identifier of EdDSA.
+const ALGORITHM_EDDSA: i64 = -8;
+const ADDRESS_HEADER: &str = "address";
+const HASHED_HEADER: &str = "hashed";
+const SIGNATURE_CONTEXT: &str = "Signature1";
+
+/// The length of the Cardano extended private key.
+const EXTENDED_KEY_LEN: usize = ed25519::cardano::ExtendedPrivateKey::LEN;
+const ED25519_PUBLIC_KEY_LEN: usize = 32;
+
+/// CIP-30 `DataSignature`.
+pub struct DataSignature {
+    /// CBOR encoded `COSE_Sign1`.
+    pub signature: Data,
+    /// CBOR encoded `COSE_Key` of the signing key.
+    pub key: Data,
+}
+
+/// CIP-8 message signer, as requested by dApps via CIP-30 `signData`.
+/// Exposed to hosts as `TWCardanoMessageSigner` and via `TWMessageSigner` with the `Cip8` scheme.
+/// https://cips.cardano.org/cip/CIP-0008 and https://cips.cardano.org/cip/CIP-0030
+pub struct CardanoMessageSigner;
+
+impl CardanoMessageSigner {
+    /// Signs the `payload` by the key the `address` belongs to:
+    /// the staking key for a reward address, the spending key otherwise.
+    ///
+    /// `private_key` is a Cardano extended private key (192 bytes).
+    pub fn sign_data(
+        private_key: &[u8],
+        address: &str,
+        payload: &[u8],
+    ) -> SigningResult<DataSignature> {
+        if private_key.len() != EXTENDED_KEY_LEN {
+            return SigningError::err(SigningErrorType::Error_invalid_private_key)
+                .context("Cardano extended private key is expected");
+        }
+        let address = CardanoAddress::from_str(address)
+            .into_tw()
+            .context("Invalid Cardano address")?;
+
+        let signing_key = if address.kind() == AddressKind::Reward {
+            // The staking key is the second half, padded with an empty key.
+            let half_len = EXTENDED_KEY_LEN / 2;
+            let mut staking_key = Zeroizing::new(private_key[half_len..].to_vec());
+            staking_key.resize(EXTENDED_KEY_LEN, 0);
+            ed25519::cardano::ExtendedPrivateKey::try_from(staking_key.as_slice())?
+        } else {
+            ed25519::cardano::ExtendedPrivateKey::try_from(private_key)?
+        };
+
+        let public_key = signing_key.public().to_vec();
+        let public_key = &public_key[..ED25519_PUBLIC_KEY_LEN];
+        if key_hash(public_key)? != address.key_hash() {
+            return SigningError::err(SigningErrorType::Error_invalid_address)
+                .context("Address doesn't belong to the private key");
+        }
+
+        let protected_header = encode_protected_header(address.as_bytes())?;
+        let sig_structure = Self::sig_structure(&protected_header, payload)?;
+        let signature = signing_key.sign(sig_structure)?;
+
+        let cose_sign1 = Value::Array(vec![
+            Value::Bytes(protected_header),
+            // The payload is signed as is.
+            Value::Map(vec![(Value::from(HASHED_HEADER), Value::Bool(false))]),
+            Value::Bytes(payload.to_vec()),
+            Value::Bytes(signature.to_vec()),
+        ]);
+
+        Ok(DataSignature {
+            signature: encode(&cose_sign1)?,
+            key: encode_cose_key(public_key)?,
+        })
+    }
+
+    /// Verifies a CIP-30 `DataSignature` over the `payload`, signed by the key the `address` belongs to.
+    /// Returns false on any invalid input.
+    pub fn verify_data(address: &str, payload: &[u8], signature: &[u8], key: &[u8]) -> bool {
+        let address = try_or_false!(CardanoAddress::from_str(address));
+
+        let key: Value = try_or_false!(cbor::decode(key));
+        let public_key = try_or_false!(find_map_value(&key, &Value::from(KEY_X)));
+        let public_key = try_or_false!(public_key.as_bytes());
+        if public_key.len() != ED25519_PUBLIC_KEY_LEN
+            || try_or_false!(key_hash(public_key)) != address.key_hash()
+        {
+            return false;
+        }
+
+        let cose_sign1: Value = try_or_false!(cbor::decode(signature));
+        let cose_sign1 = try_or_false!(cose_sign1.as_array());
+        let [protected_header, _unprotected_header, signed_payload, signature] =
+            cose_sign1.as_slice()
+        else {
+            return false;
+        };
+        let protected_header = try_or_false!(protected_header.as_bytes());
+        let header: Value = try_or_false!(cbor::decode(protected_header));
+        let signed_address = try_or_false!(find_map_value(&header, &Value::from(ADDRESS_HEADER)));
+        if try_or_false!(signed_address.as_bytes()).as_slice() != address.as_bytes()
+            || try_or_false!(signed_payload.as_bytes()).as_slice() != payload
+        {
+            return false;
+        }
+
+        let signature = try_or_false!(signature.as_bytes());
+        let signature = try_or_false!(ed25519::Signature::try_from(signature.as_slice()));
+        let public_key = try_or_false!(ed25519::sha512::PublicKey::try_from(public_key.as_slice()));
+        let sig_structure = try_or_false!(Self::sig_structure(protected_header, payload));
+        public_key.verify(signature, sig_structure)
+    }
+
+    /// Returns the CBOR encoded `Sig_structure` that is signed.
+    pub fn sig_structure(protected_header: &[u8], payload: &[u8]) -> SigningResult<Data> {
+        encode(&Value::Array(vec![
+            Value::from(SIGNATURE_CONTEXT),
+            Value::Bytes(protected_header.to_vec()),
+            // external_aad
+            Value::Bytes(Vec::new()),
+            Value::Bytes(payload.to_vec()),
+        ]))
+    }
+}
+
+/// Encodes the protected header, that binds the signature to the address.
+fn encode_protected_header(address: &[u8]) -> SigningResult<Data> {
+    encode(&Value::Map(vec![
+        (Value::from(HEADER_ALGORITHM), Value::from(ALGORITHM_EDDSA)),
+        (Value::from(ADDRESS_HEADER), Value::Bytes(address.to_vec())),
+    ]))
+}
+
+fn encode_cose_key(public_key: &[u8]) -> SigningResult<Data> {
+    encode(&Value::Map(vec![
+        (Value::from(KEY_TYPE), Value::from(KEY_TYPE_OKP)),
+        (Value::from(KEY_ALGORITHM), Value::from(ALGORITHM_EDDSA)),
+        (Value::from(KEY_CURVE), Value::from(CURVE_ED25519)),
+        (Value::from(KEY_X), Value::Bytes(public_key.to_vec())),
+    ]))
+}
+
+fn encode(value: &Value) -> SigningResult<Data> {
+    cbor::encode(value).tw_err(SigningErrorType::Error_internal)
+}
+
+/// Returns the value of the given `label` in a CBOR map.
+fn find_map_value<'a>(map: &'a Value, label: &Value) -> Option<&'a Value> {
+    map.as_map()?
+        .iter()
+        .find(|(key, _)| key == label)
+        .map(|(_, value)| value)
+}
+
+fn key_hash(public_key: &[u8]) -> SigningResult<Data> {
+    blake2_b(public_key, CardanoAddress::KEY_HASH_LEN).tw_err(SigningErrorType::Error_internal)
+}
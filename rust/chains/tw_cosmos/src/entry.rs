@@ -8,13 +8,14 @@ use tw_coin_entry::coin_entry::{CoinEntry, PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::modules::json_signer::NoJsonSigner;
-use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_planner::NoTransactionPlanner;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_cosmos_sdk::address::{Address, Bech32Prefix};
 use tw_cosmos_sdk::context::StandardCosmosContext;
 use tw_cosmos_sdk::modules::compiler::tw_compiler::TWTransactionCompiler;
+use tw_cosmos_sdk::modules::message_signer::Adr36MessageSigner;
 use tw_cosmos_sdk::modules::signer::tw_signer::TWSigner;
 use tw_cosmos_sdk::modules::transaction_util::CosmosTransactionUtil;
 use tw_keypair::tw;
@@ -33,10 +34,11 @@ impl CoinEntry for CosmosEntry {
     // Optional modules:
     type JsonSigner = NoJsonSigner;
     type PlanBuilder = NoPlanBuilder;
-    type MessageSigner = NoMessageSigner;
+    type MessageSigner = Adr36MessageSigner<StandardCosmosContext>;
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = CosmosTransactionUtil<StandardCosmosContext>;
+    type TransactionPlanner = NoTransactionPlanner;
 
     #[inline]
     fn parse_address(
@@ -94,6 +96,11 @@ impl CoinEntry for CosmosEntry {
         )
     }
 
+    #[inline]
+    fn message_signer(&self) -> Option<Self::MessageSigner> {
+        Some(Adr36MessageSigner::default())
+    }
+
     #[inline]
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(CosmosTransactionUtil::<StandardCosmosContext>::default())
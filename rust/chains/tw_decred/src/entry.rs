@@ -37,6 +37,7 @@ impl CoinEntry for DecredEntry {
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = NoTransactionUtil;
+    type TransactionPlanner = BitcoinPlanner<DecredContext>;
 
     #[inline]
     fn parse_address(
@@ -93,4 +94,9 @@ impl CoinEntry for DecredEntry {
     fn plan_builder(&self) -> Option<Self::PlanBuilder> {
         Some(BitcoinPlanner::<DecredContext>::default())
     }
+
+    #[inline]
+    fn transaction_planner(&self) -> Option<Self::TransactionPlanner> {
+        Some(BitcoinPlanner::<DecredContext>::default())
+    }
 }
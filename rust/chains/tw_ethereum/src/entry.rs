@@ -18,6 +18,7 @@ use tw_evm::evm_entry::EvmEntry;
 use tw_evm::modules::compiler::Compiler;
 use tw_evm::modules::message_signer::EthMessageSigner;
 use tw_evm::modules::signer::Signer;
+use tw_evm::modules::transaction_planner::EvmTransactionPlanner;
 use tw_evm::modules::transaction_util::EvmTransactionUtil;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Ethereum::Proto;
@@ -39,6 +40,7 @@ impl CoinEntry for EthereumEntry {
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = EvmTransactionUtil;
+    type TransactionPlanner = EvmTransactionPlanner<StandardEvmContext>;
 
     #[inline]
     fn parse_address(
@@ -103,6 +105,11 @@ impl CoinEntry for EthereumEntry {
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         Some(EvmTransactionUtil)
     }
+
+    #[inline]
+    fn transaction_planner(&self) -> Option<Self::TransactionPlanner> {
+        Some(EvmTransactionPlanner::<StandardEvmContext>::default())
+    }
 }
 
 impl EvmEntry for EthereumEntry {
@@ -7,7 +7,7 @@ use tw_cosmos_sdk::public_key::{
     CosmosPublicKey, JsonPublicKey, ProtobufPublicKey, PublicKeyParams,
 };
 use tw_keypair::ecdsa::secp256k1;
-use tw_keypair::{tw, KeyPairError, KeyPairResult};
+use tw_keypair::{tw, KeyPairError, KeyPairErrorKind, KeyPairResult};
 use tw_memory::Data;
 use tw_proto::{google, to_any};
 
@@ -38,7 +38,7 @@ impl CosmosPublicKey for GreenfieldPublicKey {
         let public_key = private_key
             .get_public_key_by_type(tw::PublicKeyType::Secp256k1)?
             .to_secp256k1()
-            .ok_or(KeyPairError::InvalidPublicKey)?
+            .ok_or(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?
             .clone();
         Ok(GreenfieldPublicKey(public_key))
     }
@@ -37,6 +37,7 @@ impl CoinEntry for GroestlcoinEntry {
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = BitcoinTransactionUtil;
+    type TransactionPlanner = BitcoinPlanner<GroestlContext>;
 
     #[inline]
     fn parse_address(
@@ -93,4 +94,9 @@ impl CoinEntry for GroestlcoinEntry {
     fn plan_builder(&self) -> Option<Self::PlanBuilder> {
         Some(BitcoinPlanner::<GroestlContext>::default())
     }
+
+    #[inline]
+    fn transaction_planner(&self) -> Option<Self::TransactionPlanner> {
+        Some(BitcoinPlanner::<GroestlContext>::default())
+    }
 }
@@ -5,6 +5,7 @@
 use std::str::FromStr;
 
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_planner::NoTransactionPlanner;
 use tw_coin_entry::modules::transaction_util::NoTransactionUtil;
 use tw_coin_entry::{
     coin_context::CoinContext,
@@ -40,6 +41,7 @@ impl CoinEntry for InternetComputerEntry {
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = NoTransactionUtil;
+    type TransactionPlanner = NoTransactionPlanner;
 
     #[inline]
     fn parse_address(
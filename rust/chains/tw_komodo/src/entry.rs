@@ -37,6 +37,7 @@ impl CoinEntry for KomodoEntry {
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = NoTransactionUtil;
+    type TransactionPlanner = BitcoinPlanner<KomodoContext>;
 
     #[inline]
     fn parse_address(
@@ -93,4 +94,9 @@ impl CoinEntry for KomodoEntry {
     fn plan_builder(&self) -> Option<Self::PlanBuilder> {
         Some(BitcoinPlanner::<KomodoContext>::default())
     }
+
+    #[inline]
+    fn transaction_planner(&self) -> Option<Self::TransactionPlanner> {
+        Some(BitcoinPlanner::<KomodoContext>::default())
+    }
 }
@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Blech32 is a Bech32 variant with a 12-character checksum used by Elements confidential segwit addresses.
+//! https://github.com/ElementsProject/elements/blob/master/src/blech32.cpp
+
+use tw_coin_entry::error::prelude::*;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u64; 5] = [
+    0x7d52fba40bd886,
+    0x5e8dbf1a03950c,
+    0x1c3a3c74072a18,
+    0x385d72fa0e5139,
+    0x7093e5a608865b,
+];
+const CHECKSUM_LEN: usize = 12;
+const SEPARATOR: char = '1';
+/// Maximum length of a Blech32 string.
+const MAX_LEN: usize = 1000;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Variant {
+    /// Used for witness v0 addresses.
+    Blech32,
+    /// Used for witness v1+ addresses.
+    Blech32m,
+}
+
+impl Variant {
+    fn constant(self) -> u64 {
+        match self {
+            Variant::Blech32 => 1,
+            Variant::Blech32m => 0x455972a3350f7a1,
+        }
+    }
+
+    fn from_constant(constant: u64) -> Option<Variant> {
+        [Variant::Blech32, Variant::Blech32m]
+            .into_iter()
+            .find(|variant| variant.constant() == constant)
+    }
+}
+
+/// Encodes the 5-bit `data` values with the given human-readable part.
+pub fn encode(hrp: &str, data: &[u8], variant: Variant) -> String {
+    let hrp = hrp.to_lowercase();
+
+    let mut values = expand_hrp(&hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0; CHECKSUM_LEN]);
+    let checksum = polymod(&values) ^ variant.constant();
+
+    let mut result = String::with_capacity(hrp.len() + 1 + data.len() + CHECKSUM_LEN);
+    result.push_str(&hrp);
+    result.push(SEPARATOR);
+    for value in data {
+        result.push(CHARSET[*value as usize] as char);
+    }
+    for i in 0..CHECKSUM_LEN {
+        let value = (checksum >> (5 * (CHECKSUM_LEN - 1 - i))) & 0x1f;
+        result.push(CHARSET[value as usize] as char);
+    }
+    result
+}
+
+/// Decodes a Blech32 string into the lowercase human-readable part and the 5-bit data values without the checksum.
+pub fn decode(s: &str) -> AddressResult<(String, Vec<u8>, Variant)> {
+    if s.len() > MAX_LEN {
+        return Err(AddressError::InvalidInput);
+    }
+    let has_lower = s.chars().any(|ch| ch.is_ascii_lowercase());
+    let has_upper = s.chars().any(|ch| ch.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return Err(AddressError::InvalidInput);
+    }
+
+    let s = s.to_lowercase();
+    let (hrp, data_part) = s.rsplit_once(SEPARATOR).ok_or(AddressError::InvalidInput)?;
+    if hrp.is_empty() || data_part.len() < CHECKSUM_LEN {
+        return Err(AddressError::InvalidInput);
+    }
+    if !hrp.bytes().all(|ch| (33..=126).contains(&ch)) {
+        return Err(AddressError::InvalidHrp);
+    }
+
+    let data = data_part
+        .bytes()
+        .map(|ch| {
+            CHARSET
+                .iter()
+                .position(|c| *c == ch)
+                .map(|value| value as u8)
+                .ok_or(AddressError::InvalidInput)
+        })
+        .collect::<AddressResult<Vec<_>>>()?;
+
+    let mut values = expand_hrp(hrp);
+    values.extend_from_slice(&data);
+    let variant = Variant::from_constant(polymod(&values)).ok_or(AddressError::InvalidChecksum)?;
+
+    let data_len = data.len() - CHECKSUM_LEN;
+    Ok((hrp.to_string(), data[..data_len].to_vec(), variant))
+}
+
+fn expand_hrp(hrp: &str) -> Vec<u8> {
+    let mut values = Vec::with_capacity(hrp.len() * 2 + 1 + CHECKSUM_LEN);
+    values.extend(hrp.bytes().map(|ch| ch >> 5));
+    values.push(0);
+    values.extend(hrp.bytes().map(|ch| ch & 0x1f));
+    values
+}
+
+fn polymod(values: &[u8]) -> u64 {
+    let mut chk: u64 = 1;
+    for value in values {
+        let top = chk >> 55;
+        chk = ((chk & 0x7fffffffffffff) << 5) ^ (*value as u64);
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blech32_decode_encode() {
+        let addr = "lq1qqvxk052kf3qtkxmrakx50a9gc3smqad2ync54hzntjt980kfej9kkfe0247rp5h4yzmdftsahhw64uy8pzfe7cpg4fgykm7cv";
+        let (hrp, data, variant) = decode(addr).unwrap();
+        assert_eq!(hrp, "lq");
+        assert_eq!(variant, Variant::Blech32);
+        assert_eq!(encode(&hrp, &data, variant), addr);
+
+        let (_, _, variant) = decode(&addr.to_uppercase()).unwrap();
+        assert_eq!(variant, Variant::Blech32);
+    }
+
+    #[test]
+    fn test_blech32_decode_invalid() {
+        // Invalid checksum.
+        let addr = "lq1qqvxk052kf3qtkxmrakx50a9gc3smqad2ync54hzntjt980kfej9kkfe0247rp5h4yzmdftsahhw64uy8pzfe7cpg4fgykm7cw";
+        assert_eq!(decode(addr), Err(AddressError::InvalidChecksum));
+
+        // Mixed case.
+        let addr = "lq1qqvxk052kf3qtkxmrakx50a9gc3smqad2ync54hzntjt980kfej9kkfe0247rp5h4yzmdftsahhw64uy8pzfe7cpg4fgykm7CV";
+        assert_eq!(decode(addr), Err(AddressError::InvalidInput));
+
+        // Bech32 address.
+        let addr = "ex1qyuh42lps6t6jpdk54cwmmhd27zrs3yulrc7t5a";
+        assert_eq!(decode(addr), Err(AddressError::InvalidChecksum));
+    }
+}
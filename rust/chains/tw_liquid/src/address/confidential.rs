@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::blech32;
+use crate::address::network::LiquidNetwork;
+use bech32::convert_bits;
+use std::fmt;
+use std::str::FromStr;
+use tw_base58_address::Base58Address;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::base58::Alphabet;
+use tw_hash::hasher::Sha256d;
+use tw_hash::{H160, H264};
+use tw_keypair::ecdsa::secp256k1;
+use tw_utxo::address::legacy::LegacyAddress;
+use tw_utxo::address::segwit::SegwitAddress;
+use tw_utxo::address::standard_bitcoin::StandardBitcoinAddress;
+use tw_utxo::address::taproot::{TaprootAddress, WITNESS_V1};
+use tw_utxo::address::witness_program::WITNESS_V0;
+
+/// `confidential_prefix || version || blinding_pubkey || hash`.
+const BASE58_CONFIDENTIAL_SIZE: usize = 2 + H264::LEN + H160::LEN;
+const BASE58_CHECKSUM_SIZE: usize = 4;
+
+type Base58ConfidentialAddress =
+    Base58Address<BASE58_CONFIDENTIAL_SIZE, BASE58_CHECKSUM_SIZE, Sha256d>;
+
+/// Elements confidential address: an unconfidential address extended with the blinding public key,
+/// which the sender uses to blind the output amount and asset.
+/// https://github.com/ElementsProject/elements/blob/master/src/key_io.cpp
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfidentialAddress {
+    blinding_pubkey: H264,
+    unconfidential: StandardBitcoinAddress,
+}
+
+impl ConfidentialAddress {
+    pub fn new(
+        blinding_pubkey: &secp256k1::PublicKey,
+        unconfidential: StandardBitcoinAddress,
+    ) -> AddressResult<ConfidentialAddress> {
+        LiquidNetwork::for_address(&unconfidential).ok_or(AddressError::UnexpectedAddressPrefix)?;
+        Ok(ConfidentialAddress {
+            blinding_pubkey: blinding_pubkey.compressed(),
+            unconfidential,
+        })
+    }
+
+    pub fn blinding_pubkey(&self) -> secp256k1::PublicKey {
+        secp256k1::PublicKey::try_from(self.blinding_pubkey.as_slice())
+            .expect("Blinding public key is validated on construction")
+    }
+
+    pub fn unconfidential(&self) -> &StandardBitcoinAddress {
+        &self.unconfidential
+    }
+
+    pub fn network(&self) -> &'static LiquidNetwork {
+        LiquidNetwork::for_address(&self.unconfidential)
+            .expect("Address network is validated on construction")
+    }
+
+    fn with_blinding_pubkey_bytes(
+        blinding_pubkey: &[u8],
+        unconfidential: StandardBitcoinAddress,
+    ) -> AddressResult<ConfidentialAddress> {
+        let blinding_pubkey = secp256k1::PublicKey::try_from(blinding_pubkey)
+            .map_err(|_| AddressError::InvalidInput)?;
+        ConfidentialAddress::new(&blinding_pubkey, unconfidential)
+    }
+
+    fn from_blech32(s: &str) -> AddressResult<ConfidentialAddress> {
+        let (hrp, data, variant) = blech32::decode(s)?;
+        let network = LiquidNetwork::by_blech32_hrp(&hrp).ok_or(AddressError::InvalidHrp)?;
+
+        let (version, program) = data.split_first().ok_or(AddressError::InvalidInput)?;
+        let payload =
+            convert_bits(program, 5, 8, false).map_err(|_| AddressError::InvalidWitnessProgram)?;
+        if payload.len() <= H264::LEN {
+            return Err(AddressError::InvalidWitnessProgram);
+        }
+        let (blinding_pubkey, witness_program) = payload.split_at(H264::LEN);
+
+        let hrp = network.hrp.to_string();
+        let unconfidential = match (*version, variant) {
+            (WITNESS_V0, blech32::Variant::Blech32) => {
+                SegwitAddress::new(hrp, witness_program.to_vec())
+                    .map(StandardBitcoinAddress::Segwit)?
+            },
+            (WITNESS_V1, blech32::Variant::Blech32m) => {
+                TaprootAddress::new(hrp, witness_program.to_vec())
+                    .map(StandardBitcoinAddress::Taproot)?
+            },
+            _ => return Err(AddressError::InvalidWitnessProgram),
+        };
+        ConfidentialAddress::with_blinding_pubkey_bytes(blinding_pubkey, unconfidential)
+    }
+
+    fn from_base58(s: &str) -> AddressResult<ConfidentialAddress> {
+        let base58 = Base58ConfidentialAddress::from_str_with_alphabet(s, Alphabet::Bitcoin)?;
+
+        let (prefixes, rest) = base58.bytes.split_at(2);
+        let (blinding_pubkey, hash) = rest.split_at(H264::LEN);
+        let (confidential_prefix, version) = (prefixes[0], prefixes[1]);
+
+        let network = LiquidNetwork::by_confidential_prefix(confidential_prefix)
+            .ok_or(AddressError::UnexpectedAddressPrefix)?;
+        if version != network.p2pkh_prefix && version != network.p2sh_prefix {
+            return Err(AddressError::UnexpectedAddressPrefix);
+        }
+
+        let unconfidential =
+            LegacyAddress::new(version, hash).map(StandardBitcoinAddress::Legacy)?;
+        ConfidentialAddress::with_blinding_pubkey_bytes(blinding_pubkey, unconfidential)
+    }
+}
+
+impl FromStr for ConfidentialAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(blech32) = ConfidentialAddress::from_blech32(s) {
+            return Ok(blech32);
+        }
+        ConfidentialAddress::from_base58(s)
+    }
+}
+
+impl fmt::Display for ConfidentialAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let network = self.network();
+
+        let (version, variant, witness_program) = match self.unconfidential {
+            StandardBitcoinAddress::Legacy(ref legacy) => {
+                let mut bytes = Vec::with_capacity(BASE58_CONFIDENTIAL_SIZE);
+                bytes.push(network.confidential_prefix);
+                bytes.push(legacy.prefix());
+                bytes.extend_from_slice(self.blinding_pubkey.as_slice());
+                bytes.extend_from_slice(legacy.payload().as_slice());
+
+                let base58 = Base58ConfidentialAddress::new(&bytes, Alphabet::Bitcoin)
+                    .expect("Confidential address has a fixed size");
+                return write!(f, "{base58}");
+            },
+            StandardBitcoinAddress::Segwit(ref segwit) => (
+                WITNESS_V0,
+                blech32::Variant::Blech32,
+                segwit.witness_program(),
+            ),
+            StandardBitcoinAddress::Taproot(ref taproot) => (
+                WITNESS_V1,
+                blech32::Variant::Blech32m,
+                taproot.witness_program(),
+            ),
+        };
+
+        let mut payload = self.blinding_pubkey.to_vec();
+        payload.extend_from_slice(witness_program);
+
+        let mut data = vec![version];
+        data.extend(convert_bits(&payload, 8, 5, true).map_err(|_| fmt::Error)?);
+        write!(
+            f,
+            "{}",
+            blech32::encode(network.blech32_hrp, &data, variant)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_roundtrip(s: &str, blinding_pubkey: &str, unconfidential: &str) {
+        let addr = ConfidentialAddress::from_str(s).unwrap();
+        assert_eq!(
+            addr.blinding_pubkey().compressed(),
+            H264::from_str(blinding_pubkey).unwrap()
+        );
+        assert_eq!(addr.unconfidential().to_string(), unconfidential);
+        assert_eq!(addr.to_string(), s);
+
+        let unconfidential = StandardBitcoinAddress::from_str(unconfidential).unwrap();
+        let expected = ConfidentialAddress::new(&addr.blinding_pubkey(), unconfidential).unwrap();
+        assert_eq!(addr, expected);
+    }
+
+    #[test]
+    fn test_confidential_address_blech32() {
+        test_roundtrip(
+            "lq1qqvxk052kf3qtkxmrakx50a9gc3smqad2ync54hzntjt980kfej9kkfe0247rp5h4yzmdftsahhw64uy8pzfe7cpg4fgykm7cv",
+            "030d67d1564c40bb1b63ed8d47f4a8c461b075aa24f14adc535c9653bec9cc8b6b",
+            "ex1qyuh42lps6t6jpdk54cwmmhd27zrs3yulrc7t5a",
+        );
+    }
+
+    #[test]
+    fn test_confidential_address_base58() {
+        test_roundtrip(
+            "VTpvKKc1SNmLG4H8CnR1fGJdHdyWGEQEvdP9gfeneJR7n81S5kiwNtgF7vrZjC8mp63HvwxM81nEbTxU",
+            "025476c2e83188368da1ff3e292e7acafcdb3566bb0ad253f62fc70f07aeee6357",
+            "Q7wegLt2qMGhm28vch6VTzvpzs8KXvs4X7",
+        );
+        test_roundtrip(
+            "VJLCbLBTCdxhWyjVLdjcSmGAksVMtabYg15maSi93zknQD2ihC38R7CUd8KbDFnV8A4hiykxnRB3Uv6d",
+            "03041892270e1179954deb2480f1c08925b76b9094b9304651f641b4856293c4f8",
+            "GjzwdM3QQBLizDdJPzFRaNjfufkMdbzBvr",
+        );
+    }
+
+    #[test]
+    fn test_confidential_address_invalid() {
+        // Unconfidential address.
+        ConfidentialAddress::from_str("ex1qyuh42lps6t6jpdk54cwmmhd27zrs3yulrc7t5a").unwrap_err();
+        // Bitcoin `bc` segwit address cannot be confidential.
+        let bitcoin =
+            StandardBitcoinAddress::from_str("bc1qazgc2zhu2kmy42py0vs8d7yff67l3zgpwfzlpk").unwrap();
+        let blinding_pubkey = secp256k1::PublicKey::try_from(
+            "030d67d1564c40bb1b63ed8d47f4a8c461b075aa24f14adc535c9653bec9cc8b6b",
+        )
+        .unwrap();
+        ConfidentialAddress::new(&blinding_pubkey, bitcoin).unwrap_err();
+    }
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::confidential::ConfidentialAddress;
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::prelude::*;
+use tw_memory::Data;
+use tw_utxo::address::standard_bitcoin::{StandardBitcoinAddress, StandardBitcoinPrefix};
+
+pub mod blech32;
+pub mod confidential;
+pub mod network;
+
+/// Liquid address is either a standard Bitcoin-like unconfidential address,
+/// or a confidential address that commits to a blinding public key in addition.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LiquidAddress {
+    Unconfidential(StandardBitcoinAddress),
+    Confidential(ConfidentialAddress),
+}
+
+impl LiquidAddress {
+    /// Tries to parse a confidential or unconfidential address
+    /// and validates if the result address matches the given `prefix` address or belongs to the `coin` network.
+    /// The `prefix` is checked against the unconfidential part of a confidential address.
+    pub fn from_str_with_coin_and_prefix(
+        coin: &dyn CoinContext,
+        s: &str,
+        prefix: Option<StandardBitcoinPrefix>,
+    ) -> AddressResult<LiquidAddress> {
+        if let Ok(confidential) = ConfidentialAddress::from_str(s) {
+            let unconfidential = confidential.unconfidential().to_string();
+            StandardBitcoinAddress::from_str_with_coin_and_prefix(coin, &unconfidential, prefix)?;
+            return Ok(LiquidAddress::Confidential(confidential));
+        }
+
+        StandardBitcoinAddress::from_str_with_coin_and_prefix(coin, s, prefix)
+            .map(LiquidAddress::Unconfidential)
+    }
+
+    /// Returns the unconfidential part of the address.
+    pub fn unconfidential(&self) -> &StandardBitcoinAddress {
+        match self {
+            LiquidAddress::Unconfidential(unconfidential) => unconfidential,
+            LiquidAddress::Confidential(confidential) => confidential.unconfidential(),
+        }
+    }
+}
+
+impl FromStr for LiquidAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(confidential) = ConfidentialAddress::from_str(s) {
+            return Ok(LiquidAddress::Confidential(confidential));
+        }
+        StandardBitcoinAddress::from_str(s).map(LiquidAddress::Unconfidential)
+    }
+}
+
+impl fmt::Display for LiquidAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LiquidAddress::Unconfidential(unconfidential) => write!(f, "{unconfidential}"),
+            LiquidAddress::Confidential(confidential) => write!(f, "{confidential}"),
+        }
+    }
+}
+
+impl CoinAddress for LiquidAddress {
+    #[inline]
+    fn data(&self) -> Data {
+        self.unconfidential().data()
+    }
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_utxo::address::standard_bitcoin::StandardBitcoinAddress;
+use tw_utxo::context::AddressPrefixes;
+
+/// Address parameters of an Elements network.
+/// https://github.com/ElementsProject/elements/blob/master/src/chainparams.cpp
+#[derive(Debug, Eq, PartialEq)]
+pub struct LiquidNetwork {
+    pub p2pkh_prefix: u8,
+    pub p2sh_prefix: u8,
+    /// Human-readable part of unconfidential segwit addresses.
+    pub hrp: &'static str,
+    /// Base58 prefix of confidential P2PKH and P2SH addresses.
+    pub confidential_prefix: u8,
+    /// Human-readable part of confidential segwit addresses.
+    pub blech32_hrp: &'static str,
+}
+
+pub const LIQUID_MAINNET: LiquidNetwork = LiquidNetwork {
+    p2pkh_prefix: 57,
+    p2sh_prefix: 39,
+    hrp: "ex",
+    confidential_prefix: 12,
+    blech32_hrp: "lq",
+};
+
+pub const LIQUID_TESTNET: LiquidNetwork = LiquidNetwork {
+    p2pkh_prefix: 36,
+    p2sh_prefix: 19,
+    hrp: "tex",
+    confidential_prefix: 23,
+    blech32_hrp: "tlq",
+};
+
+pub const ELEMENTS_REGTEST: LiquidNetwork = LiquidNetwork {
+    p2pkh_prefix: 235,
+    p2sh_prefix: 75,
+    hrp: "ert",
+    confidential_prefix: 4,
+    blech32_hrp: "el",
+};
+
+const NETWORKS: [&LiquidNetwork; 3] = [&LIQUID_MAINNET, &LIQUID_TESTNET, &ELEMENTS_REGTEST];
+
+impl LiquidNetwork {
+    /// Returns the network the given unconfidential address belongs to.
+    pub fn for_address(addr: &StandardBitcoinAddress) -> Option<&'static LiquidNetwork> {
+        match addr {
+            StandardBitcoinAddress::Legacy(legacy) => Self::find(|network| {
+                network.p2pkh_prefix == legacy.prefix() || network.p2sh_prefix == legacy.prefix()
+            }),
+            StandardBitcoinAddress::Segwit(segwit) => {
+                Self::find(|network| network.hrp == segwit.hrp())
+            },
+            StandardBitcoinAddress::Taproot(taproot) => {
+                Self::find(|network| network.hrp == taproot.hrp())
+            },
+        }
+    }
+
+    pub fn by_confidential_prefix(prefix: u8) -> Option<&'static LiquidNetwork> {
+        Self::find(|network| network.confidential_prefix == prefix)
+    }
+
+    pub fn by_blech32_hrp(hrp: &str) -> Option<&'static LiquidNetwork> {
+        Self::find(|network| network.blech32_hrp == hrp)
+    }
+
+    pub fn address_prefixes(&self) -> AddressPrefixes {
+        AddressPrefixes {
+            p2pkh_prefix: self.p2pkh_prefix,
+            p2sh_prefix: self.p2sh_prefix,
+        }
+    }
+
+    fn find<F>(f: F) -> Option<&'static LiquidNetwork>
+    where
+        F: Fn(&LiquidNetwork) -> bool,
+    {
+        NETWORKS.into_iter().find(|network| f(network))
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! SLIP-77 deterministic blinding key derivation.
+//! https://github.com/satoshilabs/slips/blob/master/slip-0077.md
+
+use crate::address::confidential::ConfidentialAddress;
+use crate::address::network::LiquidNetwork;
+use std::fmt;
+use tw_bitcoin::context::StandardBitcoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::hmac::{hmac_sha256, hmac_sha512};
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::redact::SecretFingerprint;
+use tw_utxo::address::standard_bitcoin::StandardBitcoinAddress;
+use tw_utxo::context::UtxoContext;
+use tw_utxo::script::Script;
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+const SLIP77_SEED_KEY: &[u8] = b"Symmetric key seed";
+const SLIP77_LABEL: &[u8] = b"\x00SLIP-0077";
+
+/// SLIP-77 master blinding key.
+/// Blinding keys are derived from the master key and an output `scriptPubkey`,
+/// so they can be restored from the wallet seed alone.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct MasterBlindingKey {
+    key: H256,
+}
+
+impl MasterBlindingKey {
+    pub fn new(key: H256) -> MasterBlindingKey {
+        MasterBlindingKey { key }
+    }
+
+    /// Derives the master blinding key from a BIP-39 seed.
+    pub fn from_seed(seed: &[u8]) -> MasterBlindingKey {
+        let root = Zeroizing::new(hmac_sha512(SLIP77_SEED_KEY, seed));
+        let node = Zeroizing::new(hmac_sha512(&root[..H256::LEN], SLIP77_LABEL));
+
+        let key = H256::try_from(&node[H256::LEN..]).expect("HMAC-SHA512 returns 64 bytes");
+        MasterBlindingKey { key }
+    }
+
+    /// Derives the blinding private key of the `script_pubkey` output.
+    pub fn blinding_private_key(
+        &self,
+        script_pubkey: &Script,
+    ) -> SigningResult<secp256k1::PrivateKey> {
+        let secret = Zeroizing::new(hmac_sha256(self.key.as_slice(), script_pubkey.as_slice()));
+        secp256k1::PrivateKey::try_from(secret.as_slice())
+            .into_tw()
+            .context("Invalid blinding private key")
+    }
+
+    /// Derives the blinding public key of the `script_pubkey` output.
+    pub fn blinding_public_key(
+        &self,
+        script_pubkey: &Script,
+    ) -> SigningResult<secp256k1::PublicKey> {
+        self.blinding_private_key(script_pubkey)
+            .map(|private| private.public())
+    }
+
+    /// Derives a confidential address from the `unconfidential` address.
+    pub fn confidential_address(
+        &self,
+        unconfidential: StandardBitcoinAddress,
+    ) -> SigningResult<ConfidentialAddress> {
+        let network = LiquidNetwork::for_address(&unconfidential)
+            .or_tw_err(SigningErrorType::Error_invalid_address)
+            .context("The address does not belong to any of Liquid networks")?;
+        let script_pubkey = StandardBitcoinContext::addr_to_script_pubkey(
+            &unconfidential,
+            network.address_prefixes(),
+        )?;
+
+        let blinding_pubkey = self.blinding_public_key(&script_pubkey)?;
+        ConfidentialAddress::new(&blinding_pubkey, unconfidential).into_tw()
+    }
+}
+
+impl fmt::Debug for MasterBlindingKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MasterBlindingKey")
+            .field("fingerprint", &SecretFingerprint::new(self.key.as_slice()))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use tw_encoding::hex::DecodeHex;
+
+    /// Seed of the `all all all all all all all all all all all all` mnemonic.
+    const ALL_SEED: &str = "c76c4ac4f4e4a00d6b274d5c39c700bb4a7ddc04fbc6f78e85ca75007b5b495f74a9043eeb77bdd53aa6fc3a0e31462270316fa04b8c19114c8798706cd02ac8";
+
+    #[test]
+    fn test_slip77_master_blinding_key() {
+        let master = MasterBlindingKey::from_seed(&ALL_SEED.decode_hex().unwrap());
+        assert_eq!(
+            master.key,
+            H256::from("6c2de18eabeff3f7822bc724ad482bef0557f3e1c1e1c75b7a393a5ced4de616")
+        );
+    }
+
+    #[test]
+    fn test_slip77_confidential_address() {
+        let master = MasterBlindingKey::from_seed(&ALL_SEED.decode_hex().unwrap());
+
+        let unconfidential =
+            StandardBitcoinAddress::from_str("ex1quvgm34kal7zke68f5nsrh3k5leg9p2paq6zsap").unwrap();
+        let confidential = master.confidential_address(unconfidential).unwrap();
+        assert_eq!(
+            confidential.to_string(),
+            "lq1qqgt2h6r8zdwx90me5y4jm2cv3wlhz0m8lzayrxs3qvlgz7ua60wymcc3hrtdmlu9dn5wnf8q80rdfljs2z5r6k50x405j3cqq"
+        );
+
+        let unconfidential =
+            StandardBitcoinAddress::from_str("QHy1k5pdQpmidvQ1Angzb54jXzpuZXYkVe").unwrap();
+        let confidential = master.confidential_address(unconfidential).unwrap();
+        assert_eq!(
+            confidential.to_string(),
+            "VTpu1uzXHX4ddLqns2wecYWiKDTSZ33TJm4gCXq5jQUrbbpjk4cybEPAP7HXVJ4mzopEZubKUvinZsHa"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::LiquidAddress;
+use crate::modules::protobuf_builder::LiquidProtobufBuilder;
+use crate::modules::signing_request::LiquidSigningRequestBuilder;
+use crate::transaction::LiquidTransaction;
+use tw_bitcoin::context::{BitcoinSigningContext, StandardBitcoinContext};
+use tw_bitcoin::modules::psbt_request::NoPsbtRequestBuilder;
+use tw_coin_entry::error::prelude::*;
+use tw_utxo::context::{AddressPrefixes, UtxoContext};
+use tw_utxo::fee::fee_estimator::StandardFeeEstimator;
+use tw_utxo::script::Script;
+
+#[derive(Default)]
+pub struct LiquidContext;
+
+impl UtxoContext for LiquidContext {
+    type Address = LiquidAddress;
+    type Transaction = LiquidTransaction;
+    type FeeEstimator = StandardFeeEstimator<LiquidTransaction>;
+
+    fn addr_to_script_pubkey(
+        addr: &Self::Address,
+        prefixes: AddressPrefixes,
+    ) -> SigningResult<Script> {
+        match addr {
+            LiquidAddress::Unconfidential(unconfidential) => {
+                StandardBitcoinContext::addr_to_script_pubkey(unconfidential, prefixes)
+            },
+            LiquidAddress::Confidential(_) => {
+                SigningError::err(SigningErrorType::Error_not_supported).context(
+                    "Confidential outputs are not supported yet, use the unconfidential address instead",
+                )
+            },
+        }
+    }
+}
+
+impl BitcoinSigningContext for LiquidContext {
+    type SigningRequestBuilder = LiquidSigningRequestBuilder;
+    type ProtobufBuilder = LiquidProtobufBuilder;
+    type PsbtRequestBuilder = NoPsbtRequestBuilder;
+}
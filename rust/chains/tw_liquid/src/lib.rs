@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! The `tw_liquid` crate provides an implementation of the Liquid (Elements) blockchain in Rust.
+//!
+//! This crate reuses Bitcoin's transaction Signer, compiler and planner APIs,
+//! while Liquid introduces a different transaction structure, pre-image hashing and confidential addresses.
+//!
+//! ## Key Features
+//!
+//! - **Confidential addresses**: Parses and formats Base58 and Blech32 confidential addresses.
+//! - **Blinding keys**: Derives SLIP-77 blinding keys to generate confidential addresses.
+//! - **Transaction Signer**: Signs transactions with explicit (unblinded) inputs and outputs
+//!   spending P2WPKH and P2WSH outputs.
+//! - **Fee calculation**: Utilizes Bitcoin's transaction fee estimation and UTXO selection.
+//!   The fee is paid to an explicit fee output.
+//!
+//! Blinding confidential outputs is not supported yet, so the outputs must be sent to unconfidential addresses.
+
+pub mod address;
+pub mod blinding;
+pub mod context;
+pub mod entry;
+pub mod modules;
+pub mod transaction;
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::{LiquidTransaction, LiquidTransactionOutput};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::hasher::StatefulHasher;
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_utxo::encode::stream::Stream;
+use tw_utxo::sighash::SighashBase;
+use tw_utxo::transaction::transaction_hashing::TransactionHasher;
+use tw_utxo::transaction::transaction_interface::TxInputInterface;
+use tw_utxo::transaction::UtxoPreimageArgs;
+
+/// Explicit value prefix.
+const EXPLICIT_VALUE_PREFIX: u8 = 0x01;
+/// Encoding of a null asset issuance.
+const NULL_ASSET_ISSUANCE: u8 = 0x00;
+
+/// `LiquidSighash` is used to calculate a preimage hash of a P2WPKH or P2WSH unspent output.
+/// It differs from the BIP-143 Bitcoin sighash by the `hashIssuance` field, the explicit input value encoding,
+/// and the outputs hash that commits to the fee output.
+/// https://github.com/ElementsProject/elements/blob/master/src/script/interpreter.cpp
+pub struct LiquidSighash;
+
+impl LiquidSighash {
+    pub fn sighash_tx(tx: &LiquidTransaction, args: &UtxoPreimageArgs) -> SigningResult<H256> {
+        let input = tx
+            .inputs
+            .get(args.input_index)
+            .or_tw_err(SigningErrorType::Error_internal)
+            .context("Liquid sighash error: input_index is out of bounds")?;
+
+        let prevout_hash =
+            TransactionHasher::preimage_prevout_hash(tx, args.sighash_ty, args.tx_hasher);
+        let sequence_hash =
+            TransactionHasher::preimage_sequence_hash(tx, args.sighash_ty, args.tx_hasher);
+        let issuance_hash = Self::preimage_issuance_hash(tx, args);
+        let outputs_hash = Self::preimage_outputs_hash(tx, args)?;
+
+        let mut stream = Stream::default();
+        stream
+            .append(&tx.version)
+            .append_raw_slice(&prevout_hash)
+            .append_raw_slice(&sequence_hash)
+            .append_raw_slice(&issuance_hash);
+
+        stream
+            .append(input.previous_output())
+            .append(&args.script_pubkey)
+            .append(&EXPLICIT_VALUE_PREFIX)
+            // Explicit values are encoded in big-endian.
+            .append_raw_slice(&(args.amount as u64).to_be_bytes())
+            .append(&input.sequence())
+            .append_raw_slice(&outputs_hash)
+            .append(&tx.locktime)
+            .append(&args.sighash_ty.raw_sighash());
+
+        let hash = args.tx_hasher.hash(&stream.out());
+        H256::try_from(hash.as_slice())
+            .tw_err(SigningErrorType::Error_internal)
+            .context("Liquid sighash must be H256")
+    }
+
+    /// Returns a zero hash if [`UtxoPreimageArgs::sighash_ty`] is `ANYONECANPAY`,
+    /// otherwise returns a hash of the inputs asset issuances, which are always null.
+    fn preimage_issuance_hash(tx: &LiquidTransaction, args: &UtxoPreimageArgs) -> Data {
+        if args.sighash_ty.anyone_can_pay() {
+            return args.tx_hasher.zero_hash();
+        }
+
+        let mut stream = Stream::default();
+        for _ in tx.inputs.iter() {
+            stream.append(&NULL_ASSET_ISSUANCE);
+        }
+        args.tx_hasher.hash(&stream.out())
+    }
+
+    /// Returns a hash of the required outputs including the fee output according to the [`UtxoPreimageArgs::sighash_ty`].
+    fn preimage_outputs_hash(
+        tx: &LiquidTransaction,
+        args: &UtxoPreimageArgs,
+    ) -> SigningResult<Data> {
+        let outputs = tx.outputs_with_fee()?;
+        let hash_outputs = |outputs: &[LiquidTransactionOutput]| {
+            let mut stream = Stream::default();
+            for output in outputs {
+                stream.append(output);
+            }
+            args.tx_hasher.hash(&stream.out())
+        };
+
+        let hash = match args.sighash_ty.base_type() {
+            SighashBase::All => hash_outputs(&outputs),
+            SighashBase::Single if args.input_index < outputs.len() => {
+                hash_outputs(&outputs[args.input_index..=args.input_index])
+            },
+            _ => args.tx_hasher.zero_hash(),
+        };
+        Ok(hash)
+    }
+}
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+pub mod liquid_sighash;
+pub mod protobuf_builder;
+pub mod signing_request;
+pub mod transaction_builder;
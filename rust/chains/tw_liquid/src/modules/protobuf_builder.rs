@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::context::LiquidContext;
+use crate::transaction::{LiquidTransaction, LiquidTransactionInput, LiquidTransactionOutput};
+use std::borrow::Cow;
+use tw_bitcoin::modules::protobuf_builder::standard_protobuf_builder::StandardProtobufBuilder;
+use tw_bitcoin::modules::protobuf_builder::{ProtobufBuilder, ProtobufTransaction};
+use tw_proto::Liquid::Proto as LiquidProto;
+use tw_proto::Utxo::Proto as UtxoProto;
+
+pub struct LiquidProtobufBuilder;
+
+impl LiquidProtobufBuilder {
+    pub fn tx_input_to_proto(
+        input: &LiquidTransactionInput,
+    ) -> UtxoProto::TransactionInput<'static> {
+        UtxoProto::TransactionInput {
+            out_point: Some(StandardProtobufBuilder::out_point_to_proto(
+                &input.previous_output,
+            )),
+            sequence: input.sequence,
+            script_sig: StandardProtobufBuilder::script_data(&input.script_sig),
+            witness_items: StandardProtobufBuilder::witness_to_proto(&input.witness),
+        }
+    }
+
+    pub fn tx_output_to_proto(
+        output: &LiquidTransactionOutput,
+    ) -> LiquidProto::TransactionOutput<'static> {
+        LiquidProto::TransactionOutput {
+            asset: Cow::from(output.asset.to_vec()),
+            value: output.value,
+            script_pubkey: StandardProtobufBuilder::script_data(&output.script_pubkey),
+        }
+    }
+}
+
+impl ProtobufBuilder<LiquidContext> for LiquidProtobufBuilder {
+    fn tx_to_proto(tx: &LiquidTransaction) -> ProtobufTransaction<'static> {
+        let inputs = tx.inputs.iter().map(Self::tx_input_to_proto).collect();
+        // Include the fee output, so the transaction can be reconstructed from the Protobuf.
+        // The balance has been checked on signing already.
+        let outputs = tx
+            .outputs_with_fee_unchecked()
+            .iter()
+            .map(Self::tx_output_to_proto)
+            .collect();
+
+        ProtobufTransaction::liquid(LiquidProto::Transaction {
+            version: tx.version,
+            inputs,
+            outputs,
+            lock_time: tx.locktime,
+        })
+    }
+}
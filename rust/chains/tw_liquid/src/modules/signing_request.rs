@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::context::LiquidContext;
+use crate::modules::transaction_builder::{LiquidOutputBuilder, LiquidTransactionBuilder};
+use crate::transaction::{LIQUID_POLICY_ASSET, TRANSACTION_VERSION_2};
+use std::str::FromStr;
+use tw_bitcoin::modules::signing_request::standard_signing_request::{
+    chain_info, StandardSigningRequestBuilder,
+};
+use tw_bitcoin::modules::signing_request::SigningRequestBuilder;
+use tw_bitcoin::modules::tx_builder::output_protobuf::OutputProtobuf;
+use tw_bitcoin::modules::tx_builder::utxo_protobuf::UtxoProtobuf;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H256;
+use tw_proto::BitcoinV2::Proto::{SigningInput, TransactionBuilder};
+use tw_utxo::modules::tx_planner::{PlanRequest, RequestType};
+
+pub struct LiquidSigningRequestBuilder;
+
+impl SigningRequestBuilder<LiquidContext> for LiquidSigningRequestBuilder {
+    fn build(
+        coin: &dyn CoinContext,
+        input: &SigningInput,
+        transaction_builder: &TransactionBuilder,
+    ) -> SigningResult<PlanRequest<LiquidContext>> {
+        let chain_info = chain_info(coin, &input.chain_info)?;
+        let dust_policy =
+            StandardSigningRequestBuilder::dust_policy(&transaction_builder.dust_policy)?;
+        let fee_estimator = StandardSigningRequestBuilder::fee_estimator(transaction_builder)?;
+        let version = StandardSigningRequestBuilder::transaction_version(
+            &transaction_builder.version,
+            TRANSACTION_VERSION_2,
+        );
+        let asset = Self::asset(transaction_builder)?;
+
+        let public_keys = StandardSigningRequestBuilder::get_public_keys::<LiquidContext>(input)?;
+
+        let mut builder = LiquidTransactionBuilder::new();
+        builder
+            .version(version)
+            .lock_time(transaction_builder.lock_time)
+            .asset(asset)?;
+
+        // Parse all UTXOs.
+        for utxo_proto in transaction_builder.inputs.iter() {
+            let utxo_builder =
+                UtxoProtobuf::<LiquidContext>::new(&chain_info, utxo_proto, &public_keys);
+            let (utxo, utxo_args) = utxo_builder
+                .utxo_from_proto()
+                .context("Error creating UTXO from Protobuf")?;
+            builder.push_standard_input(utxo, utxo_args)?;
+        }
+
+        // If `max_amount_output` is set, construct a transaction with only one output.
+        if let Some(max_output_proto) = transaction_builder.max_amount_output.as_ref() {
+            let output_builder =
+                OutputProtobuf::<LiquidContext>::new(&chain_info, max_output_proto);
+
+            let max_output = output_builder
+                .output_from_proto()
+                .context("Error creating Max Output from Protobuf")?;
+            builder.push_standard_output(max_output);
+
+            let unsigned_tx = builder.build()?;
+            return Ok(PlanRequest {
+                ty: RequestType::SendMax { unsigned_tx },
+                dust_policy,
+                fee_estimator,
+            });
+        }
+
+        // `max_amount_output` isn't set, parse all Outputs.
+        for output_proto in transaction_builder.outputs.iter() {
+            let output = OutputProtobuf::<LiquidContext>::new(&chain_info, output_proto)
+                .output_from_proto()
+                .context("Error creating Output from Proto")?;
+            builder.push_standard_output(output);
+        }
+
+        // Parse change output if it was provided.
+        let change_output = transaction_builder
+            .change_output
+            .as_ref()
+            .map(|change_output_proto| {
+                OutputProtobuf::<LiquidContext>::new(&chain_info, change_output_proto)
+                    .output_from_proto()
+                    .map(|out| LiquidOutputBuilder::from_standard_output(out, asset).build())
+                    .context("Error creating Change Output from Proto")
+            })
+            .transpose()?;
+
+        let input_selector =
+            StandardSigningRequestBuilder::input_selector(&transaction_builder.input_selector);
+
+        let unsigned_tx = builder.build()?;
+        Ok(PlanRequest {
+            ty: RequestType::SendExact {
+                unsigned_tx,
+                change_output,
+                input_selector,
+            },
+            dust_policy,
+            fee_estimator,
+        })
+    }
+}
+
+impl LiquidSigningRequestBuilder {
+    /// Returns the asset of the transaction outputs in the network byte order.
+    /// L-BTC is used by default.
+    pub fn asset(proto: &TransactionBuilder) -> SigningResult<H256> {
+        use tw_proto::BitcoinV2::Proto::mod_TransactionBuilder::OneOfchain_specific as ChainSpecific;
+
+        let extra_data = match proto.chain_specific {
+            ChainSpecific::liquid_extra_data(ref liquid) => liquid,
+            ChainSpecific::None => return Ok(LIQUID_POLICY_ASSET),
+            _ => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Expected 'TransactionBuilder.liquid_extra_data' to be set")
+            },
+        };
+
+        if extra_data.asset_id.is_empty() {
+            return Ok(LIQUID_POLICY_ASSET);
+        }
+        // Asset IDs are displayed in the reversed byte order, similar to transaction IDs.
+        H256::from_str(&extra_data.asset_id)
+            .map(H256::rev)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid 'assetId', expected 32-byte hex")
+    }
+}
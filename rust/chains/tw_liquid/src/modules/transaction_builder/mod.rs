@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::{
+    LiquidTransaction, LiquidTransactionInput, LiquidTransactionOutput, LIQUID_POLICY_ASSET,
+    TRANSACTION_VERSION_2,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H256;
+use tw_utxo::transaction::standard_transaction::{
+    TransactionInput, TransactionOutput, DEFAULT_LOCKTIME,
+};
+use tw_utxo::transaction::unsigned_transaction::UnsignedTransaction;
+use tw_utxo::transaction::UtxoToSign;
+
+mod output;
+mod utxo;
+
+pub use output::LiquidOutputBuilder;
+pub use utxo::LiquidUtxoBuilder;
+
+pub struct LiquidTransactionBuilder {
+    transaction: LiquidTransaction,
+    utxo_args: Vec<UtxoToSign>,
+    /// Asset of the standard outputs.
+    asset: H256,
+}
+
+impl Default for LiquidTransactionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LiquidTransactionBuilder {
+    pub fn new() -> Self {
+        LiquidTransactionBuilder {
+            transaction: LiquidTransaction {
+                version: TRANSACTION_VERSION_2,
+                inputs: Vec::default(),
+                outputs: Vec::default(),
+                locktime: DEFAULT_LOCKTIME,
+                fee_asset: LIQUID_POLICY_ASSET,
+            },
+            utxo_args: Vec::default(),
+            asset: LIQUID_POLICY_ASSET,
+        }
+    }
+
+    pub fn version(&mut self, version: u32) -> &mut Self {
+        self.transaction.version = version;
+        self
+    }
+
+    pub fn lock_time(&mut self, locktime: u32) -> &mut Self {
+        self.transaction.locktime = locktime;
+        self
+    }
+
+    /// Sets the asset of the standard outputs.
+    /// The fee is paid from the same inputs and outputs, so only the network policy asset (L-BTC) is supported.
+    pub fn asset(&mut self, asset: H256) -> SigningResult<&mut Self> {
+        if asset != LIQUID_POLICY_ASSET {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Only L-BTC transfers are supported, as the fee must be paid in L-BTC");
+        }
+        self.asset = asset;
+        Ok(self)
+    }
+
+    pub fn push_input(&mut self, input: LiquidTransactionInput, arg: UtxoToSign) -> &mut Self {
+        self.transaction.inputs.push(input);
+        self.utxo_args.push(arg);
+        self
+    }
+
+    pub fn push_standard_input(
+        &mut self,
+        standard_input: TransactionInput,
+        arg: UtxoToSign,
+    ) -> SigningResult<&mut Self> {
+        let (liquid_input, arg) =
+            LiquidUtxoBuilder::try_from_standard_input(standard_input, arg)?.build();
+        Ok(self.push_input(liquid_input, arg))
+    }
+
+    pub fn push_output(&mut self, out: LiquidTransactionOutput) -> &mut Self {
+        self.transaction.outputs.push(out);
+        self
+    }
+
+    pub fn push_standard_output(&mut self, out: TransactionOutput) -> &mut Self {
+        let liquid_output = LiquidOutputBuilder::from_standard_output(out, self.asset).build();
+        self.push_output(liquid_output)
+    }
+
+    pub fn build(self) -> SigningResult<UnsignedTransaction<LiquidTransaction>> {
+        UnsignedTransaction::new(self.transaction, self.utxo_args)
+    }
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::LiquidTransactionOutput;
+use tw_hash::H256;
+use tw_utxo::transaction::standard_transaction::TransactionOutput;
+
+pub struct LiquidOutputBuilder {
+    output: LiquidTransactionOutput,
+}
+
+impl LiquidOutputBuilder {
+    pub fn from_standard_output(standard_output: TransactionOutput, asset: H256) -> Self {
+        LiquidOutputBuilder {
+            output: LiquidTransactionOutput {
+                asset,
+                value: standard_output.value,
+                script_pubkey: standard_output.script_pubkey,
+            },
+        }
+    }
+
+    pub fn build(self) -> LiquidTransactionOutput {
+        self.output
+    }
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::transaction::LiquidTransactionInput;
+use tw_coin_entry::error::prelude::*;
+use tw_utxo::signing_mode::SigningMethod;
+use tw_utxo::transaction::standard_transaction::TransactionInput;
+use tw_utxo::transaction::UtxoToSign;
+
+pub struct LiquidUtxoBuilder {
+    input: LiquidTransactionInput,
+    args: UtxoToSign,
+}
+
+impl LiquidUtxoBuilder {
+    pub fn try_from_standard_input(
+        standard_input: TransactionInput,
+        args: UtxoToSign,
+    ) -> SigningResult<Self> {
+        let input = LiquidTransactionInput {
+            previous_output: standard_input.previous_output,
+            sequence: standard_input.sequence,
+            script_sig: standard_input.script_sig,
+            witness: standard_input.witness,
+            value: args.amount,
+        };
+        if !matches!(args.signing_method, SigningMethod::Segwit) {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Liquid blockchain supports spending explicit Segwit outputs only");
+        }
+
+        Ok(LiquidUtxoBuilder { input, args })
+    }
+
+    pub fn build(self) -> (LiquidTransactionInput, UtxoToSign) {
+        (self.input, self.args)
+    }
+}
@@ -0,0 +1,397 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::liquid_sighash::LiquidSighash;
+use tw_coin_entry::error::prelude::*;
+use tw_hash::hasher::{Hasher, StatefulHasher};
+use tw_hash::H256;
+use tw_memory::Data;
+use tw_utxo::encode::stream::Stream;
+use tw_utxo::encode::Encodable;
+use tw_utxo::script::{Script, Witness};
+use tw_utxo::transaction::standard_transaction::{DEFAULT_OUTPUT_VALUE, SEGWIT_SCALE_FACTOR};
+use tw_utxo::transaction::transaction_interface::{
+    TransactionInterface, TxInputInterface, TxOutputInterface,
+};
+use tw_utxo::transaction::transaction_parts::{Amount, OutPoint};
+use tw_utxo::transaction::{TransactionPreimage, UtxoPreimageArgs};
+
+pub const TRANSACTION_VERSION_2: u32 = 2;
+
+/// L-BTC asset tag in the network byte order.
+/// Displayed as `6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d`.
+pub const LIQUID_POLICY_ASSET: H256 = H256::from_array([
+    0x6d, 0x52, 0x1c, 0x38, 0xec, 0x1e, 0xa1, 0x57, 0x34, 0xae, 0x22, 0xb7, 0xc4, 0x60, 0x64, 0x41,
+    0x28, 0x29, 0xc0, 0xd0, 0x57, 0x9f, 0x0a, 0x71, 0x3d, 0x1c, 0x04, 0xed, 0xe9, 0x79, 0x02, 0x6f,
+]);
+
+/// Prefix of an explicit (unblinded) asset tag.
+const EXPLICIT_ASSET_PREFIX: u8 = 0x01;
+/// Prefix of an explicit (unblinded) value.
+const EXPLICIT_VALUE_PREFIX: u8 = 0x01;
+/// Encoding of an empty confidential field, e.g. a nonce of an explicit output.
+const NULL_CONFIDENTIAL_FIELD: u8 = 0x00;
+
+/// Elements transaction with explicit (unblinded) inputs and outputs.
+/// https://github.com/ElementsProject/elements/blob/master/doc/elements-tx-format.md
+///
+/// Unlike Bitcoin, the transaction fee is paid to an explicit fee output with an empty `scriptPubkey`.
+/// The fee output is not part of [`LiquidTransaction::outputs`], instead it's calculated as the difference
+/// between the inputs and outputs amounts and appended on encoding and preimage hashing.
+#[derive(Clone, Debug)]
+pub struct LiquidTransaction {
+    /// Transaction data format version.
+    pub version: u32,
+    /// Transaction inputs.
+    pub inputs: Vec<LiquidTransactionInput>,
+    /// Transaction outputs excluding the fee output.
+    pub outputs: Vec<LiquidTransactionOutput>,
+    /// The block number or timestamp at which this transaction is unlocked.
+    pub locktime: u32,
+    /// The asset the transaction fee is paid in, always the network policy asset.
+    pub fee_asset: H256,
+}
+
+impl LiquidTransaction {
+    /// Returns the amount of the fee output.
+    /// Returns an error if the outputs amount exceeds the inputs amount.
+    pub fn fee(&self) -> SigningResult<Amount> {
+        let total_in = self
+            .inputs
+            .iter()
+            .try_fold(0, |total: Amount, input| total.checked_add(input.value))
+            .or_tw_err(SigningErrorType::Error_wrong_fee)
+            .context("Liquid inputs amount overflow")?;
+        let total_out = self
+            .outputs
+            .iter()
+            .try_fold(0, |total: Amount, output| total.checked_add(output.value))
+            .or_tw_err(SigningErrorType::Error_wrong_fee)
+            .context("Liquid outputs amount overflow")?;
+
+        if total_out > total_in {
+            return SigningError::err(SigningErrorType::Error_not_enough_utxos).context(format!(
+                "Liquid outputs amount {total_out} exceeds the inputs amount {total_in}"
+            ));
+        }
+        Ok(total_in - total_out)
+    }
+
+    /// Returns the explicit fee output.
+    pub fn fee_output(&self) -> SigningResult<LiquidTransactionOutput> {
+        Ok(LiquidTransactionOutput {
+            asset: self.fee_asset,
+            value: self.fee()?,
+            script_pubkey: Script::default(),
+        })
+    }
+
+    /// Returns all transaction outputs including the fee output.
+    pub fn outputs_with_fee(&self) -> SigningResult<Vec<LiquidTransactionOutput>> {
+        let mut outputs = self.outputs.clone();
+        outputs.push(self.fee_output()?);
+        Ok(outputs)
+    }
+
+    /// Returns all transaction outputs including the fee output, which is zero if the transaction is unbalanced.
+    /// Used where an error can't be returned, see [`LiquidTransaction::encode`].
+    pub fn outputs_with_fee_unchecked(&self) -> Vec<LiquidTransactionOutput> {
+        let mut outputs = self.outputs.clone();
+        outputs.push(LiquidTransactionOutput {
+            asset: self.fee_asset,
+            value: self.fee().unwrap_or_default(),
+            script_pubkey: Script::default(),
+        });
+        outputs
+    }
+
+    /// Encodes the transaction with or without the witness data.
+    ///
+    /// The encoding can't fail, so an unbalanced transaction is encoded with a zero fee.
+    /// It only happens while the transaction size is estimated, as every input sighash
+    /// commits to the [`LiquidTransaction::fee_output`] that checks the balance.
+    pub fn encode(&self, stream: &mut Stream, include_witness: bool) {
+        let has_witness = include_witness && self.has_witness();
+        let outputs = self.outputs_with_fee_unchecked();
+
+        stream
+            .append(&self.version)
+            // Elements always encodes the flag byte, it signals whether the witness data follows.
+            .append(&(has_witness as u8))
+            .append_list(&self.inputs)
+            .append_list(&outputs)
+            .append(&self.locktime);
+
+        if has_witness {
+            for input in self.inputs.iter() {
+                input.encode_witness(stream);
+            }
+            for _ in outputs.iter() {
+                // Surjection proof and range proof of the explicit output.
+                stream
+                    .append(&NULL_CONFIDENTIAL_FIELD)
+                    .append(&NULL_CONFIDENTIAL_FIELD);
+            }
+        }
+    }
+
+    fn base_size(&self) -> usize {
+        let mut stream = Stream::new();
+        self.encode(&mut stream, false);
+        stream.out().len()
+    }
+}
+
+impl Encodable for LiquidTransaction {
+    fn encode(&self, stream: &mut Stream) {
+        self.encode(stream, true);
+    }
+
+    fn encoded_size(&self) -> usize {
+        // TODO consider optimising it by calculating encoded size manually without allocating extra memory.
+        self.encode_out().len()
+    }
+}
+
+impl TransactionInterface for LiquidTransaction {
+    type Input = LiquidTransactionInput;
+    type Output = LiquidTransactionOutput;
+
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn inputs(&self) -> &[Self::Input] {
+        &self.inputs
+    }
+
+    fn inputs_mut(&mut self) -> &mut [Self::Input] {
+        &mut self.inputs
+    }
+
+    fn replace_inputs(&mut self, inputs: Vec<Self::Input>) {
+        self.inputs = inputs;
+    }
+
+    fn outputs(&self) -> &[Self::Output] {
+        &self.outputs
+    }
+
+    fn outputs_mut(&mut self) -> &mut [Self::Output] {
+        &mut self.outputs
+    }
+
+    fn replace_outputs(&mut self, outputs: Vec<Self::Output>) {
+        self.outputs = outputs;
+    }
+
+    fn push_output(&mut self, output: Self::Output) {
+        self.outputs.push(output)
+    }
+
+    fn locktime(&self) -> u32 {
+        self.locktime
+    }
+
+    fn vsize(&self) -> usize {
+        (self.weight() + 3) / SEGWIT_SCALE_FACTOR // ceil(weight / 4)
+    }
+
+    fn weight(&self) -> usize {
+        self.base_size() * 3 + self.encoded_size()
+    }
+
+    fn txid(&self, hasher: Hasher) -> Data {
+        let mut stream = Stream::new();
+        self.encode(&mut stream, false);
+        let mut tx_hash = hasher.hash(&stream.out());
+        tx_hash.reverse();
+        tx_hash
+    }
+}
+
+impl TransactionPreimage for LiquidTransaction {
+    fn preimage_tx(&self, args: &UtxoPreimageArgs) -> SigningResult<H256> {
+        LiquidSighash::sighash_tx(self, args)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LiquidTransactionInput {
+    /// Reference to the previous transaction's output.
+    pub previous_output: OutPoint,
+    /// Transaction version as defined by the sender.
+    pub sequence: u32,
+    /// Computational Script for confirming transaction authorization.
+    pub script_sig: Script,
+    /// Witness stack.
+    pub witness: Witness,
+    /// Explicit amount of the spent output.
+    /// Not serialized, used to calculate the fee output.
+    pub value: Amount,
+}
+
+impl LiquidTransactionInput {
+    /// Encodes the input witness. The asset issuance and peg-in witnesses are always empty.
+    pub fn encode_witness(&self, stream: &mut Stream) {
+        stream
+            // Issuance amount range proof.
+            .append(&NULL_CONFIDENTIAL_FIELD)
+            // Inflation keys range proof.
+            .append(&NULL_CONFIDENTIAL_FIELD)
+            .append(&self.witness)
+            // Peg-in witness.
+            .append(&NULL_CONFIDENTIAL_FIELD);
+    }
+}
+
+impl Encodable for LiquidTransactionInput {
+    fn encode(&self, stream: &mut Stream) {
+        stream
+            .append(&self.previous_output)
+            .append(&self.script_sig)
+            .append(&self.sequence);
+    }
+
+    fn encoded_size(&self) -> usize {
+        self.previous_output.encoded_size()
+            + self.script_sig.encoded_size()
+            + self.sequence.encoded_size()
+    }
+}
+
+impl TxInputInterface for LiquidTransactionInput {
+    fn previous_output(&self) -> &OutPoint {
+        &self.previous_output
+    }
+
+    fn sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    fn script_sig(&self) -> &Script {
+        &self.script_sig
+    }
+
+    fn witness(&self) -> Option<&Witness> {
+        Some(&self.witness)
+    }
+
+    fn set_sequence(&mut self, sequence: u32) {
+        self.sequence = sequence;
+    }
+
+    fn set_script_sig(&mut self, script_sig: Script) {
+        self.script_sig = script_sig;
+    }
+
+    fn set_witness(&mut self, witness: Witness) {
+        self.witness = witness;
+    }
+
+    fn has_script_sig(&self) -> bool {
+        !self.script_sig.is_empty()
+    }
+
+    fn has_witness(&self) -> bool {
+        !self.witness.is_empty()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LiquidTransactionOutput {
+    /// Explicit asset tag in the network byte order.
+    pub asset: H256,
+    /// Explicit amount.
+    pub value: Amount,
+    /// Usually contains the public key as a Bitcoin script setting up
+    /// conditions to claim this output.
+    pub script_pubkey: Script,
+}
+
+impl Default for LiquidTransactionOutput {
+    fn default() -> Self {
+        LiquidTransactionOutput {
+            asset: LIQUID_POLICY_ASSET,
+            value: DEFAULT_OUTPUT_VALUE,
+            script_pubkey: Script::default(),
+        }
+    }
+}
+
+impl Encodable for LiquidTransactionOutput {
+    fn encode(&self, stream: &mut Stream) {
+        // Explicit values are encoded in big-endian.
+        let value = (self.value as u64).to_be_bytes();
+
+        stream
+            .append(&EXPLICIT_ASSET_PREFIX)
+            .append_raw_slice(self.asset.as_slice())
+            .append(&EXPLICIT_VALUE_PREFIX)
+            .append_raw_slice(&value)
+            .append(&NULL_CONFIDENTIAL_FIELD)
+            .append(&self.script_pubkey);
+    }
+
+    fn encoded_size(&self) -> usize {
+        1 + H256::LEN + 1 + 8 + 1 + self.script_pubkey.encoded_size()
+    }
+}
+
+impl TxOutputInterface for LiquidTransactionOutput {
+    fn value(&self) -> Amount {
+        self.value
+    }
+
+    fn set_value(&mut self, value: Amount) {
+        self.value = value;
+    }
+
+    fn script_pubkey(&self) -> &Script {
+        &self.script_pubkey
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transaction(input_value: Amount, output_value: Amount) -> LiquidTransaction {
+        LiquidTransaction {
+            version: TRANSACTION_VERSION_2,
+            inputs: vec![LiquidTransactionInput {
+                previous_output: OutPoint::default(),
+                sequence: u32::MAX,
+                script_sig: Script::default(),
+                witness: Witness::default(),
+                value: input_value,
+            }],
+            outputs: vec![LiquidTransactionOutput {
+                value: output_value,
+                ..LiquidTransactionOutput::default()
+            }],
+            locktime: 0,
+            fee_asset: LIQUID_POLICY_ASSET,
+        }
+    }
+
+    #[test]
+    fn test_liquid_transaction_fee() {
+        let tx = transaction(100_000, 99_800);
+        assert_eq!(tx.fee().unwrap(), 200);
+        assert_eq!(tx.outputs_with_fee().unwrap().last().unwrap().value, 200);
+    }
+
+    #[test]
+    fn test_liquid_transaction_fee_outputs_exceed_inputs() {
+        let tx = transaction(100_000, 100_001);
+        assert_eq!(
+            *tx.fee().unwrap_err().error_type(),
+            SigningErrorType::Error_not_enough_utxos
+        );
+        assert!(tx.outputs_with_fee().is_err());
+        // Encoding doesn't fail, so the transaction size can be estimated.
+        assert_eq!(tx.outputs_with_fee_unchecked().last().unwrap().value, 0);
+    }
+}
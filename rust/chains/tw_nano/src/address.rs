@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::coin_entry::CoinAddress;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::base32;
+use tw_hash::blake2::blake2_b;
+use tw_hash::H256;
+use tw_keypair::ed25519::blake2b::PublicKey;
+use tw_memory::Data;
+
+/// The default address prefix.
+pub const NANO_PREFIX: &str = "nano_";
+/// The legacy address prefix that is still accepted.
+pub const XRB_PREFIX: &str = "xrb_";
+
+/// Nano base32 alphabet.
+const ALPHABET: &str = "13456789abcdefghijkmnopqrstuwxyz";
+const CHECKSUM_LEN: usize = 5;
+/// 256 bits of the public key are padded with 4 zero bits to 52 base32 characters.
+const ENCODED_PUBLIC_KEY_LEN: usize = 52;
+/// 40 bits of the checksum are encoded to 8 base32 characters.
+const ENCODED_CHECKSUM_LEN: usize = 8;
+/// The public key is prepended with 3 zero bytes, so the base32 encoding is aligned.
+/// This adds 4 leading zero characters.
+const PADDING_BYTES: [u8; 3] = [0; 3];
+const PADDING_CHARS: &str = "1111";
+
+/// Nano account address: base32 encoded public key followed by the blake2b checksum.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NanoAddress {
+    public_key: H256,
+}
+
+impl NanoAddress {
+    pub fn with_public_key(public_key: &PublicKey) -> NanoAddress {
+        NanoAddress::with_public_key_bytes(public_key.to_bytes())
+    }
+
+    /// Creates an address from the raw public key bytes.
+    /// Any 32 byte value can be represented as an address, e.g. a `link` block hash.
+    pub fn with_public_key_bytes(public_key: H256) -> NanoAddress {
+        NanoAddress { public_key }
+    }
+
+    /// Returns the raw public key bytes.
+    pub fn public_key_bytes(&self) -> H256 {
+        self.public_key
+    }
+
+    fn checksum(public_key: &H256) -> Data {
+        let mut checksum =
+            blake2_b(public_key.as_slice(), CHECKSUM_LEN).expect("Expected a valid hash size");
+        checksum.reverse();
+        checksum
+    }
+
+    fn encode(bytes: &[u8]) -> String {
+        base32::encode(bytes, Some(ALPHABET.to_string()), false)
+            .expect("Expected a valid base32 alphabet")
+    }
+
+    fn decode(s: &str) -> AddressResult<Data> {
+        base32::decode(s, Some(ALPHABET.to_string()), false).map_err(|_| AddressError::InvalidInput)
+    }
+}
+
+impl CoinAddress for NanoAddress {
+    #[inline]
+    fn data(&self) -> Data {
+        self.public_key.to_vec()
+    }
+}
+
+impl FromStr for NanoAddress {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let encoded = s
+            .strip_prefix(NANO_PREFIX)
+            .or_else(|| s.strip_prefix(XRB_PREFIX))
+            .ok_or(AddressError::MissingPrefix)?;
+        if encoded.len() != ENCODED_PUBLIC_KEY_LEN + ENCODED_CHECKSUM_LEN || !encoded.is_ascii() {
+            return Err(AddressError::InvalidInput);
+        }
+
+        let (encoded_public_key, encoded_checksum) = encoded.split_at(ENCODED_PUBLIC_KEY_LEN);
+        let padded = NanoAddress::decode(&format!("{PADDING_CHARS}{encoded_public_key}"))?;
+        let (padding, public_key) = padded.split_at(PADDING_BYTES.len());
+        if padding != PADDING_BYTES {
+            return Err(AddressError::InvalidInput);
+        }
+        let public_key = H256::try_from(public_key).map_err(|_| AddressError::InvalidInput)?;
+
+        if NanoAddress::decode(encoded_checksum)? != NanoAddress::checksum(&public_key) {
+            return Err(AddressError::InvalidChecksum);
+        }
+        Ok(NanoAddress { public_key })
+    }
+}
+
+impl fmt::Display for NanoAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut padded = PADDING_BYTES.to_vec();
+        padded.extend_from_slice(self.public_key.as_slice());
+        let encoded = NanoAddress::encode(&padded);
+        let encoded_public_key = &encoded[PADDING_CHARS.len()..];
+        let encoded_checksum = NanoAddress::encode(&NanoAddress::checksum(&self.public_key));
+
+        write!(f, "{NANO_PREFIX}{encoded_public_key}{encoded_checksum}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nano_address_from_public_key() {
+        let public_key =
+            PublicKey::try_from("5114aad86a390897d2a91b33b931b3a59a7df9e63eb3694f9430122f5622ae50")
+                .unwrap();
+        let address = NanoAddress::with_public_key(&public_key);
+        assert_eq!(
+            address.to_string(),
+            "nano_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg"
+        );
+    }
+
+    #[test]
+    fn test_nano_address_from_str() {
+        let nano = "nano_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg";
+        let xrb = "xrb_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg";
+        assert_eq!(NanoAddress::from_str(nano).unwrap().to_string(), nano);
+        assert_eq!(
+            NanoAddress::from_str(xrb).unwrap(),
+            NanoAddress::from_str(nano).unwrap()
+        );
+
+        let zero = "nano_1111111111111111111111111111111111111111111111111111hifc8npp";
+        assert_eq!(
+            NanoAddress::from_str(zero).unwrap().public_key_bytes(),
+            H256::default()
+        );
+    }
+
+    #[test]
+    fn test_nano_address_invalid() {
+        let invalid = [
+            // Invalid checksum.
+            "xrb_1111111111111111111111111111111111111111111111111111hi111111",
+            // Invalid prefix.
+            "nano1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg",
+            // Too short.
+            "nano_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffe",
+            // Non-zero padding bits.
+            "nano_4nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg",
+            // Invalid character `0`.
+            "nano_0nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg",
+        ];
+        for address in invalid {
+            assert!(NanoAddress::from_str(address).is_err(), "{address}");
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::NanoAddress;
+use serde::Serialize;
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::ToHex;
+use tw_hash::blake2::blake2_b;
+use tw_hash::{H256, H512};
+use tw_proto::Nano::Proto;
+use tw_proto::Nano::Proto::mod_SigningInput::OneOflink_oneof as LinkOneOf;
+
+/// The state block type preamble, i.e. `6` as a 32 byte big-endian number.
+const STATE_BLOCK_PREAMBLE: H256 = H256::from_array([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6,
+]);
+
+/// Nano state block.
+/// https://docs.nano.org/integration-guides/the-basics/#block-format
+pub struct StateBlock {
+    pub account: H256,
+    /// The previous block hash. Zero for the first (open) block of the account.
+    pub previous: H256,
+    pub representative: NanoAddress,
+    /// The account balance after this block, in raw units.
+    pub balance: u128,
+    /// Either the hash of the block to receive from, or the recipient public key.
+    pub link: H256,
+}
+
+impl StateBlock {
+    /// Creates a state block of the given `account` public key.
+    pub fn from_proto(input: &Proto::SigningInput<'_>, account: H256) -> SigningResult<StateBlock> {
+        let previous = if input.parent_block.is_empty() {
+            H256::default()
+        } else {
+            H256::try_from(input.parent_block.as_ref())
+                .tw_err(SigningErrorType::Error_invalid_params)
+                .context("Invalid parent block hash")?
+        };
+        let is_open_block = previous.is_zero();
+
+        let representative = NanoAddress::from_str(&input.representative)
+            .into_tw()
+            .context("Invalid representative address")?;
+
+        let balance = u128::from_str(&input.balance)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid balance")?;
+        if is_open_block && balance == 0 {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Open block must have a non-zero balance");
+        }
+
+        let link = match input.link_oneof {
+            LinkOneOf::link_block(ref link_block) => H256::try_from(link_block.as_ref())
+                .tw_err(SigningErrorType::Error_invalid_params)
+                .context("Invalid link block hash")?,
+            // An open block can only receive funds.
+            LinkOneOf::link_recipient(_) if is_open_block => H256::default(),
+            LinkOneOf::link_recipient(ref recipient) => NanoAddress::from_str(recipient)
+                .into_tw()
+                .context("Invalid recipient address")?
+                .public_key_bytes(),
+            LinkOneOf::None => H256::default(),
+        };
+        if is_open_block && link.is_zero() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Missing link block hash");
+        }
+
+        Ok(StateBlock {
+            account,
+            previous,
+            representative,
+            balance,
+            link,
+        })
+    }
+
+    /// Returns the block hash that is signed.
+    pub fn hash(&self) -> H256 {
+        let mut data = Vec::with_capacity(H256::LEN * 5 + 16);
+        data.extend_from_slice(STATE_BLOCK_PREAMBLE.as_slice());
+        data.extend_from_slice(self.account.as_slice());
+        data.extend_from_slice(self.previous.as_slice());
+        data.extend_from_slice(self.representative.public_key_bytes().as_slice());
+        data.extend_from_slice(&self.balance.to_be_bytes());
+        data.extend_from_slice(self.link.as_slice());
+
+        let hash = blake2_b(&data, H256::LEN).expect("Expected a valid hash size");
+        H256::try_from(hash.as_slice()).expect("Expected 32 byte hash")
+    }
+
+    /// Returns the JSON representation of the signed block as accepted by the `process` RPC.
+    pub fn to_json(&self, signature: &H512, work: &str) -> SigningResult<String> {
+        let block = StateBlockJson {
+            account: NanoAddress::with_public_key_bytes(self.account).to_string(),
+            balance: self.balance.to_string(),
+            link: self.link.to_hex(),
+            link_as_account: NanoAddress::with_public_key_bytes(self.link).to_string(),
+            previous: self.previous.to_hex(),
+            representative: self.representative.to_string(),
+            signature: signature.to_hex(),
+            r#type: "state",
+            work,
+        };
+        serde_json::to_string(&block)
+            .tw_err(SigningErrorType::Error_internal)
+            .context("Error serializing the block as JSON")
+    }
+}
+
+/// The fields are sorted alphabetically to produce a canonical JSON.
+#[derive(Serialize)]
+struct StateBlockJson<'a> {
+    account: String,
+    balance: String,
+    link: String,
+    link_as_account: String,
+    previous: String,
+    representative: String,
+    signature: String,
+    r#type: &'static str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    work: &'a str,
+}
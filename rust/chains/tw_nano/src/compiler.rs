@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::block::StateBlock;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
+use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ed25519;
+use tw_keypair::traits::VerifyingKeyTrait;
+use tw_proto::Nano::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+pub struct NanoCompiler;
+
+impl NanoCompiler {
+    #[inline]
+    pub fn preimage_hashes(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> CompilerProto::PreSigningOutput<'static> {
+        Self::preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn preimage_hashes_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let public_key = ed25519::blake2b::PublicKey::try_from(input.public_key.as_ref())?;
+        let block = StateBlock::from_proto(&input, public_key.to_bytes())?;
+        let block_hash = block.hash().to_vec();
+
+        Ok(CompilerProto::PreSigningOutput {
+            data: block_hash.clone().into(),
+            data_hash: block_hash.into(),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    #[inline]
+    pub fn compile(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::compile_impl(coin, input, signatures, public_keys)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn compile_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let SingleSignaturePubkey {
+            signature,
+            public_key,
+        } = SingleSignaturePubkey::from_sign_pubkey_list(signatures, public_keys)?;
+
+        let public_key = ed25519::blake2b::PublicKey::try_from(public_key.as_slice())?;
+        let signature = ed25519::Signature::try_from(signature.as_slice())?;
+
+        let block = StateBlock::from_proto(&input, public_key.to_bytes())?;
+        let block_hash = block.hash();
+
+        if !public_key.verify(signature.clone(), block_hash.to_vec()) {
+            return SigningError::err(SigningErrorType::Error_signing)
+                .context("Signature does not match the block hash");
+        }
+
+        let signature = signature.to_bytes();
+        Ok(Proto::SigningOutput {
+            signature: signature.to_vec().into(),
+            block_hash: block_hash.to_vec().into(),
+            json: block.to_json(&signature, &input.work)?.into(),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::block::StateBlock;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_proto::Nano::Proto;
+
+pub struct NanoSigner;
+
+impl NanoSigner {
+    pub fn sign(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> Proto::SigningOutput<'static> {
+        Self::sign_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let key_pair = ed25519::blake2b::KeyPair::try_from(input.private_key.as_ref())?;
+        let block = StateBlock::from_proto(&input, key_pair.public().to_bytes())?;
+
+        let block_hash = block.hash();
+        let signature = key_pair.sign(block_hash.to_vec())?.to_bytes();
+
+        Ok(Proto::SigningOutput {
+            signature: signature.to_vec().into(),
+            block_hash: block_hash.to_vec().into(),
+            json: block.to_json(&signature, &input.work)?.into(),
+            ..Proto::SigningOutput::default()
+        })
+    }
+}
@@ -12,6 +12,7 @@ use tw_coin_entry::modules::json_signer::NoJsonSigner;
 use tw_coin_entry::modules::message_signer::NoMessageSigner;
 use tw_coin_entry::modules::plan_builder::NoPlanBuilder;
 use tw_coin_entry::modules::transaction_decoder::NoTransactionDecoder;
+use tw_coin_entry::modules::transaction_planner::NoTransactionPlanner;
 use tw_coin_entry::modules::wallet_connector::NoWalletConnector;
 use tw_coin_entry::prefix::NoPrefix;
 use tw_keypair::tw::PublicKey;
@@ -33,6 +34,7 @@ impl CoinEntry for PactusEntry {
     type SigningOutput = Proto::SigningOutput<'static>;
     type PreSigningOutput = CompilerProto::PreSigningOutput<'static>;
     type TransactionUtil = PactusTransactionUtil;
+    type TransactionPlanner = NoTransactionPlanner;
 
     // Optional modules:
     type JsonSigner = NoJsonSigner;
@@ -69,7 +71,9 @@ impl CoinEntry for PactusEntry {
             .ok_or(AddressError::PublicKeyTypeMismatch)?;
 
         match derivation {
-            Derivation::Default => Address::from_public_key(public_key, Network::Mainnet),
+            Derivation::Default | Derivation::Mainnet => {
+                Address::from_public_key(public_key, Network::Mainnet)
+            },
             Derivation::Testnet => Address::from_public_key(public_key, Network::Testnet),
             _ => AddressResult::Err(AddressError::Unsupported),
         }
@@ -3,7 +3,7 @@ use crate::encoder::{decode::decode_fix_slice, encode::encode_fix_slice};
 use crate::encoder::{Decodable, Encodable};
 use bech32::FromBase32;
 use std::str::FromStr;
-use tw_keypair::KeyPairError;
+use tw_keypair::{KeyPairError, KeyPairErrorKind};
 
 use super::network::{MAINNET_PUBLIC_KEY_HRP, TESTNET_PUBLIC_KEY_HRP};
 
@@ -34,13 +34,17 @@ impl FromStr for ValidatorPublicKey {
     type Err = KeyPairError;
 
     fn from_str(s: &str) -> Result<Self, KeyPairError> {
-        let (hrp, b32, _variant) = bech32::decode(s).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let (hrp, b32, _variant) =
+            bech32::decode(s).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         if hrp != MAINNET_PUBLIC_KEY_HRP && hrp != TESTNET_PUBLIC_KEY_HRP {
-            return Err(KeyPairError::InvalidPublicKey);
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey));
         }
 
-        let b8 = Vec::<u8>::from_base32(&b32[1..]).map_err(|_| KeyPairError::InvalidPublicKey)?;
-        let pub_data = b8.try_into().map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let b8 = Vec::<u8>::from_base32(&b32[1..])
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
+        let pub_data = b8
+            .try_into()
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
 
         Ok(ValidatorPublicKey(pub_data))
     }
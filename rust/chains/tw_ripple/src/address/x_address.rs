@@ -49,6 +49,28 @@ pub struct XAddress {
 serde_as_string!(XAddress);
 
 impl XAddress {
+    /// Creates a mainnet X-address from the account public key hash and an optional destination tag.
+    pub fn new(public_key_hash: H160, tag: Option<u32>) -> AddressResult<XAddress> {
+        let (tag_flag, tag) = match tag {
+            Some(tag) => (TagFlag::Classic, tag),
+            None => (TagFlag::None, 0),
+        };
+
+        let mut bytes = [0_u8; X_ADDRESS_LEN];
+        bytes[PREFIX_RANGE].copy_from_slice(&MAINNET_PREFIX);
+        bytes[KEY_HASH_RANGE].copy_from_slice(public_key_hash.as_slice());
+        bytes[TAG_FLAG_POSITION] = tag_flag as u8;
+        bytes[TAG_RANGE].copy_from_slice(&tag.to_le_bytes());
+        // The remaining 4 bytes are reserved for 64-bit tags, and must be zeros.
+
+        let inner = Base58Address::new(&bytes, Alphabet::Ripple)?;
+        Ok(XAddress {
+            tag,
+            inner,
+            tag_flag,
+        })
+    }
+
     pub fn public_key_hash(&self) -> H160 {
         H160::try_from(&self.inner.bytes[KEY_HASH_RANGE])
             .expect("'KEY_HASH_RANGE' must be 20 bytes length")
@@ -109,3 +131,29 @@ impl CoinAddress for XAddress {
         self.public_key_hash().to_vec()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_x_address_new() {
+        let classic = ClassicAddress::from_str("rnBFvgZphmN39GWzUJeUitaP22Fr9be75H").unwrap();
+
+        let with_tag = XAddress::new(classic.public_key_hash(), Some(12345)).unwrap();
+        assert_eq!(
+            with_tag.to_string(),
+            "X76UnYEMbQfEs3mUqgtjp4zFy9exgThRj7XVZ6UxsdrBptF"
+        );
+        assert_eq!(with_tag.destination_tag(), 12345);
+        assert_eq!(with_tag.tag_flag(), TagFlag::Classic);
+
+        let without_tag = XAddress::new(classic.public_key_hash(), None).unwrap();
+        assert_eq!(
+            without_tag.to_string(),
+            "X76UnYEMbQfEs3mUqgtjp4zFy9exgSxWAqcQwu9z2r5d7Tm"
+        );
+        assert_eq!(without_tag.tag_flag(), TagFlag::None);
+        assert_eq!(without_tag.to_classic().unwrap(), classic);
+    }
+}
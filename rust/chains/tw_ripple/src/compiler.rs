@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::address::classic_address::ClassicAddress;
 use crate::encode::{encode_tx, EncodeMode};
 use crate::modules::protobuf_builder::ProtobufBuilder;
 use crate::modules::transaction_signer::TransactionSigner;
@@ -31,8 +32,17 @@ impl RippleCompiler {
         _coin: &dyn CoinContext,
         input: Proto::SigningInput<'_>,
     ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
-        let unsigned_tx = ProtobufBuilder::new(&input).build()?;
-        let pre_image = TransactionSigner::pre_image(&unsigned_tx)?;
+        let builder = ProtobufBuilder::new(&input);
+        let unsigned_tx = builder.build()?;
+        let pre_image = if input.multisign {
+            let signer_public_key = builder.signing_public_key()?;
+            let signer_account = ClassicAddress::with_public_key(&signer_public_key)
+                .into_tw()
+                .context("Error generating an account address for the multi-signer public key")?;
+            TransactionSigner::multisign_pre_image(&unsigned_tx, &signer_account)?
+        } else {
+            TransactionSigner::pre_image(&unsigned_tx)?
+        };
 
         Ok(CompilerProto::PreSigningOutput {
             data_hash: pre_image.hash_to_sign.to_vec().into(),
@@ -58,6 +68,10 @@ impl RippleCompiler {
         signatures: Vec<SignatureBytes>,
         public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
+        if input.multisign {
+            return Self::compile_multisigned_impl(input, signatures, public_keys);
+        }
+
         let SingleSignaturePubkey {
             signature,
             public_key,
@@ -79,4 +93,38 @@ impl RippleCompiler {
             ..Proto::SigningOutput::default()
         })
     }
+
+    fn compile_multisigned_impl(
+        input: Proto::SigningInput<'_>,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        if signatures.is_empty() || signatures.len() != public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Expected the same non-zero number of signatures and public keys");
+        }
+
+        let signatures = signatures
+            .iter()
+            .zip(public_keys.iter())
+            .map(|(signature, public_key)| {
+                let signature = secp256k1::Signature::from_bytes(signature.as_slice())
+                    .into_tw()
+                    .context("Invalid signature")?;
+                let public_key = secp256k1::PublicKey::try_from(public_key.as_slice())
+                    .into_tw()
+                    .context("Invalid public key")?;
+                Ok((signature, public_key))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        let unsigned_tx = ProtobufBuilder::new(&input).build()?;
+        let signed_tx = TransactionSigner::compile_multisigned(unsigned_tx, signatures)?;
+
+        let encoded = encode_tx(&signed_tx, EncodeMode::All)?.encoded;
+        Ok(Proto::SigningOutput {
+            encoded: encoded.into(),
+            ..Proto::SigningOutput::default()
+        })
+    }
 }
@@ -21,6 +21,8 @@
 //! - **Transaction Signing**: Sign transactions securely using your private key.
 //! - **Transaction Pre-Image Hashing and Compiling**: Hash and compile transaction pre-images,
 //!   allowing you to sign transactions externally and compile them with the provided signature.
+//! - **Multi-Signing**: Collect signatures of the signer list members via `Signers` field,
+//!   either one by one or compiling externally provided signatures at once.
 //! - **Protobuf-Based Transaction Builder**: Utilize Protobuf for building transactions,
 //!   ensuring efficient and standardized transaction structures.
 //!
@@ -35,6 +37,7 @@
 //! - `NftokenCancelOffer`: Cancel an existing NFT offer.
 //! - `NftokenAcceptOffer`: Accept an offer to buy or sell an NFT.
 //! - `TrustSet`: Establish a trust line between two accounts for issued currencies.
+//! - `SignerListSet`: Create, replace or remove a list of signers to authorize multi-signed transactions.
 //!
 //! ## Rust Usage
 //!
@@ -8,6 +8,7 @@ use crate::transaction::common_fields::CommonFields;
 use crate::transaction::json_transaction::JsonTransaction;
 use crate::transaction::transaction_builder::TransactionBuilder;
 use crate::transaction::transaction_type::TransactionType;
+use crate::transaction::transactions::signer_list_set::SignerEntry;
 use crate::transaction::RippleTransaction;
 use crate::types::account_id::AccountId;
 use crate::types::amount::issued_currency::IssuedCurrency;
@@ -22,6 +23,7 @@ use tw_encoding::hex::as_hex::AsHex;
 use tw_encoding::hex::DecodeHex;
 use tw_hash::H256;
 use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
 use tw_misc::traits::{OptionalEmpty, OptionalInt};
 use tw_proto::Ripple::Proto;
 use tw_proto::Ripple::Proto::mod_SigningInput::OneOfoperation_oneof as OperationType;
@@ -76,19 +78,34 @@ impl<'a> ProtobufBuilder<'a> {
             .tw_err(SigningErrorType::Error_input_parse)
             .context("Invalid 'SigningInput.rawJson'")?;
 
-        let expected_signing_pubkey = self.signing_public_key()?;
+        if self.input.multisign {
+            // `SigningPubKey` must be empty for a multi-signed transaction.
+            if tx.common_fields.signing_pub_key.is_none() {
+                tx.common_fields.signing_pub_key = Some(AsHex(Data::default()));
+            }
 
-        // Check whether JSON transaction contains `SigningPubKey` field, otherwise set it.
-        if tx.common_fields.signing_pub_key.is_none() {
-            tx.common_fields.signing_pub_key = Some(AsHex(expected_signing_pubkey.compressed()));
-        }
-
-        // Check whether JSON transaction contains `Account` field, otherwise set it.
-        if tx.common_fields.account.is_none() {
-            let address = ClassicAddress::with_public_key(&expected_signing_pubkey)
-                .into_tw()
-                .context("Internal: error generating an address for the signing public key")?;
-            tx.common_fields.account = Some(RippleAddress::Classic(address));
+            // `Account` cannot be derived from a multi-signer public key.
+            if tx.common_fields.account.is_none() {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("'Account' must be specified for a multi-signed transaction");
+            }
+        } else {
+            let expected_signing_pubkey = self.signing_public_key()?;
+
+            // Check whether JSON transaction contains `SigningPubKey` field, otherwise set it.
+            if tx.common_fields.signing_pub_key.is_none() {
+                tx.common_fields.signing_pub_key = Some(AsHex(
+                    expected_signing_pubkey.compressed().as_slice().to_vec(),
+                ));
+            }
+
+            // Check whether JSON transaction contains `Account` field, otherwise set it.
+            if tx.common_fields.account.is_none() {
+                let address = ClassicAddress::with_public_key(&expected_signing_pubkey)
+                    .into_tw()
+                    .context("Internal: error generating an address for the signing public key")?;
+                tx.common_fields.account = Some(RippleAddress::Classic(address));
+            }
         }
 
         // Check whether `SigningInput.fee` is specified, or JSON transaction doesn't contain that field,
@@ -123,6 +140,7 @@ impl<'a> ProtobufBuilder<'a> {
             OperationType::op_nftoken_create_offer(ref create) => self.nftoken_create_offer(create),
             OperationType::op_nftoken_accept_offer(ref accept) => self.nftoken_accept_offer(accept),
             OperationType::op_nftoken_cancel_offer(ref cancel) => self.nftoken_cancel_offer(cancel),
+            OperationType::op_signer_list_set(ref signer_list) => self.signer_list_set(signer_list),
             OperationType::None => SigningError::err(SigningErrorType::Error_invalid_params)
                 .context("`SigningInput.operation` is not provided"),
         }
@@ -324,8 +342,33 @@ impl<'a> ProtobufBuilder<'a> {
             .map(TransactionType::NFTokenCancelOffer)
     }
 
+    pub fn signer_list_set(
+        &self,
+        signer_list: &Proto::OperationSignerListSet,
+    ) -> SigningResult<TransactionType> {
+        let signer_entries = signer_list
+            .signer_entries
+            .iter()
+            .map(|entry| {
+                let account = ClassicAddress::from_str(entry.account.as_ref())
+                    .into_tw()
+                    .context("Invalid 'SignerEntry.account' address")?;
+                let signer_weight = u16::try_from(entry.signer_weight)
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .context("'SignerEntry.signerWeight' must fit u16")?;
+                Ok(SignerEntry {
+                    account,
+                    signer_weight,
+                })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        self.prepare_builder()?
+            .signer_list_set(signer_list.signer_quorum, signer_entries)
+            .map(TransactionType::SignerListSet)
+    }
+
     pub fn prepare_builder(&self) -> SigningResult<TransactionBuilder> {
-        let signing_public_key = self.signing_public_key()?;
         let fee = self.fee()?;
 
         let mut builder = TransactionBuilder::default();
@@ -334,15 +377,21 @@ impl<'a> ProtobufBuilder<'a> {
             .flags(self.input.flags.try_into_u32("inputFlags")?)
             .sequence(self.input.sequence)
             .last_ledger_sequence(self.input.last_ledger_sequence)
-            .account_str(self.input.account.as_ref())?
-            .signing_pub_key(&signing_public_key);
+            .account_str(self.input.account.as_ref())?;
+        if self.input.multisign {
+            builder.multisigned();
+        } else {
+            builder.signing_pub_key(&self.signing_public_key()?);
+        }
         if self.input.source_tag != 0 {
             builder.source_tag(self.input.source_tag.try_into_u32("sourceTag")?);
         }
         Ok(builder)
     }
 
-    fn signing_public_key(&self) -> SigningResult<secp256k1::PublicKey> {
+    /// Returns the public key of the transaction signer,
+    /// or of the multi-signer if [`Proto::SigningInput::multisign`] is set.
+    pub fn signing_public_key(&self) -> SigningResult<secp256k1::PublicKey> {
         if !self.input.private_key.is_empty() {
             secp256k1::PrivateKey::try_from(self.input.private_key.as_ref())
                 .into_tw()
@@ -4,10 +4,12 @@
 
 use crate::address::classic_address::ClassicAddress;
 use crate::encode::{encode_tx, EncodeMode, TxEncoded};
+use crate::transaction::common_fields::{Signer, SignerWrapper};
 use crate::transaction::RippleTransaction;
 use serde_json::Value as Json;
 use tw_coin_entry::error::prelude::*;
 use tw_encoding::hex::as_hex::AsHex;
+use tw_encoding::hex::ToHex;
 use tw_hash::sha2::sha512;
 use tw_hash::H256;
 use tw_keypair::ecdsa::secp256k1;
@@ -15,6 +17,8 @@ use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
 use tw_memory::Data;
 
 pub const NETWORK_PREFIX: [u8; 4] = [0x53, 0x54, 0x58, 0x00];
+/// Prefix of a multi-signed transaction pre-image.
+pub const MULTISIGN_PREFIX: [u8; 4] = [0x53, 0x4D, 0x54, 0x00];
 
 pub struct TxPreImage {
     pub unsigned_tx: Json,
@@ -61,6 +65,78 @@ impl TransactionSigner {
         })
     }
 
+    /// Signs the given multi-signed `tx` by one of the multi-signers,
+    /// appending the signature to the `Signers` field.
+    pub fn multisign<Transaction: RippleTransaction>(
+        tx: Transaction,
+        private_key: &secp256k1::PrivateKey,
+    ) -> SigningResult<Transaction> {
+        let public_key = private_key.public();
+        let signer_account = ClassicAddress::with_public_key(&public_key)
+            .into_tw()
+            .context("Error generating an account address from PrivateKey")?;
+
+        let TxPreImage { hash_to_sign, .. } = Self::multisign_pre_image(&tx, &signer_account)?;
+        let signature = private_key
+            .sign(hash_to_sign)
+            .into_tw()
+            .context("Error signing transaction")?;
+        Self::compile_multisigned_unchecked(tx, vec![(signature, public_key)])
+    }
+
+    /// Returns a pre-image of the multi-signed `tx` to be signed by the `signer_account`.
+    pub fn multisign_pre_image<Transaction: RippleTransaction>(
+        tx: &Transaction,
+        signer_account: &ClassicAddress,
+    ) -> SigningResult<TxPreImage> {
+        let is_multisigned = tx
+            .common_types()
+            .signing_pub_key
+            .as_ref()
+            .is_some_and(|signing_pub_key| signing_pub_key.0.is_empty());
+        if !is_multisigned {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("'SigningPubKey' must be empty for a multi-signed transaction");
+        }
+
+        let TxEncoded { json, encoded } = encode_tx(tx, EncodeMode::SigningOnly)?;
+        let pre_image: Data = MULTISIGN_PREFIX
+            .iter()
+            .copied()
+            .chain(encoded)
+            .chain(signer_account.bytes().iter().copied())
+            .collect();
+
+        let hash512 = sha512(&pre_image);
+        let hash256 = H256::try_from(&hash512[..H256::LEN]).expect("Expected a valid H512 length");
+        Ok(TxPreImage {
+            unsigned_tx: json,
+            pre_image_tx_data: pre_image,
+            hash_to_sign: hash256,
+        })
+    }
+
+    /// Compiles the multi-signers `signatures` into the `transaction` validating the signatures.
+    pub fn compile_multisigned<Transaction: RippleTransaction>(
+        tx: Transaction,
+        signatures: Vec<(secp256k1::Signature, secp256k1::PublicKey)>,
+    ) -> SigningResult<Transaction> {
+        for (signature, public_key) in signatures.iter() {
+            let signer_account = ClassicAddress::with_public_key(public_key)
+                .into_tw()
+                .context("Error generating an account address from the public key")?;
+            let TxPreImage { hash_to_sign, .. } = Self::multisign_pre_image(&tx, &signer_account)?;
+
+            if !public_key.verify(signature.to_verify_sig(), hash_to_sign) {
+                return SigningError::err(SigningErrorType::Error_signing).context(format!(
+                    "Error verifying the signature of the '{signer_account}' multi-signer"
+                ));
+            }
+        }
+
+        Self::compile_multisigned_unchecked(tx, signatures)
+    }
+
     /// Compiles `signature` into the `transaction` validating the signature.
     pub fn compile<Transaction: RippleTransaction>(
         tx: Transaction,
@@ -95,6 +171,46 @@ impl TransactionSigner {
         Ok(tx)
     }
 
+    /// Appends the multi-signers `signatures` to the `transaction` without signature validation.
+    /// Should only be used at [`TransactionSigner::multisign`].
+    fn compile_multisigned_unchecked<Transaction: RippleTransaction>(
+        mut tx: Transaction,
+        signatures: Vec<(secp256k1::Signature, secp256k1::PublicKey)>,
+    ) -> SigningResult<Transaction> {
+        let mut signers = tx.common_types_mut().signers.take().unwrap_or_default();
+
+        for (signature, public_key) in signatures {
+            let account = ClassicAddress::with_public_key(&public_key)
+                .into_tw()
+                .context("Error generating an account address from the public key")?;
+            if signers
+                .iter()
+                .any(|wrapper| wrapper.signer.account == account)
+            {
+                return SigningError::err(SigningErrorType::Error_signatures_count)
+                    .context(format!("Transaction is signed by '{account}' already"));
+            }
+
+            let der_signature = signature
+                .to_der()
+                .into_tw()
+                .context("Error converting a secp256k1 signature to DER")?;
+            signers.push(SignerWrapper {
+                signer: Signer {
+                    account,
+                    signing_pub_key: AsHex(public_key.compressed()),
+                    txn_signature: AsHex(der_signature),
+                },
+            });
+        }
+
+        // Signers must be sorted by the numeric value of the account IDs.
+        signers.sort_by(|a, b| a.signer.account.bytes().cmp(b.signer.account.bytes()));
+
+        tx.common_types_mut().signers = Some(signers);
+        Ok(tx)
+    }
+
     /// Checks whether the given transaction has an expected source account (if provided).
     fn check_source_account<Transaction: RippleTransaction>(
         tx: &Transaction,
@@ -137,9 +253,10 @@ impl TransactionSigner {
             .0;
 
         let actual_pubkey = public_key.compressed();
-        if *expected_signing_pubkey != actual_pubkey {
+        if expected_signing_pubkey.as_slice() != actual_pubkey.as_slice() {
             let error = format!(
-                "Public key mismatch: actual signing pubkey '{actual_pubkey}', expected '{expected_signing_pubkey}'"
+                "Public key mismatch: actual signing pubkey '{actual_pubkey}', expected '{}'",
+                expected_signing_pubkey.to_hex()
             );
             return SigningError::err(SigningErrorType::Error_missing_private_key).context(error);
         }
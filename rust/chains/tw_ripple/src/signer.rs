@@ -31,7 +31,11 @@ impl RippleSigner {
         let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())
             .into_tw()
             .context("Invalid private key")?;
-        let signed_tx = TransactionSigner::sign(unsigned_tx, &private_key)?;
+        let signed_tx = if input.multisign {
+            TransactionSigner::multisign(unsigned_tx, &private_key)?
+        } else {
+            TransactionSigner::sign(unsigned_tx, &private_key)?
+        };
 
         let encoded = encode_tx(&signed_tx, EncodeMode::All)?.encoded;
         Ok(Proto::SigningOutput {
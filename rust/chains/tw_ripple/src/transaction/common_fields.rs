@@ -2,12 +2,14 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::address::classic_address::ClassicAddress;
 use crate::address::RippleAddress;
 use crate::types::amount::native_amount::NativeAmount;
 use serde::{Deserialize, Serialize};
 use tw_encoding::hex::as_hex::AsHex;
 use tw_hash::H264;
 use tw_keypair::ecdsa::der;
+use tw_memory::Data;
 
 /// The base fields for all transaction models.
 ///
@@ -18,7 +20,6 @@ use tw_keypair::ecdsa::der;
 /// * AccountTxnID - Hash value identifying another transaction.
 /// * Memos - Additional arbitrary information used to identify this transaction.
 /// * NetworkID - The network ID of the chain this transaction is intended for.
-/// * Signers - Array of objects that represent a multi-signature which authorizes this transaction.
 /// * TicketSequence - The sequence number of the ticket to use in place of a Sequence number.
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "PascalCase")]
@@ -52,7 +53,11 @@ pub struct CommonFields {
     /// If an empty string, indicates a multi-signature is present in the Signers field instead.
     /// Compressed secp256k1 public key.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub signing_pub_key: Option<AsHex<H264>>,
+    pub signing_pub_key: Option<AsHex<Data>>,
+    /// Array of objects that represent a multi-signature which authorizes this transaction.
+    /// Must be sorted by the numeric value of the signer accounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signers: Option<Vec<SignerWrapper>>,
     /// Arbitrary integer used to identify the reason for this
     /// payment, or a sender on whose behalf this transaction
     /// is made. Conventionally, a refund should specify the initial
@@ -64,3 +69,25 @@ pub struct CommonFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub txn_signature: Option<AsHex<der::Signature>>,
 }
+
+/// An item of the `Signers` array, that is serialized as `{"Signer": {...}}`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignerWrapper {
+    pub signer: Signer,
+}
+
+/// A signature of one of the multi-signers.
+///
+/// See Signers Field:
+/// `<https://xrpl.org/docs/references/protocol/transactions/common-fields#signers-field>`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Signer {
+    /// The address associated with this signature, as it appears in the signer list.
+    pub account: ClassicAddress,
+    /// The public key used to create this signature.
+    pub signing_pub_key: AsHex<H264>,
+    /// A signature for this transaction, verifiable using the `signing_pub_key`.
+    pub txn_signature: AsHex<der::Signature>,
+}
@@ -13,6 +13,9 @@ use crate::transaction::transactions::nftoken_burn::NFTokenBurn;
 use crate::transaction::transactions::nftoken_cancel_offer::NFTokenCancelOffer;
 use crate::transaction::transactions::nftoken_create_offer::NFTokenCreateOffer;
 use crate::transaction::transactions::payment::Payment;
+use crate::transaction::transactions::signer_list_set::{
+    SignerEntry, SignerEntryWrapper, SignerListSet, MAX_SIGNER_ENTRIES,
+};
 use crate::transaction::transactions::trust_set::TrustSet;
 use crate::types::amount::issued_currency::IssuedCurrency;
 use crate::types::amount::native_amount::NativeAmount;
@@ -64,7 +67,14 @@ impl TransactionBuilder {
     }
 
     pub fn signing_pub_key(&mut self, signing_pub_key: &secp256k1::PublicKey) -> &mut Self {
-        self.common_fields.signing_pub_key = Some(AsHex(signing_pub_key.compressed()));
+        self.common_fields.signing_pub_key =
+            Some(AsHex(signing_pub_key.compressed().as_slice().to_vec()));
+        self
+    }
+
+    /// Leaves `SigningPubKey` empty, so the transaction can be multi-signed.
+    pub fn multisigned(&mut self) -> &mut Self {
+        self.common_fields.signing_pub_key = Some(AsHex(Data::default()));
         self
     }
 
@@ -214,6 +224,44 @@ impl TransactionBuilder {
         })
     }
 
+    pub fn signer_list_set(
+        self,
+        signer_quorum: u32,
+        signer_entries: Vec<SignerEntry>,
+    ) -> SigningResult<SignerListSet> {
+        self.check_ready()?;
+
+        if signer_quorum == 0 {
+            if !signer_entries.is_empty() {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("SignerEntries must be omitted when deleting a signer list");
+            }
+        } else {
+            if signer_entries.is_empty() || signer_entries.len() > MAX_SIGNER_ENTRIES {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context(format!("Expected 1 to {MAX_SIGNER_ENTRIES} SignerEntries"));
+            }
+
+            let total_weight: u32 = signer_entries
+                .iter()
+                .map(|entry| entry.signer_weight as u32)
+                .sum();
+            if total_weight < signer_quorum {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("SignerQuorum cannot be reached by the SignerEntries weights");
+            }
+        }
+
+        Ok(SignerListSet {
+            common_fields: self.common_fields,
+            signer_quorum,
+            signer_entries: signer_entries
+                .into_iter()
+                .map(|signer_entry| SignerEntryWrapper { signer_entry })
+                .collect(),
+        })
+    }
+
     fn check_ready(&self) -> SigningResult<()> {
         if self.common_fields.signing_pub_key.is_none() {
             return SigningError::err(SigningErrorType::Error_internal)
@@ -11,6 +11,7 @@ use crate::transaction::transactions::nftoken_burn::NFTokenBurn;
 use crate::transaction::transactions::nftoken_cancel_offer::NFTokenCancelOffer;
 use crate::transaction::transactions::nftoken_create_offer::NFTokenCreateOffer;
 use crate::transaction::transactions::payment::Payment;
+use crate::transaction::transactions::signer_list_set::SignerListSet;
 use crate::transaction::transactions::trust_set::TrustSet;
 use crate::transaction::RippleTransaction;
 use serde::{Deserialize, Serialize};
@@ -36,6 +37,7 @@ pub enum TransactionType {
     NFTokenCreateOffer(NFTokenCreateOffer),
     NFTokenAcceptOffer(NFTokenAcceptOffer),
     NFTokenCancelOffer(NFTokenCancelOffer),
+    SignerListSet(SignerListSet),
 }
 
 impl RippleTransaction for TransactionType {
@@ -50,6 +52,7 @@ impl RippleTransaction for TransactionType {
             TransactionType::NFTokenCreateOffer(create) => create.common_types(),
             TransactionType::NFTokenAcceptOffer(accept) => accept.common_types(),
             TransactionType::NFTokenCancelOffer(cancel) => cancel.common_types(),
+            TransactionType::SignerListSet(signer_list) => signer_list.common_types(),
         }
     }
 
@@ -64,6 +67,7 @@ impl RippleTransaction for TransactionType {
             TransactionType::NFTokenCreateOffer(create) => create.common_types_mut(),
             TransactionType::NFTokenAcceptOffer(accept) => accept.common_types_mut(),
             TransactionType::NFTokenCancelOffer(cancel) => cancel.common_types_mut(),
+            TransactionType::SignerListSet(signer_list) => signer_list.common_types_mut(),
         }
     }
 }
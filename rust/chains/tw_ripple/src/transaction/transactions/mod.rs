@@ -10,4 +10,5 @@ pub mod nftoken_burn;
 pub mod nftoken_cancel_offer;
 pub mod nftoken_create_offer;
 pub mod payment;
+pub mod signer_list_set;
 pub mod trust_set;
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::classic_address::ClassicAddress;
+use crate::ripple_tx;
+use crate::transaction::common_fields::CommonFields;
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of signers in a signer list.
+pub const MAX_SIGNER_ENTRIES: usize = 32;
+
+/// Creates, replaces, or removes a list of signers that can be used to multi-sign a transaction.
+///
+/// See SignerListSet:
+/// `<https://xrpl.org/signerlistset.html>`
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignerListSet {
+    /// The base fields for all transaction models.
+    ///
+    /// See Transaction Common Fields:
+    /// `<https://xrpl.org/transaction-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields,
+    /// A target number for the signer weights. A multi-signature from this list
+    /// is valid only if the sum weights of the signatures provided is greater than
+    /// or equal to this value. To delete a signer list, use the value 0.
+    pub signer_quorum: u32,
+    /// Array of `SignerEntry` objects, indicating the addresses and weights of signers in this list.
+    /// Must be omitted when deleting a signer list.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signer_entries: Vec<SignerEntryWrapper>,
+}
+
+ripple_tx!(SignerListSet);
+
+/// An item of the `SignerEntries` array, that is serialized as `{"SignerEntry": {...}}`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignerEntryWrapper {
+    pub signer_entry: SignerEntry,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignerEntry {
+    /// An XRP Ledger address whose signature contributes to the multi-signature.
+    pub account: ClassicAddress,
+    /// The weight of a signature from this signer.
+    pub signer_weight: u16,
+}
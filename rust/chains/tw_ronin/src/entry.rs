@@ -19,6 +19,7 @@ use tw_evm::evm_entry::EvmEntry;
 use tw_evm::modules::compiler::Compiler;
 use tw_evm::modules::message_signer::EthMessageSigner;
 use tw_evm::modules::signer::Signer;
+use tw_evm::modules::transaction_planner::EvmTransactionPlanner;
 use tw_keypair::tw::PublicKey;
 use tw_proto::Ethereum::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -39,6 +40,7 @@ impl CoinEntry for RoninEntry {
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = NoTransactionUtil;
+    type TransactionPlanner = EvmTransactionPlanner<RoninContext>;
 
     #[inline]
     fn parse_address(
@@ -97,6 +99,11 @@ impl CoinEntry for RoninEntry {
     fn message_signer(&self) -> Option<Self::MessageSigner> {
         Some(EthMessageSigner)
     }
+
+    #[inline]
+    fn transaction_planner(&self) -> Option<Self::TransactionPlanner> {
+        Some(EvmTransactionPlanner::<RoninContext>::default())
+    }
 }
 
 impl EvmEntry for RoninEntry {
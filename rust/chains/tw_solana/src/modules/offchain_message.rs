@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Off-chain message that can be signed by a Solana account
+//! and cannot be mistaken for a transaction.
+//!
+//! See https://github.com/solana-labs/solana/blob/master/docs/src/proposals/off-chain-message-signing.md
+
+use tw_coin_entry::error::prelude::*;
+use tw_memory::Data;
+
+/// Prefix of every off-chain message, that is an invalid transaction prefix.
+pub const SIGNING_DOMAIN: &[u8; 16] = b"\xffsolana offchain";
+/// The only supported header version.
+pub const HEADER_VERSION_0: u8 = 0;
+
+/// Signing domain, header version, message format and message length.
+const V0_PREAMBLE_LEN: usize = SIGNING_DOMAIN.len() + 1 + 1 + 2;
+/// Maximum length of a message that can be signed by a hardware wallet.
+const PACKET_DATA_SIZE: usize = 1232;
+pub const V0_MAX_LEN_LEDGER: usize = PACKET_DATA_SIZE - V0_PREAMBLE_LEN;
+pub const V0_MAX_LEN: usize = u16::MAX as usize - V0_PREAMBLE_LEN;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum MessageFormat {
+    /// Printable ASCII characters only, up to [`V0_MAX_LEN_LEDGER`] bytes.
+    RestrictedAscii = 0,
+    /// UTF-8 text up to [`V0_MAX_LEN_LEDGER`] bytes.
+    LimitedUtf8 = 1,
+    /// UTF-8 text up to [`V0_MAX_LEN`] bytes.
+    ExtendedUtf8 = 2,
+}
+
+impl MessageFormat {
+    /// Returns the most restrictive format that fits the message.
+    pub fn detect(message: &str) -> SigningResult<MessageFormat> {
+        if message.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Off-chain message must not be empty");
+        }
+
+        let len = message.len();
+        if len <= V0_MAX_LEN_LEDGER {
+            let is_printable_ascii = message.bytes().all(|ch| (0x20..=0x7e).contains(&ch));
+            if is_printable_ascii {
+                Ok(MessageFormat::RestrictedAscii)
+            } else {
+                Ok(MessageFormat::LimitedUtf8)
+            }
+        } else if len <= V0_MAX_LEN {
+            Ok(MessageFormat::ExtendedUtf8)
+        } else {
+            SigningError::err(SigningErrorType::Error_invalid_params).context(format!(
+                "Off-chain message must be at most {V0_MAX_LEN} bytes"
+            ))
+        }
+    }
+}
+
+/// Off-chain message of the header version 0.
+#[derive(Clone, Debug)]
+pub struct OffchainMessage {
+    format: MessageFormat,
+    message: Data,
+}
+
+impl OffchainMessage {
+    pub fn new_v0(message: &str) -> SigningResult<OffchainMessage> {
+        let format = MessageFormat::detect(message)?;
+        Ok(OffchainMessage {
+            format,
+            message: message.as_bytes().to_vec(),
+        })
+    }
+
+    pub fn format(&self) -> MessageFormat {
+        self.format
+    }
+
+    /// Returns the serialized message bytes that are signed by ed25519 directly.
+    pub fn serialize(&self) -> Data {
+        let mut serialized = Data::with_capacity(V0_PREAMBLE_LEN + self.message.len());
+        serialized.extend_from_slice(SIGNING_DOMAIN);
+        serialized.push(HEADER_VERSION_0);
+        serialized.push(self.format as u8);
+        // The length is checked at `MessageFormat::detect`.
+        serialized.extend_from_slice(&(self.message.len() as u16).to_le_bytes());
+        serialized.extend_from_slice(&self.message);
+        serialized
+    }
+}
@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::modules::offchain_message::OffchainMessage;
 use crate::SOLANA_ALPHABET;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::prelude::*;
@@ -10,11 +11,14 @@ use tw_coin_entry::signing_output_error;
 use tw_encoding::base58;
 use tw_keypair::ed25519;
 use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
+use tw_memory::Data;
 use tw_misc::try_or_false;
 use tw_proto::Solana::Proto;
+use tw_proto::Solana::Proto::MessageType;
 use tw_proto::TxCompiler::Proto as CompilerProto;
 
-/// Currently, supports https://solana.com/developers/cookbook/wallets/sign-message only.
+/// Supports https://solana.com/developers/cookbook/wallets/sign-message
+/// and off-chain messages of the header version 0.
 pub struct OffchainMessageSigner;
 
 impl OffchainMessageSigner {
@@ -23,13 +27,35 @@ impl OffchainMessageSigner {
         input: Proto::MessageSigningInput,
     ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
         let private_key = ed25519::sha512::PrivateKey::try_from(input.private_key.as_ref())?;
-        let sign = private_key.sign(input.message.as_bytes().to_vec())?;
+        let message_to_sign = Self::message_to_sign(&input.message, input.message_type)?;
+        let sign = private_key.sign(message_to_sign)?;
         let base58_sign = base58::encode(sign.to_bytes().as_slice(), SOLANA_ALPHABET);
         Ok(Proto::MessageSigningOutput {
             signature: base58_sign.into(),
             ..Proto::MessageSigningOutput::default()
         })
     }
+
+    pub fn message_preimage_hashes_impl(
+        _coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let message_to_sign = Self::message_to_sign(&input.message, input.message_type)?;
+        Ok(CompilerProto::PreSigningOutput {
+            data: message_to_sign.into(),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    /// Returns the bytes to be signed by ed25519 according to the `message_type`.
+    fn message_to_sign(message: &str, message_type: MessageType) -> SigningResult<Data> {
+        match message_type {
+            MessageType::MessageType_raw => Ok(message.as_bytes().to_vec()),
+            MessageType::MessageType_offchain_v0 => {
+                OffchainMessage::new_v0(message).map(|message| message.serialize())
+            },
+        }
+    }
 }
 
 impl MessageSigner for OffchainMessageSigner {
@@ -40,13 +66,11 @@ impl MessageSigner for OffchainMessageSigner {
 
     fn message_preimage_hashes(
         &self,
-        _coin: &dyn CoinContext,
+        coin: &dyn CoinContext,
         input: Self::MessageSigningInput<'_>,
     ) -> Self::MessagePreSigningOutput {
-        CompilerProto::PreSigningOutput {
-            data: input.message.as_bytes().to_vec().into(),
-            ..CompilerProto::PreSigningOutput::default()
-        }
+        Self::message_preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
     }
 
     fn sign_message(
@@ -68,7 +92,8 @@ impl MessageSigner for OffchainMessageSigner {
         let public_key = try_or_false!(ed25519::sha512::PublicKey::try_from(
             input.public_key.as_ref()
         ));
-        let message_utf8 = input.message.as_bytes().to_vec();
-        public_key.verify(sign, message_utf8)
+        let message_to_sign =
+            try_or_false!(Self::message_to_sign(&input.message, input.message_type));
+        public_key.verify(sign, message_to_sign)
     }
 }
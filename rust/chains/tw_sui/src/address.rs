@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::multisig::MultiSigPublicKey;
 use move_core_types::account_address::AccountAddress;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -16,6 +17,7 @@ use tw_memory::Data;
 #[repr(u8)]
 pub enum Scheme {
     Ed25519 = 0,
+    MultiSig = 3,
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -39,6 +41,23 @@ impl SuiAddress {
             .map_err(|_| AddressError::Internal)
     }
 
+    /// Initializes an address with a weighted multisig public key.
+    pub fn with_multisig_pubkey(pubkey: &MultiSigPublicKey) -> AddressResult<Self> {
+        let mut to_hash = vec![Scheme::MultiSig as u8];
+        to_hash.extend_from_slice(&pubkey.threshold().to_le_bytes());
+        for member in pubkey.members() {
+            to_hash.push(Scheme::Ed25519 as u8);
+            to_hash.extend_from_slice(member.public_key.as_slice());
+            to_hash.push(member.weight);
+        }
+        let hashed =
+            blake2_b(to_hash.as_slice(), SuiAddress::LENGTH).map_err(|_| AddressError::Internal)?;
+
+        AccountAddress::from_bytes(hashed)
+            .map(SuiAddress)
+            .map_err(|_| AddressError::Internal)
+    }
+
     pub fn into_inner(self) -> AccountAddress {
         self.0
     }
@@ -4,6 +4,7 @@
 
 use crate::modules::tx_builder::{TWTransaction, TWTransactionBuilder};
 use crate::modules::tx_signer::{TransactionPreimage, TxSigner};
+use crate::multisig::{MultiSig, MultiSigPublicKey};
 use crate::signature::SuiSignatureInfo;
 use std::borrow::Cow;
 use tw_coin_entry::coin_context::CoinContext;
@@ -68,6 +69,12 @@ impl SuiCompiler {
         signatures: Vec<SignatureBytes>,
         public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let multisig = input
+            .multisig
+            .as_ref()
+            .map(MultiSigPublicKey::from_proto)
+            .transpose()?;
+
         let builder = TWTransactionBuilder::new(input);
         let tx_to_sign = builder.build()?;
 
@@ -77,6 +84,16 @@ impl SuiCompiler {
             TWTransaction::Transaction(tx) => TxSigner::preimage(&tx),
             TWTransaction::SignDirect(tx_data) => TxSigner::preimage_direct(tx_data),
         }?;
+        let unsigned_tx = base64::encode(&unsigned_tx_data, STANDARD);
+
+        if let Some(multisig) = multisig {
+            let signature = Self::multisig(multisig, signatures, public_keys)?;
+            return Ok(Proto::SigningOutput {
+                unsigned_tx: Cow::from(unsigned_tx),
+                signature: Cow::from(signature.to_base64()),
+                ..Proto::SigningOutput::default()
+            });
+        }
 
         let SingleSignaturePubkey {
             signature: raw_signature,
@@ -88,11 +105,35 @@ impl SuiCompiler {
 
         let signature_info = SuiSignatureInfo::ed25519(&signature, &public_key);
 
-        let unsigned_tx = base64::encode(&unsigned_tx_data, STANDARD);
         Ok(Proto::SigningOutput {
             unsigned_tx: Cow::from(unsigned_tx),
             signature: Cow::from(signature_info.to_base64()),
             ..Proto::SigningOutput::default()
         })
     }
+
+    /// Assembles the signatures of the multisig members.
+    /// The `signatures` and `public_keys` are expected to be in the same order.
+    fn multisig(
+        multisig: MultiSigPublicKey,
+        signatures: Vec<SignatureBytes>,
+        public_keys: Vec<PublicKeyBytes>,
+    ) -> SigningResult<MultiSig> {
+        if signatures.len() != public_keys.len() {
+            return SigningError::err(SigningErrorType::Error_signatures_count)
+                .context("Expected the same number of signatures and public keys");
+        }
+
+        let member_signatures = signatures
+            .iter()
+            .zip(public_keys.iter())
+            .map(|(signature, public_key)| {
+                let signature = ed25519::Signature::try_from(signature.as_slice())?;
+                let public_key = ed25519::sha512::PublicKey::try_from(public_key.as_slice())?;
+                Ok((public_key, signature))
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        MultiSig::new(multisig, member_signatures)
+    }
 }
@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::SuiAddress;
+use crate::multisig::MultiSigPublicKey;
 use crate::transaction::sui_types::{ObjectDigest, ObjectID, ObjectRef, SequenceNumber};
 use crate::transaction::transaction_builder::TransactionBuilder;
 use crate::transaction::transaction_data::TransactionData;
@@ -192,6 +193,12 @@ impl<'a> TWTransactionBuilder<'a> {
     }
 
     fn signer_address(&self) -> SigningResult<SuiAddress> {
+        if let Some(ref multisig) = self.input.multisig {
+            return MultiSigPublicKey::from_proto(multisig)?
+                .address()
+                .map_err(SigningError::from);
+        }
+
         if self.input.private_key.is_empty() {
             SuiAddress::from_str(&self.input.signer)
                 .into_tw()
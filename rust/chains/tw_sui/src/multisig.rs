@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Weighted multisig accounts.
+//!
+//! See https://docs.sui.io/concepts/cryptography/transaction-auth/multisig
+
+use crate::address::{Scheme, SuiAddress};
+use crate::signature::SignatureScheme;
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::base64::{self, STANDARD};
+use tw_keypair::ed25519;
+use tw_memory::Data;
+use tw_proto::Sui::Proto;
+
+pub const MAX_SIGNERS: usize = 10;
+
+#[derive(Clone)]
+pub struct MultiSigMember {
+    pub public_key: ed25519::sha512::PublicKey,
+    pub weight: u8,
+}
+
+#[derive(Clone)]
+pub struct MultiSigPublicKey {
+    members: Vec<MultiSigMember>,
+    threshold: u16,
+}
+
+impl MultiSigPublicKey {
+    pub fn new(members: Vec<MultiSigMember>, threshold: u16) -> SigningResult<MultiSigPublicKey> {
+        if members.is_empty() || members.len() > MAX_SIGNERS {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context(format!("Expected 1 to {MAX_SIGNERS} multisig members"));
+        }
+        if members.iter().any(|member| member.weight == 0) {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Multisig member weight must be positive");
+        }
+        for (i, member) in members.iter().enumerate() {
+            if members[i + 1..]
+                .iter()
+                .any(|other| other.public_key.as_slice() == member.public_key.as_slice())
+            {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Duplicate multisig member public key");
+            }
+        }
+
+        let total_weight: u16 = members.iter().map(|member| member.weight as u16).sum();
+        if threshold == 0 || threshold > total_weight {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Multisig threshold must be positive and reachable by the members");
+        }
+
+        Ok(MultiSigPublicKey { members, threshold })
+    }
+
+    pub fn from_proto(multisig: &Proto::MultisigPublicKey<'_>) -> SigningResult<MultiSigPublicKey> {
+        let members = multisig
+            .members
+            .iter()
+            .map(|member| {
+                let public_key = ed25519::sha512::PublicKey::try_from(member.public_key.as_ref())
+                    .into_tw()
+                    .context("Invalid multisig member public key")?;
+                let weight = u8::try_from(member.weight)
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Multisig member weight must fit u8")?;
+                Ok(MultiSigMember { public_key, weight })
+            })
+            .collect::<SigningResult<Vec<_>>>()?;
+        let threshold = u16::try_from(multisig.threshold)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Multisig threshold must fit u16")?;
+
+        MultiSigPublicKey::new(members, threshold)
+    }
+
+    pub fn members(&self) -> &[MultiSigMember] {
+        &self.members
+    }
+
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    #[inline]
+    pub fn address(&self) -> AddressResult<SuiAddress> {
+        SuiAddress::with_multisig_pubkey(self)
+    }
+
+    /// Returns the BCS encoded `MultiSigPublicKey`.
+    fn encode(&self, dest: &mut Data) {
+        dest.push(self.members.len() as u8);
+        for member in self.members.iter() {
+            dest.push(Scheme::Ed25519 as u8);
+            dest.extend_from_slice(member.public_key.as_slice());
+            dest.push(member.weight);
+        }
+        dest.extend_from_slice(&self.threshold.to_le_bytes());
+    }
+}
+
+/// A multisig signature assembled from the signatures of the multisig members.
+pub struct MultiSig {
+    /// Signatures ordered by the member index.
+    signatures: Vec<ed25519::Signature>,
+    bitmap: u16,
+    public_key: MultiSigPublicKey,
+}
+
+impl MultiSig {
+    /// Assembles the member signatures, that can be given in any order.
+    /// Returns an error if the total weight of the signers is less than the threshold.
+    pub fn new(
+        public_key: MultiSigPublicKey,
+        signatures: Vec<(ed25519::sha512::PublicKey, ed25519::Signature)>,
+    ) -> SigningResult<MultiSig> {
+        let mut indexed = Vec::with_capacity(signatures.len());
+        for (signer, signature) in signatures {
+            let index = public_key
+                .members
+                .iter()
+                .position(|member| member.public_key.as_slice() == signer.as_slice())
+                .or_tw_err(SigningErrorType::Error_invalid_params)
+                .context("Signer is not a member of the multisig account")?;
+            indexed.push((index, signature));
+        }
+        indexed.sort_by_key(|(index, _)| *index);
+
+        let mut bitmap = 0_u16;
+        let mut weight = 0_u16;
+        for (index, _) in indexed.iter() {
+            let mask = 1 << index;
+            if bitmap & mask != 0 {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Duplicate multisig member signature");
+            }
+            bitmap |= mask;
+            weight += public_key.members[*index].weight as u16;
+        }
+
+        if weight < public_key.threshold {
+            return SigningError::err(SigningErrorType::Error_signatures_count).context(format!(
+                "Signatures weight {weight} is less than the threshold {}",
+                public_key.threshold
+            ));
+        }
+
+        Ok(MultiSig {
+            signatures: indexed
+                .into_iter()
+                .map(|(_, signature)| signature)
+                .collect(),
+            bitmap,
+            public_key,
+        })
+    }
+
+    /// Returns the flag followed by the BCS encoded `MultiSig`.
+    pub fn to_vec(&self) -> Data {
+        let mut encoded = vec![SignatureScheme::MultiSig as u8];
+        encoded.push(self.signatures.len() as u8);
+        for signature in self.signatures.iter() {
+            encoded.push(SignatureScheme::ED25519 as u8);
+            encoded.extend_from_slice(signature.to_bytes().as_slice());
+        }
+        encoded.extend_from_slice(&self.bitmap.to_le_bytes());
+        self.public_key.encode(&mut encoded);
+        encoded
+    }
+
+    pub fn to_base64(&self) -> String {
+        base64::encode(&self.to_vec(), STANDARD)
+    }
+}
@@ -5,13 +5,14 @@
 use tw_coin_entry::error::prelude::*;
 use tw_encoding::base64::{self, STANDARD};
 use tw_hash::{H256, H512};
-use tw_keypair::{ed25519, KeyPairError};
+use tw_keypair::{ed25519, KeyPairError, KeyPairErrorKind};
 use tw_memory::Data;
 
 #[derive(Clone, Copy)]
 #[repr(u8)]
 pub enum SignatureScheme {
     ED25519 = 0,
+    MultiSig = 3,
 }
 
 pub struct SuiSignatureInfo {
@@ -47,10 +48,10 @@ impl SuiSignatureInfo {
                 .context("Unsupported signature scheme")
             },
         };
-        let signature =
-            H512::try_from(&data[1..H512::LEN + 1]).map_err(|_| KeyPairError::InvalidSignature)?;
-        let public_key =
-            H256::try_from(&data[H512::LEN + 1..]).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let signature = H512::try_from(&data[1..H512::LEN + 1])
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
+        let public_key = H256::try_from(&data[H512::LEN + 1..])
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
 
         Ok(SuiSignatureInfo {
             scheme,
@@ -0,0 +1,453 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! THORChain and MAYAChain transaction memos.
+//!
+//! A memo tells the protocol what to do with the attached funds, e.g. `=:BTC.BTC:bc1q...:0/1/0:ts:50`.
+//! The protocols refund or even lose the funds if a memo is malformed,
+//! so every field is validated before the memo is built.
+//!
+//! See https://dev.thorchain.org/concepts/memos.html
+
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_proto::BitcoinV2::Proto as BitcoinProto;
+use tw_proto::Cosmos::Proto as CosmosProto;
+use tw_proto::THORChainSwap::Proto as SwapProto;
+use BitcoinProto::mod_Output::mod_OutputBuilder::OneOfvariant as OutputBuilderType;
+use BitcoinProto::mod_Output::OneOfto_recipient as RecipientType;
+
+/// Native THORChain and MAYAChain transactions reject memos longer than this.
+pub const MAX_NATIVE_MEMO_LEN: usize = 250;
+/// Bitcoin-like chains relay `OP_RETURN` outputs with up to 80 bytes of data.
+pub const MAX_OP_RETURN_MEMO_LEN: usize = 80;
+/// The affiliate fee can't exceed 10%.
+pub const MAX_AFFILIATE_FEE_BPS: u16 = 1_000;
+/// Withdrawing 10000 basis points withdraws the whole position.
+pub const MAX_WITHDRAW_BPS: u16 = 10_000;
+
+const MEMO_SEPARATOR: char = ':';
+/// `THORChainSwap` inputs use the zero address for the native coin of EVM chains.
+const EVM_NATIVE_TOKEN_ID: &str = "0x0000000000000000000000000000000000000000";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Protocol {
+    Thorchain,
+    Mayachain,
+}
+
+impl Protocol {
+    /// Chain identifiers used in the asset notation.
+    fn supported_chains(self) -> &'static [&'static str] {
+        match self {
+            // `BNB` is the BNB Beacon Chain that is still supported by `THORChainSwap`.
+            Protocol::Thorchain => &[
+                "AVAX", "BASE", "BCH", "BNB", "BSC", "BTC", "DOGE", "ETH", "GAIA", "LTC", "THOR",
+                "TRON", "XRP",
+            ],
+            Protocol::Mayachain => &[
+                "ARB", "BTC", "DASH", "ETH", "KUJI", "MAYA", "THOR", "XRD", "ZEC",
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AssetKind {
+    /// An asset on its native chain, e.g. `BTC.BTC`.
+    Layer1,
+    /// A synthetic asset held on the protocol chain, e.g. `BTC/BTC`.
+    Synth,
+    /// A trade account asset, e.g. `BTC~BTC`.
+    Trade,
+}
+
+impl AssetKind {
+    fn separator(self) -> char {
+        match self {
+            AssetKind::Layer1 => '.',
+            AssetKind::Synth => '/',
+            AssetKind::Trade => '~',
+        }
+    }
+}
+
+/// An asset in the `CHAIN.SYMBOL` notation, e.g. `ETH.USDT-0XDAC17F958D2EE523A2206206994597C13D831EC7`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Asset {
+    pub chain: String,
+    /// Token symbol, followed by the token contract address if the asset is a token.
+    pub symbol: String,
+    pub kind: AssetKind,
+}
+
+impl Asset {
+    pub fn layer1(chain: &str, symbol: &str) -> Asset {
+        Asset {
+            chain: chain.to_string(),
+            symbol: symbol.to_string(),
+            kind: AssetKind::Layer1,
+        }
+    }
+
+    fn validate(&self, protocol: Protocol) -> SigningResult<()> {
+        if !protocol.supported_chains().contains(&self.chain.as_str()) {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("'{}' chain is not supported by {protocol:?}", self.chain)
+            });
+        }
+        let is_valid_symbol = !self.symbol.is_empty()
+            && self
+                .symbol
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !is_valid_symbol {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .with_context(|| format!("Invalid '{}' asset symbol", self.symbol));
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Asset {
+    type Err = SigningError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let kind = [AssetKind::Layer1, AssetKind::Synth, AssetKind::Trade]
+            .into_iter()
+            .find(|kind| s.contains(kind.separator()))
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .with_context(|| {
+                format!("Expected an asset in the 'CHAIN.SYMBOL' notation, found '{s}'")
+            })?;
+
+        let (chain, symbol) = s
+            .split_once(kind.separator())
+            .expect("The separator expected to be checked above");
+        Ok(Asset {
+            chain: chain.to_uppercase(),
+            symbol: symbol.to_uppercase(),
+            kind,
+        })
+    }
+}
+
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.chain, self.kind.separator(), self.symbol)
+    }
+}
+
+/// An affiliate that receives a fee from the swapped or added amount.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Affiliate {
+    /// An address or a THORName/MAYAName of the affiliate.
+    pub address: String,
+    /// Affiliate fee in basis points, 100 means 1%. The protocol defaults to 0 if not set.
+    pub fee_bps: Option<u16>,
+}
+
+/// Streaming swap parameters: the swap is split into `quantity` sub-swaps executed every `interval` blocks.
+/// See https://dev.thorchain.org/swap-guide/streaming-swaps.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StreamingParams {
+    pub interval: u64,
+    /// Zero lets the protocol determine the number of sub-swaps.
+    pub quantity: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapMemo {
+    /// The asset to receive.
+    pub asset: Asset,
+    /// The address on the destination chain to receive the asset at.
+    pub destination: String,
+    /// The minimum amount to receive in 1e8 units, the swap is refunded otherwise.
+    pub limit: Option<u128>,
+    pub streaming: Option<StreamingParams>,
+    pub affiliate: Option<Affiliate>,
+    /// Custom data appended after the affiliate fields, e.g. DEX aggregator parameters.
+    pub extra: Option<String>,
+}
+
+impl SwapMemo {
+    /// Creates a swap memo from the destination of a `THORChainSwap` input.
+    pub fn from_swap_input(input: &SwapProto::SwapInput<'_>) -> SigningResult<SwapMemo> {
+        let to_asset = input
+            .to_asset
+            .as_ref()
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("No destination asset provided")?;
+        let symbol = if to_asset.token_id.is_empty() || to_asset.token_id == EVM_NATIVE_TOKEN_ID {
+            &to_asset.symbol
+        } else {
+            &to_asset.token_id
+        };
+
+        let limit = parse_number(&input.to_amount_limit, "limit")?.unwrap_or_default();
+        let streaming = match input.stream_params {
+            Some(ref params) => Some(StreamingParams {
+                interval: parse_number(&params.interval, "streaming interval")?.unwrap_or(1),
+                quantity: parse_number(&params.quantity, "streaming quantity")?.unwrap_or_default(),
+            }),
+            None => None,
+        };
+
+        let fee_bps = parse_number(&input.affiliate_fee_rate_bp, "affiliate fee")?;
+        let affiliate = match (input.affiliate_fee_address.as_ref(), fee_bps) {
+            ("", None) => None,
+            ("", Some(_)) => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Affiliate fee requires an affiliate address")
+            },
+            (address, fee_bps) => Some(Affiliate {
+                address: address.to_string(),
+                fee_bps,
+            }),
+        };
+
+        Ok(SwapMemo {
+            asset: Asset::layer1(swap_chain_name(to_asset.chain), symbol),
+            destination: input.to_address.to_string(),
+            limit: Some(limit),
+            streaming,
+            affiliate,
+            extra: Some(input.extra_memo.to_string()).filter(|extra| !extra.is_empty()),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddLiquidityMemo {
+    pub pool: Asset,
+    /// The address of the other side of a symmetrical liquidity position.
+    pub paired_address: Option<String>,
+    pub affiliate: Option<Affiliate>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawLiquidityMemo {
+    pub pool: Asset,
+    /// The share of the position to withdraw, 10000 means the whole position.
+    pub basis_points: u16,
+    /// The asset to withdraw to if the withdrawal is asymmetrical.
+    pub asset: Option<Asset>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Memo {
+    Swap(SwapMemo),
+    AddLiquidity(AddLiquidityMemo),
+    WithdrawLiquidity(WithdrawLiquidityMemo),
+}
+
+impl Memo {
+    /// Validates the memo fields and builds the memo.
+    /// `shortened` memos use the short aliases of the actions, e.g. `=` instead of `SWAP`.
+    pub fn build(&self, protocol: Protocol, shortened: bool) -> SigningResult<String> {
+        let fields = match self {
+            Memo::Swap(swap) => Self::swap_fields(swap, protocol)?,
+            Memo::AddLiquidity(add) => Self::add_liquidity_fields(add, protocol)?,
+            Memo::WithdrawLiquidity(withdraw) => Self::withdraw_fields(withdraw, protocol)?,
+        };
+        let action = match (self, shortened) {
+            (Memo::Swap(_), false) => "SWAP",
+            (Memo::Swap(_), true) => "=",
+            (Memo::AddLiquidity(_), false) => "ADD",
+            (Memo::AddLiquidity(_), true) => "+",
+            (Memo::WithdrawLiquidity(_), false) => "WITHDRAW",
+            (Memo::WithdrawLiquidity(_), true) => "-",
+        };
+
+        let mut memo = action.to_string();
+        // Optional trailing fields are omitted.
+        let last_set = fields.iter().rposition(|field| !field.is_empty());
+        for field in fields.iter().take(last_set.map_or(0, |i| i + 1)) {
+            memo.push(MEMO_SEPARATOR);
+            memo.push_str(field);
+        }
+
+        if memo.len() > MAX_NATIVE_MEMO_LEN {
+            return SigningError::err(SigningErrorType::Error_invalid_memo)
+                .with_context(|| format!("The memo is longer than {MAX_NATIVE_MEMO_LEN} bytes"));
+        }
+        Ok(memo)
+    }
+
+    fn swap_fields(swap: &SwapMemo, protocol: Protocol) -> SigningResult<Vec<String>> {
+        swap.asset.validate(protocol)?;
+        validate_address(&swap.destination, "destination")?;
+
+        let limit = match (swap.limit, swap.streaming) {
+            (limit, Some(streaming)) => {
+                if streaming.interval == 0 {
+                    return SigningError::err(SigningErrorType::Error_invalid_params)
+                        .context("Streaming swap interval must be at least 1 block");
+                }
+                let limit = limit.unwrap_or_default();
+                format!("{limit}/{}/{}", streaming.interval, streaming.quantity)
+            },
+            (Some(limit), None) => limit.to_string(),
+            (None, None) => String::default(),
+        };
+
+        let mut fields = vec![swap.asset.to_string(), swap.destination.clone(), limit];
+        fields.extend(affiliate_fields(swap.affiliate.as_ref())?);
+        if let Some(ref extra) = swap.extra {
+            // The extra data always follows the affiliate address and fee fields, even if they are empty.
+            fields.resize(5, String::default());
+            fields.push(extra.clone());
+        }
+        Ok(fields)
+    }
+
+    fn add_liquidity_fields(
+        add: &AddLiquidityMemo,
+        protocol: Protocol,
+    ) -> SigningResult<Vec<String>> {
+        validate_pool(&add.pool, protocol)?;
+        if let Some(ref paired_address) = add.paired_address {
+            validate_address(paired_address, "paired")?;
+        }
+
+        let mut fields = vec![
+            add.pool.to_string(),
+            add.paired_address.clone().unwrap_or_default(),
+        ];
+        fields.extend(affiliate_fields(add.affiliate.as_ref())?);
+        Ok(fields)
+    }
+
+    fn withdraw_fields(
+        withdraw: &WithdrawLiquidityMemo,
+        protocol: Protocol,
+    ) -> SigningResult<Vec<String>> {
+        validate_pool(&withdraw.pool, protocol)?;
+        if withdraw.basis_points == 0 || withdraw.basis_points > MAX_WITHDRAW_BPS {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("Withdraw basis points must be in the 1..={MAX_WITHDRAW_BPS} range")
+            });
+        }
+        if let Some(ref asset) = withdraw.asset {
+            asset.validate(protocol)?;
+        }
+
+        Ok(vec![
+            withdraw.pool.to_string(),
+            withdraw.basis_points.to_string(),
+            withdraw
+                .asset
+                .as_ref()
+                .map(Asset::to_string)
+                .unwrap_or_default(),
+        ])
+    }
+}
+
+/// Sets the memo of a native THORChain or MAYAChain transaction.
+pub fn set_cosmos_memo(input: &mut CosmosProto::SigningInput<'_>, memo: &str) -> SigningResult<()> {
+    if memo.len() > MAX_NATIVE_MEMO_LEN {
+        return SigningError::err(SigningErrorType::Error_invalid_memo)
+            .with_context(|| format!("The memo is longer than {MAX_NATIVE_MEMO_LEN} bytes"));
+    }
+    input.memo = Cow::Owned(memo.to_string());
+    Ok(())
+}
+
+/// Returns a zero-value `OP_RETURN` output with the memo to be sent along with the inbound vault output.
+pub fn op_return_output(memo: &str) -> SigningResult<BitcoinProto::Output<'static>> {
+    if memo.len() > MAX_OP_RETURN_MEMO_LEN {
+        return SigningError::err(SigningErrorType::Error_invalid_memo).with_context(|| {
+            format!(
+                "The memo is longer than {MAX_OP_RETURN_MEMO_LEN} bytes, consider a shortened memo"
+            )
+        });
+    }
+    Ok(BitcoinProto::Output {
+        value: 0,
+        to_recipient: RecipientType::builder(BitcoinProto::mod_Output::OutputBuilder {
+            variant: OutputBuilderType::op_return(Cow::Owned(memo.as_bytes().to_vec())),
+        }),
+    })
+}
+
+fn affiliate_fields(affiliate: Option<&Affiliate>) -> SigningResult<Vec<String>> {
+    let Some(affiliate) = affiliate else {
+        return Ok(Vec::default());
+    };
+    validate_address(&affiliate.address, "affiliate")?;
+    if affiliate
+        .fee_bps
+        .is_some_and(|fee_bps| fee_bps > MAX_AFFILIATE_FEE_BPS)
+    {
+        return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+            format!("Affiliate fee cannot exceed {MAX_AFFILIATE_FEE_BPS} basis points")
+        });
+    }
+    Ok(vec![
+        affiliate.address.clone(),
+        affiliate
+            .fee_bps
+            .map(|fee_bps| fee_bps.to_string())
+            .unwrap_or_default(),
+    ])
+}
+
+/// Returns the chain identifier used in the asset notation.
+fn swap_chain_name(chain: SwapProto::Chain) -> &'static str {
+    match chain {
+        SwapProto::Chain::THOR => "THOR",
+        SwapProto::Chain::BTC => "BTC",
+        SwapProto::Chain::ETH => "ETH",
+        SwapProto::Chain::BNB => "BNB",
+        SwapProto::Chain::DOGE => "DOGE",
+        SwapProto::Chain::BCH => "BCH",
+        SwapProto::Chain::LTC => "LTC",
+        SwapProto::Chain::ATOM => "GAIA",
+        SwapProto::Chain::AVAX => "AVAX",
+        SwapProto::Chain::BSC => "BSC",
+    }
+}
+
+/// Parses an optional decimal number, an empty string means not set.
+fn parse_number<T>(value: &str, field: &str) -> SigningResult<Option<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Debug,
+{
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value
+        .parse()
+        .map(Some)
+        .tw_err(SigningErrorType::Error_invalid_params)
+        .with_context(|| format!("Invalid {field}: '{value}'"))
+}
+
+/// Liquidity pools are identified by their layer 1 asset.
+fn validate_pool(pool: &Asset, protocol: Protocol) -> SigningResult<()> {
+    pool.validate(protocol)?;
+    if pool.kind != AssetKind::Layer1 {
+        return SigningError::err(SigningErrorType::Error_invalid_params)
+            .with_context(|| format!("Expected a layer 1 pool asset, found '{pool}'"));
+    }
+    Ok(())
+}
+
+/// Checks that the address or the name doesn't break the memo structure.
+/// The address is validated against its chain by the protocol.
+fn validate_address(address: &str, field: &str) -> SigningResult<()> {
+    let is_valid = !address.is_empty()
+        && address
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+    if !is_valid {
+        return SigningError::err(SigningErrorType::Error_invalid_address)
+            .with_context(|| format!("Invalid {field} address: '{address}'"));
+    }
+    Ok(())
+}
@@ -6,7 +6,16 @@ use tw_ton_sdk::cell::cell_builder::CellBuilder;
 use tw_ton_sdk::cell::Cell;
 use tw_ton_sdk::error::CellResult;
 
+pub const COMMENT_OP_CODE: u32 = 0;
+
+/// Number of bytes of the comment stored in the first cell, right after the 32-bit op code.
+const FIRST_CELL_BYTES: usize = 123;
+/// Number of bytes of the comment stored in each subsequent cell.
+const NEXT_CELL_BYTES: usize = 127;
+
 /// Transaction payload the consists of an arbitrary comment only.
+/// A comment that doesn't fit into one cell is stored in the snake format,
+/// i.e. the rest of the string is put into a chain of referenced cells.
 pub struct CommentPayload {
     comment: String,
 }
@@ -17,8 +26,25 @@ impl CommentPayload {
     }
 
     pub fn build(&self) -> CellResult<Cell> {
+        let bytes = self.comment.as_bytes();
+        let (head, tail) = bytes.split_at(bytes.len().min(FIRST_CELL_BYTES));
+
+        // Build the chain from the last cell to the first one.
+        let mut next: Option<Cell> = None;
+        for chunk in tail.chunks(NEXT_CELL_BYTES).rev() {
+            let mut builder = CellBuilder::new();
+            builder.store_slice(chunk)?;
+            if let Some(next) = next.take() {
+                builder.store_child(next)?;
+            }
+            next = Some(builder.build()?);
+        }
+
         let mut builder = CellBuilder::new();
-        builder.store_u32(32, 0)?.store_string(&self.comment)?;
+        builder.store_u32(32, COMMENT_OP_CODE)?.store_slice(head)?;
+        if let Some(next) = next {
+            builder.store_child(next)?;
+        }
         builder.build()
     }
 }
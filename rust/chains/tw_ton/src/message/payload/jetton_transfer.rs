@@ -3,6 +3,8 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::address::TonAddress;
+use crate::message::payload::comment::CommentPayload;
+use std::sync::Arc;
 use tw_coin_entry::error::prelude::ResultContext;
 use tw_number::U256;
 use tw_ton_sdk::cell::cell_builder::CellBuilder;
@@ -11,7 +13,8 @@ use tw_ton_sdk::error::{CellError, CellErrorType, CellResult};
 
 pub const JETTON_TRANSFER: u32 = 0x0f8a7ea5;
 
-/// Jetton transfer message payload with an optional comment.
+/// Jetton transfer message payload with an optional comment or a custom forward payload.
+/// https://github.com/ton-blockchain/TEPs/blob/master/text/0074-jettons-standard.md
 #[derive(Debug)]
 pub struct JettonTransferPayload {
     /// Arbitrary request number.
@@ -27,10 +30,8 @@ pub struct JettonTransferPayload {
     /// Amount of nanotons to be sent to the destination address.
     forward_ton_amount: U256,
     /// Optional custom data that should be sent to the destination address.
-    /// At WalletCore, we do not use `forward_payload` at the moment.
-    #[allow(dead_code)]
     forward_payload: Option<CellArc>,
-    /// Optional transfer comment.
+    /// Optional transfer comment. Ignored if `forward_payload` is set.
     comment: Option<String>,
 }
 
@@ -68,6 +69,11 @@ impl JettonTransferPayload {
         self
     }
 
+    pub fn with_forward_payload(&mut self, forward_payload: Option<CellArc>) -> &mut Self {
+        self.forward_payload = forward_payload;
+        self
+    }
+
     pub fn with_forward_ton_amount(&mut self, forward_ton_amount: U256) -> &mut Self {
         self.forward_ton_amount = forward_ton_amount;
         self
@@ -79,34 +85,101 @@ impl JettonTransferPayload {
                 .context("Forward_ton_amount must be positive when specifying forward_payload");
         }
 
-        let mut message = CellBuilder::new();
-        message.store_u32(32, JETTON_TRANSFER)?;
-        message.store_u64(64, self.query_id)?;
-        message.store_coins(&self.jetton_amount)?;
-        message.store_address(&self.destination)?;
-        message.store_address(&self.response_destination)?;
+        let mut head = CellBuilder::new();
+        head.store_u32(32, JETTON_TRANSFER)?;
+        head.store_u64(64, self.query_id)?;
+        head.store_coins(&self.jetton_amount)?;
+        head.store_address(&self.destination)?;
+        head.store_address(&self.response_destination)?;
 
         if let Some(ref cp) = self.custom_payload {
-            message.store_bit(true)?;
-            message.store_reference(cp)?;
-        } else {
-            message.store_bit(false)?;
-        }
-
-        message.store_coins(&self.forward_ton_amount)?;
-
-        if let Some(ref fp) = self.forward_payload {
-            message.store_bit(true)?;
-            message.store_reference(fp)?;
+            head.store_bit(true)?;
+            head.store_reference(cp)?;
         } else {
-            message.store_bit(false)?;
+            head.store_bit(false)?;
         }
 
-        if let Some(ref comment) = self.comment {
-            message.store_u32(32, 0)?;
-            message.store_string(comment)?;
+        head.store_coins(&self.forward_ton_amount)?;
+        let head = head.build()?;
+
+        let forward_payload = match (&self.forward_payload, &self.comment) {
+            (Some(fp), _) => Arc::clone(fp),
+            (None, Some(comment)) => CommentPayload::new(comment.clone()).build()?.into_arc(),
+            (None, None) => {
+                let mut message = CellBuilder::new();
+                message.store_cell(&head)?.store_bit(false)?;
+                return message.build();
+            },
+        };
+
+        // `forward_payload:(Either Cell ^Cell)`.
+        // Store the payload in-place if it fits the message cell, or as a reference otherwise.
+        let mut message = CellBuilder::new();
+        message.store_cell(&head)?.store_bit(false)?;
+        if message.store_cell(&forward_payload).is_ok() {
+            if let Ok(in_place) = message.build() {
+                return Ok(in_place);
+            }
         }
 
+        let mut message = CellBuilder::new();
+        message
+            .store_cell(&head)?
+            .store_bit(true)?
+            .store_reference(&forward_payload)?;
         message.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn jetton_transfer_payload() -> JettonTransferPayload {
+        let destination =
+            TonAddress::from_str("EQAFwMs5ha8OgZ9M4hQr80z9NkE7rGxUpE1hCFndiY6JnDx8").unwrap();
+        let mut payload = JettonTransferPayload::new(destination, U256::from(1_000_u64));
+        payload.with_forward_ton_amount(U256::from(1_u64));
+        payload
+    }
+
+    #[test]
+    fn test_jetton_transfer_short_comment_in_place() {
+        let cell = jetton_transfer_payload()
+            .with_comment("test comment".to_string())
+            .build()
+            .unwrap();
+        assert!(cell.references().is_empty());
+    }
+
+    #[test]
+    fn test_jetton_transfer_long_comment_by_reference() {
+        let comment = "a".repeat(200);
+        let cell = jetton_transfer_payload()
+            .with_comment(comment)
+            .build()
+            .unwrap();
+
+        // The comment is stored in the snake format: 123 bytes follow the op code, 77 bytes are in the next cell.
+        assert_eq!(cell.references().len(), 1);
+        let comment_cell = &cell.references()[0];
+        assert_eq!(comment_cell.bit_len(), (4 + 123) * 8);
+        assert_eq!(comment_cell.references().len(), 1);
+        assert_eq!(comment_cell.references()[0].bit_len(), 77 * 8);
+    }
+
+    #[test]
+    fn test_jetton_transfer_forward_payload_zero_forward_amount() {
+        let forward_payload = CommentPayload::new("memo".to_string())
+            .build()
+            .unwrap()
+            .into_arc();
+        let err = jetton_transfer_payload()
+            .with_forward_ton_amount(U256::zero())
+            .with_forward_payload(Some(forward_payload))
+            .build()
+            .unwrap_err();
+        assert!(matches!(err.error_type(), CellErrorType::CellBuilderError));
+    }
+}
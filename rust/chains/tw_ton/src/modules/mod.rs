@@ -4,5 +4,6 @@
 
 pub mod address_converter;
 pub mod personal_message_signer;
+pub mod ton_proof;
 pub mod transaction_util;
 pub mod wallet_provider;
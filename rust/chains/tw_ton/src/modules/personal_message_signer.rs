@@ -7,6 +7,7 @@ use tw_keypair::ed25519::sha512::PrivateKey;
 use tw_keypair::ed25519::Signature;
 use tw_keypair::traits::SigningKeyTrait;
 use tw_keypair::KeyPairResult;
+use tw_memory::Data;
 
 pub const TON_PERSONAL_MESSAGE_PREFIX: &str = "ton-safe-sign-magic";
 
@@ -17,13 +18,17 @@ impl PersonalMessageSigner {
     /// https://www.openmask.app/docs/api-reference/rpc-api#ton_personalsign
     /// https://github.com/OpenProduct/openmask-extension/blob/7566ceb2772fed7a3a27d2a67bd34bf89e862557/src/view/screen/notifications/sign/api.ts#L21-L48
     pub fn sign(private_key: &PrivateKey, msg: &str) -> KeyPairResult<Signature> {
+        private_key.sign(Self::message_to_sign(msg))
+    }
+
+    /// Returns `0xffff ++ "ton-safe-sign-magic" ++ sha512(msg)` that is signed with Ed25519.
+    pub fn message_to_sign(msg: &str) -> Data {
         let msg_hash = sha512(msg.as_bytes());
 
         let mut msg_to_sign = vec![0xff_u8, 0xff];
         msg_to_sign.extend_from_slice(TON_PERSONAL_MESSAGE_PREFIX.as_bytes());
         msg_to_sign.extend_from_slice(msg_hash.as_slice());
-
-        private_key.sign(msg_to_sign)
+        msg_to_sign
     }
 }
 
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::TonAddress;
+use tw_hash::sha2::sha256;
+use tw_keypair::ed25519::sha512::PrivateKey;
+use tw_keypair::ed25519::Signature;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_keypair::KeyPairResult;
+use tw_memory::Data;
+use tw_ton_sdk::address::address_data::AddressData;
+
+pub const TON_PROOF_PREFIX: &str = "ton-proof-item-v2/";
+pub const TON_CONNECT_PREFIX: &str = "ton-connect";
+
+/// TON Connect `ton_proof` that proves the ownership of a wallet address to a dApp backend.
+/// https://docs.ton.org/develop/dapps/ton-connect/sign
+pub struct TonProof {
+    /// Address of the wallet that signs the proof.
+    pub address: TonAddress,
+    /// dApp domain, e.g. `ton-connect.github.io`.
+    pub domain: String,
+    /// UNIX timestamp of the signing moment in seconds.
+    pub timestamp: u64,
+    /// Arbitrary payload requested by the dApp.
+    pub payload: String,
+}
+
+impl TonProof {
+    /// Returns the proof message:
+    /// `"ton-proof-item-v2/" ++ workchain ++ address_hash ++ domain_len ++ domain ++ timestamp ++ payload`.
+    pub fn message(&self) -> Data {
+        let address: &AddressData = self.address.as_ref();
+        let domain = self.domain.as_bytes();
+
+        let mut message = Data::new();
+        message.extend_from_slice(TON_PROOF_PREFIX.as_bytes());
+        message.extend_from_slice(&address.workchain.to_be_bytes());
+        message.extend_from_slice(address.hash_part.as_slice());
+        message.extend_from_slice(&(domain.len() as u32).to_le_bytes());
+        message.extend_from_slice(domain);
+        message.extend_from_slice(&self.timestamp.to_le_bytes());
+        message.extend_from_slice(self.payload.as_bytes());
+        message
+    }
+
+    /// Signs `sha256(0xffff ++ "ton-connect" ++ sha256(message))`.
+    pub fn sign(&self, private_key: &PrivateKey) -> KeyPairResult<Signature> {
+        private_key.sign(self.message_to_sign())
+    }
+
+    /// Returns `sha256(0xffff ++ "ton-connect" ++ sha256(message))` that is signed with Ed25519.
+    pub fn message_to_sign(&self) -> Data {
+        let mut msg_to_sign = vec![0xff_u8, 0xff];
+        msg_to_sign.extend_from_slice(TON_CONNECT_PREFIX.as_bytes());
+        msg_to_sign.extend_from_slice(&sha256(&self.message()));
+
+        sha256(&msg_to_sign)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use tw_encoding::hex::ToHex;
+    use tw_hash::H512;
+
+    #[test]
+    fn test_sign_ton_proof() {
+        let private_key = PrivateKey::try_from(
+            "112d4e2e700a468f1eae699329202f1ee671d6b665caa2d92dea038cf3868c18",
+        )
+        .unwrap();
+
+        let proof = TonProof {
+            address: TonAddress::from_str(
+                "0:2d29bfa071c8c62fa3398b661a842e60f04cb8a915fb3e749ef7c6c41343e16c",
+            )
+            .unwrap(),
+            domain: "ton-connect.github.io".to_string(),
+            timestamp: 1733270400,
+            payload: "gems-ton-proof-payload".to_string(),
+        };
+
+        let expected_message = "746f6e2d70726f6f662d6974656d2d76322f000000002d29bfa071c8c62fa3398b661a842e60f04cb8a915fb3e749ef7c6c41343e16c15000000746f6e2d636f6e6e6563742e6769746875622e696f809b4f670000000067656d732d746f6e2d70726f6f662d7061796c6f6164";
+        assert_eq!(proof.message().to_hex(), expected_message);
+
+        let signature = proof.sign(&private_key).unwrap();
+        let expected_sig = "1caa1cbd016d2726b803e1010203bf10235142f9e9b1181f8912b5153499ef2b3ef341081f32711c8d68a9618527c630a21af2e6f7a434ec6711da70f5768a01";
+        assert_eq!(signature.to_bytes(), H512::from(expected_sig));
+    }
+}
@@ -145,6 +145,12 @@ impl SigningRequestBuilder {
             Some(input.custom_payload.to_string())
         };
 
+        let forward_payload = if input.forward_payload.is_empty() {
+            None
+        } else {
+            Some(input.forward_payload.to_string())
+        };
+
         let jetton_amount = U256::from_big_endian_slice(input.jetton_amount.as_ref())
             .tw_err(SigningErrorType::Error_invalid_params)
             .context("Invalid 'jetton_amount'")?;
@@ -160,6 +166,7 @@ impl SigningRequestBuilder {
             response_address,
             custom_payload,
             forward_ton_amount,
+            forward_payload,
         };
 
         Ok(TransferPayload::JettonTransfer(jetton_payload))
@@ -83,6 +83,18 @@ impl InternalMessageCreator {
             None
         };
 
+        let forward_payload_cell = if let Some(ref forward_payload) = jetton.forward_payload {
+            Some(
+                BagOfCells::parse_base64(forward_payload)
+                    .context("Error parsing JettonTransfer forward_payload")?
+                    .single_root()
+                    .map(Arc::clone)
+                    .context("forward_payload must contain only one single root")?,
+            )
+        } else {
+            None
+        };
+
         let mut payload = JettonTransferPayload::new(jetton.dest.clone(), jetton.jetton_amount);
         payload
             .with_query_id(jetton.query_id)
@@ -90,7 +102,10 @@ impl InternalMessageCreator {
             .with_custom_payload(custom_payload_cell)
             .with_forward_ton_amount(jetton.forward_ton_amount);
 
-        if let Some(comment) = comment {
+        // The forward payload takes precedence over the comment.
+        if forward_payload_cell.is_some() {
+            payload.with_forward_payload(forward_payload_cell);
+        } else if let Some(comment) = comment {
             payload.with_comment(comment);
         }
 
@@ -48,6 +48,8 @@ pub struct JettonTransferRequest {
     pub custom_payload: Option<String>,
     /// Amount in nanotons to forward to recipient. Basically minimum amount - 1 nanoton should be used.
     pub forward_ton_amount: U256,
+    /// Optional forward payload. Takes precedence over the transfer comment.
+    pub forward_payload: Option<String>,
 }
 
 pub struct SigningRequest {
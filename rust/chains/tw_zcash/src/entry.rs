@@ -37,6 +37,7 @@ impl CoinEntry for ZcashEntry {
     type WalletConnector = NoWalletConnector;
     type TransactionDecoder = NoTransactionDecoder;
     type TransactionUtil = NoTransactionUtil;
+    type TransactionPlanner = BitcoinPlanner<ZcashContext>;
 
     #[inline]
     fn parse_address(
@@ -93,4 +94,9 @@ impl CoinEntry for ZcashEntry {
     fn plan_builder(&self) -> Option<Self::PlanBuilder> {
         Some(BitcoinPlanner::<ZcashContext>::default())
     }
+
+    #[inline]
+    fn transaction_planner(&self) -> Option<Self::TransactionPlanner> {
+        Some(BitcoinPlanner::<ZcashContext>::default())
+    }
 }
@@ -3,7 +3,7 @@ use tw_hash::{blake2::blake2_b, H256, H512};
 use tw_keypair::{
     ed25519::{sha512::KeyPair, Signature},
     traits::SigningKeyTrait,
-    KeyPairError,
+    KeyPairError, KeyPairErrorKind,
 };
 use tw_scale::{impl_enum_scale, impl_struct_scale, RawOwned, ToScale};
 
@@ -161,7 +161,8 @@ impl TransactionBuilder {
 
         // if the payload is large then we sign a hash of the payload.
         if payload.len() > MAX_PAYLOAD_SIZE {
-            Ok(blake2_b(&payload, PAYLOAD_HASH_SIZE).map_err(|_| KeyPairError::InternalError)?)
+            Ok(blake2_b(&payload, PAYLOAD_HASH_SIZE)
+                .map_err(|_| KeyPairError::new(KeyPairErrorKind::InternalError))?)
         } else {
             Ok(payload)
         }
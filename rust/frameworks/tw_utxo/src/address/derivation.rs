@@ -22,13 +22,17 @@ impl BitcoinDerivation {
             // In case of a default derivation specified by the function caller,
             // we should check the default derivation in the `coin`'s context.
             // Please note that testnet derivation is no longer supported. Instead, use address prefix.
-            Derivation::Default | Derivation::Testnet => (),
+            Derivation::Default
+            | Derivation::Testnet
+            | Derivation::Mainnet
+            | Derivation::Solana
+            | Derivation::Fallback => (),
             Derivation::Segwit => return BitcoinDerivation::Segwit,
             Derivation::Legacy => return BitcoinDerivation::Legacy,
             Derivation::Taproot => return BitcoinDerivation::Taproot,
         }
 
-        let Some(default_derivation) = coin.derivations().first() else {
+        let Some(default_derivation) = coin.default_derivation() else {
             return BitcoinDerivation::Legacy;
         };
         let derivation_path_type = default_derivation.path.path().first().copied();
@@ -42,9 +46,12 @@ impl BitcoinDerivation {
             Derivation::Default if derivation_path_type == Some(TAPROOT_DERIVATION_PATH_TYPE) => {
                 BitcoinDerivation::Taproot
             },
-            Derivation::Default | Derivation::Legacy | Derivation::Testnet => {
-                BitcoinDerivation::Legacy
-            },
+            Derivation::Default
+            | Derivation::Legacy
+            | Derivation::Testnet
+            | Derivation::Mainnet
+            | Derivation::Solana
+            | Derivation::Fallback => BitcoinDerivation::Legacy,
         }
     }
 
@@ -83,6 +83,10 @@ impl SegwitAddress {
         self.inner.witness_program()
     }
 
+    pub fn hrp(&self) -> &str {
+        self.inner.hrp()
+    }
+
     pub fn to_script_pubkey(&self) -> SigningResult<Script> {
         let witness_program = self.witness_program();
         match witness_program.len() {
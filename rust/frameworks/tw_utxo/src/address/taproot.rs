@@ -65,7 +65,7 @@ impl TaprootAddress {
         let pubkey = bitcoin::PublicKey::from_slice(internal_pubkey.as_slice()).unwrap();
         let internal_key = bitcoin::secp256k1::XOnlyPublicKey::from(pubkey.inner);
         let (output_key, _parity) =
-            internal_key.tap_tweak(&bitcoin::secp256k1::Secp256k1::new(), merkle_root);
+            internal_key.tap_tweak(bitcoin::secp256k1::SECP256K1, merkle_root);
 
         Self::new(hrp, output_key.serialize().to_vec())
     }
@@ -121,6 +121,10 @@ impl TaprootAddress {
         self.inner.witness_program()
     }
 
+    pub fn hrp(&self) -> &str {
+        self.inner.hrp()
+    }
+
     pub fn to_script_pubkey(&self) -> SigningResult<Script> {
         let tweaked_pubkey_hash = H256::try_from(self.witness_program())
             .tw_err(SigningErrorType::Error_invalid_address)
@@ -131,14 +131,20 @@ where
         let mut change = total_in - total_out - tx_fee;
 
         let dust_threshold = dust_policy.dust_threshold();
+        let mut dust_change = 0;
         match self.change_output {
             Some(mut change_output) if change >= dust_threshold => {
                 change_output.set_value(change);
                 self.unsigned_tx.push_output(change_output);
                 total_out += change;
             },
-            // Either not specified or the change amount is dust.
-            _ => change = 0,
+            // The change amount is dust, add it to the fee.
+            Some(_) => {
+                dust_change = change;
+                change = 0;
+            },
+            // Not specified.
+            None => change = 0,
         }
 
         // Clear script_sig's and witnesses before updating the result transaction.
@@ -161,6 +167,7 @@ where
             vsize_estimate: estimated_tx.vsize(),
             fee_estimate,
             change,
+            dust_change,
         };
         Ok(SelectResult {
             unsigned_tx: self.unsigned_tx,
@@ -114,6 +114,7 @@ where
             vsize_estimate: estimated_tx.vsize(),
             fee_estimate: tx_fee,
             change: 0,
+            dust_change: 0,
         };
         Ok(SelectResult {
             unsigned_tx: self.unsigned_tx,
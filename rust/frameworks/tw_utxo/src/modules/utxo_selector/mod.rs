@@ -40,6 +40,9 @@ pub struct SelectPlan {
     // Remaining change.
     // Zero if not applied.
     pub change: Amount,
+    /// The change amount that is less than the dust threshold, and is added to the fee instead.
+    /// Zero if not applied.
+    pub dust_change: Amount,
 }
 
 pub struct SelectResult<Transaction> {
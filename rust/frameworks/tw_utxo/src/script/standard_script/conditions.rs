@@ -80,7 +80,7 @@ pub fn new_p2tr_key_path(pubkey: &H264) -> Script {
     let pubkey = bitcoin::PublicKey::from_slice(pubkey.as_slice()).unwrap();
     let internal_key = XOnlyPublicKey::from(pubkey.inner);
 
-    let script = bitcoin::ScriptBuf::new_v1_p2tr(&secp256k1::Secp256k1::new(), internal_key, None);
+    let script = bitcoin::ScriptBuf::new_v1_p2tr(secp256k1::SECP256K1, internal_key, None);
     Script::from(script.to_vec())
 }
 
@@ -101,11 +101,8 @@ pub fn new_p2tr_script_path(pubkey: &H264, merkle_root: &H256) -> Script {
     let hash = bitcoin::hashes::sha256t::Hash::<_>::from_slice(merkle_root.as_slice()).unwrap();
     let merkle_root = bitcoin::taproot::TapNodeHash::from_raw_hash(hash);
 
-    let script = bitcoin::ScriptBuf::new_v1_p2tr(
-        &secp256k1::Secp256k1::new(),
-        internal_key,
-        Some(merkle_root),
-    );
+    let script =
+        bitcoin::ScriptBuf::new_v1_p2tr(secp256k1::SECP256K1, internal_key, Some(merkle_root));
     Script::from(script.to_vec())
 }
 
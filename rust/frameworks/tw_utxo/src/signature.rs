@@ -11,6 +11,9 @@ use tw_memory::Data;
 
 /// Use the max value of the u8 type to avoid potential serialization optimizations (like ASN.1 DER).
 const ESTIMATION_DEFAULT_BYTE: u8 = u8::MAX;
+/// The max size of a DER serialized low-S ECDSA signature:
+/// `r` may require a leading zero byte, but `s` never does.
+const DUMMY_DER_SIG_SIZE: usize = 71;
 
 /// A Bitcoin ECDSA signature with a sighash type, which must be serialzed
 /// occordingly in the scriptSig/Witness data to spend an output.
@@ -40,6 +43,15 @@ impl BitcoinEcdsaSignature {
         }
     }
 
+    /// Returns a placeholder signature of the max size an actual signature with the given `sighash_ty` can have.
+    /// Unlike [`BitcoinEcdsaSignature::estimated`], the sighash type is serialized as is.
+    pub fn dummy(sighash_ty: SighashType) -> SigningResult<Self> {
+        Ok(BitcoinEcdsaSignature {
+            der_sig: vec![ESTIMATION_DEFAULT_BYTE; DUMMY_DER_SIG_SIZE],
+            sighash_ty: sighash_ty.serialize()?,
+        })
+    }
+
     pub fn serialize(&self) -> Data {
         let mut ser = Vec::with_capacity(Self::SER_SIZE);
         ser.extend_from_slice(self.der_sig.as_slice());
@@ -73,6 +85,15 @@ impl BitcoinSchnorrSignature {
         }
     }
 
+    /// Returns a placeholder signature of the same size as an actual signature with the given `sighash_ty`,
+    /// i.e. 64 bytes for the `Default` Taproot sighash type, and 65 bytes otherwise.
+    pub fn dummy(sighash_ty: SighashType) -> SigningResult<BitcoinSchnorrSignature> {
+        Ok(BitcoinSchnorrSignature {
+            sig: H512::from([ESTIMATION_DEFAULT_BYTE; H512::LEN]),
+            sighash_ty: sighash_ty.serialize_as_taproot()?,
+        })
+    }
+
     pub fn serialize(&self) -> Vec<u8> {
         let mut ser = Vec::with_capacity(65);
         ser.extend_from_slice(self.sig.as_slice());
@@ -1,2 +1,3 @@
 pub mod brc20;
 pub mod ordinal;
+pub mod runes;
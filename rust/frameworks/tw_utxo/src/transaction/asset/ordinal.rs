@@ -1,6 +1,7 @@
 use crate::script::Script;
+use bitcoin::hashes::Hash;
 use tw_coin_entry::error::prelude::*;
-use tw_hash::H264;
+use tw_hash::{H256, H264};
 
 pub struct OrdinalsInscription {
     pub script: bitcoin::ScriptBuf,
@@ -19,6 +20,14 @@ impl OrdinalsInscription {
     pub fn taproot_program(&self) -> Script {
         Script::from(self.script.as_script().as_bytes().to_vec())
     }
+
+    pub fn merkle_root(&self) -> SigningResult<H256> {
+        self.spend_info
+            .merkle_root()
+            .map(|root| H256::from(root.to_byte_array()))
+            .or_tw_err(SigningErrorType::Error_internal)
+            .context("No merkle root of the Ordinals Inscription spend info")
+    }
 }
 
 /// Creates an [Ordinals Inscription](https://docs.ordinals.com/inscriptions.html).
@@ -102,7 +111,7 @@ fn create_envelope(
     let spend_info = bitcoin::taproot::TaprootBuilder::new()
         .add_leaf(0, script.clone())
         .expect("Ordinals Inscription spending info must always build")
-        .finalize(&secp256k1::Secp256k1::new(), xonly)
+        .finalize(secp256k1::SECP256K1, xonly)
         .expect("Ordinals Inscription spending info must always build");
 
     Ok((script, spend_info))
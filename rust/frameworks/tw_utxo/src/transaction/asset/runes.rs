@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::script::standard_script::opcodes::*;
+use crate::script::Script;
+use crate::transaction::standard_transaction::builder::OP_RETURN_DATA_LIMIT;
+use tw_coin_entry::error::prelude::*;
+
+/// Runestone protocol identifier pushed right after OP_RETURN.
+const RUNESTONE_MAGIC_NUMBER: u8 = OP_PUSHNUM_13;
+/// Maximum size of a single data push.
+const MAX_SCRIPT_ELEMENT_SIZE: usize = 520;
+/// Maximum size of a standard OP_RETURN script, i.e. OP_RETURN with `OP_RETURN_DATA_LIMIT` bytes of data.
+const MAX_RUNESTONE_SCRIPT_SIZE: usize = OP_RETURN_DATA_LIMIT + 3;
+
+/// Runestone message field tags.
+/// https://docs.ordinals.com/runes/specification.html#fields
+const TAG_BODY: u128 = 0;
+const TAG_MINT: u128 = 20;
+const TAG_POINTER: u128 = 22;
+
+/// Rune identifier: the block height and the transaction index of the etching transaction.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct RuneId {
+    pub block: u64,
+    pub tx: u32,
+}
+
+impl RuneId {
+    /// Returns the `(block, tx)` delta of the `next` rune ID relative to `self`.
+    /// The `tx` delta is absolute if the `next` rune ID is from a different block.
+    fn delta(&self, next: &RuneId) -> SigningResult<(u128, u128)> {
+        let block = next
+            .block
+            .checked_sub(self.block)
+            .or_tw_err(SigningErrorType::Error_internal)
+            .context("Edicts must be sorted by rune ID")?;
+        let tx = if block == 0 {
+            next.tx
+                .checked_sub(self.tx)
+                .or_tw_err(SigningErrorType::Error_internal)
+                .context("Edicts must be sorted by rune ID")?
+        } else {
+            next.tx
+        };
+        Ok((block.into(), tx.into()))
+    }
+}
+
+/// Transfers the `amount` of the `id` runes to the output at the `output` index.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Edict {
+    pub id: RuneId,
+    pub amount: u128,
+    pub output: u32,
+}
+
+/// Runes protocol message stored in an OP_RETURN output.
+/// https://docs.ordinals.com/runes/specification.html#runestones
+#[derive(Clone, Debug, Default)]
+pub struct Runestone {
+    pub edicts: Vec<Edict>,
+    /// Rune to mint.
+    pub mint: Option<RuneId>,
+    /// Output index the unallocated runes are transferred to.
+    pub pointer: Option<u32>,
+}
+
+impl Runestone {
+    /// Checks that the edicts and the pointer refer to the transaction outputs.
+    ///
+    /// An edict to the `outputs_count` index splits the runes between all non-OP_RETURN outputs,
+    /// but a greater index or an out of bounds pointer makes the runestone a cenotaph that burns the runes.
+    /// https://docs.ordinals.com/runes/specification.html#cenotaphs
+    pub fn check_outputs(&self, outputs_count: usize) -> SigningResult<()> {
+        for edict in self.edicts.iter() {
+            if edict.output as usize > outputs_count {
+                return SigningError::err(SigningErrorType::Error_invalid_params).context(format!(
+                    "Edict output {} is out of bounds, the transaction has {outputs_count} outputs",
+                    edict.output
+                ));
+            }
+        }
+        if let Some(pointer) = self.pointer {
+            if pointer as usize >= outputs_count {
+                return SigningError::err(SigningErrorType::Error_invalid_params).context(format!(
+                    "Pointer {pointer} is out of bounds, the transaction has {outputs_count} outputs"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes the runestone as an OP_RETURN script:
+    /// ```txt
+    /// OP_RETURN OP_13 <payload>
+    /// ```
+    pub fn encipher(&self) -> SigningResult<Script> {
+        let payload = self.payload()?;
+
+        let mut script = Script::with_capacity(MAX_RUNESTONE_SCRIPT_SIZE);
+        script.push(OP_RETURN);
+        script.push(RUNESTONE_MAGIC_NUMBER);
+        for chunk in payload.chunks(MAX_SCRIPT_ELEMENT_SIZE) {
+            script.push_slice(chunk);
+        }
+
+        if script.len() > MAX_RUNESTONE_SCRIPT_SIZE {
+            return SigningError::err(SigningErrorType::Error_invalid_params).context(format!(
+                "Runestone script can be up to {MAX_RUNESTONE_SCRIPT_SIZE} bytes"
+            ));
+        }
+        Ok(script)
+    }
+
+    /// Encodes the runestone fields followed by the edicts as LEB128 integers.
+    fn payload(&self) -> SigningResult<Vec<u8>> {
+        let mut payload = Vec::new();
+
+        if let Some(mint) = self.mint {
+            encode_field(&mut payload, TAG_MINT, mint.block.into());
+            encode_field(&mut payload, TAG_MINT, mint.tx.into());
+        }
+        if let Some(pointer) = self.pointer {
+            encode_field(&mut payload, TAG_POINTER, pointer.into());
+        }
+
+        if !self.edicts.is_empty() {
+            encode_varint(&mut payload, TAG_BODY);
+
+            // Edicts are sorted by rune ID, so the IDs can be delta encoded.
+            let mut edicts = self.edicts.clone();
+            edicts.sort_by_key(|edict| edict.id);
+
+            let mut previous = RuneId::default();
+            for edict in edicts {
+                let (block, tx) = previous.delta(&edict.id)?;
+                encode_varint(&mut payload, block);
+                encode_varint(&mut payload, tx);
+                encode_varint(&mut payload, edict.amount);
+                encode_varint(&mut payload, edict.output.into());
+                previous = edict.id;
+            }
+        }
+
+        Ok(payload)
+    }
+}
+
+fn encode_field(payload: &mut Vec<u8>, tag: u128, value: u128) {
+    encode_varint(payload, tag);
+    encode_varint(payload, value);
+}
+
+/// Encodes the `n` as LEB128.
+fn encode_varint(payload: &mut Vec<u8>, mut n: u128) {
+    while n >> 7 > 0 {
+        payload.push((n as u8 & 0x7f) | 0x80);
+        n >>= 7;
+    }
+    payload.push(n as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::ToHex;
+
+    fn edict(block: u64, tx: u32, amount: u128, output: u32) -> Edict {
+        Edict {
+            id: RuneId { block, tx },
+            amount,
+            output,
+        }
+    }
+
+    #[test]
+    fn test_runestone_edict() {
+        let runestone = Runestone {
+            edicts: vec![edict(840_000, 3, 1000, 1)],
+            ..Runestone::default()
+        };
+        assert_eq!(
+            runestone.encipher().unwrap().as_slice().to_hex(),
+            "6a5d0800c0a23303e80701"
+        );
+    }
+
+    #[test]
+    fn test_runestone_edicts_sorted_delta_encoded() {
+        let runestone = Runestone {
+            edicts: vec![
+                edict(840_000, 3, 1000, 1),
+                edict(2_585_189, 88, u64::MAX as u128 + 6, 2),
+                edict(840_000, 1, 7, 0),
+            ],
+            pointer: Some(2),
+            ..Runestone::default()
+        };
+        assert_eq!(
+            runestone.encipher().unwrap().as_slice().to_hex(),
+            "6a5d1d160200c0a2330107000002e80701a5c26a588580808080808080800202"
+        );
+    }
+
+    #[test]
+    fn test_runestone_mint() {
+        // UNCOMMON•GOODS mint.
+        let runestone = Runestone {
+            mint: Some(RuneId { block: 1, tx: 0 }),
+            ..Runestone::default()
+        };
+        assert_eq!(
+            runestone.encipher().unwrap().as_slice().to_hex(),
+            "6a5d0414011400"
+        );
+    }
+
+    #[test]
+    fn test_runestone_check_outputs() {
+        let runestone = Runestone {
+            // The runes are split between all outputs.
+            edicts: vec![edict(840_000, 3, 1000, 1), edict(840_000, 3, 1000, 3)],
+            pointer: Some(2),
+            ..Runestone::default()
+        };
+        runestone.check_outputs(3).unwrap();
+        // The edict to output #3 would burn the runes.
+        assert!(runestone.check_outputs(2).is_err());
+
+        let runestone = Runestone {
+            pointer: Some(3),
+            ..Runestone::default()
+        };
+        assert!(runestone.check_outputs(3).is_err());
+    }
+
+    #[test]
+    fn test_runestone_too_large() {
+        let runestone = Runestone {
+            edicts: (0..10).map(|tx| edict(840_000, tx, u128::MAX, 1)).collect(),
+            ..Runestone::default()
+        };
+        assert!(runestone.encipher().is_err());
+    }
+}
@@ -12,7 +12,7 @@ use tw_hash::H256;
 mod output;
 mod utxo;
 
-pub use output::OutputBuilder;
+pub use output::{OutputBuilder, OP_RETURN_DATA_LIMIT};
 pub use utxo::UtxoBuilder;
 
 pub fn txid_from_str(txid: &str) -> SigningResult<H256> {
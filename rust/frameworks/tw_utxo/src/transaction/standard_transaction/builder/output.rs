@@ -4,6 +4,8 @@ use crate::{
     script::{standard_script::conditions, Script},
     transaction::{
         asset::brc20::{BRC20TransferInscription, Brc20Ticker},
+        asset::ordinal::OrdinalNftInscription,
+        asset::runes::Runestone,
         transaction_parts::Amount,
     },
 };
@@ -156,6 +158,26 @@ impl OutputBuilder {
         Ok(self.p2tr_script_path(pubkey, merkle_root))
     }
 
+    /// Commits to an Ordinals inscription of the `data` with the given `mime_type`.
+    pub fn ordinal_nft(
+        self,
+        pubkey: &schnorr::PublicKey,
+        mime_type: &[u8],
+        data: &[u8],
+    ) -> SigningResult<TransactionOutput> {
+        let nft = OrdinalNftInscription::new(mime_type, data, &pubkey.compressed())?;
+        let merkle_root = nft.inscription().merkle_root()?;
+        Ok(self.p2tr_script_path(pubkey, merkle_root))
+    }
+
+    /// Creates a Runes protocol OP_RETURN output.
+    pub fn runestone(self, runestone: &Runestone) -> SigningResult<TransactionOutput> {
+        Ok(TransactionOutput {
+            value: self.amount,
+            script_pubkey: runestone.encipher()?,
+        })
+    }
+
     pub fn op_return(self, data: &[u8]) -> SigningResult<TransactionOutput> {
         if data.len() > OP_RETURN_DATA_LIMIT {
             return SigningError::err(SigningErrorType::Error_invalid_memo).context(format!(
@@ -8,6 +8,7 @@ use crate::{
     script::{standard_script::conditions, Script, Witness},
     signing_mode::SigningMethod,
     transaction::asset::brc20::{BRC20TransferInscription, Brc20Ticker},
+    transaction::asset::ordinal::{OrdinalNftInscription, OrdinalsInscription},
     transaction::transaction_parts::{Amount, OutPoint},
 };
 use bitcoin::hashes::Hash;
@@ -323,24 +324,42 @@ impl UtxoBuilder {
         let ticker = Brc20Ticker::new(ticker).unwrap();
         let transfer =
             BRC20TransferInscription::new(&pubkey.compressed(), &ticker, &value).unwrap();
+        self.ordinals_inscription(pubkey, &transfer)
+    }
 
-        let control_block = transfer
+    /// Reveals an Ordinals inscription of the `data` with the given `mime_type`.
+    pub fn ordinal_nft(
+        self,
+        pubkey: &schnorr::PublicKey,
+        mime_type: &[u8],
+        data: &[u8],
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        let nft = OrdinalNftInscription::new(mime_type, data, &pubkey.compressed())?;
+        self.ordinals_inscription(pubkey, nft.inscription())
+    }
+
+    fn ordinals_inscription(
+        self,
+        pubkey: &schnorr::PublicKey,
+        inscription: &OrdinalsInscription,
+    ) -> SigningResult<(TransactionInput, UtxoToSign)> {
+        let control_block = inscription
             .spend_info
             .control_block(&(
-                transfer.script.to_owned(),
+                inscription.script.to_owned(),
                 bitcoin::taproot::LeafVersion::TapScript,
             ))
             .or_tw_err(SigningErrorType::Error_internal)
             .context("'TaprootSpendInfo::control_block' is None")?;
 
-        let merkle_root = transfer.merkle_root()?;
-        let transfer_payload = Script::from(transfer.script.to_bytes());
+        let merkle_root = inscription.merkle_root()?;
+        let inscription_payload = Script::from(inscription.script.to_bytes());
 
         self.p2tr_script_path()
-            .reveal_script_pubkey(transfer_payload.clone())
+            .reveal_script_pubkey(inscription_payload.clone())
             .spender_public_key(pubkey)
-            // BRC20 transfer UTXO should be signed by revealing actual script pubkey.
-            .taproot_reveal_script_pubkey(transfer_payload)
+            // Inscription UTXO should be signed by revealing actual script pubkey.
+            .taproot_reveal_script_pubkey(inscription_payload)
             .restore_prevout_script_pubkey(pubkey, &merkle_root)
             .control_block(control_block.serialize())
             .build()
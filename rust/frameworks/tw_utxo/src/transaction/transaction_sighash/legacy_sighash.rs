@@ -19,6 +19,17 @@ pub struct LegacySighash<Transaction: std::fmt::Debug + TransactionInterface> {
 
 impl<Transaction: std::fmt::Debug + TransactionInterface> LegacySighash<Transaction> {
     pub fn sighash_tx(tx: &Transaction, args: &UtxoPreimageArgs) -> SigningResult<H256> {
+        // The sighash of such an input is `1` by consensus rules,
+        // so the signature could be reused to spend any UTXO of the same key.
+        // https://en.bitcoin.it/wiki/OP_CHECKSIG#Procedure_for_Hashtype_SIGHASH_SINGLE
+        if args.sighash_ty.base_type() == SighashBase::Single
+            && args.input_index >= tx.outputs().len()
+        {
+            return SigningError::err(SigningErrorType::Error_invalid_params).context(
+                "'single' sighash type requires an output with the same index as the input",
+            );
+        }
+
         let mut tx_preimage = tx.clone();
 
         tx_preimage.replace_inputs(Self::inputs_for_preimage(tx, args)?);
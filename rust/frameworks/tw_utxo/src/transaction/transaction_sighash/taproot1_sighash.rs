@@ -5,37 +5,33 @@
 use crate::encode::stream::Stream;
 use crate::sighash::SighashBase;
 use crate::transaction::transaction_hashing::TransactionHasher;
-use crate::transaction::transaction_interface::TransactionInterface;
+use crate::transaction::transaction_interface::{TransactionInterface, TxInputInterface};
 use crate::transaction::UtxoPreimageArgs;
 use std::marker::PhantomData;
 use tw_coin_entry::error::prelude::*;
-use tw_hash::hasher::tapsighash;
+use tw_hash::hasher::{tapsighash, StatefulHasher};
 use tw_hash::H256;
 
-/// `Taproot1Sighash`is used to calculate a preimage hash of a P2WPKH or P2WSH unspent output.
+/// Sighash types allowed in Taproot signatures.
+/// https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki#common-signature-message
+const TAPROOT_SIGHASH_TYPES: [u8; 7] = [0x00, 0x01, 0x02, 0x03, 0x81, 0x82, 0x83];
+
+/// `Taproot1Sighash`is used to calculate a preimage hash of a P2TR unspent output.
+/// https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki#common-signature-message
 pub struct Taproot1Sighash<Transaction: TransactionInterface> {
     _phantom: PhantomData<Transaction>,
 }
 
 impl<Transaction: TransactionInterface> Taproot1Sighash<Transaction> {
     pub fn sighash_tx(tx: &Transaction, args: &UtxoPreimageArgs) -> SigningResult<H256> {
-        // TODO if anyone_can_pay flag is set, there is no need to append these hashes.
-        // See https://github.com/rust-bitcoin/rust-bitcoin/blob/b0870634f0e4bd4c36e7ab0b7c9c7deb23ae62bf/bitcoin/src/crypto/sighash.rs#L608-L622
-        let prevout_hash =
-            TransactionHasher::preimage_prevout_hash(tx, args.sighash_ty, args.tx_hasher);
-        let sequence_hash =
-            TransactionHasher::preimage_sequence_hash(tx, args.sighash_ty, args.tx_hasher);
-        let outputs_hash = TransactionHasher::preimage_outputs_hash(
-            tx,
-            args.input_index,
-            args.sighash_ty,
-            args.tx_hasher,
-        );
-        let spent_amounts_hash = TransactionHasher::<Transaction>::spent_amount_hash(args);
         let raw_sighash = args.sighash_ty.serialize_as_taproot()?;
+        if !TAPROOT_SIGHASH_TYPES.contains(&raw_sighash) {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context(format!("Invalid Taproot sighash type: {raw_sighash:#04x}"));
+        }
 
-        let spent_script_pubkeys_hash =
-            TransactionHasher::<Transaction>::spent_script_pubkeys(args);
+        let anyone_can_pay = args.sighash_ty.anyone_can_pay();
+        let base_type = args.sighash_ty.base_type();
 
         let mut stream = Stream::default();
 
@@ -43,14 +39,33 @@ impl<Transaction: TransactionInterface> Taproot1Sighash<Transaction> {
             .append(&0u8) // epoch
             .append(&raw_sighash)
             .append(&tx.version())
-            .append(&tx.locktime())
-            .append_raw_slice(&prevout_hash)
-            .append_raw_slice(&spent_amounts_hash)
-            .append_raw_slice(&spent_script_pubkeys_hash)
-            .append_raw_slice(&sequence_hash)
-            // TODO: Double check behavior or `Single`
-            // TODO: What about `NonePlusAnyoneCanPay`?.
-            .append_raw_slice(&outputs_hash);
+            .append(&tx.locktime());
+
+        // Commit to all prevouts, amounts, scriptPubkeys and sequences
+        // unless only the signing input is committed to.
+        if !anyone_can_pay {
+            let prevout_hash = TransactionHasher::prevout_hash(tx, args.tx_hasher);
+            let spent_amounts_hash = TransactionHasher::<Transaction>::spent_amount_hash(args);
+            let spent_script_pubkeys_hash =
+                TransactionHasher::<Transaction>::spent_script_pubkeys(args);
+            let sequence_hash = TransactionHasher::sequence_hash(tx, args.tx_hasher);
+
+            stream
+                .append_raw_slice(&prevout_hash)
+                .append_raw_slice(&spent_amounts_hash)
+                .append_raw_slice(&spent_script_pubkeys_hash)
+                .append_raw_slice(&sequence_hash);
+        }
+
+        if base_type == SighashBase::All {
+            let outputs_hash = TransactionHasher::preimage_outputs_hash(
+                tx,
+                args.input_index,
+                args.sighash_ty,
+                args.tx_hasher,
+            );
+            stream.append_raw_slice(&outputs_hash);
+        }
 
         let mut spend_type = 0u8;
 
@@ -60,16 +75,40 @@ impl<Transaction: TransactionInterface> Taproot1Sighash<Transaction> {
 
         stream.append(&spend_type);
 
-        if args.sighash_ty.anyone_can_pay() {
-            return SigningError::err(SigningErrorType::Error_not_supported)
-                .context("'anyone can pay' sighash type is not supported for Taproot yet");
+        if anyone_can_pay {
+            let input = tx
+                .inputs()
+                .get(args.input_index)
+                .or_tw_err(SigningErrorType::Error_internal)
+                .context("Taproot sighash error: input_index is out of bounds")?;
+            let spent_script_pubkey = args
+                .taproot_args
+                .spent_script_pubkeys
+                .get(args.input_index)
+                .or_tw_err(SigningErrorType::Error_internal)
+                .context("Taproot sighash error: no spent scriptPubkey of the input")?;
+
+            stream
+                .append(input.previous_output())
+                .append(&args.amount)
+                .append(spent_script_pubkey)
+                .append(&input.sequence());
         } else {
             stream.append(&(args.input_index as u32));
         }
 
-        if args.sighash_ty.base_type() == SighashBase::Single {
-            return SigningError::err(SigningErrorType::Error_not_supported)
-                .context("'single' sighash type is not supported for Taproot yet");
+        if base_type == SighashBase::Single {
+            let output = tx
+                .outputs()
+                .get(args.input_index)
+                .or_tw_err(SigningErrorType::Error_invalid_params)
+                .context(
+                    "'single' sighash type requires an output with the same index as the input",
+                )?;
+
+            let mut output_stream = Stream::default();
+            output_stream.append(output);
+            stream.append_raw_slice(&args.tx_hasher.hash(&output_stream.out()));
         }
 
         if let Some((leaf_hash, separator)) = args.taproot_args.leaf_hash_code_separator {
@@ -85,6 +85,33 @@ where
         estimated_tx
     }
 
+    /// Returns the transaction with the placeholder signatures that have the final size
+    /// (or the max possible size for ECDSA signatures). See [`BitcoinEcdsaSignature::dummy`].
+    /// Used to get the final `size`, `vsize` and `weight` of the transaction without signing it.
+    pub fn dummy_signed_transaction(&self) -> SigningResult<Transaction> {
+        let mut dummy_tx = self.transaction.clone();
+
+        debug_assert_eq!(self.transaction.inputs().len(), self.utxo_args.len());
+
+        for (utxo_args, utxo) in self.utxo_args.iter().zip(dummy_tx.inputs_mut()) {
+            let dummy_spending = match utxo_args.spending_data_constructor {
+                SpendingDataConstructor::Ecdsa(ref ecdsa_constructor) => {
+                    let dummy_sig = BitcoinEcdsaSignature::dummy(utxo_args.sighash_ty)?;
+                    ecdsa_constructor.get_spending_data(&dummy_sig)
+                },
+                SpendingDataConstructor::Schnorr(ref schnorr_constructor) => {
+                    let dummy_sig = BitcoinSchnorrSignature::dummy(utxo_args.sighash_ty)?;
+                    schnorr_constructor.get_spending_data(&dummy_sig)
+                },
+            };
+
+            utxo.set_script_sig(dummy_spending.script_sig);
+            utxo.set_witness(dummy_spending.witness);
+        }
+
+        Ok(dummy_tx)
+    }
+
     pub fn set_inputs(
         &mut self,
         utxos: Vec<Transaction::Input>,
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::any_address::AnyAddress;
+use std::borrow::Cow;
+use tw_coin_entry::account_discovery::{AccountDiscovery, EXTERNAL_CHAIN, INTERNAL_CHAIN};
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::registry::get_coin_item;
+use tw_coin_registry::tw_derivation::TWDerivation;
+use tw_keypair::ecdsa::secp256k1::{self, ExtendedPublicKey, HARDENED_OFFSET};
+use tw_keypair::tw::{PublicKey, PublicKeyType};
+use tw_memory::Data;
+use tw_proto::AccountDiscovery::Proto;
+
+/// Scans the addresses of a BIP-44 account by its account-level extended public key, step by step.
+///
+/// Each step returns the addresses the host should check for activity, for example in a blockchain index.
+/// The host passes the results back to the next step until no addresses are pending.
+/// See [`AccountDiscovery`] for the gap limit rules.
+pub struct AccountScanner;
+
+impl AccountScanner {
+    /// Runs a scanning step using `TW.AccountDiscovery.Proto.ScanInput`.
+    /// Returns the serialized `TW.AccountDiscovery.Proto.ScanOutput`.
+    pub fn scan(input: &[u8]) -> SigningResult<Data> {
+        let input: Proto::ScanInput = tw_proto::deserialize(input)?;
+        let output =
+            Self::scan_impl(input).unwrap_or_else(|e| signing_output_error!(Proto::ScanOutput, e));
+        tw_proto::serialize(&output).map_err(SigningError::from)
+    }
+
+    fn scan_impl(input: Proto::ScanInput<'_>) -> SigningResult<Proto::ScanOutput<'static>> {
+        let coin = CoinType::try_from(input.coin)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Unknown coin type")?;
+        let item = get_coin_item(coin)?;
+        let derivation = TWDerivation::from_repr(input.derivation)
+            .map(Derivation::from)
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("Unknown derivation")?;
+        let derivation_path = &item
+            .derivation(derivation)
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("The coin doesn't declare the given derivation")?
+            .path;
+
+        let mut discovery = AccountDiscovery::with_derivation_path(derivation_path)
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("The coin derivation path is too short")?;
+        if input.gap_limit != 0 {
+            discovery = discovery.gap_limit(input.gap_limit);
+        }
+        if input.account >= HARDENED_OFFSET {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Account index must be less than 2^31");
+        }
+
+        let into_public_key: fn(secp256k1::PublicKey) -> PublicKey = match item.public_key_type {
+            PublicKeyType::Secp256k1 => PublicKey::Secp256k1,
+            PublicKeyType::Secp256k1Extended => PublicKey::Secp256k1Extended,
+            _ => {
+                return SigningError::err(SigningErrorType::Error_not_supported)
+                    .context("Only secp256k1 extended public keys are supported")
+            },
+        };
+        let account_key = ExtendedPublicKey::from_base58(&input.extended_public_key)
+            .into_tw()
+            .context("Invalid extended public key")?;
+
+        let mut chains = vec![(Proto::Chain::External, EXTERNAL_CHAIN, input.external)];
+        if input.scan_internal {
+            chains.push((Proto::Chain::Internal, INTERNAL_CHAIN, input.internal));
+        }
+
+        let mut output = Proto::ScanOutput::default();
+        for (chain, chain_index, activity) in chains {
+            let activity = activity.unwrap_or_default();
+            if activity.used.iter().any(|index| *index >= activity.checked) {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Used address indexes must be less than the number of checked ones");
+            }
+
+            let last_used = activity.used.iter().copied().max();
+            let pending = discovery.pending_indexes(last_used, activity.checked);
+            let children = account_key.derive_range(&[chain_index], pending.clone())?;
+
+            for (index, child) in pending.zip(children) {
+                let public_key = into_public_key(child);
+                let address = AnyAddress::with_public_key(coin, public_key, derivation, None)?;
+                output.pending.push(Proto::PendingAddress {
+                    chain,
+                    index,
+                    derivation_path: Cow::from(
+                        discovery
+                            .address_path(input.account, chain_index, index)
+                            .to_string(),
+                    ),
+                    address: Cow::from(address.description().to_string()),
+                });
+            }
+
+            let next_index = last_used.map_or(0, |index| index + 1);
+            match chain {
+                Proto::Chain::External => output.next_external_index = next_index,
+                Proto::Chain::Internal => output.next_internal_index = next_index,
+            }
+            output.has_activity |= last_used.is_some();
+        }
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Checks addresses against the address formats declared in `registry.json`.
+//! Unlike `CoinEntry::validate_address`, it works for every coin of the registry,
+//! including the ones that are not implemented in Rust, and tells why an address doesn't match.
+
+use tw_coin_registry::registry::{AddressEncoding, AddressFormat, CoinItem, NetworkPrefixes};
+use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::bech32::{self, Bech32Error};
+use tw_encoding::hex;
+use tw_hash::sha2::sha256_d;
+use tw_proto::AddressValidation::Proto::ValidationError;
+
+const BASE58_CHECKSUM_LEN: usize = 4;
+
+/// Address prefixes of either the main or the test network of a coin.
+pub struct FormatPrefixes<'a> {
+    pub hrp: Option<&'a str>,
+    pub p2pkh_prefix: Option<u8>,
+    pub p2sh_prefix: Option<u8>,
+    pub static_prefix: Option<u8>,
+}
+
+impl<'a> FormatPrefixes<'a> {
+    pub fn mainnet(item: &'a CoinItem) -> Self {
+        FormatPrefixes {
+            hrp: item.hrp.as_deref(),
+            p2pkh_prefix: item.p2pkh_prefix,
+            p2sh_prefix: item.p2sh_prefix,
+            static_prefix: item.static_prefix,
+        }
+    }
+
+    pub fn testnet(testnet: &'a NetworkPrefixes) -> Self {
+        FormatPrefixes {
+            hrp: testnet.hrp.as_deref(),
+            p2pkh_prefix: testnet.p2pkh_prefix,
+            p2sh_prefix: testnet.p2sh_prefix,
+            static_prefix: None,
+        }
+    }
+}
+
+/// Whether the address matches any of the coin address formats with the given prefixes.
+/// Always `false` if the coin doesn't declare its address formats.
+pub fn matches_format(item: &CoinItem, prefixes: &FormatPrefixes, address: &str) -> bool {
+    item.address_formats
+        .iter()
+        .any(|format| check_format(format, prefixes, address).is_ok())
+}
+
+/// Returns the error of the mainnet address format the address is the closest to,
+/// or [`ValidationError::NoError`] if the address matches a format or the coin doesn't declare any.
+pub fn closest_format_error(item: &CoinItem, address: &str) -> ValidationError {
+    let prefixes = FormatPrefixes::mainnet(item);
+    let mut closest = None;
+    for format in item.address_formats.iter() {
+        match check_format(format, &prefixes, address) {
+            Ok(()) => return ValidationError::NoError,
+            Err(error) => {
+                if closest.map_or(true, |closest| progress(error) > progress(closest)) {
+                    closest = Some(error);
+                }
+            },
+        }
+    }
+    closest.unwrap_or(ValidationError::NoError)
+}
+
+/// How far the address got through the format checks.
+fn progress(error: ValidationError) -> u8 {
+    match error {
+        ValidationError::InvalidEncoding => 1,
+        ValidationError::InvalidChecksum => 2,
+        ValidationError::InvalidPrefix => 3,
+        ValidationError::InvalidLength => 4,
+        ValidationError::NoError => 5,
+    }
+}
+
+fn check_format(
+    format: &AddressFormat,
+    prefixes: &FormatPrefixes,
+    address: &str,
+) -> Result<(), ValidationError> {
+    let payload_len = match format.encoding {
+        AddressEncoding::Base58Check => check_base58(prefixes, address)?,
+        AddressEncoding::Segwit => check_segwit(prefixes, address)?,
+        AddressEncoding::Bech32 => check_bech32(prefixes, address)?,
+        AddressEncoding::Hex => check_hex(address)?,
+    };
+    if !format.lengths.contains(&payload_len) {
+        return Err(ValidationError::InvalidLength);
+    }
+    Ok(())
+}
+
+/// Returns the payload length including the version bytes.
+fn check_base58(prefixes: &FormatPrefixes, address: &str) -> Result<usize, ValidationError> {
+    let decoded =
+        base58::decode(address, Alphabet::Bitcoin).map_err(|_| ValidationError::InvalidEncoding)?;
+    if decoded.len() < BASE58_CHECKSUM_LEN {
+        return Err(ValidationError::InvalidEncoding);
+    }
+
+    let (payload, checksum) = decoded.split_at(decoded.len() - BASE58_CHECKSUM_LEN);
+    if sha256_d(payload)[..BASE58_CHECKSUM_LEN] != *checksum {
+        return Err(ValidationError::InvalidChecksum);
+    }
+
+    let versions = match prefixes.static_prefix {
+        Some(static_prefix) => payload.get(..2).map(|v| (Some(v[0]), v[1])),
+        None => payload.first().map(|v| (None, *v)),
+    };
+    let (static_prefix, version) = versions.ok_or(ValidationError::InvalidLength)?;
+    let is_known_version = [prefixes.p2pkh_prefix, prefixes.p2sh_prefix].contains(&Some(version));
+    if static_prefix != prefixes.static_prefix || !is_known_version {
+        return Err(ValidationError::InvalidPrefix);
+    }
+    Ok(payload.len())
+}
+
+/// Returns the witness program length.
+fn check_segwit(prefixes: &FormatPrefixes, address: &str) -> Result<usize, ValidationError> {
+    let decoded = bech32::decode_witness(address).map_err(bech32_error)?;
+    check_hrp(prefixes, &decoded.hrp)?;
+    Ok(decoded.program.len())
+}
+
+/// Returns the data length.
+fn check_bech32(prefixes: &FormatPrefixes, address: &str) -> Result<usize, ValidationError> {
+    let decoded = bech32::decode(address).map_err(bech32_error)?;
+    check_hrp(prefixes, &decoded.hrp)?;
+    Ok(decoded.bytes.len())
+}
+
+fn check_hrp(prefixes: &FormatPrefixes, hrp: &str) -> Result<(), ValidationError> {
+    if prefixes.hrp != Some(hrp) {
+        return Err(ValidationError::InvalidPrefix);
+    }
+    Ok(())
+}
+
+fn bech32_error(error: Bech32Error) -> ValidationError {
+    match error {
+        Bech32Error::InvalidChecksum => ValidationError::InvalidChecksum,
+        _ => ValidationError::InvalidEncoding,
+    }
+}
+
+/// Returns the address length in bytes.
+fn check_hex(address: &str) -> Result<usize, ValidationError> {
+    let hex_address = address
+        .strip_prefix("0x")
+        .ok_or(ValidationError::InvalidPrefix)?;
+    let bytes = hex::decode(hex_address).map_err(|_| ValidationError::InvalidEncoding)?;
+    Ok(bytes.len())
+}
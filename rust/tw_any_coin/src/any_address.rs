@@ -2,14 +2,17 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::address_format::{self, FormatPrefixes};
 use tw_coin_entry::derivation::Derivation;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::prefix::AddressPrefix;
 use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::coin_dispatcher;
+use tw_coin_registry::registry::{get_coin_item, registry_iter};
 use tw_keypair::tw::PublicKey;
 use tw_memory::Data;
 use tw_misc::try_or_false;
+use tw_proto::AddressValidation::Proto;
 
 /// Represents an address in Rust for almost any blockchain.
 #[derive(Debug, PartialEq)]
@@ -26,6 +29,73 @@ impl AnyAddress {
         entry.validate_address(&ctx, address, prefix).is_ok()
     }
 
+    /// Validates the address and tells if it belongs to the test network or to another chain.
+    ///
+    /// Coins that are not implemented in Rust are checked against their address formats in `registry.json` only,
+    /// so `valid` is never set for them, and their `detected_coins` should be confirmed by the caller.
+    pub fn validate(
+        coin: CoinType,
+        address: &str,
+    ) -> AddressResult<Proto::ValidationResult<'static>> {
+        let item = get_coin_item(coin).map_err(|_| AddressError::UnknownCoinType)?;
+        let dispatched = coin_dispatcher(coin).ok();
+
+        if let Some((ctx, entry)) = &dispatched {
+            if let Ok(normalized) = entry.normalize_address(ctx, address, None) {
+                return Ok(Proto::ValidationResult {
+                    valid: true,
+                    normalized: normalized.into(),
+                    network: Proto::Network::Mainnet,
+                    ..Proto::ValidationResult::default()
+                });
+            }
+        }
+
+        let is_testnet = item.testnet.iter().any(|testnet| {
+            let is_valid_testnet = dispatched.as_ref().map_or(false, |(ctx, entry)| {
+                testnet
+                    .address_prefixes()
+                    .into_iter()
+                    .any(|prefix| entry.validate_address(ctx, address, Some(prefix)).is_ok())
+            });
+            is_valid_testnet
+                || address_format::matches_format(item, &FormatPrefixes::testnet(testnet), address)
+        });
+        if is_testnet {
+            return Ok(Proto::ValidationResult {
+                network: Proto::Network::Testnet,
+                error: Proto::ValidationError::InvalidPrefix,
+                ..Proto::ValidationResult::default()
+            });
+        }
+
+        let mut detected_coins: Vec<_> = registry_iter()
+            .filter(|other| other.coin_id != coin && !other.deprecated)
+            .filter(|other| other.has_address_prefix())
+            .filter(|other| match coin_dispatcher(other.coin_id) {
+                Ok(_) => AnyAddress::is_valid(other.coin_id, address, None),
+                Err(_) => {
+                    address_format::matches_format(other, &FormatPrefixes::mainnet(other), address)
+                },
+            })
+            .map(|other| other.coin_id as u32)
+            .collect();
+        // The registry is not ordered.
+        detected_coins.sort_unstable();
+        let network = if detected_coins.is_empty() {
+            Proto::Network::Unknown
+        } else {
+            Proto::Network::Mainnet
+        };
+
+        Ok(Proto::ValidationResult {
+            network,
+            detected_coins,
+            error: address_format::closest_format_error(item, address),
+            ..Proto::ValidationResult::default()
+        })
+    }
+
     /// Creates an address from a string representation and a coin type.
     #[inline]
     pub fn with_string(
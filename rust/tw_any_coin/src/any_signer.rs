@@ -5,7 +5,10 @@
 use tw_coin_entry::error::prelude::*;
 use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::coin_dispatcher;
+use tw_coin_registry::evm_network::evm_network_by_chain_id;
 use tw_memory::Data;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto as EthereumProto;
 
 /// Represents a signer to sign transactions for any blockchain.
 pub struct AnySigner;
@@ -25,4 +28,34 @@ impl AnySigner {
         let (ctx, entry) = coin_dispatcher(coin)?;
         entry.plan(&ctx, input)
     }
+
+    /// Signs an EVM transaction for the network specified by `TW.Ethereum.Proto.SigningInput.chain_id`.
+    /// Known networks are signed with their coin type, other chain IDs are signed as generic EVM networks.
+    /// `TransactionMode::NetworkDefault` is replaced with the default transaction mode of the network.
+    pub fn sign_evm(input: &[u8]) -> SigningResult<Data> {
+        let mut input: EthereumProto::SigningInput = tw_proto::deserialize(input)?;
+        let chain_id = U256::from_big_endian_slice(&input.chain_id)
+            .into_tw()
+            .context("Invalid chain ID")?;
+        if chain_id.is_zero() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Chain ID must be specified");
+        }
+
+        let network = u64::try_from(chain_id)
+            .ok()
+            .and_then(evm_network_by_chain_id);
+        let coin = network.map_or(CoinType::Ethereum, |network| network.coin);
+
+        if input.tx_mode == EthereumProto::TransactionMode::NetworkDefault {
+            let eip1559 = network.map_or(true, |network| network.eip1559);
+            input.tx_mode = if eip1559 {
+                EthereumProto::TransactionMode::Enveloped
+            } else {
+                EthereumProto::TransactionMode::Legacy
+            };
+        }
+
+        Self::sign(&tw_proto::serialize(&input)?, coin)
+    }
 }
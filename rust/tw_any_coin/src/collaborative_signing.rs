@@ -0,0 +1,344 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Serialization format of MuSig2 and FROST round messages,
+//! so collaborative signing sessions can be persisted and transferred across devices.
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_hash::sha2::sha256;
+use tw_hash::{H256, H264};
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_proto::CollaborativeSigning::Proto;
+use tw_proto::CollaborativeSigning::Proto::mod_RoundMessage::OneOfpayload as ProtoPayload;
+
+pub const COLLABORATIVE_SIGNING_VERSION: u32 = 1;
+pub const NONCE_COMMITMENT_ROUND: u32 = 1;
+pub const PARTIAL_SIGNATURE_ROUND: u32 = 2;
+
+const MAX_DOMAIN_LEN: usize = 128;
+const DIGEST_TAG_PREFIX: &str = "TW/CollaborativeSigning/v1/";
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scheme {
+    MuSig2,
+    Frost,
+}
+
+impl From<Proto::Scheme> for Scheme {
+    fn from(scheme: Proto::Scheme) -> Self {
+        match scheme {
+            Proto::Scheme::MuSig2 => Scheme::MuSig2,
+            Proto::Scheme::Frost => Scheme::Frost,
+        }
+    }
+}
+
+impl From<Scheme> for Proto::Scheme {
+    fn from(scheme: Scheme) -> Self {
+        match scheme {
+            Scheme::MuSig2 => Proto::Scheme::MuSig2,
+            Scheme::Frost => Proto::Scheme::Frost,
+        }
+    }
+}
+
+/// Binds a round message to a signing session.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageHeader {
+    pub version: u32,
+    pub scheme: Scheme,
+    /// Application domain separation tag.
+    pub domain: String,
+    /// Unique random identifier of the signing session.
+    pub session_id: H256,
+    pub round: u32,
+    /// Index of the participant that produced the message.
+    pub sender: u32,
+    /// Monotonically increasing counter of the sender's messages within the session.
+    pub sequence: u64,
+    /// UNIX timestamp in seconds after which the message must be rejected, `0` if never expires.
+    pub expires_at: u64,
+}
+
+/// MuSig2 public nonce `(R1, R2)` or FROST `(hiding, binding)` nonce commitments.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct NonceCommitment {
+    pub hiding: H264,
+    pub binding: H264,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PartialSignature {
+    pub share: H256,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum RoundPayload {
+    NonceCommitment(NonceCommitment),
+    PartialSignature(PartialSignature),
+}
+
+impl RoundPayload {
+    /// Returns the signing round the payload is sent in.
+    pub fn round(&self) -> u32 {
+        match self {
+            RoundPayload::NonceCommitment(_) => NONCE_COMMITMENT_ROUND,
+            RoundPayload::PartialSignature(_) => PARTIAL_SIGNATURE_ROUND,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RoundMessage {
+    pub header: MessageHeader,
+    pub payload: RoundPayload,
+}
+
+impl RoundMessage {
+    /// Checks if the message is well-formed, i.e. the version is supported,
+    /// the session is identified, and the payload matches the round and contains valid curve elements.
+    pub fn validate(&self) -> SigningResult<()> {
+        let header = &self.header;
+        if header.version != COLLABORATIVE_SIGNING_VERSION {
+            return SigningError::err(SigningErrorType::Error_not_supported).context(format!(
+                "Unsupported collaborative signing message version: {}",
+                header.version
+            ));
+        }
+        if header.domain.is_empty() || header.domain.len() > MAX_DOMAIN_LEN {
+            return SigningError::err(SigningErrorType::Error_invalid_params).context(format!(
+                "Domain must be from 1 to {MAX_DOMAIN_LEN} bytes long"
+            ));
+        }
+        if header.session_id.is_zero() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Session ID must not be empty");
+        }
+        if header.round != self.payload.round() {
+            return SigningError::err(SigningErrorType::Error_invalid_params).context(format!(
+                "Payload is not expected in the round {}",
+                header.round
+            ));
+        }
+
+        match self.payload {
+            RoundPayload::NonceCommitment(ref commitment) => {
+                secp256k1::PublicKey::try_from(commitment.hiding.as_slice())
+                    .into_tw()
+                    .context("Invalid hiding nonce commitment")?;
+                secp256k1::PublicKey::try_from(commitment.binding.as_slice())
+                    .into_tw()
+                    .context("Invalid binding nonce commitment")?;
+            },
+            RoundPayload::PartialSignature(ref signature) => {
+                secp256k1::PrivateKey::try_from(signature.share.as_slice())
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Partial signature share must be a valid secp256k1 scalar")?;
+            },
+        }
+        Ok(())
+    }
+
+    /// Returns a domain separated digest of the message:
+    /// `SHA256(SHA256(tag) || SHA256(tag) || proto(message))`,
+    /// where `tag` is `TW/CollaborativeSigning/v1/{domain}`.
+    pub fn digest(&self) -> SigningResult<H256> {
+        let tag = sha256(format!("{DIGEST_TAG_PREFIX}{}", self.header.domain).as_bytes());
+        let encoded = tw_proto::serialize(&self.to_proto())?;
+
+        let mut preimage = Vec::with_capacity(tag.len() * 2 + encoded.len());
+        preimage.extend_from_slice(&tag);
+        preimage.extend_from_slice(&tag);
+        preimage.extend_from_slice(&encoded);
+
+        H256::try_from(sha256(&preimage).as_slice())
+            .tw_err(SigningErrorType::Error_internal)
+            .context("SHA256 digest must be 32 bytes")
+    }
+
+    pub fn from_json(json: &str) -> SigningResult<RoundMessage> {
+        let message: RoundMessage = serde_json::from_str(json)?;
+        message.validate()?;
+        Ok(message)
+    }
+
+    pub fn to_json(&self) -> SigningResult<String> {
+        self.validate()?;
+        serde_json::to_string(self).map_err(SigningError::from)
+    }
+
+    pub fn from_proto(proto: &Proto::RoundMessage) -> SigningResult<RoundMessage> {
+        let header = proto
+            .header
+            .as_ref()
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("Round message header is not set")?;
+        let session_id = H256::try_from(header.session_id.as_ref())
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Session ID must be 32 bytes")?;
+
+        let payload = match proto.payload {
+            ProtoPayload::nonce_commitment(ref commitment) => {
+                RoundPayload::NonceCommitment(NonceCommitment {
+                    hiding: h264_from_proto(&commitment.hiding)
+                        .context("Invalid hiding nonce commitment")?,
+                    binding: h264_from_proto(&commitment.binding)
+                        .context("Invalid binding nonce commitment")?,
+                })
+            },
+            ProtoPayload::partial_signature(ref signature) => {
+                let share = H256::try_from(signature.share.as_ref())
+                    .tw_err(SigningErrorType::Error_invalid_params)
+                    .context("Partial signature share must be 32 bytes")?;
+                RoundPayload::PartialSignature(PartialSignature { share })
+            },
+            ProtoPayload::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("Round message payload is not set")
+            },
+        };
+
+        let message = RoundMessage {
+            header: MessageHeader {
+                version: header.version,
+                scheme: Scheme::from(header.scheme),
+                domain: header.domain.to_string(),
+                session_id,
+                round: header.round,
+                sender: header.sender,
+                sequence: header.sequence,
+                expires_at: header.expires_at,
+            },
+            payload,
+        };
+        message.validate()?;
+        Ok(message)
+    }
+
+    pub fn to_proto(&self) -> Proto::RoundMessage<'static> {
+        let header = &self.header;
+        let payload = match self.payload {
+            RoundPayload::NonceCommitment(ref commitment) => {
+                ProtoPayload::nonce_commitment(Proto::NonceCommitment {
+                    hiding: commitment.hiding.to_vec().into(),
+                    binding: commitment.binding.to_vec().into(),
+                })
+            },
+            RoundPayload::PartialSignature(ref signature) => {
+                ProtoPayload::partial_signature(Proto::PartialSignature {
+                    share: signature.share.to_vec().into(),
+                })
+            },
+        };
+
+        Proto::RoundMessage {
+            header: Some(Proto::MessageHeader {
+                version: header.version,
+                scheme: Proto::Scheme::from(header.scheme),
+                domain: Cow::Owned(header.domain.clone()),
+                session_id: header.session_id.to_vec().into(),
+                round: header.round,
+                sender: header.sender,
+                sequence: header.sequence,
+                expires_at: header.expires_at,
+            }),
+            payload,
+        }
+    }
+}
+
+/// Rejects round messages that belong to another session, have expired, or have been seen already.
+/// Should be persisted along with the session state.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayGuard {
+    scheme: Scheme,
+    domain: String,
+    session_id: H256,
+    /// The last accepted sequence number of each sender.
+    last_sequences: HashMap<u32, u64>,
+}
+
+impl ReplayGuard {
+    pub fn new(scheme: Scheme, domain: String, session_id: H256) -> ReplayGuard {
+        ReplayGuard {
+            scheme,
+            domain,
+            session_id,
+            last_sequences: HashMap::new(),
+        }
+    }
+
+    /// Validates the `message` and accepts its sequence number.
+    /// `now` is the current UNIX timestamp in seconds.
+    pub fn accept(&mut self, message: &RoundMessage, now: u64) -> SigningResult<()> {
+        message.validate()?;
+
+        let header = &message.header;
+        if header.scheme != self.scheme
+            || header.domain != self.domain
+            || header.session_id != self.session_id
+        {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Round message belongs to another signing session");
+        }
+        if header.expires_at != 0 && now >= header.expires_at {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Round message has expired");
+        }
+
+        let last_sequence = self.last_sequences.get(&header.sender);
+        if last_sequence.is_some_and(|last| header.sequence <= *last) {
+            return SigningError::err(SigningErrorType::Error_invalid_params).context(format!(
+                "Round message #{} of the participant {} has been replayed",
+                header.sequence, header.sender
+            ));
+        }
+
+        self.last_sequences.insert(header.sender, header.sequence);
+        Ok(())
+    }
+}
+
+/// Converts collaborative signing round messages between Protobuf and JSON.
+pub struct CollaborativeSigning;
+
+impl CollaborativeSigning {
+    /// Encodes the serialized `TW.CollaborativeSigning.Proto.RoundMessage` as a JSON string.
+    pub fn encode_json(input: &[u8]) -> SigningResult<String> {
+        let proto: Proto::RoundMessage = tw_proto::deserialize(input)?;
+        RoundMessage::from_proto(&proto)?.to_json()
+    }
+
+    /// Decodes a JSON round message.
+    /// Returns the serialized `TW.CollaborativeSigning.Proto.DecodingOutput`.
+    pub fn decode_json(json: &str) -> SigningResult<Data> {
+        let output = Self::decode_json_impl(json)
+            .unwrap_or_else(|e| signing_output_error!(Proto::DecodingOutput, e));
+        tw_proto::serialize(&output).map_err(SigningError::from)
+    }
+
+    fn decode_json_impl(json: &str) -> SigningResult<Proto::DecodingOutput<'static>> {
+        let message = RoundMessage::from_json(json)?;
+        Ok(Proto::DecodingOutput {
+            digest: message.digest()?.to_vec().into(),
+            message: Some(message.to_proto()),
+            ..Proto::DecodingOutput::default()
+        })
+    }
+}
+
+fn h264_from_proto(bytes: &[u8]) -> SigningResult<H264> {
+    H264::try_from(bytes)
+        .tw_err(SigningErrorType::Error_invalid_params)
+        .context("Expected a 33-byte compressed point")
+}
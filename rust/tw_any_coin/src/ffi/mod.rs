@@ -2,10 +2,15 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod tw_account_discovery;
 pub mod tw_any_address;
 pub mod tw_any_signer;
+pub mod tw_coin_derivation;
+#[cfg(feature = "collaborative-signing")]
+pub mod tw_collaborative_signing;
 pub mod tw_message_signer;
 pub mod tw_transaction_compiler;
 pub mod tw_transaction_decoder;
+pub mod tw_transaction_planner;
 pub mod tw_transaction_util;
 pub mod tw_wallet_connect_request;
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::account_discovery::AccountScanner;
+use tw_macros::tw_ffi;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::{Nonnull, NullableMut, RawPtrTrait};
+use tw_misc::try_or_else;
+
+/// Runs a step of the BIP-44 account scanning by an account-level extended public key.
+/// Returns the addresses to check for activity, whose results should be passed to the next step.
+///
+/// \param input The serialized data of TW.AccountDiscovery.Proto.ScanInput.
+/// \return The serialized data of TW.AccountDiscovery.Proto.ScanOutput.
+#[tw_ffi(ty = static_function, class = TWAccountDiscovery, name = Scan)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_account_discovery_scan(input: Nonnull<TWData>) -> NullableMut<TWData> {
+    let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    AccountScanner::scan(input.as_slice())
+        .map(|output| TWData::from(output).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
@@ -10,6 +10,7 @@ use tw_coin_entry::prefix::{AddressPrefix, BitcoinBase58Prefix};
 use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::tw_derivation::TWDerivation;
 use tw_keypair::ffi::pubkey::TWPublicKey;
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::tw_string::TWString;
 use tw_memory::ffi::RawPtrTrait;
@@ -26,6 +27,7 @@ impl RawPtrTrait for TWAnyAddress {}
 /// \param coin coin type of the address.
 /// \return bool indicating if the address is valid.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_is_valid(string: *const TWString, coin: u32) -> bool {
     let string = try_or_false!(TWString::from_ptr_as_ref(string));
     let string = try_or_false!(string.as_str());
@@ -34,6 +36,29 @@ pub unsafe extern "C" fn tw_any_address_is_valid(string: *const TWString, coin:
     AnyAddress::is_valid(coin, string, None)
 }
 
+/// Validates the string as an address of the given coin and returns a structured result.
+/// The result tells if the address belongs to the test network or to another chain.
+/// Coins that are not implemented in Rust are checked against their `registry.json` address formats only,
+/// see [`AnyAddress::validate`].
+///
+/// \param string address to validate.
+/// \param coin coin type of the address.
+/// \return serialized `TW.AddressValidation.Proto.ValidationResult`, or null if the coin is unknown.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_any_address_validate(
+    string: *const TWString,
+    coin: u32,
+) -> *mut TWData {
+    let string = try_or_else!(TWString::from_ptr_as_ref(string), std::ptr::null_mut);
+    let string = try_or_else!(string.as_str(), std::ptr::null_mut);
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+
+    let result = try_or_else!(AnyAddress::validate(coin, string), std::ptr::null_mut);
+    let data = try_or_else!(tw_proto::serialize(&result), std::ptr::null_mut);
+    TWData::from(data).into_ptr()
+}
+
 /// Determines if the string is a valid Any address with the given hrp.
 ///
 /// \param string address to validate.
@@ -41,6 +66,7 @@ pub unsafe extern "C" fn tw_any_address_is_valid(string: *const TWString, coin:
 /// \param hrp explicit given hrp of the given address.
 /// \return bool indicating if the address is valid.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_is_valid_bech32(
     string: *const TWString,
     coin: u32,
@@ -66,6 +92,7 @@ pub unsafe extern "C" fn tw_any_address_is_valid_bech32(
 /// \param p2sh pay-to-script-hash address prefix.
 /// \return bool indicating if the address is valid.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_is_valid_base58(
     string: *const TWString,
     coin: u32,
@@ -88,6 +115,7 @@ pub unsafe extern "C" fn tw_any_address_is_valid_base58(
 /// \param ss58 ss58 address prefix.
 /// \return bool indicating if the address is valid.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_is_valid_ss58(
     string: *const TWString,
     coin: u32,
@@ -108,6 +136,7 @@ pub unsafe extern "C" fn tw_any_address_is_valid_ss58(
 /// \param coin coin type of the address.
 /// \return `TWAnyAddress` pointer or nullptr if address and coin are invalid.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_create_with_string(
     string: *const TWString,
     coin: u32,
@@ -128,6 +157,7 @@ pub unsafe extern "C" fn tw_any_address_create_with_string(
 /// \param derivation the custom derivation to use.
 /// \return `TWAnyAddress` pointer or nullptr if public key is invalid.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_create_with_public_key_derivation(
     public_key: *mut TWPublicKey,
     coin: u32,
@@ -150,6 +180,7 @@ pub unsafe extern "C" fn tw_any_address_create_with_public_key_derivation(
 /// \param hrp hrp of the address.
 /// \return TWAnyAddress pointer or nullptr if public key is invalid.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_create_bech32_with_public_key(
     public_key: *mut TWPublicKey,
     coin: u32,
@@ -180,6 +211,7 @@ pub unsafe extern "C" fn tw_any_address_create_bech32_with_public_key(
 /// \param p2sh pay-to-script-hash address prefix.
 /// \return TWAnyAddress pointer or nullptr if public key is invalid.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_create_base58_with_public_key(
     public_key: *mut TWPublicKey,
     coin: u32,
@@ -207,6 +239,7 @@ pub unsafe extern "C" fn tw_any_address_create_base58_with_public_key(
 /// \param ss58 SS58 address prefix.
 /// \return TWAnyAddress pointer or nullptr if public key is invalid.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_create_ss58_with_public_key(
     public_key: *mut TWPublicKey,
     coin: u32,
@@ -230,6 +263,7 @@ pub unsafe extern "C" fn tw_any_address_create_ss58_with_public_key(
 ///
 /// \param address address to delete.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_delete(address: *mut TWAnyAddress) {
     // Take the ownership back to rust and drop the owner.
     let _ = TWAnyAddress::from_ptr(address);
@@ -239,6 +273,7 @@ pub unsafe extern "C" fn tw_any_address_delete(address: *mut TWAnyAddress) {
 ///
 /// \param address address to get the string representation of.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_description(address: *const TWAnyAddress) -> *mut TWString {
     // Take the ownership back to rust and drop the owner.
     let address = try_or_else!(TWAnyAddress::from_ptr_as_ref(address), std::ptr::null_mut);
@@ -251,6 +286,7 @@ pub unsafe extern "C" fn tw_any_address_description(address: *const TWAnyAddress
 ///
 /// \param address address to get the data of.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_data(address: *const TWAnyAddress) -> *mut TWData {
     // Take the ownership back to rust and drop the owner.
     let address = try_or_else!(TWAnyAddress::from_ptr_as_ref(address), std::ptr::null_mut);
@@ -272,6 +308,7 @@ pub unsafe extern "C" fn tw_any_address_data(address: *const TWAnyAddress) -> *m
 /// Consider using `tw_any_address_create_<PREFIX>_with_string` if the prefix is known.
 /// Please note that this function should be removed when all chains are migrated to Rust.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_address_create_with_string_unchecked(
     string: *const TWString,
     coin: u32,
@@ -6,6 +6,7 @@
 
 use crate::any_signer::AnySigner;
 use tw_coin_registry::coin_type::CoinType;
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::try_or_else;
@@ -16,6 +17,7 @@ use tw_misc::try_or_else;
 /// \param coin The given coin type to sign the transaction for.
 /// \return The serialized data of a `SigningOutput` proto object. (e.g. TW.Bitcoin.Proto.SigningOutput).
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_signer_sign(input: *const TWData, coin: u32) -> *mut TWData {
     let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
     let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
@@ -31,6 +33,7 @@ pub unsafe extern "C" fn tw_any_signer_sign(input: *const TWData, coin: u32) ->
 /// \param coin The given coin type to plan the transaction for.
 /// \return The serialized data of a `TransactionPlan` proto object.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_any_signer_plan(input: *const TWData, coin: u32) -> *mut TWData {
     let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
     let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
@@ -39,3 +42,18 @@ pub unsafe extern "C" fn tw_any_signer_plan(input: *const TWData, coin: u32) ->
         .map(|output| TWData::from(output).into_ptr())
         .unwrap_or_else(|_| std::ptr::null_mut())
 }
+
+/// Signs an EVM transaction for the network specified by the chain ID of the signing input.
+/// Allows to sign transactions for any EVM-compatible network, including the networks unknown to the library.
+///
+/// \param input The serialized data of a `TW.Ethereum.Proto.SigningInput`.
+/// \return The serialized data of a `TW.Ethereum.Proto.SigningOutput` proto object.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_any_signer_sign_evm(input: *const TWData) -> *mut TWData {
+    let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+
+    AnySigner::sign_evm(input.as_slice())
+        .map(|output| TWData::from(output).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::registry::get_coin_item;
+use tw_coin_registry::tw_derivation::TWDerivation;
+use tw_macros::tw_catch_panic;
+use tw_memory::ffi::tw_string::TWString;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+
+/// Returns the derivation path of the given coin and derivation, e.g. `m/44'/0'/0'/0/0` for Bitcoin legacy.
+///
+/// \param coin coin type.
+/// \param derivation the derivation to get the path of, `TWDerivationDefault` for the default one.
+/// \return *nullable* `TWString` pointer. Null if the coin doesn't declare the given derivation.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_coin_derivation_path(coin: u32, derivation: u32) -> *mut TWString {
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+    let derivation = try_or_else!(TWDerivation::from_repr(derivation), std::ptr::null_mut);
+    let item = try_or_else!(get_coin_item(coin), std::ptr::null_mut);
+    let derivation = try_or_else!(
+        item.derivation(Derivation::from(derivation)),
+        std::ptr::null_mut
+    );
+    TWString::from(derivation.path.to_string()).into_ptr()
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::collaborative_signing::CollaborativeSigning;
+use tw_macros::tw_ffi;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::tw_string::TWString;
+use tw_memory::ffi::{Nonnull, NullableMut, RawPtrTrait};
+use tw_misc::try_or_else;
+
+/// Encodes a MuSig2 or FROST round message as a JSON string to persist or transfer it to another device.
+///
+/// \param input The serialized data of TW.CollaborativeSigning.Proto.RoundMessage.
+/// \return *nullable* JSON string. Null if the message is malformed.
+#[tw_ffi(ty = static_function, class = TWCollaborativeSigning, name = EncodeJSON)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_collaborative_signing_encode_json(
+    input: Nonnull<TWData>,
+) -> NullableMut<TWString> {
+    let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    CollaborativeSigning::encode_json(input.as_slice())
+        .map(|json| TWString::from(json).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Decodes and validates a JSON round message.
+///
+/// \param json The JSON string returned by `TWCollaborativeSigningEncodeJSON`.
+/// \return The serialized data of TW.CollaborativeSigning.Proto.DecodingOutput.
+#[tw_ffi(ty = static_function, class = TWCollaborativeSigning, name = DecodeJSON)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_collaborative_signing_decode_json(
+    json: Nonnull<TWString>,
+) -> NullableMut<TWData> {
+    let json = try_or_else!(TWString::from_ptr_as_ref(json), std::ptr::null_mut);
+    let json = try_or_else!(json.as_str(), std::ptr::null_mut);
+    CollaborativeSigning::decode_json(json)
+        .map(|output| TWData::from(output).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
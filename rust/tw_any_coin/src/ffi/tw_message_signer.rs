@@ -5,9 +5,13 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::message_signer::MessageSigner;
+#[cfg(feature = "ownership-proof")]
+use crate::ownership_proof::OwnershipProof;
 use crate::TWFFICoinType;
 use tw_coin_registry::coin_type::CoinType;
 use tw_macros::tw_ffi;
+#[cfg(feature = "ownership-proof")]
+use tw_memory::ffi::tw_string::TWString;
 use tw_memory::ffi::{tw_data::TWData, Nonnull};
 use tw_memory::ffi::{NullableMut, RawPtrTrait};
 use tw_misc::{try_or_else, try_or_false};
@@ -65,3 +69,85 @@ pub unsafe extern "C" fn tw_message_signer_pre_image_hashes(
         .map(|output| TWData::from(output).into_ptr())
         .unwrap_or_else(|_| std::ptr::null_mut())
 }
+
+/// Signs an arbitrary message using the blockchain agnostic input.
+/// The default message signing scheme of the blockchain is used if not specified.
+///
+/// \param coin The given coin type to sign the message for.
+/// \param input The serialized data of TW.MessageSigner.Proto.SigningInput.
+/// \return The serialized data of TW.MessageSigner.Proto.SigningOutput.
+#[tw_ffi(ty = static_function, class = TWMessageSigner, name = SignAny)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_message_signer_sign_any(
+    coin: TWFFICoinType,
+    input: Nonnull<TWData>,
+) -> NullableMut<TWData> {
+    let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+
+    MessageSigner::sign(input.as_slice(), coin)
+        .map(|output| TWData::from(output).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Verifies a signature for a message using the blockchain agnostic input.
+///
+/// \param coin The given coin type to verify the message for.
+/// \param input The serialized data of TW.MessageSigner.Proto.VerifyingInput.
+/// \return whether the signature is valid.
+#[tw_ffi(ty = static_function, class = TWMessageSigner, name = VerifyAny)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_message_signer_verify_any(
+    coin: TWFFICoinType,
+    input: Nonnull<TWData>,
+) -> bool {
+    let input = try_or_false!(TWData::from_ptr_as_ref(input));
+    let coin = try_or_false!(CoinType::try_from(coin));
+    MessageSigner::verify(input.as_slice(), coin).unwrap_or_default()
+}
+
+/// Proves the ownership of the address derived from the private key
+/// by signing a standard message with the default (or the given) signing scheme of the blockchain.
+/// If the private key can't be exported, pass the public key to get the message,
+/// and then pass the message signature to get the proof.
+///
+/// \param coin The given coin type to prove the address ownership for.
+/// \param input The serialized data of TW.MessageSigner.Proto.OwnershipProofInput.
+/// \return The serialized data of TW.MessageSigner.Proto.OwnershipProofOutput.
+#[cfg(feature = "ownership-proof")]
+#[tw_ffi(ty = static_function, class = TWMessageSigner, name = ProveOwnership)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_message_signer_prove_ownership(
+    coin: TWFFICoinType,
+    input: Nonnull<TWData>,
+) -> NullableMut<TWData> {
+    let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+
+    OwnershipProof::prove(input.as_slice(), coin)
+        .map(|output| TWData::from(output).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Verifies an ownership proof JSON envelope.
+///
+/// \param envelope The JSON envelope, same as the `TW.MessageSigner.Proto.OwnershipProofOutput.envelope` field.
+/// \param challenge The challenge the proof is expected to be issued for.
+/// \param now The current UNIX timestamp in seconds.
+/// \param max_age The maximum difference between the proof timestamp and `now` in seconds.
+/// \return whether the proof is valid.
+#[cfg(feature = "ownership-proof")]
+#[tw_ffi(ty = static_function, class = TWMessageSigner, name = VerifyOwnership)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_message_signer_verify_ownership(
+    envelope: Nonnull<TWString>,
+    challenge: Nonnull<TWString>,
+    now: u64,
+    max_age: u64,
+) -> bool {
+    let envelope = try_or_false!(TWString::from_ptr_as_ref(envelope));
+    let envelope = try_or_false!(envelope.as_str());
+    let challenge = try_or_false!(TWString::from_ptr_as_ref(challenge));
+    let challenge = try_or_false!(challenge.as_str());
+    OwnershipProof::verify(envelope, challenge, now, max_age).unwrap_or_default()
+}
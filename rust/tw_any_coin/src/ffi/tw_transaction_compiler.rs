@@ -6,6 +6,7 @@
 
 use crate::transaction_compiler::TransactionCompiler;
 use tw_coin_registry::coin_type::CoinType;
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::tw_data_vector::TWDataVector;
 use tw_memory::ffi::RawPtrTrait;
@@ -19,6 +20,7 @@ use tw_misc::try_or_else;
 /// \param input The serialized data of a signing input
 /// \return serialized data of a proto object `PreSigningOutput` includes hash.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_transaction_compiler_pre_image_hashes(
     coin: u32,
     input: *const TWData,
@@ -42,6 +44,7 @@ pub unsafe extern "C" fn tw_transaction_compiler_pre_image_hashes(
 /// \param public_keys public keys for signers to match private keys, using `TWDataVector`.
 /// \return serialized data of a proto object `SigningOutput`.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_transaction_compiler_compile(
     coin: u32,
     input: *const TWData,
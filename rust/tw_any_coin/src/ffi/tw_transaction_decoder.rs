@@ -6,6 +6,7 @@
 
 use crate::transaction_decoder::TransactionDecoder;
 use tw_coin_registry::coin_type::CoinType;
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::try_or_else;
@@ -16,6 +17,7 @@ use tw_misc::try_or_else;
 /// \param tx encoded transaction data.
 /// \return serialized protobuf message specific for the given coin.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_transaction_decoder_decode(
     coin: u32,
     tx: *const TWData,
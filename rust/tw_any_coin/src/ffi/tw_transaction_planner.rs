@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::transaction_planner::TransactionPlanner;
+use tw_coin_registry::coin_type::CoinType;
+use tw_macros::tw_catch_panic;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+
+/// Plans a transaction in the chain-agnostic format: amount, fee, change, effective fee rate and the max amount.
+/// Only Bitcoin-family and EVM chains are supported, other chains return `Error_not_supported`.
+///
+/// \param coin coin type.
+/// \param input The serialized data of a signing input (e.g. TW.BitcoinV2.Proto.SigningInput).
+/// \return The serialized data of a `TW.TransactionPlanner.Proto.TransactionPlan` proto object.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_transaction_planner_plan(
+    coin: u32,
+    input: *const TWData,
+) -> *mut TWData {
+    let input = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+
+    TransactionPlanner::plan(coin, input.as_slice())
+        .map(|output| TWData::from(output).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
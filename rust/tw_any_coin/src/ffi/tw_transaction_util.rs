@@ -6,6 +6,7 @@
 
 use crate::transaction_util::TransactionUtil;
 use tw_coin_registry::coin_type::CoinType;
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::tw_string::TWString;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::try_or_else;
@@ -16,6 +17,7 @@ use tw_misc::try_or_else;
 /// \param encoded_tx encoded transaction data.
 /// \return The TX hash of a transaction, If the input is invalid or the chain is unsupported, null is returned.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_transaction_util_calc_tx_hash(
     coin: u32,
     encoded_tx: *const TWString,
@@ -2,12 +2,19 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod account_discovery;
+pub mod address_format;
 pub mod any_address;
 pub mod any_signer;
+#[cfg(feature = "collaborative-signing")]
+pub mod collaborative_signing;
 pub mod ffi;
 pub mod message_signer;
+#[cfg(feature = "ownership-proof")]
+pub mod ownership_proof;
 pub mod transaction_compiler;
 pub mod transaction_decoder;
+pub mod transaction_planner;
 pub mod wallet_connect_request;
 
 #[cfg(feature = "test-utils")]
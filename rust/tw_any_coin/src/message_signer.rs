@@ -2,10 +2,38 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use std::borrow::Cow;
+use tw_cardano::modules::message_signer::CardanoMessageSigner;
 use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_coin_registry::blockchain_type::BlockchainType;
 use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::coin_dispatcher;
+use tw_coin_registry::registry::get_coin_item;
+use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_memory::Data;
+use tw_proto::BitcoinV2::Proto as BitcoinProto;
+use tw_proto::Cosmos::Proto as CosmosProto;
+use tw_proto::Ethereum::Proto as EthereumProto;
+use tw_proto::MessageSigner::Proto;
+use tw_proto::MessageSigner::Proto::SigningScheme;
+use tw_proto::Solana::Proto as SolanaProto;
+use tw_proto::Sui::Proto as SuiProto;
+
+/// Serializes a blockchain specific message signing input, signs it,
+/// and returns the signature or an error from the blockchain specific output.
+macro_rules! sign_chain_message {
+    ($coin:expr, $input:expr, $output:ty) => {{
+        let (ctx, entry) = coin_dispatcher($coin)?;
+        let input = tw_proto::serialize(&$input)?;
+        let output = entry.sign_message(&ctx, &input)?;
+        let output: $output = tw_proto::deserialize(&output)?;
+        if output.error != SigningErrorType::OK {
+            return SigningError::err(output.error).context(output.error_message);
+        }
+        output.signature.into_owned()
+    }};
+}
 
 /// Represents a message signer to sign regular or typed structured data for any blockchain.
 pub struct MessageSigner;
@@ -31,4 +59,195 @@ impl MessageSigner {
         let (ctx, entry) = coin_dispatcher(coin)?;
         entry.verify_message(&ctx, input)
     }
+
+    /// Signs a message using the chain-agnostic `TW.MessageSigner.Proto.SigningInput`.
+    /// Returns the serialized `TW.MessageSigner.Proto.SigningOutput`.
+    pub fn sign(input: &[u8], coin: CoinType) -> SigningResult<Data> {
+        let input: Proto::SigningInput = tw_proto::deserialize(input)?;
+        let output = Self::sign_impl(input, coin)
+            .unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e));
+        tw_proto::serialize(&output).map_err(SigningError::from)
+    }
+
+    /// Verifies a signature for a message using the chain-agnostic `TW.MessageSigner.Proto.VerifyingInput`.
+    pub fn verify(input: &[u8], coin: CoinType) -> SigningResult<bool> {
+        let input: Proto::VerifyingInput = tw_proto::deserialize(input)?;
+        Self::verify_impl(input, coin)
+    }
+
+    fn sign_impl(
+        input: Proto::SigningInput<'_>,
+        coin: CoinType,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let scheme = Self::resolve_scheme(coin, input.scheme)?;
+        let mut output = Proto::SigningOutput {
+            scheme,
+            ..Proto::SigningOutput::default()
+        };
+
+        let signature = match scheme {
+            SigningScheme::Eip191 | SigningScheme::Eip712 => {
+                let chain_input = EthereumProto::MessageSigningInput {
+                    private_key: input.private_key,
+                    message: input.message,
+                    chain_id: Self::eth_chain_id(input.chain_id),
+                    message_type: Self::eth_message_type(scheme, input.chain_id),
+                };
+                sign_chain_message!(coin, chain_input, EthereumProto::MessageSigningOutput)
+            },
+            SigningScheme::Adr36 => {
+                let chain_input = CosmosProto::MessageSigningInput {
+                    private_key: input.private_key,
+                    message: input.message,
+                };
+                sign_chain_message!(coin, chain_input, CosmosProto::MessageSigningOutput)
+            },
+            SigningScheme::Bip322 => {
+                let chain_input = BitcoinProto::MessageSigningInput {
+                    private_key: input.private_key,
+                    message: input.message,
+                };
+                sign_chain_message!(coin, chain_input, BitcoinProto::MessageSigningOutput)
+            },
+            SigningScheme::SolanaOffchain | SigningScheme::SolanaOffchainV0 => {
+                let chain_input = SolanaProto::MessageSigningInput {
+                    private_key: input.private_key,
+                    message: input.message,
+                    message_type: Self::solana_message_type(scheme),
+                };
+                sign_chain_message!(coin, chain_input, SolanaProto::MessageSigningOutput)
+            },
+            SigningScheme::SuiPersonal => {
+                let chain_input = SuiProto::MessageSigningInput {
+                    private_key: input.private_key,
+                    message: input.message,
+                };
+                sign_chain_message!(coin, chain_input, SuiProto::MessageSigningOutput)
+            },
+            SigningScheme::Cip8 => {
+                let data_signature = CardanoMessageSigner::sign_data(
+                    &input.private_key,
+                    &input.address,
+                    input.message.as_bytes(),
+                )?;
+                output.key = Cow::Owned(data_signature.key.to_hex());
+                data_signature.signature.to_hex()
+            },
+            SigningScheme::Default => {
+                return SigningError::err(SigningErrorType::Error_not_supported)
+                    .context(format!("{scheme:?} message signing is not supported"))
+            },
+        };
+
+        output.signature = Cow::Owned(signature);
+        Ok(output)
+    }
+
+    fn verify_impl(input: Proto::VerifyingInput<'_>, coin: CoinType) -> SigningResult<bool> {
+        let scheme = Self::resolve_scheme(coin, input.scheme)?;
+
+        let chain_input = match scheme {
+            // EVM signer detects whether the message is a typed data.
+            SigningScheme::Eip191 | SigningScheme::Eip712 => {
+                tw_proto::serialize(&EthereumProto::MessageVerifyingInput {
+                    message: input.message,
+                    public_key: input.public_key,
+                    signature: input.signature,
+                })?
+            },
+            SigningScheme::Adr36 => tw_proto::serialize(&CosmosProto::MessageVerifyingInput {
+                message: input.message,
+                public_key: input.public_key,
+                signature: input.signature,
+            })?,
+            SigningScheme::Bip322 => tw_proto::serialize(&BitcoinProto::MessageVerifyingInput {
+                message: input.message,
+                public_key: input.public_key,
+                signature: input.signature,
+            })?,
+            SigningScheme::SolanaOffchain | SigningScheme::SolanaOffchainV0 => {
+                tw_proto::serialize(&SolanaProto::MessageVerifyingInput {
+                    message: input.message,
+                    public_key: input.public_key,
+                    signature: input.signature,
+                    message_type: Self::solana_message_type(scheme),
+                })?
+            },
+            SigningScheme::SuiPersonal => tw_proto::serialize(&SuiProto::MessageVerifyingInput {
+                message: input.message,
+                public_key: input.public_key,
+                signature: input.signature,
+            })?,
+            SigningScheme::Cip8 => {
+                let Ok(signature) = input.signature.decode_hex() else {
+                    return Ok(false);
+                };
+                return Ok(CardanoMessageSigner::verify_data(
+                    &input.address,
+                    input.message.as_bytes(),
+                    &signature,
+                    &input.public_key,
+                ));
+            },
+            SigningScheme::Default => {
+                return SigningError::err(SigningErrorType::Error_not_supported)
+                    .context(format!("{scheme:?} message verification is not supported"))
+            },
+        };
+
+        let (ctx, entry) = coin_dispatcher(coin)?;
+        entry.verify_message(&ctx, &chain_input)
+    }
+
+    /// Returns the signing scheme to use for the given `coin`.
+    /// [`SigningScheme::Default`] is replaced with the default scheme of the blockchain.
+    pub(crate) fn resolve_scheme(
+        coin: CoinType,
+        scheme: SigningScheme,
+    ) -> SigningResult<SigningScheme> {
+        let blockchain = get_coin_item(coin)?.blockchain;
+        let supported: &[SigningScheme] = match blockchain {
+            BlockchainType::Ethereum | BlockchainType::Ronin => {
+                &[SigningScheme::Eip191, SigningScheme::Eip712]
+            },
+            BlockchainType::Cosmos => &[SigningScheme::Adr36],
+            BlockchainType::Bitcoin => &[SigningScheme::Bip322],
+            BlockchainType::Solana => &[
+                SigningScheme::SolanaOffchain,
+                SigningScheme::SolanaOffchainV0,
+            ],
+            BlockchainType::Sui => &[SigningScheme::SuiPersonal],
+            // Cardano is implemented in C++, but its CIP-8 messages are signed in Rust.
+            _ if coin == CoinType::Cardano => &[SigningScheme::Cip8],
+            _ => &[],
+        };
+
+        match (supported.first(), scheme) {
+            (Some(default), SigningScheme::Default) => Ok(*default),
+            (_, scheme) if supported.contains(&scheme) => Ok(scheme),
+            _ => SigningError::err(SigningErrorType::Error_not_supported).context(format!(
+                "{scheme:?} message signing is not supported by {blockchain:?} blockchain"
+            )),
+        }
+    }
+
+    fn eth_chain_id(chain_id: u64) -> Option<EthereumProto::MaybeChainId> {
+        (chain_id != 0).then_some(EthereumProto::MaybeChainId { chain_id })
+    }
+
+    fn eth_message_type(scheme: SigningScheme, chain_id: u64) -> EthereumProto::MessageType {
+        match (scheme, chain_id) {
+            (SigningScheme::Eip712, 0) => EthereumProto::MessageType::MessageType_typed,
+            (SigningScheme::Eip712, _) => EthereumProto::MessageType::MessageType_typed_eip155,
+            (_, 0) => EthereumProto::MessageType::MessageType_legacy,
+            (_, _) => EthereumProto::MessageType::MessageType_eip155,
+        }
+    }
+
+    fn solana_message_type(scheme: SigningScheme) -> SolanaProto::MessageType {
+        match scheme {
+            SigningScheme::SolanaOffchainV0 => SolanaProto::MessageType::MessageType_offchain_v0,
+            _ => SolanaProto::MessageType::MessageType_raw,
+        }
+    }
 }
@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::any_address::AnyAddress;
+use crate::message_signer::MessageSigner;
+use serde::{Deserialize, Serialize};
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::registry::get_coin_item;
+use tw_coin_registry::tw_derivation::TWDerivation;
+use tw_encoding::hex::{self, ToHex};
+use tw_keypair::tw::{PrivateKey, PublicKey};
+use tw_memory::Data;
+use tw_proto::MessageSigner::Proto;
+use tw_proto::MessageSigner::Proto::SigningScheme;
+
+pub const OWNERSHIP_PROOF_VERSION: u32 = 1;
+
+/// The canonical JSON envelope of an ownership proof.
+/// The fields are declared in the alphabetical order, so the envelope is serialized with sorted keys.
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnershipProofEnvelope {
+    pub address: String,
+    pub challenge: String,
+    /// Coin type, e.g. `60` for Ethereum.
+    pub coin: u32,
+    pub message: String,
+    /// Hex encoded public key of the coin's public key type.
+    pub public_key: String,
+    pub scheme: String,
+    pub signature: String,
+    pub timestamp: u64,
+    pub version: u32,
+}
+
+/// Proves the ownership of an address by signing a standard message with the blockchain message signing scheme.
+///
+/// The message is signed either with the given private key, or by the host if the private key can't be exported:
+/// the host gets the message to sign by the public key, and passes the signature back to get the envelope.
+pub struct OwnershipProof;
+
+impl OwnershipProof {
+    /// Signs the ownership proof message using `TW.MessageSigner.Proto.OwnershipProofInput`.
+    /// Returns the serialized `TW.MessageSigner.Proto.OwnershipProofOutput`.
+    pub fn prove(input: &[u8], coin: CoinType) -> SigningResult<Data> {
+        let input: Proto::OwnershipProofInput = tw_proto::deserialize(input)?;
+        let output = Self::prove_impl(input, coin)
+            .unwrap_or_else(|e| signing_output_error!(Proto::OwnershipProofOutput, e));
+        tw_proto::serialize(&output).map_err(SigningError::from)
+    }
+
+    /// Verifies the ownership proof JSON `envelope` issued for the given `challenge`:
+    /// * the address must be derived from the public key using any derivation of the coin,
+    /// * the message must be signed by the public key,
+    /// * the proof timestamp must be within `max_age` seconds of `now` (UNIX timestamps in seconds).
+    pub fn verify(envelope: &str, challenge: &str, now: u64, max_age: u64) -> SigningResult<bool> {
+        let envelope: OwnershipProofEnvelope = serde_json::from_str(envelope)?;
+        if envelope.version != OWNERSHIP_PROOF_VERSION
+            || envelope.challenge != challenge
+            || envelope.timestamp.abs_diff(now) > max_age
+        {
+            return Ok(false);
+        }
+
+        let coin = CoinType::try_from(envelope.coin)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Unknown coin type")?;
+        let coin_item = get_coin_item(coin)?;
+        let scheme = scheme_from_name(&envelope.scheme)?;
+
+        let public_key_bytes = hex::decode(&envelope.public_key)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid public key hex")?;
+        let public_key = PublicKey::new(public_key_bytes.clone(), coin_item.public_key_type)?;
+
+        let is_derived = coin_item.derivation.iter().any(|derivation| {
+            AnyAddress::with_public_key(coin, public_key.clone(), derivation.name, None)
+                .is_ok_and(|address| address.description() == envelope.address)
+        });
+        if !is_derived {
+            return Ok(false);
+        }
+
+        let expected_message = Self::message(
+            coin,
+            &envelope.address,
+            &envelope.challenge,
+            envelope.timestamp,
+        )?;
+        if envelope.message != expected_message {
+            return Ok(false);
+        }
+
+        let verifying_input = Proto::VerifyingInput {
+            message: envelope.message.into(),
+            public_key: public_key_bytes.into(),
+            signature: envelope.signature.into(),
+            scheme,
+            address: envelope.address.into(),
+        };
+        MessageSigner::verify(&tw_proto::serialize(&verifying_input)?, coin)
+    }
+
+    /// Returns the ownership proof message:
+    /// ```text
+    /// Prove ownership of {address} on {coin name}.
+    /// Challenge: {challenge}
+    /// Timestamp: {timestamp}
+    /// ```
+    pub fn message(
+        coin: CoinType,
+        address: &str,
+        challenge: &str,
+        timestamp: u64,
+    ) -> SigningResult<String> {
+        let coin_name = &get_coin_item(coin)?.name;
+        Ok(format!(
+            "Prove ownership of {address} on {coin_name}.\nChallenge: {challenge}\nTimestamp: {timestamp}"
+        ))
+    }
+
+    fn prove_impl(
+        input: Proto::OwnershipProofInput<'_>,
+        coin: CoinType,
+    ) -> SigningResult<Proto::OwnershipProofOutput<'static>> {
+        if input.challenge.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Ownership proof challenge must not be empty");
+        }
+        if input.scheme == SigningScheme::Eip712 {
+            return SigningError::err(SigningErrorType::Error_not_supported)
+                .context("Ownership proof cannot be signed as a typed data");
+        }
+        let scheme = MessageSigner::resolve_scheme(coin, input.scheme)?;
+        let scheme_name = scheme_name(scheme)?;
+
+        let derivation = TWDerivation::from_repr(input.derivation)
+            .map(Derivation::from)
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("Unknown derivation")?;
+
+        let coin_item = get_coin_item(coin)?;
+        let public_key = if !input.private_key.is_empty() {
+            PrivateKey::new(input.private_key.to_vec())?
+                .get_public_key_by_type(coin_item.public_key_type)?
+        } else if !input.public_key.is_empty() {
+            PublicKey::new(input.public_key.to_vec(), coin_item.public_key_type)?
+        } else {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Either a private key or a public key must be set");
+        };
+        let public_key_bytes = public_key.to_bytes();
+        let address = AnyAddress::with_public_key(coin, public_key, derivation, None)?
+            .description()
+            .to_string();
+        let message = Self::message(coin, &address, &input.challenge, input.timestamp)?;
+
+        let signature = if !input.private_key.is_empty() {
+            Self::sign_message(&input.private_key, &message, scheme, coin)?
+        } else if !input.signature.is_empty() {
+            let verifying_input = Proto::VerifyingInput {
+                message: message.as_str().into(),
+                public_key: public_key_bytes.as_slice().into(),
+                signature: input.signature.clone(),
+                scheme,
+                address: address.as_str().into(),
+            };
+            if !MessageSigner::verify(&tw_proto::serialize(&verifying_input)?, coin)? {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("The signature doesn't match the ownership proof message");
+            }
+            input.signature.into_owned()
+        } else {
+            // The host signs the message, and passes the signature to get the envelope.
+            return Ok(Proto::OwnershipProofOutput {
+                address: address.into(),
+                message: message.into(),
+                ..Proto::OwnershipProofOutput::default()
+            });
+        };
+
+        let envelope = OwnershipProofEnvelope {
+            address: address.clone(),
+            challenge: input.challenge.into_owned(),
+            coin: coin as u32,
+            message: message.clone(),
+            public_key: public_key_bytes.to_hex(),
+            scheme: scheme_name.to_string(),
+            signature: signature.clone(),
+            timestamp: input.timestamp,
+            version: OWNERSHIP_PROOF_VERSION,
+        };
+
+        Ok(Proto::OwnershipProofOutput {
+            envelope: serde_json::to_string(&envelope)?.into(),
+            address: address.into(),
+            message: message.into(),
+            signature: signature.into(),
+            ..Proto::OwnershipProofOutput::default()
+        })
+    }
+
+    fn sign_message(
+        private_key: &[u8],
+        message: &str,
+        scheme: SigningScheme,
+        coin: CoinType,
+    ) -> SigningResult<String> {
+        let signing_input = Proto::SigningInput {
+            private_key: private_key.into(),
+            message: message.into(),
+            scheme,
+            ..Proto::SigningInput::default()
+        };
+        let signing_output: Proto::SigningOutput = tw_proto::deserialize(&MessageSigner::sign(
+            &tw_proto::serialize(&signing_input)?,
+            coin,
+        )?)?;
+        if signing_output.error != SigningErrorType::OK {
+            return SigningError::err(signing_output.error).context(signing_output.error_message);
+        }
+        Ok(signing_output.signature.into_owned())
+    }
+}
+
+fn scheme_name(scheme: SigningScheme) -> SigningResult<&'static str> {
+    match scheme {
+        SigningScheme::Eip191 => Ok("eip191"),
+        SigningScheme::Adr36 => Ok("adr36"),
+        SigningScheme::Bip322 => Ok("bip322"),
+        SigningScheme::SolanaOffchain => Ok("solana-offchain"),
+        SigningScheme::SolanaOffchainV0 => Ok("solana-offchain-v0"),
+        SigningScheme::SuiPersonal => Ok("sui-personal"),
+        SigningScheme::Default | SigningScheme::Eip712 | SigningScheme::Cip8 => {
+            SigningError::err(SigningErrorType::Error_not_supported).context(format!(
+                "{scheme:?} scheme is not supported by ownership proofs"
+            ))
+        },
+    }
+}
+
+fn scheme_from_name(name: &str) -> SigningResult<SigningScheme> {
+    match name {
+        "eip191" => Ok(SigningScheme::Eip191),
+        "adr36" => Ok(SigningScheme::Adr36),
+        "bip322" => Ok(SigningScheme::Bip322),
+        "solana-offchain" => Ok(SigningScheme::SolanaOffchain),
+        "solana-offchain-v0" => Ok(SigningScheme::SolanaOffchainV0),
+        "sui-personal" => Ok(SigningScheme::SuiPersonal),
+        _ => SigningError::err(SigningErrorType::Error_invalid_params)
+            .context(format!("Unknown ownership proof scheme: {name}")),
+    }
+}
@@ -5,6 +5,8 @@
 pub mod address_utils;
 pub mod plan_utils;
 pub mod sign_utils;
+pub mod test_vectors;
 pub mod transaction_calc_tx_hash_utils;
 pub mod transaction_decode_utils;
+pub mod transaction_planner_utils;
 pub mod wallet_connect_utils;
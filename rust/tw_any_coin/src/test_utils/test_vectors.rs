@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Cross-implementation test vectors loaded from JSON fixtures.
+//!
+//! A fixture file contains vectors generated by a reference implementation.
+//! All byte arrays are hex-encoded. A vector requires `curve` or `coin` and `private_key` only,
+//! the other fields are checked if set:
+//!
+//! ```json
+//! {
+//!   "source": "trezor-crypto",
+//!   "curves": [
+//!     {
+//!       "curve": "secp256k1",
+//!       "private_key": "cc44...62a4",
+//!       "public_key_type": "secp256k1",
+//!       "public_key": "03fe...6f32",
+//!       "message": "e47a...77ea",
+//!       "signature": "bc70...8201"
+//!     }
+//!   ],
+//!   "coins": [
+//!     {
+//!       "coin": "ethereum",
+//!       "private_key": "03a9...a25d",
+//!       "address": "0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37",
+//!       "scheme": "Eip191",
+//!       "message": "Foo",
+//!       "signature": "21a7...101b"
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Curve vectors:
+//! * `curve` is a [`Curve`] name as in `registry.json`.
+//! * `public_key_type` is a [`PublicKeyType`] name, the default type of the `curve` if not set.
+//! * `public_key` is checked against the key derived from `private_key`.
+//! * `message` is signed and the result is checked against `signature`.
+//!   The signature is also verified with the derived public key.
+//!
+//! Coin vectors:
+//! * `coin` is a coin ID as in `registry.json`.
+//! * `address` is checked against the address derived with the default derivation.
+//! * `message` is a UTF-8 message signed with the `scheme`, see `TW.MessageSigner.Proto.SigningScheme`.
+//!   The result is checked against `signature`, and the signature is verified.
+
+use crate::any_address::AnyAddress;
+use crate::message_signer::MessageSigner;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::registry::{get_coin_item, registry_iter};
+use tw_encoding::hex::as_hex::{self, AsHex};
+use tw_encoding::hex::ToHex;
+use tw_keypair::test_utils::curve_utils::public_key_type;
+use tw_keypair::tw::{Curve, PrivateKey, PublicKey, PublicKeyType};
+use tw_memory::Data;
+use tw_proto::MessageSigner::Proto;
+use tw_proto::{deserialize, serialize};
+
+#[derive(Deserialize)]
+pub struct TestVectors {
+    /// The reference implementation that generated the vectors.
+    pub source: String,
+    #[serde(default)]
+    pub curves: Vec<CurveVector>,
+    #[serde(default)]
+    pub coins: Vec<CoinVector>,
+}
+
+#[derive(Deserialize)]
+pub struct CurveVector {
+    pub curve: Curve,
+    #[serde(with = "as_hex")]
+    pub private_key: Data,
+    pub public_key_type: Option<PublicKeyType>,
+    pub public_key: Option<AsHex<Data>>,
+    pub message: Option<AsHex<Data>>,
+    pub signature: Option<AsHex<Data>>,
+}
+
+#[derive(Deserialize)]
+pub struct CoinVector {
+    pub coin: String,
+    #[serde(with = "as_hex")]
+    pub private_key: Data,
+    pub address: Option<String>,
+    pub scheme: Option<String>,
+    pub message: Option<String>,
+    pub signature: Option<String>,
+}
+
+impl TestVectors {
+    pub fn from_json(json: &str) -> TestVectors {
+        serde_json::from_str(json).expect("Invalid test vectors JSON")
+    }
+
+    /// Runs all fixtures of the given `dir` with the `.json` extension.
+    /// Returns the number of tested fixtures.
+    pub fn run_dir(dir: &Path) -> usize {
+        let mut fixtures: Vec<_> = fs::read_dir(dir)
+            .expect("Expected a valid test vectors directory")
+            .map(|entry| entry.expect("Error reading a test vectors entry").path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        fixtures.sort();
+
+        for fixture in fixtures.iter() {
+            let json = fs::read_to_string(fixture).expect("Error reading a test vectors file");
+            TestVectors::from_json(&json).run(&fixture.display().to_string());
+        }
+        fixtures.len()
+    }
+
+    /// Asserts every vector, `name` is used in the assertion messages.
+    pub fn run(&self, name: &str) {
+        for (i, vector) in self.curves.iter().enumerate() {
+            vector.run(&format!("{name}: curves[{i}] ({})", self.source));
+        }
+        for (i, vector) in self.coins.iter().enumerate() {
+            vector.run(&format!("{name}: coins[{i}] ({})", self.source));
+        }
+    }
+}
+
+impl CurveVector {
+    pub fn run(&self, name: &str) {
+        let private_key = PrivateKey::new(self.private_key.clone())
+            .unwrap_or_else(|e| panic!("{name}: invalid private key: {e:?}"));
+        let public_key_type = self
+            .public_key_type
+            .unwrap_or_else(|| public_key_type(self.curve));
+        let public_key = private_key
+            .get_public_key_by_type(public_key_type)
+            .unwrap_or_else(|e| panic!("{name}: error deriving public key: {e:?}"));
+
+        if let Some(AsHex(ref expected)) = self.public_key {
+            assert_eq!(public_key.to_bytes().to_hex(), expected.to_hex(), "{name}");
+        }
+
+        let Some(AsHex(ref message)) = self.message else {
+            return;
+        };
+        let signature = private_key
+            .sign(message, self.curve)
+            .unwrap_or_else(|e| panic!("{name}: error signing: {e:?}"));
+        if let Some(AsHex(ref expected)) = self.signature {
+            assert_eq!(signature.to_hex(), expected.to_hex(), "{name}");
+        }
+        assert!(public_key.verify(&signature, message), "{name}: !verify");
+    }
+}
+
+impl CoinVector {
+    pub fn run(&self, name: &str) {
+        let coin = registry_iter()
+            .find(|item| item.id == self.coin)
+            .unwrap_or_else(|| panic!("{name}: unknown coin '{}'", self.coin))
+            .coin_id;
+
+        if let Some(ref expected) = self.address {
+            let public_key = self.public_key(coin, name);
+            let address = AnyAddress::with_public_key(coin, public_key, Derivation::Default, None)
+                .unwrap_or_else(|e| panic!("{name}: error deriving address: {e:?}"));
+            assert_eq!(address.description(), expected, "{name}");
+        }
+
+        if let Some(ref message) = self.message {
+            self.run_message(coin, message, name);
+        }
+    }
+
+    fn run_message(&self, coin: CoinType, message: &str, name: &str) {
+        let scheme = self
+            .scheme
+            .as_deref()
+            .map(|scheme| signing_scheme(scheme, name))
+            .unwrap_or_default();
+
+        let input = Proto::SigningInput {
+            private_key: self.private_key.as_slice().into(),
+            message: message.into(),
+            scheme,
+            ..Proto::SigningInput::default()
+        };
+        let output = MessageSigner::sign(&serialize(&input).unwrap(), coin)
+            .unwrap_or_else(|e| panic!("{name}: error signing message: {e:?}"));
+        let output: Proto::SigningOutput = deserialize(&output).unwrap();
+        assert_eq!(
+            output.error,
+            SigningErrorType::OK,
+            "{name}: {}",
+            output.error_message
+        );
+        if let Some(ref expected) = self.signature {
+            assert_eq!(output.signature, expected.as_str(), "{name}");
+        }
+
+        let input = Proto::VerifyingInput {
+            message: message.into(),
+            public_key: self.public_key(coin, name).to_bytes().into(),
+            signature: output.signature,
+            scheme,
+            ..Proto::VerifyingInput::default()
+        };
+        let verified = MessageSigner::verify(&serialize(&input).unwrap(), coin)
+            .unwrap_or_else(|e| panic!("{name}: error verifying message: {e:?}"));
+        assert!(verified, "{name}: !verify");
+    }
+
+    fn public_key(&self, coin: CoinType, name: &str) -> PublicKey {
+        let public_key_type = get_coin_item(coin).unwrap().public_key_type;
+        PrivateKey::new(self.private_key.clone())
+            .and_then(|private_key| private_key.get_public_key_by_type(public_key_type))
+            .unwrap_or_else(|e| panic!("{name}: invalid private key: {e:?}"))
+    }
+}
+
+/// Parses the name of a `SigningScheme` variant, e.g. `Eip191`.
+/// Unlike `SigningScheme::from`, doesn't fall back to `Default` on an unknown name.
+fn signing_scheme(scheme: &str, name: &str) -> Proto::SigningScheme {
+    let parsed = Proto::SigningScheme::from(scheme);
+    if parsed == Proto::SigningScheme::Default && scheme != "Default" {
+        panic!("{name}: unknown signing scheme '{scheme}'");
+    }
+    parsed
+}
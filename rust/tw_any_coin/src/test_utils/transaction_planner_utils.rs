@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ffi::tw_transaction_planner::tw_transaction_planner_plan;
+use tw_coin_registry::coin_type::CoinType;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::Data;
+use tw_proto::TransactionPlanner::Proto;
+use tw_proto::{deserialize, serialize, MessageWrite};
+
+#[derive(Default)]
+pub struct TransactionPlannerHelper {
+    output_data: Data,
+}
+
+impl TransactionPlannerHelper {
+    pub fn plan<Input: MessageWrite>(
+        &mut self,
+        coin_type: CoinType,
+        input: &Input,
+    ) -> Proto::TransactionPlan<'_> {
+        let input_data = TWDataHelper::create(serialize(input).unwrap());
+
+        self.output_data = TWDataHelper::wrap(unsafe {
+            tw_transaction_planner_plan(coin_type as u32, input_data.ptr())
+        })
+        .to_vec()
+        .expect("!tw_transaction_planner_plan returned nullptr");
+
+        deserialize(&self.output_data).unwrap()
+    }
+}
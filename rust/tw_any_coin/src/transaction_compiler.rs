@@ -4,9 +4,14 @@
 
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::blockchain_type::BlockchainType;
 use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::coin_dispatcher;
+use tw_coin_registry::registry::get_coin_item;
+use tw_keypair::external_signer::ExternalSigner;
 use tw_memory::Data;
+use tw_proto::Solana::Proto as SolanaProto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
 
 /// Non-core transaction utility methods, like building a transaction using an external signature.
 pub struct TransactionCompiler;
@@ -34,4 +39,96 @@ impl TransactionCompiler {
             .compile(&ctx, input, signatures, public_keys)
             .map_err(SigningError::from)
     }
+
+    /// Returns the data that must be signed by an external signer to compile a transaction:
+    /// the pre-image hash, or the pre-image data if the blockchain doesn't hash it (e.g. Solana).
+    ///
+    /// Use it along with [`TransactionCompiler::compile`] if the signature is obtained asynchronously.
+    /// Only the transactions signed by one key are supported.
+    pub fn data_to_sign(coin: CoinType, input: &[u8]) -> SigningResult<Data> {
+        let blockchain = get_coin_item(coin)?.blockchain;
+        let preimage = Self::preimage_hashes(coin, input)?;
+
+        match blockchain {
+            BlockchainType::Solana => {
+                let output: SolanaProto::PreSigningOutput = tw_proto::deserialize(&preimage)?;
+                if output.error != SigningErrorType::OK {
+                    return SigningError::err(output.error).context(output.error_message);
+                }
+                if output.signers.len() != 1 {
+                    return SigningError::err(SigningErrorType::Error_not_supported)
+                        .context("External signer supports the transactions with one signer only");
+                }
+                Ok(output.data.into_owned())
+            },
+            // Every UTXO has its own sighash.
+            BlockchainType::Bitcoin
+            | BlockchainType::BitcoinCash
+            | BlockchainType::Decred
+            | BlockchainType::Groestlcoin
+            | BlockchainType::Komodo
+            | BlockchainType::Liquid
+            | BlockchainType::Zcash => SigningError::err(SigningErrorType::Error_not_supported)
+                .context(format!(
+                    "External signer is not supported by {blockchain:?} blockchain"
+                )),
+            // Listed explicitly, so a new blockchain must be classified here before it compiles.
+            BlockchainType::Aptos
+            | BlockchainType::AvalancheP
+            | BlockchainType::AvalancheX
+            | BlockchainType::Binance
+            | BlockchainType::Cosmos
+            | BlockchainType::Ethereum
+            | BlockchainType::Greenfield
+            | BlockchainType::InternetComputer
+            | BlockchainType::Kusama
+            | BlockchainType::Nano
+            | BlockchainType::NativeEvmos
+            | BlockchainType::NativeInjective
+            | BlockchainType::Pactus
+            | BlockchainType::Polkadot
+            | BlockchainType::Polymesh
+            | BlockchainType::Ripple
+            | BlockchainType::Ronin
+            | BlockchainType::Sui
+            | BlockchainType::TheOpenNetwork
+            | BlockchainType::Thorchain => {
+                let output: CompilerProto::PreSigningOutput = tw_proto::deserialize(&preimage)?;
+                if output.error != SigningErrorType::OK {
+                    return SigningError::err(output.error).context(output.error_message);
+                }
+                if output.data_hash.is_empty() {
+                    Ok(output.data.into_owned())
+                } else {
+                    Ok(output.data_hash.into_owned())
+                }
+            },
+            BlockchainType::Unsupported => SigningError::err(SigningErrorType::Error_not_supported)
+                .context(format!(
+                    "External signer is not supported by {blockchain:?} blockchain"
+                )),
+        }
+    }
+
+    /// Compiles a complete transaction with the signature made by the `signer`
+    /// in place of a private key. The transaction is encoded and hashed by the library.
+    pub fn compile_with_external_signer(
+        coin: CoinType,
+        input: &[u8],
+        signer: &dyn ExternalSigner,
+    ) -> SigningResult<Data> {
+        let public_key_type = get_coin_item(coin)?.public_key_type;
+        let data_to_sign = Self::data_to_sign(coin, input)?;
+
+        let signature = signer
+            .sign(&data_to_sign)
+            .into_tw()
+            .context("Error signing by the external signer")?;
+        let public_key = signer
+            .public_key(public_key_type)
+            .into_tw()
+            .context("Error getting the external signer public key")?;
+
+        Self::compile(coin, input, vec![signature], vec![public_key.to_bytes()])
+    }
 }
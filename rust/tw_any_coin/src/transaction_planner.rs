@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::dispatcher::coin_dispatcher;
+use tw_memory::Data;
+use tw_proto::TransactionPlanner::Proto;
+
+pub struct TransactionPlanner;
+
+impl TransactionPlanner {
+    /// Plans a transaction specified by the chain-specific signing input in the chain-agnostic format.
+    /// Returns a serialized `TransactionPlan` with an error if the transaction can't be planned.
+    pub fn plan(coin: CoinType, input: &[u8]) -> SigningResult<Data> {
+        let (ctx, entry) = coin_dispatcher(coin)?;
+        entry.transaction_plan(&ctx, input).or_else(|e| {
+            let output = signing_output_error!(Proto::TransactionPlan, e);
+            tw_proto::serialize(&output).map_err(SigningError::from)
+        })
+    }
+}
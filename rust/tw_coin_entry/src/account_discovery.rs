@@ -0,0 +1,318 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! BIP-44 account discovery.
+//!
+//! Accounts are scanned sequentially starting from `0`.
+//! Addresses of each account are scanned until `gap_limit` consecutive unused addresses are found.
+//! The discovery stops at the first account that has no activity.
+//!
+//! The scanning doesn't derive any keys itself. Instead, [`AccountDiscovery::discover`] calls the given
+//! `has_activity` callback with the full derivation path of every address to check,
+//! so the caller can derive the address from an xpub or an HD node and look it up in a blockchain index.
+//!
+//! See https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki#account-discovery
+
+use crate::derivation::{ChildIndex, DerivationPath};
+use std::ops::Range;
+
+/// Address gap limit recommended by BIP-44.
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+/// Chain index of the receiving addresses.
+pub const EXTERNAL_CHAIN: u32 = 0;
+/// Chain index of the change addresses.
+pub const INTERNAL_CHAIN: u32 = 1;
+/// Non-hardened indexes are less than `2^31`.
+const MAX_NORMAL_INDEX: u32 = (1 << 31) - 1;
+/// Account indexes are hardened, so they must be less than `2^31` too.
+pub const MAX_ACCOUNTS: u32 = MAX_NORMAL_INDEX;
+
+/// An account found during the discovery.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiscoveredAccount {
+    /// Account index.
+    pub account: u32,
+    /// Account level derivation path, e.g. `m/44'/0'/1'`.
+    pub path: DerivationPath,
+    /// Index of the last used receiving address.
+    pub last_used_external: Option<u32>,
+    /// Index of the last used change address. Always `None` if change addresses are not scanned.
+    pub last_used_internal: Option<u32>,
+}
+
+impl DiscoveredAccount {
+    /// Returns the index of the first unused receiving address.
+    pub fn next_external_index(&self) -> u32 {
+        self.last_used_external.map_or(0, |index| index + 1)
+    }
+
+    /// Returns the index of the first unused change address.
+    pub fn next_internal_index(&self) -> u32 {
+        self.last_used_internal.map_or(0, |index| index + 1)
+    }
+}
+
+/// BIP-44 account discovery parameters.
+#[derive(Clone, Debug)]
+pub struct AccountDiscovery {
+    purpose: ChildIndex,
+    coin_type: ChildIndex,
+    gap_limit: u32,
+    max_accounts: u32,
+    scan_internal: bool,
+}
+
+impl AccountDiscovery {
+    /// Creates the discovery of `m/purpose'/coin_type'/account'` accounts.
+    pub fn new(purpose: u32, coin_type: u32) -> AccountDiscovery {
+        AccountDiscovery {
+            purpose: ChildIndex::Hardened(purpose),
+            coin_type: ChildIndex::Hardened(coin_type),
+            gap_limit: DEFAULT_GAP_LIMIT,
+            max_accounts: MAX_ACCOUNTS,
+            scan_internal: false,
+        }
+    }
+
+    /// Creates the discovery with the purpose and coin type taken from the given derivation path,
+    /// e.g. a default coin derivation `m/84'/0'/0'/0/0`.
+    /// Returns `None` if the path is shorter than two components.
+    pub fn with_derivation_path(path: &DerivationPath) -> Option<AccountDiscovery> {
+        let (purpose, coin_type) = match path.path() {
+            [purpose, coin_type, ..] => (*purpose, *coin_type),
+            _ => return None,
+        };
+        Some(AccountDiscovery {
+            purpose,
+            coin_type,
+            ..AccountDiscovery::new(0, 0)
+        })
+    }
+
+    /// Sets the number of consecutive unused addresses after which the scanning of a chain stops.
+    /// Zero is treated as one.
+    pub fn gap_limit(mut self, gap_limit: u32) -> AccountDiscovery {
+        self.gap_limit = gap_limit.max(1);
+        self
+    }
+
+    /// Sets the maximum number of accounts to scan.
+    /// Clamped to [`MAX_ACCOUNTS`].
+    pub fn max_accounts(mut self, max_accounts: u32) -> AccountDiscovery {
+        self.max_accounts = max_accounts.min(MAX_ACCOUNTS);
+        self
+    }
+
+    /// Whether change addresses should be scanned in addition to receiving addresses.
+    pub fn scan_internal(mut self, scan_internal: bool) -> AccountDiscovery {
+        self.scan_internal = scan_internal;
+        self
+    }
+
+    /// Walks the accounts and returns all accounts that have activity.
+    ///
+    /// `has_activity` is called with the derivation path of every address to check,
+    /// e.g. `m/44'/0'/0'/0/5`. The discovery is aborted if the callback returns an error.
+    pub fn discover<F, E>(&self, mut has_activity: F) -> Result<Vec<DiscoveredAccount>, E>
+    where
+        F: FnMut(&DerivationPath) -> Result<bool, E>,
+    {
+        let mut accounts = Vec::new();
+
+        for account in 0..self.max_accounts {
+            let last_used_external = self.scan_chain(account, EXTERNAL_CHAIN, &mut has_activity)?;
+            let last_used_internal = if self.scan_internal {
+                self.scan_chain(account, INTERNAL_CHAIN, &mut has_activity)?
+            } else {
+                None
+            };
+
+            if last_used_external.is_none() && last_used_internal.is_none() {
+                break;
+            }
+
+            accounts.push(DiscoveredAccount {
+                account,
+                path: self.account_path(account),
+                last_used_external,
+                last_used_internal,
+            });
+        }
+
+        Ok(accounts)
+    }
+
+    /// Returns the address indexes of a chain that still need to be checked,
+    /// given the index of the last used address and the number of addresses checked so far starting from `0`.
+    /// Empty once `gap_limit` consecutive unused addresses have been checked.
+    ///
+    /// Lets hosts scan an account step by step, e.g. by an account-level extended public key,
+    /// when the activity can't be checked synchronously from a callback.
+    pub fn pending_indexes(&self, last_used: Option<u32>, checked: u32) -> Range<u32> {
+        let first_unused = last_used.map_or(0, |index| index + 1);
+        let end = first_unused
+            .saturating_add(self.gap_limit)
+            .min(MAX_NORMAL_INDEX + 1);
+        checked.min(end)..end
+    }
+
+    /// Returns the index of the last used address of the given chain.
+    fn scan_chain<F, E>(
+        &self,
+        account: u32,
+        chain: u32,
+        has_activity: &mut F,
+    ) -> Result<Option<u32>, E>
+    where
+        F: FnMut(&DerivationPath) -> Result<bool, E>,
+    {
+        let mut last_used = None;
+        let mut checked = 0;
+
+        while let Some(index) = self.pending_indexes(last_used, checked).next() {
+            if has_activity(&self.address_path(account, chain, index))? {
+                last_used = Some(index);
+            }
+            checked = index + 1;
+        }
+        Ok(last_used)
+    }
+
+    /// Returns the derivation path of the address of the given account.
+    pub fn address_path(&self, account: u32, chain: u32, index: u32) -> DerivationPath {
+        DerivationPath::new(vec![
+            self.purpose,
+            self.coin_type,
+            ChildIndex::Hardened(account),
+            ChildIndex::Normal(chain),
+            ChildIndex::Normal(index),
+        ])
+    }
+
+    /// Returns the account level derivation path, e.g. `m/44'/0'/1'`.
+    pub fn account_path(&self, account: u32) -> DerivationPath {
+        DerivationPath::new(vec![
+            self.purpose,
+            self.coin_type,
+            ChildIndex::Hardened(account),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::convert::Infallible;
+    use std::str::FromStr;
+
+    fn used(paths: &[&str]) -> HashSet<String> {
+        paths.iter().map(|path| path.to_string()).collect()
+    }
+
+    #[test]
+    fn test_discover_accounts() {
+        let used = used(&[
+            "m/44'/0'/0'/0/0",
+            "m/44'/0'/0'/0/19",
+            "m/44'/0'/0'/0/39",
+            "m/44'/0'/1'/0/3",
+            // Beyond the gap limit of the account #1.
+            "m/44'/0'/1'/0/30",
+            // Account #2 is empty, so account #3 is never reached.
+            "m/44'/0'/3'/0/0",
+        ]);
+
+        let mut checked = 0;
+        let accounts = AccountDiscovery::new(44, 0)
+            .discover(|path| {
+                checked += 1;
+                Ok::<_, Infallible>(used.contains(&path.to_string()))
+            })
+            .unwrap();
+
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].account, 0);
+        assert_eq!(accounts[0].path.to_string(), "m/44'/0'/0'");
+        assert_eq!(accounts[0].last_used_external, Some(39));
+        assert_eq!(accounts[0].next_external_index(), 40);
+        assert_eq!(accounts[0].last_used_internal, None);
+
+        assert_eq!(accounts[1].account, 1);
+        assert_eq!(accounts[1].last_used_external, Some(3));
+
+        // 60 addresses of the account #0, 24 of the account #1 and 20 of the account #2.
+        assert_eq!(checked, 60 + 24 + 20);
+    }
+
+    #[test]
+    fn test_discover_accounts_internal_chain() {
+        let used = used(&["m/84'/0'/0'/1/2", "m/84'/0'/1'/0/0"]);
+
+        let path = DerivationPath::from_str("m/84'/0'/0'/0/0").unwrap();
+        let accounts = AccountDiscovery::with_derivation_path(&path)
+            .unwrap()
+            .gap_limit(5)
+            .scan_internal(true)
+            .discover(|path| Ok::<_, Infallible>(used.contains(&path.to_string())))
+            .unwrap();
+
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].last_used_external, None);
+        assert_eq!(accounts[0].last_used_internal, Some(2));
+        assert_eq!(accounts[0].next_internal_index(), 3);
+        assert_eq!(accounts[1].path.to_string(), "m/84'/0'/1'");
+        assert_eq!(accounts[1].last_used_external, Some(0));
+    }
+
+    #[test]
+    fn test_discover_accounts_max_accounts() {
+        let accounts = AccountDiscovery::new(44, 60)
+            .gap_limit(1)
+            .max_accounts(3)
+            .discover(|path| Ok::<_, Infallible>(path.path()[4] == ChildIndex::Normal(0)))
+            .unwrap();
+        assert_eq!(accounts.len(), 3);
+        assert_eq!(accounts[2].path.to_string(), "m/44'/60'/2'");
+    }
+
+    #[test]
+    fn test_max_accounts_clamped() {
+        let discovery = AccountDiscovery::new(44, 60);
+        assert_eq!(discovery.max_accounts, MAX_ACCOUNTS);
+        assert_eq!(discovery.max_accounts(u32::MAX).max_accounts, MAX_ACCOUNTS);
+    }
+
+    #[test]
+    fn test_discover_accounts_error() {
+        let res = AccountDiscovery::new(44, 0).discover(|path| {
+            if path.path()[4] == ChildIndex::Normal(2) {
+                return Err("Network error");
+            }
+            Ok(true)
+        });
+        assert_eq!(res, Err("Network error"));
+    }
+
+    #[test]
+    fn test_with_derivation_path_too_short() {
+        let path = DerivationPath::from_str("m/44'").unwrap();
+        assert!(AccountDiscovery::with_derivation_path(&path).is_none());
+    }
+
+    #[test]
+    fn test_pending_indexes() {
+        let discovery = AccountDiscovery::new(44, 0).gap_limit(5);
+        assert_eq!(discovery.pending_indexes(None, 0), 0..5);
+        assert_eq!(discovery.pending_indexes(None, 3), 3..5);
+        assert!(discovery.pending_indexes(None, 5).is_empty());
+        assert_eq!(discovery.pending_indexes(Some(4), 5), 5..10);
+        // More addresses than required have been checked.
+        assert!(discovery.pending_indexes(Some(1), 10).is_empty());
+        // The last non-hardened index is used.
+        assert!(discovery
+            .pending_indexes(Some(MAX_NORMAL_INDEX), MAX_NORMAL_INDEX + 1)
+            .is_empty());
+    }
+}
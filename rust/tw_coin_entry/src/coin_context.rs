@@ -4,10 +4,13 @@
 
 use crate::derivation::DerivationWithPath;
 use tw_hash::hasher::Hasher;
-use tw_keypair::tw::PublicKeyType;
+use tw_keypair::tw::{Curve, PublicKeyType};
 
 /// Extend the trait with methods required for blockchain additions.
 pub trait CoinContext {
+    /// Necessary chain property.
+    fn curve(&self) -> Curve;
+
     /// Necessary chain property.
     fn public_key_type(&self) -> PublicKeyType;
 
@@ -26,6 +29,14 @@ pub trait CoinContext {
     /// Optional ss58 prefix (Substrate specific).
     fn ss58_prefix(&self) -> Option<u16>;
 
+    /// Returns SLIP-44 coin type.
+    fn slip44(&self) -> u32;
+
     /// Returns coin derivations.
     fn derivations(&self) -> &[DerivationWithPath];
+
+    /// Returns the default coin derivation.
+    fn default_derivation(&self) -> Option<&DerivationWithPath> {
+        self.derivations().first()
+    }
 }
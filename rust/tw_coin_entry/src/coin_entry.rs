@@ -15,6 +15,7 @@ use tw_proto::{MessageRead, MessageWrite};
 
 use crate::modules::message_signer::MessageSigner;
 use crate::modules::transaction_decoder::TransactionDecoder;
+use crate::modules::transaction_planner::TransactionPlanner;
 use crate::modules::transaction_util::TransactionUtil;
 use crate::modules::wallet_connector::WalletConnector;
 pub use tw_proto::{ProtoError, ProtoResult};
@@ -71,6 +72,10 @@ pub trait CoinEntry {
     type TransactionDecoder: TransactionDecoder;
 
     type TransactionUtil: TransactionUtil;
+    /// Transaction Planner - the module provides a chain-agnostic transaction plan: fee preview and max amount.
+    ///
+    /// **Optional**. Use `NoTransactionPlanner` if the blockchain does not support it yet.
+    type TransactionPlanner: TransactionPlanner;
 
     /// Tries to parse `Self::Address` from the given `address` string by `coin` type and address `prefix`.
     fn parse_address(
@@ -153,4 +158,11 @@ pub trait CoinEntry {
     fn transaction_util(&self) -> Option<Self::TransactionUtil> {
         None
     }
+
+    /// It is optional, chain-agnostic transaction planning, for example, the fee preview.
+    /// Returns `Ok(None)` if the blockchain does not support transaction planning yet.
+    #[inline]
+    fn transaction_planner(&self) -> Option<Self::TransactionPlanner> {
+        None
+    }
 }
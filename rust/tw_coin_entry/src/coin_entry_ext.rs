@@ -10,6 +10,7 @@ use crate::modules::json_signer::JsonSigner;
 use crate::modules::message_signer::MessageSigner;
 use crate::modules::plan_builder::PlanBuilder;
 use crate::modules::transaction_decoder::TransactionDecoder;
+use crate::modules::transaction_planner::TransactionPlanner;
 use crate::modules::transaction_util::TransactionUtil;
 use crate::modules::wallet_connector::WalletConnector;
 use crate::prefix::AddressPrefix;
@@ -106,6 +107,10 @@ pub trait CoinEntryExt {
 
     /// Calculate the TX hash of a transaction.
     fn calc_tx_hash(&self, coin: &dyn CoinContext, encoded_tx: &str) -> SigningResult<String>;
+
+    /// Plans a transaction in the chain-agnostic format.
+    /// Returns a serialized `TransactionPlanner.Proto.TransactionPlan`.
+    fn transaction_plan(&self, coin: &dyn CoinContext, input: &[u8]) -> SigningResult<Data>;
 }
 
 impl<T> CoinEntryExt for T
@@ -273,4 +278,15 @@ where
 
         tx_util.calc_tx_hash(coin, encoded_tx)
     }
+
+    fn transaction_plan(&self, coin: &dyn CoinContext, input: &[u8]) -> SigningResult<Data> {
+        let Some(planner) = self.transaction_planner() else {
+            return TWError::err(SigningErrorType::Error_not_supported);
+        };
+
+        let input: <T::TransactionPlanner as TransactionPlanner>::SigningInput<'_> =
+            deserialize(input)?;
+        let output = planner.plan_transaction(coin, &input)?;
+        serialize(&output).map_err(SigningError::from)
+    }
 }
@@ -3,27 +3,36 @@
 // Copyright © 2017 Trust Wallet.
 
 use serde::de::Error as DeError;
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::str::FromStr;
 
 pub use derivation_path::{ChildIndex, DerivationPath};
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct DerivationWithPath {
     #[serde(default)]
     pub name: Derivation,
-    #[serde(deserialize_with = "deserialize_der_path")]
+    #[serde(
+        deserialize_with = "deserialize_der_path",
+        serialize_with = "serialize_der_path"
+    )]
     pub path: DerivationPath,
 }
 
 /// Extend this enum.
-#[derive(Clone, Copy, Default, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Copy, Default, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Derivation {
     Segwit,
     Legacy,
     Testnet,
     Taproot,
+    Mainnet,
+    /// Solana specific derivation `m/44'/501'/0'/0'`.
+    Solana,
+    /// Cosmos-family derivation with the `118` coin type `m/44'/118'/0'/0/0`.
+    /// Used by Ledger and some Keplr accounts of chains with a different SLIP-44 coin type.
+    Fallback,
     /// Default derivation.
     #[default]
     #[serde(other)]
@@ -37,3 +46,10 @@ where
     let path = String::deserialize(deserializer)?;
     DerivationPath::from_str(&path).map_err(|e| DeError::custom(e.to_string()))
 }
+
+fn serialize_der_path<S>(path: &DerivationPath, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&path.to_string())
+}
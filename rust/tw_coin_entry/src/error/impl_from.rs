@@ -5,7 +5,7 @@
 use crate::error::address_error::AddressError;
 use crate::error::prelude::*;
 use tw_encoding::EncodingError;
-use tw_keypair::KeyPairError;
+use tw_keypair::{KeyPairError, KeyPairErrorKind};
 use tw_number::NumberError;
 use tw_proto::ProtoError;
 
@@ -37,19 +37,27 @@ impl From<EncodingError> for SigningError {
 
 impl From<KeyPairError> for SigningError {
     fn from(err: KeyPairError) -> Self {
-        match err {
-            KeyPairError::InvalidSecretKey => {
+        let error = match err.kind() {
+            KeyPairErrorKind::InvalidSecretKey
+            | KeyPairErrorKind::NonExportableKey
+            | KeyPairErrorKind::UnknownVaultHandle => {
                 TWError::new(SigningErrorType::Error_invalid_private_key)
             },
-            KeyPairError::InvalidPublicKey
-            | KeyPairError::InvalidSignature
-            | KeyPairError::InvalidSignMessage
-            | KeyPairError::SignatureVerifyError
-            | KeyPairError::InvalidEncryptedMessage => {
+            KeyPairErrorKind::InvalidPublicKey
+            | KeyPairErrorKind::InvalidSignature
+            | KeyPairErrorKind::InvalidSignMessage
+            | KeyPairErrorKind::SignatureVerifyError
+            | KeyPairErrorKind::InvalidEncryptedMessage
+            | KeyPairErrorKind::InvalidCurve => {
                 TWError::new(SigningErrorType::Error_invalid_params)
             },
-            KeyPairError::SigningError => TWError::new(SigningErrorType::Error_signing),
-            KeyPairError::InternalError => TWError::new(SigningErrorType::Error_internal),
+            KeyPairErrorKind::SigningError => TWError::new(SigningErrorType::Error_signing),
+            KeyPairErrorKind::InternalError => TWError::new(SigningErrorType::Error_internal),
+        };
+        if err.has_context() {
+            error.context(err)
+        } else {
+            error
         }
     }
 }
@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod account_discovery;
 pub mod coin_context;
 pub mod coin_entry;
 pub mod coin_entry_ext;
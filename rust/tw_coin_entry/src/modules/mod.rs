@@ -8,5 +8,6 @@ pub mod json_signer;
 pub mod message_signer;
 pub mod plan_builder;
 pub mod transaction_decoder;
+pub mod transaction_planner;
 pub mod transaction_util;
 pub mod wallet_connector;
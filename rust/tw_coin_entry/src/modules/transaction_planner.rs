@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::coin_context::CoinContext;
+use crate::error::prelude::*;
+use tw_proto::TransactionPlanner::Proto;
+use tw_proto::{DummyMessage, MessageRead};
+
+pub trait TransactionPlanner {
+    type SigningInput<'a>: MessageRead<'a>;
+
+    /// Plans a transaction in the chain-agnostic format: amount, fee, change, effective fee rate and the max sendable amount.
+    fn plan_transaction(
+        &self,
+        coin: &dyn CoinContext,
+        input: &Self::SigningInput<'_>,
+    ) -> SigningResult<Proto::TransactionPlan<'static>>;
+}
+
+/// `NoTransactionPlanner` can't be created since there are no enum variants.
+pub enum NoTransactionPlanner {}
+
+impl TransactionPlanner for NoTransactionPlanner {
+    type SigningInput<'a> = DummyMessage;
+
+    fn plan_transaction(
+        &self,
+        _coin: &dyn CoinContext,
+        _input: &Self::SigningInput<'_>,
+    ) -> SigningResult<Proto::TransactionPlan<'static>> {
+        panic!("`NoTransactionPlanner` should never be constructed and used")
+    }
+}
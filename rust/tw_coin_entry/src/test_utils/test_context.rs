@@ -5,11 +5,12 @@
 use crate::coin_context::CoinContext;
 use crate::derivation::DerivationWithPath;
 use tw_hash::hasher::Hasher;
-use tw_keypair::tw::PublicKeyType;
+use tw_keypair::tw::{Curve, PublicKeyType};
 
 /// Test coin context that panics on any `CoinContext` method call.
 #[derive(Default)]
 pub struct TestCoinContext {
+    pub curve: Option<Curve>,
     pub public_key_type: Option<PublicKeyType>,
     pub address_hasher: Option<Hasher>,
     pub hrp: Option<String>,
@@ -30,6 +31,10 @@ impl TestCoinContext {
 }
 
 impl CoinContext for TestCoinContext {
+    fn curve(&self) -> Curve {
+        self.curve.expect("EmptyCoinContext::curve was not set")
+    }
+
     fn public_key_type(&self) -> PublicKeyType {
         self.public_key_type
             .expect("EmptyCoinContext::public_key_type was not set")
@@ -55,6 +60,10 @@ impl CoinContext for TestCoinContext {
         unimplemented!()
     }
 
+    fn slip44(&self) -> u32 {
+        unimplemented!()
+    }
+
     fn derivations(&self) -> &[DerivationWithPath] {
         unimplemented!()
     }
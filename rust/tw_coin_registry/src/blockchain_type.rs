@@ -10,6 +10,8 @@ use serde::Deserialize;
 pub enum BlockchainType {
     // start_of_blockchain_type - USED TO GENERATE CODE
     Aptos,
+    AvalancheP,
+    AvalancheX,
     Binance,
     Bitcoin,
     BitcoinCash,
@@ -21,6 +23,8 @@ pub enum BlockchainType {
     InternetComputer,
     Komodo,
     Kusama,
+    Liquid,
+    Nano,
     NativeEvmos,
     NativeInjective,
     Pactus,
@@ -6,7 +6,7 @@ use crate::registry::CoinItem;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::derivation::DerivationWithPath;
 use tw_hash::hasher::Hasher;
-use tw_keypair::tw::PublicKeyType;
+use tw_keypair::tw::{Curve, PublicKeyType};
 
 pub struct CoinRegistryContext {
     item: &'static CoinItem,
@@ -20,6 +20,11 @@ impl CoinRegistryContext {
 }
 
 impl CoinContext for CoinRegistryContext {
+    #[inline]
+    fn curve(&self) -> Curve {
+        self.item.curve
+    }
+
     #[inline]
     fn public_key_type(&self) -> PublicKeyType {
         self.item.public_key_type
@@ -50,6 +55,11 @@ impl CoinContext for CoinRegistryContext {
         self.item.ss58_prefix
     }
 
+    #[inline]
+    fn slip44(&self) -> u32 {
+        self.item.slip44()
+    }
+
     #[inline]
     fn derivations(&self) -> &[DerivationWithPath] {
         &self.item.derivation
@@ -8,6 +8,8 @@ use crate::coin_type::CoinType;
 use crate::error::{RegistryError, RegistryResult};
 use crate::registry::get_coin_item;
 use tw_aptos::entry::AptosEntry;
+use tw_avalanche::chain::{PChain, XChain};
+use tw_avalanche::entry::AvalancheEntry;
 use tw_binance::entry::BinanceEntry;
 use tw_bitcoin::entry::BitcoinEntry;
 use tw_bitcoincash::entry::BitcoinCashEntry;
@@ -20,6 +22,8 @@ use tw_greenfield::entry::GreenfieldEntry;
 use tw_groestlcoin::entry::GroestlcoinEntry;
 use tw_internet_computer::entry::InternetComputerEntry;
 use tw_komodo::entry::KomodoEntry;
+use tw_liquid::entry::LiquidEntry;
+use tw_nano::entry::NanoEntry;
 use tw_native_evmos::entry::NativeEvmosEntry;
 use tw_native_injective::entry::NativeInjectiveEntry;
 use tw_pactus::entry::PactusEntry;
@@ -39,6 +43,8 @@ pub type EvmEntryExtStaticRef = &'static dyn EvmEntryExt;
 
 // start_of_blockchain_entries - USED TO GENERATE CODE
 const APTOS: AptosEntry = AptosEntry;
+const AVALANCHE_P: AvalancheEntry<PChain> = AvalancheEntry(PChain);
+const AVALANCHE_X: AvalancheEntry<XChain> = AvalancheEntry(XChain);
 const BINANCE: BinanceEntry = BinanceEntry;
 const BITCOIN: BitcoinEntry = BitcoinEntry;
 const BITCOIN_CASH: BitcoinCashEntry = BitcoinCashEntry;
@@ -49,6 +55,8 @@ const GREENFIELD: GreenfieldEntry = GreenfieldEntry;
 const GROESTLCOIN: GroestlcoinEntry = GroestlcoinEntry;
 const INTERNET_COMPUTER: InternetComputerEntry = InternetComputerEntry;
 const KOMODO: KomodoEntry = KomodoEntry;
+const LIQUID: LiquidEntry = LiquidEntry;
+const NANO: NanoEntry = NanoEntry;
 const NATIVE_EVMOS: NativeEvmosEntry = NativeEvmosEntry;
 const NATIVE_INJECTIVE: NativeInjectiveEntry = NativeInjectiveEntry;
 const PACTUS: PactusEntry = PactusEntry;
@@ -67,6 +75,8 @@ pub fn blockchain_dispatcher(blockchain: BlockchainType) -> RegistryResult<CoinE
     match blockchain {
         // start_of_blockchain_dispatcher - USED TO GENERATE CODE
         BlockchainType::Aptos => Ok(&APTOS),
+        BlockchainType::AvalancheP => Ok(&AVALANCHE_P),
+        BlockchainType::AvalancheX => Ok(&AVALANCHE_X),
         BlockchainType::Binance => Ok(&BINANCE),
         BlockchainType::Bitcoin => Ok(&BITCOIN),
         BlockchainType::BitcoinCash => Ok(&BITCOIN_CASH),
@@ -78,6 +88,8 @@ pub fn blockchain_dispatcher(blockchain: BlockchainType) -> RegistryResult<CoinE
         BlockchainType::InternetComputer => Ok(&INTERNET_COMPUTER),
         BlockchainType::Komodo => Ok(&KOMODO),
         BlockchainType::Kusama => Ok(&POLKADOT),
+        BlockchainType::Liquid => Ok(&LIQUID),
+        BlockchainType::Nano => Ok(&NANO),
         BlockchainType::NativeEvmos => Ok(&NATIVE_EVMOS),
         BlockchainType::NativeInjective => Ok(&NATIVE_INJECTIVE),
         BlockchainType::Pactus => Ok(&PACTUS),
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::blockchain_type::BlockchainType;
+use crate::coin_type::CoinType;
+use crate::registry::{get_coin_item, registry_iter, CoinItem};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+type EvmNetworkMap = HashMap<u64, EvmNetwork>;
+
+lazy_static! {
+    static ref EVM_NETWORKS: EvmNetworkMap = collect_evm_networks();
+}
+
+/// A known EVM-compatible network from `registry.json`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EvmNetwork {
+    /// EIP-155 chain ID.
+    pub chain_id: u64,
+    pub coin: CoinType,
+    /// Whether the network supports EIP-1559 transactions.
+    /// Legacy transactions are used by default otherwise.
+    pub eip1559: bool,
+}
+
+impl EvmNetwork {
+    fn from_coin_item(item: &CoinItem) -> Option<EvmNetwork> {
+        if !matches!(
+            item.blockchain,
+            BlockchainType::Ethereum | BlockchainType::Ronin
+        ) {
+            return None;
+        }
+        let chain_id = item.chain_id.as_ref()?.parse().ok()?;
+        Some(EvmNetwork {
+            chain_id,
+            coin: item.coin_id,
+            eip1559: item.eip1559.unwrap_or(true),
+        })
+    }
+}
+
+/// Returns the known EVM network by the given `chain_id`.
+#[inline]
+pub fn evm_network_by_chain_id(chain_id: u64) -> Option<EvmNetwork> {
+    EVM_NETWORKS.get(&chain_id).copied()
+}
+
+/// Returns the EVM network of the given `coin`.
+/// Note several coins may share one chain ID, see [`evm_network_by_chain_id`].
+pub fn evm_network_by_coin(coin: CoinType) -> Option<EvmNetwork> {
+    get_coin_item(coin)
+        .ok()
+        .and_then(EvmNetwork::from_coin_item)
+}
+
+/// Returns all known EVM networks, one per chain ID.
+#[inline]
+pub fn evm_networks() -> impl Iterator<Item = &'static EvmNetwork> {
+    EVM_NETWORKS.values()
+}
+
+fn collect_evm_networks() -> EvmNetworkMap {
+    let mut networks = EvmNetworkMap::new();
+    // Deprecated coins are inserted first, so the actual coins sharing the same chain ID replace them.
+    let mut items: Vec<_> = registry_iter().collect();
+    items.sort_by_key(|item| (!item.deprecated, item.coin_id as u32));
+
+    for network in items.into_iter().filter_map(EvmNetwork::from_coin_item) {
+        networks.insert(network.chain_id, network);
+    }
+    networks
+}
@@ -6,6 +6,7 @@ pub mod blockchain_type;
 pub mod coin_context;
 pub mod dispatcher;
 pub mod error;
+pub mod evm_network;
 pub mod registry;
 pub mod tw_derivation;
 
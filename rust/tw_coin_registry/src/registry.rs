@@ -8,9 +8,10 @@ use crate::error::{RegistryError, RegistryResult};
 use lazy_static::lazy_static;
 use serde::Deserialize;
 use std::collections::HashMap;
-use tw_coin_entry::derivation::DerivationWithPath;
+use tw_coin_entry::derivation::{Derivation, DerivationWithPath};
+use tw_coin_entry::prefix::{AddressPrefix, BitcoinBase58Prefix};
 use tw_hash::hasher::Hasher;
-use tw_keypair::tw::PublicKeyType;
+use tw_keypair::tw::{Curve, PublicKeyType};
 
 type RegistryMap = HashMap<CoinType, CoinItem>;
 
@@ -30,13 +31,106 @@ pub struct CoinItem {
     pub name: String,
     pub coin_id: CoinType,
     pub blockchain: BlockchainType,
+    /// SLIP-44 coin type if it differs from `coin_id`.
+    pub slip44: Option<u32>,
+    /// The first derivation is the default one.
     pub derivation: Vec<DerivationWithPath>,
+    pub curve: Curve,
     pub public_key_type: PublicKeyType,
     pub address_hasher: Option<Hasher>,
     pub hrp: Option<String>,
     pub p2pkh_prefix: Option<u8>,
     pub p2sh_prefix: Option<u8>,
+    /// The version byte preceding `p2pkh_prefix` and `p2sh_prefix`, e.g. Zcash transparent addresses.
+    pub static_prefix: Option<u8>,
     pub ss58_prefix: Option<u16>,
+    /// Address prefixes of the test network, if the coin has one.
+    pub testnet: Option<NetworkPrefixes>,
+    /// Address encodings of the coin, used to tell why an address is not valid.
+    #[serde(default)]
+    pub address_formats: Vec<AddressFormat>,
+    /// Chain identifier, e.g. EIP-155 chain ID of EVM-compatible chains.
+    pub chain_id: Option<String>,
+    /// Whether the EVM-compatible chain supports EIP-1559 transactions, `true` if not specified.
+    pub eip1559: Option<bool>,
+    #[serde(default)]
+    pub deprecated: bool,
+}
+
+impl CoinItem {
+    /// Returns the SLIP-44 coin type, or `coin_id` if it's not specified explicitly.
+    #[inline]
+    pub fn slip44(&self) -> u32 {
+        self.slip44.unwrap_or(self.coin_id as u32)
+    }
+
+    /// Returns the default derivation of the coin.
+    #[inline]
+    pub fn default_derivation(&self) -> Option<&DerivationWithPath> {
+        self.derivation.first()
+    }
+
+    /// Returns the derivation with the given `name`,
+    /// or the default derivation if `name` is [`Derivation::Default`].
+    pub fn derivation(&self, name: Derivation) -> Option<&DerivationWithPath> {
+        match name {
+            Derivation::Default => self.default_derivation(),
+            _ => self.derivation.iter().find(|der| der.name == name),
+        }
+    }
+
+    /// Whether the coin addresses are distinguished by a Bech32 or Base58 prefix.
+    #[inline]
+    pub fn has_address_prefix(&self) -> bool {
+        self.hrp.is_some() || self.p2pkh_prefix.is_some()
+    }
+}
+
+/// An encoding of the coin addresses and the allowed payload lengths.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressFormat {
+    pub encoding: AddressEncoding,
+    /// Allowed payload lengths in bytes:
+    /// * `base58Check` - the decoded payload including the version bytes, excluding the checksum.
+    /// * `segwit` - the witness program.
+    /// * `bech32` - the decoded data.
+    /// * `hex` - the decoded hex.
+    pub lengths: Vec<usize>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum AddressEncoding {
+    /// Base58 with a double SHA256 checksum, prefixed with `p2pkh_prefix` or `p2sh_prefix`.
+    Base58Check,
+    /// Bech32 or Bech32m witness program prefixed with `hrp`.
+    Segwit,
+    /// Bech32 data prefixed with `hrp`.
+    Bech32,
+    /// `0x`-prefixed hex.
+    Hex,
+}
+
+/// Bech32 and Base58 address prefixes of a network.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkPrefixes {
+    pub hrp: Option<String>,
+    pub p2pkh_prefix: Option<u8>,
+    pub p2sh_prefix: Option<u8>,
+}
+
+impl NetworkPrefixes {
+    /// Returns the address prefixes that can be passed to `CoinEntry::parse_address`.
+    pub fn address_prefixes(&self) -> Vec<AddressPrefix> {
+        let hrp = self.hrp.clone().map(AddressPrefix::Hrp);
+        let base58 = self
+            .p2pkh_prefix
+            .zip(self.p2sh_prefix)
+            .map(|(p2pkh, p2sh)| AddressPrefix::BitcoinBase58(BitcoinBase58Prefix { p2pkh, p2sh }));
+        hrp.into_iter().chain(base58).collect()
+    }
 }
 
 #[inline]
@@ -20,6 +20,20 @@ pub enum TWDerivation {
     BitcoinTaproot = 8,
     PactusMainnet = 9,
     PactusTestnet = 10,
+    CoreumFallback = 11,
+    TerraFallback = 12,
+    TerraV2Fallback = 13,
+    KavaFallback = 14,
+    BluzelleFallback = 15,
+    BandChainFallback = 16,
+    THORChainFallback = 17,
+    CryptoOrgFallback = 18,
+    SecretFallback = 19,
+    NativeEvmosFallback = 20,
+    AgoricFallback = 21,
+    NativeInjectiveFallback = 22,
+    NativeCantoFallback = 23,
+    NativeZetaChainFallback = 24,
     // end_of_derivation_enum - USED TO GENERATE CODE
     #[default]
     Default = 0,
@@ -32,10 +46,24 @@ impl From<TWDerivation> for Derivation {
             TWDerivation::BitcoinSegwit | TWDerivation::StratisSegwit => Derivation::Segwit,
             TWDerivation::BitcoinLegacy | TWDerivation::LitecoinLegacy => Derivation::Legacy,
             TWDerivation::BitcoinTestnet => Derivation::Testnet,
-            TWDerivation::SolanaSolana => Derivation::Default,
+            TWDerivation::SolanaSolana => Derivation::Solana,
             TWDerivation::BitcoinTaproot => Derivation::Taproot,
-            TWDerivation::PactusMainnet => Derivation::Default,
+            TWDerivation::PactusMainnet => Derivation::Mainnet,
             TWDerivation::PactusTestnet => Derivation::Testnet,
+            TWDerivation::CoreumFallback
+            | TWDerivation::TerraFallback
+            | TWDerivation::TerraV2Fallback
+            | TWDerivation::KavaFallback
+            | TWDerivation::BluzelleFallback
+            | TWDerivation::BandChainFallback
+            | TWDerivation::THORChainFallback
+            | TWDerivation::CryptoOrgFallback
+            | TWDerivation::SecretFallback
+            | TWDerivation::NativeEvmosFallback
+            | TWDerivation::AgoricFallback
+            | TWDerivation::NativeInjectiveFallback
+            | TWDerivation::NativeCantoFallback
+            | TWDerivation::NativeZetaChainFallback => Derivation::Fallback,
         }
     }
 }
@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! ADR-36 arbitrary message signatures.
+//!
+//! The message is wrapped into a `sign/MsgSignData` amino message of a zero-fee transaction
+//! with an empty chain ID, account number and sequence, and the transaction is signed.
+//!
+//! See https://docs.cosmos.network/main/build/architecture/adr-036-arbitrary-signature
+
+use crate::address::Address;
+use crate::context::CosmosContext;
+use crate::private_key::CosmosPrivateKey;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::message_signer::MessageSigner;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::base64::{self, STANDARD};
+use tw_hash::hasher::StatefulHasher;
+use tw_keypair::tw;
+use tw_memory::Data;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::Cosmos::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const MSG_SIGN_DATA_TYPE: &str = "sign/MsgSignData";
+
+pub struct Adr36MessageSigner<Context> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context> Default for Adr36MessageSigner<Context> {
+    fn default() -> Self {
+        Adr36MessageSigner {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Context: CosmosContext> MessageSigner for Adr36MessageSigner<Context> {
+    type MessageSigningInput<'a> = Proto::MessageSigningInput<'a>;
+    type MessagePreSigningOutput = CompilerProto::PreSigningOutput<'static>;
+    type MessageSigningOutput = Proto::MessageSigningOutput<'static>;
+    type MessageVerifyingInput<'a> = Proto::MessageVerifyingInput<'a>;
+
+    fn message_preimage_hashes(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessagePreSigningOutput {
+        Self::message_preimage_hashes_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(CompilerProto::PreSigningOutput, e))
+    }
+
+    fn sign_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageSigningInput<'_>,
+    ) -> Self::MessageSigningOutput {
+        Self::sign_message_impl(coin, input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::MessageSigningOutput, e))
+    }
+
+    fn verify_message(
+        &self,
+        coin: &dyn CoinContext,
+        input: Self::MessageVerifyingInput<'_>,
+    ) -> bool {
+        Self::verify_message_impl(coin, input).unwrap_or_default()
+    }
+}
+
+impl<Context: CosmosContext> Adr36MessageSigner<Context> {
+    fn message_preimage_hashes_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<CompilerProto::PreSigningOutput<'static>> {
+        let private_key = Context::PrivateKey::try_from(&input.private_key)?;
+        let public_key = private_key
+            .as_ref()
+            .get_public_key_by_type(coin.public_key_type())?;
+
+        let sign_doc = Self::sign_doc(coin, &public_key, &input.message)?;
+        let data_hash = Context::default_tx_hasher().hash(&sign_doc);
+        Ok(CompilerProto::PreSigningOutput {
+            data: Cow::Owned(sign_doc),
+            data_hash: Cow::Owned(data_hash),
+            ..CompilerProto::PreSigningOutput::default()
+        })
+    }
+
+    fn sign_message_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageSigningInput<'_>,
+    ) -> SigningResult<Proto::MessageSigningOutput<'static>> {
+        let private_key = Context::PrivateKey::try_from(&input.private_key)?;
+        let preimage = Self::message_preimage_hashes_impl(coin, input)?;
+
+        let signature_data = private_key.sign_tx_hash(&preimage.data_hash)?;
+        let signature = Context::Signature::try_from(&signature_data)?;
+
+        Ok(Proto::MessageSigningOutput {
+            signature: Cow::Owned(base64::encode(&signature.to_vec(), STANDARD)),
+            ..Proto::MessageSigningOutput::default()
+        })
+    }
+
+    fn verify_message_impl(
+        coin: &dyn CoinContext,
+        input: Proto::MessageVerifyingInput<'_>,
+    ) -> SigningResult<bool> {
+        let public_key = tw::PublicKey::new(input.public_key.to_vec(), coin.public_key_type())?;
+        let signature = base64::decode(&input.signature, STANDARD)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid base64 signature")?;
+
+        let sign_doc = Self::sign_doc(coin, &public_key, &input.message)?;
+        let hash = Context::default_tx_hasher().hash(&sign_doc);
+        Ok(public_key.verify(&signature, &hash))
+    }
+
+    /// Returns the canonical amino JSON of the ADR-36 sign doc.
+    fn sign_doc(
+        coin: &dyn CoinContext,
+        public_key: &tw::PublicKey,
+        message: &str,
+    ) -> SigningResult<Data> {
+        let signer = Address::with_public_key_coin_context(coin, public_key, None)
+            .into_tw()
+            .context("Error deriving the signer address")?;
+
+        let sign_doc = SignDoc {
+            account_number: "0",
+            chain_id: "",
+            fee: Fee {
+                amount: [],
+                gas: "0",
+            },
+            memo: "",
+            msgs: [Msg {
+                r#type: MSG_SIGN_DATA_TYPE,
+                value: MsgSignData {
+                    data: base64::encode(message.as_bytes(), STANDARD),
+                    signer: signer.to_string(),
+                },
+            }],
+            sequence: "0",
+        };
+        serde_json::to_vec(&sign_doc)
+            .tw_err(SigningErrorType::Error_internal)
+            .context("Error serializing the sign doc as JSON")
+    }
+}
+
+/// The fields are sorted alphabetically to produce a canonical JSON.
+#[derive(Serialize)]
+struct SignDoc {
+    account_number: &'static str,
+    chain_id: &'static str,
+    fee: Fee,
+    memo: &'static str,
+    msgs: [Msg; 1],
+    sequence: &'static str,
+}
+
+#[derive(Serialize)]
+struct Fee {
+    amount: [(); 0],
+    gas: &'static str,
+}
+
+#[derive(Serialize)]
+struct Msg {
+    r#type: &'static str,
+    value: MsgSignData,
+}
+
+#[derive(Serialize)]
+struct MsgSignData {
+    data: String,
+    signer: String,
+}
@@ -4,6 +4,7 @@
 
 pub mod broadcast_msg;
 pub mod compiler;
+pub mod message_signer;
 pub mod serializer;
 pub mod signer;
 pub mod transaction_util;
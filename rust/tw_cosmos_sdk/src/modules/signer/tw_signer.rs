@@ -6,13 +6,16 @@ use crate::context::CosmosContext;
 use crate::modules::compiler::tw_compiler::TWTransactionCompiler;
 use crate::modules::tx_builder::TxBuilder;
 use crate::private_key::CosmosPrivateKey;
+use crate::proto::cosmos::tx::v1beta1 as tx_proto;
 use crate::public_key::CosmosPublicKey;
 use std::borrow::Cow;
 use std::marker::PhantomData;
 use tw_coin_entry::coin_context::CoinContext;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
+use tw_memory::Data;
 use tw_proto::Cosmos::Proto;
+use tw_proto::{deserialize, serialize};
 
 pub struct TWSigner<Context> {
     _phantom: PhantomData<Context>,
@@ -32,6 +35,10 @@ impl<Context: CosmosContext> TWSigner<Context> {
         coin: &dyn CoinContext,
         mut input: Proto::SigningInput<'_>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
+        if input.skip_signing {
+            return Self::unsigned_output(coin, input);
+        }
+
         let private_key = Context::PrivateKey::try_from(&input.private_key)?;
 
         let params = TxBuilder::<Context>::public_key_params_from_proto(&input);
@@ -44,11 +51,52 @@ impl<Context: CosmosContext> TWSigner<Context> {
             TWTransactionCompiler::<Context>::preimage_hashes_impl(coin, input.clone())?;
 
         let signature_data = private_key.sign_tx_hash(&preimage_output.data_hash)?;
-        TWTransactionCompiler::<Context>::compile_impl(
+        let signing_mode = input.signing_mode;
+        let mut output = TWTransactionCompiler::<Context>::compile_impl(
             coin,
             input,
             vec![signature_data],
             vec![public_key.to_bytes()],
-        )
+        )?;
+
+        Self::set_unsigned_payload(&mut output, signing_mode, preimage_output.data.into_owned())?;
+        Ok(output)
+    }
+
+    /// Returns the unsigned payload of the transaction without signing it.
+    /// Please note the [`Proto::SigningInput::public_key`] should be set.
+    fn unsigned_output(
+        coin: &dyn CoinContext,
+        input: Proto::SigningInput<'_>,
+    ) -> SigningResult<Proto::SigningOutput<'static>> {
+        let signing_mode = input.signing_mode;
+        let preimage_output = TWTransactionCompiler::<Context>::preimage_hashes_impl(coin, input)?;
+
+        let mut output = Proto::SigningOutput::default();
+        Self::set_unsigned_payload(&mut output, signing_mode, preimage_output.data.into_owned())?;
+        Ok(output)
+    }
+
+    fn set_unsigned_payload(
+        output: &mut Proto::SigningOutput<'static>,
+        signing_mode: Proto::SigningMode,
+        sign_bytes: Data,
+    ) -> SigningResult<()> {
+        if signing_mode == Proto::SigningMode::Protobuf {
+            let sign_doc: tx_proto::SignDoc = deserialize(&sign_bytes)
+                .tw_err(SigningErrorType::Error_internal)
+                .context("Error decoding SignDoc")?;
+            let unsigned_tx = tx_proto::TxRaw {
+                body_bytes: sign_doc.body_bytes.clone(),
+                auth_info_bytes: sign_doc.auth_info_bytes,
+                signatures: vec![Cow::default()],
+            };
+
+            output.unsigned_tx = Cow::from(serialize(&unsigned_tx)?);
+            output.tx_body = Cow::from(sign_doc.body_bytes.into_owned());
+        }
+
+        output.sign_bytes = Cow::from(sign_bytes);
+        Ok(())
     }
 }
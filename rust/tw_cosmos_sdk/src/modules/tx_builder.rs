@@ -20,6 +20,10 @@ use tw_number::U256;
 use tw_proto::Cosmos::Proto;
 use tw_proto::{google, serialize};
 
+/// The maximum number of nested `AuthExec` messages.
+/// Cosmos SDK limits the nesting depth of `Any` messages to 10, including the top-level message.
+pub const MAX_AUTH_EXEC_DEPTH: usize = 9;
+
 pub struct TxBuilder<Context> {
     _phantom: PhantomData<Context>,
 }
@@ -170,6 +174,16 @@ where
     pub fn tx_message(
         coin: &dyn CoinContext,
         input: &Proto::Message,
+    ) -> SigningResult<CosmosMessageBox> {
+        let initial_depth = 0;
+        Self::nested_tx_message(coin, input, initial_depth)
+    }
+
+    /// `exec_depth` is the number of `AuthExec` messages the message is nested in.
+    fn nested_tx_message(
+        coin: &dyn CoinContext,
+        input: &Proto::Message,
+        exec_depth: usize,
     ) -> SigningResult<CosmosMessageBox> {
         use Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
 
@@ -225,6 +239,15 @@ where
             },
             MessageEnum::auth_grant(ref grant) => Self::auth_grant_msg_from_proto(coin, grant),
             MessageEnum::auth_revoke(ref revoke) => Self::auth_revoke_msg_from_proto(coin, revoke),
+            MessageEnum::auth_exec(ref exec) => {
+                Self::auth_exec_msg_from_proto(coin, exec, exec_depth)
+            },
+            MessageEnum::fee_grant_allowance(ref grant) => {
+                Self::fee_grant_allowance_msg_from_proto(coin, grant)
+            },
+            MessageEnum::fee_revoke_allowance(ref revoke) => {
+                Self::fee_revoke_allowance_msg_from_proto(coin, revoke)
+            },
             MessageEnum::msg_vote(ref vote) => Self::vote_msg_from_proto(coin, vote),
             MessageEnum::msg_stride_liquid_staking_stake(ref stake) => {
                 Self::stride_stake_msg_from_proto(coin, stake)
@@ -279,11 +302,18 @@ where
             .or_tw_err(SigningErrorType::Error_invalid_params)
             .context("No token specified")?;
         let token = Self::coin_from_proto(token)?;
-        let height = transfer
+        let timeout_height = transfer
             .timeout_height
             .as_ref()
-            .or_tw_err(SigningErrorType::Error_invalid_params)
-            .context("No timeout height specified")?;
+            .map(|height| Height {
+                revision_number: height.revision_number,
+                revision_height: height.revision_height,
+            })
+            .unwrap_or_default();
+        if timeout_height.revision_height == 0 && transfer.timeout_timestamp == 0 {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Either timeout height or timestamp should be specified");
+        }
 
         let msg = TransferTokensMessage {
             source_port: transfer.source_port.to_string(),
@@ -295,11 +325,9 @@ where
             receiver: Address::from_str(&transfer.receiver)
                 .into_tw()
                 .context("Invalid receiver address")?,
-            timeout_height: Height {
-                revision_number: height.revision_number,
-                revision_height: height.revision_height,
-            },
+            timeout_height,
             timeout_timestamp: transfer.timeout_timestamp,
+            memo: transfer.memo.to_string(),
         };
         Ok(msg.into_boxed())
     }
@@ -672,7 +700,10 @@ where
         _coin: &dyn CoinContext,
         auth: &Proto::mod_Message::AuthGrant<'_>,
     ) -> SigningResult<CosmosMessageBox> {
+        use crate::modules::serializer::protobuf_serializer::build_coin;
+        use crate::proto::cosmos;
         use crate::transaction::message::cosmos_auth_message::AuthGrantMessage;
+        use tw_proto::to_any;
         use Proto::mod_Message::mod_AuthGrant::OneOfgrant_type as ProtoGrantType;
 
         const STAKE_AUTHORIZATION_MSG_TYPE: &str = "/cosmos.staking.v1beta1.StakeAuthorization";
@@ -685,6 +716,34 @@ where
                     .context("Error serializing Grant Stake Protobuf message")?
                     .into(),
             },
+            ProtoGrantType::grant_generic(ref generic) => {
+                let authorization = cosmos::authz::v1beta1::GenericAuthorization {
+                    msg: generic.msg.to_string().into(),
+                };
+                to_any(&authorization)
+            },
+            ProtoGrantType::grant_send(ref send) => {
+                let spend_limit = send
+                    .spend_limit
+                    .iter()
+                    .map(|amount| Self::coin_from_proto(amount).map(|coin| build_coin(&coin)))
+                    .collect::<SigningResult<_>>()?;
+                let allow_list = send
+                    .allow_list
+                    .iter()
+                    .map(|addr| {
+                        Address::from_str(addr)
+                            .into_tw()
+                            .context("Invalid allow list address")
+                            .map(|addr| addr.to_string().into())
+                    })
+                    .collect::<SigningResult<_>>()?;
+                let authorization = cosmos::bank::v1beta1::SendAuthorization {
+                    spend_limit,
+                    allow_list,
+                };
+                to_any(&authorization)
+            },
             ProtoGrantType::None => {
                 return SigningError::err(SigningErrorType::Error_invalid_params)
                     .context("No Grant type specified");
@@ -722,6 +781,93 @@ where
         Ok(msg.into_boxed())
     }
 
+    /// `exec_depth` is the number of `AuthExec` messages the `auth` message is nested in.
+    pub fn auth_exec_msg_from_proto(
+        coin: &dyn CoinContext,
+        auth: &Proto::mod_Message::AuthExec<'_>,
+        exec_depth: usize,
+    ) -> SigningResult<CosmosMessageBox> {
+        use crate::transaction::message::cosmos_auth_message::AuthExecMessage;
+
+        if exec_depth >= MAX_AUTH_EXEC_DEPTH {
+            return SigningError::err(SigningErrorType::Error_invalid_params).with_context(|| {
+                format!("Allowed at most {MAX_AUTH_EXEC_DEPTH} nested `AuthExec` messages")
+            });
+        }
+        if auth.msgs.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("No messages to execute provided");
+        }
+
+        let msgs = auth
+            .msgs
+            .iter()
+            .map(|msg| Self::nested_tx_message(coin, msg, exec_depth + 1))
+            .collect::<SigningResult<_>>()
+            .context("Invalid message to execute")?;
+
+        let msg = AuthExecMessage {
+            grantee: Address::from_str(&auth.grantee)
+                .into_tw()
+                .context("Invalid grantee address")?,
+            msgs,
+        };
+        Ok(msg.into_boxed())
+    }
+
+    pub fn fee_grant_allowance_msg_from_proto(
+        _coin: &dyn CoinContext,
+        grant: &Proto::mod_Message::FeeGrantAllowance<'_>,
+    ) -> SigningResult<CosmosMessageBox> {
+        use crate::transaction::message::cosmos_feegrant_message::{
+            FeeAllowance, FeeGrantAllowanceMessage,
+        };
+        use Proto::mod_Message::mod_FeeGrantAllowance::OneOfallowance as ProtoAllowance;
+
+        let allowance = match grant.allowance {
+            ProtoAllowance::basic(ref basic) => FeeAllowance::Basic {
+                spend_limit: basic
+                    .spend_limit
+                    .iter()
+                    .map(Self::coin_from_proto)
+                    .collect::<SigningResult<_>>()?,
+                expiration_secs: Some(basic.expiration).filter(|secs| *secs != 0),
+            },
+            ProtoAllowance::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("No fee allowance specified");
+            },
+        };
+
+        let msg = FeeGrantAllowanceMessage {
+            granter: Address::from_str(&grant.granter)
+                .into_tw()
+                .context("Invalid granter address")?,
+            grantee: Address::from_str(&grant.grantee)
+                .into_tw()
+                .context("Invalid grantee address")?,
+            allowance,
+        };
+        Ok(msg.into_boxed())
+    }
+
+    pub fn fee_revoke_allowance_msg_from_proto(
+        _coin: &dyn CoinContext,
+        revoke: &Proto::mod_Message::FeeRevokeAllowance<'_>,
+    ) -> SigningResult<CosmosMessageBox> {
+        use crate::transaction::message::cosmos_feegrant_message::FeeRevokeAllowanceMessage;
+
+        let msg = FeeRevokeAllowanceMessage {
+            granter: Address::from_str(&revoke.granter)
+                .into_tw()
+                .context("Invalid granter address")?,
+            grantee: Address::from_str(&revoke.grantee)
+                .into_tw()
+                .context("Invalid grantee address")?,
+        };
+        Ok(msg.into_boxed())
+    }
+
     pub fn vote_msg_from_proto(
         _coin: &dyn CoinContext,
         vote: &Proto::mod_Message::MsgVote<'_>,
@@ -7,7 +7,7 @@ use crate::public_key::{CosmosPublicKey, JsonPublicKey, ProtobufPublicKey, Publi
 use tw_coin_entry::coin_context::CoinContext;
 use tw_keypair::ecdsa::secp256k1;
 use tw_keypair::tw;
-use tw_keypair::{KeyPairError, KeyPairResult};
+use tw_keypair::{KeyPairError, KeyPairErrorKind, KeyPairResult};
 use tw_memory::Data;
 use tw_misc::traits::ToBytesVec;
 use tw_proto::{google, to_any_with_type_url, type_url};
@@ -84,6 +84,6 @@ pub fn prepare_secp256k1_public_key(
     match public_key_type {
         tw::PublicKeyType::Secp256k1 => Ok(public_key.compressed().to_vec()),
         tw::PublicKeyType::Secp256k1Extended => Ok(public_key.uncompressed().to_vec()),
-        _ => Err(KeyPairError::InvalidPublicKey),
+        _ => Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey)),
     }
 }
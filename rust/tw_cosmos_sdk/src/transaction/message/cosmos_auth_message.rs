@@ -4,7 +4,7 @@
 
 use crate::address::CosmosAddress;
 use crate::proto::cosmos;
-use crate::transaction::message::{CosmosMessage, ProtobufMessage};
+use crate::transaction::message::{CosmosMessage, CosmosMessageBox, ProtobufMessage};
 use tw_coin_entry::error::prelude::*;
 use tw_proto::{google, to_any};
 
@@ -53,3 +53,26 @@ impl<Address: CosmosAddress> CosmosMessage for AuthRevokeMessage<Address> {
         Ok(to_any(&proto_msg))
     }
 }
+
+/// Supports Protobuf serialization only.
+pub struct AuthExecMessage<Address: CosmosAddress> {
+    pub grantee: Address,
+    /// Messages to be executed on behalf of the granters.
+    pub msgs: Vec<CosmosMessageBox>,
+}
+
+impl<Address: CosmosAddress> CosmosMessage for AuthExecMessage<Address> {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let msgs = self
+            .msgs
+            .iter()
+            .map(|msg| msg.to_proto())
+            .collect::<SigningResult<_>>()?;
+
+        let proto_msg = cosmos::authz::v1beta1::MsgExec {
+            grantee: self.grantee.to_string().into(),
+            msgs,
+        };
+        Ok(to_any(&proto_msg))
+    }
+}
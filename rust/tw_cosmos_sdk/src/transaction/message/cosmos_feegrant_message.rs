@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::CosmosAddress;
+use crate::modules::serializer::protobuf_serializer::build_coin;
+use crate::proto::cosmos;
+use crate::transaction::message::{CosmosMessage, ProtobufMessage};
+use crate::transaction::Coin;
+use tw_coin_entry::error::prelude::*;
+use tw_proto::{google, to_any};
+
+pub enum FeeAllowance {
+    Basic {
+        /// Coins that can be spent by the grantee. Empty means no limit.
+        spend_limit: Vec<Coin>,
+        /// Expiration timestamp in seconds, if any.
+        expiration_secs: Option<i64>,
+    },
+}
+
+impl FeeAllowance {
+    fn to_proto(&self) -> ProtobufMessage {
+        match self {
+            FeeAllowance::Basic {
+                spend_limit,
+                expiration_secs,
+            } => {
+                let expiration = expiration_secs.map(|seconds| google::protobuf::Timestamp {
+                    seconds,
+                    ..google::protobuf::Timestamp::default()
+                });
+                let allowance = cosmos::feegrant::v1beta1::BasicAllowance {
+                    spend_limit: spend_limit.iter().map(build_coin).collect(),
+                    expiration,
+                };
+                to_any(&allowance)
+            },
+        }
+    }
+}
+
+/// Supports Protobuf serialization only.
+pub struct FeeGrantAllowanceMessage<Address: CosmosAddress> {
+    pub granter: Address,
+    pub grantee: Address,
+    pub allowance: FeeAllowance,
+}
+
+impl<Address: CosmosAddress> CosmosMessage for FeeGrantAllowanceMessage<Address> {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let proto_msg = cosmos::feegrant::v1beta1::MsgGrantAllowance {
+            granter: self.granter.to_string().into(),
+            grantee: self.grantee.to_string().into(),
+            allowance: Some(self.allowance.to_proto()),
+        };
+        Ok(to_any(&proto_msg))
+    }
+}
+
+/// Supports Protobuf serialization only.
+pub struct FeeRevokeAllowanceMessage<Address: CosmosAddress> {
+    pub granter: Address,
+    pub grantee: Address,
+}
+
+impl<Address: CosmosAddress> CosmosMessage for FeeRevokeAllowanceMessage<Address> {
+    fn to_proto(&self) -> SigningResult<ProtobufMessage> {
+        let proto_msg = cosmos::feegrant::v1beta1::MsgRevokeAllowance {
+            granter: self.granter.to_string().into(),
+            grantee: self.grantee.to_string().into(),
+        };
+        Ok(to_any(&proto_msg))
+    }
+}
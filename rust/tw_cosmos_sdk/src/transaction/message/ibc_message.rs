@@ -10,6 +10,7 @@ use crate::transaction::Coin;
 use tw_coin_entry::error::prelude::*;
 use tw_proto::to_any;
 
+#[derive(Default)]
 pub struct Height {
     pub revision_number: u64,
     pub revision_height: u64,
@@ -28,6 +29,7 @@ pub struct TransferTokensMessage<Address: CosmosAddress> {
     pub timeout_height: Height,
     // Timeout timestamp (in nanoseconds) relative to the current block timestamp.  Either timeout height or timestamp should be set.
     pub timeout_timestamp: u64,
+    pub memo: String,
 }
 
 impl<Address: CosmosAddress> CosmosMessage for TransferTokensMessage<Address> {
@@ -45,6 +47,7 @@ impl<Address: CosmosAddress> CosmosMessage for TransferTokensMessage<Address> {
             receiver: self.receiver.to_string().into(),
             timeout_height: Some(height),
             timeout_timestamp: self.timeout_timestamp,
+            memo: self.memo.clone().into(),
         };
         Ok(to_any(&proto_msg))
     }
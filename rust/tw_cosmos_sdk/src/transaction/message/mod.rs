@@ -10,6 +10,7 @@ use tw_proto::google;
 
 pub mod cosmos_auth_message;
 pub mod cosmos_bank_message;
+pub mod cosmos_feegrant_message;
 pub mod cosmos_generic_message;
 pub mod cosmos_gov_message;
 pub mod cosmos_staking_message;
@@ -244,6 +244,77 @@ fn test_sign_ibc_transfer() {
     });
 }
 
+#[test]
+fn test_sign_ibc_transfer_with_memo_and_timeout_timestamp() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("cosmos");
+
+    let transfer_tokens = Proto::mod_Message::Transfer {
+        source_port: "transfer".into(),
+        source_channel: "channel-141".into(),
+        token: Some(make_amount("uatom", "100000")),
+        sender: "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx".into(),
+        receiver: "osmo18s0hdnsllgcclweu9aymw4ngktr2k0rkvn7jmn".into(),
+        // Timeout timestamp only.
+        timeout_timestamp: 1700000000000000000,
+        memo: r#"{"wasm":{"contract":"osmo1swap"}}"#.into(),
+        ..Proto::mod_Message::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        account_number: 546179,
+        chain_id: "cosmoshub-4".into(),
+        sequence: 3,
+        fee: Some(make_fee(500000, make_amount("uatom", "12500"))),
+        private_key: account_546179_private_key(),
+        messages: vec![make_message(MessageEnum::transfer_tokens_message(
+            transfer_tokens,
+        ))],
+        ..Proto::SigningInput::default()
+    };
+
+    test_sign_protobuf::<StandardCosmosContext>(TestInput {
+        coin: &coin,
+        input,
+        tx: r#"{"mode":"BROADCAST_MODE_BLOCK","tx_bytes":"CuQBCuEBCikvaWJjLmFwcGxpY2F0aW9ucy50cmFuc2Zlci52MS5Nc2dUcmFuc2ZlchKzAQoIdHJhbnNmZXISC2NoYW5uZWwtMTQxGg8KBXVhdG9tEgYxMDAwMDAiLWNvc21vczFta3k2OWNuOGVrdHd5MDg0NXZlYzl1cHNkcGhrdHh0MDNna3dseCorb3NtbzE4czBoZG5zbGxnY2Nsd2V1OWF5bXc0bmdrdHIyazBya3ZuN2ptbjIAOICAqLHjn+fLF0IheyJ3YXNtIjp7ImNvbnRyYWN0Ijoib3NtbzFzd2FwIn19EmgKUApGCh8vY29zbW9zLmNyeXB0by5zZWNwMjU2azEuUHViS2V5EiMKIQLs71zkN6MCxn+VRo3ksx826RH0Z9fmpStBweE+HVY2SRIECgIIARgDEhQKDgoFdWF0b20SBTEyNTAwEKDCHhpA/+83o6dR4LFoLew1SJ48AugLnJRScRvWY2PepeSrj7VGENJ/14G7QtkjTt1UbCuk6otMJ+zp9W5nMFh/JEnOlg=="}"#,
+        signature: "ffef37a3a751e0b1682dec35489e3c02e80b9c9452711bd66363dea5e4ab8fb54610d27fd781bb42d9234edd546c2ba4ea8b4c27ece9f56e6730587f2449ce96",
+        signature_json: r#"[{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"AuzvXOQ3owLGf5VGjeSzHzbpEfRn1+alK0HB4T4dVjZJ"},"signature":"/+83o6dR4LFoLew1SJ48AugLnJRScRvWY2PepeSrj7VGENJ/14G7QtkjTt1UbCuk6otMJ+zp9W5nMFh/JEnOlg=="}]"#,
+    });
+}
+
+#[test]
+fn test_sign_ibc_transfer_no_timeout() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("cosmos");
+
+    let transfer_tokens = Proto::mod_Message::Transfer {
+        source_port: "transfer".into(),
+        source_channel: "channel-141".into(),
+        token: Some(make_amount("uatom", "100000")),
+        sender: "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx".into(),
+        receiver: "osmo18s0hdnsllgcclweu9aymw4ngktr2k0rkvn7jmn".into(),
+        ..Proto::mod_Message::Transfer::default()
+    };
+    let input = Proto::SigningInput {
+        account_number: 546179,
+        chain_id: "cosmoshub-4".into(),
+        sequence: 3,
+        fee: Some(make_fee(500000, make_amount("uatom", "12500"))),
+        private_key: account_546179_private_key(),
+        messages: vec![make_message(MessageEnum::transfer_tokens_message(
+            transfer_tokens,
+        ))],
+        ..Proto::SigningInput::default()
+    };
+
+    test_sign_protobuf_error::<StandardCosmosContext>(TestErrorInput {
+        coin: &coin,
+        input,
+        error: SigningError::Error_invalid_params,
+    });
+}
+
 #[test]
 fn test_sign_direct() {
     let coin = TestCoinContext::default()
@@ -0,0 +1,241 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_coin_entry::test_utils::test_context::TestCoinContext;
+use tw_cosmos_sdk::context::StandardCosmosContext;
+use tw_cosmos_sdk::modules::tx_builder::MAX_AUTH_EXEC_DEPTH;
+use tw_cosmos_sdk::test_utils::proto_utils::{make_amount, make_fee, make_message};
+use tw_cosmos_sdk::test_utils::sign_utils::{
+    test_sign_json_error, test_sign_protobuf, test_sign_protobuf_error, TestErrorInput, TestInput,
+};
+use tw_encoding::hex::DecodeHex;
+use tw_keypair::tw::PublicKeyType;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Cosmos::Proto;
+use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+
+const GRANTER: &str = "cosmos13k0q0l7lg2kr32kvt7ly236ppldy8v9dzwh3gd";
+const GRANTEE: &str = "cosmos1fs7lu28hx5m9akm7rp0c2422cn8r2f7gurujhf";
+
+fn account_1290826_private_key() -> Cow<'static, [u8]> {
+    "c7764249cdf77f8f1d840fa8af431579e5e41cf1af937e1e23afa22f3f4f0ccc"
+        .decode_hex()
+        .unwrap()
+        .into()
+}
+
+fn make_input(sequence: u64, message: MessageEnum<'static>) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        account_number: 1290826,
+        chain_id: "cosmoshub-4".into(),
+        sequence,
+        fee: Some(make_fee(96681, make_amount("uatom", "2418"))),
+        private_key: account_1290826_private_key(),
+        messages: vec![make_message(message)],
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_sign_authz_generic_grant() {
+    use Proto::mod_Message::mod_AuthGrant::OneOfgrant_type as ProtoGrantType;
+
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("cosmos");
+
+    let auth_grant = Proto::mod_Message::AuthGrant {
+        granter: GRANTER.into(),
+        grantee: GRANTEE.into(),
+        grant_type: ProtoGrantType::grant_generic(Proto::mod_Message::GenericAuthorization {
+            msg: "/cosmos.gov.v1beta1.MsgVote".into(),
+        }),
+        expiration: 1733011200,
+    };
+    let input = make_input(7, MessageEnum::auth_grant(auth_grant));
+
+    test_sign_protobuf::<StandardCosmosContext>(TestInput {
+        coin: &coin,
+        input: input.clone(),
+        tx: r#"{"mode":"BROADCAST_MODE_BLOCK","tx_bytes":"CtsBCtgBCh4vY29zbW9zLmF1dGh6LnYxYmV0YTEuTXNnR3JhbnQStQEKLWNvc21vczEzazBxMGw3bGcya3IzMmt2dDdseTIzNnBwbGR5OHY5ZHp3aDNnZBItY29zbW9zMWZzN2x1MjhoeDVtOWFrbTdycDBjMjQyMmNuOHIyZjdndXJ1amhmGlUKSwoqL2Nvc21vcy5hdXRoei52MWJldGExLkdlbmVyaWNBdXRob3JpemF0aW9uEh0KGy9jb3Ntb3MuZ292LnYxYmV0YTEuTXNnVm90ZRIGCIDOrroGEmcKUApGCh8vY29zbW9zLmNyeXB0by5zZWNwMjU2azEuUHViS2V5EiMKIQP33EMNYQlVMfdOLfpAl04os4miyGPJcrLLtRDX82jdShIECgIIARgHEhMKDQoFdWF0b20SBDI0MTgQqfMFGkDTvfXKVVtyaJI8Xmao9SMEXCjsC3RgaCV4m7sm+4dV12+rXvW/32FSLfgxE4NL7u+GW59F2FmotHIuF1HG6xKp"}"#,
+        signature: "d3bdf5ca555b7268923c5e66a8f523045c28ec0b74606825789bbb26fb8755d76fab5ef5bfdf61522df83113834beeef865b9f45d859a8b4722e1751c6eb12a9",
+        signature_json: r#"[{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"A/fcQw1hCVUx904t+kCXTiiziaLIY8lyssu1ENfzaN1K"},"signature":"0731ylVbcmiSPF5mqPUjBFwo7At0YGgleJu7JvuHVddvq171v99hUi34MRODS+7vhlufRdhZqLRyLhdRxusSqQ=="}]"#,
+    });
+
+    // `AuthGrant` doesn't support JSON serialization and signing.
+    test_sign_json_error::<StandardCosmosContext>(TestErrorInput {
+        coin: &coin,
+        input,
+        error: SigningError::Error_not_supported,
+    });
+}
+
+#[test]
+fn test_sign_authz_send_grant() {
+    use Proto::mod_Message::mod_AuthGrant::OneOfgrant_type as ProtoGrantType;
+
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("cosmos");
+
+    let send_authorization = Proto::mod_Message::SendAuthorization {
+        spend_limit: vec![make_amount("uatom", "5000")],
+        allow_list: vec!["cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx".into()],
+    };
+    let auth_grant = Proto::mod_Message::AuthGrant {
+        granter: GRANTER.into(),
+        grantee: GRANTEE.into(),
+        grant_type: ProtoGrantType::grant_send(send_authorization),
+        expiration: 1733011200,
+    };
+    let input = make_input(8, MessageEnum::auth_grant(auth_grant));
+
+    test_sign_protobuf::<StandardCosmosContext>(TestInput {
+        coin: &coin,
+        input,
+        tx: r#"{"mode":"BROADCAST_MODE_BLOCK","tx_bytes":"CvgBCvUBCh4vY29zbW9zLmF1dGh6LnYxYmV0YTEuTXNnR3JhbnQS0gEKLWNvc21vczEzazBxMGw3bGcya3IzMmt2dDdseTIzNnBwbGR5OHY5ZHp3aDNnZBItY29zbW9zMWZzN2x1MjhoeDVtOWFrbTdycDBjMjQyMmNuOHIyZjdndXJ1amhmGnIKaAomL2Nvc21vcy5iYW5rLnYxYmV0YTEuU2VuZEF1dGhvcml6YXRpb24SPgoNCgV1YXRvbRIENTAwMBItY29zbW9zMW1reTY5Y244ZWt0d3kwODQ1dmVjOXVwc2RwaGt0eHQwM2drd2x4EgYIgM6uugYSZwpQCkYKHy9jb3Ntb3MuY3J5cHRvLnNlY3AyNTZrMS5QdWJLZXkSIwohA/fcQw1hCVUx904t+kCXTiiziaLIY8lyssu1ENfzaN1KEgQKAggBGAgSEwoNCgV1YXRvbRIEMjQxOBCp8wUaQLUyrKaAPv61ZXRNPE5a6fwV6jsopqhgEvp5Qb4cVLBkSugpI4djC8hSmzxTjqCMzbKmtgMwsGPyQXOhBxQ0Jr4="}"#,
+        signature: "b532aca6803efeb565744d3c4e5ae9fc15ea3b28a6a86012fa7941be1c54b0644ae8292387630bc8529b3c538ea08ccdb2a6b60330b063f24173a107143426be",
+        signature_json: r#"[{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"A/fcQw1hCVUx904t+kCXTiiziaLIY8lyssu1ENfzaN1K"},"signature":"tTKspoA+/rVldE08Tlrp/BXqOyimqGAS+nlBvhxUsGRK6Ckjh2MLyFKbPFOOoIzNsqa2AzCwY/JBc6EHFDQmvg=="}]"#,
+    });
+}
+
+#[test]
+fn test_sign_authz_exec_delegate() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("cosmos");
+
+    // The signer delegates on behalf of the granter.
+    let delegate = Proto::mod_Message::Delegate {
+        delegator_address: GRANTEE.into(),
+        validator_address: "cosmosvaloper1gjtvly9lel6zskvwtvlg5vhwpu9c9waw7sxzwx".into(),
+        amount: Some(make_amount("uatom", "1000")),
+        ..Proto::mod_Message::Delegate::default()
+    };
+    let auth_exec = Proto::mod_Message::AuthExec {
+        grantee: GRANTER.into(),
+        msgs: vec![make_message(MessageEnum::stake_message(delegate))],
+    };
+    let input = make_input(6, MessageEnum::auth_exec(auth_exec));
+
+    test_sign_protobuf::<StandardCosmosContext>(TestInput {
+        coin: &coin,
+        input: input.clone(),
+        tx: r#"{"mode":"BROADCAST_MODE_BLOCK","tx_bytes":"CvIBCu8BCh0vY29zbW9zLmF1dGh6LnYxYmV0YTEuTXNnRXhlYxLNAQotY29zbW9zMTNrMHEwbDdsZzJrcjMya3Z0N2x5MjM2cHBsZHk4djlkendoM2dkEpsBCiMvY29zbW9zLnN0YWtpbmcudjFiZXRhMS5Nc2dEZWxlZ2F0ZRJ0Ci1jb3Ntb3MxZnM3bHUyOGh4NW05YWttN3JwMGMyNDIyY244cjJmN2d1cnVqaGYSNGNvc21vc3ZhbG9wZXIxZ2p0dmx5OWxlbDZ6c2t2d3R2bGc1dmh3cHU5Yzl3YXc3c3h6d3gaDQoFdWF0b20SBDEwMDASZwpQCkYKHy9jb3Ntb3MuY3J5cHRvLnNlY3AyNTZrMS5QdWJLZXkSIwohA/fcQw1hCVUx904t+kCXTiiziaLIY8lyssu1ENfzaN1KEgQKAggBGAYSEwoNCgV1YXRvbRIEMjQxOBCp8wUaQL16kr0g0uNiivxGl5ipceHAuaISsTfE77e9iLeu5+jyCAtGCbtRa7p63Q3ZWXPWtG9Swkrmr3jZET52T/eBrN4="}"#,
+        signature: "bd7a92bd20d2e3628afc469798a971e1c0b9a212b137c4efb7bd88b7aee7e8f2080b4609bb516bba7add0dd95973d6b46f52c24ae6af78d9113e764ff781acde",
+        signature_json: r#"[{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"A/fcQw1hCVUx904t+kCXTiiziaLIY8lyssu1ENfzaN1K"},"signature":"vXqSvSDS42KK/EaXmKlx4cC5ohKxN8Tvt72It67n6PIIC0YJu1FrunrdDdlZc9a0b1LCSuaveNkRPnZP94Gs3g=="}]"#,
+    });
+
+    // `AuthExec` doesn't support JSON serialization and signing.
+    test_sign_json_error::<StandardCosmosContext>(TestErrorInput {
+        coin: &coin,
+        input,
+        error: SigningError::Error_not_supported,
+    });
+}
+
+#[test]
+fn test_sign_authz_exec_no_messages() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("cosmos");
+
+    let auth_exec = Proto::mod_Message::AuthExec {
+        grantee: GRANTER.into(),
+        msgs: Vec::default(),
+    };
+    let input = make_input(6, MessageEnum::auth_exec(auth_exec));
+
+    test_sign_protobuf_error::<StandardCosmosContext>(TestErrorInput {
+        coin: &coin,
+        input,
+        error: SigningError::Error_invalid_params,
+    });
+}
+
+#[test]
+fn test_sign_authz_exec_max_depth() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("cosmos");
+
+    // Wraps a send message into `exec_depth` nested `AuthExec` messages.
+    let nested_exec = |exec_depth: usize| {
+        let send = Proto::mod_Message::Send {
+            from_address: GRANTER.into(),
+            to_address: GRANTEE.into(),
+            amounts: vec![make_amount("uatom", "1000")],
+            ..Proto::mod_Message::Send::default()
+        };
+        let mut message = MessageEnum::send_coins_message(send);
+        for _ in 0..exec_depth {
+            message = MessageEnum::auth_exec(Proto::mod_Message::AuthExec {
+                grantee: GRANTER.into(),
+                msgs: vec![make_message(message)],
+            });
+        }
+        make_input(6, message)
+    };
+
+    test_sign_protobuf_error::<StandardCosmosContext>(TestErrorInput {
+        coin: &coin,
+        input: nested_exec(MAX_AUTH_EXEC_DEPTH),
+        error: SigningError::OK,
+    });
+    test_sign_protobuf_error::<StandardCosmosContext>(TestErrorInput {
+        coin: &coin,
+        input: nested_exec(MAX_AUTH_EXEC_DEPTH + 1),
+        error: SigningError::Error_invalid_params,
+    });
+}
+
+#[test]
+fn test_sign_feegrant_basic_allowance() {
+    use Proto::mod_Message::mod_FeeGrantAllowance::OneOfallowance as ProtoAllowance;
+
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("cosmos");
+
+    let basic = Proto::mod_Message::BasicAllowance {
+        spend_limit: vec![make_amount("uatom", "100000")],
+        expiration: 1733011200,
+    };
+    let fee_grant = Proto::mod_Message::FeeGrantAllowance {
+        granter: GRANTER.into(),
+        grantee: GRANTEE.into(),
+        allowance: ProtoAllowance::basic(basic),
+    };
+    let input = make_input(9, MessageEnum::fee_grant_allowance(fee_grant));
+
+    test_sign_protobuf::<StandardCosmosContext>(TestInput {
+        coin: &coin,
+        input,
+        tx: r#"{"mode":"BROADCAST_MODE_BLOCK","tx_bytes":"CtYBCtMBCiovY29zbW9zLmZlZWdyYW50LnYxYmV0YTEuTXNnR3JhbnRBbGxvd2FuY2USpAEKLWNvc21vczEzazBxMGw3bGcya3IzMmt2dDdseTIzNnBwbGR5OHY5ZHp3aDNnZBItY29zbW9zMWZzN2x1MjhoeDVtOWFrbTdycDBjMjQyMmNuOHIyZjdndXJ1amhmGkQKJy9jb3Ntb3MuZmVlZ3JhbnQudjFiZXRhMS5CYXNpY0FsbG93YW5jZRIZCg8KBXVhdG9tEgYxMDAwMDASBgiAzq66BhJnClAKRgofL2Nvc21vcy5jcnlwdG8uc2VjcDI1NmsxLlB1YktleRIjCiED99xDDWEJVTH3Ti36QJdOKLOJoshjyXKyy7UQ1/No3UoSBAoCCAEYCRITCg0KBXVhdG9tEgQyNDE4EKnzBRpA+R6IfURP6Tr7YJo95Ux6NExxCIy9DCnp+THYTis5AMh13ylrGcvStR1l2EKt39okFY29GSqKGalqwKr6UKf28A=="}"#,
+        signature: "f91e887d444fe93afb609a3de54c7a344c71088cbd0c29e9f931d84e2b3900c875df296b19cbd2b51d65d842addfda24158dbd192a8a19a96ac0aafa50a7f6f0",
+        signature_json: r#"[{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"A/fcQw1hCVUx904t+kCXTiiziaLIY8lyssu1ENfzaN1K"},"signature":"+R6IfURP6Tr7YJo95Ux6NExxCIy9DCnp+THYTis5AMh13ylrGcvStR1l2EKt39okFY29GSqKGalqwKr6UKf28A=="}]"#,
+    });
+}
+
+#[test]
+fn test_sign_feegrant_revoke_allowance() {
+    let coin = TestCoinContext::default()
+        .with_public_key_type(PublicKeyType::Secp256k1)
+        .with_hrp("cosmos");
+
+    let fee_revoke = Proto::mod_Message::FeeRevokeAllowance {
+        granter: GRANTER.into(),
+        grantee: GRANTEE.into(),
+    };
+    let input = make_input(10, MessageEnum::fee_revoke_allowance(fee_revoke));
+
+    test_sign_protobuf::<StandardCosmosContext>(TestInput {
+        coin: &coin,
+        input,
+        tx: r#"{"mode":"BROADCAST_MODE_BLOCK","tx_bytes":"CpABCo0BCisvY29zbW9zLmZlZWdyYW50LnYxYmV0YTEuTXNnUmV2b2tlQWxsb3dhbmNlEl4KLWNvc21vczEzazBxMGw3bGcya3IzMmt2dDdseTIzNnBwbGR5OHY5ZHp3aDNnZBItY29zbW9zMWZzN2x1MjhoeDVtOWFrbTdycDBjMjQyMmNuOHIyZjdndXJ1amhmEmcKUApGCh8vY29zbW9zLmNyeXB0by5zZWNwMjU2azEuUHViS2V5EiMKIQP33EMNYQlVMfdOLfpAl04os4miyGPJcrLLtRDX82jdShIECgIIARgKEhMKDQoFdWF0b20SBDI0MTgQqfMFGkBbMJ8owLKq9Zg4ijwcSJZXE2+wUkFXsy4B5iIQfNuWDWAR9TjKpksPDTu3KBe2UpKvWw5VVPWtw0CCPuZnmP0b"}"#,
+        signature: "5b309f28c0b2aaf598388a3c1c489657136fb0524157b32e01e622107cdb960d6011f538caa64b0f0d3bb72817b65292af5b0e5554f5adc340823ee66798fd1b",
+        signature_json: r#"[{"pub_key":{"type":"tendermint/PubKeySecp256k1","value":"A/fcQw1hCVUx904t+kCXTiiziaLIY8lyssu1ENfzaN1K"},"signature":"WzCfKMCyqvWYOIo8HEiWVxNvsFJBV7MuAeYiEHzblg1gEfU4yqZLDw07tygXtlKSr1sOVVT1rcNAgj7mZ5j9Gw=="}]"#,
+    });
+}
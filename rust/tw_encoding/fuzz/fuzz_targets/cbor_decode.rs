@@ -0,0 +1,14 @@
+#![no_main]
+
+mod common;
+
+use common::User;
+use libfuzzer_sys::fuzz_target;
+use tw_encoding::cbor::{decode, encode};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(user) = decode::<User>(data) {
+        let serialized = encode(&user).unwrap();
+        assert_eq!(decode::<User>(&serialized).unwrap(), user);
+    }
+});
@@ -1,21 +1,11 @@
 #![no_main]
 
-use libfuzzer_sys::{arbitrary, fuzz_target};
-
-use serde::{Deserialize, Serialize};
+mod common;
 
+use common::User;
+use libfuzzer_sys::fuzz_target;
 use tw_encoding::cbor::{decode, encode};
 
-#[derive(arbitrary::Arbitrary, Serialize, Deserialize, PartialEq, Debug)]
-struct User {
-    name: String,
-    age: u64,
-    friends: Vec<String>,
-    #[serde(with = "serde_bytes")]
-    address: Vec<u8>,
-    key: Vec<u8>,
-}
-
 fuzz_target!(|se_user: User| {
     let serialized = encode(&se_user).unwrap();
     let de_user = decode::<User>(&serialized).unwrap();
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use libfuzzer_sys::arbitrary;
+use serde::{Deserialize, Serialize};
+
+/// The CBOR fixture shared by the CBOR fuzz targets.
+#[derive(arbitrary::Arbitrary, Serialize, Deserialize, PartialEq, Debug)]
+pub struct User {
+    pub name: String,
+    pub age: u64,
+    pub friends: Vec<String>,
+    #[serde(with = "serde_bytes")]
+    pub address: Vec<u8>,
+    pub key: Vec<u8>,
+}
@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::{EncodingError, EncodingResult};
+use alloc::string::String;
 use bs58::decode::Error;
 use tw_memory::Data;
 
@@ -44,6 +45,7 @@ pub fn decode(input: &str, alphabet: Alphabet) -> EncodingResult<Data> {
 
 pub mod as_base58_bitcoin {
     use super::*;
+    use alloc::format;
     use serde::de::Error;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
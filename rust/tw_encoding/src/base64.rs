@@ -3,6 +3,9 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::{EncodingError, EncodingResult};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 use serde::de::Error as DeError;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use tw_memory::Data;
@@ -27,3 +27,33 @@ pub fn decode(s: &str) -> Bech32Result<Decoded> {
     }
     Err(Bech32Error::InvalidChecksum)
 }
+
+pub struct DecodedWitness {
+    pub hrp: String,
+    pub version: u8,
+    pub program: Data,
+}
+
+/// Decodes a segwit address: Bech32 for witness version 0, Bech32m for the later versions.
+pub fn decode_witness(s: &str) -> Bech32Result<DecodedWitness> {
+    let (hrp, base32_bytes, variant) = bech32::decode(s)?;
+    let (version, program) = base32_bytes
+        .split_first()
+        .ok_or(Bech32Error::InvalidLength)?;
+    let version = version.to_u8();
+    let program = Data::from_base32(program)?;
+
+    let expected_variant = if version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    };
+    if variant != expected_variant {
+        return Err(Bech32Error::InvalidChecksum);
+    }
+    Ok(DecodedWitness {
+        hrp,
+        version,
+        program,
+    })
+}
@@ -6,6 +6,7 @@
 
 use crate::{base32, base58, base64, hex, EncodingError};
 use std::ffi::{c_char, CStr, CString};
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::c_byte_array::{CByteArray, CByteArrayResult};
 use tw_memory::ffi::c_result::{CStrMutResult, ErrorCode};
 
@@ -63,6 +64,7 @@ impl From<Base58Alphabet> for base58::Alphabet {
 /// \param padding whether the padding bytes should be included.
 /// \return C-compatible result with a C-compatible, nul-terminated string.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn encode_base32(
     input: *const u8,
     input_len: usize,
@@ -89,6 +91,7 @@ pub unsafe extern "C" fn encode_base32(
 /// \param padding whether the padding bytes should be trimmed when decoding.
 /// \return C-compatible result with a C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn decode_base32(
     input: *const c_char,
     alphabet: *const c_char,
@@ -115,6 +118,7 @@ pub unsafe extern "C" fn decode_base32(
 /// \param alphabet alphabet type.
 /// \return *non-null* C-compatible, nul-terminated string.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn encode_base58(
     input: *const u8,
     input_len: usize,
@@ -131,6 +135,7 @@ pub unsafe extern "C" fn encode_base58(
 /// \param alphabet alphabet type.
 /// \return C-compatible result with a C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn decode_base58(
     input: *const c_char,
     alphabet: Base58Alphabet,
@@ -152,6 +157,7 @@ pub unsafe extern "C" fn decode_base58(
 /// \param is_url whether to use the [URL safe alphabet](https://www.rfc-editor.org/rfc/rfc3548#section-4).
 /// \return *non-null* C-compatible, nul-terminated string.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn encode_base64(data: *const u8, len: usize, is_url: bool) -> *mut c_char {
     let data = std::slice::from_raw_parts(data, len);
     let config = base64::Config {
@@ -167,6 +173,7 @@ pub unsafe extern "C" fn encode_base64(data: *const u8, len: usize, is_url: bool
 /// \param is_url whether to use the [URL safe alphabet](https://www.rfc-editor.org/rfc/rfc3548#section-4).
 /// \return C-compatible result with a C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn decode_base64(data: *const c_char, is_url: bool) -> CByteArrayResult {
     if data.is_null() {
         return CByteArrayResult::error(CEncodingCode::InvalidInput);
@@ -189,6 +196,7 @@ pub unsafe extern "C" fn decode_base64(data: *const c_char, is_url: bool) -> CBy
 /// \param data *optional* C-compatible, nul-terminated string.
 /// \return C-compatible result with a C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn decode_hex(data: *const c_char) -> CByteArrayResult {
     if data.is_null() {
         return CByteArrayResult::error(CEncodingCode::InvalidInput);
@@ -210,6 +218,7 @@ pub unsafe extern "C" fn decode_hex(data: *const c_char) -> CByteArrayResult {
 /// \param prefixed whether to add `0x` prefix.
 /// \return *non-null* C-compatible, nul-terminated string.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn encode_hex(data: *const u8, len: usize, prefixed: bool) -> *mut c_char {
     let data = std::slice::from_raw_parts(data, len);
     let encoded = hex::encode(data, prefixed);
@@ -2,8 +2,10 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use alloc::format;
+use alloc::string::String;
+use core::ops::Deref;
 pub use hex::FromHexError;
-use std::ops::Deref;
 use tw_memory::Data;
 
 pub type FromHexResult<T> = Result<T, FromHexError>;
@@ -91,9 +93,9 @@ pub fn bits_to_u32(bits: &str, from: usize, to: usize) -> FromHexResult<u32> {
 
 pub mod as_hex {
     use super::*;
+    use core::fmt;
     use serde::de::Error;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
-    use std::fmt;
 
     /// Helps to serialize certain types as hex.
     /// For example,
@@ -159,8 +161,8 @@ pub mod as_hex {
 
 pub mod as_hex_prefixed {
     use crate::hex::encode;
+    use core::fmt;
     use serde::{Deserializer, Serialize, Serializer};
-    use std::fmt;
 
     /// Serializes the `value` as a `0x` prefixed hex.
     pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
@@ -2,12 +2,21 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod base32;
 pub mod base58;
 pub mod base64;
+#[cfg(feature = "std")]
 pub mod bcs;
+#[cfg(feature = "std")]
 pub mod bech32;
+#[cfg(feature = "std")]
 pub mod cbor;
+#[cfg(feature = "std")]
 pub mod ffi;
 pub mod hex;
 
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tw_evm::evm_context::StandardEvmContext;
+use tw_evm::modules::rlp_decoder::RlpDecoder;
+use tw_evm::modules::rlp_encoder::RlpEncoder;
+use tw_proto::EthereumRlp::Proto;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(item) = RlpDecoder::decode(data) else {
+        return;
+    };
+    // Only canonical items are decoded, so they must be encoded back as they are.
+    let output = RlpEncoder::<StandardEvmContext>::encode_with_proto(Proto::EncodingInput {
+        item: Some(item),
+    });
+    assert_eq!(output.encoded.as_ref(), data);
+});
@@ -12,6 +12,7 @@ use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use itertools::Itertools;
 use serde::Deserialize;
 use tw_coin_entry::error::prelude::*;
+use tw_hash::H32;
 use tw_memory::Data;
 
 #[derive(Clone, Debug, Default, Deserialize)]
@@ -43,6 +44,13 @@ impl Function {
         }
     }
 
+    /// Returns the function selector, the first four bytes of the Keccak-256 hash of the signature.
+    pub fn selector(&self) -> H32 {
+        let input_param_types: Vec<_> =
+            self.inputs.iter().map(|param| param.kind.clone()).collect();
+        short_signature(&self.name, &input_param_types)
+    }
+
     /// Parses the ABI function input to a list of tokens.
     pub fn decode_input(&self, data: &[u8]) -> AbiResult<Vec<NamedToken>> {
         decode_params(&self.inputs, data)
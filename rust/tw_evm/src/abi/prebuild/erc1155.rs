@@ -15,7 +15,7 @@ use tw_number::U256;
 const ERC1155_ABI: &str = include_str!("resource/erc1155.abi.json");
 
 lazy_static! {
-    static ref ERC1155: Contract = serde_json::from_str(ERC1155_ABI).unwrap();
+    pub(crate) static ref ERC1155: Contract = serde_json::from_str(ERC1155_ABI).unwrap();
 }
 
 pub struct Erc1155;
@@ -15,7 +15,7 @@ use tw_number::U256;
 const ERC20_ABI: &str = include_str!("resource/erc20.abi.json");
 
 lazy_static! {
-    static ref ERC20: Contract = serde_json::from_str(ERC20_ABI).unwrap();
+    pub(crate) static ref ERC20: Contract = serde_json::from_str(ERC20_ABI).unwrap();
 }
 
 pub struct Erc20;
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::contract::Contract;
+use crate::abi::token::Token;
+use crate::abi::AbiResult;
+use crate::address::Address;
+use lazy_static::lazy_static;
+use tw_memory::Data;
+use tw_number::U256;
+
+/// https://github.com/OpenZeppelin/openzeppelin-contracts/blob/v4.9.2/contracts/interfaces/IERC4626.sol
+const ERC4626_ABI: &str = include_str!("resource/erc4626.abi.json");
+
+lazy_static! {
+    static ref ERC4626: Contract = serde_json::from_str(ERC4626_ABI).unwrap();
+}
+
+pub struct Erc4626;
+
+impl Erc4626 {
+    /// Please note the vault must be approved to spend `assets` of the underlying token.
+    pub fn deposit(assets: U256, receiver: Address) -> AbiResult<Data> {
+        let func = ERC4626.function("deposit")?;
+        func.encode_input(&[Token::u256(assets), Token::Address(receiver)])
+    }
+}
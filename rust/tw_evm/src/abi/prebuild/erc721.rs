@@ -15,7 +15,7 @@ use tw_number::U256;
 const ERC721_ABI: &str = include_str!("resource/erc721.abi.json");
 
 lazy_static! {
-    static ref ERC721: Contract = serde_json::from_str(ERC721_ABI).unwrap();
+    pub(crate) static ref ERC721: Contract = serde_json::from_str(ERC721_ABI).unwrap();
 }
 
 pub struct Erc721;
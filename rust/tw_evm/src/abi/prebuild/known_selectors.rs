@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::contract::Contract;
+use crate::abi::function::Function;
+use crate::abi::prebuild::erc1155::ERC1155;
+use crate::abi::prebuild::erc20::ERC20;
+use crate::abi::prebuild::erc721::ERC721;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use tw_hash::H32;
+use tw_proto::EthereumAbi::Proto::AbiStandard;
+
+lazy_static! {
+    static ref KNOWN_SELECTORS: HashMap<H32, KnownFunction> = build_known_selectors();
+}
+
+/// A function of the built-in standard ABIs.
+pub struct KnownFunction {
+    /// The function definition of the first standard that defines the selector.
+    pub function: Function,
+    /// All standards that define a function with the same selector.
+    pub standards: Vec<AbiStandard>,
+}
+
+/// Looks up a function of the built-in ERC-20, ERC-721 and ERC-1155 ABIs by its selector.
+pub fn find_known_function(selector: &H32) -> Option<&'static KnownFunction> {
+    KNOWN_SELECTORS.get(selector)
+}
+
+fn build_known_selectors() -> HashMap<H32, KnownFunction> {
+    let contracts: [(AbiStandard, &Contract); 3] = [
+        (AbiStandard::ERC20, &ERC20),
+        (AbiStandard::ERC721, &ERC721),
+        (AbiStandard::ERC1155, &ERC1155),
+    ];
+
+    let mut known = HashMap::<H32, KnownFunction>::default();
+    for (standard, contract) in contracts {
+        for function in contract.functions.values().flatten() {
+            known
+                .entry(function.selector())
+                .or_insert_with(|| KnownFunction {
+                    function: function.clone(),
+                    standards: Vec::default(),
+                })
+                .standards
+                .push(standard);
+        }
+    }
+    known
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::contract::Contract;
+use crate::abi::token::Token;
+use crate::abi::AbiResult;
+use crate::address::Address;
+use lazy_static::lazy_static;
+use tw_memory::Data;
+use tw_number::U256;
+
+/// OP Stack L1 bridge.
+/// https://github.com/ethereum-optimism/optimism/blob/develop/packages/contracts-bedrock/src/L1/L1StandardBridge.sol
+const L1_STANDARD_BRIDGE_ABI: &str = include_str!("resource/l1_standard_bridge.abi.json");
+
+lazy_static! {
+    static ref L1_STANDARD_BRIDGE: Contract = serde_json::from_str(L1_STANDARD_BRIDGE_ABI).unwrap();
+}
+
+pub struct L1StandardBridge;
+
+impl L1StandardBridge {
+    /// Deposits native coins passed as the transaction value to the `to` L2 account.
+    pub fn deposit_eth_to(to: Address, min_gas_limit: u32, extra_data: Data) -> AbiResult<Data> {
+        let func = L1_STANDARD_BRIDGE.function("depositETHTo")?;
+        func.encode_input(&[
+            Token::Address(to),
+            Token::uint(32, min_gas_limit)?,
+            Token::Bytes(extra_data),
+        ])
+    }
+
+    /// Deposits `amount` of `l1_token` to the `to` L2 account.
+    /// Please note the bridge must be approved to spend `amount` of the `l1_token`.
+    pub fn deposit_erc20_to(
+        l1_token: Address,
+        l2_token: Address,
+        to: Address,
+        amount: U256,
+        min_gas_limit: u32,
+        extra_data: Data,
+    ) -> AbiResult<Data> {
+        let func = L1_STANDARD_BRIDGE.function("depositERC20To")?;
+        func.encode_input(&[
+            Token::Address(l1_token),
+            Token::Address(l2_token),
+            Token::Address(to),
+            Token::u256(amount),
+            Token::uint(32, min_gas_limit)?,
+            Token::Bytes(extra_data),
+        ])
+    }
+}
@@ -11,7 +11,11 @@ pub mod erc1155;
 pub mod erc1967;
 pub mod erc20;
 pub mod erc4337;
+pub mod erc4626;
 pub mod erc721;
+pub mod known_selectors;
+pub mod l1_standard_bridge;
+pub mod staking;
 
 pub struct ExecuteArgs {
     pub to: Address,
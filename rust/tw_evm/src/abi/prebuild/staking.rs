@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::abi::contract::Contract;
+use crate::abi::token::Token;
+use crate::abi::AbiResult;
+use crate::address::Address;
+use lazy_static::lazy_static;
+use tw_memory::Data;
+
+/// https://github.com/lidofinance/core/blob/master/contracts/0.4.24/Lido.sol
+const LIDO_ABI: &str = include_str!("resource/lido.abi.json");
+/// https://github.com/rocket-pool/rocketpool/blob/master/contracts/contract/deposit/RocketDepositPool.sol
+const ROCKET_POOL_ABI: &str = include_str!("resource/rocket_pool.abi.json");
+/// https://github.com/bnb-chain/bsc-genesis-contract/blob/master/contracts/BC_fusion/StakeHub.sol
+const BSC_STAKE_HUB_ABI: &str = include_str!("resource/bsc_stake_hub.abi.json");
+
+lazy_static! {
+    static ref LIDO: Contract = serde_json::from_str(LIDO_ABI).unwrap();
+    static ref ROCKET_POOL: Contract = serde_json::from_str(ROCKET_POOL_ABI).unwrap();
+    static ref BSC_STAKE_HUB: Contract = serde_json::from_str(BSC_STAKE_HUB_ABI).unwrap();
+}
+
+/// Payable calls of native staking contracts.
+/// The staked amount is passed as the transaction value.
+pub struct NativeStaking;
+
+impl NativeStaking {
+    /// Lido `submit(address _referral)`.
+    pub fn lido_submit(referral: Address) -> AbiResult<Data> {
+        let func = LIDO.function("submit")?;
+        func.encode_input(&[Token::Address(referral)])
+    }
+
+    /// Rocket Pool deposit pool `deposit()`.
+    pub fn rocket_pool_deposit() -> AbiResult<Data> {
+        let func = ROCKET_POOL.function("deposit")?;
+        func.encode_input(&[])
+    }
+
+    /// BSC StakeHub `delegate(address operatorAddress, bool delegateVotePower)`.
+    pub fn bsc_delegate(validator: Address, delegate_vote_power: bool) -> AbiResult<Data> {
+        let func = BSC_STAKE_HUB.function("delegate")?;
+        func.encode_input(&[Token::Address(validator), Token::Bool(delegate_vote_power)])
+    }
+}
@@ -5,9 +5,13 @@
 use crate::abi::AbiResult;
 use crate::evm_context::EvmContext;
 use crate::modules::abi_encoder::AbiEncoder;
+use crate::modules::permit_builder::PermitBuilder;
+use crate::modules::risk_analyzer::RiskAnalyzer;
 use crate::modules::rlp_encoder::RlpEncoder;
 use tw_memory::Data;
 use tw_proto::EthereumAbi::Proto as AbiProto;
+use tw_proto::EthereumPermit::Proto as PermitProto;
+use tw_proto::EthereumRisk::Proto as RiskProto;
 use tw_proto::EthereumRlp::Proto as RlpProto;
 use tw_proto::{deserialize, serialize, ProtoResult};
 
@@ -29,6 +33,14 @@ pub trait EvmEntry {
         AbiEncoder::<Self::Context>::decode_contract_call(input)
     }
 
+    /// Decodes function call data according to a user-supplied ABI or the built-in standard ABIs.
+    #[inline]
+    fn decode_abi_calldata(
+        input: AbiProto::CalldataDecodingInput<'_>,
+    ) -> AbiProto::CalldataDecodingOutput<'static> {
+        AbiEncoder::<Self::Context>::decode_calldata(input)
+    }
+
     /// Decodes a function input or output data according to a given ABI.
     #[inline]
     fn decode_abi_params(
@@ -64,6 +76,18 @@ pub trait EvmEntry {
     ) -> AbiProto::FunctionEncodingOutput<'static> {
         AbiEncoder::<Self::Context>::encode_contract_call(input)
     }
+
+    /// Inspects a transaction or a typed data to be signed and returns warnings about risky requests.
+    #[inline]
+    fn analyze_risks(input: RiskProto::AnalysisInput<'_>) -> RiskProto::AnalysisOutput<'static> {
+        RiskAnalyzer::<Self::Context>::analyze(input)
+    }
+
+    /// Builds an ERC-2612 or Permit2 typed data and signs it.
+    #[inline]
+    fn sign_permit(input: PermitProto::SigningInput<'_>) -> PermitProto::SigningOutput<'static> {
+        PermitBuilder::<Self::Context>::sign(input)
+    }
 }
 
 /// The [`EvmEntry`] trait extension.
@@ -74,6 +98,9 @@ pub trait EvmEntryExt {
     /// Decodes function call data to human readable json format, according to input abi json.
     fn decode_abi_contract_call(&self, input: &[u8]) -> ProtoResult<Data>;
 
+    /// Decodes function call data according to a user-supplied ABI or the built-in standard ABIs.
+    fn decode_abi_calldata(&self, input: &[u8]) -> ProtoResult<Data>;
+
     /// Decodes a function input or output data according to a given ABI.
     fn decode_abi_params(&self, input: &[u8]) -> ProtoResult<Data>;
 
@@ -88,6 +115,12 @@ pub trait EvmEntryExt {
 
     /// Decodes an Eth ABI value according to a given type.
     fn decode_abi_value(&self, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Inspects a transaction or a typed data to be signed and returns warnings about risky requests.
+    fn analyze_risks(&self, input: &[u8]) -> ProtoResult<Data>;
+
+    /// Builds an ERC-2612 or Permit2 typed data and signs it.
+    fn sign_permit(&self, input: &[u8]) -> ProtoResult<Data>;
 }
 
 impl<T> EvmEntryExt for T
@@ -106,6 +139,12 @@ where
         serialize(&output)
     }
 
+    fn decode_abi_calldata(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::decode_abi_calldata(input);
+        serialize(&output)
+    }
+
     fn decode_abi_params(&self, input: &[u8]) -> ProtoResult<Data> {
         let input = deserialize(input)?;
         let output = <Self as EvmEntry>::decode_abi_params(input);
@@ -132,4 +171,16 @@ where
         let output = <Self as EvmEntry>::decode_abi_value(input);
         serialize(&output)
     }
+
+    fn analyze_risks(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::analyze_risks(input);
+        serialize(&output)
+    }
+
+    fn sign_permit(&self, input: &[u8]) -> ProtoResult<Data> {
+        let input = deserialize(input)?;
+        let output = <Self as EvmEntry>::sign_permit(input);
+        serialize(&output)
+    }
 }
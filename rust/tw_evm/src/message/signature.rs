@@ -9,7 +9,7 @@ use std::str::FromStr;
 use tw_encoding::hex::DecodeHex;
 use tw_hash::{H256, H520};
 use tw_keypair::ecdsa::secp256k1;
-use tw_keypair::{KeyPairError, KeyPairResult};
+use tw_keypair::{KeyPairError, KeyPairErrorKind, KeyPairResult};
 use tw_number::U256;
 
 pub enum SignatureType {
@@ -33,11 +33,10 @@ impl MessageSignature {
     pub fn prepared(sign: secp256k1::Signature, sign_type: SignatureType) -> KeyPairResult<Self> {
         let v = match sign_type {
             SignatureType::Standard => U256::from(sign.v()),
-            SignatureType::Legacy => {
-                legacy_replay_protection(sign.v()).map_err(|_| KeyPairError::InvalidSignature)?
-            },
+            SignatureType::Legacy => legacy_replay_protection(sign.v())
+                .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?,
             SignatureType::Eip155 { chain_id } => eip155_replay_protection(chain_id, sign.v())
-                .map_err(|_| KeyPairError::InvalidSignature)?,
+                .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?,
         };
         Ok(MessageSignature {
             r: sign.r(),
@@ -65,7 +64,9 @@ impl FromStr for MessageSignature {
     type Err = KeyPairError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let bytes = s.decode_hex().map_err(|_| KeyPairError::InvalidSignature)?;
+        let bytes = s
+            .decode_hex()
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
         Self::try_from(bytes.as_slice())
     }
 }
@@ -75,7 +76,7 @@ impl<'a> TryFrom<&'a [u8]> for MessageSignature {
 
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
         if data.len() != Self::LEN {
-            return Err(KeyPairError::InvalidSignature);
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSignature));
         }
 
         let r = H256::try_from(&data[secp256k1::Signature::R_RANGE])
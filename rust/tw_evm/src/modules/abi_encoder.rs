@@ -2,11 +2,13 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use crate::abi::contract::Contract;
 use crate::abi::decode::{decode_params, decode_value};
 use crate::abi::function::Function;
 use crate::abi::param::Param;
 use crate::abi::param_token::NamedToken;
 use crate::abi::param_type::ParamType;
+use crate::abi::prebuild::known_selectors::find_known_function;
 use crate::abi::token::Token;
 use crate::abi::{AbiError, AbiErrorKind, AbiResult};
 use crate::abi_output_error;
@@ -18,7 +20,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::str::FromStr;
-use tw_encoding::hex::as_hex;
+use tw_encoding::hex::{as_hex, ToHex};
 use tw_hash::H32;
 use tw_misc::traits::ToBytesVec;
 use tw_number::{I256, U256};
@@ -44,6 +46,14 @@ impl<Context: EvmContext> AbiEncoder<Context> {
             .unwrap_or_else(|err| abi_output_error!(Proto::ContractCallDecodingOutput, err))
     }
 
+    #[inline]
+    pub fn decode_calldata(
+        input: Proto::CalldataDecodingInput<'_>,
+    ) -> Proto::CalldataDecodingOutput<'static> {
+        Self::decode_calldata_impl(input)
+            .unwrap_or_else(|err| abi_output_error!(Proto::CalldataDecodingOutput, err))
+    }
+
     #[inline]
     pub fn decode_params(
         input: Proto::ParamsDecodingInput<'_>,
@@ -134,6 +144,62 @@ impl<Context: EvmContext> AbiEncoder<Context> {
         })
     }
 
+    fn decode_calldata_impl(
+        input: Proto::CalldataDecodingInput<'_>,
+    ) -> AbiResult<Proto::CalldataDecodingOutput<'static>> {
+        if input.calldata.len() < H32::len() {
+            return AbiError::err(AbiErrorKind::Error_decoding_data)
+                .context("Calldata bytes too short");
+        }
+        let selector = H32::try_from(&input.calldata[0..H32::len()])
+            .expect("The length expected to be checked above");
+        let encoded_data = &input.calldata[H32::len()..];
+
+        let user_function = if input.abi_json.is_empty() {
+            None
+        } else {
+            let contract: Contract = serde_json::from_str(&input.abi_json)
+                .tw_err(AbiErrorKind::Error_invalid_abi)
+                .context("Error deserializing Smart Contract ABI as JSON")?;
+            contract
+                .functions
+                .into_values()
+                .flatten()
+                .find(|fun| fun.selector() == selector)
+        };
+
+        // Look up the built-in standard ABIs if the user-supplied ABI doesn't have the function.
+        let (mut function, standards) = match user_function {
+            Some(fun) => (fun, Vec::default()),
+            None => {
+                let known = find_known_function(&selector)
+                    .or_tw_err(AbiErrorKind::Error_abi_mismatch)
+                    .with_context(|| format!("Unknown function selector: {selector}"))?;
+                (known.function.clone(), known.standards.clone())
+            },
+        };
+
+        let decoded_tokens = function.decode_input(encoded_data)?;
+
+        // Clear the `outputs` to avoid adding them to the signature.
+        function.outputs.clear();
+        let function_signature = function.signature();
+
+        let params = decoded_tokens
+            .into_iter()
+            .map(Self::named_token_to_proto)
+            .collect();
+
+        Ok(Proto::CalldataDecodingOutput {
+            selector: Cow::Owned(selector.to_hex_prefixed()),
+            function_name: Cow::Owned(function.name),
+            function_signature: Cow::Owned(function_signature),
+            params,
+            standards,
+            ..Proto::CalldataDecodingOutput::default()
+        })
+    }
+
     fn decode_params_impl(
         input: Proto::ParamsDecodingInput<'_>,
     ) -> AbiResult<Proto::ParamsDecodingOutput<'static>> {
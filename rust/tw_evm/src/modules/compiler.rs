@@ -4,13 +4,16 @@
 
 use crate::evm_context::EvmContext;
 use crate::modules::tx_builder::TxBuilder;
+use crate::signature::recovery_id_from_v;
 use std::borrow::Cow;
 use std::marker::PhantomData;
 use tw_coin_entry::coin_entry::{PublicKeyBytes, SignatureBytes};
 use tw_coin_entry::common::compile_input::SingleSignaturePubkey;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
+use tw_hash::{H256, H520};
 use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::ecdsa::secp256k1::external;
 use tw_number::U256;
 use tw_proto::Ethereum::Proto;
 use tw_proto::TxCompiler::Proto as CompilerProto;
@@ -59,13 +62,12 @@ impl<Context: EvmContext> Compiler<Context> {
     fn compile_impl(
         input: Proto::SigningInput<'_>,
         signatures: Vec<SignatureBytes>,
-        _: Vec<PublicKeyBytes>,
+        public_keys: Vec<PublicKeyBytes>,
     ) -> SigningResult<Proto::SigningOutput<'static>> {
         let SingleSignaturePubkey {
             signature,
             public_key: _,
         } = SingleSignaturePubkey::from_sign_list(signatures)?;
-        let signature = secp256k1::Signature::from_bytes(&signature)?;
 
         let chain_id = U256::from_big_endian_slice(&input.chain_id)
             .into_tw()
@@ -75,6 +77,9 @@ impl<Context: EvmContext> Compiler<Context> {
 
         let pre_hash = unsigned.pre_hash(chain_id);
 
+        let signature =
+            Self::external_signature(&signature, public_keys.first(), pre_hash, chain_id)?;
+
         let signed = unsigned.try_into_signed(signature, chain_id)?;
 
         let eth_signature = signed.signature();
@@ -89,4 +94,42 @@ impl<Context: EvmContext> Compiler<Context> {
             ..Proto::SigningOutput::default()
         })
     }
+
+    /// Adapts a signature obtained from an external signer, e.g. a hardware wallet.
+    ///
+    /// Supports 65 byte `r || s || v` signatures with any form of `v`
+    /// (see [`recovery_id_from_v`]), and 64 byte `r || s` or DER signatures
+    /// if the public key is given to restore the recovery ID.
+    fn external_signature(
+        signature: &[u8],
+        public_key: Option<&PublicKeyBytes>,
+        pre_hash: H256,
+        chain_id: U256,
+    ) -> SigningResult<secp256k1::Signature> {
+        if signature.len() == secp256k1::Signature::LEN {
+            let (rs, v) = signature.split_at(secp256k1::Signature::RECOVERY_LAST);
+            let v = recovery_id_from_v(U256::from(v[0]), chain_id)
+                .into_tw()
+                .context("Invalid signature 'v' value")?;
+
+            let mut rsv = H520::default();
+            rsv[..secp256k1::Signature::RECOVERY_LAST].copy_from_slice(rs);
+            rsv[secp256k1::Signature::RECOVERY_LAST] = v;
+            return Ok(secp256k1::Signature::from_bytes(rsv.as_slice())?);
+        }
+
+        let public_key = public_key
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("Public key is required to restore the signature recovery ID")?;
+        let public_key = secp256k1::PublicKey::try_from(public_key.as_slice())
+            .into_tw()
+            .context("Invalid public key")?;
+
+        let signature = if signature.len() == secp256k1::Signature::RECOVERY_LAST {
+            external::signature_from_rs(signature, &public_key, pre_hash)
+        } else {
+            external::signature_from_der(signature, &public_key, pre_hash)
+        };
+        Ok(signature?)
+    }
 }
@@ -6,7 +6,11 @@ pub mod abi_encoder;
 pub mod barz;
 pub mod compiler;
 pub mod message_signer;
+pub mod permit_builder;
+pub mod risk_analyzer;
+pub mod rlp_decoder;
 pub mod rlp_encoder;
 pub mod signer;
+pub mod transaction_planner;
 pub mod transaction_util;
 pub mod tx_builder;
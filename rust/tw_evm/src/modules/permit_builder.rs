@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Gasless approvals.
+//!
+//! Builds the EIP-712 typed data of ERC-2612 and Uniswap Permit2 permits and signs it,
+//! so the hosts don't have to construct the typed data JSON manually.
+
+use crate::address::Address;
+use crate::evm_context::EvmContext;
+use crate::message::eip712::eip712_message::Eip712Message;
+use crate::message::signature::{MessageSignature, SignatureType};
+use crate::message::{to_signing, EthMessage};
+use crate::modules::risk_analyzer::{PERMIT2_ADDRESS, PERMIT2_DOMAIN_NAME};
+use serde_json::{json, Value as Json};
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_encoding::hex::ToHex;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_number::U256;
+use tw_proto::EthereumPermit::Proto;
+use Proto::mod_SigningInput::OneOfpermit as PermitType;
+
+const UINT48_MAX: u64 = (1 << 48) - 1;
+const UINT160_BITS: usize = 160;
+
+pub struct PermitBuilder<Context: EvmContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: EvmContext> PermitBuilder<Context> {
+    #[inline]
+    pub fn sign(input: Proto::SigningInput<'_>) -> Proto::SigningOutput<'static> {
+        Self::sign_impl(input).unwrap_or_else(|e| signing_output_error!(Proto::SigningOutput, e))
+    }
+
+    fn sign_impl(input: Proto::SigningInput<'_>) -> SigningResult<Proto::SigningOutput<'static>> {
+        let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
+        let owner = Address::with_secp256k1_pubkey(&private_key.public());
+
+        let typed_data = match input.permit {
+            PermitType::erc2612(ref permit) => {
+                Self::erc2612_typed_data(permit, &owner, input.chain_id)?
+            },
+            PermitType::permit2_single(ref permit) => {
+                Self::permit2_single_typed_data(permit, input.chain_id)?
+            },
+            PermitType::permit2_batch(ref permit) => {
+                Self::permit2_batch_typed_data(permit, input.chain_id)?
+            },
+            PermitType::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("No permit provided")
+            },
+        };
+        let typed_data = typed_data.to_string();
+
+        let hash_to_sign = Eip712Message::new(&typed_data)
+            .and_then(|msg| msg.hash())
+            .map_err(to_signing)?;
+        let secp_sign = private_key.sign(hash_to_sign)?;
+        let signature = MessageSignature::prepared(secp_sign, SignatureType::Legacy)?;
+
+        Ok(Proto::SigningOutput {
+            typed_data: Cow::Owned(typed_data),
+            signature: Cow::Owned(signature.to_bytes().to_hex()),
+            owner: Cow::Owned(owner.into_checksum_address()),
+            ..Proto::SigningOutput::default()
+        })
+    }
+
+    fn erc2612_typed_data(
+        permit: &Proto::Erc2612Permit<'_>,
+        owner: &Address,
+        chain_id: u64,
+    ) -> SigningResult<Json> {
+        // The domain version is token specific, e.g. "2" for USDC, so it can't be defaulted.
+        if permit.version.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("ERC-2612 permit 'version' must be set");
+        }
+        let version = permit.version.as_ref();
+
+        Ok(json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" }
+                ],
+                "Permit": [
+                    { "name": "owner", "type": "address" },
+                    { "name": "spender", "type": "address" },
+                    { "name": "value", "type": "uint256" },
+                    { "name": "nonce", "type": "uint256" },
+                    { "name": "deadline", "type": "uint256" }
+                ]
+            },
+            "primaryType": "Permit",
+            "domain": {
+                "name": permit.token_name,
+                "version": version,
+                "chainId": chain_id,
+                "verifyingContract": parse_address(&permit.token, "token")?
+            },
+            "message": {
+                "owner": owner.to_string(),
+                "spender": parse_address(&permit.spender, "spender")?,
+                "value": parse_u256(&permit.value, "value")?,
+                "nonce": parse_u256(&permit.nonce, "nonce")?,
+                "deadline": permit.deadline.to_string()
+            }
+        }))
+    }
+
+    fn permit2_single_typed_data(
+        permit: &Proto::Permit2Single<'_>,
+        chain_id: u64,
+    ) -> SigningResult<Json> {
+        let details = permit
+            .details
+            .as_ref()
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("No permit details provided")?;
+
+        Ok(json!({
+            "types": Self::permit2_types("PermitSingle", "PermitDetails"),
+            "primaryType": "PermitSingle",
+            "domain": Self::permit2_domain(chain_id),
+            "message": {
+                "details": permit2_details(details)?,
+                "spender": parse_address(&permit.spender, "spender")?,
+                "sigDeadline": permit.sig_deadline.to_string()
+            }
+        }))
+    }
+
+    fn permit2_batch_typed_data(
+        permit: &Proto::Permit2Batch<'_>,
+        chain_id: u64,
+    ) -> SigningResult<Json> {
+        if permit.details.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("No permit details provided");
+        }
+        let details = permit
+            .details
+            .iter()
+            .map(permit2_details)
+            .collect::<SigningResult<Vec<_>>>()?;
+
+        Ok(json!({
+            "types": Self::permit2_types("PermitBatch", "PermitDetails[]"),
+            "primaryType": "PermitBatch",
+            "domain": Self::permit2_domain(chain_id),
+            "message": {
+                "details": details,
+                "spender": parse_address(&permit.spender, "spender")?,
+                "sigDeadline": permit.sig_deadline.to_string()
+            }
+        }))
+    }
+
+    fn permit2_types(primary_type: &str, details_type: &str) -> Json {
+        json!({
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "chainId", "type": "uint256" },
+                { "name": "verifyingContract", "type": "address" }
+            ],
+            primary_type: [
+                { "name": "details", "type": details_type },
+                { "name": "spender", "type": "address" },
+                { "name": "sigDeadline", "type": "uint256" }
+            ],
+            "PermitDetails": [
+                { "name": "token", "type": "address" },
+                { "name": "amount", "type": "uint160" },
+                { "name": "expiration", "type": "uint48" },
+                { "name": "nonce", "type": "uint48" }
+            ]
+        })
+    }
+
+    fn permit2_domain(chain_id: u64) -> Json {
+        json!({
+            "name": PERMIT2_DOMAIN_NAME,
+            "chainId": chain_id,
+            "verifyingContract": PERMIT2_ADDRESS
+        })
+    }
+}
+
+fn permit2_details(details: &Proto::Permit2Details<'_>) -> SigningResult<Json> {
+    let amount = U256::from_big_endian_slice(&details.amount)
+        .into_tw()
+        .context("Invalid permit amount")?;
+    if amount.bits() > UINT160_BITS {
+        return SigningError::err(SigningErrorType::Error_invalid_params)
+            .context("Permit2 amount must fit in uint160");
+    }
+    if details.expiration > UINT48_MAX || details.nonce > UINT48_MAX {
+        return SigningError::err(SigningErrorType::Error_invalid_params)
+            .context("Permit2 expiration and nonce must fit in uint48");
+    }
+
+    Ok(json!({
+        "token": parse_address(&details.token, "token")?,
+        "amount": amount.to_string(),
+        "expiration": details.expiration.to_string(),
+        "nonce": details.nonce.to_string()
+    }))
+}
+
+/// Returns a checksum address.
+fn parse_address(address: &str, name: &str) -> SigningResult<String> {
+    Address::from_str(address)
+        .map(|address| address.to_string())
+        .into_tw()
+        .with_context(|| format!("Invalid {name} address"))
+}
+
+/// Returns a decimal string of the uint256 value.
+fn parse_u256(value: &[u8], name: &str) -> SigningResult<String> {
+    U256::from_big_endian_slice(value)
+        .map(|value| value.to_string())
+        .into_tw()
+        .with_context(|| format!("Invalid {name}"))
+}
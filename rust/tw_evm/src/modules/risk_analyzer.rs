@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Risk annotations of signature requests.
+//!
+//! Inspects a transaction or an EIP-712 typed data and warns about requests
+//! that give a third party access to the user's tokens, so all platforms show the same warnings.
+
+use crate::abi::decode::decode_params;
+use crate::abi::param::Param;
+use crate::abi::param_type::ParamType;
+use crate::abi::signature::short_signature;
+use crate::abi::token::Token;
+use crate::abi::uint::UintBits;
+use crate::address::Address;
+use crate::evm_context::EvmContext;
+use crate::message::eip712::eip712_message::Eip712Message;
+use crate::message::to_signing;
+use serde_json::Value as Json;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::signing_output_error;
+use tw_hash::H32;
+use tw_number::U256;
+use tw_proto::EthereumRisk::Proto;
+use Proto::mod_AnalysisInput::OneOfrequest as RequestType;
+
+/// The Uniswap Permit2 contract, deployed to the same address on every chain.
+pub(crate) const PERMIT2_ADDRESS: &str = "0x000000000022D473030F116dDEE9F6B43aC78BA3";
+pub(crate) const PERMIT2_DOMAIN_NAME: &str = "Permit2";
+/// EIP-2612 and DAI-like permit typed data.
+const PERMIT_PRIMARY_TYPE: &str = "Permit";
+
+pub struct RiskAnalyzer<Context: EvmContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: EvmContext> RiskAnalyzer<Context> {
+    #[inline]
+    pub fn analyze(input: Proto::AnalysisInput<'_>) -> Proto::AnalysisOutput<'static> {
+        Self::analyze_impl(input)
+            .unwrap_or_else(|e| signing_output_error!(Proto::AnalysisOutput, e))
+    }
+
+    fn analyze_impl(
+        input: Proto::AnalysisInput<'_>,
+    ) -> SigningResult<Proto::AnalysisOutput<'static>> {
+        let warnings = match input.request {
+            RequestType::transaction(ref tx) => Self::analyze_transaction(tx)?,
+            RequestType::typed_data(ref typed_data) => Self::analyze_typed_data(typed_data)?,
+            RequestType::None => {
+                return SigningError::err(SigningErrorType::Error_invalid_params)
+                    .context("No signature request provided")
+            },
+        };
+
+        Ok(Proto::AnalysisOutput {
+            warnings,
+            ..Proto::AnalysisOutput::default()
+        })
+    }
+
+    fn analyze_transaction(
+        tx: &Proto::Transaction<'_>,
+    ) -> SigningResult<Vec<Proto::RiskWarning<'static>>> {
+        let value = U256::from_big_endian_slice(&tx.value)
+            .tw_err(SigningErrorType::Error_invalid_params)
+            .context("Invalid transaction value")?;
+
+        if tx.to.is_empty() {
+            let warnings = (!value.is_zero())
+                .then(|| warning(Proto::RiskType::ValueToNewContract, "", ""))
+                .into_iter()
+                .collect();
+            return Ok(warnings);
+        }
+
+        let to = Address::from_str(&tx.to)
+            .into_tw()
+            .context("Invalid destination address")?;
+        Ok(Self::analyze_call(&to, &tx.data).into_iter().collect())
+    }
+
+    fn analyze_call(to: &Address, data: &[u8]) -> Option<Proto::RiskWarning<'static>> {
+        let contract = to.to_string();
+        let address_u256 = [ParamType::Address, ParamType::u256()];
+
+        for name in ["approve", "increaseAllowance"] {
+            if let Some([Token::Address(spender), Token::Uint { uint, .. }]) =
+                decode_call(data, name, &address_u256).as_deref()
+            {
+                return (*uint == U256::MAX).then(|| {
+                    warning(
+                        Proto::RiskType::UnlimitedApproval,
+                        &contract,
+                        &spender.to_string(),
+                    )
+                });
+            }
+        }
+
+        let set_approval_for_all = [ParamType::Address, ParamType::Bool];
+        if let Some([Token::Address(operator), Token::Bool(approved)]) =
+            decode_call(data, "setApprovalForAll", &set_approval_for_all).as_deref()
+        {
+            return approved.then(|| {
+                warning(
+                    Proto::RiskType::ApprovalForAll,
+                    &contract,
+                    &operator.to_string(),
+                )
+            });
+        }
+
+        // `approve(address token, address spender, uint160 amount, uint48 expiration)`.
+        let permit2_approve = [
+            ParamType::Address,
+            ParamType::Address,
+            uint_type(160),
+            uint_type(48),
+        ];
+        if is_permit2(&contract) {
+            if let Some([Token::Address(token), Token::Address(spender), ..]) =
+                decode_call(data, "approve", &permit2_approve).as_deref()
+            {
+                return Some(warning(
+                    Proto::RiskType::Permit2,
+                    &token.to_string(),
+                    &spender.to_string(),
+                ));
+            }
+        }
+
+        None
+    }
+
+    fn analyze_typed_data(typed_data: &str) -> SigningResult<Vec<Proto::RiskWarning<'static>>> {
+        let msg = Eip712Message::new(typed_data).map_err(to_signing)?;
+        let verifying_contract = json_address(&msg.domain["verifyingContract"]);
+
+        if msg.domain["name"] == PERMIT2_DOMAIN_NAME || is_permit2(&verifying_contract) {
+            return Ok(Self::permit2_warnings(&msg.message));
+        }
+
+        if msg.primary_type == PERMIT_PRIMARY_TYPE {
+            // EIP-2612 permits specify the `value`, DAI-like permits allow all tokens with `allowed`.
+            let unlimited = json_u256(&msg.message["value"]) == Some(U256::MAX)
+                || msg.message["allowed"] == true;
+            if unlimited {
+                let spender = json_address(&msg.message["spender"]);
+                return Ok(vec![warning(
+                    Proto::RiskType::UnlimitedApproval,
+                    &verifying_contract,
+                    &spender,
+                )]);
+            }
+        }
+
+        Ok(Vec::default())
+    }
+
+    /// Returns a warning for every token of a Permit2 permit.
+    fn permit2_warnings(message: &Json) -> Vec<Proto::RiskWarning<'static>> {
+        let spender = json_address(&message["spender"]);
+
+        // `PermitSingle` and `PermitBatch` list the tokens in `details`,
+        // `PermitTransferFrom` and `PermitBatchTransferFrom` in `permitted`.
+        let permitted = if message["details"].is_null() {
+            &message["permitted"]
+        } else {
+            &message["details"]
+        };
+        let tokens = match permitted {
+            Json::Array(items) => items.iter().collect(),
+            item => vec![item],
+        };
+
+        tokens
+            .into_iter()
+            .map(|item| {
+                let token = json_address(&item["token"]);
+                warning(Proto::RiskType::Permit2, &token, &spender)
+            })
+            .collect()
+    }
+}
+
+/// Decodes the call parameters if the `data` is a call of the `name` function with the given `types`.
+fn decode_call(data: &[u8], name: &str, types: &[ParamType]) -> Option<Vec<Token>> {
+    if !data.starts_with(short_signature(name, types).as_slice()) {
+        return None;
+    }
+
+    let params: Vec<_> = types.iter().cloned().map(Param::with_type).collect();
+    let tokens = decode_params(&params, &data[H32::LEN..]).ok()?;
+    Some(tokens.into_iter().map(|token| token.value).collect())
+}
+
+fn uint_type(bits: usize) -> ParamType {
+    ParamType::Uint {
+        bits: UintBits::new(bits).expect("Expected a valid uint bits number"),
+    }
+}
+
+fn is_permit2(address: &str) -> bool {
+    address.eq_ignore_ascii_case(PERMIT2_ADDRESS)
+}
+
+/// Returns a checksum address if the `value` is a valid address, or the `value` as it is otherwise.
+fn json_address(value: &Json) -> String {
+    let Some(address) = value.as_str() else {
+        return String::default();
+    };
+    Address::from_str(address)
+        .map(|address| address.to_string())
+        .unwrap_or_else(|_| address.to_string())
+}
+
+/// Parses a number, a decimal string or a `0x` prefixed hex string, as EIP-712 allows any of them.
+fn json_u256(value: &Json) -> Option<U256> {
+    match value {
+        Json::String(s) => U256::from_str(s).ok(),
+        _ => U256::from_u64_or_decimal_str(value.clone()).ok(),
+    }
+}
+
+fn warning(
+    risk_type: Proto::RiskType,
+    contract: &str,
+    spender: &str,
+) -> Proto::RiskWarning<'static> {
+    let description = match risk_type {
+        Proto::RiskType::UnlimitedApproval => {
+            "Grants the spender an unlimited allowance to transfer your tokens"
+        },
+        Proto::RiskType::ApprovalForAll => {
+            "Grants the operator access to transfer all your tokens of the collection"
+        },
+        Proto::RiskType::Permit2 => {
+            "Grants the spender an allowance to transfer your tokens via Permit2"
+        },
+        Proto::RiskType::ValueToNewContract => "Sends native coins to a contract being deployed",
+    };
+
+    Proto::RiskWarning {
+        risk_type,
+        contract: Cow::Owned(contract.to_string()),
+        spender: Cow::Owned(spender.to_string()),
+        description: Cow::Borrowed(description),
+    }
+}
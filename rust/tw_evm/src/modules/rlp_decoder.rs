@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::modules::rlp_encoder::RECURSION_LIMIT;
+use rlp::{PayloadInfo, Rlp};
+use std::borrow::Cow;
+use tw_coin_entry::error::prelude::*;
+use tw_proto::EthereumRlp::Proto;
+
+pub struct RlpDecoder;
+
+impl RlpDecoder {
+    /// Decodes a canonical RLP item into [`Proto::mod_RlpItem::OneOfitem::data`] and
+    /// [`Proto::mod_RlpItem::OneOfitem::list`] items.
+    /// Lists nested deeper than [`RECURSION_LIMIT`] and trailing bytes are rejected.
+    pub fn decode(encoded: &[u8]) -> SigningResult<Proto::RlpItem<'static>> {
+        let rlp = Rlp::new(encoded);
+        if Self::payload_info(&rlp)?.total() != encoded.len() {
+            return SigningError::err(SigningErrorType::Error_input_parse)
+                .context("Unexpected trailing bytes after the RLP item");
+        }
+
+        let initial_depth = 0;
+        Self::decode_item(initial_depth, &rlp)
+    }
+
+    fn decode_item(depth: usize, rlp: &Rlp<'_>) -> SigningResult<Proto::RlpItem<'static>> {
+        use Proto::mod_RlpItem::OneOfitem as Item;
+
+        if depth >= RECURSION_LIMIT {
+            return SigningError::err(SigningErrorType::Error_input_parse).with_context(|| {
+                format!("Allowed complex types with the {RECURSION_LIMIT} maximum depth")
+            });
+        }
+
+        let item = if rlp.is_list() {
+            // `Rlp::item_count` stops at the first invalid item silently,
+            // so the decoded items are checked to take the whole list payload.
+            let payload_len = Self::payload_info(rlp)?.value_len;
+            let item_count = rlp
+                .item_count()
+                .map_err(|_| SigningError::new(SigningErrorType::Error_input_parse))
+                .context("Invalid RLP list")?;
+            let new_depth = depth + 1;
+
+            let mut items_len = 0;
+            let mut items = Vec::with_capacity(item_count);
+            for i in 0..item_count {
+                let nested = rlp
+                    .at(i)
+                    .map_err(|_| SigningError::new(SigningErrorType::Error_input_parse))
+                    .with_context(|| format!("Invalid RLP list item #{i}"))?;
+                items_len += nested.as_raw().len();
+                items.push(Self::decode_item(new_depth, &nested)?);
+            }
+
+            if items_len != payload_len {
+                return SigningError::err(SigningErrorType::Error_input_parse)
+                    .context("Invalid RLP list item");
+            }
+            Item::list(Proto::RlpList { items })
+        } else {
+            let data = rlp
+                .data()
+                .map_err(|_| SigningError::new(SigningErrorType::Error_input_parse))
+                .context("Invalid RLP data")?;
+            Item::data(Cow::from(data.to_vec()))
+        };
+        Ok(Proto::RlpItem { item })
+    }
+
+    fn payload_info(rlp: &Rlp<'_>) -> SigningResult<PayloadInfo> {
+        rlp.payload_info()
+            .map_err(|_| SigningError::new(SigningErrorType::Error_input_parse))
+            .context("Invalid RLP header")
+    }
+}
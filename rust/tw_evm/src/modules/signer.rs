@@ -8,12 +8,17 @@ use std::borrow::Cow;
 use std::marker::PhantomData;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_entry::signing_output_error;
+use tw_hash::H256;
 use tw_keypair::ecdsa::secp256k1;
 use tw_keypair::traits::SigningKeyTrait;
 use tw_number::U256;
 use tw_proto::Ethereum::Proto;
 
 const SIGNATURE_V_MIN_LEN: usize = 1;
+/// `r` and `s` of the dummy signature. Has no leading zeros, and is less than the curve order.
+const DUMMY_SIGNATURE_BYTE: u8 = 0x7f;
+/// The recovery ID that leads to the max `v` value.
+const DUMMY_SIGNATURE_RECOVERY_ID: u8 = 1;
 
 pub struct Signer<Context: EvmContext> {
     _phantom: PhantomData<Context>,
@@ -32,12 +37,27 @@ impl<Context: EvmContext> Signer<Context> {
         let chain_id = U256::from_big_endian_slice(&input.chain_id)
             .into_tw()
             .context("Invalid chain ID")?;
-        let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
 
         let unsigned = TxBuilder::<Context>::tx_from_proto(&input)?;
 
         let pre_hash = unsigned.pre_hash(chain_id);
-        let signature = private_key.sign(pre_hash)?;
+        let unsigned_encoded = unsigned.encode(chain_id);
+
+        if input.skip_signing {
+            return Ok(Proto::SigningOutput {
+                data: Cow::from(unsigned.payload()),
+                pre_hash: Cow::from(pre_hash.to_vec()),
+                unsigned_encoded: Cow::from(unsigned_encoded),
+                ..Proto::SigningOutput::default()
+            });
+        }
+
+        let signature = if input.dummy_signature {
+            Self::dummy_signature()?
+        } else {
+            let private_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())?;
+            private_key.sign(pre_hash)?
+        };
 
         let signed = unsigned.try_into_signed(signature, chain_id)?;
 
@@ -54,7 +74,16 @@ impl<Context: EvmContext> Signer<Context> {
             s: Cow::from(eth_signature.s().to_big_endian().to_vec()),
             data: Cow::from(signed.payload()),
             pre_hash: Cow::from(pre_hash.to_vec()),
+            unsigned_encoded: Cow::from(unsigned_encoded),
             ..Proto::SigningOutput::default()
         })
     }
+
+    /// Returns a placeholder signature of the max possible size once encoded.
+    fn dummy_signature() -> SigningResult<secp256k1::Signature> {
+        let dummy_part = H256::from([DUMMY_SIGNATURE_BYTE; H256::LEN]);
+        secp256k1::Signature::try_from_parts(dummy_part, dummy_part, DUMMY_SIGNATURE_RECOVERY_ID)
+            .tw_err(SigningErrorType::Error_internal)
+            .context("Error creating a dummy signature")
+    }
 }
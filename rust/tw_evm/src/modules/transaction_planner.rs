@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::evm_context::EvmContext;
+use crate::modules::tx_builder::TxBuilder;
+use std::marker::PhantomData;
+use tw_coin_entry::coin_context::CoinContext;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_entry::modules::transaction_planner::TransactionPlanner;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+use tw_proto::TransactionPlanner::Proto as PlannerProto;
+use Proto::TransactionMode as TxMode;
+
+/// https://eips.ethereum.org/EIPS/eip-4844#parameters
+const GAS_PER_BLOB: u64 = 1 << 17;
+
+/// Plans EVM transactions: the fee is the max gas fee the sender can be charged.
+/// The max amount can't be calculated as the balance is unknown.
+pub struct EvmTransactionPlanner<Context: EvmContext> {
+    _phantom: PhantomData<Context>,
+}
+
+impl<Context: EvmContext> Default for EvmTransactionPlanner<Context> {
+    fn default() -> Self {
+        EvmTransactionPlanner {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Context: EvmContext> TransactionPlanner for EvmTransactionPlanner<Context> {
+    type SigningInput<'a> = Proto::SigningInput<'a>;
+
+    fn plan_transaction(
+        &self,
+        _coin: &dyn CoinContext,
+        input: &Self::SigningInput<'_>,
+    ) -> SigningResult<PlannerProto::TransactionPlan<'static>> {
+        Self::plan_impl(input)
+    }
+}
+
+impl<Context: EvmContext> EvmTransactionPlanner<Context> {
+    fn plan_impl(
+        input: &Proto::SigningInput<'_>,
+    ) -> SigningResult<PlannerProto::TransactionPlan<'static>> {
+        let fee_per_gas = match input.tx_mode {
+            TxMode::Legacy => &input.gas_price,
+            TxMode::Enveloped | TxMode::Blob | TxMode::SetCode => &input.max_fee_per_gas,
+            TxMode::UserOp | TxMode::NetworkDefault => {
+                return SigningError::err(SigningErrorType::Error_not_supported).context(
+                    "Only Legacy, Enveloped, Blob and SetCode transactions can be planned",
+                )
+            },
+        };
+
+        // Validate the whole transaction.
+        TxBuilder::<Context>::tx_from_proto(input)?;
+
+        let fee_per_gas = U256::from_big_endian_slice(fee_per_gas)
+            .into_tw()
+            .context("Invalid fee per gas")?;
+        let gas_limit = U256::from_big_endian_slice(&input.gas_limit)
+            .into_tw()
+            .context("Invalid gas limit")?;
+        let gas_units = u64::try_from(gas_limit)
+            .into_tw()
+            .context("Gas limit is too big")?;
+
+        let mut fee = gas_limit
+            .checked_mul(fee_per_gas)
+            .into_tw()
+            .context("Fee overflow")?;
+        if input.tx_mode == TxMode::Blob {
+            fee = Self::add_blob_fee(input, fee)?;
+        }
+
+        let amount = TxBuilder::<Context>::eth_amount_from_proto(input)?;
+
+        Ok(PlannerProto::TransactionPlan {
+            amount: amount.to_string().into(),
+            fee: fee.to_string().into(),
+            change: "0".into(),
+            fee_rate: Some(PlannerProto::FeeRate {
+                unit: PlannerProto::FeeUnit::PerGas,
+                units: gas_units,
+                amount_per_unit: fee_per_gas.to_string().into(),
+            }),
+            ..PlannerProto::TransactionPlan::default()
+        })
+    }
+
+    /// Blob gas is paid in addition to the execution gas.
+    fn add_blob_fee(input: &Proto::SigningInput<'_>, fee: U256) -> SigningResult<U256> {
+        let max_fee_per_blob_gas = U256::from_big_endian_slice(&input.max_fee_per_blob_gas)
+            .into_tw()
+            .context("Invalid max fee per blob gas")?;
+        let blob_gas = GAS_PER_BLOB * input.blob_versioned_hashes.len() as u64;
+
+        max_fee_per_blob_gas
+            .checked_mul(blob_gas)
+            .and_then(|blob_fee| fee.checked_add(blob_fee))
+            .into_tw()
+            .context("Fee overflow")
+    }
+}
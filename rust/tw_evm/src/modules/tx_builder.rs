@@ -7,7 +7,10 @@ use crate::abi::prebuild::biz::BizAccount;
 use crate::abi::prebuild::erc1155::Erc1155;
 use crate::abi::prebuild::erc20::Erc20;
 use crate::abi::prebuild::erc4337::Erc4337SimpleAccount;
+use crate::abi::prebuild::erc4626::Erc4626;
 use crate::abi::prebuild::erc721::Erc721;
+use crate::abi::prebuild::l1_standard_bridge::L1StandardBridge;
+use crate::abi::prebuild::staking::NativeStaking;
 use crate::abi::prebuild::ExecuteArgs;
 use crate::address::{Address, EvmAddress};
 use crate::evm_context::EvmContext;
@@ -17,6 +20,7 @@ use crate::transaction::authorization_list::{
     Authorization, AuthorizationList, SignedAuthorization,
 };
 use crate::transaction::transaction_eip1559::TransactionEip1559;
+use crate::transaction::transaction_eip4844::{BlobVersionedHashes, TransactionEip4844};
 use crate::transaction::transaction_eip7702::TransactionEip7702;
 use crate::transaction::transaction_non_typed::TransactionNonTyped;
 use crate::transaction::user_operation::UserOperation;
@@ -30,7 +34,7 @@ use tw_hash::H256;
 use tw_keypair::ecdsa::secp256k1;
 use tw_keypair::ecdsa::secp256k1::Signature;
 use tw_keypair::traits::SigningKeyTrait;
-use tw_keypair::KeyPairError;
+use tw_keypair::{KeyPairError, KeyPairErrorKind};
 use tw_memory::Data;
 use tw_number::U256;
 use tw_proto::Common::Proto::SigningError as CommonError;
@@ -38,6 +42,7 @@ use tw_proto::Ethereum::Proto;
 use Proto::mod_SigningInput::OneOfuser_operation_oneof as UserOp;
 use Proto::mod_Transaction::OneOftransaction_oneof as Tx;
 use Proto::SCWalletType;
+use Proto::StakingContract;
 use Proto::TransactionMode as TxMode;
 
 pub struct TransactionParts {
@@ -89,11 +94,29 @@ impl<Context: EvmContext> TxBuilder<Context> {
                 Self::transaction_eip1559_from_proto(input, eth_amount, data, to)?.into_boxed()
             },
             TxMode::UserOp => Self::user_operation_from_proto(input, data)?,
+            TxMode::Blob => {
+                Self::transaction_eip4844_from_proto(input, eth_amount, data, to)?.into_boxed()
+            },
             TxMode::SetCode => Self::transaction_eip7702_from_proto(input, eth_amount, data, to)?,
+            TxMode::NetworkDefault => {
+                return SigningError::err(CommonError::Error_invalid_params).context(
+                    "NetworkDefault transaction mode must be resolved by the network chain ID",
+                )
+            },
         };
         Ok(tx)
     }
 
+    /// Returns the amount of native coins sent by the transaction, excluding the fee.
+    pub fn eth_amount_from_proto(input: &Proto::SigningInput<'_>) -> SigningResult<U256> {
+        let Some(ref transaction) = input.transaction else {
+            return SigningError::err(CommonError::Error_invalid_params)
+                .context("No transaction specified");
+        };
+        Self::handle_transaction_type(input, &transaction.transaction_oneof)
+            .map(|parts| parts.eth_amount)
+    }
+
     fn handle_transaction_type(
         input: &Proto::SigningInput,
         transaction: &Tx,
@@ -254,6 +277,101 @@ impl<Context: EvmContext> TxBuilder<Context> {
                     to,
                 })
             },
+            Tx::native_stake(ref stake) => {
+                let eth_amount = U256::from_big_endian_slice(&stake.amount)
+                    .into_tw()
+                    .context("Invalid amount")?;
+
+                let data = match stake.contract {
+                    StakingContract::Lido => {
+                        let referral = Self::parse_address_optional(&stake.referral)
+                            .context("Invalid referral address")?
+                            .unwrap_or_default();
+                        NativeStaking::lido_submit(referral)
+                    },
+                    StakingContract::RocketPool => NativeStaking::rocket_pool_deposit(),
+                    StakingContract::BscStakeHub => {
+                        let validator = Self::parse_address(&stake.validator)
+                            .context("Invalid validator address")?;
+                        NativeStaking::bsc_delegate(validator, stake.delegate_vote_power)
+                    },
+                }
+                .map_err(abi_to_signing_error)?;
+
+                let contract_address = Self::parse_address(&input.to_address)
+                    .context("Invalid staking contract address")?;
+
+                Ok(TransactionParts {
+                    eth_amount,
+                    data,
+                    to: Some(contract_address),
+                })
+            },
+            Tx::erc4626_deposit(ref deposit) => {
+                let receiver =
+                    Self::parse_address(&deposit.receiver).context("Invalid receiver address")?;
+
+                let assets = U256::from_big_endian_slice(&deposit.amount)
+                    .into_tw()
+                    .context("Invalid amount")?;
+
+                let vault_address =
+                    Self::parse_address(&input.to_address).context("Invalid vault address")?;
+
+                let data = Erc4626::deposit(assets, receiver).map_err(abi_to_signing_error)?;
+
+                Ok(TransactionParts {
+                    eth_amount: U256::zero(),
+                    data,
+                    to: Some(vault_address),
+                })
+            },
+            Tx::l2_deposit(ref deposit) => {
+                let recipient =
+                    Self::parse_address(&deposit.recipient).context("Invalid recipient address")?;
+
+                let amount = U256::from_big_endian_slice(&deposit.amount)
+                    .into_tw()
+                    .context("Invalid amount")?;
+
+                let bridge_address =
+                    Self::parse_address(&input.to_address).context("Invalid bridge address")?;
+
+                let extra_data = deposit.extra_data.to_vec();
+                let l1_token = Self::parse_address_optional(&deposit.l1_token)
+                    .context("Invalid L1 token address")?;
+
+                // Native coins are passed as the transaction value.
+                let (eth_amount, data) = match l1_token {
+                    Some(l1_token) => {
+                        let l2_token = Self::parse_address(&deposit.l2_token)
+                            .context("Invalid L2 token address")?;
+                        let data = L1StandardBridge::deposit_erc20_to(
+                            l1_token,
+                            l2_token,
+                            recipient,
+                            amount,
+                            deposit.min_gas_limit,
+                            extra_data,
+                        );
+                        (U256::zero(), data)
+                    },
+                    None => {
+                        let data = L1StandardBridge::deposit_eth_to(
+                            recipient,
+                            deposit.min_gas_limit,
+                            extra_data,
+                        );
+                        (amount, data)
+                    },
+                };
+
+                Ok(TransactionParts {
+                    eth_amount,
+                    data: data.map_err(abi_to_signing_error)?,
+                    to: Some(bridge_address),
+                })
+            },
             Tx::None => SigningError::err(SigningErrorType::Error_invalid_params)
                 .context("No transaction specified"),
         }
@@ -362,6 +480,59 @@ impl<Context: EvmContext> TxBuilder<Context> {
         })
     }
 
+    #[inline]
+    fn transaction_eip4844_from_proto(
+        input: &Proto::SigningInput,
+        eth_amount: U256,
+        payload: Data,
+        to_address: Option<Address>,
+    ) -> SigningResult<TransactionEip4844> {
+        let nonce = U256::from_big_endian_slice(&input.nonce)
+            .into_tw()
+            .context("Invalid nonce")?;
+
+        let gas_limit = U256::from_big_endian_slice(&input.gas_limit)
+            .into_tw()
+            .context("Invalid gas limit")?;
+
+        // Blob transactions cannot be used to create a contract.
+        let to = to_address
+            .or_tw_err(SigningErrorType::Error_invalid_params)
+            .context("'to' must be provided for `Blob` transaction")?;
+
+        let max_inclusion_fee_per_gas =
+            U256::from_big_endian_slice(&input.max_inclusion_fee_per_gas)
+                .into_tw()
+                .context("Invalid max inclusion fee per gas")?;
+
+        let max_fee_per_gas = U256::from_big_endian_slice(&input.max_fee_per_gas)
+            .into_tw()
+            .context("Invalid max fee per gas")?;
+
+        let max_fee_per_blob_gas = U256::from_big_endian_slice(&input.max_fee_per_blob_gas)
+            .into_tw()
+            .context("Invalid max fee per blob gas")?;
+
+        let access_list =
+            Self::parse_access_list(&input.access_list).context("Invalid access list")?;
+
+        let blob_versioned_hashes = Self::parse_blob_versioned_hashes(&input.blob_versioned_hashes)
+            .context("Invalid blob versioned hashes")?;
+
+        Ok(TransactionEip4844 {
+            nonce,
+            max_inclusion_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit,
+            to,
+            amount: eth_amount,
+            payload,
+            access_list,
+            max_fee_per_blob_gas,
+            blob_versioned_hashes,
+        })
+    }
+
     #[inline]
     fn transaction_eip7702_from_proto(
         input: &Proto::SigningInput,
@@ -570,13 +741,15 @@ impl<Context: EvmContext> TxBuilder<Context> {
             (TxMode::UserOp, SCWalletType::SimpleAccount | SCWalletType::Biz4337) => Ok(None),
             (TxMode::UserOp, _) => SigningError::err(SigningErrorType::Error_invalid_params)
                 .context("Biz account cannot be used in UserOperation flow"),
-            (TxMode::Legacy | TxMode::Enveloped | TxMode::SetCode, SCWalletType::Biz) => {
+            (TxMode::Legacy | TxMode::Enveloped | TxMode::Blob | TxMode::SetCode, SCWalletType::Biz) => {
                 Self::signer_address(input).map(Some)
             },
-            (TxMode::Legacy | TxMode::Enveloped | TxMode::SetCode, _) => SigningError::err(
+            (TxMode::Legacy | TxMode::Enveloped | TxMode::Blob | TxMode::SetCode, _) => SigningError::err(
                 SigningErrorType::Error_invalid_params,
             )
-            .context("Biz account can only be used in Legacy/Enveloped/SetCode transactions flow"),
+            .context("Biz account can only be used in Legacy/Enveloped/Blob/SetCode transactions flow"),
+            (TxMode::NetworkDefault, _) => SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("NetworkDefault transaction mode must be resolved by the network chain ID"),
         }
     }
 
@@ -639,6 +812,24 @@ impl<Context: EvmContext> TxBuilder<Context> {
         Ok(access)
     }
 
+    fn parse_blob_versioned_hashes<T: AsRef<[u8]>>(
+        hashes_proto: &[T],
+    ) -> SigningResult<BlobVersionedHashes> {
+        let mut hashes = BlobVersionedHashes::default();
+        for hash_proto in hashes_proto.iter() {
+            let hash = H256::try_from(hash_proto.as_ref())
+                .tw_err(SigningErrorType::Error_invalid_params)
+                .context("Invalid blob versioned hash length")?;
+            hashes.add_hash(hash)?;
+        }
+
+        if hashes.is_empty() {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("`Blob` transaction must have at least one blob versioned hash");
+        }
+        Ok(hashes)
+    }
+
     fn signer_address(input: &Proto::SigningInput) -> SigningResult<Address> {
         let signer_key = secp256k1::PrivateKey::try_from(input.private_key.as_ref())
             .into_tw()
@@ -676,7 +867,7 @@ impl<Context: EvmContext> TxBuilder<Context> {
                     other_auth_fields
                         .signature
                         .decode_hex()
-                        .map_err(|_| KeyPairError::InvalidSignature)?
+                        .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?
                         .as_slice(),
                 )
                 .tw_err(SigningErrorType::Error_invalid_params)
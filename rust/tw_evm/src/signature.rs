@@ -42,6 +42,41 @@ pub fn eip155_replay_protection(chain_id: U256, v: u8) -> NumberResult<U256> {
         .checked_add(v)
 }
 
+/// Restores the recovery ID from the `v` value returned by an external signer, e.g. a hardware wallet.
+///
+/// Supports raw recovery IDs, legacy `27 + v` and EIP155 `35 + chain_id * 2 + v` values,
+/// including EIP155 values truncated to one byte as returned by hardware wallets for large chain IDs.
+/// Please note that `0`, `1`, `27` and `28` are never treated as truncated values.
+pub fn recovery_id_from_v(v: U256, chain_id: U256) -> NumberResult<u8> {
+    const MAX_RECOVERY_ID: u8 = 1;
+
+    if v <= U256::from(MAX_RECOVERY_ID) {
+        return Ok(v.low_u8());
+    }
+
+    let legacy = U256::from(ETHEREUM_SIGNATURE_V_OFFSET);
+    if v == legacy || v == legacy.checked_add(MAX_RECOVERY_ID)? {
+        return Ok((v - legacy).low_u8());
+    }
+
+    if !chain_id.is_zero() {
+        let eip155 = eip155_replay_protection(chain_id, 0)?;
+        if v == eip155 || v == eip155.checked_add(MAX_RECOVERY_ID)? {
+            return Ok((v - eip155).low_u8());
+        }
+
+        let max_byte = U256::from(u8::MAX);
+        if eip155 > max_byte && v <= max_byte {
+            let recovery_id = v.low_u8().wrapping_sub(eip155.low_u8());
+            if recovery_id <= MAX_RECOVERY_ID {
+                return Ok(recovery_id);
+            }
+        }
+    }
+
+    Err(NumberError::InvalidBinaryRepresentation)
+}
+
 /// Removes EIP155 or legacy replay protection.
 #[inline]
 pub fn remove_replay_protection(v: u8) -> u8 {
@@ -51,3 +86,40 @@ pub fn remove_replay_protection(v: u8) -> u8 {
     }
     v
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recovery_id(v: u64, chain_id: u64) -> NumberResult<u8> {
+        recovery_id_from_v(U256::from(v), U256::from(chain_id))
+    }
+
+    #[test]
+    fn test_recovery_id_from_v() {
+        assert_eq!(recovery_id(0, 1), Ok(0));
+        assert_eq!(recovery_id(1, 1), Ok(1));
+        assert_eq!(recovery_id(27, 1), Ok(0));
+        assert_eq!(recovery_id(28, 0), Ok(1));
+        assert_eq!(recovery_id(37, 1), Ok(0));
+        assert_eq!(recovery_id(38, 1), Ok(1));
+        assert_eq!(recovery_id(148, 56), Ok(1));
+        // Polygon: 137 * 2 + 35 = 309.
+        assert_eq!(recovery_id(309, 137), Ok(0));
+        assert_eq!(recovery_id(310, 137), Ok(1));
+        // 309 truncated to one byte is 53.
+        assert_eq!(recovery_id(53, 137), Ok(0));
+        assert_eq!(recovery_id(54, 137), Ok(1));
+    }
+
+    #[test]
+    fn test_recovery_id_from_v_invalid() {
+        assert!(recovery_id(2, 1).is_err());
+        assert!(recovery_id(29, 1).is_err());
+        assert!(recovery_id(39, 1).is_err());
+        assert!(recovery_id(37, 0).is_err());
+        // Not truncated since the EIP155 value fits one byte.
+        assert!(recovery_id(53, 5).is_err());
+        assert!(recovery_id(55, 137).is_err());
+    }
+}
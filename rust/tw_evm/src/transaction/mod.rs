@@ -7,6 +7,9 @@
 //!   - simple ETH transfer
 //!   - others with payload, function call, e.g. ERC20 transfer
 //! - Typed transactions (enveloped, EIP2718), with specific type and transaction payload
+//!   - EIP1559 fee market transactions
+//!   - EIP4844 blob transactions
+//!   - EIP7702 set code transactions
 //! - User operations (EIP4337)
 
 use crate::transaction::signature::EthSignature;
@@ -20,6 +23,7 @@ pub mod access_list;
 pub mod authorization_list;
 pub mod signature;
 pub mod transaction_eip1559;
+pub mod transaction_eip4844;
 pub mod transaction_eip7702;
 pub mod transaction_non_typed;
 pub mod user_operation;
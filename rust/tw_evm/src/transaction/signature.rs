@@ -5,7 +5,7 @@
 use crate::signature::replay_protection;
 use tw_hash::H520;
 use tw_keypair::ecdsa::secp256k1;
-use tw_keypair::{KeyPairError, KeyPairResult};
+use tw_keypair::{KeyPairError, KeyPairErrorKind, KeyPairResult};
 use tw_number::U256;
 
 pub trait EthSignature {
@@ -68,8 +68,8 @@ pub struct SignatureEip155 {
 impl SignatureEip155 {
     #[inline]
     pub fn new(sign: secp256k1::Signature, chain_id: U256) -> KeyPairResult<Self> {
-        let v =
-            replay_protection(chain_id, sign.v()).map_err(|_| KeyPairError::InvalidSignature)?;
+        let v = replay_protection(chain_id, sign.v())
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
         Ok(SignatureEip155 {
             v,
             r: U256::from_big_endian(sign.r()),
@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::address::Address;
+use crate::rlp::buffer::RlpBuffer;
+use crate::rlp::list::RlpList;
+use crate::rlp::RlpEncode;
+use crate::transaction::access_list::AccessList;
+use crate::transaction::signature::{EthSignature, Signature};
+use crate::transaction::{SignedTransaction, TransactionCommon, UnsignedTransaction};
+use tw_coin_entry::error::prelude::*;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_memory::Data;
+use tw_number::U256;
+
+const EIP4844_TX_TYPE: u8 = 0x03;
+/// The version byte of a versioned hash of a KZG commitment.
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+/// A list of versioned hashes of the blobs carried by the transaction.
+#[derive(Default)]
+pub struct BlobVersionedHashes(pub(crate) Vec<H256>);
+
+impl BlobVersionedHashes {
+    /// Adds a versioned hash. The hash must start with the [`VERSIONED_HASH_VERSION_KZG`] byte.
+    pub fn add_hash(&mut self, hash: H256) -> SigningResult<&mut Self> {
+        if hash[0] != VERSIONED_HASH_VERSION_KZG {
+            return SigningError::err(SigningErrorType::Error_invalid_params)
+                .context("Unsupported blob versioned hash version");
+        }
+        self.0.push(hash);
+        Ok(self)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl RlpEncode for BlobVersionedHashes {
+    fn rlp_append(&self, buf: &mut RlpBuffer) {
+        buf.begin_list();
+
+        for hash in self.0.iter() {
+            hash.rlp_append(buf);
+        }
+
+        buf.finalize_list();
+    }
+}
+
+/// EIP4844 blob transaction.
+/// Please note that the blob sidecar (blobs, commitments and proofs) is not a part of the signed transaction
+/// and should be attached by the caller when broadcasting the transaction.
+pub struct TransactionEip4844 {
+    pub nonce: U256,
+    pub max_inclusion_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub gas_limit: U256,
+    pub to: Address,
+    pub amount: U256,
+    pub payload: Data,
+    pub access_list: AccessList,
+    pub max_fee_per_blob_gas: U256,
+    pub blob_versioned_hashes: BlobVersionedHashes,
+}
+
+impl TransactionCommon for TransactionEip4844 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.payload.clone()
+    }
+}
+
+impl UnsignedTransaction for TransactionEip4844 {
+    type SignedTransaction = SignedTransactionEip4844;
+
+    #[inline]
+    fn encode(&self, chain_id: U256) -> Data {
+        encode_transaction(self, chain_id, None)
+    }
+
+    #[inline]
+    fn try_into_signed(
+        self,
+        signature: secp256k1::Signature,
+        chain_id: U256,
+    ) -> SigningResult<Self::SignedTransaction> {
+        Ok(SignedTransactionEip4844 {
+            unsigned: self,
+            signature: Signature::new(signature),
+            chain_id,
+        })
+    }
+}
+
+pub struct SignedTransactionEip4844 {
+    unsigned: TransactionEip4844,
+    signature: Signature,
+    chain_id: U256,
+}
+
+impl TransactionCommon for SignedTransactionEip4844 {
+    #[inline]
+    fn payload(&self) -> Data {
+        self.unsigned.payload.clone()
+    }
+}
+
+impl SignedTransaction for SignedTransactionEip4844 {
+    type Signature = Signature;
+
+    #[inline]
+    fn encode(&self) -> Data {
+        encode_transaction(&self.unsigned, self.chain_id, Some(&self.signature))
+    }
+
+    #[inline]
+    fn signature(&self) -> &Self::Signature {
+        &self.signature
+    }
+}
+
+fn encode_transaction(
+    tx: &TransactionEip4844,
+    chain_id: U256,
+    signature: Option<&Signature>,
+) -> Data {
+    let mut list = RlpList::new();
+    list.append(&chain_id)
+        .append(&tx.nonce)
+        .append(&tx.max_inclusion_fee_per_gas)
+        .append(&tx.max_fee_per_gas)
+        .append(&tx.gas_limit)
+        .append(&tx.to)
+        .append(&tx.amount)
+        .append(tx.payload.as_slice())
+        .append(&tx.access_list)
+        .append(&tx.max_fee_per_blob_gas)
+        .append(&tx.blob_versioned_hashes);
+
+    if let Some(signature) = signature {
+        list.append(&signature.v());
+        list.append(&signature.r());
+        list.append(&signature.s());
+    }
+
+    let tx_encoded = list.finish();
+
+    let mut envelope = Vec::with_capacity(tx_encoded.len() + 1);
+    envelope.push(EIP4844_TX_TYPE);
+    envelope.extend_from_slice(tx_encoded.as_slice());
+    envelope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use tw_encoding::hex::{DecodeHex, ToHex};
+
+    #[test]
+    fn test_encode_transaction_eip4844() {
+        let mut blob_versioned_hashes = BlobVersionedHashes::default();
+        blob_versioned_hashes
+            .add_hash(H256::from(
+                "01b0a4cdd5f55589f5c5b4d46c76704bb6ce95c0a8c09f77f197a57808dded28",
+            ))
+            .unwrap();
+
+        let tx = TransactionEip4844 {
+            nonce: U256::from(1_u32),
+            max_inclusion_fee_per_gas: U256::from(2_u32),
+            max_fee_per_gas: U256::from(3_u32),
+            gas_limit: U256::from(4_u32),
+            to: Address::from_str("0x0101010101010101010101010101010101010101").unwrap(),
+            amount: U256::from(5_u32),
+            payload: "0x1234".decode_hex().unwrap(),
+            access_list: AccessList::default(),
+            max_fee_per_blob_gas: U256::from(6_u32),
+            blob_versioned_hashes,
+        };
+
+        let r = H256::from("d93fc9ae934d4f72db91cb149e7e84b50ca83b5a8a7b873b0fdb009546e3af47");
+        let s = H256::from("786bfaf31af61eea6471dbb1bec7d94f73fb90887e4f04d0e9b85676c47ab02a");
+        let v = 0x01;
+        let signature = secp256k1::Signature::try_from_parts(r, s, v).unwrap();
+        let chain_id = U256::from(1_u32);
+
+        assert_eq!(
+            tx.encode(chain_id).to_hex(),
+            "03f842010102030494010101010101010101010101010101010101010105821234c006e1a001b0a4cdd5f55589f5c5b4d46c76704bb6ce95c0a8c09f77f197a57808dded28"
+        );
+
+        let signed_tx = tx.try_into_signed(signature, chain_id).unwrap();
+        assert_eq!(signed_tx.encode().to_hex(), "03f885010102030494010101010101010101010101010101010101010105821234c006e1a001b0a4cdd5f55589f5c5b4d46c76704bb6ce95c0a8c09f77f197a57808dded2801a0d93fc9ae934d4f72db91cb149e7e84b50ca83b5a8a7b873b0fdb009546e3af47a0786bfaf31af61eea6471dbb1bec7d94f73fb90887e4f04d0e9b85676c47ab02a");
+    }
+
+    #[test]
+    fn test_blob_versioned_hash_invalid_version() {
+        let mut hashes = BlobVersionedHashes::default();
+        let hash = H256::from("02b0a4cdd5f55589f5c5b4d46c76704bb6ce95c0a8c09f77f197a57808dded28");
+        assert!(hashes.add_hash(hash).is_err());
+        assert!(hashes.is_empty());
+    }
+}
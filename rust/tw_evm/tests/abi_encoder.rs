@@ -685,3 +685,138 @@ fn test_decode_contract_call_error() {
     assert_eq!(output.error, AbiErrorKind::Error_abi_mismatch);
     assert!(!output.error_message.is_empty());
 }
+
+fn decode_calldata(calldata: &str, abi_json: &str) -> Proto::CalldataDecodingOutput<'static> {
+    let input = Proto::CalldataDecodingInput {
+        calldata: calldata.decode_hex().unwrap().into(),
+        abi_json: Cow::Owned(abi_json.to_string()),
+    };
+    AbiEncoder::<StandardEvmContext>::decode_calldata(input)
+}
+
+#[test]
+fn test_decode_calldata_erc20_transfer() {
+    let calldata = "a9059cbb\
+        0000000000000000000000003535353535353535353535353535353535353535\
+        0000000000000000000000000000000000000000000000001bc16d674ec80000";
+
+    let output = decode_calldata(calldata, "");
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.selector, "0xa9059cbb");
+    assert_eq!(output.function_name, "transfer");
+    assert_eq!(output.function_signature, "transfer(address,uint256)");
+    assert_eq!(output.standards, vec![Proto::AbiStandard::ERC20]);
+    assert_eq!(
+        output.params,
+        vec![
+            named_token(
+                "to",
+                TokenEnum::address("0x3535353535353535353535353535353535353535".into())
+            ),
+            named_token("amount", u_number_n::<256>(2_000_000_000_000_000_000)),
+        ]
+    );
+}
+
+#[test]
+fn test_decode_calldata_shared_selectors() {
+    // `approve(address,uint256)` is defined by both ERC-20 and ERC-721.
+    let approve = "095ea7b3\
+        0000000000000000000000003535353535353535353535353535353535353535\
+        ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+    let output = decode_calldata(approve, "");
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert_eq!(output.function_signature, "approve(address,uint256)");
+    assert_eq!(
+        output.standards,
+        vec![Proto::AbiStandard::ERC20, Proto::AbiStandard::ERC721]
+    );
+    assert_eq!(output.params[0].name, "spender");
+    assert_eq!(output.params[1].name, "amount");
+
+    // `setApprovalForAll(address,bool)` is defined by both ERC-721 and ERC-1155.
+    let set_approval_for_all = "a22cb465\
+        0000000000000000000000003535353535353535353535353535353535353535\
+        0000000000000000000000000000000000000000000000000000000000000001";
+    let output = decode_calldata(set_approval_for_all, "");
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert_eq!(output.selector, "0xa22cb465");
+    assert_eq!(
+        output.standards,
+        vec![Proto::AbiStandard::ERC721, Proto::AbiStandard::ERC1155]
+    );
+    assert_eq!(
+        output.params,
+        vec![
+            named_token(
+                "operator",
+                TokenEnum::address("0x3535353535353535353535353535353535353535".into())
+            ),
+            named_token("approved", TokenEnum::boolean(true)),
+        ]
+    );
+}
+
+#[test]
+fn test_decode_calldata_user_abi() {
+    // WETH9 names the `approve` parameters differently.
+    let abi_json = json!([
+        {
+            "type": "function",
+            "name": "approve",
+            "inputs": [
+                { "name": "guy", "type": "address" },
+                { "name": "wad", "type": "uint256" }
+            ],
+            "outputs": [{ "name": "", "type": "bool" }]
+        }
+    ])
+    .to_string();
+    let calldata = "095ea7b3\
+        0000000000000000000000003535353535353535353535353535353535353535\
+        00000000000000000000000000000000000000000000000000000000000003e8";
+
+    let output = decode_calldata(calldata, &abi_json);
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert_eq!(output.function_signature, "approve(address,uint256)");
+    assert!(output.standards.is_empty());
+    assert_eq!(
+        output.params,
+        vec![
+            named_token(
+                "guy",
+                TokenEnum::address("0x3535353535353535353535353535353535353535".into())
+            ),
+            named_token("wad", u_number_n::<256>(1000)),
+        ]
+    );
+
+    // Falls back to the built-in ABIs if the user-supplied ABI doesn't have the function.
+    let transfer = "a9059cbb\
+        0000000000000000000000003535353535353535353535353535353535353535\
+        00000000000000000000000000000000000000000000000000000000000003e8";
+    let output = decode_calldata(transfer, &abi_json);
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert_eq!(output.function_name, "transfer");
+    assert_eq!(output.standards, vec![Proto::AbiStandard::ERC20]);
+}
+
+#[test]
+fn test_decode_calldata_error() {
+    let unknown = "11223344\
+        0000000000000000000000003535353535353535353535353535353535353535";
+    let output = decode_calldata(unknown, "");
+    assert_eq!(output.error, AbiErrorKind::Error_abi_mismatch);
+    assert!(!output.error_message.is_empty());
+
+    let output = decode_calldata("a9059c", "");
+    assert_eq!(output.error, AbiErrorKind::Error_decoding_data);
+
+    let output = decode_calldata(unknown, "{ invalid json");
+    assert_eq!(output.error, AbiErrorKind::Error_invalid_abi);
+
+    // Truncated parameters.
+    let output = decode_calldata("a9059cbb0000", "");
+    assert_eq!(output.error, AbiErrorKind::Error_decoding_data);
+}
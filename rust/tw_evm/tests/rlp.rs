@@ -7,6 +7,7 @@ use std::str::FromStr;
 use tw_coin_entry::error::prelude::*;
 use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_evm::evm_context::StandardEvmContext;
+use tw_evm::modules::rlp_decoder::RlpDecoder;
 use tw_evm::modules::rlp_encoder::{RlpEncoder, RECURSION_LIMIT};
 use tw_number::U256;
 use tw_proto::EthereumRlp::Proto as RlpProto;
@@ -243,3 +244,55 @@ fn test_ethereum_rlp_list_eip1559() {
         "f86c0a06847735940084b2d05e0082526c946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000001ee0c29f50cb1c0"
     );
 }
+
+#[track_caller]
+fn test_decode_error(encoded: &str) {
+    let err = RlpDecoder::decode(&encoded.decode_hex().unwrap()).unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_input_parse);
+}
+
+#[test]
+fn test_ethereum_rlp_decode_list() {
+    let decoded = RlpDecoder::decode(&"c88363617483646f67".decode_hex().unwrap()).unwrap();
+    let expected = RlpProto::RlpList {
+        items: vec![
+            make_item(Item::data(Cow::from(b"cat".to_vec()))),
+            make_item(Item::data(Cow::from(b"dog".to_vec()))),
+        ],
+    };
+    assert_eq!(decoded, make_item(Item::list(expected)));
+}
+
+#[test]
+fn test_ethereum_rlp_decode_encode_eip1559() {
+    let encoded = "f86c0a06847735940084b2d05e0082526c946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000001ee0c29f50cb1c0";
+    let decoded = RlpDecoder::decode(&encoded.decode_hex().unwrap()).unwrap();
+    test_encode(decoded.item, encoded);
+}
+
+#[test]
+fn test_ethereum_rlp_decode_invalid() {
+    // Trailing bytes.
+    test_decode_error("8363617400");
+    // A single byte below 0x80 must be encoded as itself.
+    test_decode_error("8100");
+    // The string is shorter than its header says.
+    test_decode_error("836361");
+    // The list item is shorter than its header says.
+    test_decode_error("c283636174");
+}
+
+#[test]
+fn test_ethereum_rlp_decode_recursion_limit() {
+    let nested_list = |depth: usize| {
+        let mut encoded = vec![0xc0];
+        for _ in 0..depth {
+            encoded.insert(0, 0xc0 + encoded.len() as u8);
+        }
+        encoded
+    };
+
+    RlpDecoder::decode(&nested_list(RECURSION_LIMIT - 1)).unwrap();
+    let err = RlpDecoder::decode(&nested_list(RECURSION_LIMIT)).unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_input_parse);
+}
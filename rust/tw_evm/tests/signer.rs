@@ -54,6 +54,96 @@ fn test_sign_transaction_non_typed_erc20_transfer() {
         hex::encode(output.pre_hash, false),
         "b3525019dc367d3ecac48905f9a95ff3550c25a24823db765f92cae2dec7ebfd"
     );
+    assert_eq!(
+        hex::encode(output.unsigned_encoded, false),
+        "f86a808509c7652400830130b9946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec80000348080"
+    );
+}
+
+#[test]
+fn test_sign_transaction_skip_signing() {
+    let erc20_transfer = Proto::mod_Transaction::ERC20Transfer {
+        to: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        amount: U256::encode_be_compact(2_000_000_000_000_000_000),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(0x34),
+        tx_mode: TransactionMode::Legacy,
+        // 42000000000
+        gas_price: U256::encode_be_compact(0x09_c765_2400),
+        // 78009
+        gas_limit: U256::encode_be_compact(0x01_30B9),
+        // DAI
+        to_address: "0x6b175474e89094c44da98b954eedeac495271d0f".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::erc20_transfer(
+                erc20_transfer,
+            ),
+        }),
+        // No private key is required.
+        skip_signing: true,
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    // The transaction is not signed.
+    assert!(output.encoded.is_empty());
+    assert!(output.v.is_empty());
+    assert!(output.r.is_empty());
+    assert!(output.s.is_empty());
+
+    assert_eq!(
+        hex::encode(output.unsigned_encoded, false),
+        "f86a808509c7652400830130b9946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec80000348080"
+    );
+    assert_eq!(
+        hex::encode(output.pre_hash, false),
+        "b3525019dc367d3ecac48905f9a95ff3550c25a24823db765f92cae2dec7ebfd"
+    );
+    assert_eq!(
+        hex::encode(output.data, false),
+        "a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec80000"
+    );
+}
+
+#[test]
+fn test_sign_transaction_dummy_signature() {
+    let erc20_transfer = Proto::mod_Transaction::ERC20Transfer {
+        to: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        amount: U256::encode_be_compact(2_000_000_000_000_000_000),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(0x34),
+        tx_mode: TransactionMode::Legacy,
+        // 42000000000
+        gas_price: U256::encode_be_compact(0x09_c765_2400),
+        // 78009
+        gas_limit: U256::encode_be_compact(0x01_30B9),
+        // DAI
+        to_address: "0x6b175474e89094c44da98b954eedeac495271d0f".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::erc20_transfer(
+                erc20_transfer,
+            ),
+        }),
+        // No private key is required.
+        dummy_signature: true,
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "f8ab808509c7652400830130b9946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec80000818ca07f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7fa07f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f";
+    assert_eq!(hex::encode(&output.encoded, false), expected);
+    // The same length as of the transaction signed in `test_sign_transaction_non_typed_erc20_transfer`.
+    assert_eq!(output.encoded.len(), 173);
 }
 
 #[test]
@@ -643,3 +733,228 @@ fn test_sign_transaction_non_typed_erc1155_transfer() {
     let expected_data = "f242432a000000000000000000000000718046867b5b1782379a14ea4fc0c9b724da94fc0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000023c47ee50000000000000000000000000000000000000000000000001bc16d674ec8000000000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000040102030400000000000000000000000000000000000000000000000000000000";
     assert_eq!(hex::encode(output.data, false), expected_data);
 }
+
+fn staking_input(
+    to_address: &str,
+    transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof<'static>,
+) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Enveloped,
+        gas_limit: U256::encode_be_compact(200_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(3_000_000_000),
+        to_address: to_address.to_string().into(),
+        transaction: Some(Proto::Transaction { transaction_oneof }),
+        private_key: parse_hex(
+            "0x4646464646464646464646464646464646464646464646464646464646464646",
+        ),
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_sign_transaction_native_stake() {
+    use Proto::mod_Transaction::OneOftransaction_oneof as Tx;
+
+    let lido = Proto::mod_Transaction::NativeStake {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        contract: Proto::StakingContract::Lido,
+        ..Proto::mod_Transaction::NativeStake::default()
+    };
+    // stETH
+    let input = staking_input(
+        "0xae7ab96520DE3A18E5e111B5EaAb095312D7fE84",
+        Tx::native_stake(lido),
+    );
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.data.to_hex(),
+        "a1903eab0000000000000000000000000000000000000000000000000000000000000000"
+    );
+
+    let rocket_pool = Proto::mod_Transaction::NativeStake {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        contract: Proto::StakingContract::RocketPool,
+        ..Proto::mod_Transaction::NativeStake::default()
+    };
+    let input = staking_input(
+        "0xDD3f50F8A6CafbE9b31a427582963f465E745AF8",
+        Tx::native_stake(rocket_pool),
+    );
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.data.to_hex(), "d0e30db0");
+
+    let bsc = Proto::mod_Transaction::NativeStake {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        contract: Proto::StakingContract::BscStakeHub,
+        validator: "0x343dA7Ff0446247ca47AA41e2A25c5Bbb230ED0A".into(),
+        delegate_vote_power: true,
+        ..Proto::mod_Transaction::NativeStake::default()
+    };
+    let input = staking_input(
+        "0x0000000000000000000000000000000000002002",
+        Tx::native_stake(bsc),
+    );
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.data.to_hex(),
+        "982ef0a7000000000000000000000000343da7ff0446247ca47aa41e2a25c5bbb230ed0a0000000000000000000000000000000000000000000000000000000000000001"
+    );
+}
+
+#[test]
+fn test_sign_transaction_native_stake_missing_validator() {
+    let bsc = Proto::mod_Transaction::NativeStake {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        contract: Proto::StakingContract::BscStakeHub,
+        ..Proto::mod_Transaction::NativeStake::default()
+    };
+    let input = staking_input(
+        "0x0000000000000000000000000000000000002002",
+        Proto::mod_Transaction::OneOftransaction_oneof::native_stake(bsc),
+    );
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+}
+
+#[test]
+fn test_sign_transaction_erc4626_deposit() {
+    let deposit = Proto::mod_Transaction::ERC4626Deposit {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        receiver: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+    };
+    // sDAI
+    let input = staking_input(
+        "0x83F20F44975D03b1b09e64809B757c47f942BEeA",
+        Proto::mod_Transaction::OneOftransaction_oneof::erc4626_deposit(deposit),
+    );
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.data.to_hex(),
+        "6e553f650000000000000000000000000000000000000000000000000de0b6b3a76400000000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc84"
+    );
+}
+
+#[test]
+fn test_sign_transaction_l2_deposit() {
+    use Proto::mod_Transaction::OneOftransaction_oneof as Tx;
+
+    // Optimism L1StandardBridge
+    let bridge = "0x99C9fc46f92E8a1c0deC1b1747d010903E884bE1";
+
+    let eth_deposit = Proto::mod_Transaction::L2Deposit {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        recipient: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        min_gas_limit: 200_000,
+        ..Proto::mod_Transaction::L2Deposit::default()
+    };
+    let output = Signer::<StandardEvmContext>::sign_proto(staking_input(
+        bridge,
+        Tx::l2_deposit(eth_deposit),
+    ));
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.data.to_hex(),
+        "9a2ac6d50000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000000000000000030d4000000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000000"
+    );
+
+    let erc20_deposit = Proto::mod_Transaction::L2Deposit {
+        amount: U256::encode_be_compact(1_000_000),
+        recipient: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        // USDC on Ethereum and Optimism
+        l1_token: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".into(),
+        l2_token: "0x7F5c764cBc14f9669B88837ca1490cCa17c31607".into(),
+        min_gas_limit: 200_000,
+        extra_data: parse_hex("abcd"),
+    };
+    let output = Signer::<StandardEvmContext>::sign_proto(staking_input(
+        bridge,
+        Tx::l2_deposit(erc20_deposit),
+    ));
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.data.to_hex(),
+        "838b2520000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb480000000000000000000000007f5c764cbc14f9669b88837ca1490cca17c316070000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc8400000000000000000000000000000000000000000000000000000000000f42400000000000000000000000000000000000000000000000000000000000030d4000000000000000000000000000000000000000000000000000000000000000c00000000000000000000000000000000000000000000000000000000000000002abcd000000000000000000000000000000000000000000000000000000000000"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip4844_blob() {
+    let private_key =
+        parse_hex("0x4646464646464646464646464646464646464646464646464646464646464646");
+
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(0),
+        data: Cow::default(),
+    };
+
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(0),
+        tx_mode: TransactionMode::Blob,
+        gas_limit: U256::encode_be_compact(21_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(3_000_000_000),
+        max_fee_per_blob_gas: U256::encode_be_compact(1_000_000_000),
+        blob_versioned_hashes: vec![parse_hex(
+            "01b0a4cdd5f55589f5c5b4d46c76704bb6ce95c0a8c09f77f197a57808dded28",
+        )],
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key,
+        ..Proto::SigningInput::default()
+    };
+
+    let output = Signer::<StandardEvmContext>::sign_proto(input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected = "03f8910180847735940084b2d05e008252089435353535353535353535353535353535353535358080c0843b9aca00e1a001b0a4cdd5f55589f5c5b4d46c76704bb6ce95c0a8c09f77f197a57808dded2880a02e17735d9a6dc1890f2207e393d6e5e18cd93c07cc5855e2c315e9840e6793d2a056831bd3d4ba9f54565b86b149966678b388bb63737a74a706549a4329b1dab4";
+    assert_eq!(hex::encode(output.encoded, false), expected);
+
+    assert_eq!(
+        hex::encode(output.pre_hash, false),
+        "218412c9436ec81c3796136538f6fe2c55a6da17462e6913bb72e4dc38353aa6"
+    );
+}
+
+#[test]
+fn test_sign_transaction_eip4844_invalid_blob_hashes() {
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(0),
+        data: Cow::default(),
+    };
+    let input = Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        tx_mode: TransactionMode::Blob,
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key: parse_hex(
+            "0x4646464646464646464646464646464646464646464646464646464646464646",
+        ),
+        ..Proto::SigningInput::default()
+    };
+
+    // No blob versioned hashes.
+    let output = Signer::<StandardEvmContext>::sign_proto(input.clone());
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    // Unsupported version.
+    let output = Signer::<StandardEvmContext>::sign_proto(Proto::SigningInput {
+        blob_versioned_hashes: vec![parse_hex(
+            "02b0a4cdd5f55589f5c5b4d46c76704bb6ce95c0a8c09f77f197a57808dded28",
+        )],
+        ..input
+    });
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
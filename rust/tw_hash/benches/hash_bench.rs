@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use tw_hash::{blake, blake2, hmac, ripemd, sha2, sha3};
+
+/// Payload sizes: a digest, a typical transaction and a large contract call or message.
+const PAYLOAD_SIZES: [usize; 3] = [32, 1024, 64 * 1024];
+
+fn payload(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+fn bench_hash(c: &mut Criterion, name: &str, hash: fn(&[u8]) -> Vec<u8>) {
+    let mut group = c.benchmark_group(name);
+    for size in PAYLOAD_SIZES {
+        let input = payload(size);
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &input, |b, input| {
+            b.iter(|| hash(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_hashes(c: &mut Criterion) {
+    bench_hash(c, "sha256", sha2::sha256);
+    bench_hash(c, "sha256_d", sha2::sha256_d);
+    bench_hash(c, "sha512", sha2::sha512);
+    bench_hash(c, "keccak256", sha3::keccak256);
+    bench_hash(c, "sha3_256", sha3::sha3_256);
+    bench_hash(c, "blake2b_256", |input| {
+        blake2::blake2_b(input, 32).unwrap()
+    });
+    bench_hash(c, "blake256", blake::blake_256);
+    bench_hash(c, "ripemd160", ripemd::ripemd_160);
+    bench_hash(c, "sha256_ripemd", ripemd::sha256_ripemd);
+    bench_hash(c, "hmac_sha256", |input| {
+        hmac::hmac_sha256(&[0x42; 32], input)
+    });
+    bench_hash(c, "hmac_sha512", |input| {
+        hmac::hmac_sha512(&[0x42; 32], input)
+    });
+}
+
+criterion_group!(benches, bench_hashes);
+criterion_main!(benches);
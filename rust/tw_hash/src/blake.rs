@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::impl_static_hasher;
+use alloc::vec::Vec;
 use blake_hash::Digest;
 
 pub fn blake_256(input: &[u8]) -> Vec<u8> {
@@ -4,8 +4,10 @@
 
 use crate::hasher::StatefulHasher;
 use crate::Error;
+use alloc::vec;
+use alloc::vec::Vec;
 use blake2b_ref::Blake2bBuilder;
-use std::ops::RangeInclusive;
+use core::ops::RangeInclusive;
 use tw_memory::Data;
 
 pub const OUTPUT_HASH_LEN_RANGE: RangeInclusive<usize> = 1..=64;
@@ -4,7 +4,9 @@
 
 #![allow(clippy::missing_safety_doc)]
 
-use crate::{blake, blake2, groestl, hmac, ripemd, sha1, sha2, sha3, Error};
+use crate::poseidon::poseidon_bn254;
+use crate::{blake, blake2, groestl, hmac, ripemd, sha1, sha2, sha3, Error, H256};
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::c_byte_array::{CByteArray, CByteArrayResult};
 use tw_memory::ffi::c_result::ErrorCode;
 
@@ -36,6 +38,7 @@ impl From<CHashingCode> for ErrorCode {
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn blake_256(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     blake::blake_256(input).into()
@@ -47,6 +50,7 @@ pub unsafe extern "C" fn blake_256(input: *const u8, input_len: usize) -> CByteA
 /// \param hash_size the size of the output hash.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn blake2_b(
     input: *const u8,
     input_len: usize,
@@ -67,6 +71,7 @@ pub unsafe extern "C" fn blake2_b(
 /// \param personal_len the length of the `personal_input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn blake2_b_personal(
     input: *const u8,
     input_len: usize,
@@ -87,6 +92,7 @@ pub unsafe extern "C" fn blake2_b_personal(
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn groestl_512(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     groestl::groestl_512(input).into()
@@ -99,6 +105,7 @@ pub unsafe extern "C" fn groestl_512(input: *const u8, input_len: usize) -> CByt
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn hmac__sha256(
     key: *const u8,
     key_len: usize,
@@ -110,11 +117,36 @@ pub unsafe extern "C" fn hmac__sha256(
     hmac::hmac_sha256(key, input).into()
 }
 
+/// Checks if the `mac` is a valid HMAC-SHA256 code of the `input`, comparing it in constant time.
+/// \param key *non-null* byte array.
+/// \param key_len the length of the `key` array.
+/// \param input *non-null* byte array.
+/// \param input_len the length of the `input` array.
+/// \param mac *non-null* byte array.
+/// \param mac_len the length of the `mac` array.
+/// \return whether the `mac` is valid.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn hmac__sha256_verify(
+    key: *const u8,
+    key_len: usize,
+    input: *const u8,
+    input_len: usize,
+    mac: *const u8,
+    mac_len: usize,
+) -> bool {
+    let key = std::slice::from_raw_parts(key, key_len);
+    let input = std::slice::from_raw_parts(input, input_len);
+    let mac = std::slice::from_raw_parts(mac, mac_len);
+    hmac::hmac_sha256_verify(key, input, mac)
+}
+
 /// Computes the RIPEMD-160 hash of the `input` byte array.
 /// \param input *non-null* byte array.
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn ripemd_160(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     ripemd::ripemd_160(input).into()
@@ -125,6 +157,7 @@ pub unsafe extern "C" fn ripemd_160(input: *const u8, input_len: usize) -> CByte
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn sha1(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     sha1::sha1(input).into()
@@ -135,6 +168,7 @@ pub unsafe extern "C" fn sha1(input: *const u8, input_len: usize) -> CByteArray
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn sha256(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     sha2::sha256(input).into()
@@ -145,6 +179,7 @@ pub unsafe extern "C" fn sha256(input: *const u8, input_len: usize) -> CByteArra
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn sha512(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     sha2::sha512(input).into()
@@ -155,6 +190,7 @@ pub unsafe extern "C" fn sha512(input: *const u8, input_len: usize) -> CByteArra
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn sha512_256(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     sha2::sha512_256(input).into()
@@ -165,6 +201,7 @@ pub unsafe extern "C" fn sha512_256(input: *const u8, input_len: usize) -> CByte
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn keccak256(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     sha3::keccak256(input).into()
@@ -175,6 +212,7 @@ pub unsafe extern "C" fn keccak256(input: *const u8, input_len: usize) -> CByteA
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn keccak512(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     sha3::keccak512(input).into()
@@ -185,6 +223,7 @@ pub unsafe extern "C" fn keccak512(input: *const u8, input_len: usize) -> CByteA
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn sha3__256(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     sha3::sha3_256(input).into()
@@ -195,7 +234,29 @@ pub unsafe extern "C" fn sha3__256(input: *const u8, input_len: usize) -> CByteA
 /// \param input_len the length of the `input` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn sha3__512(input: *const u8, input_len: usize) -> CByteArray {
     let input = std::slice::from_raw_parts(input, input_len);
     sha3::sha3_512(input).into()
 }
+
+/// Computes the circomlib compatible Poseidon hash of the BN254 field elements.
+/// \param input *non-null* byte array of concatenated 32-byte big-endian field elements, from 1 to 16 elements.
+/// \param input_len the length of the `input` array.
+/// \return C-compatible byte array, an error if the `input` is not a sequence of valid field elements.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_hash_poseidon(input: *const u8, input_len: usize) -> CByteArrayResult {
+    let input = std::slice::from_raw_parts(input, input_len);
+    if input.len() % H256::LEN != 0 {
+        return Err::<CByteArray, _>(CHashingCode::InvalidArgument).into();
+    }
+    let elements: Vec<H256> = input
+        .chunks_exact(H256::LEN)
+        .map(|element| H256::try_from(element).expect("Expected exactly 32 bytes"))
+        .collect();
+    poseidon_bn254(&elements)
+        .map(|hash| CByteArray::from(hash.into_vec()))
+        .map_err(CHashingCode::from)
+        .into()
+}
@@ -3,11 +3,13 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::Error;
-use std::fmt;
-use std::ops::{Deref, DerefMut};
-use std::str::FromStr;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use core::str::FromStr;
 use tw_encoding::hex;
 use tw_encoding::hex::ToHex;
+use tw_memory::constant_time::{Choice, ConstantTimeEq};
 use zeroize::DefaultIsZeroes;
 
 pub type H32 = Hash<4>;
@@ -46,6 +48,13 @@ pub struct Hash<const N: usize>([u8; N]);
 
 impl<const N: usize> DefaultIsZeroes for Hash<N> {}
 
+/// Should be used instead of `==` if the hash is a secret, e.g. a shared secret or a MAC.
+impl<const N: usize> ConstantTimeEq for Hash<N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
 /// cbindgen:ignore
 impl<const N: usize> Hash<N> {
     pub const LEN: usize = N;
@@ -190,9 +199,33 @@ impl<const N: usize> fmt::Display for Hash<N> {
     }
 }
 
+/// Serializes the hash as a hex string by default.
+/// Consider using [`as_bytes`] or [`as_byte_sequence`] to get a binary representation.
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Hash<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        hex::as_hex::serialize(self, serializer)
+    }
+}
+
+/// Deserializes the hash from a hex string (`0x` prefixed or not).
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Hash<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        hex::as_hex::deserialize(deserializer)
+    }
+}
+
 #[cfg(feature = "serde")]
 pub mod as_bytes {
     use super::Hash;
+    use alloc::format;
     use serde::de::Error;
     use serde::{Deserialize, Deserializer, Serializer};
     use tw_memory::Data;
@@ -216,11 +249,11 @@ pub mod as_bytes {
 #[cfg(feature = "serde")]
 pub mod as_byte_sequence {
     use super::Hash;
+    use core::fmt;
+    use core::marker::PhantomData;
     use serde::de::{Error, SeqAccess, Visitor};
     use serde::ser::SerializeTuple;
     use serde::{Deserializer, Serializer};
-    use std::fmt;
-    use std::marker::PhantomData;
 
     struct ByteArrayVisitor<const N: usize> {
         _n: PhantomData<[u8; N]>,
@@ -282,6 +315,14 @@ mod tests {
         assert_eq!(actual.0[..], expected[..]);
     }
 
+    #[test]
+    fn test_hash_ct_eq() {
+        let hash = H256::from("afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5");
+        let other = H256::from("afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f6");
+        assert!(bool::from(hash.ct_eq(&hash)));
+        assert!(!bool::from(hash.ct_eq(&other)));
+    }
+
     #[test]
     fn test_hash_display() {
         let str = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
@@ -412,4 +453,18 @@ mod serde_tests {
         let actual = serde_json::to_value(&test).unwrap();
         assert_eq!(actual, json!({"data": HEX_32}));
     }
+
+    #[test]
+    fn test_hash_serde_default() {
+        let hash = H160::from("2db500ac919cdde351ac36e3711d832c6db97669");
+        let actual = serde_json::to_value(hash).unwrap();
+        assert_eq!(actual, json!("2db500ac919cdde351ac36e3711d832c6db97669"));
+
+        let decoded: H160 =
+            serde_json::from_value(json!("0x2db500ac919cdde351ac36e3711d832c6db97669")).unwrap();
+        assert_eq!(decoded, hash);
+
+        serde_json::from_value::<H256>(json!("2db500ac919cdde351ac36e3711d832c6db97669"))
+            .unwrap_err();
+    }
 }
@@ -2,6 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use alloc::vec::Vec;
 use digest::Digest;
 
 pub fn hasher<D: Digest>(input: &[u8]) -> Vec<u8> {
@@ -7,7 +7,8 @@ use crate::ripemd::{blake256_ripemd, sha256_ripemd};
 use crate::sha2::{sha256, sha256_d};
 use crate::sha3::keccak256;
 use crate::{H160, H256};
-use serde::Deserialize;
+use alloc::vec;
+use alloc::vec::Vec;
 use tw_memory::Data;
 
 #[macro_export]
@@ -81,24 +82,25 @@ pub trait StatefulHasher {
 ///
 /// Add hash types if necessary. For example, when add a new hasher to `registry.json`,
 /// otherwise use hash functions directly.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub enum Hasher {
-    #[serde(rename = "sha256")]
+    #[cfg_attr(feature = "serde", serde(rename = "sha256"))]
     Sha256,
-    #[serde(rename = "keccak256")]
+    #[cfg_attr(feature = "serde", serde(rename = "keccak256"))]
     Keccak256,
     /// SHA256 hash of the SHA256 hash
-    #[serde(rename = "sha256d")]
+    #[cfg_attr(feature = "serde", serde(rename = "sha256d"))]
     Sha256d,
     /// ripemd hash of the SHA256 hash
-    #[serde(rename = "sha256ripemd")]
+    #[cfg_attr(feature = "serde", serde(rename = "sha256ripemd"))]
     Sha256ripemd,
-    #[serde(rename = "blake256")]
+    #[cfg_attr(feature = "serde", serde(rename = "blake256"))]
     Blake256,
     /// ripemd hash of the BLAKE256 hash
-    #[serde(rename = "blake256ripemd")]
+    #[cfg_attr(feature = "serde", serde(rename = "blake256ripemd"))]
     Blake256ripemd,
-    #[serde(rename = "tapsighash")]
+    #[cfg_attr(feature = "serde", serde(rename = "tapsighash"))]
     TapSighash,
 }
 
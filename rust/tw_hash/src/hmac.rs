@@ -2,10 +2,12 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use alloc::vec::Vec;
 use hmac::{Hmac, Mac};
-use sha2::Sha256;
+use sha2::{Sha256, Sha512};
 
 type HmacSha256 = Hmac<Sha256>;
+type HmacSha512 = Hmac<Sha512>;
 
 pub fn hmac_sha256(key: &[u8], input: &[u8]) -> Vec<u8> {
     let mut mac = HmacSha256::new_from_slice(key).unwrap();
@@ -14,3 +16,47 @@ pub fn hmac_sha256(key: &[u8], input: &[u8]) -> Vec<u8> {
     let code_bytes = res.into_bytes();
     code_bytes.to_vec()
 }
+
+pub fn hmac_sha512(key: &[u8], input: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha512::new_from_slice(key).unwrap();
+    mac.update(input);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Checks if the `mac` is a valid HMAC-SHA256 code of the `input`.
+/// The `mac` is compared in constant time.
+pub fn hmac_sha256_verify(key: &[u8], input: &[u8], mac: &[u8]) -> bool {
+    let mut hmac = HmacSha256::new_from_slice(key).unwrap();
+    hmac.update(input);
+    hmac.verify_slice(mac).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex;
+
+    #[test]
+    fn test_hmac_sha256_verify() {
+        let key = hex::decode("531cbfcf12a168faff61af28bf437377397b4bf435ee732cf4ac95761a651f14")
+            .unwrap();
+        let data = hex::decode("f300888ca4f512cebdc0020ff0f7224c7f896315e90e172bed65d005138f224d")
+            .unwrap();
+        let mut mac = hmac_sha256(&key, &data);
+        assert!(hmac_sha256_verify(&key, &data, &mac));
+
+        mac[31] ^= 1;
+        assert!(!hmac_sha256_verify(&key, &data, &mac));
+        assert!(!hmac_sha256_verify(&key, &data, &mac[..31]));
+    }
+
+    #[test]
+    fn test_hmac_sha512() {
+        // RFC 4231 test case 2.
+        let mac = hmac_sha512(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            hex::encode(mac, false),
+            "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737"
+        );
+    }
+}
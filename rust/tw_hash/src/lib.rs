@@ -2,13 +2,19 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod blake;
 pub mod blake2;
 pub mod crc32;
+#[cfg(feature = "std")]
 pub mod ffi;
 pub mod groestl;
 pub mod hasher;
 pub mod hmac;
+pub mod poseidon;
 pub mod ripemd;
 pub mod sha1;
 pub mod sha2;
@@ -21,7 +27,7 @@ pub use hash_array::*;
 
 use tw_encoding::hex::FromHexError;
 
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
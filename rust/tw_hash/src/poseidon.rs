@@ -0,0 +1,540 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Poseidon hash over the BN254 scalar field and the Stark field.
+//!
+//! The permutation is configured by the state width `t`, the number of full and partial rounds,
+//! the S-box exponent, the round constants and the MDS matrix.
+//! [`PoseidonParams::generate`] derives the round constants and the MDS matrix with the Grain LFSR
+//! as the reference implementation does, so [`PoseidonParams::circom`] is compatible with circomlib.
+//!
+//! Note the Stark field parameters generated with the Grain LFSR are not the Starknet Poseidon hash,
+//! which uses its own round constants and MDS matrix.
+//! Use [`PoseidonParams::new`] with the constants of the Starknet specification instead.
+//!
+//! See https://eprint.iacr.org/2019/458.pdf
+
+use crate::{Error, Result, H256};
+use alloc::vec;
+use alloc::vec::Vec;
+use crypto_bigint::modular::runtime_mod::{DynResidue, DynResidueParams};
+use crypto_bigint::{Encoding, U256};
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+const LIMBS: usize = U256::LIMBS;
+
+type Element = DynResidue<LIMBS>;
+
+/// The BN254 (alt_bn128) scalar field used by Groth16 circuits.
+const BN254_MODULUS: U256 =
+    U256::from_be_hex("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+/// The Stark field `2^251 + 17 * 2^192 + 1`.
+const STARK252_MODULUS: U256 =
+    U256::from_be_hex("0800000000000011000000000000000000000000000000000000000000000001");
+
+/// The numbers of partial rounds used by circomlib, indexed by `t - 2`.
+const CIRCOM_PARTIAL_ROUNDS: [usize; 16] = [
+    56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68,
+];
+const CIRCOM_FULL_ROUNDS: usize = 8;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PoseidonField {
+    Bn254,
+    Stark252,
+}
+
+impl PoseidonField {
+    /// Returns the big-endian field modulus.
+    pub fn modulus(&self) -> H256 {
+        H256::from(self.modulus_uint().to_be_bytes())
+    }
+
+    /// Returns the smallest S-box exponent `alpha` such that `gcd(alpha, p - 1) = 1`.
+    pub fn default_alpha(&self) -> u64 {
+        match self {
+            PoseidonField::Bn254 => 5,
+            PoseidonField::Stark252 => 3,
+        }
+    }
+
+    fn modulus_uint(&self) -> U256 {
+        match self {
+            PoseidonField::Bn254 => BN254_MODULUS,
+            PoseidonField::Stark252 => STARK252_MODULUS,
+        }
+    }
+
+    fn bits(&self) -> usize {
+        match self {
+            PoseidonField::Bn254 => 254,
+            PoseidonField::Stark252 => 252,
+        }
+    }
+}
+
+pub struct PoseidonParams {
+    field: PoseidonField,
+    residue_params: DynResidueParams<LIMBS>,
+    t: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+    alpha: u64,
+    /// `t` constants per round.
+    round_constants: Vec<Element>,
+    /// `t x t` matrix.
+    mds: Vec<Vec<Element>>,
+}
+
+impl PoseidonParams {
+    /// Creates the parameters with the given round constants and MDS matrix.
+    /// All values are big-endian field elements.
+    pub fn new(
+        field: PoseidonField,
+        t: usize,
+        full_rounds: usize,
+        partial_rounds: usize,
+        alpha: u64,
+        round_constants: &[H256],
+        mds: &[Vec<H256>],
+    ) -> Result<PoseidonParams> {
+        check_config(t, full_rounds, alpha)?;
+
+        let residue_params = DynResidueParams::new(&field.modulus_uint());
+        if round_constants.len() != (full_rounds + partial_rounds) * t
+            || mds.len() != t
+            || mds.iter().any(|row| row.len() != t)
+        {
+            return Err(Error::InvalidArgument);
+        }
+
+        let to_element = |value: &H256| element_from_hash(value, field, residue_params);
+        let round_constants = round_constants
+            .iter()
+            .map(to_element)
+            .collect::<Result<Vec<_>>>()?;
+        let mds = mds
+            .iter()
+            .map(|row| row.iter().map(to_element).collect::<Result<Vec<_>>>())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PoseidonParams {
+            field,
+            residue_params,
+            t,
+            full_rounds,
+            partial_rounds,
+            alpha,
+            round_constants,
+            mds,
+        })
+    }
+
+    /// Generates the round constants and a Cauchy MDS matrix with the Grain LFSR
+    /// the same way as the reference `generate_parameters_grain.sage` script.
+    /// Note the MDS security checks of the script are not performed,
+    /// so the parameters are expected to be already checked by the reference implementation.
+    pub fn generate(
+        field: PoseidonField,
+        t: usize,
+        full_rounds: usize,
+        partial_rounds: usize,
+    ) -> Result<PoseidonParams> {
+        let alpha = field.default_alpha();
+        check_config(t, full_rounds, alpha)?;
+
+        let residue_params = DynResidueParams::new(&field.modulus_uint());
+        let modulus = field.modulus_uint();
+        let bits = field.bits();
+        let mut grain = Grain::new(bits, t, full_rounds, partial_rounds);
+
+        let round_constants = (0..(full_rounds + partial_rounds) * t)
+            .map(|_| loop {
+                // Rejection sampling.
+                let value = grain.next_uint(bits);
+                if value < modulus {
+                    break Element::new(&value, residue_params);
+                }
+            })
+            .collect();
+
+        let mds = loop {
+            // Reduced modulo `p` instead of rejection sampling.
+            let values: Vec<_> = (0..2 * t)
+                .map(|_| {
+                    let value = grain.next_uint(bits);
+                    if value < modulus {
+                        value
+                    } else {
+                        value.wrapping_sub(&modulus)
+                    }
+                })
+                .collect();
+            if let Some(mds) = cauchy_matrix(&values, t, residue_params) {
+                break mds;
+            }
+        };
+
+        Ok(PoseidonParams {
+            field,
+            residue_params,
+            t,
+            full_rounds,
+            partial_rounds,
+            alpha,
+            round_constants,
+            mds,
+        })
+    }
+
+    /// Returns the BN254 parameters of circomlib `Poseidon(inputs)`.
+    pub fn circom(inputs: usize) -> Result<PoseidonParams> {
+        let partial_rounds = inputs
+            .checked_sub(1)
+            .and_then(|idx| CIRCOM_PARTIAL_ROUNDS.get(idx))
+            .ok_or(Error::InvalidArgument)?;
+        PoseidonParams::generate(
+            PoseidonField::Bn254,
+            inputs + 1,
+            CIRCOM_FULL_ROUNDS,
+            *partial_rounds,
+        )
+    }
+
+    pub fn field(&self) -> PoseidonField {
+        self.field
+    }
+
+    /// Returns the state width.
+    pub fn t(&self) -> usize {
+        self.t
+    }
+
+    /// Applies the Poseidon permutation to the `state` of `t` big-endian field elements.
+    pub fn permute(&self, state: &[H256]) -> Result<Vec<H256>> {
+        if state.len() != self.t {
+            return Err(Error::InvalidArgument);
+        }
+        let mut state = state
+            .iter()
+            .map(|value| element_from_hash(value, self.field, self.residue_params))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.permute_elements(&mut state);
+        Ok(state.iter().map(element_to_hash).collect())
+    }
+
+    /// Hashes `t - 1` big-endian field elements.
+    /// The capacity element is initialized with zero and the first element of the state is returned.
+    pub fn hash(&self, inputs: &[H256]) -> Result<H256> {
+        if inputs.len() + 1 != self.t {
+            return Err(Error::InvalidArgument);
+        }
+
+        let mut state = vec![H256::default()];
+        state.extend_from_slice(inputs);
+        let state = self.permute(&state)?;
+        Ok(state[0])
+    }
+
+    fn permute_elements(&self, state: &mut [Element]) {
+        let half_full_rounds = self.full_rounds / 2;
+        let alpha = U256::from_u64(self.alpha).to_be_bytes();
+
+        for (round, constants) in self.round_constants.chunks(self.t).enumerate() {
+            for (item, constant) in state.iter_mut().zip(constants) {
+                *item = *item + *constant;
+            }
+
+            let is_full_round =
+                round < half_full_rounds || round >= half_full_rounds + self.partial_rounds;
+            if is_full_round {
+                for item in state.iter_mut() {
+                    *item = pow(*item, &alpha);
+                }
+            } else {
+                state[0] = pow(state[0], &alpha);
+            }
+
+            let mixed: Vec<_> = self
+                .mds
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(state.iter())
+                        .fold(Element::zero(self.residue_params), |acc, (m, item)| {
+                            acc + *m * *item
+                        })
+                })
+                .collect();
+            state.copy_from_slice(&mixed);
+        }
+    }
+}
+
+/// Hashes the big-endian BN254 field elements the same way as circomlib `Poseidon(inputs.len())`.
+pub fn poseidon_bn254(inputs: &[H256]) -> Result<H256> {
+    with_circom_params(inputs.len(), |params| params.hash(inputs))
+}
+
+/// Calls `f` with the circomlib parameters of the given number of inputs.
+/// The parameters are generated once per number of inputs, as running the Grain LFSR is slow.
+#[cfg(feature = "std")]
+fn with_circom_params<R>(inputs: usize, f: impl FnOnce(&PoseidonParams) -> Result<R>) -> Result<R> {
+    static CIRCOM_PARAMS: [OnceLock<PoseidonParams>; CIRCOM_PARTIAL_ROUNDS.len()] =
+        [const { OnceLock::new() }; CIRCOM_PARTIAL_ROUNDS.len()];
+
+    let cached = inputs
+        .checked_sub(1)
+        .and_then(|idx| CIRCOM_PARAMS.get(idx))
+        .ok_or(Error::InvalidArgument)?;
+    if let Some(params) = cached.get() {
+        return f(params);
+    }
+    let params = PoseidonParams::circom(inputs)?;
+    f(cached.get_or_init(|| params))
+}
+
+/// Calls `f` with the circomlib parameters of the given number of inputs.
+/// The parameters are generated on every call, as there is no `OnceLock` without `std`.
+#[cfg(not(feature = "std"))]
+fn with_circom_params<R>(inputs: usize, f: impl FnOnce(&PoseidonParams) -> Result<R>) -> Result<R> {
+    f(&PoseidonParams::circom(inputs)?)
+}
+
+fn check_config(t: usize, full_rounds: usize, alpha: u64) -> Result<()> {
+    if t < 2 || full_rounds % 2 != 0 || alpha < 3 {
+        return Err(Error::InvalidArgument);
+    }
+    Ok(())
+}
+
+/// Returns `None` if the values contain duplicates or `x_i + y_j = 0`,
+/// so the next values should be generated.
+fn cauchy_matrix(
+    values: &[U256],
+    t: usize,
+    residue_params: DynResidueParams<LIMBS>,
+) -> Option<Vec<Vec<Element>>> {
+    let has_duplicates = values
+        .iter()
+        .enumerate()
+        .any(|(i, value)| values[i + 1..].contains(value));
+    if has_duplicates {
+        return None;
+    }
+
+    let (xs, ys) = values.split_at(t);
+    let modulus = residue_params.modulus();
+    let p_minus_2 = modulus.wrapping_sub(&U256::from_u64(2)).to_be_bytes();
+
+    xs.iter()
+        .map(|x| {
+            ys.iter()
+                .map(|y| {
+                    let sum = Element::new(x, residue_params) + Element::new(y, residue_params);
+                    if sum.retrieve() == U256::ZERO {
+                        return None;
+                    }
+                    // Fermat's little theorem inversion.
+                    Some(pow(sum, &p_minus_2))
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect()
+}
+
+fn element_from_hash(
+    value: &H256,
+    field: PoseidonField,
+    residue_params: DynResidueParams<LIMBS>,
+) -> Result<Element> {
+    let value = U256::from_be_slice(value.as_slice());
+    if value >= field.modulus_uint() {
+        return Err(Error::InvalidArgument);
+    }
+    Ok(Element::new(&value, residue_params))
+}
+
+fn element_to_hash(element: &Element) -> H256 {
+    H256::from(element.retrieve().to_be_bytes())
+}
+
+/// Square-and-multiply exponentiation by the big-endian `exponent`.
+fn pow(base: Element, exponent: &[u8]) -> Element {
+    let mut result = Element::one(*base.params());
+    for byte in exponent {
+        for shift in (0..8).rev() {
+            result = result * result;
+            if (byte >> shift) & 1 == 1 {
+                result = result * base;
+            }
+        }
+    }
+    result
+}
+
+/// The 80-bit Grain LFSR of the Poseidon reference implementation.
+struct Grain {
+    /// `state[0]` is the bit 0.
+    state: u128,
+}
+
+impl Grain {
+    const STATE_BITS: usize = 80;
+    /// The prime field.
+    const FIELD: u128 = 1;
+    /// `x^alpha` S-box.
+    const SBOX: u128 = 0;
+    const WARM_UP_STEPS: usize = 160;
+
+    fn new(bits: usize, t: usize, full_rounds: usize, partial_rounds: usize) -> Grain {
+        let fields = [
+            (Grain::FIELD, 2),
+            (Grain::SBOX, 4),
+            (bits as u128, 12),
+            (t as u128, 12),
+            (full_rounds as u128, 10),
+            (partial_rounds as u128, 10),
+            ((1 << 30) - 1, 30),
+        ];
+
+        let mut state = 0_u128;
+        let mut position = 0;
+        for (value, width) in fields {
+            for shift in (0..width).rev() {
+                state |= ((value >> shift) & 1) << position;
+                position += 1;
+            }
+        }
+
+        let mut grain = Grain { state };
+        for _ in 0..Grain::WARM_UP_STEPS {
+            grain.step();
+        }
+        grain
+    }
+
+    fn step(&mut self) -> bool {
+        let s = self.state;
+        let bit = ((s >> 62) ^ (s >> 51) ^ (s >> 38) ^ (s >> 23) ^ (s >> 13) ^ s) & 1;
+        self.state = (s >> 1) | (bit << (Grain::STATE_BITS - 1));
+        bit == 1
+    }
+
+    /// The output bits are filtered in pairs: the second bit is emitted if the first one is set.
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let emit = self.step();
+            let bit = self.step();
+            if emit {
+                return bit;
+            }
+        }
+    }
+
+    /// Returns an unsigned integer of the next `bits` bits, most significant bit first.
+    fn next_uint(&mut self, bits: usize) -> U256 {
+        let mut bytes = [0_u8; 32];
+        let offset = U256::BITS - bits;
+        for i in 0..bits {
+            if self.next_bit() {
+                let position = offset + i;
+                bytes[position / 8] |= 0x80 >> (position % 8);
+            }
+        }
+        U256::from_be_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(value: u64) -> H256 {
+        H256::from(U256::from_u64(value).to_be_bytes())
+    }
+
+    #[test]
+    fn test_poseidon_bn254_circom() {
+        let tests = [
+            (
+                vec![hash(1)],
+                "29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133",
+            ),
+            (
+                vec![hash(1), hash(2)],
+                "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a",
+            ),
+            (
+                vec![hash(1), hash(2), hash(3), hash(4)],
+                "299c867db6c1fdd79dcefa40e4510b9837e60ebb1ce0663dbaa525df65250465",
+            ),
+        ];
+        for (inputs, expected) in tests {
+            assert_eq!(poseidon_bn254(&inputs).unwrap(), H256::from(expected));
+        }
+    }
+
+    /// Regression test of the Grain LFSR generated Stark field parameters.
+    /// The expected value is not the Starknet Poseidon hash of `[1, 2]`, see the module docs.
+    #[test]
+    fn test_poseidon_stark252_grain_params() {
+        let params = PoseidonParams::generate(PoseidonField::Stark252, 3, 8, 83).unwrap();
+        let actual = params.hash(&[hash(1), hash(2)]).unwrap();
+        let expected = "01ad2535f42c5cf6d12d470e9e3f9b614a6fc9b8641ab07913b3f0853094432a";
+        assert_eq!(actual, H256::from(expected));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_poseidon_bn254_params_cached() {
+        let params_ptr = |inputs| {
+            with_circom_params(inputs, |params| Ok(params as *const PoseidonParams)).unwrap()
+        };
+        assert_eq!(params_ptr(2), params_ptr(2));
+        assert_ne!(params_ptr(2), params_ptr(3));
+        with_circom_params(0, |_| Ok(())).unwrap_err();
+    }
+
+    #[test]
+    fn test_poseidon_custom_params() {
+        let generated = PoseidonParams::circom(2).unwrap();
+        let round_constants: Vec<_> = generated
+            .round_constants
+            .iter()
+            .map(element_to_hash)
+            .collect();
+        let mds: Vec<Vec<_>> = generated
+            .mds
+            .iter()
+            .map(|row| row.iter().map(element_to_hash).collect())
+            .collect();
+
+        let params =
+            PoseidonParams::new(PoseidonField::Bn254, 3, 8, 57, 5, &round_constants, &mds).unwrap();
+        let inputs = [hash(1), hash(2)];
+        assert_eq!(
+            params.hash(&inputs).unwrap(),
+            generated.hash(&inputs).unwrap()
+        );
+
+        // Invalid number of round constants.
+        PoseidonParams::new(PoseidonField::Bn254, 3, 8, 56, 5, &round_constants, &mds).unwrap_err();
+    }
+
+    #[test]
+    fn test_poseidon_invalid_input() {
+        let params = PoseidonParams::circom(2).unwrap();
+        params.hash(&[hash(1)]).unwrap_err();
+        params
+            .hash(&[hash(1), PoseidonField::Bn254.modulus()])
+            .unwrap_err();
+
+        PoseidonParams::circom(0).unwrap_err();
+        PoseidonParams::circom(17).unwrap_err();
+        PoseidonParams::generate(PoseidonField::Bn254, 3, 7, 57).unwrap_err();
+    }
+}
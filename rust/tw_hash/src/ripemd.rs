@@ -6,6 +6,7 @@ use crate::blake::blake_256;
 use crate::hash_wrapper::hasher;
 use crate::impl_static_hasher;
 use crate::sha2::sha256;
+use alloc::vec::Vec;
 use tw_memory::Data;
 
 pub fn ripemd_160(input: &[u8]) -> Vec<u8> {
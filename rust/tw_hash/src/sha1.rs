@@ -4,6 +4,7 @@
 
 use crate::hash_wrapper::hasher;
 use crate::impl_static_hasher;
+use alloc::vec::Vec;
 
 pub fn sha1(input: &[u8]) -> Vec<u8> {
     hasher::<sha1::Sha1>(input)
@@ -4,6 +4,7 @@
 
 use crate::hash_wrapper::hasher;
 use crate::impl_static_hasher;
+use alloc::vec::Vec;
 use tw_memory::Data;
 
 pub fn sha224(input: &[u8]) -> Vec<u8> {
@@ -4,6 +4,7 @@
 
 use crate::hash_wrapper::hasher;
 use crate::impl_static_hasher;
+use alloc::vec::Vec;
 
 pub fn keccak256(input: &[u8]) -> Vec<u8> {
     hasher::<sha3::Keccak256>(input)
@@ -5,8 +5,9 @@
 use tw_encoding::hex;
 use tw_encoding::hex::FromHexError;
 use tw_hash::ffi::{
-    blake2_b, blake2_b_personal, blake_256, groestl_512, hmac__sha256, keccak256, keccak512,
-    ripemd_160, sha1, sha256, sha3__256, sha3__512, sha512, sha512_256, CHashingCode,
+    blake2_b, blake2_b_personal, blake_256, groestl_512, hmac__sha256, hmac__sha256_verify,
+    keccak256, keccak512, ripemd_160, sha1, sha256, sha3__256, sha3__512, sha512, sha512_256,
+    tw_hash_poseidon, CHashingCode,
 };
 use tw_hash::Error;
 use tw_memory::ffi::c_byte_array::CByteArray;
@@ -87,6 +88,31 @@ fn test_hmac_sha256() {
     assert_eq!(hex::encode(actual, false), expected);
 }
 
+#[test]
+fn test_hmac_sha256_verify() {
+    let key =
+        hex::decode("531cbfcf12a168faff61af28bf437377397b4bf435ee732cf4ac95761a651f14").unwrap();
+    let data =
+        hex::decode("f300888ca4f512cebdc0020ff0f7224c7f896315e90e172bed65d005138f224d").unwrap();
+    let mut mac =
+        hex::decode("a7301d5563614e3955750e4480aabf7753f44b4975308aeb8e23c31e114962ab").unwrap();
+
+    let verify = |mac: &[u8]| unsafe {
+        hmac__sha256_verify(
+            key.as_ptr(),
+            key.len(),
+            data.as_ptr(),
+            data.len(),
+            mac.as_ptr(),
+            mac.len(),
+        )
+    };
+    assert!(verify(&mac));
+    assert!(!verify(&mac[..31]));
+    mac[0] ^= 1;
+    assert!(!verify(&mac));
+}
+
 #[test]
 fn test_ripemd160() {
     test_hash_helper(
@@ -230,3 +256,33 @@ fn test_c_hashing_error_convert() {
         Error::InvalidHashLength.into(),
     );
 }
+
+#[test]
+fn test_poseidon() {
+    let poseidon = |input: &str| {
+        let input = hex::decode(input).unwrap();
+        unsafe { tw_hash_poseidon(input.as_ptr(), input.len()) }
+            .into_result()
+            .map(|hash| hex::encode(hash.into_vec(), false))
+    };
+
+    // circomlib `Poseidon(2)` of `[1, 2]`.
+    assert_eq!(
+        poseidon("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002"),
+        Ok("115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a".to_string())
+    );
+
+    let invalid_argument = Err(ErrorCode::from(CHashingCode::InvalidArgument));
+    // No elements.
+    assert_eq!(poseidon(""), invalid_argument);
+    // Not a multiple of 32 bytes.
+    assert_eq!(
+        poseidon("000000000000000000000000000000000000000000000000000000000000000102"),
+        invalid_argument
+    );
+    // The BN254 scalar field modulus is not a field element.
+    assert_eq!(
+        poseidon("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"),
+        invalid_argument
+    );
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1::ExtendedPublicKey;
+use tw_keypair::test_utils::bench_utils::{
+    deterministic_bytes, deterministic_message, deterministic_private_key,
+};
+use tw_keypair::test_utils::curve_utils::public_key_type;
+use tw_keypair::tw::{Curve, PrivateKey};
+
+const SEED: u64 = 0x7772_6b65_7973;
+
+fn bench_sign(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sign");
+    for curve in Curve::all().iter().copied() {
+        let private = deterministic_private_key(SEED, curve);
+        let message = deterministic_message(SEED, curve);
+        group.bench_function(curve.as_str(), |b| {
+            b.iter(|| private.sign(black_box(&message), curve).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_verify(c: &mut Criterion) {
+    let mut group = c.benchmark_group("verify");
+    for curve in Curve::all().iter().copied() {
+        let private = deterministic_private_key(SEED, curve);
+        let public = private
+            .get_public_key_by_type(public_key_type(curve))
+            .unwrap();
+        let message = deterministic_message(SEED, curve);
+        let signature = private.sign(&message, curve).unwrap();
+        group.bench_function(curve.as_str(), |b| {
+            b.iter(|| assert!(public.verify(black_box(&signature), black_box(&message))))
+        });
+    }
+    group.finish();
+}
+
+fn bench_public_key(c: &mut Criterion) {
+    let mut group = c.benchmark_group("public_key");
+    for curve in Curve::all().iter().copied() {
+        let ty = public_key_type(curve);
+        let bytes = deterministic_private_key(SEED, curve)
+            .bytes()
+            .unwrap()
+            .to_vec();
+        // Measure a fresh key each time, so cached key setup doesn't hide the derivation cost.
+        group.bench_function(curve.as_str(), |b| {
+            b.iter_batched(
+                || PrivateKey::new(bytes.clone()).unwrap(),
+                |private| private.get_public_key_by_type(ty).unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_derive(c: &mut Criterion) {
+    let private = deterministic_private_key(SEED, Curve::Secp256k1);
+    let public = private
+        .get_public_key_by_type(public_key_type(Curve::Secp256k1))
+        .unwrap();
+    let chain_code = H256::try_from(deterministic_bytes(SEED, H256::len()).as_slice()).unwrap();
+    let xpub = ExtendedPublicKey::new(public.to_secp256k1().unwrap().clone(), chain_code);
+
+    let mut group = c.benchmark_group("derive");
+    group.bench_function("secp256k1_child", |b| {
+        b.iter(|| xpub.derive_child(black_box(1)).unwrap())
+    });
+    group.bench_function("secp256k1_range_100", |b| {
+        b.iter(|| xpub.derive_range(&[0], 0..100).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_sign,
+    bench_verify,
+    bench_public_key,
+    bench_derive
+);
+criterion_main!(benches);
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![no_main]
+
+use libfuzzer_sys::{arbitrary, fuzz_target};
+use tw_keypair::ecdsa::der;
+use tw_keypair::tw::{PublicKey, PublicKeyType};
+
+#[derive(arbitrary::Arbitrary, Debug)]
+struct DerDecodeInput {
+    der: Vec<u8>,
+    pubkey_type: PublicKeyType,
+}
+
+fuzz_target!(|input: DerDecodeInput| {
+    // A parsed signature must survive the re-encoding.
+    if let Ok(sign) = der::Signature::from_bytes(&input.der) {
+        let reparsed = der::Signature::from_bytes(&sign.der_bytes()).unwrap();
+        assert_eq!(reparsed.to_bytes(), sign.to_bytes());
+    }
+
+    if let Ok(public) = PublicKey::from_der(&input.der, input.pubkey_type) {
+        let _ = public.to_der();
+    }
+});
@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tw_keypair::ecdsa::secp256k1::ExtendedPublicKey;
+
+fuzz_target!(|xpub: &str| {
+    if let Ok(xpub) = ExtendedPublicKey::from_base58(xpub) {
+        let _ = xpub.derive_child(0);
+    }
+});
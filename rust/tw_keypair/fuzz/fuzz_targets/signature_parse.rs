@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tw_keypair::{ecdsa, ed25519, schnorr, starkex};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(sign) = ecdsa::secp256k1::Signature::try_from(data) {
+        let bytes = sign.to_bytes();
+        let parsed = ecdsa::secp256k1::Signature::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(parsed.to_bytes(), bytes);
+    }
+    let _ = ecdsa::secp256k1::VerifySignature::try_from(data);
+    let _ = ecdsa::nist256p1::Signature::try_from(data);
+    let _ = ecdsa::nist256p1::VerifySignature::try_from(data);
+    let _ = ed25519::Signature::try_from(data);
+    let _ = ed25519::waves::Signature::try_from(data);
+    let _ = schnorr::Signature::try_from(data);
+    if let Ok(sign) = starkex::Signature::try_from(data) {
+        let _ = sign.to_vec();
+        let _ = sign.to_extended_vec();
+    }
+});
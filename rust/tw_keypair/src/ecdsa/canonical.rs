@@ -13,7 +13,7 @@
 
 use crate::ecdsa::signature::Signature;
 use crate::ecdsa::EcdsaCurve;
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
 use ecdsa::elliptic_curve::generic_array::ArrayLength;
 use ecdsa::elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use ecdsa::elliptic_curve::{Curve, FieldBytesEncoding, PrimeField, Scalar};
@@ -40,8 +40,8 @@ where
 {
     let priv_scalar = signing_key.as_nonzero_scalar();
 
-    let nonce =
-        bits2field::<C>(hash_to_sign.as_slice()).map_err(|_| KeyPairError::InvalidSignMessage)?;
+    let nonce = bits2field::<C>(hash_to_sign.as_slice())
+        .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignMessage))?;
     let entropy_input = &priv_scalar.to_repr();
     let n = &C::ORDER.encode_field_bytes();
     let additional_data = &[];
@@ -55,8 +55,8 @@ where
 
         let (sig, r) = priv_scalar
             .try_sign_prehashed(k_scalar, &nonce)
-            .map_err(|_| KeyPairError::SigningError)?;
-        let r = r.ok_or(KeyPairError::SigningError)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::SigningError))?;
+        let r = r.ok_or(KeyPairError::new(KeyPairErrorKind::SigningError))?;
 
         let signature = Signature::<C>::new(sig, r);
         if is_canonical(&signature) {
@@ -64,7 +64,7 @@ where
         }
     }
 
-    Err(KeyPairError::SigningError)
+    Err(KeyPairError::new(KeyPairErrorKind::SigningError))
 }
 
 fn ct_eq<N: ArrayLength<u8>>(a: &ByteArray<N>, b: &ByteArray<N>) -> Choice {
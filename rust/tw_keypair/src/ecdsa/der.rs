@@ -2,7 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
 use der::asn1::UintRef;
 use der::{Decode, Encode, Reader};
 use tw_hash::{H256, H512};
@@ -35,18 +36,19 @@ pub struct Signature {
 
 impl Signature {
     pub fn new(r: H256, s: H256) -> KeyPairResult<Signature> {
-        let der_bytes =
-            encode_der(r.as_slice(), s.as_slice()).map_err(|_| KeyPairError::InvalidSignature)?;
+        let der_bytes = encode_der(r.as_slice(), s.as_slice())
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
         Ok(Signature { r, s, der_bytes })
     }
 
     /// Parses signature from DER-encoded bytes.
     /// Source: https://github.com/RustCrypto/signatures/blob/ecb112aa25ac210d86946089cddf0a62a672ce14/ecdsa/src/der.rs#L83-L108
     pub fn from_bytes(input: &[u8]) -> KeyPairResult<Signature> {
-        let (r, s) = decode_der(input).map_err(|_| KeyPairError::InvalidSignature)?;
+        let (r, s) =
+            decode_der(input).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
 
         if r.as_bytes().len() > R_LENGTH || s.as_bytes().len() > S_LENGTH {
-            return Err(KeyPairError::InvalidSignature);
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSignature));
         }
 
         let r_begin = R_LENGTH.saturating_sub(r.as_bytes().len());
@@ -110,6 +112,8 @@ impl<'a> TryFrom<&'a [u8]> for Signature {
     }
 }
 
+impl_serde_as_hex!(Signature);
+
 /// Decode the `r` and `s` components of a DER-encoded ECDSA signature.
 /// Source: https://github.com/RustCrypto/signatures/blob/ecb112aa25ac210d86946089cddf0a62a672ce14/ecdsa/src/der.rs#L357C69-L370
 fn decode_der(der_bytes: &[u8]) -> der::Result<(UintRef<'_>, UintRef<'_>)> {
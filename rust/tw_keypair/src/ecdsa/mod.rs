@@ -13,6 +13,7 @@ pub mod der;
 pub mod nist256p1;
 pub mod secp256k1;
 pub mod signature;
+mod spki;
 
 /// This is an alias used for convenience.
 pub trait EcdsaCurve:
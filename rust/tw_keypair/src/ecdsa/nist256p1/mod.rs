@@ -20,6 +20,7 @@ mod tests {
     use super::*;
     use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
     use tw_encoding::hex;
+    use tw_encoding::hex::ToHex;
     use tw_hash::sha3::keccak256;
     use tw_hash::{H256, H264, H520};
     use tw_misc::traits::{ToBytesVec, ToBytesZeroizing};
@@ -91,6 +92,24 @@ mod tests {
         assert_eq!(public.uncompressed(), expected_uncompressed);
     }
 
+    #[test]
+    fn test_public_key_der_encoded() {
+        let der = "3059301306072a8648ce3d020106082a8648ce3d030107034200046d786ab8fda678cf50f71d13641049a393b325063b8c0d4e5070de48a2caf9ab918b4fe46ccbf56701fb210d67d91c5779468f6b3fdc7a63692b9b62543f47ae";
+        let public = PublicKey::try_from(
+            "026d786ab8fda678cf50f71d13641049a393b325063b8c0d4e5070de48a2caf9ab",
+        )
+        .unwrap();
+        assert_eq!(public.der_encoded().to_hex(), der);
+
+        let der_bytes = hex::decode(der).unwrap();
+        let parsed = PublicKey::from_der_encoded(&der_bytes).unwrap();
+        assert_eq!(parsed.compressed(), public.compressed());
+
+        // `secp256k1` public key.
+        let der = hex::decode("3056301006072a8648ce3d020106052b8104000a0342000499c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c166b489a4b7c491e7688e6ebea3a71fc3a1a48d60f98d5ce84c93b65e423fde91").unwrap();
+        PublicKey::from_der_encoded(&der).unwrap_err();
+    }
+
     #[test]
     fn test_public_key_recover() {
         let sign_bytes = H520::from("8859e63a0c0cc2fc7f788d7e78406157b288faa6f76f76d37c4cd1534e8d83c468f9fd6ca7dde378df594625dcde98559389569e039282275e3d87c26e36447401");
@@ -4,8 +4,11 @@
 
 use crate::ecdsa::nist256p1::public::PublicKey;
 use crate::ecdsa::nist256p1::Signature;
+use crate::redact::fmt_redacted;
 use crate::traits::SigningKeyTrait;
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
+use core::fmt;
 use p256::ecdsa::SigningKey;
 use tw_encoding::hex;
 use tw_hash::H256;
@@ -18,6 +21,12 @@ pub struct PrivateKey {
     pub(crate) secret: SigningKey,
 }
 
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_redacted(f, "PrivateKey", &self.to_zeroizing_vec())
+    }
+}
+
 impl PrivateKey {
     /// Returns an associated `nist256p1` public key.
     pub fn public(&self) -> PublicKey {
@@ -33,7 +42,7 @@ impl SigningKeyTrait for PrivateKey {
         let (signature, recovery_id) = self
             .secret
             .sign_prehash_recoverable(message.as_slice())
-            .map_err(|_| KeyPairError::SigningError)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::SigningError))?;
         Ok(Signature::new(signature, recovery_id))
     }
 }
@@ -42,7 +51,8 @@ impl<'a> TryFrom<&'a [u8]> for PrivateKey {
     type Error = KeyPairError;
 
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        let secret = SigningKey::from_slice(data).map_err(|_| KeyPairError::InvalidSecretKey)?;
+        let secret = SigningKey::from_slice(data)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
         Ok(PrivateKey { secret })
     }
 }
@@ -51,7 +61,9 @@ impl<'a> TryFrom<&'a str> for PrivateKey {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
@@ -62,3 +74,5 @@ impl ToBytesZeroizing for PrivateKey {
         Zeroizing::new(secret.as_slice().to_vec())
     }
 }
+
+impl_constant_time_eq!(PrivateKey);
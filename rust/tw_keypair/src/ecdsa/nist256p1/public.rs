@@ -3,8 +3,10 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::ecdsa::nist256p1::{Signature, VerifySignature};
+use crate::ecdsa::spki;
 use crate::traits::VerifyingKeyTrait;
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
 use p256::ecdsa::signature::hazmat::PrehashVerifier;
 use p256::ecdsa::VerifyingKey;
 use tw_encoding::hex;
@@ -28,7 +30,7 @@ impl PublicKey {
     pub fn recover(sign: Signature, message: H256) -> KeyPairResult<Self> {
         VerifyingKey::recover_from_prehash(message.as_slice(), &sign.signature, sign.v)
             .map(|public| PublicKey { public })
-            .map_err(|_| KeyPairError::InvalidSignature)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))
     }
 
     /// Creates a public key from the given [`VerifyingKey`].
@@ -49,6 +51,17 @@ impl PublicKey {
         H520::try_from(self.public.to_encoded_point(compressed).as_bytes())
             .expect("Expected 65 byte array Public Key")
     }
+
+    /// Returns the public key as DER-encoded `SubjectPublicKeyInfo` bytes.
+    pub fn der_encoded(&self) -> Vec<u8> {
+        spki::encode_public_key(spki::NIST256P1_OID, self.uncompressed().as_slice())
+    }
+
+    /// Parses a public key from DER-encoded `SubjectPublicKeyInfo` bytes.
+    pub fn from_der_encoded(der_bytes: &[u8]) -> KeyPairResult<PublicKey> {
+        let sec1_bytes = spki::decode_public_key(der_bytes, spki::NIST256P1_OID)?;
+        PublicKey::try_from(sec1_bytes)
+    }
 }
 
 impl VerifyingKeyTrait for PublicKey {
@@ -66,7 +79,8 @@ impl<'a> TryFrom<&'a str> for PublicKey {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = hex::decode(hex).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let bytes =
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Self::try_from(bytes.as_slice())
     }
 }
@@ -78,7 +92,7 @@ impl<'a> TryFrom<&'a [u8]> for PublicKey {
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
         Ok(PublicKey {
             public: VerifyingKey::from_sec1_bytes(data)
-                .map_err(|_| KeyPairError::InvalidPublicKey)?,
+                .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?,
         })
     }
 }
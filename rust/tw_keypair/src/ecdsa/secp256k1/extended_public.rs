@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::ecdsa::secp256k1::PublicKey;
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
+use core::ops::Range;
+use tw_encoding::base58;
+use tw_hash::hmac::hmac_sha512;
+use tw_hash::ripemd::sha256_ripemd;
+use tw_hash::sha2::sha256_d;
+use tw_hash::{H160, H256, H264, H32};
+
+/// Child indexes starting from `2^31` are hardened and can't be derived from a public key.
+pub const HARDENED_OFFSET: u32 = 1 << 31;
+
+/// A BIP-32 extended `secp256k1` public key (HD node) that can derive non-hardened children.
+#[derive(Clone)]
+pub struct ExtendedPublicKey {
+    public: PublicKey,
+    chain_code: H256,
+}
+
+/// cbindgen:ignore
+impl ExtendedPublicKey {
+    /// The number of bytes of a serialized extended key without the checksum.
+    pub const SERIALIZED_LEN: usize = 78;
+
+    pub fn new(public: PublicKey, chain_code: H256) -> ExtendedPublicKey {
+        ExtendedPublicKey { public, chain_code }
+    }
+
+    /// Parses a base58 encoded extended public key, e.g. `xpub`, `zpub`.
+    /// Please note the version, depth, parent fingerprint and child number are not validated.
+    pub fn from_base58(extended: &str) -> KeyPairResult<ExtendedPublicKey> {
+        let decoded = base58::decode(extended, base58::Alphabet::Bitcoin)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
+        if decoded.len() != Self::SERIALIZED_LEN + base58::CHECKSUM_LEN {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey));
+        }
+
+        let (payload, checksum) = decoded.split_at(Self::SERIALIZED_LEN);
+        if sha256_d(payload)[..base58::CHECKSUM_LEN] != *checksum {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey));
+        }
+
+        let chain_code = H256::try_from(&payload[13..45]).expect("Expected 32 byte chain code");
+        let public = PublicKey::try_from(&payload[45..])?;
+        Ok(ExtendedPublicKey { public, chain_code })
+    }
+
+    pub fn public(&self) -> &PublicKey {
+        &self.public
+    }
+
+    pub fn chain_code(&self) -> H256 {
+        self.chain_code
+    }
+
+    /// Returns the key identifier: `RIPEMD160(SHA256(compressed public key))`.
+    pub fn identifier(&self) -> H160 {
+        let hash = sha256_ripemd(self.public.compressed().as_slice());
+        H160::try_from(hash.as_slice()).expect("Expected exactly 20 bytes")
+    }
+
+    /// Returns the first 4 bytes of the key identifier.
+    /// The serialized children keys refer to it as the parent fingerprint.
+    pub fn fingerprint(&self) -> H32 {
+        let (fingerprint, _) = self.identifier().split::<4, 16>();
+        fingerprint
+    }
+
+    /// Derives a non-hardened child key at the given `index` (CKDpub).
+    pub fn derive_child(&self, index: u32) -> KeyPairResult<ExtendedPublicKey> {
+        if index >= HARDENED_OFFSET {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey));
+        }
+
+        let mut data = Vec::with_capacity(H264::len() + 4);
+        data.extend_from_slice(self.public.compressed().as_slice());
+        data.extend_from_slice(&index.to_be_bytes());
+        let hmac = hmac_sha512(self.chain_code.as_slice(), &data);
+        let (tweak, chain_code) = hmac.split_at(H256::len());
+
+        let tweak = H256::try_from(tweak).expect("Expected 32 byte tweak");
+        // The child key is invalid if the tweak is not less than the curve order,
+        // or if it's the point at infinity.
+        let child = self.public.tweak_add(tweak)?;
+
+        Ok(ExtendedPublicKey {
+            public: child,
+            chain_code: H256::try_from(chain_code).expect("Expected 32 byte chain code"),
+        })
+    }
+
+    /// Derives a descendant key by the given non-hardened `path` relative to this key.
+    pub fn derive_path(&self, path: &[u32]) -> KeyPairResult<ExtendedPublicKey> {
+        path.iter()
+            .try_fold(self.clone(), |node, index| node.derive_child(*index))
+    }
+
+    /// Derives public keys at `path_prefix/index` for every index of the `range`.
+    ///
+    /// The prefix node is derived once, then the children are derived in parallel
+    /// if the `parallel` feature is enabled.
+    pub fn derive_range(
+        &self,
+        path_prefix: &[u32],
+        range: Range<u32>,
+    ) -> KeyPairResult<Vec<PublicKey>> {
+        let node = self.derive_path(path_prefix)?;
+        let derive = |index| node.derive_child(index).map(|child| child.public);
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            range.into_par_iter().map(derive).collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            range.map(derive).collect()
+        }
+    }
+}
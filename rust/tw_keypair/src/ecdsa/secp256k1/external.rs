@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Helpers that adapt `secp256k1` signatures produced by external signers,
+//! e.g. Ledger or Trezor hardware wallets, into [`Signature`].
+
+use crate::ecdsa::der;
+use crate::ecdsa::secp256k1::{PublicKey, Signature, VerifySignature};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use k256::ecdsa::{RecoveryId, VerifyingKey};
+use tw_hash::H256;
+
+/// Header bytes as specified in BIP-137 and used by legacy Ethereum signatures: `27 + recovery ID`.
+const RECOVERY_HEADER_OFFSET: u8 = 27;
+/// The last BIP-137 header byte (P2WPKH address with the recovery ID 3).
+const MAX_RECOVERY_HEADER: u8 = 42;
+/// Recovery IDs are within `0..=3`.
+const RECOVERY_ID_MASK: u8 = 0x03;
+
+/// The byte order of a 65 byte recoverable signature.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RecoverableLayout {
+    /// `r || s || v`, e.g. returned by Ethereum hardware wallet apps.
+    Rsv,
+    /// `v || r || s`, e.g. BIP-137 compact signatures returned by Bitcoin `signmessage`.
+    Vrs,
+}
+
+/// Converts the recovery byte of an external signature to a recovery ID.
+///
+/// Accepts raw recovery IDs `0..=3` and header bytes `27..=42`.
+/// Please note that EIP-155 `v` values must be handled by the caller as they depend on the chain ID.
+pub fn normalize_recovery_byte(v: u8) -> KeyPairResult<u8> {
+    match v {
+        0..=RECOVERY_ID_MASK => Ok(v),
+        RECOVERY_HEADER_OFFSET..=MAX_RECOVERY_HEADER => {
+            Ok((v - RECOVERY_HEADER_OFFSET) & RECOVERY_ID_MASK)
+        },
+        _ => Err(KeyPairError::new(KeyPairErrorKind::InvalidSignature)),
+    }
+}
+
+/// Parses a 65 byte recoverable signature with the given `layout`.
+pub fn signature_from_recoverable(
+    sig: &[u8],
+    layout: RecoverableLayout,
+) -> KeyPairResult<Signature> {
+    if sig.len() != Signature::LEN {
+        return Err(KeyPairError::new(KeyPairErrorKind::InvalidSignature));
+    }
+
+    let (v, rs) = match layout {
+        RecoverableLayout::Rsv => (
+            sig[Signature::RECOVERY_LAST],
+            &sig[..Signature::RECOVERY_LAST],
+        ),
+        RecoverableLayout::Vrs => (sig[0], &sig[1..]),
+    };
+    let r = H256::try_from(&rs[Signature::R_RANGE]).expect("Expected 32 byte 'r'");
+    let s = H256::try_from(&rs[Signature::S_RANGE]).expect("Expected 32 byte 's'");
+    Signature::try_from_parts(r, s, normalize_recovery_byte(v)?)
+}
+
+/// Parses an ASN.1 DER encoded signature and restores its recovery ID
+/// by finding the one that recovers the given `public` key from the `hash`.
+///
+/// A high `s` value is normalized as required by Bitcoin and Ethereum.
+pub fn signature_from_der(der: &[u8], public: &PublicKey, hash: H256) -> KeyPairResult<Signature> {
+    let der = der::Signature::from_bytes(der)?;
+    let verify_sig = VerifySignature::from_der(der)?;
+    signature_with_recovered_id(verify_sig, public, hash)
+}
+
+/// Restores the recovery ID of a 64 byte `r || s` signature
+/// by finding the one that recovers the given `public` key from the `hash`.
+///
+/// A high `s` value is normalized as required by Bitcoin and Ethereum.
+pub fn signature_from_rs(rs: &[u8], public: &PublicKey, hash: H256) -> KeyPairResult<Signature> {
+    if rs.len() != Signature::RECOVERY_LAST {
+        return Err(KeyPairError::new(KeyPairErrorKind::InvalidSignature));
+    }
+    let verify_sig = VerifySignature::try_from(rs)?;
+    signature_with_recovered_id(verify_sig, public, hash)
+}
+
+fn signature_with_recovered_id(
+    verify_sig: VerifySignature,
+    public: &PublicKey,
+    hash: H256,
+) -> KeyPairResult<Signature> {
+    let signature = verify_sig
+        .signature
+        .normalize_s()
+        .unwrap_or(verify_sig.signature);
+
+    (0..=RECOVERY_ID_MASK)
+        .filter_map(RecoveryId::from_byte)
+        .find(|v| {
+            let recovered = VerifyingKey::recover_from_prehash(hash.as_slice(), &signature, *v);
+            matches!(recovered, Ok(recovered) if recovered == public.public)
+        })
+        .map(|v| Signature::new(signature, v))
+        .ok_or(KeyPairError::new(KeyPairErrorKind::InvalidSignature))
+}
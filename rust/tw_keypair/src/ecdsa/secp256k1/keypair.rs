@@ -6,7 +6,7 @@ use crate::ecdsa::secp256k1::private::PrivateKey;
 use crate::ecdsa::secp256k1::public::PublicKey;
 use crate::ecdsa::secp256k1::{Signature, VerifySignature};
 use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
 use tw_encoding::hex;
 use tw_hash::H256;
 use zeroize::{ZeroizeOnDrop, Zeroizing};
@@ -64,7 +64,9 @@ impl<'a> TryFrom<&'a str> for KeyPair {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
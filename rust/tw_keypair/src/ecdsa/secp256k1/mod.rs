@@ -4,10 +4,13 @@
 
 use k256::Secp256k1;
 
+mod extended_public;
+pub mod external;
 mod keypair;
 mod private;
 mod public;
 
+pub use extended_public::{ExtendedPublicKey, HARDENED_OFFSET};
 pub use keypair::KeyPair;
 pub use private::PrivateKey;
 pub use public::PublicKey;
@@ -19,11 +22,12 @@ pub type VerifySignature = crate::ecdsa::signature::VerifySignature<Secp256k1>;
 mod tests {
     use super::*;
     use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+    use crate::{KeyPairError, KeyPairErrorKind};
     use tw_encoding::hex;
     use tw_encoding::hex::ToHex;
     use tw_hash::sha2::sha256;
     use tw_hash::sha3::keccak256;
-    use tw_hash::{H256, H264, H520};
+    use tw_hash::{H160, H256, H264, H32, H520};
     use tw_misc::traits::{ToBytesVec, ToBytesZeroizing};
 
     #[test]
@@ -39,6 +43,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_private_key_eq() {
+        let secret = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+        let private = PrivateKey::try_from(secret).unwrap();
+        assert!(private == PrivateKey::try_from(secret).unwrap());
+
+        let other = PrivateKey::try_from(
+            "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f6",
+        )
+        .unwrap();
+        assert!(private != other);
+    }
+
     #[test]
     fn test_key_pair_sign() {
         let key_pair =
@@ -129,6 +146,36 @@ mod tests {
         assert_eq!(public.uncompressed(), expected_uncompressed);
     }
 
+    #[test]
+    fn test_public_key_der_encoded() {
+        let der = "3056301006072a8648ce3d020106052b8104000a0342000499c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c166b489a4b7c491e7688e6ebea3a71fc3a1a48d60f98d5ce84c93b65e423fde91";
+        let public = PublicKey::try_from(
+            "0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1",
+        )
+        .unwrap();
+        assert_eq!(public.der_encoded().to_hex(), der);
+
+        let der_bytes = hex::decode(der).unwrap();
+        let parsed = PublicKey::from_der_encoded(&der_bytes).unwrap();
+        assert_eq!(parsed.compressed(), public.compressed());
+    }
+
+    #[test]
+    fn test_public_key_from_der_encoded_invalid() {
+        // `nist256p1` public key.
+        let der = hex::decode("3059301306072a8648ce3d020106082a8648ce3d030107034200046d786ab8fda678cf50f71d13641049a393b325063b8c0d4e5070de48a2caf9ab918b4fe46ccbf56701fb210d67d91c5779468f6b3fdc7a63692b9b62543f47ae").unwrap();
+        PublicKey::from_der_encoded(&der).unwrap_err();
+
+        // Truncated `SubjectPublicKeyInfo`.
+        let der = hex::decode("3056301006072a8648ce3d020106052b8104000a0342000499c6f51ad6f98c9c583f8e92bb7758ab2ca9a041").unwrap();
+        PublicKey::from_der_encoded(&der).unwrap_err();
+
+        // Raw SEC1 public key.
+        let der = hex::decode("0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1")
+            .unwrap();
+        PublicKey::from_der_encoded(&der).unwrap_err();
+    }
+
     #[test]
     fn test_verify_invalid() {
         let secret = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
@@ -172,4 +219,80 @@ mod tests {
             H264::from("0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1");
         assert_eq!(actual.compressed(), expected_compressed);
     }
+
+    // BIP-32 test vector 1, chain `m/0H/1/2H/2`.
+    const XPUB: &str = "xpub6FHa3pjLCk84BayeJxFW2SP4XRrFd1JYnxeLeU8EqN3vDfZmbqBqaGJAyiLjTAwm6ZLRQUMv1ZACTj37sR62cfN7fe5JnJ7dh8zL4fiyLHV";
+
+    #[test]
+    fn test_extended_public_key_derive_child() {
+        let xpub = ExtendedPublicKey::from_base58(XPUB).unwrap();
+        assert_eq!(
+            xpub.public().compressed(),
+            H264::from("02e8445082a72f29b75ca48748a914df60622a609cacfce8ed0e35804560741d29")
+        );
+        assert_eq!(
+            xpub.chain_code(),
+            H256::from("cfb71883f01676f587d023cc53a35bc7f88f724b1f8c2892ac1275ac822a3edd")
+        );
+
+        // `m/0H/1/2H/2/1000000000`
+        let child = xpub.derive_child(1_000_000_000).unwrap();
+        assert_eq!(
+            child.public().compressed(),
+            H264::from("022a471424da5e657499d1ff51cb43c47481a03b1e77f951fe64cec9f5a48f7011")
+        );
+        assert_eq!(
+            child.chain_code(),
+            H256::from("c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e")
+        );
+
+        assert_eq!(
+            xpub.derive_child(HARDENED_OFFSET).err(),
+            Some(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))
+        );
+    }
+
+    #[test]
+    fn test_extended_public_key_derive_range() {
+        let xpub = ExtendedPublicKey::from_base58(XPUB).unwrap();
+
+        let children = xpub.derive_range(&[], 0..3).unwrap();
+        let expected = [
+            "02df12b7035bdac8e3bab862a3a83d06ea6b17b6753d52edecba9be46f5d09e076",
+            "032869a233c9adff9a994e4966e5b821fd5bac066da6c3112488dc52383b4a98ec",
+            "035d30b6c66dc1e036c45369da8287518cf7e0d6ed1e2b905171c605708f14ca03",
+        ];
+        let actual: Vec<_> = children.iter().map(PublicKey::compressed).collect();
+        assert_eq!(actual, expected.map(H264::from));
+
+        let with_prefix = xpub.derive_range(&[0], 0..100).unwrap();
+        assert_eq!(with_prefix.len(), 100);
+        let expected_last = xpub.derive_path(&[0, 99]).unwrap();
+        assert_eq!(
+            with_prefix[99].compressed(),
+            expected_last.public().compressed()
+        );
+
+        assert!(xpub.derive_range(&[HARDENED_OFFSET], 0..1).is_err());
+    }
+
+    #[test]
+    fn test_extended_public_key_fingerprint() {
+        let xpub = ExtendedPublicKey::from_base58(XPUB).unwrap();
+        assert_eq!(
+            xpub.identifier(),
+            H160::from("d880d7d893848509a62d8fb74e32148dac68412f")
+        );
+        // The parent fingerprint of `m/0H/1/2H/2/1000000000`.
+        assert_eq!(xpub.fingerprint(), H32::from("d880d7d8"));
+    }
+
+    #[test]
+    fn test_extended_public_key_invalid() {
+        let mut invalid_checksum = XPUB.to_string();
+        invalid_checksum.pop();
+        invalid_checksum.push('W');
+        assert!(ExtendedPublicKey::from_base58(&invalid_checksum).is_err());
+        assert!(ExtendedPublicKey::from_base58("xpub").is_err());
+    }
 }
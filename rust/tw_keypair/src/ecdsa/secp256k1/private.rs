@@ -4,22 +4,33 @@
 
 use crate::ecdsa::secp256k1::public::PublicKey;
 use crate::ecdsa::secp256k1::Signature;
+use crate::redact::fmt_redacted;
 use crate::traits::SigningKeyTrait;
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
+use core::fmt;
 use k256::ecdsa::{SigningKey, VerifyingKey};
+use k256::elliptic_curve::point::AffineCoordinates;
 use k256::elliptic_curve::sec1::ToEncodedPoint;
-use k256::{AffinePoint, ProjectivePoint};
+use k256::elliptic_curve::PrimeField;
+use k256::{AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, Scalar};
 use tw_encoding::hex;
 use tw_hash::H256;
 use tw_misc::traits::ToBytesZeroizing;
 use zeroize::{ZeroizeOnDrop, Zeroizing};
 
 /// Represents a `secp256k1` private key.
-#[derive(ZeroizeOnDrop)]
+#[derive(Clone, ZeroizeOnDrop)]
 pub struct PrivateKey {
     pub(crate) secret: SigningKey,
 }
 
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_redacted(f, "PrivateKey", &self.to_zeroizing_vec())
+    }
+}
+
 impl PrivateKey {
     /// Returns an associated `secp256k1` public key.
     pub fn public(&self) -> PublicKey {
@@ -38,6 +49,27 @@ impl PrivateKey {
         let shared_secret_hash = tw_hash::sha2::sha256(shared_secret_compressed.as_bytes());
         H256::try_from(shared_secret_hash.as_slice()).expect("Expected 32 byte array sha256 hash")
     }
+
+    /// Computes an EC Diffie-Hellman secret in constant time and returns its x-coordinate.
+    pub fn shared_secret_x(&self, pubkey: &PublicKey) -> H256 {
+        let shared_secret = diffie_hellman(&self.secret, &pubkey.public);
+        H256::try_from(shared_secret.x().as_slice()).expect("Expected 32 byte x-coordinate")
+    }
+
+    /// Returns `(self + tweak) mod n`.
+    /// Fails if the `tweak` is not less than the curve order or the result is zero.
+    pub fn tweak_add(&self, tweak: H256) -> KeyPairResult<PrivateKey> {
+        let tweak: Option<Scalar> =
+            Scalar::from_repr(FieldBytes::clone_from_slice(tweak.as_slice())).into();
+        let tweak = tweak.ok_or(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
+
+        let secret = *self.secret.as_nonzero_scalar().as_ref() + tweak;
+        let secret: Option<NonZeroScalar> = NonZeroScalar::new(secret).into();
+        let secret = secret.ok_or(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
+        Ok(PrivateKey {
+            secret: SigningKey::from(secret),
+        })
+    }
 }
 
 /// This method is inspired by [elliptic_curve::ecdh::diffie_hellman](https://github.com/RustCrypto/traits/blob/f0dbe44fea56d4c17e625ababacb580fec842137/elliptic-curve/src/ecdh.rs#L60-L70)
@@ -56,7 +88,7 @@ impl SigningKeyTrait for PrivateKey {
         let (signature, recovery_id) = self
             .secret
             .sign_prehash_recoverable(message.as_slice())
-            .map_err(|_| KeyPairError::SigningError)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::SigningError))?;
         Ok(Signature::new(signature, recovery_id))
     }
 }
@@ -65,7 +97,8 @@ impl<'a> TryFrom<&'a [u8]> for PrivateKey {
     type Error = KeyPairError;
 
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        let secret = SigningKey::from_slice(data).map_err(|_| KeyPairError::InvalidSecretKey)?;
+        let secret = SigningKey::from_slice(data)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
         Ok(PrivateKey { secret })
     }
 }
@@ -74,7 +107,9 @@ impl<'a> TryFrom<&'a str> for PrivateKey {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
@@ -85,3 +120,5 @@ impl ToBytesZeroizing for PrivateKey {
         Zeroizing::new(secret.as_slice().to_vec())
     }
 }
+
+impl_constant_time_eq!(PrivateKey);
@@ -3,11 +3,14 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::ecdsa::secp256k1::{Signature, VerifySignature};
+use crate::ecdsa::spki;
 use crate::traits::VerifyingKeyTrait;
-use crate::{KeyPairError, KeyPairResult};
-use der::Document;
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
 use k256::ecdsa::signature::hazmat::PrehashVerifier;
 use k256::ecdsa::VerifyingKey;
+use k256::elliptic_curve::PrimeField;
+use k256::{FieldBytes, ProjectivePoint, Scalar};
 use tw_encoding::hex;
 use tw_hash::{Hash, H256, H264, H512, H520};
 use tw_misc::traits::ToBytesVec;
@@ -29,7 +32,7 @@ impl PublicKey {
     pub fn recover(sign: Signature, message: H256) -> KeyPairResult<Self> {
         VerifyingKey::recover_from_prehash(message.as_slice(), &sign.signature, sign.v)
             .map(|public| PublicKey { public })
-            .map_err(|_| KeyPairError::InvalidSignature)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))
     }
 
     /// Creates a public key from the given [`VerifyingKey`].
@@ -57,26 +60,29 @@ impl PublicKey {
         public
     }
 
-    /// Returns the public key as DER-encoded bytes.
+    /// Returns the public key as DER-encoded `SubjectPublicKeyInfo` bytes.
     pub fn der_encoded(&self) -> Vec<u8> {
-        let compressed = false;
-        let public_key_bytes = self.public.to_encoded_point(compressed);
-        let subject_public_key =
-            der::asn1::BitStringRef::new(0, public_key_bytes.as_bytes()).unwrap();
-
-        let oid: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
-        let associated_oid: pkcs8::ObjectIdentifier =
-            pkcs8::ObjectIdentifier::new_unwrap("1.3.132.0.10");
-        let spki = pkcs8::SubjectPublicKeyInfo {
-            algorithm: pkcs8::spki::AlgorithmIdentifier {
-                oid,
-                parameters: Some(associated_oid),
-            },
-            subject_public_key,
-        };
-
-        let doc = Document::try_from(&spki).unwrap();
-        doc.into_vec()
+        spki::encode_public_key(spki::SECP256K1_OID, self.uncompressed().as_slice())
+    }
+
+    /// Parses a public key from DER-encoded `SubjectPublicKeyInfo` bytes.
+    pub fn from_der_encoded(der_bytes: &[u8]) -> KeyPairResult<PublicKey> {
+        let sec1_bytes = spki::decode_public_key(der_bytes, spki::SECP256K1_OID)?;
+        PublicKey::try_from(sec1_bytes)
+    }
+
+    /// Returns `self + tweak * G`.
+    /// Fails if the `tweak` is not less than the curve order or the result is the point at infinity.
+    pub fn tweak_add(&self, tweak: H256) -> KeyPairResult<PublicKey> {
+        let tweak: Option<Scalar> =
+            Scalar::from_repr(FieldBytes::clone_from_slice(tweak.as_slice())).into();
+        let tweak = tweak.ok_or(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
+
+        let point =
+            ProjectivePoint::GENERATOR * tweak + ProjectivePoint::from(*self.public.as_affine());
+        VerifyingKey::from_affine(point.to_affine())
+            .map(PublicKey::new)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))
     }
 }
 
@@ -95,7 +101,8 @@ impl<'a> TryFrom<&'a str> for PublicKey {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = hex::decode(hex).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let bytes =
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Self::try_from(bytes.as_slice())
     }
 }
@@ -107,7 +114,7 @@ impl<'a> TryFrom<&'a [u8]> for PublicKey {
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
         Ok(PublicKey {
             public: VerifyingKey::from_sec1_bytes(data)
-                .map_err(|_| KeyPairError::InvalidPublicKey)?,
+                .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?,
         })
     }
 }
@@ -4,9 +4,10 @@
 
 use crate::ecdsa::der;
 use crate::ecdsa::EcdsaCurve;
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
+use core::ops::{Range, RangeInclusive};
 use ecdsa::elliptic_curve::FieldBytes;
-use std::ops::{Range, RangeInclusive};
 use tw_hash::{concat, H256, H512, H520};
 use tw_misc::traits::ToBytesVec;
 
@@ -58,11 +59,11 @@ impl<C: EcdsaCurve> Signature<C> {
     /// Tries to create a Signature from the serialized representation.
     pub fn from_bytes(sig: &[u8]) -> KeyPairResult<Self> {
         if sig.len() != Self::len() {
-            return Err(KeyPairError::InvalidSignature);
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSignature));
         }
 
         let v = ecdsa::RecoveryId::from_byte(sig[Self::RECOVERY_LAST])
-            .ok_or(KeyPairError::InvalidSignature)?;
+            .ok_or(KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
 
         Ok(Signature {
             signature: Self::signature_from_slices(&sig[Self::R_RANGE], &sig[Self::S_RANGE])?,
@@ -74,7 +75,8 @@ impl<C: EcdsaCurve> Signature<C> {
     pub fn try_from_parts(r: H256, s: H256, v: u8) -> KeyPairResult<Self> {
         Ok(Signature {
             signature: Self::signature_from_slices(r.as_slice(), s.as_slice())?,
-            v: ecdsa::RecoveryId::from_byte(v).ok_or(KeyPairError::InvalidSignature)?,
+            v: ecdsa::RecoveryId::from_byte(v)
+                .ok_or(KeyPairError::new(KeyPairErrorKind::InvalidSignature))?,
         })
     }
 
@@ -105,7 +107,8 @@ impl<C: EcdsaCurve> Signature<C> {
         let r = FieldBytes::<C>::clone_from_slice(r);
         let s = FieldBytes::<C>::clone_from_slice(s);
 
-        ecdsa::Signature::<C>::from_scalars(r, s).map_err(|_| KeyPairError::InvalidSignature)
+        ecdsa::Signature::<C>::from_scalars(r, s)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))
     }
 }
 
@@ -123,6 +126,8 @@ impl<'a, C: EcdsaCurve> TryFrom<&'a [u8]> for Signature<C> {
     }
 }
 
+impl_serde_as_hex!(impl<C: EcdsaCurve> Signature<C>);
+
 /// To verify the signature, it's enough to check `r` and `s` parts without the recovery ID.
 pub struct VerifySignature<C: EcdsaCurve> {
     pub(crate) signature: ecdsa::Signature<C>,
@@ -134,7 +139,7 @@ impl<C: EcdsaCurve> VerifySignature<C> {
             der_signature.r().as_slice(),
             der_signature.s().as_slice(),
         )
-        .map_err(|_| KeyPairError::InvalidSignature)?;
+        .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
         Ok(VerifySignature { signature })
     }
 
@@ -176,7 +181,7 @@ impl<'a, C: EcdsaCurve> TryFrom<&'a [u8]> for VerifySignature<C> {
 
     fn try_from(sig: &'a [u8]) -> Result<Self, Self::Error> {
         if !Signature::<C>::VERIFY_SIGNATURE_LEN_RANGE.contains(&sig.len()) {
-            return Err(KeyPairError::InvalidSignature);
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSignature));
         }
 
         Ok(VerifySignature {
@@ -188,6 +193,8 @@ impl<'a, C: EcdsaCurve> TryFrom<&'a [u8]> for VerifySignature<C> {
     }
 }
 
+impl_serde_as_hex!(impl<C: EcdsaCurve> VerifySignature<C>);
+
 impl<C: EcdsaCurve> From<Signature<C>> for VerifySignature<C> {
     fn from(sig: Signature<C>) -> Self {
         VerifySignature {
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
+use der::asn1::BitStringRef;
+use der::{Decode, Document};
+use pkcs8::spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
+use pkcs8::ObjectIdentifier;
+
+/// `id-ecPublicKey` algorithm identifier as specified in [RFC5480 Section 2.1.1].
+///
+/// [RFC5480 Section 2.1.1]: https://www.rfc-editor.org/rfc/rfc5480#section-2.1.1
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+/// `secp256k1` named curve identifier.
+pub(crate) const SECP256K1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.10");
+/// `prime256v1` (aka `nist256p1`, `secp256r1`) named curve identifier.
+pub(crate) const NIST256P1_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+/// An elliptic curve `SubjectPublicKeyInfo` with the named curve parameter:
+///
+/// ```text
+/// SubjectPublicKeyInfo  ::=  SEQUENCE  {
+///   algorithm         AlgorithmIdentifier,
+///   subjectPublicKey  BIT STRING
+/// }
+/// ```
+type EcSubjectPublicKeyInfo<'a> = SubjectPublicKeyInfo<ObjectIdentifier, BitStringRef<'a>>;
+
+/// Wraps the given SEC1-encoded public key into a DER-encoded `SubjectPublicKeyInfo`.
+pub(crate) fn encode_public_key(curve_oid: ObjectIdentifier, sec1_bytes: &[u8]) -> Vec<u8> {
+    let subject_public_key =
+        BitStringRef::new(0, sec1_bytes).expect("SEC1 public key is a valid bit string");
+
+    let spki = EcSubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: EC_PUBLIC_KEY_OID,
+            parameters: Some(curve_oid),
+        },
+        subject_public_key,
+    };
+
+    Document::try_from(&spki)
+        .expect("SubjectPublicKeyInfo is expected to be encoded successfully")
+        .into_vec()
+}
+
+/// Parses a DER-encoded `SubjectPublicKeyInfo` and returns the SEC1-encoded public key.
+/// Returns an error if the algorithm is not `id-ecPublicKey` or the curve doesn't match `curve_oid`.
+pub(crate) fn decode_public_key(
+    der_bytes: &[u8],
+    curve_oid: ObjectIdentifier,
+) -> KeyPairResult<&[u8]> {
+    let spki = EcSubjectPublicKeyInfo::from_der(der_bytes)
+        .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
+
+    if spki.algorithm.oid != EC_PUBLIC_KEY_OID || spki.algorithm.parameters != Some(curve_oid) {
+        return Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey));
+    }
+
+    spki.subject_public_key
+        .as_bytes()
+        .ok_or(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))
+}
@@ -4,7 +4,8 @@
 
 use crate::ed25519::{private::PrivateKey, public::PublicKey, signature::Signature, Hasher512};
 use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
 use tw_encoding::hex;
 use zeroize::Zeroizing;
 
@@ -60,7 +61,9 @@ impl<'a, H: Hasher512> TryFrom<&'a str> for KeyPair<H> {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
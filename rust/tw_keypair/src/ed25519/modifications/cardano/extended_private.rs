@@ -9,9 +9,12 @@ use crate::ed25519::public::PublicKey;
 use crate::ed25519::secret::ExpandedSecretKey;
 use crate::ed25519::signature::Signature;
 use crate::ed25519::Hasher512;
+use crate::redact::fmt_redacted;
 use crate::traits::SigningKeyTrait;
-use crate::{KeyPairError, KeyPairResult};
-use std::ops::Range;
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
 use tw_encoding::hex;
 use tw_hash::H256;
 use tw_misc::traits::ToBytesZeroizing;
@@ -25,6 +28,12 @@ pub struct ExtendedPrivateKey<H: Hasher512> {
     second_key: ExtendedSecretPart<H>,
 }
 
+impl<H: Hasher512> fmt::Debug for ExtendedPrivateKey<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_redacted(f, "ExtendedPrivateKey", &self.to_zeroizing_vec())
+    }
+}
+
 /// cbindgen:ignore
 impl<H: Hasher512> ExtendedPrivateKey<H> {
     /// The number of bytes in a serialized private key (192 bytes).
@@ -64,12 +73,14 @@ impl<H: Hasher512> ToBytesZeroizing for ExtendedPrivateKey<H> {
     }
 }
 
+impl_constant_time_eq!(impl<H: Hasher512> ExtendedPrivateKey<H>);
+
 impl<'a, H: Hasher512> TryFrom<&'a [u8]> for ExtendedPrivateKey<H> {
     type Error = KeyPairError;
 
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
         if bytes.len() != Self::LEN {
-            return Err(KeyPairError::InvalidSecretKey);
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey));
         }
         let key = ExtendedSecretPart::try_from(&bytes[Self::KEY_RANGE])?;
         let second_key = ExtendedSecretPart::try_from(&bytes[Self::SECOND_KEY_RANGE])?;
@@ -82,7 +93,9 @@ impl<'a, H: Hasher512> TryFrom<&'a str> for ExtendedPrivateKey<H> {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
@@ -121,14 +134,14 @@ impl<'a, H: Hasher512> TryFrom<&'a [u8]> for ExtendedSecretPart<H> {
 
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
         if bytes.len() != Self::LEN {
-            return Err(KeyPairError::InvalidSecretKey);
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey));
         }
         let secret = H256::try_from(&bytes[Self::SECRET_RANGE])
-            .map_err(|_| KeyPairError::InvalidSecretKey)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
         let extension = H256::try_from(&bytes[Self::EXTENSION_RANGE])
-            .map_err(|_| KeyPairError::InvalidSecretKey)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
         let chain_code = H256::try_from(&bytes[Self::CHAIN_CODE_RANGE])
-            .map_err(|_| KeyPairError::InvalidSecretKey)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
 
         let expanded_key = ExpandedSecretKey::with_extended_secret(secret, extension);
         Ok(ExtendedSecretPart {
@@ -6,8 +6,9 @@ use crate::ed25519::public::PublicKey;
 use crate::ed25519::signature::Signature;
 use crate::ed25519::Hasher512;
 use crate::traits::VerifyingKeyTrait;
-use crate::KeyPairError;
-use std::ops::Range;
+use crate::{KeyPairError, KeyPairErrorKind};
+use alloc::vec::Vec;
+use core::ops::Range;
 use tw_encoding::hex;
 use tw_hash::H256;
 use tw_misc::traits::ToBytesVec;
@@ -61,7 +62,7 @@ impl<'a, H: Hasher512> TryFrom<&'a [u8]> for ExtendedPublicKey<H> {
 
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
         if bytes.len() != Self::LEN {
-            return Err(KeyPairError::InvalidPublicKey);
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey));
         }
 
         let key = ExtendedPublicPart::try_from(&bytes[Self::KEY_RANGE])?;
@@ -75,7 +76,8 @@ impl<'a, H: Hasher512> TryFrom<&'a str> for ExtendedPublicKey<H> {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = hex::decode(hex).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let bytes =
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Self::try_from(bytes.as_slice())
     }
 }
@@ -102,12 +104,12 @@ impl<'a, H: Hasher512> TryFrom<&'a [u8]> for ExtendedPublicPart<H> {
 
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
         if bytes.len() != Self::LEN {
-            return Err(KeyPairError::InvalidPublicKey);
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey));
         }
 
         let public = PublicKey::try_from(&bytes[Self::PUBLIC_RANGE])?;
         let chain_code = H256::try_from(&bytes[Self::CHAIN_CODE_RANGE])
-            .map_err(|_| KeyPairError::InvalidPublicKey)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
 
         Ok(ExtendedPublicPart { public, chain_code })
     }
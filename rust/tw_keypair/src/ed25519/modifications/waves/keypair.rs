@@ -7,7 +7,8 @@ use crate::ed25519::modifications::waves::public::PublicKey;
 use crate::ed25519::modifications::waves::Signature;
 use crate::ed25519::Hasher512;
 use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
 use tw_encoding::hex;
 use zeroize::Zeroizing;
 
@@ -62,7 +63,9 @@ impl<'a, H: Hasher512> TryFrom<&'a str> for KeyPair<H> {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidPublicKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
@@ -5,8 +5,11 @@
 use crate::ed25519::modifications::waves::public::PublicKey;
 use crate::ed25519::modifications::waves::signature::Signature;
 use crate::ed25519::{private::PrivateKey as StandardPrivateKey, Hasher512};
+use crate::redact::fmt_redacted;
 use crate::traits::SigningKeyTrait;
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
+use core::fmt;
 use tw_encoding::hex;
 use tw_misc::traits::ToBytesZeroizing;
 use zeroize::Zeroizing;
@@ -16,6 +19,12 @@ pub struct PrivateKey<H: Hasher512> {
     standard_key: StandardPrivateKey<H>,
 }
 
+impl<H: Hasher512> fmt::Debug for PrivateKey<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_redacted(f, "PrivateKey", &self.to_zeroizing_vec())
+    }
+}
+
 impl<H: Hasher512> PrivateKey<H> {
     /// Returns an associated Waves `ed25519` public key.
     pub fn public(&self) -> PublicKey<H> {
@@ -47,7 +56,9 @@ impl<'a, H: Hasher512> TryFrom<&'a str> for PrivateKey<H> {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
@@ -57,3 +68,5 @@ impl<H: Hasher512> ToBytesZeroizing for PrivateKey<H> {
         self.standard_key.to_zeroizing_vec()
     }
 }
+
+impl_constant_time_eq!(impl<H: Hasher512> PrivateKey<H>);
@@ -6,9 +6,10 @@ use crate::ed25519::modifications::waves::signature::Signature;
 use crate::ed25519::public::PublicKey as StandardPublicKey;
 use crate::ed25519::Hasher512;
 use crate::traits::VerifyingKeyTrait;
-use crate::KeyPairError;
+use crate::{KeyPairError, KeyPairErrorKind};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 use curve25519_dalek::montgomery::MontgomeryPoint;
-use std::marker::PhantomData;
 use tw_encoding::hex;
 use tw_hash::H256;
 use tw_misc::traits::ToBytesVec;
@@ -67,7 +68,8 @@ impl<'a, H: Hasher512> TryFrom<&'a [u8]> for PublicKey<H> {
     type Error = KeyPairError;
 
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
-        let curve25519_pk = H256::try_from(bytes).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let curve25519_pk = H256::try_from(bytes)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Ok(PublicKey {
             curve25519_pk,
             _phantom: PhantomData,
@@ -79,7 +81,8 @@ impl<'a, H: Hasher512> TryFrom<&'a str> for PublicKey<H> {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = hex::decode(hex).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let bytes =
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Self::try_from(bytes.as_slice())
     }
 }
@@ -5,7 +5,8 @@
 use crate::ed25519::public::PublicKey as StandardPublicKey;
 use crate::ed25519::signature::Signature as StandardSignature;
 use crate::ed25519::Hasher512;
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
 use tw_hash::{H256, H512};
 use tw_misc::traits::ToBytesVec;
 
@@ -68,11 +69,14 @@ impl<'a> TryFrom<&'a [u8]> for Signature {
     type Error = KeyPairError;
 
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
-        let bytes = H512::try_from(bytes).map_err(|_| KeyPairError::InvalidSignature)?;
+        let bytes = H512::try_from(bytes)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
         Ok(Signature { bytes })
     }
 }
 
+impl_serde_as_hex!(Signature);
+
 impl ToBytesVec for Signature {
     fn to_vec(&self) -> Vec<u8> {
         self.bytes.into_vec()
@@ -6,9 +6,11 @@ use crate::ed25519::public::PublicKey;
 use crate::ed25519::secret::ExpandedSecretKey;
 use crate::ed25519::signature::Signature;
 use crate::ed25519::Hasher512;
+use crate::redact::fmt_redacted;
 use crate::traits::SigningKeyTrait;
-use crate::{KeyPairError, KeyPairResult};
-use std::fmt;
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
+use core::fmt;
 use tw_encoding::hex;
 use tw_hash::H256;
 use tw_misc::traits::ToBytesZeroizing;
@@ -25,9 +27,7 @@ pub struct PrivateKey<H: Hasher512> {
 
 impl<H: Hasher512> fmt::Debug for PrivateKey<H> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("PrivateKey")
-            .field("secret", &self.secret)
-            .finish()
+        fmt_redacted(f, "PrivateKey", self.secret.as_slice())
     }
 }
 
@@ -52,7 +52,8 @@ impl<H: Hasher512> TryFrom<&[u8]> for PrivateKey<H> {
     type Error = KeyPairError;
 
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
-        let secret = H256::try_from(data).map_err(|_| KeyPairError::InvalidSecretKey)?;
+        let secret = H256::try_from(data)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
         let expanded_key = ExpandedSecretKey::<H>::with_secret(secret);
         Ok(PrivateKey {
             secret,
@@ -65,7 +66,9 @@ impl<'a, H: Hasher512> TryFrom<&'a str> for PrivateKey<H> {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
@@ -75,3 +78,5 @@ impl<H: Hasher512> ToBytesZeroizing for PrivateKey<H> {
         Zeroizing::new(self.secret.to_vec())
     }
 }
+
+impl_constant_time_eq!(impl<H: Hasher512> PrivateKey<H>);
@@ -7,12 +7,13 @@ use crate::ed25519::secret::ExpandedSecretKey;
 use crate::ed25519::signature::Signature;
 use crate::ed25519::Hasher512;
 use crate::traits::VerifyingKeyTrait;
-use crate::KeyPairError;
+use crate::{KeyPairError, KeyPairErrorKind};
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
 use curve25519_dalek::constants;
 use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
 use curve25519_dalek::scalar::Scalar;
-use std::fmt;
-use std::marker::PhantomData;
 use tw_encoding::hex;
 use tw_hash::H256;
 use tw_misc::traits::ToBytesVec;
@@ -143,12 +144,13 @@ impl<'a, H: Hasher512> TryFrom<&'a [u8]> for PublicKey<H> {
 
     /// Inspired by: https://github.com/dalek-cryptography/ed25519-dalek/blob/1.0.1/src/public.rs#L92-L145
     fn try_from(pubkey: &'a [u8]) -> Result<Self, Self::Error> {
-        let pubkey = H256::try_from(pubkey).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let pubkey = H256::try_from(pubkey)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
 
         let compressed = CompressedEdwardsY(pubkey.take());
         let point = compressed
             .decompress()
-            .ok_or(KeyPairError::InvalidPublicKey)?;
+            .ok_or(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
 
         Ok(PublicKey {
             compressed,
@@ -162,7 +164,8 @@ impl<'a, H: Hasher512> TryFrom<&'a str> for PublicKey<H> {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = hex::decode(hex).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let bytes =
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Self::try_from(bytes.as_slice())
     }
 }
@@ -6,10 +6,10 @@ use crate::ed25519::mangle::mangle_scalar;
 use crate::ed25519::signature::Signature;
 use crate::ed25519::Hasher512;
 use crate::KeyPairResult;
+use core::marker::PhantomData;
+use core::ops::DerefMut;
 use curve25519_dalek::constants;
 use curve25519_dalek::scalar::Scalar;
-use std::marker::PhantomData;
-use std::ops::DerefMut;
 use tw_hash::{H256, H512};
 use zeroize::ZeroizeOnDrop;
 
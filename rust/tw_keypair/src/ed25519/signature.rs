@@ -2,7 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use alloc::vec::Vec;
 use curve25519_dalek::edwards::CompressedEdwardsY;
 use curve25519_dalek::scalar::Scalar;
 use tw_hash::{concat, H256, H512};
@@ -60,7 +61,8 @@ impl<'a> TryFrom<&'a [u8]> for Signature {
     /// Construct a `Signature` from a slice of bytes.
     /// Source: https://github.com/dalek-cryptography/ed25519-dalek/blob/1.0.1/src/signature.rs#L115-L190
     fn try_from(sign: &'a [u8]) -> Result<Self, Self::Error> {
-        let bytes = H512::try_from(sign).map_err(|_| KeyPairError::InvalidSignature)?;
+        let bytes = H512::try_from(sign)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
 
         let (lower, upper): (H256, H256) = bytes.split();
 
@@ -72,6 +74,8 @@ impl<'a> TryFrom<&'a [u8]> for Signature {
     }
 }
 
+impl_serde_as_hex!(Signature);
+
 /// Source: https://github.com/dalek-cryptography/ed25519-dalek/blob/1.0.1/src/signature.rs#L83-L102
 fn get_scalar(bytes: H256) -> KeyPairResult<Scalar> {
     /// Equals to 240 decimal.
@@ -93,6 +97,6 @@ fn get_scalar(bytes: H256) -> KeyPairResult<Scalar> {
 
     match Scalar::from_canonical_bytes(bytes.take()).into() {
         Some(x) => Ok(x),
-        None => Err(KeyPairError::InvalidSignature),
+        None => Err(KeyPairError::new(KeyPairErrorKind::InvalidSignature)),
     }
 }
@@ -0,0 +1,272 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! A versioned password-protected backup container, shared by all platforms.
+//!
+//! The encryption key is derived from the password with Argon2id,
+//! and the payload is encrypted with XChaCha20-Poly1305.
+//! Wallet metadata is stored in plaintext, but it's authenticated along with the whole header,
+//! so neither the metadata nor the KDF parameters can be altered without the password.
+//!
+//! Layout of the encoded container, integers are little-endian:
+//! ```text
+//! magic "TWEB" (4) | version (1) | KDF (1) | memory KiB (4) | iterations (4) | parallelism (4)
+//! | salt (16) | nonce (24) | metadata length (4) | metadata | ciphertext with the Poly1305 tag
+//! ```
+
+use crate::rand::OsRng;
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use rand_core::RngCore;
+use tw_memory::Data;
+use zeroize::Zeroizing;
+
+pub const ENCRYPTED_BACKUP_VERSION: u8 = 1;
+pub const KDF_ARGON2ID: u8 = 1;
+
+const MAGIC: &[u8; 4] = b"TWEB";
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const TAG_LEN: usize = 16;
+const HEADER_LEN: usize = 4 + 1 + 1 + 4 + 4 + 4 + SALT_LEN + NONCE_LEN + 4;
+const MAX_METADATA_LEN: usize = 64 * 1024;
+
+/// The lowest Argon2id parameters recommended by OWASP: 19 MiB of memory and 2 iterations.
+/// Backups with lower parameters are rejected to prevent a downgrade.
+pub const MIN_MEMORY_KIB: u32 = 19 * 1024;
+pub const MIN_ITERATIONS: u32 = 2;
+/// Upper bounds prevent a denial of service with a crafted backup.
+pub const MAX_MEMORY_KIB: u32 = 1024 * 1024;
+pub const MAX_ITERATIONS: u32 = 16;
+pub const MAX_PARALLELISM: u32 = 16;
+
+/// Argon2id cost parameters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BackupKdfParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for BackupKdfParams {
+    /// The second recommended option of RFC 9106: 64 MiB of memory, 3 iterations and 4 lanes.
+    fn default() -> Self {
+        BackupKdfParams {
+            memory_kib: 64 * 1024,
+            iterations: 3,
+            parallelism: 4,
+        }
+    }
+}
+
+impl BackupKdfParams {
+    fn validate(&self) -> KeyPairResult<()> {
+        let memory_valid = (MIN_MEMORY_KIB..=MAX_MEMORY_KIB).contains(&self.memory_kib);
+        let iterations_valid = (MIN_ITERATIONS..=MAX_ITERATIONS).contains(&self.iterations);
+        let parallelism_valid = (1..=MAX_PARALLELISM).contains(&self.parallelism);
+        if memory_valid && iterations_valid && parallelism_valid {
+            Ok(())
+        } else {
+            Err(KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage))
+        }
+    }
+
+    fn derive_key(&self, password: &[u8], salt: &[u8]) -> KeyPairResult<Zeroizing<[u8; KEY_LEN]>> {
+        let params = Params::new(
+            self.memory_kib,
+            self.iterations,
+            self.parallelism,
+            Some(KEY_LEN),
+        )
+        .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage))?;
+
+        let mut key = Zeroizing::new([0; KEY_LEN]);
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(password, salt, key.as_mut())
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InternalError))?;
+        Ok(key)
+    }
+}
+
+/// A decoded encrypted backup container.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EncryptedBackup {
+    params: BackupKdfParams,
+    salt: [u8; SALT_LEN],
+    nonce: [u8; NONCE_LEN],
+    metadata: Data,
+    ciphertext: Data,
+}
+
+impl EncryptedBackup {
+    /// Encrypts the `payload` (e.g. StoredKey JSON) with a key derived from the `password`.
+    /// `metadata` is kept in plaintext, but authenticated.
+    pub fn create(
+        payload: &[u8],
+        password: &[u8],
+        metadata: &[u8],
+        params: BackupKdfParams,
+    ) -> KeyPairResult<EncryptedBackup> {
+        params.validate()?;
+        if metadata.len() > MAX_METADATA_LEN {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage));
+        }
+
+        let mut salt = [0; SALT_LEN];
+        let mut nonce = [0; NONCE_LEN];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+
+        let mut backup = EncryptedBackup {
+            params,
+            salt,
+            nonce,
+            metadata: metadata.to_vec(),
+            ciphertext: Data::default(),
+        };
+
+        let key = params.derive_key(password, &salt)?;
+        let aad = backup.header();
+        backup.ciphertext = XChaCha20Poly1305::new_from_slice(key.as_slice())
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InternalError))?
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: payload,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InternalError))?;
+        Ok(backup)
+    }
+
+    /// Decrypts the payload.
+    /// Returns [`KeyPairErrorKind::InvalidEncryptedMessage`] if the password is wrong or the backup has been altered.
+    pub fn open(&self, password: &[u8]) -> KeyPairResult<Zeroizing<Data>> {
+        self.params.validate()?;
+        let key = self.params.derive_key(password, &self.salt)?;
+        let aad = self.header();
+
+        XChaCha20Poly1305::new_from_slice(key.as_slice())
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InternalError))?
+            .decrypt(
+                XNonce::from_slice(&self.nonce),
+                Payload {
+                    msg: &self.ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage))
+    }
+
+    /// Returns the authenticated plaintext metadata.
+    /// Note the metadata can be trusted only after the backup has been opened successfully.
+    pub fn metadata(&self) -> &[u8] {
+        &self.metadata
+    }
+
+    pub fn params(&self) -> BackupKdfParams {
+        self.params
+    }
+
+    pub fn decode(encoded: &[u8]) -> KeyPairResult<EncryptedBackup> {
+        if encoded.len() < HEADER_LEN + TAG_LEN || &encoded[..MAGIC.len()] != MAGIC {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage));
+        }
+
+        let mut reader = Reader {
+            data: encoded,
+            pos: MAGIC.len(),
+        };
+        if reader.read_u8()? != ENCRYPTED_BACKUP_VERSION || reader.read_u8()? != KDF_ARGON2ID {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage));
+        }
+
+        let params = BackupKdfParams {
+            memory_kib: reader.read_u32()?,
+            iterations: reader.read_u32()?,
+            parallelism: reader.read_u32()?,
+        };
+        params.validate()?;
+
+        let salt = reader.read_array()?;
+        let nonce = reader.read_array()?;
+        let metadata_len = reader.read_u32()? as usize;
+        if metadata_len > MAX_METADATA_LEN {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage));
+        }
+        let metadata = reader.read_slice(metadata_len)?.to_vec();
+
+        let ciphertext = reader.read_slice(encoded.len() - reader.pos)?.to_vec();
+        if ciphertext.len() < TAG_LEN {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage));
+        }
+
+        Ok(EncryptedBackup {
+            params,
+            salt,
+            nonce,
+            metadata,
+            ciphertext,
+        })
+    }
+
+    pub fn encode(&self) -> Data {
+        let mut encoded = self.header();
+        encoded.extend_from_slice(&self.ciphertext);
+        encoded
+    }
+
+    /// Returns everything but the ciphertext. Used as the associated data.
+    fn header(&self) -> Data {
+        let mut header = Vec::with_capacity(HEADER_LEN + self.metadata.len());
+        header.extend_from_slice(MAGIC);
+        header.push(ENCRYPTED_BACKUP_VERSION);
+        header.push(KDF_ARGON2ID);
+        header.extend_from_slice(&self.params.memory_kib.to_le_bytes());
+        header.extend_from_slice(&self.params.iterations.to_le_bytes());
+        header.extend_from_slice(&self.params.parallelism.to_le_bytes());
+        header.extend_from_slice(&self.salt);
+        header.extend_from_slice(&self.nonce);
+        header.extend_from_slice(&(self.metadata.len() as u32).to_le_bytes());
+        header.extend_from_slice(&self.metadata);
+        header
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_slice(&mut self, len: usize) -> KeyPairResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage))?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> KeyPairResult<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_slice(N)?);
+        Ok(array)
+    }
+
+    fn read_u8(&mut self) -> KeyPairResult<u8> {
+        self.read_array::<1>().map(|[byte]| byte)
+    }
+
+    fn read_u32(&mut self) -> KeyPairResult<u32> {
+        self.read_array().map(u32::from_le_bytes)
+    }
+}
@@ -0,0 +1,330 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::tw::{Curve, PublicKeyType};
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt;
+use tw_memory::ffi::c_result::ErrorCode;
+
+pub type KeyPairResult<T> = Result<T, KeyPairError>;
+
+/// Keypair error kind.
+///
+/// The discriminants are stable error codes exposed through the C FFI.
+/// Never reorder or reuse them, append new variants with a new code instead.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyPairErrorKind {
+    InvalidSecretKey = 1,
+    InvalidPublicKey = 2,
+    InvalidSignature = 3,
+    InvalidSignMessage = 4,
+    InvalidEncryptedMessage = 5,
+    SignatureVerifyError = 6,
+    SigningError = 7,
+    InternalError = 8,
+    NonExportableKey = 9,
+    UnknownVaultHandle = 10,
+    InvalidCurve = 11,
+}
+
+impl KeyPairErrorKind {
+    /// Returns the stable numeric code of the error.
+    pub fn code(self) -> ErrorCode {
+        self as ErrorCode
+    }
+
+    /// Returns the error kind by the given stable numeric `code`.
+    pub fn from_code(code: ErrorCode) -> Option<KeyPairErrorKind> {
+        match code {
+            1 => Some(KeyPairErrorKind::InvalidSecretKey),
+            2 => Some(KeyPairErrorKind::InvalidPublicKey),
+            3 => Some(KeyPairErrorKind::InvalidSignature),
+            4 => Some(KeyPairErrorKind::InvalidSignMessage),
+            5 => Some(KeyPairErrorKind::InvalidEncryptedMessage),
+            6 => Some(KeyPairErrorKind::SignatureVerifyError),
+            7 => Some(KeyPairErrorKind::SigningError),
+            8 => Some(KeyPairErrorKind::InternalError),
+            9 => Some(KeyPairErrorKind::NonExportableKey),
+            10 => Some(KeyPairErrorKind::UnknownVaultHandle),
+            11 => Some(KeyPairErrorKind::InvalidCurve),
+            _ => None,
+        }
+    }
+
+    /// Returns a short human-readable description of the error kind.
+    pub fn description(self) -> &'static str {
+        match self {
+            KeyPairErrorKind::InvalidSecretKey => "Invalid private key",
+            KeyPairErrorKind::InvalidPublicKey => "Invalid public key",
+            KeyPairErrorKind::InvalidSignature => "Invalid signature",
+            KeyPairErrorKind::InvalidSignMessage => "Invalid message to sign",
+            KeyPairErrorKind::InvalidEncryptedMessage => "Invalid encrypted message",
+            KeyPairErrorKind::SignatureVerifyError => "Signature verification failed",
+            KeyPairErrorKind::SigningError => "Signing failed",
+            KeyPairErrorKind::InternalError => "Internal error",
+            KeyPairErrorKind::NonExportableKey => "Private key export is forbidden",
+            KeyPairErrorKind::UnknownVaultHandle => "Unknown or removed key vault handle",
+            KeyPairErrorKind::InvalidCurve => "Unknown elliptic curve",
+        }
+    }
+}
+
+impl fmt::Display for KeyPairErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl From<KeyPairErrorKind> for ErrorCode {
+    fn from(kind: KeyPairErrorKind) -> Self {
+        kind.code()
+    }
+}
+
+/// Keypair error: the [`KeyPairErrorKind`] and the context it occurred in,
+/// such as the curve or public key type, the invalid field, expected vs actual length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyPairError {
+    kind: KeyPairErrorKind,
+    curve: Option<Curve>,
+    public_key_type: Option<PublicKeyType>,
+    field: Option<&'static str>,
+    expected_len: Option<usize>,
+    actual_len: Option<usize>,
+}
+
+impl KeyPairError {
+    pub const fn new(kind: KeyPairErrorKind) -> KeyPairError {
+        KeyPairError {
+            kind,
+            curve: None,
+            public_key_type: None,
+            field: None,
+            expected_len: None,
+            actual_len: None,
+        }
+    }
+
+    pub fn with_curve(mut self, curve: Curve) -> Self {
+        self.curve = Some(curve);
+        self
+    }
+
+    pub fn with_public_key_type(mut self, ty: PublicKeyType) -> Self {
+        self.public_key_type = Some(ty);
+        self
+    }
+
+    pub fn with_field(mut self, field: &'static str) -> Self {
+        self.field = Some(field);
+        self
+    }
+
+    pub fn with_expected_len(mut self, expected_len: usize) -> Self {
+        self.expected_len = Some(expected_len);
+        self
+    }
+
+    pub fn with_actual_len(mut self, actual_len: usize) -> Self {
+        self.actual_len = Some(actual_len);
+        self
+    }
+
+    pub fn kind(&self) -> KeyPairErrorKind {
+        self.kind
+    }
+
+    /// Returns the stable numeric code of the error.
+    pub fn code(&self) -> ErrorCode {
+        self.kind.code()
+    }
+
+    pub fn curve(&self) -> Option<Curve> {
+        self.curve
+    }
+
+    pub fn public_key_type(&self) -> Option<PublicKeyType> {
+        self.public_key_type
+    }
+
+    pub fn field(&self) -> Option<&'static str> {
+        self.field
+    }
+
+    pub fn expected_len(&self) -> Option<usize> {
+        self.expected_len
+    }
+
+    pub fn actual_len(&self) -> Option<usize> {
+        self.actual_len
+    }
+
+    /// Whether the error carries a context besides its kind.
+    pub fn has_context(&self) -> bool {
+        *self != KeyPairError::new(self.kind)
+    }
+}
+
+impl From<KeyPairErrorKind> for KeyPairError {
+    fn from(kind: KeyPairErrorKind) -> Self {
+        KeyPairError::new(kind)
+    }
+}
+
+impl From<KeyPairError> for ErrorCode {
+    fn from(e: KeyPairError) -> Self {
+        e.code()
+    }
+}
+
+impl fmt::Display for KeyPairError {
+    /// Formats the error as `Invalid public key: type=Secp256k1, field=public key, expected 33 bytes, got 32 bytes`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut details = Vec::new();
+        if let Some(curve) = self.curve {
+            details.push(format!("curve={curve:?}"));
+        }
+        if let Some(ty) = self.public_key_type {
+            details.push(format!("type={ty:?}"));
+        }
+        if let Some(field) = self.field {
+            details.push(format!("field={field}"));
+        }
+        if let Some(expected_len) = self.expected_len {
+            details.push(format!("expected {expected_len} bytes"));
+        }
+        if let Some(actual_len) = self.actual_len {
+            details.push(format!("got {actual_len} bytes"));
+        }
+
+        if details.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.kind, details.join(", "))
+        }
+    }
+}
+
+/// Helps to attach a context to an error result.
+pub trait KeyPairErrorContext<T> {
+    fn with_curve(self, curve: Curve) -> KeyPairResult<T>;
+
+    fn with_field(self, field: &'static str) -> KeyPairResult<T>;
+}
+
+impl<T, E> KeyPairErrorContext<T> for Result<T, E>
+where
+    E: Into<KeyPairError>,
+{
+    fn with_curve(self, curve: Curve) -> KeyPairResult<T> {
+        self.map_err(|e| e.into().with_curve(curve))
+    }
+
+    fn with_field(self, field: &'static str) -> KeyPairResult<T> {
+        self.map_err(|e| e.into().with_field(field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tw::{PrivateKey, PublicKey};
+
+    #[test]
+    fn test_error_codes_stable() {
+        assert_eq!(KeyPairErrorKind::InvalidSecretKey.code(), 1);
+        assert_eq!(KeyPairErrorKind::InvalidPublicKey.code(), 2);
+        assert_eq!(KeyPairErrorKind::InvalidSignature.code(), 3);
+        assert_eq!(KeyPairErrorKind::InvalidSignMessage.code(), 4);
+        assert_eq!(KeyPairErrorKind::InvalidEncryptedMessage.code(), 5);
+        assert_eq!(KeyPairErrorKind::SignatureVerifyError.code(), 6);
+        assert_eq!(KeyPairErrorKind::SigningError.code(), 7);
+        assert_eq!(KeyPairErrorKind::InternalError.code(), 8);
+        assert_eq!(KeyPairErrorKind::NonExportableKey.code(), 9);
+        assert_eq!(KeyPairErrorKind::UnknownVaultHandle.code(), 10);
+        assert_eq!(KeyPairErrorKind::InvalidCurve.code(), 11);
+
+        for code in 1..=11 {
+            assert_eq!(KeyPairErrorKind::from_code(code).unwrap().code(), code);
+        }
+        assert_eq!(KeyPairErrorKind::from_code(0), None);
+        assert_eq!(KeyPairErrorKind::from_code(12), None);
+    }
+
+    #[test]
+    fn test_error_display() {
+        let error = KeyPairError::new(KeyPairErrorKind::InvalidSignMessage)
+            .with_curve(Curve::Secp256k1)
+            .with_field("message")
+            .with_expected_len(32)
+            .with_actual_len(31);
+        assert_eq!(
+            error.to_string(),
+            "Invalid message to sign: curve=Secp256k1, field=message, expected 32 bytes, got 31 bytes"
+        );
+        assert_eq!(error.code(), 4);
+        assert_eq!(error.kind(), KeyPairErrorKind::InvalidSignMessage);
+        assert!(error.has_context());
+
+        let error = KeyPairError::from(KeyPairErrorKind::SigningError);
+        assert_eq!(error.to_string(), "Signing failed");
+        assert!(!error.has_context());
+    }
+
+    #[test]
+    fn test_private_key_sign_context() {
+        let private = PrivateKey::new(
+            tw_encoding::hex::decode(
+                "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        let error = private.sign(&[1; 31], Curve::Secp256k1).unwrap_err();
+        assert_eq!(error.kind(), KeyPairErrorKind::InvalidSignMessage);
+        assert_eq!(error.curve(), Some(Curve::Secp256k1));
+        assert_eq!(error.field(), Some("message"));
+        assert_eq!(error.expected_len(), Some(32));
+        assert_eq!(error.actual_len(), Some(31));
+
+        // Cardano extended private key is expected.
+        let error = private
+            .sign(&[1; 32], Curve::Ed25519ExtendedCardano)
+            .unwrap_err();
+        assert_eq!(error.kind(), KeyPairErrorKind::InvalidSecretKey);
+        assert_eq!(error.curve(), Some(Curve::Ed25519ExtendedCardano));
+        assert_eq!(error.field(), Some("private key"));
+    }
+
+    #[test]
+    fn test_private_key_new_context() {
+        let error = PrivateKey::new(vec![1; 31]).err().unwrap();
+        assert_eq!(error.kind(), KeyPairErrorKind::InvalidSecretKey);
+        assert_eq!(error.expected_len(), Some(32));
+        assert_eq!(error.actual_len(), Some(31));
+
+        let error = PrivateKey::new(vec![0; 32]).err().unwrap();
+        assert_eq!(error.kind(), KeyPairErrorKind::InvalidSecretKey);
+        assert_eq!(error.actual_len(), None);
+    }
+
+    #[test]
+    fn test_public_key_new_context() {
+        let error = PublicKey::new(vec![2; 32], PublicKeyType::Secp256k1)
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), KeyPairErrorKind::InvalidPublicKey);
+        assert_eq!(error.public_key_type(), Some(PublicKeyType::Secp256k1));
+        assert_eq!(error.field(), Some("public key"));
+        assert_eq!(error.expected_len(), Some(33));
+        assert_eq!(error.actual_len(), Some(32));
+        assert_eq!(
+            error.to_string(),
+            "Invalid public key: type=Secp256k1, field=public key, expected 33 bytes, got 32 bytes"
+        );
+    }
+}
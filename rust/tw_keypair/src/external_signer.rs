@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! A signer that replaces [`PrivateKey`] in the transaction compile phase:
+//! pre-image hashes are computed and transactions are encoded by the library,
+//! while the signatures come from a remote party, e.g. an MPC service or an HSM.
+//!
+//! If the signature cannot be obtained synchronously, split the compile phase instead:
+//! get the data to sign, request the signature asynchronously, and compile the transaction with it.
+
+use crate::tw::{Curve, PrivateKey, PublicKey, PublicKeyType};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use tw_memory::Data;
+
+pub trait ExternalSigner {
+    /// Returns the public key of the signing key.
+    /// Returns [`KeyPairErrorKind::InvalidPublicKey`] if the key cannot be represented with the given type.
+    fn public_key(&self, ty: PublicKeyType) -> KeyPairResult<PublicKey>;
+
+    /// Signs the pre-image hash, or the pre-image data for the curves that hash it on their own (like `ed25519`).
+    /// The signature must be encoded as [`PrivateKey::sign`] does for the same curve.
+    fn sign(&self, message: &[u8]) -> KeyPairResult<Data>;
+}
+
+/// A reference `secp256k1` [`ExternalSigner`] backed by a private key.
+/// Returns 65 bytes `r || s || v` signatures.
+pub struct Secp256k1ExternalSigner {
+    private_key: PrivateKey,
+}
+
+impl Secp256k1ExternalSigner {
+    pub fn new(private_key: PrivateKey) -> Self {
+        Secp256k1ExternalSigner { private_key }
+    }
+}
+
+impl ExternalSigner for Secp256k1ExternalSigner {
+    fn public_key(&self, ty: PublicKeyType) -> KeyPairResult<PublicKey> {
+        match ty {
+            PublicKeyType::Secp256k1 | PublicKeyType::Secp256k1Extended => {
+                self.private_key.get_public_key_by_type(ty)
+            },
+            _ => Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey)),
+        }
+    }
+
+    fn sign(&self, message: &[u8]) -> KeyPairResult<Data> {
+        self.private_key.sign(message, Curve::Secp256k1)
+    }
+}
+
+/// A reference `ed25519` [`ExternalSigner`] backed by a private key.
+/// Returns 64 bytes signatures.
+pub struct Ed25519ExternalSigner {
+    private_key: PrivateKey,
+}
+
+impl Ed25519ExternalSigner {
+    pub fn new(private_key: PrivateKey) -> Self {
+        Ed25519ExternalSigner { private_key }
+    }
+}
+
+impl ExternalSigner for Ed25519ExternalSigner {
+    fn public_key(&self, ty: PublicKeyType) -> KeyPairResult<PublicKey> {
+        match ty {
+            PublicKeyType::Ed25519 => self.private_key.get_public_key_by_type(ty),
+            _ => Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey)),
+        }
+    }
+
+    fn sign(&self, message: &[u8]) -> KeyPairResult<Data> {
+        self.private_key.sign(message, Curve::Ed25519)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tw_encoding::hex::{DecodeHex, ToHex};
+
+    #[test]
+    fn test_secp256k1_external_signer() {
+        let private_key = PrivateKey::new(
+            "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5"
+                .decode_hex()
+                .unwrap(),
+        )
+        .unwrap();
+        let signer = Secp256k1ExternalSigner::new(private_key);
+
+        let hash = "1e592823d4a1eaf1284c5cd29aca7a0dccbb5d7ec12e340c2976cd8b3a64237b"
+            .decode_hex()
+            .unwrap();
+        let signature = signer.sign(&hash).unwrap();
+        assert_eq!(signature.len(), 65);
+
+        let public_key = signer.public_key(PublicKeyType::Secp256k1).unwrap();
+        assert!(public_key.verify(&signature[..64], &hash));
+        assert_eq!(
+            signer.public_key(PublicKeyType::Ed25519).err(),
+            Some(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))
+        );
+    }
+
+    #[test]
+    fn test_ed25519_external_signer() {
+        let private_key = PrivateKey::new(
+            "44f480ca27711895586074a14c552e58cc52e66a58edb6c58cf9b9b7295d4a2d"
+                .decode_hex()
+                .unwrap(),
+        )
+        .unwrap();
+        let signer = Ed25519ExternalSigner::new(private_key);
+
+        let public_key = signer.public_key(PublicKeyType::Ed25519).unwrap();
+        assert_eq!(
+            public_key.to_bytes().to_hex(),
+            "ee6d61a89fc8f9909585a996bb0d2b2ac69ae23b5acf39a19f32631239ba06f9"
+        );
+
+        let message = b"Hello, world!";
+        let signature = signer.sign(message).unwrap();
+        assert_eq!(signature.len(), 64);
+        assert!(public_key.verify(&signature, message));
+    }
+}
@@ -5,6 +5,7 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::ecdsa::der;
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::c_byte_array::{CByteArray, CByteArrayResult};
 use tw_memory::ffi::c_byte_array_ref::CByteArrayRef;
 use tw_memory::ffi::c_result::ErrorCode;
@@ -27,6 +28,7 @@ impl From<CKeyPairError> for ErrorCode {
 /// \param encoded_len the length of the `encoded` array.
 /// \return C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn ecdsa_signature_from_asn_der(
     encoded: *const u8,
     encoded_len: usize,
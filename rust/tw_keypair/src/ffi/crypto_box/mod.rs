@@ -7,6 +7,7 @@
 use crate::ffi::crypto_box::public_key::TWCryptoBoxPublicKey;
 use crate::ffi::crypto_box::secret_key::TWCryptoBoxSecretKey;
 use crate::nacl_crypto_box::CryptoBox;
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::try_or_else;
@@ -23,6 +24,7 @@ pub mod secret_key;
 /// \param message *non-null* pointer to the message to be encrypted.
 /// \return *nullable* pointer to the encrypted message with randomly generated nonce prepended to it.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_crypto_box_encrypt_easy(
     my_secret: *const TWCryptoBoxSecretKey,
     other_pubkey: *const TWCryptoBoxPublicKey,
@@ -52,6 +54,7 @@ pub unsafe extern "C" fn tw_crypto_box_encrypt_easy(
 /// \param encrypted *non-null* pointer to the encrypted message with nonce prepended to it.
 /// \return *nullable* pointer to the decrypted message.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_crypto_box_decrypt_easy(
     my_secret: *const TWCryptoBoxSecretKey,
     other_pubkey: *const TWCryptoBoxPublicKey,
@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::tw::{Curve, PublicKeyType};
+use crate::KeyPairErrorKind;
+use std::ffi::{c_char, CStr};
+use tw_macros::tw_catch_panic;
+use tw_memory::c_string_standalone;
+use tw_memory::ffi::c_byte_array_ref::CByteArrayRef;
+use tw_memory::ffi::c_result::{CUInt32Result, ErrorCode, OK_CODE, UNKNOWN_ERROR};
+use tw_misc::try_or_else;
+
+/// Returns the name of the given curve, e.g. `secp256k1`.
+///
+/// \param curve Elliptic curve.
+/// \note Should be deleted with \free_string.
+/// \return *nullable* C-compatible, nul-terminated string. Null if the curve is not supported.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_curve_name(curve: u32) -> *const c_char {
+    let curve = try_or_else!(Curve::from_raw(curve), std::ptr::null);
+    c_string_standalone(curve.as_str())
+}
+
+/// Parses a curve by its name, e.g. `secp256k1`.
+///
+/// \param name *non-null* C-compatible, nul-terminated string.
+/// \return C-compatible result with the curve value.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_curve_from_name(name: *const c_char) -> CUInt32Result {
+    if name.is_null() {
+        return CUInt32Result::error(UNKNOWN_ERROR);
+    }
+    let Ok(name) = CStr::from_ptr(name).to_str() else {
+        return CUInt32Result::error(UNKNOWN_ERROR);
+    };
+    match name.parse::<Curve>() {
+        Ok(curve) => CUInt32Result::ok(curve.to_raw()),
+        Err(_) => CUInt32Result::error(UNKNOWN_ERROR),
+    }
+}
+
+/// Returns the number of curves supported in Rust.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_curve_count() -> usize {
+    Curve::all().len()
+}
+
+/// Returns the curve value at the given `index` of the supported curves, ordered by their values.
+///
+/// \param index index of the curve, must be less than \tw_curve_count.
+/// \return C-compatible result with the curve value.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_curve_at(index: usize) -> CUInt32Result {
+    match Curve::all().get(index) {
+        Some(curve) => CUInt32Result::ok(curve.to_raw()),
+        None => CUInt32Result::error(UNKNOWN_ERROR),
+    }
+}
+
+/// Returns the minimum length of a message to be signed with the given curve.
+///
+/// \param curve Elliptic curve.
+/// \return C-compatible result with the length in bytes.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_curve_signing_hash_min_len(curve: u32) -> CUInt32Result {
+    match Curve::from_raw(curve) {
+        Some(curve) => CUInt32Result::ok(curve.signing_hash_spec().min_len as u32),
+        None => CUInt32Result::error(UNKNOWN_ERROR),
+    }
+}
+
+/// Returns the maximum length of a message to be signed with the given curve.
+///
+/// \param curve Elliptic curve.
+/// \return C-compatible result with the length in bytes, or 0 if a message of any length can be signed.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_curve_signing_hash_max_len(curve: u32) -> CUInt32Result {
+    match Curve::from_raw(curve) {
+        Some(curve) => {
+            let max_len = curve.signing_hash_spec().max_len.unwrap_or_default();
+            CUInt32Result::ok(max_len as u32)
+        },
+        None => CUInt32Result::error(UNKNOWN_ERROR),
+    }
+}
+
+/// Returns the number of hash algorithms which digests are signed with the given curve.
+///
+/// \param curve Elliptic curve.
+/// \return the number of hash algorithms, or 0 if the curve is not supported.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_curve_signing_hash_count(curve: u32) -> usize {
+    Curve::from_raw(curve)
+        .map(|curve| curve.signing_hash_spec().hashes.len())
+        .unwrap_or_default()
+}
+
+/// Returns the hash algorithm at the given `index` of the hashes signed with the given curve.
+/// `0` stands for a raw message that is hashed by the curve internally.
+///
+/// \param curve Elliptic curve.
+/// \param index index of the hash, must be less than \tw_curve_signing_hash_count.
+/// \return C-compatible result with the hash algorithm value.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_curve_signing_hash_at(curve: u32, index: usize) -> CUInt32Result {
+    let hash = Curve::from_raw(curve).and_then(|curve| curve.signing_hash_spec().hashes.get(index));
+    match hash {
+        Some(hash) => CUInt32Result::ok(hash.to_raw()),
+        None => CUInt32Result::error(UNKNOWN_ERROR),
+    }
+}
+
+/// Checks if the given message can be signed with the curve before signing it.
+///
+/// \param curve Elliptic curve.
+/// \param message *non-null* byte array.
+/// \param message_len the length of the `message` array.
+/// \return 0 if the message is valid, or the error code that can be described with \tw_keypair_error_description.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_curve_validate_signing_message(
+    curve: u32,
+    message: *const u8,
+    message_len: usize,
+) -> ErrorCode {
+    let Some(curve) = Curve::from_raw(curve) else {
+        return KeyPairErrorKind::InvalidCurve.code();
+    };
+    let Some(message) = CByteArrayRef::new(message, message_len).as_slice() else {
+        return KeyPairErrorKind::InvalidSignMessage.code();
+    };
+    match curve.signing_hash_spec().validate(message) {
+        Ok(()) => OK_CODE,
+        Err(e) => e.code(),
+    }
+}
+
+/// Returns the name of the given public key type, e.g. `secp256k1Extended`.
+///
+/// \param ty type of the public key.
+/// \note Should be deleted with \free_string.
+/// \return *nullable* C-compatible, nul-terminated string. Null if the type is not supported.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_public_key_type_name(ty: u32) -> *const c_char {
+    let ty = try_or_else!(PublicKeyType::from_raw(ty), std::ptr::null);
+    c_string_standalone(ty.as_str())
+}
+
+/// Parses a public key type by its name, e.g. `secp256k1Extended`.
+///
+/// \param name *non-null* C-compatible, nul-terminated string.
+/// \return C-compatible result with the public key type value.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_public_key_type_from_name(name: *const c_char) -> CUInt32Result {
+    if name.is_null() {
+        return CUInt32Result::error(UNKNOWN_ERROR);
+    }
+    let Ok(name) = CStr::from_ptr(name).to_str() else {
+        return CUInt32Result::error(UNKNOWN_ERROR);
+    };
+    match name.parse::<PublicKeyType>() {
+        Ok(ty) => CUInt32Result::ok(ty.to_raw()),
+        Err(_) => CUInt32Result::error(UNKNOWN_ERROR),
+    }
+}
+
+/// Returns the number of public key types supported in Rust.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_public_key_type_count() -> usize {
+    PublicKeyType::all().len()
+}
+
+/// Returns the public key type value at the given `index` of the supported types, ordered by their values.
+///
+/// \param index index of the type, must be less than \tw_public_key_type_count.
+/// \return C-compatible result with the public key type value.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_public_key_type_at(index: usize) -> CUInt32Result {
+    match PublicKeyType::all().get(index) {
+        Some(ty) => CUInt32Result::ok(ty.to_raw()),
+        None => CUInt32Result::error(UNKNOWN_ERROR),
+    }
+}
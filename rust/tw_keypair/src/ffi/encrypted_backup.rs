@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::encrypted_backup::{BackupKdfParams, EncryptedBackup};
+use crate::KeyPairErrorKind;
+use tw_macros::tw_catch_panic;
+use tw_memory::ffi::c_byte_array::{CByteArray, CByteArrayResult};
+use tw_memory::ffi::c_byte_array_ref::CByteArrayRef;
+use tw_misc::try_or_else;
+
+/// Encrypts the payload into a versioned backup container with the default Argon2id parameters.
+///
+/// \param payload *non-null* byte array, e.g. StoredKey JSON.
+/// \param payload_len the length of the `payload` array.
+/// \param password *non-null* byte array.
+/// \param password_len the length of the `password` array.
+/// \param metadata *optional* byte array stored in plaintext, but authenticated.
+/// \param metadata_len the length of the `metadata` array.
+/// \return The encoded backup as a C-compatible result with a C-compatible byte array.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_encrypted_backup_create(
+    payload: *const u8,
+    payload_len: usize,
+    password: *const u8,
+    password_len: usize,
+    metadata: *const u8,
+    metadata_len: usize,
+) -> CByteArrayResult {
+    let payload = try_or_else!(CByteArrayRef::new(payload, payload_len).as_slice(), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidEncryptedMessage)
+    });
+    let password = try_or_else!(
+        CByteArrayRef::new(password, password_len).as_slice(),
+        || { CByteArrayResult::error(KeyPairErrorKind::InvalidEncryptedMessage) }
+    );
+    let metadata = CByteArrayRef::new(metadata, metadata_len)
+        .as_slice()
+        .unwrap_or_default();
+
+    EncryptedBackup::create(payload, password, metadata, BackupKdfParams::default())
+        .map(|backup| CByteArray::from(backup.encode()))
+        .into()
+}
+
+/// Decrypts the payload of an encoded backup container.
+///
+/// \param backup *non-null* byte array.
+/// \param backup_len the length of the `backup` array.
+/// \param password *non-null* byte array.
+/// \param password_len the length of the `password` array.
+/// \return The decrypted payload as a C-compatible result with a C-compatible byte array.
+/// The error code can be described with \tw_keypair_error_description.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_encrypted_backup_open(
+    backup: *const u8,
+    backup_len: usize,
+    password: *const u8,
+    password_len: usize,
+) -> CByteArrayResult {
+    let backup = try_or_else!(CByteArrayRef::new(backup, backup_len).as_slice(), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidEncryptedMessage)
+    });
+    let password = try_or_else!(
+        CByteArrayRef::new(password, password_len).as_slice(),
+        || { CByteArrayResult::error(KeyPairErrorKind::InvalidEncryptedMessage) }
+    );
+
+    EncryptedBackup::decode(backup)
+        .and_then(|backup| backup.open(password))
+        .map(|payload| CByteArray::from(payload.to_vec()))
+        .into()
+}
+
+/// Returns the plaintext metadata of an encoded backup container without decrypting it.
+///
+/// \param backup *non-null* byte array.
+/// \param backup_len the length of the `backup` array.
+/// \return The metadata as a C-compatible result with a C-compatible byte array.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_encrypted_backup_metadata(
+    backup: *const u8,
+    backup_len: usize,
+) -> CByteArrayResult {
+    let backup = try_or_else!(CByteArrayRef::new(backup, backup_len).as_slice(), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidEncryptedMessage)
+    });
+
+    EncryptedBackup::decode(backup)
+        .map(|backup| CByteArray::from(backup.metadata().to_vec()))
+        .into()
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::KeyPairErrorKind;
+use std::ffi::c_char;
+use tw_macros::tw_catch_panic;
+use tw_memory::c_string_standalone;
+use tw_memory::ffi::c_result::ErrorCode;
+
+/// Returns a human-readable description of the given keypair error code.
+///
+/// \param code error code returned by a keypair function.
+/// \note Should be deleted with \free_string.
+/// \return *nullable* C-compatible, nul-terminated string. Null if the code is unknown.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_keypair_error_description(code: ErrorCode) -> *const c_char {
+    match KeyPairErrorKind::from_code(code) {
+        Some(error) => c_string_standalone(error.description()),
+        None => std::ptr::null(),
+    }
+}
@@ -4,5 +4,9 @@
 
 pub mod asn;
 pub mod crypto_box;
+pub mod curve;
+pub mod encrypted_backup;
+pub mod error;
 pub mod privkey;
 pub mod pubkey;
+pub mod vault;
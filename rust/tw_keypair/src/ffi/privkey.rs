@@ -5,8 +5,12 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::ffi::pubkey::TWPublicKey;
+use crate::starkex::{MessageHashMode, NonceMode};
 use crate::tw::{Curve, PrivateKey, PublicKeyType};
-use tw_memory::ffi::c_byte_array::CByteArray;
+use crate::KeyPairErrorKind;
+use std::fmt;
+use tw_macros::tw_catch_panic;
+use tw_memory::ffi::c_byte_array::{CByteArray, CByteArrayResult};
 use tw_memory::ffi::c_byte_array_ref::CByteArrayRef;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::{try_or_else, try_or_false};
@@ -15,6 +19,12 @@ pub struct TWPrivateKey(pub(crate) PrivateKey);
 
 impl RawPtrTrait for TWPrivateKey {}
 
+impl fmt::Debug for TWPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TWPrivateKey").field(&self.0).finish()
+    }
+}
+
 impl AsRef<PrivateKey> for TWPrivateKey {
     fn as_ref(&self) -> &PrivateKey {
         &self.0
@@ -28,6 +38,7 @@ impl AsRef<PrivateKey> for TWPrivateKey {
 /// \note Should be deleted with \tw_private_key_delete.
 /// \return Nullable pointer to Private Key.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_private_key_create_with_data(
     input: *const u8,
     input_len: usize,
@@ -45,6 +56,7 @@ pub unsafe extern "C" fn tw_private_key_create_with_data(
 ///
 /// \param key *non-null* pointer to private key.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_private_key_delete(key: *mut TWPrivateKey) {
     // Take the ownership back to rust and drop the owner.
     let _ = TWPrivateKey::from_ptr(key);
@@ -53,25 +65,53 @@ pub unsafe extern "C" fn tw_private_key_delete(key: *mut TWPrivateKey) {
 /// Returns the raw pointer to the underlying bytes of the private key.
 ///
 /// \param data A non-null valid block of private key
-/// \return the raw pointer to the contents of private key
+/// \return the raw pointer to the contents of private key, or null if the private key is non-exportable.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_private_key_bytes(data: *const TWPrivateKey) -> *const u8 {
     TWPrivateKey::from_ptr_as_ref(data)
-        .map(|data| data.0.bytes().as_ptr())
+        .and_then(|data| data.0.bytes().ok())
+        .map(<[u8]>::as_ptr)
         .unwrap_or_else(std::ptr::null)
 }
 
 /// Returns the size in bytes.
 ///
 /// \param data A non-null valid block of private key
-/// \return the size of the given block of private key
+/// \return the size of the given block of private key, or 0 if the private key is non-exportable.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_private_key_size(data: *const TWPrivateKey) -> usize {
     TWPrivateKey::from_ptr_as_ref(data)
-        .map(|data| data.0.bytes().len())
+        .and_then(|data| data.0.bytes().ok())
+        .map(<[u8]>::len)
         .unwrap_or_default()
 }
 
+/// Forbids exporting the private key bytes, so \tw_private_key_bytes returns null.
+/// The private key can still be used for signing. The export can't be enabled again.
+///
+/// \param key *non-null* pointer to a Private key
+#[cfg(feature = "export-policy")]
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_private_key_disable_export(key: *mut TWPrivateKey) {
+    if let Some(private) = TWPrivateKey::from_ptr_as_mut(key) {
+        private.0.disable_export();
+    }
+}
+
+/// Determines if the private key bytes can be exported.
+///
+/// \param key *non-null* pointer to a Private key
+/// \return true if the private key is exportable, false otherwise.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_private_key_is_exportable(key: *const TWPrivateKey) -> bool {
+    let private = try_or_false!(TWPrivateKey::from_ptr_as_ref(key));
+    private.0.is_exportable()
+}
+
 /// Determines if the given private key is valid or not.
 ///
 /// \param key *non-null* byte array.
@@ -79,6 +119,7 @@ pub unsafe extern "C" fn tw_private_key_size(data: *const TWPrivateKey) -> usize
 /// \param curve Elliptic curve of the private key.
 /// \return true if the private key is valid, false otherwise.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_private_key_is_valid(
     key: *const u8,
     key_len: usize,
@@ -97,6 +138,7 @@ pub unsafe extern "C" fn tw_private_key_is_valid(
 /// \param curve Elliptic curve.
 /// \return Signature as a C-compatible result with a C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_private_key_sign(
     key: *mut TWPrivateKey,
     message: *const u8,
@@ -115,12 +157,125 @@ pub unsafe extern "C" fn tw_private_key_sign(
     CByteArray::from(sig)
 }
 
+/// Signs a digest using ECDSA and given curve.
+/// Unlike \tw_private_key_sign, reports a stable error code if the signing fails.
+/// Internal, it's not exposed through the TW headers: the C++ `PrivateKey::sign` uses it
+/// for the curves implemented in Rust.
+///
+/// \param key *non-null* pointer to a Private key
+/// \param message *non-null* byte array.
+/// \param message_len the length of the `input` array.
+/// \param curve Elliptic curve.
+/// \return Signature as a C-compatible result with a C-compatible byte array.
+/// The error code can be described with \tw_keypair_error_description.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_private_key_sign_with_result(
+    key: *mut TWPrivateKey,
+    message: *const u8,
+    message_len: usize,
+    curve: u32,
+) -> CByteArrayResult {
+    let curve = try_or_else!(Curve::from_raw(curve), || CByteArrayResult::error(
+        KeyPairErrorKind::InvalidCurve
+    ));
+    let private = try_or_else!(TWPrivateKey::from_ptr_as_ref(key), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSecretKey)
+    });
+    let message_to_sign = try_or_else!(CByteArrayRef::new(message, message_len).as_slice(), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSignMessage)
+    });
+
+    private
+        .0
+        .sign(message_to_sign, curve)
+        .map(CByteArray::from)
+        .into()
+}
+
+/// Signs a message hash with the Starkex curve.
+/// Unlike \tw_private_key_sign, allows to sign hashes that don't fit the stark field.
+///
+/// \param key *non-null* pointer to a Private key
+/// \param message *non-null* byte array.
+/// \param message_len the length of the `input` array.
+/// \param hash_mode 0 - the hash must be less than 2^251, 1 - the hash of up to 32 bytes is truncated to its 251 most significant bits.
+/// \param nonce_mode 0 - the nonce is regenerated with an extra entropy as `cairo-lang` does, 1 - plain RFC6979 nonces.
+/// \return Signature as a C-compatible result with a C-compatible byte array.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_private_key_sign_starkex(
+    key: *mut TWPrivateKey,
+    message: *const u8,
+    message_len: usize,
+    hash_mode: u32,
+    nonce_mode: u32,
+) -> CByteArrayResult {
+    let hash_mode = try_or_else!(MessageHashMode::from_raw(hash_mode), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSignMessage)
+    });
+    let nonce_mode = try_or_else!(NonceMode::from_raw(nonce_mode), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSignMessage)
+    });
+    let private = try_or_else!(TWPrivateKey::from_ptr_as_ref(key), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSecretKey)
+    });
+    let message_to_sign = try_or_else!(CByteArrayRef::new(message, message_len).as_slice(), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSignMessage)
+    });
+
+    private
+        .0
+        .sign_starkex(message_to_sign, hash_mode, nonce_mode)
+        .map(CByteArray::from)
+        .into()
+}
+
+/// Signs a message hash with the Starkex curve as \tw_private_key_sign_starkex does,
+/// but returns the 96 byte `(r, s, v)` signature that allows to recover the public key.
+///
+/// \param key *non-null* pointer to a Private key
+/// \param message *non-null* byte array.
+/// \param message_len the length of the `input` array.
+/// \param hash_mode 0 - the hash must be less than 2^251, 1 - the hash of up to 32 bytes is truncated to its 251 most significant bits.
+/// \param nonce_mode 0 - the nonce is regenerated with an extra entropy as `cairo-lang` does, 1 - plain RFC6979 nonces.
+/// \return Signature as a C-compatible result with a C-compatible byte array.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_private_key_sign_starkex_extended(
+    key: *mut TWPrivateKey,
+    message: *const u8,
+    message_len: usize,
+    hash_mode: u32,
+    nonce_mode: u32,
+) -> CByteArrayResult {
+    let hash_mode = try_or_else!(MessageHashMode::from_raw(hash_mode), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSignMessage)
+    });
+    let nonce_mode = try_or_else!(NonceMode::from_raw(nonce_mode), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSignMessage)
+    });
+    let private = try_or_else!(TWPrivateKey::from_ptr_as_ref(key), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSecretKey)
+    });
+    let message_to_sign = try_or_else!(CByteArrayRef::new(message, message_len).as_slice(), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSignMessage)
+    });
+
+    private
+        .0
+        .sign_starkex_extended(message_to_sign, hash_mode, nonce_mode)
+        .map(CByteArray::from)
+        .into()
+}
+
 /// Returns the public key associated with the given pubkeyType and privateKey
 ///
 /// \param key *non-null* pointer to the private key.
 /// \param pubkey_type type of the public key to return.
 /// \return *non-null* pointer to the corresponding public key.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_private_key_get_public_key_by_type(
     key: *mut TWPrivateKey,
     pubkey_type: u32,
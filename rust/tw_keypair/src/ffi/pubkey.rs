@@ -5,6 +5,7 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::tw::{PublicKey, PublicKeyType};
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::c_byte_array::CByteArray;
 use tw_memory::ffi::c_byte_array_ref::CByteArrayRef;
 use tw_memory::ffi::RawPtrTrait;
@@ -28,6 +29,7 @@ impl RawPtrTrait for TWPublicKey {}
 /// \note Should be deleted with \tw_public_key_delete.
 /// \return Nullable pointer to the public key.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_public_key_create_with_data(
     input: *const u8,
     input_len: usize,
@@ -41,10 +43,36 @@ pub unsafe extern "C" fn tw_public_key_create_with_data(
         .unwrap_or_else(|_| std::ptr::null_mut())
 }
 
+/// Create a public key from the given DER-encoded `SubjectPublicKeyInfo` and specified public key type.
+/// Only ECDSA public key types are supported.
+///
+/// \param input *non-null* byte array.
+/// \param input_len the length of the `input` array.
+/// \param ty type of the public key.
+/// \note Should be deleted with \tw_public_key_delete.
+/// \return Nullable pointer to the public key.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_public_key_create_with_der(
+    input: *const u8,
+    input_len: usize,
+    ty: u32,
+) -> *mut TWPublicKey {
+    let der_bytes = try_or_else!(
+        CByteArrayRef::new(input, input_len).as_slice(),
+        std::ptr::null_mut
+    );
+    let ty = try_or_else!(PublicKeyType::from_raw(ty), std::ptr::null_mut);
+    PublicKey::from_der(der_bytes, ty)
+        .map(|public| TWPublicKey(public).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
 /// Delete the given public key.
 ///
 /// \param key *non-null* pointer to public key.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_public_key_delete(key: *mut TWPublicKey) {
     // Take the ownership back to rust and drop the owner.
     let _ = TWPublicKey::from_ptr(key);
@@ -59,6 +87,7 @@ pub unsafe extern "C" fn tw_public_key_delete(key: *mut TWPublicKey) {
 /// \param msg_len the length of the `msg` array.
 /// \return true if the signature and the message belongs to the given public key, otherwise false.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_public_key_verify(
     key: *mut TWPublicKey,
     sig: *const u8,
@@ -77,11 +106,61 @@ pub unsafe extern "C" fn tw_public_key_verify(
 /// \param key *non-null* pointer to a public key.
 /// \return C-compatible result with a C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_public_key_data(key: *mut TWPublicKey) -> CByteArray {
     let public = try_or_else!(TWPublicKey::from_ptr_as_ref(key), CByteArray::default);
     CByteArray::from(public.0.to_bytes())
 }
 
+/// Returns the DER-encoded `SubjectPublicKeyInfo` of a given public-key.
+/// Only ECDSA public keys are supported, returns an empty array otherwise.
+///
+/// \param key *non-null* pointer to a public key.
+/// \return C-compatible byte array.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_public_key_der_encoded(key: *mut TWPublicKey) -> CByteArray {
+    let public = try_or_else!(TWPublicKey::from_ptr_as_ref(key), CByteArray::default);
+    public.0.to_der().map(CByteArray::from).unwrap_or_default()
+}
+
+/// Returns the BIP-32 fingerprint of a given public-key as a big-endian integer:
+/// the first 4 bytes of `RIPEMD160(SHA256(public key))`.
+/// ECDSA public keys are hashed in the compressed form.
+///
+/// \param key *non-null* pointer to a public key.
+/// \return the fingerprint, or 0 if the key is null.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_public_key_fingerprint(key: *mut TWPublicKey) -> u32 {
+    let public = try_or_else!(TWPublicKey::from_ptr_as_ref(key), || 0);
+    u32::from_be_bytes(public.0.fingerprint().take())
+}
+
+/// Returns the 20-byte key identifier of a given public-key: `RIPEMD160(SHA256(public key))`.
+/// ECDSA public keys are hashed in the compressed form.
+///
+/// \param key *non-null* pointer to a public key.
+/// \return C-compatible byte array.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_public_key_key_identifier(key: *mut TWPublicKey) -> CByteArray {
+    let public = try_or_else!(TWPublicKey::from_ptr_as_ref(key), CByteArray::default);
+    CByteArray::from(public.0.key_identifier().into_vec())
+}
+
+/// Returns the 32-byte account identifier of a given public-key that can be used as a database key.
+/// The identifier is the same for the compressed and uncompressed forms of the key.
+///
+/// \param key *non-null* pointer to a public key.
+/// \return C-compatible byte array.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_public_key_account_identifier(key: *mut TWPublicKey) -> CByteArray {
+    let public = try_or_else!(TWPublicKey::from_ptr_as_ref(key), CByteArray::default);
+    CByteArray::from(public.0.account_identifier().into_vec())
+}
+
 // #[no_mangle]
 // pub unsafe extern "C" fn tw_public_key_is_valid(
 //     pubkey: *const u8,
@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::ffi::pubkey::TWPublicKey;
+use crate::tw::{Curve, PublicKeyType};
+use crate::vault::{KeyVault, VaultHandle};
+use crate::KeyPairErrorKind;
+use tw_macros::tw_catch_panic;
+use tw_memory::ffi::c_byte_array::{CByteArray, CByteArrayResult};
+use tw_memory::ffi::c_byte_array_ref::CByteArrayRef;
+use tw_memory::ffi::c_result::CUInt64Result;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::{try_or_else, try_or_false};
+
+pub struct TWKeyVault(pub(crate) KeyVault);
+
+impl RawPtrTrait for TWKeyVault {}
+
+/// Creates an empty key vault with a random session key.
+///
+/// \note Should be deleted with \tw_key_vault_delete.
+/// \return *non-null* pointer to the key vault.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_key_vault_create() -> *mut TWKeyVault {
+    TWKeyVault(KeyVault::new()).into_ptr()
+}
+
+/// Deletes the given key vault and all its secrets.
+///
+/// \param vault *non-null* pointer to the key vault.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_key_vault_delete(vault: *mut TWKeyVault) {
+    // Take the ownership back to rust and drop the owner.
+    let _ = TWKeyVault::from_ptr(vault);
+}
+
+/// Imports a private key into the vault.
+///
+/// \param vault *non-null* pointer to the key vault.
+/// \param key *non-null* byte array.
+/// \param key_len the length of the `key` array.
+/// \return Handle of the private key as a C-compatible result.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_key_vault_import_private_key(
+    vault: *const TWKeyVault,
+    key: *const u8,
+    key_len: usize,
+) -> CUInt64Result {
+    let vault = try_or_else!(TWKeyVault::from_ptr_as_ref(vault), || {
+        CUInt64Result::error(KeyPairErrorKind::InternalError)
+    });
+    let key = try_or_else!(CByteArrayRef::new(key, key_len).to_vec(), || {
+        CUInt64Result::error(KeyPairErrorKind::InvalidSecretKey)
+    });
+
+    vault
+        .0
+        .import_private_key(key)
+        .map(VaultHandle::to_raw)
+        .into()
+}
+
+/// Imports a BIP-32 seed of 16 to 64 bytes into the vault.
+///
+/// \param vault *non-null* pointer to the key vault.
+/// \param seed *non-null* byte array.
+/// \param seed_len the length of the `seed` array.
+/// \return Handle of the seed as a C-compatible result.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_key_vault_import_seed(
+    vault: *const TWKeyVault,
+    seed: *const u8,
+    seed_len: usize,
+) -> CUInt64Result {
+    let vault = try_or_else!(TWKeyVault::from_ptr_as_ref(vault), || {
+        CUInt64Result::error(KeyPairErrorKind::InternalError)
+    });
+    let seed = try_or_else!(CByteArrayRef::new(seed, seed_len).to_vec(), || {
+        CUInt64Result::error(KeyPairErrorKind::InvalidSecretKey)
+    });
+
+    vault.0.import_seed(seed).map(VaultHandle::to_raw).into()
+}
+
+/// Derives a BIP-32 `secp256k1` private key from the seed, and keeps it in the vault.
+///
+/// \param vault *non-null* pointer to the key vault.
+/// \param seed handle of the seed.
+/// \param path *non-null* array of the child indexes, hardened indexes have the `0x80000000` bit set.
+/// \param path_len the number of the child indexes.
+/// \return Handle of the derived private key as a C-compatible result.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_key_vault_derive_secp256k1(
+    vault: *const TWKeyVault,
+    seed: u64,
+    path: *const u32,
+    path_len: usize,
+) -> CUInt64Result {
+    let vault = try_or_else!(TWKeyVault::from_ptr_as_ref(vault), || {
+        CUInt64Result::error(KeyPairErrorKind::InternalError)
+    });
+    let path: &[u32] = if path_len == 0 {
+        &[]
+    } else if path.is_null() {
+        return CUInt64Result::error(KeyPairErrorKind::InternalError);
+    } else {
+        std::slice::from_raw_parts(path, path_len)
+    };
+
+    vault
+        .0
+        .derive_secp256k1(VaultHandle::from_raw(seed), path)
+        .map(VaultHandle::to_raw)
+        .into()
+}
+
+/// Signs a digest by the private key referenced by the `handle` with the given curve.
+///
+/// \param vault *non-null* pointer to the key vault.
+/// \param handle handle of the private key.
+/// \param message *non-null* byte array.
+/// \param message_len the length of the `message` array.
+/// \param curve Elliptic curve.
+/// \return Signature as a C-compatible result with a C-compatible byte array.
+/// The error code can be described with \tw_keypair_error_description.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_key_vault_sign(
+    vault: *const TWKeyVault,
+    handle: u64,
+    message: *const u8,
+    message_len: usize,
+    curve: u32,
+) -> CByteArrayResult {
+    let curve = try_or_else!(Curve::from_raw(curve), || CByteArrayResult::error(
+        KeyPairErrorKind::InvalidCurve
+    ));
+    let vault = try_or_else!(TWKeyVault::from_ptr_as_ref(vault), || {
+        CByteArrayResult::error(KeyPairErrorKind::InternalError)
+    });
+    let message_to_sign = try_or_else!(CByteArrayRef::new(message, message_len).as_slice(), || {
+        CByteArrayResult::error(KeyPairErrorKind::InvalidSignMessage)
+    });
+
+    vault
+        .0
+        .sign(VaultHandle::from_raw(handle), message_to_sign, curve)
+        .map(CByteArray::from)
+        .into()
+}
+
+/// Returns the public key of the private key referenced by the `handle`.
+///
+/// \param vault *non-null* pointer to the key vault.
+/// \param handle handle of the private key.
+/// \param pubkey_type type of the public key to return.
+/// \return pointer to the public key, or null if the handle is unknown.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_key_vault_get_public_key_by_type(
+    vault: *const TWKeyVault,
+    handle: u64,
+    pubkey_type: u32,
+) -> *mut TWPublicKey {
+    let ty = try_or_else!(PublicKeyType::from_raw(pubkey_type), std::ptr::null_mut);
+    let vault = try_or_else!(TWKeyVault::from_ptr_as_ref(vault), std::ptr::null_mut);
+    vault
+        .0
+        .public_key(VaultHandle::from_raw(handle), ty)
+        .map(|public| TWPublicKey(public).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
+/// Removes the secret referenced by the `handle` from the vault.
+///
+/// \param vault *non-null* pointer to the key vault.
+/// \param handle handle of the secret.
+/// \return true if the secret has been removed, false if the handle is unknown.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_key_vault_remove(vault: *const TWKeyVault, handle: u64) -> bool {
+    let vault = try_or_false!(TWKeyVault::from_ptr_as_ref(vault));
+    vault.0.remove(VaultHandle::from_raw(handle))
+}
@@ -42,29 +42,43 @@
 //! assert_eq(eth_signature.v, H256::from(EXPECTED_V));
 //! ```
 
+//!
+//! # `no_std` support
+//!
+//! Disable the default `std` feature to build the crate for `no_std` + `alloc` targets.
+//! Only the `ecdsa` and `ed25519` curves, and [`tw::Curve`], [`tw::PublicKeyType`], [`tw::SigningHashSpec`] are available then.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[macro_use]
+mod macros;
+
 pub mod ecdsa;
 pub mod ed25519;
+#[cfg(feature = "std")]
+pub mod encrypted_backup;
+mod error;
+#[cfg(feature = "std")]
+pub mod external_signer;
+#[cfg(feature = "std")]
 pub mod ffi;
+#[cfg(feature = "std")]
 pub mod nacl_crypto_box;
+#[cfg(feature = "std")]
 pub mod rand;
+pub mod redact;
+#[cfg(feature = "std")]
 pub mod schnorr;
+#[cfg(feature = "std")]
 pub mod starkex;
 pub mod traits;
 pub mod tw;
+#[cfg(feature = "std")]
+pub mod vault;
 
 #[cfg(feature = "test-utils")]
 pub mod test_utils;
 
-pub type KeyPairResult<T> = Result<T, KeyPairError>;
-
-#[derive(Debug, PartialEq, Eq)]
-pub enum KeyPairError {
-    InvalidSecretKey,
-    InvalidPublicKey,
-    InvalidSignature,
-    InvalidSignMessage,
-    InvalidEncryptedMessage,
-    SignatureVerifyError,
-    SigningError,
-    InternalError,
-}
+pub use error::{KeyPairError, KeyPairErrorContext, KeyPairErrorKind, KeyPairResult};
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+/// Implements `serde::Serialize` and `serde::Deserialize` as a hex-encoded string
+/// for a type that implements [`tw_misc::traits::ToBytesVec`] and `TryFrom<&[u8]>`.
+///
+/// The implementations are available with the `serde` feature only.
+macro_rules! impl_serde_as_hex {
+    ($name:ty) => {
+        impl_serde_as_hex!(impl<> $name);
+    };
+    (impl<$($generic:ident: $bound:path),*> $name:ty) => {
+        #[cfg(feature = "serde")]
+        impl<$($generic: $bound),*> serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let bytes = tw_misc::traits::ToBytesVec::to_vec(self);
+                tw_encoding::hex::as_hex::serialize(&bytes, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $($generic: $bound),*> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                tw_encoding::hex::as_hex::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+/// Implements `PartialEq` and `Eq` comparing the secret bytes in constant time
+/// for a type that implements [`tw_misc::traits::ToBytesZeroizing`].
+macro_rules! impl_constant_time_eq {
+    ($name:ty) => {
+        impl_constant_time_eq!(impl<> $name);
+    };
+    (impl<$($generic:ident: $bound:path),*> $name:ty) => {
+        impl<$($generic: $bound),*> PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                let this = tw_misc::traits::ToBytesZeroizing::to_zeroizing_vec(self);
+                let other = tw_misc::traits::ToBytesZeroizing::to_zeroizing_vec(other);
+                tw_memory::constant_time::ct_eq(&this, &other)
+            }
+        }
+
+        impl<$($generic: $bound),*> Eq for $name {}
+    };
+}
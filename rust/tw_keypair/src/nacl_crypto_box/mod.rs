@@ -2,7 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
 use crypto_box::aead::Aead;
 use tw_hash::H192;
 use tw_memory::Data;
@@ -30,7 +30,8 @@ impl CryptoBox {
         use crypto_box::aead::AeadCore;
 
         let nonce = crypto_box::SalsaBox::generate_nonce(&mut OsRng);
-        let nonce = H192::try_from(nonce.as_slice()).map_err(|_| KeyPairError::InternalError)?;
+        let nonce = H192::try_from(nonce.as_slice())
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InternalError))?;
         let encrypted = Self::encrypt(my_secret, other_pubkey, message, nonce)?;
 
         let ecrypted_with_nonce: Data = nonce
@@ -53,7 +54,7 @@ impl CryptoBox {
         let salsa_box = crypto_box::SalsaBox::new(other_pubkey.inner(), my_secret.inner());
         salsa_box
             .encrypt(&nonce, message)
-            .map_err(|_| KeyPairError::InternalError)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InternalError))
     }
 
     /// Decrypts box produced by [`CryptoBox::encrypt_easy`].
@@ -64,11 +65,11 @@ impl CryptoBox {
         encrypted_with_nonce: &[u8],
     ) -> KeyPairResult<Data> {
         if encrypted_with_nonce.len() < NONCE_LEN {
-            return Err(KeyPairError::InvalidEncryptedMessage);
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage));
         }
 
         let nonce = H192::try_from(&encrypted_with_nonce[..NONCE_LEN])
-            .map_err(|_| KeyPairError::InternalError)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InternalError))?;
         let encrypted = &encrypted_with_nonce[NONCE_LEN..];
 
         Self::decrypt(my_secret, other_pubkey, encrypted, nonce)
@@ -85,7 +86,7 @@ impl CryptoBox {
         let salsa_box = crypto_box::SalsaBox::new(other_pubkey.inner(), my_secret.inner());
         salsa_box
             .decrypt(&nonce, encrypted)
-            .map_err(|_| KeyPairError::InvalidEncryptedMessage)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidEncryptedMessage))
     }
 }
 
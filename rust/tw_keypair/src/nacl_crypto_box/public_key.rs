@@ -2,7 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::KeyPairError;
+use crate::{KeyPairError, KeyPairErrorKind};
 
 pub struct PublicKey {
     public: crypto_box::PublicKey,
@@ -26,8 +26,8 @@ impl<'a> TryFrom<&'a [u8]> for PublicKey {
     type Error = KeyPairError;
 
     fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
-        let public =
-            crypto_box::PublicKey::from_slice(value).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let public = crypto_box::PublicKey::from_slice(value)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Ok(PublicKey { public })
     }
 }
@@ -4,7 +4,9 @@
 
 use crate::nacl_crypto_box::public_key::PublicKey;
 use crate::rand::{CryptoRngCore, OsRng};
-use crate::KeyPairError;
+use crate::redact::fmt_redacted;
+use crate::{KeyPairError, KeyPairErrorKind};
+use core::fmt;
 use tw_hash::H256;
 use tw_misc::traits::ToBytesZeroizing;
 use zeroize::Zeroizing;
@@ -13,6 +15,12 @@ pub struct SecretKey {
     secret: crypto_box::SecretKey,
 }
 
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_redacted(f, "SecretKey", &self.to_zeroizing_vec())
+    }
+}
+
 impl SecretKey {
     pub fn random() -> SecretKey {
         SecretKey::generate(&mut OsRng)
@@ -43,12 +51,14 @@ impl ToBytesZeroizing for SecretKey {
     }
 }
 
+impl_constant_time_eq!(SecretKey);
+
 impl<'a> TryFrom<&'a [u8]> for SecretKey {
     type Error = KeyPairError;
 
     fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
-        let secret =
-            crypto_box::SecretKey::from_slice(value).map_err(|_| KeyPairError::InvalidSecretKey)?;
+        let secret = crypto_box::SecretKey::from_slice(value)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
         Ok(SecretKey { secret })
     }
 }
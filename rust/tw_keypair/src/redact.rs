@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Helpers that keep secrets out of `Debug` output.
+//!
+//! Secret types print a [`SecretFingerprint`] only, so accidentally logged keys don't leak.
+//! Use [`ToZeroizingHex::to_zeroizing_hex`] to export the secret bytes deliberately.
+
+use alloc::string::String;
+use core::fmt;
+use tw_encoding::hex;
+use tw_hash::sha2::sha256_d;
+use tw_misc::traits::ToBytesZeroizing;
+use zeroize::Zeroizing;
+
+/// A short identifier of a secret: the first 4 bytes of the double SHA256 hash of the secret bytes.
+/// It allows to tell keys apart in logs without revealing them.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct SecretFingerprint([u8; SecretFingerprint::LEN]);
+
+impl SecretFingerprint {
+    pub const LEN: usize = 4;
+
+    pub fn new(secret: &[u8]) -> SecretFingerprint {
+        let hash = Zeroizing::new(sha256_d(secret));
+        let mut fingerprint = [0; SecretFingerprint::LEN];
+        fingerprint.copy_from_slice(&hash[..SecretFingerprint::LEN]);
+        SecretFingerprint(fingerprint)
+    }
+
+    pub fn to_bytes(&self) -> [u8; SecretFingerprint::LEN] {
+        self.0
+    }
+}
+
+impl fmt::Display for SecretFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode(self.0, false))
+    }
+}
+
+impl fmt::Debug for SecretFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Deliberately exports the secret bytes as a hex string, that is wiped on drop.
+pub trait ToZeroizingHex {
+    fn to_zeroizing_hex(&self) -> Zeroizing<String>;
+}
+
+impl<T: ToBytesZeroizing> ToZeroizingHex for T {
+    fn to_zeroizing_hex(&self) -> Zeroizing<String> {
+        let bytes = self.to_zeroizing_vec();
+        Zeroizing::new(hex::encode(bytes.as_slice(), false))
+    }
+}
+
+/// Formats a secret type as `name { fingerprint: .., .. }`.
+pub(crate) fn fmt_redacted(f: &mut fmt::Formatter<'_>, name: &str, secret: &[u8]) -> fmt::Result {
+    f.debug_struct(name)
+        .field("fingerprint", &SecretFingerprint::new(secret))
+        .finish_non_exhaustive()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_secret_fingerprint() {
+        let fingerprint = SecretFingerprint::new(b"hello");
+        // sha256d("hello") = 9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50
+        assert_eq!(fingerprint.to_bytes(), [0x95, 0x95, 0xc9, 0xdf]);
+        assert_eq!(format!("{fingerprint:?}"), "9595c9df");
+    }
+}
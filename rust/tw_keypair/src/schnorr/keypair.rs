@@ -6,7 +6,7 @@ use crate::schnorr::private::PrivateKey;
 use crate::schnorr::public::PublicKey;
 use crate::schnorr::signature::Signature;
 use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
-use crate::KeyPairError;
+use crate::{KeyPairError, KeyPairErrorKind};
 
 pub struct KeyPair {
     private: PrivateKey,
@@ -68,7 +68,9 @@ impl<'a> TryFrom<&'a str> for KeyPair {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
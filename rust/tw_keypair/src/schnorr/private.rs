@@ -1,9 +1,11 @@
+use crate::redact::fmt_redacted;
 use crate::schnorr::bitcoin_tweak;
 use crate::schnorr::public::PublicKey;
 use crate::schnorr::signature::Signature;
 use crate::traits::SigningKeyTrait;
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
 use bitcoin::key::TapTweak;
+use core::fmt;
 use secp256k1::SECP256K1;
 use tw_encoding::hex;
 use tw_hash::H256;
@@ -17,6 +19,12 @@ pub struct PrivateKey {
     no_aux_rand: bool,
 }
 
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_redacted(f, "PrivateKey", &self.to_zeroizing_vec())
+    }
+}
+
 impl PrivateKey {
     pub fn public(&self) -> PublicKey {
         PublicKey {
@@ -84,11 +92,14 @@ impl ToBytesZeroizing for PrivateKey {
     }
 }
 
+impl_constant_time_eq!(PrivateKey);
+
 impl<'a> TryFrom<&'a str> for PrivateKey {
     type Error = KeyPairError;
 
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
-        let bytes = hex::decode(s).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let bytes =
+            hex::decode(s).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         PrivateKey::try_from(bytes.as_slice())
     }
 }
@@ -98,7 +109,7 @@ impl<'a> TryFrom<&'a [u8]> for PrivateKey {
 
     fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
         let key_pair = secp256k1::KeyPair::from_seckey_slice(SECP256K1, value)
-            .map_err(|_| KeyPairError::InvalidSecretKey)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
         Ok(PrivateKey {
             key_pair,
             no_aux_rand: false,
@@ -1,6 +1,6 @@
 use crate::schnorr::{bitcoin_tweak, Signature};
 use crate::traits::VerifyingKeyTrait;
-use crate::KeyPairError;
+use crate::{KeyPairError, KeyPairErrorKind};
 use bitcoin::key::TapTweak;
 use secp256k1::SECP256K1;
 use tw_hash::{H256, H264};
@@ -57,8 +57,8 @@ impl<'a> TryFrom<&'a [u8]> for PublicKey {
     type Error = KeyPairError;
 
     fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
-        let public =
-            secp256k1::PublicKey::from_slice(value).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let public = secp256k1::PublicKey::from_slice(value)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Ok(PublicKey { public })
     }
 }
@@ -99,7 +99,7 @@ impl<'a> TryFrom<&'a [u8]> for XOnlyPublicKey {
         };
 
         let public = secp256k1::XOnlyPublicKey::from_slice(x_only_slice)
-            .map_err(|_| KeyPairError::InvalidPublicKey)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Ok(XOnlyPublicKey { public })
     }
 }
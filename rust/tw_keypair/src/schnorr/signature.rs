@@ -1,4 +1,4 @@
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
 use tw_hash::H512;
 use tw_misc::traits::ToBytesVec;
 
@@ -13,7 +13,7 @@ impl Signature {
     pub fn from_bytes(sig: &[u8]) -> KeyPairResult<Self> {
         Ok(Signature {
             signature: secp256k1::schnorr::Signature::from_slice(sig)
-                .map_err(|_| KeyPairError::InvalidSignature)?,
+                .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?,
         })
     }
 
@@ -36,6 +36,8 @@ impl<'a> TryFrom<&'a [u8]> for Signature {
     }
 }
 
+impl_serde_as_hex!(Signature);
+
 impl From<secp256k1::schnorr::Signature> for Signature {
     fn from(sig: secp256k1::schnorr::Signature) -> Self {
         Signature { signature: sig }
@@ -6,7 +6,7 @@ use crate::starkex::private::PrivateKey;
 use crate::starkex::public::PublicKey;
 use crate::starkex::signature::Signature;
 use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
-use crate::{KeyPairError, KeyPairResult};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
 use tw_encoding::hex;
 use zeroize::Zeroizing;
 
@@ -61,7 +61,9 @@ impl<'a> TryFrom<&'a str> for KeyPair {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
@@ -13,6 +13,97 @@ pub use public::PublicKey;
 pub use signature::Signature;
 use starknet_ff::FieldElement;
 
+/// The number of bits a message hash must fit in to be signed: `0 <= hash < 2^251`.
+const MESSAGE_HASH_BITS: usize = 251;
+
+/// Defines how a message hash is converted to a field element to be signed or verified.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(u32)]
+pub enum MessageHashMode {
+    /// The message hash is signed as is. Signing fails if it's not less than `2^251`.
+    #[default]
+    Strict = 0,
+    /// The message hash is truncated to its 251 most significant bits, so any 256-bit digest can be signed.
+    /// A 32-byte digest (e.g. keccak256) is shifted right by 5 bits, shorter hashes are signed as is.
+    /// Signing fails if the hash is longer than 32 bytes, as it can't be a 256-bit digest.
+    ///
+    /// Note this is not the reduction of the starkware SDKs: `starkware-crypto-utils` rejects hashes
+    /// that are not less than `2^251` (same as [`MessageHashMode::Strict`]), while `elliptic` truncates
+    /// to the 252-bit curve order length. Signatures are only interoperable with verifiers that apply
+    /// the same 5-bit shift, or that are given the truncated hash.
+    Truncate = 1,
+}
+
+impl MessageHashMode {
+    pub fn from_raw(mode: u32) -> Option<MessageHashMode> {
+        match mode {
+            0 => Some(MessageHashMode::Strict),
+            1 => Some(MessageHashMode::Truncate),
+            _ => None,
+        }
+    }
+
+    /// Converts the `message` hash to a field element according to the mode.
+    fn to_field_element(self, message: &[u8]) -> Result<FieldElement, ()> {
+        match self {
+            MessageHashMode::Strict => field_element_from_bytes_be(message),
+            MessageHashMode::Truncate => {
+                field_element_from_bytes_be(&truncate_message_hash(message)?)
+            },
+        }
+    }
+}
+
+/// Defines how the signature nonce `k` is generated.
+/// Both modes start with the same RFC6979 nonce, but differ if it doesn't produce a valid signature.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[repr(u32)]
+pub enum NonceMode {
+    /// The starkware reference (`cairo-lang`) behavior:
+    /// the RFC6979 nonce is regenerated with an incremented extra entropy until the signature is valid.
+    #[default]
+    GrindExtraEntropy = 0,
+    /// The plain RFC6979 behavior, as in the `elliptic` based starkware JS SDK:
+    /// the next nonce is taken from the same HMAC-DRBG stream.
+    Rfc6979 = 1,
+}
+
+impl NonceMode {
+    pub fn from_raw(mode: u32) -> Option<NonceMode> {
+        match mode {
+            0 => Some(NonceMode::GrindExtraEntropy),
+            1 => Some(NonceMode::Rfc6979),
+            _ => None,
+        }
+    }
+}
+
+/// Keeps the 251 most significant bits of the `message` hash.
+/// Returns an error if the hash is longer than 32 bytes.
+fn truncate_message_hash(message: &[u8]) -> Result<Vec<u8>, ()> {
+    const HASH_LEN: usize = 32;
+    const SHIFT: usize = HASH_LEN * 8 - MESSAGE_HASH_BITS;
+
+    if message.len() > HASH_LEN {
+        return Err(());
+    }
+    // The hash fits 248 bits at most.
+    if message.len() < HASH_LEN {
+        return Ok(message.to_vec());
+    }
+
+    let mut carry = 0_u8;
+    let truncated = message
+        .iter()
+        .map(|byte| {
+            let shifted = (byte >> SHIFT) | carry;
+            carry = byte << (8 - SHIFT);
+            shifted
+        })
+        .collect();
+    Ok(truncated)
+}
+
 fn field_element_from_bytes_be(bytes: &[u8]) -> Result<FieldElement, ()> {
     const FIELD_ELEMENT_LEN: usize = 32;
 
@@ -28,7 +119,9 @@ fn field_element_from_bytes_be(bytes: &[u8]) -> Result<FieldElement, ()> {
 mod tests {
     use super::*;
     use crate::traits::{KeyPairTrait, SigningKeyTrait, VerifyingKeyTrait};
+    use crate::{KeyPairError, KeyPairErrorKind};
     use tw_encoding::hex;
+    use tw_hash::sha3::keccak256;
     use tw_hash::{H256, H512};
     use tw_misc::traits::{ToBytesVec, ToBytesZeroizing};
 
@@ -49,6 +142,83 @@ mod tests {
         assert!(keypair.verify(actual, hash_to_sign));
     }
 
+    #[test]
+    fn test_truncate_message_hash() {
+        let hash = [0xff; 32];
+        let mut expected = [0xff; 32];
+        expected[0] = 0x07;
+        assert_eq!(truncate_message_hash(&hash).unwrap(), expected);
+
+        // Longer inputs are not 256-bit digests.
+        truncate_message_hash(&[0xff; 40]).unwrap_err();
+
+        // Shorter hashes fit the field.
+        let hash = [0xff; 31];
+        assert_eq!(truncate_message_hash(&hash).unwrap(), hash);
+    }
+
+    #[test]
+    fn test_key_pair_sign_verify_truncate() {
+        let keypair =
+            KeyPair::try_from("0139fe4d6f02e666e86a6f58e65060f115cd3c185bd9e98bd829636931458f79")
+                .unwrap();
+        let hash_to_sign = keccak256(b"Hello, Starkex!");
+
+        let signature = keypair
+            .private()
+            .sign_with_hash_mode(&hash_to_sign, MessageHashMode::Truncate)
+            .unwrap();
+        assert!(keypair.public().verify_with_hash_mode(
+            &signature,
+            &hash_to_sign,
+            MessageHashMode::Truncate
+        ));
+        assert!(!keypair.public().verify_with_hash_mode(
+            &signature,
+            &hash_to_sign,
+            MessageHashMode::Strict
+        ));
+    }
+
+    #[test]
+    fn test_signature_recover() {
+        let keypair =
+            KeyPair::try_from("0139fe4d6f02e666e86a6f58e65060f115cd3c185bd9e98bd829636931458f79")
+                .unwrap();
+        let hash_to_sign =
+            hex::decode("06fea80189363a786037ed3e7ba546dad0ef7de49fccae0e31eb658b7dd4ea76")
+                .unwrap();
+
+        let signature = keypair.sign(hash_to_sign.clone()).unwrap();
+        let extended = signature.to_extended_vec().unwrap();
+        assert_eq!(extended.len(), Signature::EXTENDED_LEN);
+        assert_eq!(extended[..Signature::LEN], signature.to_vec());
+
+        let parsed = Signature::try_from(extended.as_slice()).unwrap();
+        assert_eq!(parsed.v(), signature.v());
+        let recovered = PublicKey::recover(&parsed, &hash_to_sign).unwrap();
+        assert_eq!(recovered.to_vec(), keypair.public().to_vec());
+
+        // The v-value is unknown.
+        let parsed = Signature::try_from(signature.to_vec().as_slice()).unwrap();
+        assert_eq!(parsed.v(), None);
+        assert_eq!(parsed.to_extended_vec(), None);
+        assert_eq!(
+            PublicKey::recover(&parsed, &hash_to_sign).err(),
+            Some(KeyPairError::new(KeyPairErrorKind::InvalidSignature))
+        );
+    }
+
+    #[test]
+    fn test_signature_invalid_v() {
+        let mut bytes = hex::decode("061ec782f76a66f6984efc3a1b6d152a124c701c00abdd2bf76641b4135c770f04e44e759cea02c23568bb4d8a09929bbca8768ab68270d50c18d214166ccd9a").unwrap();
+        bytes.extend_from_slice(
+            H256::from("0000000000000000000000000000000000000000000000000000000000000002")
+                .as_slice(),
+        );
+        Signature::try_from(bytes.as_slice()).err().unwrap();
+    }
+
     #[test]
     fn test_key_pair_get_private_public() {
         let privkey_bytes =
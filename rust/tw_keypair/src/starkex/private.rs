@@ -2,14 +2,16 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::starkex::field_element_from_bytes_be;
+use crate::redact::fmt_redacted;
 use crate::starkex::public::PublicKey;
 use crate::starkex::signature::Signature;
+use crate::starkex::{MessageHashMode, NonceMode};
 use crate::traits::SigningKeyTrait;
-use crate::{KeyPairError, KeyPairResult};
-use starknet_crypto::{
-    get_public_key, rfc6979_generate_k, sign, SignError, Signature as EcdsaSignature,
-};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use core::fmt;
+use rfc6979::HmacDrbg;
+use sha2::Sha256;
+use starknet_crypto::{get_public_key, rfc6979_generate_k, sign, ExtendedSignature, SignError};
 use starknet_ff::FieldElement;
 use tw_encoding::hex;
 use tw_hash::H256;
@@ -20,17 +22,57 @@ use zeroize::Zeroizing;
 /// As the number is coming from `rfc6979_generate_k` so the probability is lower.
 const SIGN_RETRIES: usize = 5;
 
+/// `EC_ORDER - 1` of the stark curve, big-endian.
+const EC_ORDER_MINUS_ONE: [u8; 32] = [
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xb7, 0x81, 0x12, 0x6d, 0xca, 0xe7, 0xb2, 0x32, 0x1e, 0x66, 0xa2, 0x41, 0xad, 0xc6, 0x4d, 0x2e,
+];
+
 /// Represents a private key that is used in `starknet` context.
 pub struct PrivateKey {
     secret: FieldElement,
 }
 
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_redacted(f, "PrivateKey", &self.to_zeroizing_vec())
+    }
+}
+
 impl PrivateKey {
     /// Returns an associated `starknet` public key.
     pub fn public(&self) -> PublicKey {
         let public_scalar = get_public_key(&self.secret);
         PublicKey::from_scalar(public_scalar)
     }
+
+    /// Signs the `message` hash converted to a field element according to the `hash_mode`.
+    pub fn sign_with_hash_mode(
+        &self,
+        message: &[u8],
+        hash_mode: MessageHashMode,
+    ) -> KeyPairResult<Signature> {
+        self.sign_with_nonce_mode(message, hash_mode, NonceMode::default())
+    }
+
+    /// Signs the `message` hash converted to a field element according to the `hash_mode`,
+    /// using the nonces generated according to the `nonce_mode`.
+    pub fn sign_with_nonce_mode(
+        &self,
+        message: &[u8],
+        hash_mode: MessageHashMode,
+        nonce_mode: NonceMode,
+    ) -> KeyPairResult<Signature> {
+        let hash_to_sign = hash_mode
+            .to_field_element(message)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignMessage))?;
+        let signature = match nonce_mode {
+            NonceMode::GrindExtraEntropy => ecdsa_sign(&self.secret, &hash_to_sign),
+            NonceMode::Rfc6979 => ecdsa_sign_rfc6979(&self.secret, &hash_to_sign),
+        }
+        .map_err(|_| KeyPairError::new(KeyPairErrorKind::SigningError))?;
+        Ok(Signature::new(signature))
+    }
 }
 
 impl SigningKeyTrait for PrivateKey {
@@ -38,11 +80,7 @@ impl SigningKeyTrait for PrivateKey {
     type Signature = Signature;
 
     fn sign(&self, message: Self::SigningMessage) -> KeyPairResult<Self::Signature> {
-        let hash_to_sign =
-            field_element_from_bytes_be(&message).map_err(|_| KeyPairError::InvalidSignMessage)?;
-        let signature =
-            ecdsa_sign(&self.secret, &hash_to_sign).map_err(|_| KeyPairError::SigningError)?;
-        Ok(Signature::new(signature))
+        self.sign_with_hash_mode(&message, MessageHashMode::Strict)
     }
 }
 
@@ -50,9 +88,10 @@ impl<'a> TryFrom<&'a [u8]> for PrivateKey {
     type Error = KeyPairError;
 
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
-        let bytes = H256::try_from(bytes).map_err(|_| KeyPairError::InvalidSecretKey)?;
+        let bytes = H256::try_from(bytes)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
         let secret = FieldElement::from_bytes_be(&bytes.take())
-            .map_err(|_| KeyPairError::InvalidSecretKey)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?;
         Ok(PrivateKey { secret })
     }
 }
@@ -61,7 +100,9 @@ impl<'a> TryFrom<&'a str> for PrivateKey {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = Zeroizing::new(hex::decode(hex).map_err(|_| KeyPairError::InvalidSecretKey)?);
+        let bytes = Zeroizing::new(
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))?,
+        );
         Self::try_from(bytes.as_slice())
     }
 }
@@ -73,22 +114,22 @@ impl ToBytesZeroizing for PrivateKey {
     }
 }
 
+impl_constant_time_eq!(PrivateKey);
+
 /// `starknet-core` depends on an out-dated `starknet-crypto` crate.
 /// We need to reimplement the same but using the latest `starknet-crypto` version.
 /// https://github.com/xJonathanLEI/starknet-rs/blob/0c78b365c2a7a7d4138553cba42fa69d695aa73d/starknet-core/src/crypto.rs#L34-L59
 pub fn ecdsa_sign(
     private_key: &FieldElement,
     message_hash: &FieldElement,
-) -> Result<EcdsaSignature, SignError> {
+) -> Result<ExtendedSignature, SignError> {
     // Seed-retry logic ported from `cairo-lang`
     let mut seed = None;
     for _ in 0..SIGN_RETRIES {
         let k = rfc6979_generate_k(message_hash, private_key, seed.as_ref());
 
         match sign(private_key, message_hash, &k) {
-            Ok(sig) => {
-                return Ok(EcdsaSignature { r: sig.r, s: sig.s });
-            },
+            Ok(sig) => return Ok(sig),
             Err(SignError::InvalidMessageHash) => return Err(SignError::InvalidMessageHash),
             Err(SignError::InvalidK) => {
                 // Bump seed and retry
@@ -102,9 +143,70 @@ pub fn ecdsa_sign(
     Err(SignError::InvalidMessageHash)
 }
 
+/// Signs the `message_hash` using the nonces taken from the same RFC6979 HMAC-DRBG stream
+/// until the signature is valid, like the `elliptic` based Starkware signers do.
+pub fn ecdsa_sign_rfc6979(
+    private_key: &FieldElement,
+    message_hash: &FieldElement,
+) -> Result<ExtendedSignature, SignError> {
+    let mut nonces = Rfc6979Nonces::new(private_key, message_hash);
+    for _ in 0..SIGN_RETRIES {
+        let k = nonces.next_k();
+
+        match sign(private_key, message_hash, &k) {
+            Ok(sig) => return Ok(sig),
+            Err(SignError::InvalidMessageHash) => return Err(SignError::InvalidMessageHash),
+            Err(SignError::InvalidK) => continue,
+        };
+    }
+    Err(SignError::InvalidMessageHash)
+}
+
+/// RFC6979 nonces of the stark curve, which order is 252 bits long.
+struct Rfc6979Nonces {
+    hmac_drbg: HmacDrbg<Sha256>,
+}
+
+impl Rfc6979Nonces {
+    fn new(private_key: &FieldElement, message_hash: &FieldElement) -> Self {
+        let secret = Zeroizing::new(private_key.to_bytes_be());
+        let hmac_drbg = HmacDrbg::<Sha256>::new(secret.as_ref(), &message_hash.to_bytes_be(), &[]);
+        Rfc6979Nonces { hmac_drbg }
+    }
+
+    /// Returns the next nonce in the `(1, EC_ORDER - 1)` range.
+    fn next_k(&mut self) -> FieldElement {
+        loop {
+            let mut k = Zeroizing::new([0_u8; 32]);
+            self.hmac_drbg.fill_bytes(k.as_mut());
+            // Keep the 252 leftmost bits.
+            shift_right_nibble(k.as_mut());
+
+            let is_zero_or_one = k[..31].iter().all(|byte| *byte == 0) && k[31] <= 1;
+            if is_zero_or_one || *k >= EC_ORDER_MINUS_ONE {
+                continue;
+            }
+            if let Ok(k) = FieldElement::from_bytes_be(&k) {
+                return k;
+            }
+        }
+    }
+}
+
+/// Shifts the big-endian `bytes` right by 4 bits.
+fn shift_right_nibble(bytes: &mut [u8]) {
+    for i in (1..bytes.len()).rev() {
+        bytes[i] = (bytes[i] >> 4) | (bytes[i - 1] << 4);
+    }
+    if let Some(first) = bytes.first_mut() {
+        *first >>= 4;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::starkex::field_element_from_bytes_be;
 
     #[test]
     fn test_starknet_sign_invalid_k() {
@@ -116,7 +218,104 @@ mod tests {
         // Return value does not implement Debug, so we cannot unwrap here.
         match private.sign(hash) {
             Ok(_) => panic!("Retry limit expected"),
-            Err(err) => assert_eq!(err, KeyPairError::SigningError),
+            Err(err) => assert_eq!(err, KeyPairError::new(KeyPairErrorKind::SigningError)),
+        }
+    }
+
+    #[test]
+    fn test_starknet_nonce_modes() {
+        let private_key = field_element_from_bytes_be(
+            &hex::decode("0139fe4d6f02e666e86a6f58e65060f115cd3c185bd9e98bd829636931458f79")
+                .unwrap(),
+        )
+        .unwrap();
+        let message_hash = field_element_from_bytes_be(
+            &hex::decode("06fea80189363a786037ed3e7ba546dad0ef7de49fccae0e31eb658b7dd4ea76")
+                .unwrap(),
+        )
+        .unwrap();
+        let to_hex = |k: FieldElement| hex::encode(k.to_bytes_be(), false);
+
+        // Both modes start with the same nonce.
+        let first_k = "0738245b914bff42937b4cb377be9920abd5e8476e8998bfc74cb6cca4b9167f";
+        let mut nonces = Rfc6979Nonces::new(&private_key, &message_hash);
+        assert_eq!(to_hex(nonces.next_k()), first_k);
+        assert_eq!(
+            to_hex(rfc6979_generate_k(&message_hash, &private_key, None)),
+            first_k
+        );
+
+        // The next nonce is either taken from the same stream or generated with an extra entropy.
+        assert_eq!(
+            to_hex(nonces.next_k()),
+            "02c9a8e20680d4047b3a0bf4c7d3f2e3e2e30647f81158322751c3d6037f1aeb"
+        );
+        let seed = FieldElement::ONE;
+        assert_eq!(
+            to_hex(rfc6979_generate_k(&message_hash, &private_key, Some(&seed))),
+            "01b76fc776b9f7e4ed6c5816e569baf8a69a6dd5e39162ba7a35bdcda5a83b01"
+        );
+
+        let grind_signature = ecdsa_sign(&private_key, &message_hash).unwrap();
+        let rfc6979_signature = ecdsa_sign_rfc6979(&private_key, &message_hash).unwrap();
+        assert_eq!(grind_signature.r, rfc6979_signature.r);
+        assert_eq!(grind_signature.s, rfc6979_signature.s);
+    }
+
+    /// Cross-checks the second nonces of both modes with `starknet_crypto`.
+    /// The nonces and signatures are also reproduced with an independent RFC6979 and stark curve implementation.
+    #[test]
+    fn test_starknet_second_nonce_signatures() {
+        let private_key = field_element_from_bytes_be(
+            &hex::decode("0139fe4d6f02e666e86a6f58e65060f115cd3c185bd9e98bd829636931458f79")
+                .unwrap(),
+        )
+        .unwrap();
+        let message_hash = field_element_from_bytes_be(
+            &hex::decode("06fea80189363a786037ed3e7ba546dad0ef7de49fccae0e31eb658b7dd4ea76")
+                .unwrap(),
+        )
+        .unwrap();
+        let public_key = get_public_key(&private_key);
+        let to_hex = |k: FieldElement| hex::encode(k.to_bytes_be(), false);
+
+        // `NonceMode::Rfc6979`: the HMAC-DRBG stream skips two out of range candidates before the second nonce.
+        let mut nonces = Rfc6979Nonces::new(&private_key, &message_hash);
+        nonces.next_k();
+        let stream_k = nonces.next_k();
+        // `NonceMode::GrindExtraEntropy`: `cairo-lang` regenerates the nonce with the seed 1.
+        let seed_k = rfc6979_generate_k(&message_hash, &private_key, Some(&FieldElement::ONE));
+
+        let test_cases = [
+            (
+                stream_k,
+                "06b6e9e1765ca4cd6cc2bbd3f3ff2d081f0ea45ee314f465436e9842fe552a76",
+                "05676cd58cd9c380557200c4157ba383c3c4949dc6cf3f5cc53246954b71e8eb",
+            ),
+            (
+                seed_k,
+                "0063565641211209b579b01b09882802b140222d04053285a62e969f1725dfe7",
+                "00ad12052ce4cb505e694c0b9185efaaab826437ae38f2cfb960c3c3e317a659",
+            ),
+        ];
+        for (k, expected_r, expected_s) in test_cases {
+            let signature = sign(&private_key, &message_hash, &k).unwrap();
+            assert_eq!(to_hex(signature.r), expected_r);
+            assert_eq!(to_hex(signature.s), expected_s);
+            assert!(starknet_crypto::verify(
+                &public_key,
+                &message_hash,
+                &signature.r,
+                &signature.s
+            )
+            .unwrap());
         }
     }
+
+    #[test]
+    fn test_shift_right_nibble() {
+        let mut bytes = [0xab, 0xcd, 0xef];
+        shift_right_nibble(&mut bytes);
+        assert_eq!(bytes, [0x0a, 0xbc, 0xde]);
+    }
 }
@@ -2,11 +2,11 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::starkex::field_element_from_bytes_be;
 use crate::starkex::signature::Signature;
+use crate::starkex::MessageHashMode;
 use crate::traits::VerifyingKeyTrait;
-use crate::KeyPairError;
-use starknet_crypto::verify as ecdsa_verify;
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use starknet_crypto::{recover as ecdsa_recover, verify as ecdsa_verify};
 use starknet_ff::FieldElement;
 use tw_encoding::hex;
 use tw_hash::H256;
@@ -23,6 +23,45 @@ impl PublicKey {
     pub(crate) fn from_scalar(public: FieldElement) -> PublicKey {
         PublicKey { public }
     }
+
+    /// Recovers the public key from the `signature` of the `message` hash.
+    /// The `signature` is required to carry the v-value.
+    pub fn recover(signature: &Signature, message: &[u8]) -> KeyPairResult<PublicKey> {
+        PublicKey::recover_with_hash_mode(signature, message, MessageHashMode::Strict)
+    }
+
+    /// Recovers the public key from the `signature` of the `message` hash
+    /// converted to a field element according to the `hash_mode`.
+    /// The `signature` is required to carry the v-value.
+    pub fn recover_with_hash_mode(
+        signature: &Signature,
+        message: &[u8],
+        hash_mode: MessageHashMode,
+    ) -> KeyPairResult<PublicKey> {
+        let v = signature
+            .v
+            .ok_or(KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
+        let hash = hash_mode
+            .to_field_element(message)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignMessage))?;
+        let ecdsa_signature = signature.inner();
+        ecdsa_recover(&hash, &ecdsa_signature.r, &ecdsa_signature.s, &v)
+            .map(PublicKey::from_scalar)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))
+    }
+
+    /// Verifies the `signature` of the `message` hash converted to a field element according to the `hash_mode`.
+    pub fn verify_with_hash_mode(
+        &self,
+        signature: &Signature,
+        message: &[u8],
+        hash_mode: MessageHashMode,
+    ) -> bool {
+        let hash = try_or_false!(hash_mode.to_field_element(message));
+        let ecdsa_signature = signature.inner();
+        ecdsa_verify(&self.public, &hash, &ecdsa_signature.r, &ecdsa_signature.s)
+            .unwrap_or_default()
+    }
 }
 
 impl VerifyingKeyTrait for PublicKey {
@@ -30,10 +69,7 @@ impl VerifyingKeyTrait for PublicKey {
     type VerifySignature = Signature;
 
     fn verify(&self, signature: Self::VerifySignature, message: Self::SigningMessage) -> bool {
-        let hash = try_or_false!(field_element_from_bytes_be(&message));
-        let ecdsa_signature = signature.inner();
-        ecdsa_verify(&self.public, &hash, &ecdsa_signature.r, &ecdsa_signature.s)
-            .unwrap_or_default()
+        self.verify_with_hash_mode(&signature, &message, MessageHashMode::Strict)
     }
 }
 
@@ -41,9 +77,10 @@ impl<'a> TryFrom<&'a [u8]> for PublicKey {
     type Error = KeyPairError;
 
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
-        let bytes = H256::try_from(bytes).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let bytes = H256::try_from(bytes)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         let public_scalar = FieldElement::from_bytes_be(&bytes.take())
-            .map_err(|_| KeyPairError::InvalidPublicKey)?;
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Ok(PublicKey::from_scalar(public_scalar))
     }
 }
@@ -52,7 +89,8 @@ impl<'a> TryFrom<&'a str> for PublicKey {
     type Error = KeyPairError;
 
     fn try_from(hex: &'a str) -> Result<Self, Self::Error> {
-        let bytes = hex::decode(hex).map_err(|_| KeyPairError::InvalidPublicKey)?;
+        let bytes =
+            hex::decode(hex).map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidPublicKey))?;
         Self::try_from(bytes.as_slice())
     }
 }
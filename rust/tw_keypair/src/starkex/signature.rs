@@ -2,7 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use crate::KeyPairError;
+use crate::{KeyPairError, KeyPairErrorKind};
 use starknet_ff::FieldElement;
 use std::ops::Range;
 use tw_hash::H256;
@@ -12,25 +12,37 @@ use tw_misc::traits::ToBytesVec;
 const R_RANGE: Range<usize> = 0..32;
 /// cbindgen:ignore
 const S_RANGE: Range<usize> = 32..64;
+/// cbindgen:ignore
+const V_RANGE: Range<usize> = 64..96;
 
 /// Represents a `starknet` signature.
+/// Optionally carries the `v` y-parity value required to recover the public key.
 pub struct Signature {
     pub(crate) signature: starknet_crypto::Signature,
+    pub(crate) v: Option<FieldElement>,
 }
 
 /// cbindgen:ignore
 impl Signature {
     /// The number of bytes for a serialized signature representation.
     pub const LEN: usize = 64;
+    /// The number of bytes for a serialized `(r, s, v)` signature representation.
+    pub const EXTENDED_LEN: usize = 96;
 
     /// Returns the number of bytes for a serialized signature representation.
     pub const fn len() -> usize {
         Self::LEN
     }
 
-    /// Creates a `starknet` signature from the given [`starknet_crypto::Signature`].
-    pub(crate) fn new(signature: starknet_crypto::Signature) -> Signature {
-        Signature { signature }
+    /// Creates a `starknet` signature from the given [`starknet_crypto::ExtendedSignature`].
+    pub(crate) fn new(signature: starknet_crypto::ExtendedSignature) -> Signature {
+        Signature {
+            signature: starknet_crypto::Signature {
+                r: signature.r,
+                s: signature.s,
+            },
+            v: Some(signature.v),
+        }
     }
 
     /// Returns a reference to the inner [`starknet_crypto::Signature`].
@@ -47,6 +59,21 @@ impl Signature {
     pub fn s(&self) -> H256 {
         H256::from(self.signature.s.to_bytes_be())
     }
+
+    /// Returns a v-value (y-parity) as 32 byte array if it's known.
+    pub fn v(&self) -> Option<H256> {
+        self.v.map(|v| H256::from(v.to_bytes_be()))
+    }
+
+    /// Returns the 96 byte `(r, s, v)` representation if the v-value is known.
+    pub fn to_extended_vec(&self) -> Option<Vec<u8>> {
+        let v = self.v()?;
+        let mut to_return = Vec::with_capacity(Signature::EXTENDED_LEN);
+        to_return.extend_from_slice(self.r().as_slice());
+        to_return.extend_from_slice(self.s().as_slice());
+        to_return.extend_from_slice(v.as_slice());
+        Some(to_return)
+    }
 }
 
 impl ToBytesVec for Signature {
@@ -61,21 +88,36 @@ impl ToBytesVec for Signature {
 impl<'a> TryFrom<&'a [u8]> for Signature {
     type Error = KeyPairError;
 
+    /// Parses either a 64 byte `(r, s)` or a 96 byte `(r, s, v)` signature.
     fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
-        if bytes.len() != Signature::len() {
-            return Err(KeyPairError::InvalidSignature);
+        fn field_element(bytes: &[u8]) -> Result<FieldElement, KeyPairError> {
+            let bytes = H256::try_from(bytes)
+                .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))?;
+            FieldElement::from_bytes_be(&bytes.take())
+                .map_err(|_| KeyPairError::new(KeyPairErrorKind::InvalidSignature))
         }
 
-        let r_bytes = H256::try_from(&bytes[R_RANGE]).expect("Expected a valid r range");
-        let s_bytes = H256::try_from(&bytes[S_RANGE]).expect("Expected a valid s range");
+        let v = match bytes.len() {
+            Signature::LEN => None,
+            Signature::EXTENDED_LEN => {
+                let v = field_element(&bytes[V_RANGE])?;
+                // `v` is expected to be the y-parity.
+                if v != FieldElement::ZERO && v != FieldElement::ONE {
+                    return Err(KeyPairError::new(KeyPairErrorKind::InvalidSignature));
+                }
+                Some(v)
+            },
+            _ => return Err(KeyPairError::new(KeyPairErrorKind::InvalidSignature)),
+        };
 
-        let r = FieldElement::from_bytes_be(&r_bytes.take())
-            .map_err(|_| KeyPairError::InvalidSignature)?;
-        let s = FieldElement::from_bytes_be(&s_bytes.take())
-            .map_err(|_| KeyPairError::InvalidSignature)?;
+        let r = field_element(&bytes[R_RANGE])?;
+        let s = field_element(&bytes[S_RANGE])?;
 
         Ok(Signature {
             signature: starknet_crypto::Signature { r, s },
+            v,
         })
     }
 }
+
+impl_serde_as_hex!(Signature);
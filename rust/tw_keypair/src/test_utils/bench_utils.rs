@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Deterministic keys and messages for benchmarks.
+//! The same `seed` always gives the same output, so benchmark runs are comparable.
+
+use crate::ed25519::cardano::ExtendedPrivateKey;
+use crate::tw::{Curve, PrivateKey};
+use tw_hash::sha2::sha256;
+use tw_hash::H256;
+
+/// The number of attempts to generate a private key valid for a curve.
+const MAX_KEY_ATTEMPTS: u64 = 1000;
+/// The length of a message signed with curves that accept messages of any length.
+pub const DEFAULT_MESSAGE_LEN: usize = 128;
+
+/// Returns `len` pseudo-random bytes generated from the `seed`.
+pub fn deterministic_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(len + H256::len());
+    let mut counter = 0_u64;
+    while bytes.len() < len {
+        let mut block = seed.to_be_bytes().to_vec();
+        block.extend_from_slice(&counter.to_be_bytes());
+        bytes.extend_from_slice(&sha256(&block));
+        counter += 1;
+    }
+    bytes.truncate(len);
+    bytes
+}
+
+/// Returns a private key valid for the given `curve` generated from the `seed`.
+pub fn deterministic_private_key(seed: u64, curve: Curve) -> PrivateKey {
+    let len = match curve {
+        Curve::Ed25519ExtendedCardano => ExtendedPrivateKey::LEN,
+        _ => H256::len(),
+    };
+    (0..MAX_KEY_ATTEMPTS)
+        .map(|attempt| deterministic_bytes(seed.wrapping_add(attempt << 32), len))
+        .find(|bytes| PrivateKey::is_valid(bytes, curve))
+        .and_then(|bytes| PrivateKey::new(bytes).ok())
+        .expect("Expected a valid private key to be generated")
+}
+
+/// Returns a message that can be signed with the given `curve` generated from the `seed`.
+pub fn deterministic_message(seed: u64, curve: Curve) -> Vec<u8> {
+    if curve == Curve::Starkex {
+        let mut message = deterministic_bytes(seed, H256::len());
+        // Keep the message hash less than the Stark field modulus.
+        message[0] &= 0x03;
+        return message;
+    }
+    let len = PrivateKey::sign_message_len(curve).unwrap_or(DEFAULT_MESSAGE_LEN);
+    deterministic_bytes(seed, len)
+}
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::tw::{Curve, PublicKeyType};
+
+/// Returns the public key type derived from a private key of the given `curve`.
+pub fn public_key_type(curve: Curve) -> PublicKeyType {
+    match curve {
+        Curve::Secp256k1 => PublicKeyType::Secp256k1,
+        Curve::Ed25519 => PublicKeyType::Ed25519,
+        Curve::Ed25519Blake2bNano => PublicKeyType::Ed25519Blake2b,
+        Curve::Curve25519Waves => PublicKeyType::Curve25519Waves,
+        Curve::Nist256p1 => PublicKeyType::Nist256p1,
+        Curve::Ed25519ExtendedCardano => PublicKeyType::Ed25519ExtendedCardano,
+        Curve::Starkex => PublicKeyType::Starkex,
+        Curve::Schnorr => PublicKeyType::Schnorr,
+    }
+}
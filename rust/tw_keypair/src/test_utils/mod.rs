@@ -2,6 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+pub mod bench_utils;
+pub mod curve_utils;
 pub mod tw_crypto_box_helpers;
 pub mod tw_private_key_helper;
 pub mod tw_public_key_helper;
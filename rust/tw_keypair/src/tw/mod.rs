@@ -2,30 +2,58 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use serde::Deserialize;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
 
+#[cfg(feature = "std")]
 mod private;
+#[cfg(feature = "std")]
 mod public;
+mod signing_hash;
 
+#[cfg(feature = "std")]
 pub use private::PrivateKey;
+#[cfg(feature = "std")]
 pub use public::PublicKey;
+pub use signing_hash::{SigningHash, SigningHashSpec};
 
 pub type Signature = Vec<u8>;
 
+/// An error returned when parsing a [`Curve`] or [`PublicKeyType`] from an unknown name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnknownNameError;
+
+impl fmt::Display for UnknownNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Unknown name")
+    }
+}
+
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Curve {
+    #[cfg_attr(feature = "serde", serde(rename = "secp256k1"))]
     Secp256k1 = 0,
+    #[cfg_attr(feature = "serde", serde(rename = "ed25519"))]
     Ed25519 = 1,
+    #[cfg_attr(feature = "serde", serde(rename = "ed25519Blake2bNano"))]
     Ed25519Blake2bNano = 2,
     /// Waves blockchain specific `curve25519`.
+    #[cfg_attr(feature = "serde", serde(rename = "curve25519"))]
     Curve25519Waves = 3,
+    #[cfg_attr(feature = "serde", serde(rename = "nist256p1"))]
     Nist256p1 = 4,
     /// Cardano blockchain specific `ed25519` extended key.
+    #[cfg_attr(feature = "serde", serde(rename = "ed25519ExtendedCardano"))]
     Ed25519ExtendedCardano = 5,
+    #[cfg_attr(feature = "serde", serde(rename = "starkex"))]
     Starkex = 6,
-    Schnorr = 7,
+    /// `7` is `TWCurveNone` in C++.
+    #[cfg_attr(feature = "serde", serde(rename = "schnorr"))]
+    Schnorr = 8,
 }
 
 impl Curve {
@@ -39,38 +67,96 @@ impl Curve {
             4 => Some(Curve::Nist256p1),
             5 => Some(Curve::Ed25519ExtendedCardano),
             6 => Some(Curve::Starkex),
-            7 => Some(Curve::Schnorr),
+            8 => Some(Curve::Schnorr),
             _ => None,
         }
     }
+
+    /// Returns the stable FFI integer value of the curve.
+    pub fn to_raw(self) -> u32 {
+        self as u32
+    }
+
+    /// Returns all curves supported in Rust, ordered by their FFI integer values.
+    pub fn all() -> &'static [Curve] {
+        &[
+            Curve::Secp256k1,
+            Curve::Ed25519,
+            Curve::Ed25519Blake2bNano,
+            Curve::Curve25519Waves,
+            Curve::Nist256p1,
+            Curve::Ed25519ExtendedCardano,
+            Curve::Starkex,
+            Curve::Schnorr,
+        ]
+    }
+
+    /// Returns the curve name as it's used in `registry.json`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Curve::Secp256k1 => "secp256k1",
+            Curve::Ed25519 => "ed25519",
+            Curve::Ed25519Blake2bNano => "ed25519Blake2bNano",
+            Curve::Curve25519Waves => "curve25519",
+            Curve::Nist256p1 => "nist256p1",
+            Curve::Ed25519ExtendedCardano => "ed25519ExtendedCardano",
+            Curve::Starkex => "starkex",
+            Curve::Schnorr => "schnorr",
+        }
+    }
+}
+
+impl fmt::Display for Curve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses the curve name as it's used in `registry.json`.
+/// The names from `TWCurve.h` are accepted too, e.g. `ed25519-blake2b-nano`.
+impl FromStr for Curve {
+    type Err = UnknownNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ed25519-blake2b-nano" => Ok(Curve::Ed25519Blake2bNano),
+            "ed25519-cardano-seed" => Ok(Curve::Ed25519ExtendedCardano),
+            _ => Curve::all()
+                .iter()
+                .find(|curve| curve.as_str() == s)
+                .copied()
+                .ok_or(UnknownNameError),
+        }
+    }
 }
 
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[non_exhaustive]
 pub enum PublicKeyType {
-    #[serde(rename = "secp256k1")]
+    #[cfg_attr(feature = "serde", serde(rename = "secp256k1"))]
     Secp256k1 = 0,
-    #[serde(rename = "secp256k1Extended")]
+    #[cfg_attr(feature = "serde", serde(rename = "secp256k1Extended"))]
     Secp256k1Extended = 1,
-    #[serde(rename = "nist256p1")]
+    #[cfg_attr(feature = "serde", serde(rename = "nist256p1"))]
     Nist256p1 = 2,
-    #[serde(rename = "nist256p1Extended")]
+    #[cfg_attr(feature = "serde", serde(rename = "nist256p1Extended"))]
     Nist256p1Extended = 3,
-    #[serde(rename = "ed25519")]
+    #[cfg_attr(feature = "serde", serde(rename = "ed25519"))]
     Ed25519 = 4,
-    #[serde(rename = "ed25519Blake2b")]
+    #[cfg_attr(feature = "serde", serde(rename = "ed25519Blake2b"))]
     Ed25519Blake2b = 5,
     /// Waves blockchain specific public key.
-    #[serde(rename = "curve25519")]
+    #[cfg_attr(feature = "serde", serde(rename = "curve25519"))]
     Curve25519Waves = 6,
     /// Cardano blockchain specific extended public key.
-    #[serde(rename = "ed25519Cardano")]
+    #[cfg_attr(feature = "serde", serde(rename = "ed25519Cardano"))]
     Ed25519ExtendedCardano = 7,
-    #[serde(rename = "starkex")]
+    #[cfg_attr(feature = "serde", serde(rename = "starkex"))]
     Starkex = 8,
-    #[serde(rename = "schnorr")]
+    #[cfg_attr(feature = "serde", serde(rename = "schnorr"))]
     Schnorr = 9,
 }
 
@@ -91,11 +177,68 @@ impl PublicKeyType {
             _ => None,
         }
     }
+
+    /// Returns the stable FFI integer value of the pubkey type.
+    pub fn to_raw(self) -> u32 {
+        self as u32
+    }
+
+    /// Returns all pubkey types supported in Rust, ordered by their FFI integer values.
+    pub fn all() -> &'static [PublicKeyType] {
+        &[
+            PublicKeyType::Secp256k1,
+            PublicKeyType::Secp256k1Extended,
+            PublicKeyType::Nist256p1,
+            PublicKeyType::Nist256p1Extended,
+            PublicKeyType::Ed25519,
+            PublicKeyType::Ed25519Blake2b,
+            PublicKeyType::Curve25519Waves,
+            PublicKeyType::Ed25519ExtendedCardano,
+            PublicKeyType::Starkex,
+            PublicKeyType::Schnorr,
+        ]
+    }
+
+    /// Returns the pubkey type name as it's used in `registry.json`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PublicKeyType::Secp256k1 => "secp256k1",
+            PublicKeyType::Secp256k1Extended => "secp256k1Extended",
+            PublicKeyType::Nist256p1 => "nist256p1",
+            PublicKeyType::Nist256p1Extended => "nist256p1Extended",
+            PublicKeyType::Ed25519 => "ed25519",
+            PublicKeyType::Ed25519Blake2b => "ed25519Blake2b",
+            PublicKeyType::Curve25519Waves => "curve25519",
+            PublicKeyType::Ed25519ExtendedCardano => "ed25519Cardano",
+            PublicKeyType::Starkex => "starkex",
+            PublicKeyType::Schnorr => "schnorr",
+        }
+    }
+}
+
+impl fmt::Display for PublicKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses the pubkey type name as it's used in `registry.json`.
+impl FromStr for PublicKeyType {
+    type Err = UnknownNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PublicKeyType::all()
+            .iter()
+            .find(|ty| ty.as_str() == s)
+            .copied()
+            .ok_or(UnknownNameError)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn test_curve_from_raw() {
@@ -107,8 +250,9 @@ mod tests {
             (4, Some(Curve::Nist256p1)),
             (5, Some(Curve::Ed25519ExtendedCardano)),
             (6, Some(Curve::Starkex)),
-            (7, Some(Curve::Schnorr)),
-            (8, None),
+            (7, None),
+            (8, Some(Curve::Schnorr)),
+            (9, None),
         ];
         for (raw, expected) in tests {
             assert_eq!(Curve::from_raw(raw), expected);
@@ -134,4 +278,46 @@ mod tests {
             assert_eq!(PublicKeyType::from_raw(raw), expected);
         }
     }
+
+    #[test]
+    fn test_curve_name() {
+        for (i, curve) in Curve::all().iter().copied().enumerate() {
+            assert_eq!(curve.to_raw(), i as u32);
+            assert_eq!(Curve::from_raw(curve.to_raw()), Some(curve));
+            assert_eq!(Curve::from_str(curve.as_str()), Ok(curve));
+            assert_eq!(curve.to_string(), curve.as_str());
+        }
+
+        assert_eq!("secp256k1".parse(), Ok(Curve::Secp256k1));
+        assert_eq!("curve25519".parse(), Ok(Curve::Curve25519Waves));
+        assert_eq!(
+            "ed25519-blake2b-nano".parse(),
+            Ok(Curve::Ed25519Blake2bNano)
+        );
+        assert_eq!(
+            "ed25519-cardano-seed".parse(),
+            Ok(Curve::Ed25519ExtendedCardano)
+        );
+        assert_eq!("Secp256k1".parse::<Curve>(), Err(UnknownNameError));
+        assert_eq!("".parse::<Curve>(), Err(UnknownNameError));
+    }
+
+    #[test]
+    fn test_public_key_type_name() {
+        for (i, ty) in PublicKeyType::all().iter().copied().enumerate() {
+            assert_eq!(ty.to_raw(), i as u32);
+            assert_eq!(PublicKeyType::from_raw(ty.to_raw()), Some(ty));
+            assert_eq!(PublicKeyType::from_str(ty.as_str()), Ok(ty));
+            assert_eq!(ty.to_string(), ty.as_str());
+        }
+
+        assert_eq!(
+            "ed25519Cardano".parse(),
+            Ok(PublicKeyType::Ed25519ExtendedCardano)
+        );
+        assert_eq!(
+            "ed25519Extended".parse::<PublicKeyType>(),
+            Err(UnknownNameError)
+        );
+    }
 }
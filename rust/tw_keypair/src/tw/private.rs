@@ -3,21 +3,69 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::ecdsa::{nist256p1, secp256k1};
+use crate::redact::fmt_redacted;
 use crate::schnorr;
 use crate::traits::SigningKeyTrait;
 use crate::tw::{Curve, PublicKey, PublicKeyType};
-use crate::{ed25519, starkex, KeyPairError, KeyPairResult};
+use crate::{ed25519, starkex, KeyPairError, KeyPairErrorContext, KeyPairErrorKind, KeyPairResult};
+use std::fmt;
 use std::ops::Range;
-use tw_hash::H256;
+use std::sync::OnceLock;
+use tw_encoding::hex;
+use tw_memory::constant_time::ct_is_zero;
+use tw_memory::secure_bytes::SecureBytes;
 use tw_misc::traits::ToBytesVec;
-use zeroize::ZeroizeOnDrop;
+use zeroize::{ZeroizeOnDrop, Zeroizing};
 
 /// Represents a private key that can be used to sign messages with different elliptic curves.
 ///
-/// TODO add `secp256k1: Once<each_curve::PrivateKey>` for each curve.
+/// The key bytes are stored in [`SecureBytes`]: wiped on drop and locked in RAM if supported.
+/// This only covers the Rust copy of the key. The C++ `PrivateKey` and `TWData` returned by
+/// `TWPrivateKeyData` are wiped when destroyed, but they are never locked in RAM,
+/// and temporary copies made by the C++ signing code are not wiped.
+/// Private keys are compared in constant time.
+///
+/// The parsed `secp256k1` key is cached on first use,
+/// so signing many messages with the same key doesn't repeat the key setup.
+/// The cache holds a second copy of the secret outside of [`SecureBytes`]:
+/// it is wiped on drop (as are the clones handed out for signing), but it isn't locked in RAM.
+///
+/// With the `export-policy` feature, a key can be marked as non-exportable:
+/// [`PrivateKey::bytes`], [`PrivateKey::key`] and [`PrivateKey::extended_cardano_key`]
+/// return [`KeyPairErrorKind::NonExportableKey`] then, while signing still works.
+/// Hosts that hold keys on the C++ side use `TWPrivateKeyDisableExport` instead.
 #[derive(ZeroizeOnDrop)]
 pub struct PrivateKey {
-    bytes: Vec<u8>,
+    bytes: SecureBytes,
+    /// Not zeroized by the derive: [`secp256k1::PrivateKey`] is [`ZeroizeOnDrop`] by itself,
+    /// which is checked at compile time by [`assert_zeroize_on_drop`].
+    #[zeroize(skip)]
+    secp256k1: OnceLock<KeyPairResult<secp256k1::PrivateKey>>,
+    #[cfg(feature = "export-policy")]
+    #[zeroize(skip)]
+    exportable: bool,
+}
+
+const _: fn() = assert_zeroize_on_drop::<secp256k1::PrivateKey>;
+
+/// Fails to compile if the cached key type stops wiping itself on drop.
+fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+/// Compares the key bytes only.
+impl PartialEq for PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for PrivateKey {}
+
+/// Never prints the key bytes, but their fingerprint only.
+/// Use [`PrivateKey::to_zeroizing_hex`] to export the key deliberately.
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_redacted(f, "PrivateKey", &self.bytes)
+    }
 }
 
 /// cbindgen:ignore
@@ -30,33 +78,83 @@ impl PrivateKey {
     const EXTENDED_CARDANO_RANGE: Range<usize> = 0..Self::CARDANO_SIZE;
 
     /// Validates the given `bytes` secret and creates a private key.
+    /// Returns an error with the context of why the secret is invalid.
     pub fn new(bytes: Vec<u8>) -> KeyPairResult<PrivateKey> {
+        if bytes.len() != Self::SIZE && bytes.len() != Self::CARDANO_SIZE {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey)
+                .with_field("private key")
+                .with_expected_len(Self::SIZE)
+                .with_actual_len(bytes.len()));
+        }
         if !Self::is_valid_general(&bytes) {
-            return Err(KeyPairError::InvalidSecretKey);
+            return Err(
+                KeyPairError::new(KeyPairErrorKind::InvalidSecretKey).with_field("private key")
+            );
+        }
+        Ok(PrivateKey {
+            bytes: SecureBytes::from(bytes),
+            secp256k1: OnceLock::new(),
+            #[cfg(feature = "export-policy")]
+            exportable: true,
+        })
+    }
+
+    /// Validates the given `bytes` secret and creates a private key
+    /// that can be used for signing, but whose bytes can't be exported.
+    #[cfg(feature = "export-policy")]
+    pub fn new_non_exportable(bytes: Vec<u8>) -> KeyPairResult<PrivateKey> {
+        let mut private = Self::new(bytes)?;
+        private.disable_export();
+        Ok(private)
+    }
+
+    /// Forbids exporting the key bytes. The key can't be made exportable again.
+    #[cfg(feature = "export-policy")]
+    pub fn disable_export(&mut self) {
+        self.exportable = false;
+    }
+
+    /// Whether the key bytes can be exported with [`PrivateKey::bytes`] or [`PrivateKey::key`].
+    pub fn is_exportable(&self) -> bool {
+        #[cfg(feature = "export-policy")]
+        return self.exportable;
+        #[cfg(not(feature = "export-policy"))]
+        return true;
+    }
+
+    /// Returns the raw key bytes.
+    /// Returns [`KeyPairErrorKind::NonExportableKey`] if the export is forbidden.
+    pub fn bytes(&self) -> KeyPairResult<&[u8]> {
+        if !self.is_exportable() {
+            return Err(KeyPairError::new(KeyPairErrorKind::NonExportableKey));
         }
-        Ok(PrivateKey { bytes })
+        Ok(&self.bytes)
     }
 
-    pub fn bytes(&self) -> &[u8] {
-        &self.bytes
+    /// Returns the hex-encoded key bytes, that are wiped on drop.
+    /// Returns [`KeyPairErrorKind::NonExportableKey`] if the export is forbidden.
+    pub fn to_zeroizing_hex(&self) -> KeyPairResult<Zeroizing<String>> {
+        let bytes = self.bytes()?;
+        Ok(Zeroizing::new(hex::encode(bytes, false)))
     }
 
     /// Returns the 32 byte array - the essential private key data.
-    pub fn key(&self) -> H256 {
-        assert!(
-            self.bytes.len() >= Self::SIZE,
-            "'PrivateKey::bytes' has an unexpected length"
-        );
-        H256::try_from(&self.bytes[Self::KEY_RANGE])
-            .expect("H256 and KEY_RANGE must be 32 byte length")
+    /// The bytes are borrowed from the [`SecureBytes`] storage, so no copy of the secret is made.
+    /// Returns [`KeyPairErrorKind::NonExportableKey`] if the export is forbidden.
+    pub fn key(&self) -> KeyPairResult<&[u8]> {
+        if !self.is_exportable() {
+            return Err(KeyPairError::new(KeyPairErrorKind::NonExportableKey));
+        }
+        Ok(self.secret())
     }
 
     /// Returns the 192 byte array - the essential cardano extended private key data.
+    /// Returns [`KeyPairErrorKind::NonExportableKey`] if the export is forbidden.
     pub fn extended_cardano_key(&self) -> KeyPairResult<&[u8]> {
-        if self.bytes.len() != Self::CARDANO_SIZE {
-            return Err(KeyPairError::InvalidSecretKey);
+        if !self.is_exportable() {
+            return Err(KeyPairError::new(KeyPairErrorKind::NonExportableKey));
         }
-        Ok(&self.bytes[Self::EXTENDED_CARDANO_RANGE])
+        self.extended_cardano_secret()
     }
 
     /// Checks if the given `bytes` secret is valid in general (without a concrete curve).
@@ -65,7 +163,7 @@ impl PrivateKey {
             return false;
         }
         // Check for zero address.
-        !bytes.iter().all(|byte| *byte == 0)
+        !ct_is_zero(bytes)
     }
 
     /// Checks if the given `bytes` secret is valid.
@@ -95,28 +193,69 @@ impl PrivateKey {
     }
 
     /// Signs a `message` with using the given elliptic curve.
+    /// Returns an error with the context of which curve and which input were rejected.
     pub fn sign(&self, message: &[u8], curve: Curve) -> KeyPairResult<Vec<u8>> {
-        fn sign_impl<Key>(signing_key: Key, message: &[u8]) -> KeyPairResult<Vec<u8>>
+        fn sign_impl<Key>(
+            signing_key: KeyPairResult<Key>,
+            message: &[u8],
+            curve: Curve,
+        ) -> KeyPairResult<Vec<u8>>
         where
             Key: SigningKeyTrait,
         {
+            let signing_key = signing_key.with_field("private key")?;
+            curve.signing_hash_spec().validate(message)?;
             let hash_to_sign = <Key as SigningKeyTrait>::SigningMessage::try_from(message)
-                .map_err(|_| KeyPairError::InvalidSignMessage)?;
+                .map_err(|_| {
+                    KeyPairError::new(KeyPairErrorKind::InvalidSignMessage)
+                        .with_field("message")
+                        .with_actual_len(message.len())
+                })?;
             signing_key.sign(hash_to_sign).map(|sig| sig.to_vec())
         }
 
-        match curve {
-            Curve::Secp256k1 => sign_impl(self.to_secp256k1_privkey()?, message),
-            Curve::Ed25519 => sign_impl(self.to_ed25519()?, message),
-            Curve::Ed25519Blake2bNano => sign_impl(self.to_ed25519_blake2b()?, message),
-            Curve::Curve25519Waves => sign_impl(self.to_curve25519_waves()?, message),
-            Curve::Nist256p1 => sign_impl(self.to_nist256p1_privkey()?, message),
+        let res = match curve {
+            Curve::Secp256k1 => sign_impl(self.to_secp256k1_privkey(), message, curve),
+            Curve::Ed25519 => sign_impl(self.to_ed25519(), message, curve),
+            Curve::Ed25519Blake2bNano => sign_impl(self.to_ed25519_blake2b(), message, curve),
+            Curve::Curve25519Waves => sign_impl(self.to_curve25519_waves(), message, curve),
+            Curve::Nist256p1 => sign_impl(self.to_nist256p1_privkey(), message, curve),
             Curve::Ed25519ExtendedCardano => {
-                sign_impl(self.to_ed25519_extended_cardano()?, message)
+                sign_impl(self.to_ed25519_extended_cardano(), message, curve)
             },
-            Curve::Starkex => sign_impl(self.to_starkex_privkey()?, message),
-            Curve::Schnorr => sign_impl(self.to_schnorr_privkey()?, message),
-        }
+            Curve::Starkex => sign_impl(self.to_starkex_privkey(), message, curve),
+            Curve::Schnorr => sign_impl(self.to_schnorr_privkey(), message, curve),
+        };
+        res.with_curve(curve)
+    }
+
+    /// Signs a `message` hash with the [`Curve::Starkex`] curve.
+    /// Unlike [`PrivateKey::sign`], allows to sign hashes that don't fit the stark field,
+    /// e.g. 32-byte keccak256 digests, by reducing them according to the `hash_mode`.
+    /// The signature nonce is generated according to the `nonce_mode`.
+    pub fn sign_starkex(
+        &self,
+        message: &[u8],
+        hash_mode: starkex::MessageHashMode,
+        nonce_mode: starkex::NonceMode,
+    ) -> KeyPairResult<Vec<u8>> {
+        self.to_starkex_privkey()?
+            .sign_with_nonce_mode(message, hash_mode, nonce_mode)
+            .map(|sig| sig.to_vec())
+    }
+
+    /// Signs a `message` hash with the [`Curve::Starkex`] curve as [`PrivateKey::sign_starkex`] does,
+    /// but returns the 96 byte `(r, s, v)` signature that allows to recover the public key.
+    pub fn sign_starkex_extended(
+        &self,
+        message: &[u8],
+        hash_mode: starkex::MessageHashMode,
+        nonce_mode: starkex::NonceMode,
+    ) -> KeyPairResult<Vec<u8>> {
+        self.to_starkex_privkey()?
+            .sign_with_nonce_mode(message, hash_mode, nonce_mode)?
+            .to_extended_vec()
+            .ok_or(KeyPairError::new(KeyPairErrorKind::SigningError))
     }
 
     /// Returns the public key associated with the `self` private key and `ty` public key type.
@@ -167,43 +306,71 @@ impl PrivateKey {
         }
     }
 
-    /// Tries to convert [`PrivateKey::key`] to [`secp256k1::PrivateKey`].
+    /// Returns the expected length of a message to be signed with the given `curve`,
+    /// or `None` if a message of any length can be signed.
+    pub(crate) fn sign_message_len(curve: Curve) -> Option<usize> {
+        curve.signing_hash_spec().digest_len()
+    }
+
+    /// Returns the 32 byte secret regardless of the export policy.
+    /// Must be used for signing only, never handed out.
+    fn secret(&self) -> &[u8] {
+        assert!(
+            self.bytes.len() >= Self::SIZE,
+            "'PrivateKey::bytes' has an unexpected length"
+        );
+        &self.bytes[Self::KEY_RANGE]
+    }
+
+    /// Returns the 192 byte cardano extended secret regardless of the export policy.
+    /// Must be used for signing only, never handed out.
+    fn extended_cardano_secret(&self) -> KeyPairResult<&[u8]> {
+        if self.bytes.len() != Self::CARDANO_SIZE {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey));
+        }
+        Ok(&self.bytes[Self::EXTENDED_CARDANO_RANGE])
+    }
+
+    /// Tries to convert [`PrivateKey::secret`] to [`secp256k1::PrivateKey`].
+    /// The key is parsed once and then cloned from the cache.
     fn to_secp256k1_privkey(&self) -> KeyPairResult<secp256k1::PrivateKey> {
-        secp256k1::PrivateKey::try_from(self.key().as_slice())
+        self.secp256k1
+            .get_or_init(|| secp256k1::PrivateKey::try_from(self.secret()))
+            .clone()
     }
 
-    /// Tries to convert [`PrivateKey::key`] to [`nist256p1::PrivateKey`].
+    /// Tries to convert [`PrivateKey::secret`] to [`nist256p1::PrivateKey`].
     fn to_nist256p1_privkey(&self) -> KeyPairResult<nist256p1::PrivateKey> {
-        nist256p1::PrivateKey::try_from(self.key().as_slice())
+        nist256p1::PrivateKey::try_from(self.secret())
     }
 
-    /// Tries to convert [`PrivateKey::key`] to [`ed25519::sha512::PrivateKey`].
+    /// Tries to convert [`PrivateKey::secret`] to [`ed25519::sha512::PrivateKey`].
     fn to_ed25519(&self) -> KeyPairResult<ed25519::sha512::PrivateKey> {
-        ed25519::sha512::PrivateKey::try_from(self.key().as_slice())
+        ed25519::sha512::PrivateKey::try_from(self.secret())
     }
 
-    /// Tries to convert [`PrivateKey::key`] to [`ed25519::blake2b::PrivateKey`].
+    /// Tries to convert [`PrivateKey::secret`] to [`ed25519::blake2b::PrivateKey`].
     fn to_ed25519_blake2b(&self) -> KeyPairResult<ed25519::blake2b::PrivateKey> {
-        ed25519::blake2b::PrivateKey::try_from(self.key().as_slice())
+        ed25519::blake2b::PrivateKey::try_from(self.secret())
     }
 
-    /// Tries to convert [`PrivateKey::key`] to [`ed25519::waves::PrivateKey`].
+    /// Tries to convert [`PrivateKey::secret`] to [`ed25519::waves::PrivateKey`].
     fn to_curve25519_waves(&self) -> KeyPairResult<ed25519::waves::PrivateKey> {
-        ed25519::waves::PrivateKey::try_from(self.key().as_slice())
+        ed25519::waves::PrivateKey::try_from(self.secret())
     }
 
-    /// Tries to convert [`PrivateKey::extended_cardano_key`] to [`ed25519::cardano::ExtendedPrivateKey`].
+    /// Tries to convert [`PrivateKey::extended_cardano_secret`] to [`ed25519::cardano::ExtendedPrivateKey`].
     fn to_ed25519_extended_cardano(&self) -> KeyPairResult<ed25519::cardano::ExtendedPrivateKey> {
-        ed25519::cardano::ExtendedPrivateKey::try_from(self.extended_cardano_key()?)
+        ed25519::cardano::ExtendedPrivateKey::try_from(self.extended_cardano_secret()?)
     }
 
-    /// Tries to convert [`PrivateKey::key`] to [`starkex::PrivateKey`].
+    /// Tries to convert [`PrivateKey::secret`] to [`starkex::PrivateKey`].
     fn to_starkex_privkey(&self) -> KeyPairResult<starkex::PrivateKey> {
-        starkex::PrivateKey::try_from(self.key().as_slice())
+        starkex::PrivateKey::try_from(self.secret())
     }
 
-    /// Tries to convert [`PrivateKey::key`] to [`schnorr::PrivateKey`].
+    /// Tries to convert [`PrivateKey::secret`] to [`schnorr::PrivateKey`].
     fn to_schnorr_privkey(&self) -> KeyPairResult<schnorr::PrivateKey> {
-        schnorr::PrivateKey::try_from(self.key().as_slice())
+        schnorr::PrivateKey::try_from(self.secret())
     }
 }
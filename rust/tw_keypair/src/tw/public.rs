@@ -5,11 +5,33 @@
 use crate::ecdsa::{nist256p1, secp256k1};
 use crate::schnorr;
 use crate::traits::VerifyingKeyTrait;
-use crate::tw::PublicKeyType;
-use crate::{ed25519, starkex, KeyPairError, KeyPairResult};
+use crate::tw::{Curve, PublicKeyType};
+use crate::{ed25519, starkex, KeyPairError, KeyPairErrorContext, KeyPairErrorKind, KeyPairResult};
+use tw_hash::ripemd::sha256_ripemd;
+use tw_hash::sha2::sha256;
+use tw_hash::{H160, H256, H32};
 use tw_misc::traits::ToBytesVec;
 use tw_misc::try_or_false;
 
+/// Domain separator of [`PublicKey::account_identifier`], so the identifier never collides
+/// with other hashes of the same public key.
+const ACCOUNT_IDENTIFIER_DOMAIN: &[u8] = b"TrustWalletCore/AccountIdentifier/v1";
+
+/// Returns the curve tag of [`PublicKey::account_identifier`].
+/// The tags are fixed, so the identifiers don't depend on the curve FFI values or names.
+fn account_identifier_tag(curve: Curve) -> &'static [u8] {
+    match curve {
+        Curve::Secp256k1 => b"secp256k1",
+        Curve::Ed25519 => b"ed25519",
+        Curve::Ed25519Blake2bNano => b"ed25519Blake2bNano",
+        Curve::Curve25519Waves => b"curve25519",
+        Curve::Nist256p1 => b"nist256p1",
+        Curve::Ed25519ExtendedCardano => b"ed25519ExtendedCardano",
+        Curve::Starkex => b"starkex",
+        Curve::Schnorr => b"schnorr",
+    }
+}
+
 /// Represents a public key that can be used to verify signatures and messages.
 #[derive(Clone)]
 #[non_exhaustive]
@@ -28,7 +50,21 @@ pub enum PublicKey {
 
 impl PublicKey {
     /// Validates the given `bytes` using the `ty` public key type and creates a public key from it.
+    /// Returns an error with the context of which public key type and length were expected.
     pub fn new(bytes: Vec<u8>, ty: PublicKeyType) -> KeyPairResult<PublicKey> {
+        let actual_len = bytes.len();
+        Self::new_impl(bytes, ty)
+            .with_field("public key")
+            .map_err(|e| {
+                let e = e.with_public_key_type(ty).with_actual_len(actual_len);
+                match Self::expected_len(ty) {
+                    Some(expected_len) => e.with_expected_len(expected_len),
+                    None => e,
+                }
+            })
+    }
+
+    fn new_impl(bytes: Vec<u8>, ty: PublicKeyType) -> KeyPairResult<PublicKey> {
         match ty {
             PublicKeyType::Secp256k1 if secp256k1::PublicKey::COMPRESSED == bytes.len() => {
                 let pubkey = secp256k1::PublicKey::try_from(bytes.as_slice())?;
@@ -76,7 +112,44 @@ impl PublicKey {
                 let pubkey = schnorr::PublicKey::try_from(bytes.as_slice())?;
                 Ok(PublicKey::Schnorr(pubkey))
             },
-            _ => Err(KeyPairError::InvalidPublicKey),
+            _ => Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey)),
+        }
+    }
+
+    /// Returns the expected length of the `ty` public key,
+    /// or `None` if the length is checked by the public key implementation.
+    fn expected_len(ty: PublicKeyType) -> Option<usize> {
+        match ty {
+            PublicKeyType::Secp256k1 => Some(secp256k1::PublicKey::COMPRESSED),
+            PublicKeyType::Secp256k1Extended => Some(secp256k1::PublicKey::UNCOMPRESSED),
+            PublicKeyType::Nist256p1 => Some(nist256p1::PublicKey::COMPRESSED),
+            PublicKeyType::Nist256p1Extended => Some(nist256p1::PublicKey::UNCOMPRESSED),
+            PublicKeyType::Ed25519 => Some(ed25519::sha512::PublicKey::LEN),
+            PublicKeyType::Ed25519Blake2b => Some(ed25519::blake2b::PublicKey::LEN),
+            PublicKeyType::Curve25519Waves => Some(ed25519::waves::PublicKey::LEN),
+            PublicKeyType::Ed25519ExtendedCardano => Some(ed25519::cardano::ExtendedPublicKey::LEN),
+            PublicKeyType::Starkex | PublicKeyType::Schnorr => None,
+        }
+    }
+
+    /// Validates the given DER-encoded `SubjectPublicKeyInfo` using the `ty` public key type
+    /// and creates a public key from it.
+    /// Only ECDSA public key types are supported.
+    pub fn from_der(der_bytes: &[u8], ty: PublicKeyType) -> KeyPairResult<PublicKey> {
+        match ty {
+            PublicKeyType::Secp256k1 => {
+                secp256k1::PublicKey::from_der_encoded(der_bytes).map(PublicKey::Secp256k1)
+            },
+            PublicKeyType::Secp256k1Extended => {
+                secp256k1::PublicKey::from_der_encoded(der_bytes).map(PublicKey::Secp256k1Extended)
+            },
+            PublicKeyType::Nist256p1 => {
+                nist256p1::PublicKey::from_der_encoded(der_bytes).map(PublicKey::Nist256p1)
+            },
+            PublicKeyType::Nist256p1Extended => {
+                nist256p1::PublicKey::from_der_encoded(der_bytes).map(PublicKey::Nist256p1Extended)
+            },
+            _ => Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey)),
         }
     }
 
@@ -133,6 +206,77 @@ impl PublicKey {
         }
     }
 
+    /// Returns the compressed form of ECDSA public keys, and the raw data of other keys.
+    /// Compressed and uncompressed forms of the same key are represented equally.
+    fn canonical_bytes(&self) -> Vec<u8> {
+        match self {
+            PublicKey::Secp256k1(secp) | PublicKey::Secp256k1Extended(secp) => {
+                secp.compressed().into_vec()
+            },
+            PublicKey::Nist256p1(nist) | PublicKey::Nist256p1Extended(nist) => {
+                nist.compressed().into_vec()
+            },
+            _ => self.to_bytes(),
+        }
+    }
+
+    /// Returns the key identifier: `RIPEMD160(SHA256(public key))`,
+    /// where ECDSA public keys are always taken in the compressed form.
+    /// For `secp256k1` keys, it's the BIP-32 key identifier.
+    pub fn key_identifier(&self) -> H160 {
+        let hash = sha256_ripemd(&self.canonical_bytes());
+        H160::try_from(hash.as_slice()).expect("Expected exactly 20 bytes")
+    }
+
+    /// Returns the first 4 bytes of the [`PublicKey::key_identifier`].
+    /// For `secp256k1` keys, it's the BIP-32 fingerprint, e.g. the parent fingerprint of an `xpub`.
+    pub fn fingerprint(&self) -> H32 {
+        let (fingerprint, _) = self.key_identifier().split::<4, 16>();
+        fingerprint
+    }
+
+    /// Returns an identifier that can be used as a database key of the account the public key belongs to:
+    /// `SHA256(domain | tag length | curve tag | public key)`.
+    ///
+    /// Unlike the [`PublicKey::key_identifier`], it's a full 32-byte hash,
+    /// and it differs for equal key bytes on different curves.
+    pub fn account_identifier(&self) -> H256 {
+        let tag = account_identifier_tag(self.curve());
+        let mut preimage = ACCOUNT_IDENTIFIER_DOMAIN.to_vec();
+        preimage.push(tag.len() as u8);
+        preimage.extend_from_slice(tag);
+        preimage.extend_from_slice(&self.canonical_bytes());
+        H256::try_from(sha256(&preimage).as_slice()).expect("Expected exactly 32 bytes")
+    }
+
+    /// Returns the curve of the public key.
+    pub fn curve(&self) -> Curve {
+        match self {
+            PublicKey::Secp256k1(_) | PublicKey::Secp256k1Extended(_) => Curve::Secp256k1,
+            PublicKey::Nist256p1(_) | PublicKey::Nist256p1Extended(_) => Curve::Nist256p1,
+            PublicKey::Ed25519(_) => Curve::Ed25519,
+            PublicKey::Ed25519Blake2b(_) => Curve::Ed25519Blake2bNano,
+            PublicKey::Curve25519Waves(_) => Curve::Curve25519Waves,
+            PublicKey::Ed25519ExtendedCardano(_) => Curve::Ed25519ExtendedCardano,
+            PublicKey::Starkex(_) => Curve::Starkex,
+            PublicKey::Schnorr(_) => Curve::Schnorr,
+        }
+    }
+
+    /// Returns the public key as DER-encoded `SubjectPublicKeyInfo` bytes.
+    /// Only ECDSA public keys are supported.
+    pub fn to_der(&self) -> KeyPairResult<Vec<u8>> {
+        match self {
+            PublicKey::Secp256k1(secp) | PublicKey::Secp256k1Extended(secp) => {
+                Ok(secp.der_encoded())
+            },
+            PublicKey::Nist256p1(nist) | PublicKey::Nist256p1Extended(nist) => {
+                Ok(nist.der_encoded())
+            },
+            _ => Err(KeyPairError::new(KeyPairErrorKind::InvalidPublicKey)),
+        }
+    }
+
     /// Returns a `secp256k1` public key if the key type is matched.
     pub fn to_secp256k1(&self) -> Option<&secp256k1::PublicKey> {
         match self {
@@ -150,6 +294,14 @@ impl PublicKey {
         }
     }
 
+    /// Returns an `ed25519-blake2b` public key if the key type is matched.
+    pub fn to_ed25519_blake2b(&self) -> Option<&ed25519::blake2b::PublicKey> {
+        match self {
+            PublicKey::Ed25519Blake2b(blake2b) => Some(blake2b),
+            _ => None,
+        }
+    }
+
     /// Returns a `schnorr` public key if the key type is matched.
     pub fn to_schnorr(&self) -> Option<&schnorr::PublicKey> {
         match self {
@@ -174,3 +326,40 @@ impl PublicKey {
         }
     }
 }
+
+/// `serde` representation of [`PublicKey`]:
+/// `{ "type": "secp256k1", "data": "0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1" }`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize, serde::Serialize)]
+struct PublicKeyRepr {
+    #[serde(rename = "type")]
+    ty: PublicKeyType,
+    #[serde(with = "tw_encoding::hex::as_hex")]
+    data: Vec<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PublicKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let repr = PublicKeyRepr {
+            ty: self.public_key_type(),
+            data: self.to_bytes(),
+        };
+        serde::Serialize::serialize(&repr, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PublicKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let repr: PublicKeyRepr = serde::Deserialize::deserialize(deserializer)?;
+        PublicKey::new(repr.data, repr.ty)
+            .map_err(|e| serde::de::Error::custom(format!("Invalid public key: {e:?}")))
+    }
+}
@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::tw::Curve;
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+
+/// A hash algorithm which digests are signed with a [`Curve`].
+///
+/// The discriminants are stable values exposed through the C FFI.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SigningHash {
+    /// The message is signed as is, the curve hashes it internally.
+    Raw = 0,
+    Sha256 = 1,
+    /// SHA256 hash of the SHA256 hash, e.g. Bitcoin legacy and segwit sighash.
+    Sha256d = 2,
+    Keccak256 = 3,
+    Blake2b256 = 4,
+    Blake256 = 5,
+    /// BIP-340 tagged SHA256 hash, e.g. Taproot sighash.
+    TaggedSha256 = 6,
+    Pedersen = 7,
+    Poseidon = 8,
+}
+
+impl SigningHash {
+    pub fn from_raw(raw: u32) -> Option<SigningHash> {
+        match raw {
+            0 => Some(SigningHash::Raw),
+            1 => Some(SigningHash::Sha256),
+            2 => Some(SigningHash::Sha256d),
+            3 => Some(SigningHash::Keccak256),
+            4 => Some(SigningHash::Blake2b256),
+            5 => Some(SigningHash::Blake256),
+            6 => Some(SigningHash::TaggedSha256),
+            7 => Some(SigningHash::Pedersen),
+            8 => Some(SigningHash::Poseidon),
+            _ => None,
+        }
+    }
+
+    pub fn to_raw(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Describes what message a [`Curve`] can sign: its length and which hash it is expected to be.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SigningHashSpec {
+    /// The minimum length of a message to be signed.
+    pub min_len: usize,
+    /// The maximum length of a message to be signed, or `None` if a message of any length can be signed.
+    pub max_len: Option<usize>,
+    /// Hash algorithms which digests can be signed, ordered by their FFI values.
+    /// Any other hash of the same length is signed too, but isn't used by the supported chains.
+    pub hashes: &'static [SigningHash],
+}
+
+impl SigningHashSpec {
+    const DIGEST_32: SigningHashSpec = SigningHashSpec::digest(32, &[]);
+    const RAW_MESSAGE: SigningHashSpec = SigningHashSpec {
+        min_len: 0,
+        max_len: None,
+        hashes: &[SigningHash::Raw],
+    };
+
+    const fn digest(len: usize, hashes: &'static [SigningHash]) -> SigningHashSpec {
+        SigningHashSpec {
+            min_len: len,
+            max_len: Some(len),
+            hashes,
+        }
+    }
+
+    /// Returns the exact length of a digest to be signed,
+    /// or `None` if the length of a message may vary.
+    pub fn digest_len(&self) -> Option<usize> {
+        self.max_len.filter(|max_len| *max_len == self.min_len)
+    }
+
+    /// Whether the curve signs a pre-hashed digest rather than hashes the message itself.
+    pub fn is_prehashed(&self) -> bool {
+        !self.hashes.contains(&SigningHash::Raw)
+    }
+
+    /// Checks if the `message` length fits the spec.
+    /// Returns an error with the expected and actual lengths otherwise.
+    pub fn validate(&self, message: &[u8]) -> KeyPairResult<()> {
+        let len = message.len();
+        let fits_max = self.max_len.map_or(true, |max_len| len <= max_len);
+        if self.min_len <= len && fits_max {
+            return Ok(());
+        }
+
+        let error = KeyPairError::new(KeyPairErrorKind::InvalidSignMessage)
+            .with_field("message")
+            .with_actual_len(len);
+        // Report the exceeded bound.
+        let expected_len = if len < self.min_len {
+            self.min_len
+        } else {
+            self.max_len.unwrap_or(self.min_len)
+        };
+        Err(error.with_expected_len(expected_len))
+    }
+}
+
+impl Curve {
+    /// Returns the spec of a message that can be signed with the curve.
+    pub fn signing_hash_spec(self) -> SigningHashSpec {
+        match self {
+            Curve::Secp256k1 => SigningHashSpec {
+                hashes: &[
+                    SigningHash::Sha256,
+                    SigningHash::Sha256d,
+                    SigningHash::Keccak256,
+                    SigningHash::Blake2b256,
+                    SigningHash::Blake256,
+                ],
+                ..SigningHashSpec::DIGEST_32
+            },
+            Curve::Nist256p1 => SigningHashSpec {
+                hashes: &[SigningHash::Sha256],
+                ..SigningHashSpec::DIGEST_32
+            },
+            Curve::Schnorr => SigningHashSpec {
+                hashes: &[SigningHash::Sha256, SigningHash::TaggedSha256],
+                ..SigningHashSpec::DIGEST_32
+            },
+            // The hash is a field element that is less than `2^251`,
+            // see `PrivateKey::sign_starkex` to sign 256-bit hashes.
+            Curve::Starkex => SigningHashSpec {
+                min_len: 0,
+                max_len: Some(32),
+                hashes: &[SigningHash::Pedersen, SigningHash::Poseidon],
+            },
+            Curve::Ed25519
+            | Curve::Ed25519Blake2bNano
+            | Curve::Curve25519Waves
+            | Curve::Ed25519ExtendedCardano => SigningHashSpec::RAW_MESSAGE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signing_hash_spec_digest() {
+        let spec = Curve::Secp256k1.signing_hash_spec();
+        assert_eq!(spec.digest_len(), Some(32));
+        assert!(spec.is_prehashed());
+        assert!(spec.hashes.contains(&SigningHash::Keccak256));
+        assert_eq!(spec.validate(&[1; 32]), Ok(()));
+
+        let error = spec.validate(&[1; 33]).unwrap_err();
+        assert_eq!(error.kind(), KeyPairErrorKind::InvalidSignMessage);
+        assert_eq!(error.expected_len(), Some(32));
+        assert_eq!(error.actual_len(), Some(33));
+    }
+
+    #[test]
+    fn test_signing_hash_spec_raw_message() {
+        for curve in [
+            Curve::Ed25519,
+            Curve::Ed25519Blake2bNano,
+            Curve::Curve25519Waves,
+            Curve::Ed25519ExtendedCardano,
+        ] {
+            let spec = curve.signing_hash_spec();
+            assert_eq!(spec.digest_len(), None);
+            assert!(!spec.is_prehashed());
+            assert_eq!(spec.validate(&[]), Ok(()));
+            assert_eq!(spec.validate(&[1; 1000]), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_signing_hash_spec_starkex() {
+        let spec = Curve::Starkex.signing_hash_spec();
+        assert_eq!(spec.digest_len(), None);
+        assert!(spec.is_prehashed());
+        assert_eq!(spec.validate(&[1; 31]), Ok(()));
+
+        let error = spec.validate(&[1; 33]).unwrap_err();
+        assert_eq!(error.expected_len(), Some(32));
+        assert_eq!(error.actual_len(), Some(33));
+    }
+
+    #[test]
+    fn test_signing_hash_from_raw() {
+        for raw in 0..=8 {
+            assert_eq!(SigningHash::from_raw(raw).unwrap().to_raw(), raw);
+        }
+        assert_eq!(SigningHash::from_raw(9), None);
+    }
+}
@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! An in-memory vault of private keys and HD seeds.
+//!
+//! Secrets are kept encrypted with XChaCha20-Poly1305 by a random session key,
+//! that is generated when the vault is created and never leaves it.
+//! A secret is decrypted only for the time of a signing or derivation,
+//! and the plaintext is wiped right after.
+//!
+//! Secrets are referenced by opaque [`VaultHandle`]s, so the host doesn't need to keep raw seeds
+//! or private keys between signings.
+
+use crate::ecdsa::secp256k1::{self, HARDENED_OFFSET};
+use crate::rand::OsRng;
+use crate::tw::{Curve, PrivateKey, PublicKey, PublicKeyType};
+use crate::{KeyPairError, KeyPairErrorKind, KeyPairResult};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use rand_core::RngCore;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tw_hash::hmac::hmac_sha512;
+use tw_hash::H256;
+use tw_memory::secure_bytes::SecureBytes;
+use tw_misc::traits::ToBytesZeroizing;
+use zeroize::Zeroizing;
+
+const SESSION_KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 24;
+const BIP32_SEED_KEY: &[u8] = b"Bitcoin seed";
+/// BIP-32 seeds are from 128 to 512 bits.
+const MIN_SEED_LEN: usize = 16;
+const MAX_SEED_LEN: usize = 64;
+
+/// An opaque reference to a secret stored in a [`KeyVault`].
+/// Zero is never a valid handle.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct VaultHandle(u64);
+
+impl VaultHandle {
+    pub fn from_raw(raw: u64) -> VaultHandle {
+        VaultHandle(raw)
+    }
+
+    pub fn to_raw(self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+enum SecretKind {
+    PrivateKey = 1,
+    Seed = 2,
+}
+
+/// An encrypted secret.
+struct SealedSecret {
+    kind: SecretKind,
+    nonce: [u8; NONCE_LEN],
+    /// Encrypted secret followed by the authentication tag.
+    ciphertext: Vec<u8>,
+}
+
+/// Holds private keys and HD seeds encrypted at rest, and signs messages by their handles.
+///
+/// The vault is safe to share between threads.
+pub struct KeyVault {
+    session_key: SecureBytes,
+    secrets: Mutex<HashMap<u64, SealedSecret>>,
+    next_handle: AtomicU64,
+}
+
+impl Default for KeyVault {
+    fn default() -> Self {
+        KeyVault::new()
+    }
+}
+
+/// Never prints the session key or the secrets.
+impl fmt::Debug for KeyVault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyVault")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl KeyVault {
+    /// Creates an empty vault with a random session key.
+    pub fn new() -> KeyVault {
+        let mut session_key = SecureBytes::zeroed(SESSION_KEY_LEN);
+        OsRng.fill_bytes(session_key.as_mut_slice());
+        KeyVault {
+            session_key,
+            secrets: Mutex::new(HashMap::new()),
+            next_handle: AtomicU64::new(1),
+        }
+    }
+
+    /// Imports a private key, that is validated as [`PrivateKey::new`] does.
+    /// The given `secret` is wiped.
+    pub fn import_private_key(&self, secret: Vec<u8>) -> KeyPairResult<VaultHandle> {
+        let secret = Zeroizing::new(secret);
+        if !PrivateKey::is_valid_general(&secret) {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey));
+        }
+        self.insert(SecretKind::PrivateKey, &secret)
+    }
+
+    /// Imports a BIP-32 seed of 16 to 64 bytes, e.g. a BIP-39 mnemonic seed.
+    /// The given `seed` is wiped.
+    pub fn import_seed(&self, seed: Vec<u8>) -> KeyPairResult<VaultHandle> {
+        let seed = Zeroizing::new(seed);
+        if !(MIN_SEED_LEN..=MAX_SEED_LEN).contains(&seed.len()) {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey));
+        }
+        self.insert(SecretKind::Seed, &seed)
+    }
+
+    /// Derives a BIP-32 `secp256k1` private key from the `seed` by the given `path`,
+    /// and imports it into the vault. The derived key never leaves the vault.
+    pub fn derive_secp256k1(&self, seed: VaultHandle, path: &[u32]) -> KeyPairResult<VaultHandle> {
+        let seed = self.open(seed, SecretKind::Seed)?;
+
+        let hmac = Zeroizing::new(hmac_sha512(BIP32_SEED_KEY, &seed));
+        let (mut key, mut chain_code) = split_hmac(&hmac)?;
+
+        for index in path {
+            let mut data = Zeroizing::new(Vec::with_capacity(1 + H256::LEN + 4));
+            if *index >= HARDENED_OFFSET {
+                data.push(0);
+                data.extend_from_slice(&key.to_zeroizing_vec());
+            } else {
+                data.extend_from_slice(key.public().compressed().as_slice());
+            }
+            data.extend_from_slice(&index.to_be_bytes());
+
+            let hmac = Zeroizing::new(hmac_sha512(&chain_code, &data));
+            let tweak = H256::try_from(&hmac[..H256::LEN]).expect("Expected 32 byte tweak");
+            // The child key is invalid if the tweak is not less than the curve order or the result is zero.
+            key = key.tweak_add(tweak)?;
+            chain_code = Zeroizing::new(hmac[H256::LEN..].to_vec());
+        }
+
+        self.insert(SecretKind::PrivateKey, &key.to_zeroizing_vec())
+    }
+
+    /// Signs a `message` by the private key referenced by the `handle` with the given `curve`.
+    pub fn sign(
+        &self,
+        handle: VaultHandle,
+        message: &[u8],
+        curve: Curve,
+    ) -> KeyPairResult<Vec<u8>> {
+        self.private_key(handle)?.sign(message, curve)
+    }
+
+    /// Returns the public key of the private key referenced by the `handle`.
+    pub fn public_key(&self, handle: VaultHandle, ty: PublicKeyType) -> KeyPairResult<PublicKey> {
+        self.private_key(handle)?.get_public_key_by_type(ty)
+    }
+
+    /// Removes the secret referenced by the `handle`.
+    /// Returns false if the handle is unknown.
+    pub fn remove(&self, handle: VaultHandle) -> bool {
+        self.lock_secrets().remove(&handle.0).is_some()
+    }
+
+    /// Whether the `handle` references a secret of the vault.
+    pub fn contains(&self, handle: VaultHandle) -> bool {
+        self.lock_secrets().contains_key(&handle.0)
+    }
+
+    /// Returns the number of the stored secrets.
+    pub fn len(&self) -> usize {
+        self.lock_secrets().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn private_key(&self, handle: VaultHandle) -> KeyPairResult<PrivateKey> {
+        let secret = self.open(handle, SecretKind::PrivateKey)?;
+        // `PrivateKey` keeps its own copy in a secure buffer.
+        PrivateKey::new(secret.to_vec())
+    }
+
+    fn insert(&self, kind: SecretKind, secret: &[u8]) -> KeyPairResult<VaultHandle> {
+        let handle = self.next_handle.fetch_add(1, Ordering::Relaxed);
+
+        let mut nonce = [0_u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+        let aad = associated_data(handle, kind);
+        let ciphertext = self
+            .cipher()
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: secret,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InternalError))?;
+
+        let sealed = SealedSecret {
+            kind,
+            nonce,
+            ciphertext,
+        };
+        self.lock_secrets().insert(handle, sealed);
+        Ok(VaultHandle(handle))
+    }
+
+    /// Decrypts the secret referenced by the `handle`, that is wiped on drop.
+    fn open(&self, handle: VaultHandle, kind: SecretKind) -> KeyPairResult<SecureBytes> {
+        let secrets = self.lock_secrets();
+        let sealed = secrets
+            .get(&handle.0)
+            .ok_or(KeyPairError::new(KeyPairErrorKind::UnknownVaultHandle))?;
+        if sealed.kind != kind {
+            return Err(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey));
+        }
+
+        let aad = associated_data(handle.0, kind);
+        self.cipher()
+            .decrypt(
+                XNonce::from_slice(&sealed.nonce),
+                Payload {
+                    msg: &sealed.ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map(SecureBytes::from)
+            .map_err(|_| KeyPairError::new(KeyPairErrorKind::InternalError))
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new_from_slice(self.session_key.as_slice())
+            .expect("Expected a valid session key length")
+    }
+
+    fn lock_secrets(&self) -> std::sync::MutexGuard<'_, HashMap<u64, SealedSecret>> {
+        // The map stays consistent even if another thread panicked.
+        self.secrets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Binds a ciphertext to its handle and kind, so sealed secrets can't be swapped.
+fn associated_data(handle: u64, kind: SecretKind) -> [u8; 9] {
+    let mut aad = [0_u8; 9];
+    aad[..8].copy_from_slice(&handle.to_be_bytes());
+    aad[8] = kind as u8;
+    aad
+}
+
+/// Splits a BIP-32 HMAC into a private key and a chain code.
+fn split_hmac(hmac: &[u8]) -> KeyPairResult<(secp256k1::PrivateKey, Zeroizing<Vec<u8>>)> {
+    let key = secp256k1::PrivateKey::try_from(&hmac[..H256::LEN])?;
+    let chain_code = Zeroizing::new(hmac[H256::LEN..].to_vec());
+    Ok((key, chain_code))
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::ffi::{CStr, CString};
+use tw_keypair::ffi::curve::{
+    tw_curve_at, tw_curve_count, tw_curve_from_name, tw_curve_name, tw_curve_signing_hash_at,
+    tw_curve_signing_hash_count, tw_curve_signing_hash_max_len, tw_curve_signing_hash_min_len,
+    tw_curve_validate_signing_message, tw_public_key_type_at, tw_public_key_type_count,
+    tw_public_key_type_from_name, tw_public_key_type_name,
+};
+use tw_keypair::tw::{Curve, PublicKeyType, SigningHash};
+use tw_keypair::KeyPairErrorKind;
+use tw_memory::ffi::c_byte_array::CByteArray;
+use tw_memory::ffi::free_string;
+
+fn to_string(ptr: *const std::ffi::c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    let string = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+    unsafe { free_string(ptr) };
+    Some(string)
+}
+
+#[test]
+fn test_tw_curve_enumerate() {
+    let curves: Vec<_> = (0..tw_curve_count())
+        .map(|index| tw_curve_at(index).unwrap())
+        .collect();
+    assert_eq!(curves, [0, 1, 2, 3, 4, 5, 6, 8]);
+    assert!(tw_curve_at(tw_curve_count()).is_err());
+
+    for curve in curves {
+        let name = to_string(tw_curve_name(curve)).unwrap();
+        let name = CString::new(name).unwrap();
+        let parsed = unsafe { tw_curve_from_name(name.as_ptr()) };
+        assert_eq!(parsed.unwrap(), curve);
+    }
+}
+
+#[test]
+fn test_tw_curve_name() {
+    assert_eq!(
+        to_string(tw_curve_name(Curve::Ed25519ExtendedCardano.to_raw())).unwrap(),
+        "ed25519ExtendedCardano"
+    );
+    assert_eq!(to_string(tw_curve_name(100)), None);
+
+    let name = CString::new("ed25519-blake2b-nano").unwrap();
+    let parsed = unsafe { tw_curve_from_name(name.as_ptr()) };
+    assert_eq!(parsed.unwrap(), Curve::Ed25519Blake2bNano.to_raw());
+
+    let name = CString::new("unknown").unwrap();
+    assert!(unsafe { tw_curve_from_name(name.as_ptr()) }.is_err());
+    assert!(unsafe { tw_curve_from_name(std::ptr::null()) }.is_err());
+}
+
+#[test]
+fn test_tw_curve_signing_hash_spec() {
+    let secp256k1 = Curve::Secp256k1.to_raw();
+    assert_eq!(tw_curve_signing_hash_min_len(secp256k1).unwrap(), 32);
+    assert_eq!(tw_curve_signing_hash_max_len(secp256k1).unwrap(), 32);
+    let hashes: Vec<_> = (0..tw_curve_signing_hash_count(secp256k1))
+        .map(|index| tw_curve_signing_hash_at(secp256k1, index).unwrap())
+        .collect();
+    assert!(hashes.contains(&SigningHash::Keccak256.to_raw()));
+    assert!(tw_curve_signing_hash_at(secp256k1, hashes.len()).is_err());
+
+    let ed25519 = Curve::Ed25519.to_raw();
+    assert_eq!(tw_curve_signing_hash_min_len(ed25519).unwrap(), 0);
+    assert_eq!(tw_curve_signing_hash_max_len(ed25519).unwrap(), 0);
+    assert_eq!(tw_curve_signing_hash_count(ed25519), 1);
+    assert_eq!(
+        tw_curve_signing_hash_at(ed25519, 0).unwrap(),
+        SigningHash::Raw.to_raw()
+    );
+
+    assert!(tw_curve_signing_hash_min_len(100).is_err());
+    assert!(tw_curve_signing_hash_max_len(100).is_err());
+    assert_eq!(tw_curve_signing_hash_count(100), 0);
+}
+
+#[test]
+fn test_tw_curve_validate_signing_message() {
+    let digest = CByteArray::from(vec![1; 32]);
+    let message = CByteArray::from(vec![1; 31]);
+
+    let validate = |curve: Curve, message: &CByteArray| unsafe {
+        tw_curve_validate_signing_message(curve.to_raw(), message.data(), message.size())
+    };
+    assert_eq!(validate(Curve::Secp256k1, &digest), 0);
+    assert_eq!(
+        validate(Curve::Secp256k1, &message),
+        KeyPairErrorKind::InvalidSignMessage.code()
+    );
+    assert_eq!(validate(Curve::Ed25519, &message), 0);
+    assert_eq!(validate(Curve::Starkex, &message), 0);
+
+    let unknown_curve =
+        unsafe { tw_curve_validate_signing_message(100, digest.data(), digest.size()) };
+    assert_eq!(unknown_curve, KeyPairErrorKind::InvalidCurve.code());
+}
+
+#[test]
+fn test_tw_public_key_type_enumerate() {
+    let types: Vec<_> = (0..tw_public_key_type_count())
+        .map(|index| tw_public_key_type_at(index).unwrap())
+        .collect();
+    assert_eq!(types, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert!(tw_public_key_type_at(tw_public_key_type_count()).is_err());
+
+    for ty in types {
+        let name = to_string(tw_public_key_type_name(ty)).unwrap();
+        let name = CString::new(name).unwrap();
+        let parsed = unsafe { tw_public_key_type_from_name(name.as_ptr()) };
+        assert_eq!(parsed.unwrap(), ty);
+    }
+
+    assert_eq!(
+        to_string(tw_public_key_type_name(
+            PublicKeyType::Curve25519Waves.to_raw()
+        ))
+        .unwrap(),
+        "curve25519"
+    );
+    assert_eq!(to_string(tw_public_key_type_name(100)), None);
+}
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_keypair::encrypted_backup::{
+    BackupKdfParams, EncryptedBackup, MIN_ITERATIONS, MIN_MEMORY_KIB,
+};
+use tw_keypair::ffi::encrypted_backup::{
+    tw_encrypted_backup_create, tw_encrypted_backup_metadata, tw_encrypted_backup_open,
+};
+use tw_keypair::KeyPairErrorKind;
+
+const PAYLOAD: &[u8] = br#"{"version":3,"type":"mnemonic","name":"Wallet"}"#;
+const PASSWORD: &[u8] = b"correct horse battery staple";
+const METADATA: &[u8] = br#"{"name":"Wallet","createdAt":1760400000}"#;
+
+/// The lowest accepted KDF parameters to keep the tests fast.
+const TEST_PARAMS: BackupKdfParams = BackupKdfParams {
+    memory_kib: MIN_MEMORY_KIB,
+    iterations: MIN_ITERATIONS,
+    parallelism: 1,
+};
+
+fn create_backup() -> Vec<u8> {
+    EncryptedBackup::create(PAYLOAD, PASSWORD, METADATA, TEST_PARAMS)
+        .unwrap()
+        .encode()
+}
+
+#[test]
+fn test_encrypted_backup_roundtrip() {
+    let encoded = create_backup();
+    assert_eq!(&encoded[..4], b"TWEB");
+
+    let backup = EncryptedBackup::decode(&encoded).unwrap();
+    assert_eq!(backup.metadata(), METADATA);
+    assert_eq!(backup.params(), TEST_PARAMS);
+    assert_eq!(backup.open(PASSWORD).unwrap().as_slice(), PAYLOAD);
+    assert_eq!(backup.encode(), encoded);
+}
+
+#[test]
+fn test_encrypted_backup_random_salt_and_nonce() {
+    assert_ne!(create_backup(), create_backup());
+}
+
+#[test]
+fn test_encrypted_backup_wrong_password() {
+    let backup = EncryptedBackup::decode(&create_backup()).unwrap();
+    assert_eq!(
+        backup.open(b"wrong password").unwrap_err().kind(),
+        KeyPairErrorKind::InvalidEncryptedMessage
+    );
+}
+
+#[test]
+fn test_encrypted_backup_tampered() {
+    let encoded = create_backup();
+
+    // Metadata is authenticated.
+    let mut tampered = encoded.clone();
+    let metadata_pos = encoded.len() - PAYLOAD.len() - 16 - METADATA.len();
+    tampered[metadata_pos] ^= 1;
+    let backup = EncryptedBackup::decode(&tampered).unwrap();
+    assert_eq!(
+        backup.open(PASSWORD).unwrap_err().kind(),
+        KeyPairErrorKind::InvalidEncryptedMessage
+    );
+
+    // KDF parameters are authenticated: 3 iterations instead of 2.
+    let mut tampered = encoded.clone();
+    tampered[10] = 3;
+    let backup = EncryptedBackup::decode(&tampered).unwrap();
+    assert_eq!(
+        backup.open(PASSWORD).unwrap_err().kind(),
+        KeyPairErrorKind::InvalidEncryptedMessage
+    );
+
+    // Ciphertext.
+    let mut tampered = encoded;
+    *tampered.last_mut().unwrap() ^= 1;
+    let backup = EncryptedBackup::decode(&tampered).unwrap();
+    assert_eq!(
+        backup.open(PASSWORD).unwrap_err().kind(),
+        KeyPairErrorKind::InvalidEncryptedMessage
+    );
+}
+
+#[test]
+fn test_encrypted_backup_decode_invalid() {
+    let encoded = create_backup();
+
+    // Truncated.
+    EncryptedBackup::decode(&encoded[..60]).unwrap_err();
+
+    // Unknown version.
+    let mut invalid = encoded.clone();
+    invalid[4] = 2;
+    EncryptedBackup::decode(&invalid).unwrap_err();
+
+    // Downgraded KDF parameters: 1 iteration.
+    let mut invalid = encoded.clone();
+    invalid[10] = 1;
+    EncryptedBackup::decode(&invalid).unwrap_err();
+
+    // Metadata length exceeds the container.
+    let mut invalid = encoded;
+    invalid[58..62].copy_from_slice(&u32::MAX.to_le_bytes());
+    EncryptedBackup::decode(&invalid).unwrap_err();
+}
+
+#[test]
+fn test_encrypted_backup_create_weak_params() {
+    let weak = BackupKdfParams {
+        memory_kib: 1024,
+        ..TEST_PARAMS
+    };
+    assert_eq!(
+        EncryptedBackup::create(PAYLOAD, PASSWORD, METADATA, weak)
+            .unwrap_err()
+            .kind(),
+        KeyPairErrorKind::InvalidEncryptedMessage
+    );
+}
+
+#[test]
+fn test_encrypted_backup_ffi() {
+    let encoded = unsafe {
+        tw_encrypted_backup_create(
+            PAYLOAD.as_ptr(),
+            PAYLOAD.len(),
+            PASSWORD.as_ptr(),
+            PASSWORD.len(),
+            METADATA.as_ptr(),
+            METADATA.len(),
+        )
+    };
+    let encoded = unsafe { encoded.unwrap().into_vec() };
+    assert_eq!(
+        EncryptedBackup::decode(&encoded).unwrap().params(),
+        BackupKdfParams::default()
+    );
+
+    let metadata = unsafe { tw_encrypted_backup_metadata(encoded.as_ptr(), encoded.len()) };
+    assert_eq!(unsafe { metadata.unwrap().into_vec() }, METADATA);
+
+    let payload = unsafe {
+        tw_encrypted_backup_open(
+            encoded.as_ptr(),
+            encoded.len(),
+            PASSWORD.as_ptr(),
+            PASSWORD.len(),
+        )
+    };
+    assert_eq!(unsafe { payload.unwrap().into_vec() }, PAYLOAD);
+
+    let wrong_password = b"wrong password";
+    let result = unsafe {
+        tw_encrypted_backup_open(
+            encoded.as_ptr(),
+            encoded.len(),
+            wrong_password.as_ptr(),
+            wrong_password.len(),
+        )
+    };
+    assert_eq!(
+        result.into_result().map(|_| ()).unwrap_err(),
+        KeyPairErrorKind::InvalidEncryptedMessage.code()
+    );
+}
+
+#[test]
+fn test_encrypted_backup_ffi_no_metadata() {
+    let encoded = unsafe {
+        tw_encrypted_backup_create(
+            PAYLOAD.as_ptr(),
+            PAYLOAD.len(),
+            PASSWORD.as_ptr(),
+            PASSWORD.len(),
+            std::ptr::null(),
+            0,
+        )
+    };
+    let encoded = unsafe { encoded.unwrap().into_vec() };
+    assert!(EncryptedBackup::decode(&encoded)
+        .unwrap()
+        .metadata()
+        .is_empty());
+}
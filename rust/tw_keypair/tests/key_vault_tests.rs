@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::ffi::pubkey::tw_public_key_data;
+use tw_keypair::ffi::vault::{
+    tw_key_vault_create, tw_key_vault_delete, tw_key_vault_derive_secp256k1,
+    tw_key_vault_get_public_key_by_type, tw_key_vault_import_seed, tw_key_vault_remove,
+    tw_key_vault_sign,
+};
+use tw_keypair::test_utils::tw_public_key_helper::TWPublicKeyHelper;
+use tw_keypair::tw::{Curve, PrivateKey, PublicKeyType};
+use tw_keypair::vault::{KeyVault, VaultHandle};
+use tw_keypair::{KeyPairError, KeyPairErrorKind};
+use tw_memory::ffi::c_byte_array::CByteArray;
+
+const SECP256K1_PRIVATE_KEY: &str =
+    "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+const MESSAGE_HASH: &str = "1fcbf0b6d3f2d5a4d6b3e0bc3f5b6c9ec5e7f5a6d3b0e4c4b2a3f5e6d7c8b9a0";
+
+/// BIP-32 test vector 1.
+const BIP32_SEED: &str = "000102030405060708090a0b0c0d0e0f";
+const HARDENED: u32 = 0x8000_0000;
+
+#[test]
+fn test_key_vault_sign_imported_private_key() {
+    let vault = KeyVault::new();
+    let handle = vault
+        .import_private_key(SECP256K1_PRIVATE_KEY.decode_hex().unwrap())
+        .unwrap();
+    assert!(vault.contains(handle));
+    assert_eq!(vault.len(), 1);
+
+    let message = MESSAGE_HASH.decode_hex().unwrap();
+    let expected = PrivateKey::new(SECP256K1_PRIVATE_KEY.decode_hex().unwrap())
+        .unwrap()
+        .sign(&message, Curve::Secp256k1)
+        .unwrap();
+
+    let actual = vault.sign(handle, &message, Curve::Secp256k1).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_key_vault_derive_secp256k1() {
+    let vault = KeyVault::new();
+    let seed = vault.import_seed(BIP32_SEED.decode_hex().unwrap()).unwrap();
+
+    // m/0'
+    let account = vault.derive_secp256k1(seed, &[HARDENED]).unwrap();
+    let public = vault.public_key(account, PublicKeyType::Secp256k1).unwrap();
+    assert_eq!(
+        public.to_bytes().to_hex(),
+        "035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56"
+    );
+
+    // m/0'/1
+    let child = vault.derive_secp256k1(seed, &[HARDENED, 1]).unwrap();
+    let public = vault.public_key(child, PublicKeyType::Secp256k1).unwrap();
+    assert_eq!(
+        public.to_bytes().to_hex(),
+        "03501e454bf00751f24b1b489aa925215d66af2234e3891c3b21a52bedb3cd711c"
+    );
+
+    let message = MESSAGE_HASH.decode_hex().unwrap();
+    let expected_key = "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368";
+    let expected = PrivateKey::new(expected_key.decode_hex().unwrap())
+        .unwrap()
+        .sign(&message, Curve::Secp256k1)
+        .unwrap();
+    assert_eq!(
+        vault.sign(child, &message, Curve::Secp256k1).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_key_vault_unknown_handle() {
+    let vault = KeyVault::new();
+    let handle = vault
+        .import_private_key(SECP256K1_PRIVATE_KEY.decode_hex().unwrap())
+        .unwrap();
+
+    let message = MESSAGE_HASH.decode_hex().unwrap();
+    assert_eq!(
+        vault
+            .sign(VaultHandle::from_raw(0), &message, Curve::Secp256k1)
+            .unwrap_err()
+            .kind(),
+        KeyPairErrorKind::UnknownVaultHandle
+    );
+
+    assert!(vault.remove(handle));
+    assert!(!vault.remove(handle));
+    assert!(vault.is_empty());
+    assert_eq!(
+        vault
+            .sign(handle, &message, Curve::Secp256k1)
+            .unwrap_err()
+            .kind(),
+        KeyPairErrorKind::UnknownVaultHandle
+    );
+}
+
+#[test]
+fn test_key_vault_secret_kind_mismatch() {
+    let vault = KeyVault::new();
+    let seed = vault.import_seed(BIP32_SEED.decode_hex().unwrap()).unwrap();
+    let key = vault
+        .import_private_key(SECP256K1_PRIVATE_KEY.decode_hex().unwrap())
+        .unwrap();
+
+    let message = MESSAGE_HASH.decode_hex().unwrap();
+    assert_eq!(
+        vault
+            .sign(seed, &message, Curve::Secp256k1)
+            .unwrap_err()
+            .kind(),
+        KeyPairErrorKind::InvalidSecretKey
+    );
+    assert_eq!(
+        vault.derive_secp256k1(key, &[HARDENED]).unwrap_err().kind(),
+        KeyPairErrorKind::InvalidSecretKey
+    );
+}
+
+#[test]
+fn test_key_vault_invalid_secrets() {
+    let vault = KeyVault::new();
+    assert_eq!(
+        vault.import_seed(vec![1; 15]),
+        Err(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))
+    );
+    assert_eq!(
+        vault.import_seed(vec![1; 65]),
+        Err(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))
+    );
+    assert_eq!(
+        vault.import_private_key(vec![0; 32]),
+        Err(KeyPairError::new(KeyPairErrorKind::InvalidSecretKey))
+    );
+    assert!(vault.is_empty());
+}
+
+#[test]
+fn test_tw_key_vault_sign_derived() {
+    let seed = CByteArray::from(BIP32_SEED.decode_hex().unwrap());
+    let path = [HARDENED, 1];
+    let message = CByteArray::from(MESSAGE_HASH.decode_hex().unwrap());
+
+    unsafe {
+        let vault = tw_key_vault_create();
+        let seed = tw_key_vault_import_seed(vault, seed.data(), seed.size()).unwrap();
+        let child = tw_key_vault_derive_secp256k1(vault, seed, path.as_ptr(), path.len()).unwrap();
+
+        let signature = tw_key_vault_sign(
+            vault,
+            child,
+            message.data(),
+            message.size(),
+            Curve::Secp256k1 as u32,
+        )
+        .unwrap()
+        .into_vec();
+        assert_eq!(signature.len(), 65);
+
+        assert!(tw_key_vault_remove(vault, child));
+        let result = tw_key_vault_sign(
+            vault,
+            child,
+            message.data(),
+            message.size(),
+            Curve::Secp256k1 as u32,
+        );
+        assert_eq!(result.code, KeyPairErrorKind::UnknownVaultHandle.code());
+
+        tw_key_vault_delete(vault);
+    }
+}
+
+#[test]
+fn test_tw_key_vault_get_public_key() {
+    let seed = CByteArray::from(BIP32_SEED.decode_hex().unwrap());
+    let path = [HARDENED];
+
+    unsafe {
+        let vault = tw_key_vault_create();
+        let seed = tw_key_vault_import_seed(vault, seed.data(), seed.size()).unwrap();
+        let account =
+            tw_key_vault_derive_secp256k1(vault, seed, path.as_ptr(), path.len()).unwrap();
+
+        let public = TWPublicKeyHelper::wrap(tw_key_vault_get_public_key_by_type(
+            vault,
+            account,
+            PublicKeyType::Secp256k1 as u32,
+        ));
+        assert!(!public.is_null());
+        assert_eq!(
+            tw_public_key_data(public.ptr()).into_vec().to_hex(),
+            "035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56"
+        );
+
+        // A seed has no public key.
+        let public = TWPublicKeyHelper::wrap(tw_key_vault_get_public_key_by_type(
+            vault,
+            seed,
+            PublicKeyType::Secp256k1 as u32,
+        ));
+        assert!(public.is_null());
+
+        tw_key_vault_delete(vault);
+    }
+}
@@ -6,14 +6,19 @@ use tw_encoding::hex;
 use tw_hash::sha2::sha256;
 use tw_hash::sha3::keccak256;
 use tw_hash::H256;
+use tw_keypair::ffi::error::tw_keypair_error_description;
 use tw_keypair::ffi::privkey::{
-    tw_private_key_create_with_data, tw_private_key_get_public_key_by_type,
-    tw_private_key_is_valid, tw_private_key_sign,
+    tw_private_key_bytes, tw_private_key_create_with_data, tw_private_key_disable_export,
+    tw_private_key_get_public_key_by_type, tw_private_key_is_exportable, tw_private_key_is_valid,
+    tw_private_key_sign, tw_private_key_sign_starkex, tw_private_key_sign_starkex_extended,
+    tw_private_key_sign_with_result, tw_private_key_size,
 };
 use tw_keypair::ffi::pubkey::{tw_public_key_data, tw_public_key_delete, tw_public_key_verify};
+use tw_keypair::starkex::{MessageHashMode, NonceMode};
 use tw_keypair::test_utils::tw_private_key_helper::TWPrivateKeyHelper;
 use tw_keypair::test_utils::tw_public_key_helper::TWPublicKeyHelper;
-use tw_keypair::tw::{Curve, PublicKeyType};
+use tw_keypair::tw::{Curve, PrivateKey, PublicKeyType};
+use tw_keypair::KeyPairErrorKind;
 use tw_memory::ffi::c_byte_array::CByteArray;
 
 fn test_sign(curve: Curve, secret: &str, msg: &str, expected_sign: &str) {
@@ -113,6 +118,96 @@ fn test_tw_private_key_sign_starkex() {
     test_sign(Curve::Starkex, secret, msg, sign);
 }
 
+#[test]
+fn test_tw_private_key_sign_starkex_truncate() {
+    let tw_privkey = TWPrivateKeyHelper::with_hex(
+        "0139fe4d6f02e666e86a6f58e65060f115cd3c185bd9e98bd829636931458f79",
+    );
+    // `06fea80189363a786037ed3e7ba546dad0ef7de49fccae0e31eb658b7dd4ea76 << 5`,
+    // doesn't fit the stark field.
+    let hash =
+        hex::decode("dfd5003126c74f0c06fda7cf74a8db5a1defbc93f995c1c63d6cb16fba9d4ec0").unwrap();
+    let hash_raw = CByteArray::from(hash);
+
+    let res = unsafe {
+        tw_private_key_sign_starkex(
+            tw_privkey.ptr(),
+            hash_raw.data(),
+            hash_raw.size(),
+            MessageHashMode::Strict as u32,
+            NonceMode::GrindExtraEntropy as u32,
+        )
+    };
+    assert!(res.is_err());
+
+    let actual = unsafe {
+        tw_private_key_sign_starkex(
+            tw_privkey.ptr(),
+            hash_raw.data(),
+            hash_raw.size(),
+            MessageHashMode::Truncate as u32,
+            NonceMode::GrindExtraEntropy as u32,
+        )
+        .unwrap()
+        .into_vec()
+    };
+    // The same as signing `06fea80189363a786037ed3e7ba546dad0ef7de49fccae0e31eb658b7dd4ea76` as is.
+    let expected = "061ec782f76a66f6984efc3a1b6d152a124c701c00abdd2bf76641b4135c770f04e44e759cea02c23568bb4d8a09929bbca8768ab68270d50c18d214166ccd9a";
+    assert_eq!(actual, hex::decode(expected).unwrap());
+
+    // Longer inputs are not 256-bit digests, so they can't be truncated.
+    let long_hash_raw = CByteArray::from(vec![0xff; 33]);
+    let res = unsafe {
+        tw_private_key_sign_starkex(
+            tw_privkey.ptr(),
+            long_hash_raw.data(),
+            long_hash_raw.size(),
+            MessageHashMode::Truncate as u32,
+            NonceMode::GrindExtraEntropy as u32,
+        )
+    };
+    assert_eq!(res.code, KeyPairErrorKind::InvalidSignMessage.code());
+
+    let res = unsafe {
+        tw_private_key_sign_starkex(tw_privkey.ptr(), hash_raw.data(), hash_raw.size(), 2, 0)
+    };
+    assert_eq!(res.code, KeyPairErrorKind::InvalidSignMessage.code());
+
+    let res = unsafe {
+        tw_private_key_sign_starkex(tw_privkey.ptr(), hash_raw.data(), hash_raw.size(), 1, 2)
+    };
+    assert_eq!(res.code, KeyPairErrorKind::InvalidSignMessage.code());
+}
+
+#[test]
+fn test_tw_private_key_sign_starkex_extended() {
+    let tw_privkey = TWPrivateKeyHelper::with_hex(
+        "0139fe4d6f02e666e86a6f58e65060f115cd3c185bd9e98bd829636931458f79",
+    );
+    let hash =
+        hex::decode("06fea80189363a786037ed3e7ba546dad0ef7de49fccae0e31eb658b7dd4ea76").unwrap();
+    let hash_raw = CByteArray::from(hash);
+
+    let actual = unsafe {
+        tw_private_key_sign_starkex_extended(
+            tw_privkey.ptr(),
+            hash_raw.data(),
+            hash_raw.size(),
+            MessageHashMode::Strict as u32,
+            NonceMode::Rfc6979 as u32,
+        )
+        .unwrap()
+        .into_vec()
+    };
+    // The first RFC6979 nonce is valid, so the signature is the same for both nonce modes.
+    let expected_rs = "061ec782f76a66f6984efc3a1b6d152a124c701c00abdd2bf76641b4135c770f04e44e759cea02c23568bb4d8a09929bbca8768ab68270d50c18d214166ccd9a";
+    assert_eq!(actual.len(), 96);
+    assert_eq!(hex::encode(&actual[..64], false), expected_rs);
+    // v-value is the y-parity.
+    assert!(actual[64..95].iter().all(|byte| *byte == 0));
+    assert!(actual[95] <= 1);
+}
+
 #[test]
 fn test_tw_private_key_sign_invalid_hash() {
     let tw_privkey = TWPrivateKeyHelper::with_hex(
@@ -149,6 +244,72 @@ fn test_tw_private_key_sign_null_hash() {
     assert!(actual.is_empty());
 }
 
+#[test]
+fn test_tw_private_key_sign_with_result() {
+    let tw_privkey = TWPrivateKeyHelper::with_hex(
+        "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5",
+    );
+    let msg =
+        hex::decode("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8").unwrap();
+    let msg_raw = CByteArray::from(msg);
+    let actual = unsafe {
+        tw_private_key_sign_with_result(
+            tw_privkey.ptr(),
+            msg_raw.data(),
+            msg_raw.size(),
+            Curve::Secp256k1 as u32,
+        )
+        .unwrap()
+        .into_vec()
+    };
+    let expected = "8720a46b5b3963790d94bcc61ad57ca02fd153584315bfa161ed3455e336ba624d68df010ed934b8792c5b6a57ba86c3da31d039f9612b44d1bf054132254de901";
+    assert_eq!(actual, hex::decode(expected).unwrap());
+}
+
+#[test]
+fn test_tw_private_key_sign_with_result_invalid_hash() {
+    let tw_privkey = TWPrivateKeyHelper::with_hex(
+        "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5",
+    );
+    // 31 bytes instead of 32.
+    let hash =
+        hex::decode("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36dea").unwrap();
+    let hash_raw = CByteArray::from(hash);
+    let res = unsafe {
+        tw_private_key_sign_with_result(
+            tw_privkey.ptr(),
+            hash_raw.data(),
+            hash_raw.size(),
+            Curve::Secp256k1 as u32,
+        )
+    };
+    assert_eq!(res.code, KeyPairErrorKind::InvalidSignMessage.code());
+}
+
+#[test]
+fn test_tw_private_key_sign_with_result_invalid_curve() {
+    let tw_privkey = TWPrivateKeyHelper::with_hex(
+        "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5",
+    );
+    let hash_raw = CByteArray::from(vec![1; 32]);
+    let res = unsafe {
+        tw_private_key_sign_with_result(tw_privkey.ptr(), hash_raw.data(), hash_raw.size(), 100)
+    };
+    assert_eq!(res.code, KeyPairErrorKind::InvalidCurve.code());
+}
+
+#[test]
+fn test_tw_keypair_error_description() {
+    let description =
+        unsafe { tw_keypair_error_description(KeyPairErrorKind::InvalidSignMessage.code()) };
+    assert!(!description.is_null());
+    let description = unsafe { std::ffi::CString::from_raw(description as *mut _) };
+    assert_eq!(description.to_str().unwrap(), "Invalid message to sign");
+
+    let description = unsafe { tw_keypair_error_description(0) };
+    assert!(description.is_null());
+}
+
 #[test]
 fn test_tw_private_key_get_public_key_by_type() {
     #[track_caller]
@@ -247,3 +408,77 @@ fn test_tw_private_key_sign_schnorr() {
     };
     assert!(is_valid, "Error verifying a schnorr signature");
 }
+
+#[test]
+fn test_tw_private_key_disable_export() {
+    let secret = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+    let msg = "f86a808509c7652400830130b9946b175474e89094c44da98b954eedeac495271d0f80b844a9059cbb0000000000000000000000005322b34c88ed0691971bf52a7047448f0f4efc840000000000000000000000000000000000000000000000001bc16d674ec8000025a0724c62ad4fbf47346b02de06e603e013f26f26b56fdc0be7ba3d6273401d98cea0032131cae15da7ddcda66963e8bef51ca0d9962bfef0547d3f02597a4a58c931";
+    let hash = keccak256(&hex::decode(msg).unwrap());
+    let hash_raw = CByteArray::from(hash);
+
+    let tw_privkey = TWPrivateKeyHelper::with_hex(secret);
+    let expected_sign = unsafe {
+        tw_private_key_sign(
+            tw_privkey.ptr(),
+            hash_raw.data(),
+            hash_raw.size(),
+            Curve::Secp256k1 as u32,
+        )
+        .into_vec()
+    };
+
+    unsafe {
+        assert!(tw_private_key_is_exportable(tw_privkey.ptr()));
+        assert!(!tw_private_key_bytes(tw_privkey.ptr()).is_null());
+        assert_eq!(tw_private_key_size(tw_privkey.ptr()), 32);
+
+        tw_private_key_disable_export(tw_privkey.ptr());
+
+        assert!(!tw_private_key_is_exportable(tw_privkey.ptr()));
+        assert!(tw_private_key_bytes(tw_privkey.ptr()).is_null());
+        assert_eq!(tw_private_key_size(tw_privkey.ptr()), 0);
+    }
+
+    // The non-exportable key can still sign.
+    let actual_sign = unsafe {
+        tw_private_key_sign(
+            tw_privkey.ptr(),
+            hash_raw.data(),
+            hash_raw.size(),
+            Curve::Secp256k1 as u32,
+        )
+        .into_vec()
+    };
+    assert!(!actual_sign.is_empty());
+    assert_eq!(actual_sign, expected_sign);
+}
+
+#[test]
+fn test_private_key_non_exportable_key() {
+    let secret =
+        hex::decode("afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5").unwrap();
+    let hash = sha256(b"hello");
+
+    let exportable = PrivateKey::new(secret.clone()).unwrap();
+    assert_eq!(exportable.key().unwrap(), secret);
+
+    let non_exportable = PrivateKey::new_non_exportable(secret).unwrap();
+    assert_eq!(
+        non_exportable.key().unwrap_err().kind(),
+        KeyPairErrorKind::NonExportableKey
+    );
+    assert_eq!(
+        non_exportable.extended_cardano_key().unwrap_err().kind(),
+        KeyPairErrorKind::NonExportableKey
+    );
+    assert_eq!(
+        non_exportable.bytes().unwrap_err().kind(),
+        KeyPairErrorKind::NonExportableKey
+    );
+
+    // The non-exportable key can still sign.
+    assert_eq!(
+        non_exportable.sign(&hash, Curve::Secp256k1).unwrap(),
+        exportable.sign(&hash, Curve::Secp256k1).unwrap()
+    );
+}
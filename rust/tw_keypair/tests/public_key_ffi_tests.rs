@@ -5,7 +5,11 @@
 use tw_encoding::hex;
 use tw_hash::sha2::sha256;
 use tw_hash::sha3::keccak256;
-use tw_keypair::ffi::pubkey::{tw_public_key_delete, tw_public_key_verify};
+use tw_keypair::ffi::pubkey::{
+    tw_public_key_account_identifier, tw_public_key_create_with_der, tw_public_key_delete,
+    tw_public_key_der_encoded, tw_public_key_fingerprint, tw_public_key_key_identifier,
+    tw_public_key_verify,
+};
 use tw_keypair::test_utils::tw_public_key_helper::TWPublicKeyHelper;
 use tw_keypair::tw::PublicKeyType;
 use tw_memory::ffi::c_byte_array::CByteArray;
@@ -120,3 +124,114 @@ fn test_tw_public_key_verify_ed25519_extended_cardano() {
     let sign = "375df53b6a4931dcf41e062b1c64288ed4ff3307f862d5c1b1c71964ce3b14c99422d0fdfeb2807e9900a26d491d5e8a874c24f98eec141ed694d7a433a90f08";
     test_verify(PublicKeyType::Ed25519ExtendedCardano, public, &msg, sign);
 }
+
+#[test]
+fn test_tw_public_key_der_encoded() {
+    #[track_caller]
+    fn test_impl(ty: PublicKeyType, public: &str, der: &str) {
+        let tw_public = TWPublicKeyHelper::with_hex(public, ty);
+        assert!(!tw_public.is_null());
+
+        let actual = unsafe { tw_public_key_der_encoded(tw_public.ptr()).into_vec() };
+        assert_eq!(hex::encode(actual, false), der);
+
+        let der_raw = CByteArray::from(hex::decode(der).unwrap());
+        let tw_parsed = TWPublicKeyHelper::wrap(unsafe {
+            tw_public_key_create_with_der(der_raw.data(), der_raw.size(), ty as u32)
+        });
+        assert!(!tw_parsed.is_null());
+    }
+
+    test_impl(
+        PublicKeyType::Secp256k1,
+        "0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1",
+        "3056301006072a8648ce3d020106052b8104000a0342000499c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c166b489a4b7c491e7688e6ebea3a71fc3a1a48d60f98d5ce84c93b65e423fde91",
+    );
+    test_impl(
+        PublicKeyType::Nist256p1Extended,
+        "046d786ab8fda678cf50f71d13641049a393b325063b8c0d4e5070de48a2caf9ab918b4fe46ccbf56701fb210d67d91c5779468f6b3fdc7a63692b9b62543f47ae",
+        "3059301306072a8648ce3d020106082a8648ce3d030107034200046d786ab8fda678cf50f71d13641049a393b325063b8c0d4e5070de48a2caf9ab918b4fe46ccbf56701fb210d67d91c5779468f6b3fdc7a63692b9b62543f47ae",
+    );
+}
+
+#[test]
+fn test_tw_public_key_der_encoded_unsupported() {
+    let tw_public = TWPublicKeyHelper::with_hex(
+        "4870d56d074c50e891506d78faa4fb69ca039cc5f131eb491e166b975880e867",
+        PublicKeyType::Ed25519,
+    );
+    assert!(!tw_public.is_null());
+
+    let actual = unsafe { tw_public_key_der_encoded(tw_public.ptr()).into_vec() };
+    assert!(actual.is_empty());
+}
+
+#[test]
+fn test_tw_public_key_create_with_der_invalid() {
+    // `nist256p1` DER-encoded public key, but `Secp256k1` type.
+    let der = hex::decode("3059301306072a8648ce3d020106082a8648ce3d030107034200046d786ab8fda678cf50f71d13641049a393b325063b8c0d4e5070de48a2caf9ab918b4fe46ccbf56701fb210d67d91c5779468f6b3fdc7a63692b9b62543f47ae").unwrap();
+    let der_raw = CByteArray::from(der);
+    let tw_public = TWPublicKeyHelper::wrap(unsafe {
+        tw_public_key_create_with_der(
+            der_raw.data(),
+            der_raw.size(),
+            PublicKeyType::Secp256k1 as u32,
+        )
+    });
+    assert!(tw_public.is_null());
+}
+
+#[test]
+fn test_tw_public_key_identifiers() {
+    #[track_caller]
+    fn test_impl(
+        ty: PublicKeyType,
+        public: &str,
+        fingerprint: u32,
+        key_id: &str,
+        account_id: &str,
+    ) {
+        let tw_public = TWPublicKeyHelper::with_hex(public, ty);
+        assert!(!tw_public.is_null());
+
+        assert_eq!(
+            unsafe { tw_public_key_fingerprint(tw_public.ptr()) },
+            fingerprint
+        );
+        let actual = unsafe { tw_public_key_key_identifier(tw_public.ptr()).into_vec() };
+        assert_eq!(hex::encode(actual, false), key_id);
+        let actual = unsafe { tw_public_key_account_identifier(tw_public.ptr()).into_vec() };
+        assert_eq!(hex::encode(actual, false), account_id);
+    }
+
+    // Both forms of the same key have equal identifiers.
+    test_impl(
+        PublicKeyType::Secp256k1,
+        "0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1",
+        0x5e675567,
+        "5e67556730cddcfb2dadc05e9743cd8931006451",
+        "4d4edf5c17c676238e302c5c6dc28421cf7e4a95b67ab80dcc812328b0fc80c6",
+    );
+    test_impl(
+        PublicKeyType::Secp256k1Extended,
+        "0499c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c166b489a4b7c491e7688e6ebea3a71fc3a1a48d60f98d5ce84c93b65e423fde91",
+        0x5e675567,
+        "5e67556730cddcfb2dadc05e9743cd8931006451",
+        "4d4edf5c17c676238e302c5c6dc28421cf7e4a95b67ab80dcc812328b0fc80c6",
+    );
+    test_impl(
+        PublicKeyType::Ed25519,
+        "4870d56d074c50e891506d78faa4fb69ca039cc5f131eb491e166b975880e867",
+        0xacb81639,
+        "acb8163903add1e6e90cbb34dca0f08bf6501533",
+        "6cf258f981402c0bceb51d7edb5f24c305a2b4c486a9f5bc7217f22032601484",
+    );
+}
+
+#[test]
+fn test_tw_public_key_identifiers_null() {
+    let null = std::ptr::null_mut();
+    assert_eq!(unsafe { tw_public_key_fingerprint(null) }, 0);
+    assert!(unsafe { tw_public_key_key_identifier(null).into_vec() }.is_empty());
+    assert!(unsafe { tw_public_key_account_identifier(null).into_vec() }.is_empty());
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_encoding::hex::DecodeHex;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::ed25519;
+use tw_keypair::redact::{SecretFingerprint, ToZeroizingHex};
+use tw_keypair::tw::PrivateKey;
+
+const PRIVATE_KEY: &str = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+
+#[test]
+fn test_private_key_debug_redacted() {
+    let private = PrivateKey::new(PRIVATE_KEY.decode_hex().unwrap()).unwrap();
+
+    let output = format!("{private:?}");
+    assert_eq!(output, "PrivateKey { fingerprint: e9c5d7a2, .. }");
+    assert!(!output.contains(&PRIVATE_KEY[..8]));
+}
+
+#[test]
+fn test_curve_private_keys_debug_redacted() {
+    let fingerprint = SecretFingerprint::new(&PRIVATE_KEY.decode_hex().unwrap());
+    let expected = format!("PrivateKey {{ fingerprint: {fingerprint}, .. }}");
+
+    let secp256k1 = secp256k1::PrivateKey::try_from(PRIVATE_KEY).unwrap();
+    assert_eq!(format!("{secp256k1:?}"), expected);
+
+    let ed25519 = ed25519::sha512::PrivateKey::try_from(PRIVATE_KEY).unwrap();
+    assert_eq!(format!("{ed25519:?}"), expected);
+}
+
+#[test]
+fn test_private_key_to_zeroizing_hex() {
+    let private = PrivateKey::new(PRIVATE_KEY.decode_hex().unwrap()).unwrap();
+    assert_eq!(private.to_zeroizing_hex().unwrap().as_str(), PRIVATE_KEY);
+
+    let secp256k1 = secp256k1::PrivateKey::try_from(PRIVATE_KEY).unwrap();
+    assert_eq!(secp256k1.to_zeroizing_hex().as_str(), PRIVATE_KEY);
+}
@@ -5,8 +5,11 @@
 use serde::Deserialize;
 use tw_encoding::hex::as_hex;
 use tw_hash::{H256, H520};
+use tw_keypair::ecdsa::secp256k1::external::{self, RecoverableLayout};
 use tw_keypair::ecdsa::secp256k1::{KeyPair, VerifySignature};
 use tw_keypair::traits::{SigningKeyTrait, VerifyingKeyTrait};
+use tw_keypair::tw::{self, Curve};
+use tw_keypair::KeyPairErrorKind;
 
 /// The tests were generated in C++ using the `trezor-crypto` library.
 const SECP256K1_SIGN: &str = include_str!("secp256k1_sign.json");
@@ -33,3 +36,98 @@ fn test_secp256k1_sign_verify() {
         assert!(keypair.verify(verify_sign, test.hash));
     }
 }
+
+#[test]
+fn test_secp256k1_sign_with_cached_key() {
+    let tests: Vec<Secp256k1SignTest> = serde_json::from_str(SECP256K1_SIGN).unwrap();
+    for test in tests {
+        let private = tw::PrivateKey::new(test.secret.to_vec()).unwrap();
+        // The first call parses and caches the key, the next ones reuse it.
+        for _ in 0..3 {
+            let actual = private
+                .sign(test.hash.as_slice(), Curve::Secp256k1)
+                .unwrap();
+            assert_eq!(actual, test.signature.to_vec());
+        }
+    }
+}
+
+#[test]
+fn test_secp256k1_cached_invalid_key() {
+    // The curve order is a valid general private key, but not a valid `secp256k1` key.
+    let order = H256::from("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+    let private = tw::PrivateKey::new(order.to_vec()).unwrap();
+    for _ in 0..2 {
+        assert_eq!(
+            private
+                .sign(H256::default().as_slice(), Curve::Secp256k1)
+                .unwrap_err()
+                .kind(),
+            KeyPairErrorKind::InvalidSecretKey
+        );
+    }
+}
+
+#[test]
+fn test_secp256k1_external_signature() {
+    let tests: Vec<Secp256k1SignTest> = serde_json::from_str(SECP256K1_SIGN).unwrap();
+    for test in tests {
+        let keypair = KeyPair::try_from(test.secret.as_slice()).unwrap();
+        let public = keypair.public();
+        let expected = keypair.sign(test.hash).unwrap();
+
+        let der = expected.to_der().unwrap();
+        let actual = external::signature_from_der(&der.der_bytes(), public, test.hash).unwrap();
+        assert_eq!(actual, expected);
+
+        let rs = expected.to_verify_sig().to_bytes();
+        let actual = external::signature_from_rs(rs.as_slice(), public, test.hash).unwrap();
+        assert_eq!(actual, expected);
+
+        // Legacy Ethereum `v`.
+        let mut rsv = expected.to_bytes();
+        rsv[64] += 27;
+        let actual =
+            external::signature_from_recoverable(rsv.as_slice(), RecoverableLayout::Rsv).unwrap();
+        assert_eq!(actual, expected);
+
+        // BIP-137 compact signature header of a P2WPKH address.
+        let mut vrs = vec![39 + expected.v()];
+        vrs.extend_from_slice(rs.as_slice());
+        let actual = external::signature_from_recoverable(&vrs, RecoverableLayout::Vrs).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn test_secp256k1_external_signature_invalid() {
+    let tests: Vec<Secp256k1SignTest> = serde_json::from_str(SECP256K1_SIGN).unwrap();
+    let test = &tests[0];
+    let keypair = KeyPair::try_from(test.secret.as_slice()).unwrap();
+    let signature = keypair.sign(test.hash).unwrap();
+    let rs = signature.to_verify_sig().to_bytes();
+
+    // The signature doesn't match the hash.
+    let other_hash = H256::from([1; 32]);
+    assert_eq!(
+        external::signature_from_rs(rs.as_slice(), keypair.public(), other_hash)
+            .unwrap_err()
+            .kind(),
+        KeyPairErrorKind::InvalidSignature
+    );
+
+    let mut rsv = signature.to_bytes();
+    rsv[64] = 26;
+    assert_eq!(
+        external::signature_from_recoverable(rsv.as_slice(), RecoverableLayout::Rsv)
+            .unwrap_err()
+            .kind(),
+        KeyPairErrorKind::InvalidSignature
+    );
+    assert_eq!(
+        external::signature_from_recoverable(rs.as_slice(), RecoverableLayout::Rsv)
+            .unwrap_err()
+            .kind(),
+        KeyPairErrorKind::InvalidSignature
+    );
+}
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::json;
+use tw_keypair::ecdsa::{der, secp256k1};
+use tw_keypair::ed25519;
+use tw_keypair::tw::{PublicKey, PublicKeyType};
+use tw_misc::traits::ToBytesVec;
+
+#[test]
+fn test_public_key_serde() {
+    let public = PublicKey::new(
+        tw_encoding::hex::decode(
+            "0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1",
+        )
+        .unwrap(),
+        PublicKeyType::Secp256k1,
+    )
+    .unwrap();
+
+    let expected = json!({
+        "type": "secp256k1",
+        "data": "0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1",
+    });
+    assert_eq!(serde_json::to_value(&public).unwrap(), expected);
+
+    let decoded: PublicKey = serde_json::from_value(expected).unwrap();
+    assert_eq!(decoded.public_key_type(), PublicKeyType::Secp256k1);
+    assert_eq!(decoded.to_bytes(), public.to_bytes());
+}
+
+#[test]
+fn test_public_key_deserialize_invalid() {
+    // Compressed public key, but `secp256k1Extended` type.
+    serde_json::from_value::<PublicKey>(json!({
+        "type": "secp256k1Extended",
+        "data": "0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1",
+    }))
+    .unwrap_err();
+
+    // Unknown public key type.
+    serde_json::from_value::<PublicKey>(json!({
+        "type": "unknown",
+        "data": "0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1",
+    }))
+    .unwrap_err();
+}
+
+#[test]
+fn test_secp256k1_signature_serde() {
+    let sign_hex = "8720a46b5b3963790d94bcc61ad57ca02fd153584315bfa161ed3455e336ba624d68df010ed934b8792c5b6a57ba86c3da31d039f9612b44d1bf054132254de901";
+
+    let sign: secp256k1::Signature = serde_json::from_value(json!(sign_hex)).unwrap();
+    assert_eq!(sign.v(), 1);
+    assert_eq!(serde_json::to_value(&sign).unwrap(), json!(sign_hex));
+
+    // Signature without the recovery byte.
+    serde_json::from_value::<secp256k1::Signature>(json!(&sign_hex[..128])).unwrap_err();
+}
+
+#[test]
+fn test_der_signature_serde() {
+    let der_hex = "304402200f5d5a9e5fc4b82a625312f3be5d3e8ad017d882de86c72c92fcefa924e894c102202071772a14201a3a0debf381b5e8dea39fadb9bcabdc02ee71ab018f55bf717f";
+
+    let sign: der::Signature = serde_json::from_value(json!(der_hex)).unwrap();
+    assert_eq!(serde_json::to_value(&sign).unwrap(), json!(der_hex));
+}
+
+#[test]
+fn test_ed25519_signature_serde() {
+    let sign_hex = "42848abf2641a731e18b8a1fb80eff341a5acebdc56faeccdcbadb960aef775192842fccec344679446daa4d02d264259c8f9aa364164ebe0ebea218581e2e03";
+
+    let sign: ed25519::Signature = serde_json::from_value(json!(sign_hex)).unwrap();
+    assert_eq!(sign.to_vec(), tw_encoding::hex::decode(sign_hex).unwrap());
+    assert_eq!(serde_json::to_value(&sign).unwrap(), json!(sign_hex));
+}
@@ -14,7 +14,7 @@ fn test_starkex_tw_private_key() {
         hex::decode("02a4c7332c55d6c1c510d24272d1db82878f2302f05b53bcc38695ed5f78fffd").unwrap();
 
     let privkey = PrivateKey::new(privkey_bytes.clone()).unwrap();
-    assert_eq!(privkey.key().into_vec(), privkey_bytes);
+    assert_eq!(privkey.key().unwrap(), privkey_bytes);
 
     let public = privkey
         .get_public_key_by_type(PublicKeyType::Starkex)
@@ -0,0 +1,57 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens;
+use syn::{parse2, parse_quote, ItemFn, Result};
+
+/// Wraps the body of an FFI function into `tw_memory::ffi::panic::catch_panic`,
+/// so a panic is converted into a fallback value instead of unwinding across the FFI boundary.
+pub fn wrap_fn(mut func: ItemFn) -> ItemFn {
+    let func_name = func.sig.ident.to_string();
+    let block = &func.block;
+    func.block = parse_quote!({
+        ::tw_memory::ffi::panic::catch_panic(#func_name, move || #block)
+    });
+    func
+}
+
+pub fn tw_catch_panic(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2> {
+    if !attr.is_empty() {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "`tw_catch_panic` doesn't accept arguments",
+        ));
+    }
+
+    let func = parse2::<ItemFn>(item)?;
+    Ok(wrap_fn(func).into_token_stream())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn test_wrap_fn() {
+        let func = parse2::<ItemFn>(quote! {
+            #[no_mangle]
+            pub unsafe extern "C" fn tw_foo(input: *const u8) -> bool {
+                !input.is_null()
+            }
+        })
+        .unwrap();
+
+        let expected = quote! {
+            #[no_mangle]
+            pub unsafe extern "C" fn tw_foo(input: *const u8) -> bool {
+                ::tw_memory::ffi::panic::catch_panic("tw_foo", move || {
+                    !input.is_null()
+                })
+            }
+        };
+
+        assert_eq!(
+            wrap_fn(func).into_token_stream().to_string(),
+            expected.to_string()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use proc_macro::TokenStream;
 
+mod catch_panic;
 mod code_gen;
 mod tw_ffi;
 
@@ -10,3 +11,15 @@ pub fn tw_ffi(attr: TokenStream, item: TokenStream) -> TokenStream {
         Err(e) => e.to_compile_error().into(),
     }
 }
+
+/// Catches a panic in the FFI function body and returns a fallback value instead,
+/// storing the panic message as the last error. See `tw_memory::ffi::panic`.
+///
+/// Functions marked with `#[tw_ffi]` are wrapped automatically.
+#[proc_macro_attribute]
+pub fn tw_catch_panic(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match catch_panic::tw_catch_panic(attr.into(), item.into()) {
+        Ok(item) => item.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
@@ -1,6 +1,6 @@
 use derive_syn_parse::Parse;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
     parse2, Ident, Result, Token,
@@ -11,6 +11,7 @@ use std::fmt;
 use std::fs;
 use std::path::Path;
 
+use crate::catch_panic::wrap_fn;
 use crate::code_gen::{TWArg, TWConfig, TWFunction};
 
 pub mod keywords {
@@ -111,7 +112,7 @@ fn update_or_append_function(functions: &mut Option<Vec<TWFunction>>, function:
 pub fn tw_ffi(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2> {
     let args = parse2::<TWFFIAttrArgs>(attr)?;
 
-    let func = parse2::<syn::ItemFn>(item.clone())?;
+    let func = parse2::<syn::ItemFn>(item)?;
     let func_name = func.sig.ident.to_string();
     let func_args = func
         .sig
@@ -127,8 +128,7 @@ pub fn tw_ffi(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2> {
         })
         .collect::<Vec<TWArg>>();
 
-    let return_type = func.sig.output;
-    let return_type = match return_type {
+    let return_type = match &func.sig.output {
         syn::ReturnType::Type(_, ty) => quote!(#ty).to_string(),
         _ => "void".to_string(),
     };
@@ -217,7 +217,7 @@ pub fn tw_ffi(attr: TokenStream2, item: TokenStream2) -> Result<TokenStream2> {
     let yaml_output: String = serde_yaml::to_string(&config).expect("Failed to serialize to YAML");
     fs::write(&yaml_file_path, yaml_output).expect("Failed to write YAML file");
 
-    Ok(item)
+    Ok(wrap_fn(func).into_token_stream())
 }
 
 #[cfg(test)]
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Constant-time comparisons of secret data, such as private keys, shared secrets and MACs.
+//!
+//! Secret data must never be compared with `==` on slices, as it returns early on the first mismatch.
+
+pub use subtle::{Choice, ConstantTimeEq};
+
+/// Compares two byte slices in constant time.
+/// The execution time depends on the slice lengths only, not on their contents.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    a.ct_eq(b).into()
+}
+
+/// Checks if all the bytes are zero in constant time.
+/// The execution time depends on the slice length only, not on its contents.
+pub fn ct_is_zero(bytes: &[u8]) -> bool {
+    let acc = bytes.iter().fold(0_u8, |acc, byte| acc | byte);
+    acc.ct_eq(&0).into()
+}
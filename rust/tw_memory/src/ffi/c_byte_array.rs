@@ -2,6 +2,9 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use alloc::vec::Vec;
+use tw_macros::tw_catch_panic;
+
 #[repr(C)]
 pub struct CByteArrayResult {
     pub code: i32,
@@ -30,7 +33,7 @@ impl From<Vec<u8>> for CByteArray {
         let data = mut_vec.as_mut_ptr();
         let size = mut_vec.len();
         let capacity = mut_vec.capacity();
-        std::mem::forget(mut_vec);
+        core::mem::forget(mut_vec);
         CByteArray {
             data,
             size,
@@ -49,7 +52,7 @@ impl CByteArray {
     /// Returns a null `CByteArray` instance.
     pub fn null() -> CByteArray {
         CByteArray {
-            data: std::ptr::null_mut(),
+            data: core::ptr::null_mut(),
             size: 0,
             capacity: 0,
         }
@@ -85,7 +88,7 @@ impl CByteArray {
 
     /// Set the pointer, size and capacity to the default values.
     fn set_null(&mut self) {
-        self.data = std::ptr::null_mut();
+        self.data = core::ptr::null_mut();
         self.size = 0;
         self.capacity = 0;
     }
@@ -102,13 +105,14 @@ impl CByteArray {
 
     /// Returns the data slice.
     pub unsafe fn as_slice(&self) -> &[u8] {
-        std::slice::from_raw_parts(self.data, self.size)
+        core::slice::from_raw_parts(self.data, self.size)
     }
 }
 
 /// Releases the memory previously allocated for the pointer to `CByteArray`.
 /// \param ptr *non-null* C-compatible byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn free_c_byte_array(ptr: *mut CByteArray) {
     if ptr.is_null() {
         return;
@@ -2,6 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use alloc::vec::Vec;
+
 /// A C-compatible wrapper over a byte array given as the FFI argument.
 #[repr(C)]
 #[derive(Debug)]
@@ -36,6 +38,6 @@ impl CByteArrayRef {
         if self.data.is_null() {
             return None;
         }
-        Some(std::slice::from_raw_parts(self.data, self.size))
+        Some(core::slice::from_raw_parts(self.data, self.size))
     }
 }
@@ -2,10 +2,12 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use std::ffi::c_char;
+use core::ffi::c_char;
 
 pub const OK_CODE: ErrorCode = 0;
 pub const UNKNOWN_ERROR: ErrorCode = -1;
+/// The FFI function panicked. See [`crate::ffi::panic`].
+pub const PANIC_ERROR: ErrorCode = -2;
 
 pub type ErrorCode = i32;
 
@@ -101,6 +103,12 @@ pub struct CUInt8Result {
     pub result: u8,
 }
 
+#[repr(C)]
+pub struct CUInt32Result {
+    pub code: i32,
+    pub result: u32,
+}
+
 #[repr(C)]
 pub struct CUInt64Result {
     pub code: i32,
@@ -111,4 +119,5 @@ impl_c_result!(CStrResult, *const c_char, core::ptr::null());
 impl_c_result!(CStrMutResult, *mut c_char, core::ptr::null_mut());
 impl_c_result!(CBoolResult, bool, false);
 impl_c_result!(CUInt8Result, u8, 0);
+impl_c_result!(CUInt32Result, u32, 0);
 impl_c_result!(CUInt64Result, u64, 0);
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::constant_time::ct_eq;
+use tw_macros::tw_catch_panic;
+
+/// Compares two byte arrays in constant time.
+/// Should be used to compare secret data, such as MACs and shared secrets.
+/// \param a *non-null* byte array.
+/// \param a_len the length of the `a` array.
+/// \param b *non-null* byte array.
+/// \param b_len the length of the `b` array.
+/// \return whether the arrays are equal.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn constant_time_eq(
+    a: *const u8,
+    a_len: usize,
+    b: *const u8,
+    b_len: usize,
+) -> bool {
+    let a = core::slice::from_raw_parts(a, a_len);
+    let b = core::slice::from_raw_parts(b, b_len);
+    ct_eq(a, b)
+}
@@ -4,11 +4,16 @@
 
 #![allow(clippy::missing_safety_doc)]
 
-use std::ffi::{c_char, CString};
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use core::ffi::c_char;
+use tw_macros::tw_catch_panic;
 
 pub mod c_byte_array;
 pub mod c_byte_array_ref;
 pub mod c_result;
+pub mod constant_time;
+pub mod panic;
 pub mod tw_data;
 pub mod tw_data_vector;
 pub mod tw_string;
@@ -16,6 +21,7 @@ pub mod tw_string;
 /// Releases the memory previously allocated for the `ptr` string.
 /// \param ptr *non-null* C-compatible, nul-terminated string.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn free_string(ptr: *const c_char) {
     // Take the ownership back to rust and drop the owner.
     let _ = CString::from_raw(ptr as *mut _);
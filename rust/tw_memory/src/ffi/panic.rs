@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Prevents panics from unwinding across the FFI boundary.
+//!
+//! Every `extern "C"` function should run its body within [`catch_panic`].
+//! It's done automatically by `#[tw_ffi]` and `#[tw_catch_panic]` attribute macros.
+//! If the body panics, the panic message is stored as the last error of the current thread,
+//! and the function returns the [`PanicFallback::on_panic`] value instead.
+//!
+//! Please note that panics can be caught only if the library is built with `panic = "unwind"`.
+//! The WASM target is built with `panic = "abort"` (see `tools/rust-bindgen`), so a panic aborts the module there.
+//! Without `std`, the body is called as is.
+
+use crate::ffi::c_byte_array::{CByteArray, CByteArrayResult};
+use crate::ffi::c_result::{
+    CBoolResult, CStrMutResult, CStrResult, CUInt32Result, CUInt64Result, CUInt8Result, PANIC_ERROR,
+};
+use alloc::string::String;
+use core::ffi::c_char;
+use tw_macros::tw_catch_panic;
+
+/// A value returned from an FFI function if its body panicked.
+pub trait PanicFallback {
+    fn on_panic() -> Self;
+}
+
+impl PanicFallback for () {
+    fn on_panic() -> Self {}
+}
+
+impl PanicFallback for bool {
+    fn on_panic() -> Self {
+        false
+    }
+}
+
+impl<T> PanicFallback for *const T {
+    fn on_panic() -> Self {
+        core::ptr::null()
+    }
+}
+
+impl<T> PanicFallback for *mut T {
+    fn on_panic() -> Self {
+        core::ptr::null_mut()
+    }
+}
+
+impl PanicFallback for CByteArray {
+    fn on_panic() -> Self {
+        CByteArray::null()
+    }
+}
+
+/// Unsigned integers are sizes or values, so `0` means "no value".
+macro_rules! impl_panic_fallback_for_uint {
+    ($($int:ty),+) => {
+        $(
+            impl PanicFallback for $int {
+                fn on_panic() -> Self {
+                    0
+                }
+            }
+        )+
+    };
+}
+
+/// Signed integers are usually error codes, where `0` is [`OK_CODE`](crate::ffi::c_result::OK_CODE),
+/// so [`PANIC_ERROR`] is returned instead.
+macro_rules! impl_panic_fallback_for_int {
+    ($($int:ty),+) => {
+        $(
+            impl PanicFallback for $int {
+                fn on_panic() -> Self {
+                    PANIC_ERROR as $int
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_panic_fallback_for_c_result {
+    ($($name:ty),+) => {
+        $(
+            impl PanicFallback for $name {
+                fn on_panic() -> Self {
+                    <$name>::error(PANIC_ERROR)
+                }
+            }
+        )+
+    };
+}
+
+impl_panic_fallback_for_uint!(u8, u16, u32, u64, usize);
+impl_panic_fallback_for_int!(i8, i16, i32, i64, isize);
+impl_panic_fallback_for_c_result!(
+    CByteArrayResult,
+    CStrResult,
+    CStrMutResult,
+    CBoolResult,
+    CUInt8Result,
+    CUInt32Result,
+    CUInt64Result
+);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static LAST_ERROR: core::cell::RefCell<Option<String>> = const { core::cell::RefCell::new(None) };
+}
+
+/// Calls `f` and returns its result.
+/// If `f` panics, stores the panic message as the last error and returns [`PanicFallback::on_panic`].
+///
+/// `fn_name` is the name of the FFI function `f` is the body of.
+#[cfg(feature = "std")]
+pub fn catch_panic<R, F>(fn_name: &str, f: F) -> R
+where
+    R: PanicFallback,
+    F: FnOnce() -> R,
+{
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => {
+            let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                *message
+            } else if let Some(message) = payload.downcast_ref::<String>() {
+                message.as_str()
+            } else {
+                "unknown panic"
+            };
+            set_last_error(format!("'{fn_name}' panicked: {message}"));
+            R::on_panic()
+        },
+    }
+}
+
+/// Calls `f` and returns its result.
+/// Panics cannot be caught without `std`.
+#[cfg(not(feature = "std"))]
+pub fn catch_panic<R, F>(_fn_name: &str, f: F) -> R
+where
+    R: PanicFallback,
+    F: FnOnce() -> R,
+{
+    f()
+}
+
+/// Stores the `message` as the last error of the current thread.
+#[cfg(feature = "std")]
+pub fn set_last_error<S: Into<String>>(message: S) {
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message.into()));
+}
+
+/// Returns the last error of the current thread if it occurred.
+#[cfg(feature = "std")]
+pub fn last_error() -> Option<String> {
+    LAST_ERROR.with(|last| last.borrow().clone())
+}
+
+/// Clears the last error of the current thread.
+#[cfg(feature = "std")]
+pub fn clear_last_error() {
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+}
+
+/// The last error is not tracked without `std`.
+#[cfg(not(feature = "std"))]
+pub fn set_last_error<S: Into<String>>(_message: S) {}
+
+/// The last error is not tracked without `std`.
+#[cfg(not(feature = "std"))]
+pub fn last_error() -> Option<String> {
+    None
+}
+
+/// The last error is not tracked without `std`.
+#[cfg(not(feature = "std"))]
+pub fn clear_last_error() {}
+
+/// Returns the message of the last error occurred on the current thread, e.g. a panic caught in an FFI function.
+/// \return *nullable* C-compatible, nul-terminated string. Null if there is no error.
+/// The string must be released with `free_string`.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_last_error_message() -> *const c_char {
+    match last_error() {
+        Some(message) => crate::c_string_standalone(message.replace('\0', "")),
+        None => core::ptr::null(),
+    }
+}
+
+/// Clears the last error of the current thread.
+#[no_mangle]
+#[tw_catch_panic]
+pub extern "C" fn tw_clear_last_error() {
+    clear_last_error()
+}
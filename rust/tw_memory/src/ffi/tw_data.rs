@@ -5,6 +5,8 @@
 use crate::ffi::c_byte_array_ref::CByteArrayRef;
 use crate::ffi::RawPtrTrait;
 use crate::Data;
+use alloc::vec::Vec;
+use tw_macros::tw_catch_panic;
 
 /// Defines a resizable block of data.
 ///
@@ -64,16 +66,18 @@ impl RawPtrTrait for TWData {}
 /// \param size size of the buffer
 /// \return Non-null filled block of data.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_data_create_with_bytes(bytes: *const u8, size: usize) -> *mut TWData {
     TWData::from_raw_data(bytes, size)
         .map(|data| data.into_ptr())
-        .unwrap_or_else(std::ptr::null_mut)
+        .unwrap_or_else(core::ptr::null_mut)
 }
 
 /// Deletes a block of data created with a `TWDataCreate*` method.
 ///
 /// \param data A non-null valid block of data
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_data_delete(data: *mut TWData) {
     // Take the ownership back to rust and drop the owner.
     let _ = TWData::from_ptr(data);
@@ -84,10 +88,11 @@ pub unsafe extern "C" fn tw_data_delete(data: *mut TWData) {
 /// \param data A non-null valid block of data
 /// \return the raw pointer to the contents of data
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_data_bytes(data: *const TWData) -> *const u8 {
     TWData::from_ptr_as_ref(data)
         .map(TWData::data)
-        .unwrap_or_else(std::ptr::null)
+        .unwrap_or_else(core::ptr::null)
 }
 
 /// Returns the size in bytes.
@@ -95,6 +100,7 @@ pub unsafe extern "C" fn tw_data_bytes(data: *const TWData) -> *const u8 {
 /// \param data A non-null valid block of data
 /// \return the size of the given block of data
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_data_size(data: *const TWData) -> usize {
     TWData::from_ptr_as_ref(data)
         .map(|data| data.size())
@@ -4,6 +4,8 @@
 
 use crate::ffi::tw_data::TWData;
 use crate::ffi::RawPtrTrait;
+use alloc::vec::Vec;
+use tw_macros::tw_catch_panic;
 
 type Data = Vec<u8>;
 
@@ -27,12 +29,23 @@ impl TWDataVector {
         self.0.len()
     }
 
+    /// Returns an element at the given `index` if it exists.
+    pub fn get(&self, index: usize) -> Option<&TWData> {
+        self.0.get(index)
+    }
+
     /// Converts `Vec<TWData>` to `Vec<Data>` by cloning each element.
     pub fn to_data_vec(&self) -> Vec<Data> {
         self.0.iter().map(TWData::to_vec).collect()
     }
 }
 
+impl From<Vec<Data>> for TWDataVector {
+    fn from(vec: Vec<Data>) -> Self {
+        TWDataVector(vec.into_iter().map(TWData::from).collect())
+    }
+}
+
 impl RawPtrTrait for TWDataVector {}
 
 /// Creates a Vector of Data.
@@ -40,14 +53,33 @@ impl RawPtrTrait for TWDataVector {}
 /// \note Must be deleted with \TWDataVectorDelete
 /// \return a non-null Vector of Data.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_data_vector_create() -> *mut TWDataVector {
     TWDataVector::new().into_ptr()
 }
 
+/// Creates a Vector of Data with the given element. Element is cloned
+///
+/// \param data A non-null valid block of data
+/// \note Must be deleted with \TWDataVectorDelete
+/// \return a nullable Vector of Data. Null if the `data` is null.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_data_vector_create_with_data(data: *const TWData) -> *mut TWDataVector {
+    let Some(data_ref) = TWData::from_ptr_as_ref(data) else {
+        return core::ptr::null_mut();
+    };
+
+    let mut data_vector = TWDataVector::new();
+    data_vector.push(data_ref.clone());
+    data_vector.into_ptr()
+}
+
 /// Delete/Deallocate a Vector of Data
 ///
 /// \param data_vector A non-null Vector of data
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_data_vector_delete(data_vector: *mut TWDataVector) {
     // Take the ownership back to rust and drop the owner.
     let _ = TWDataVector::from_ptr(data_vector);
@@ -59,6 +91,7 @@ pub unsafe extern "C" fn tw_data_vector_delete(data_vector: *mut TWDataVector) {
 /// \param data A non-null valid block of data
 /// \note data input parameter must be deleted on its own
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_data_vector_add(data_vector: *mut TWDataVector, data: *const TWData) {
     let Some(data_vector) = TWDataVector::from_ptr_as_mut(data_vector) else {
         return;
@@ -70,3 +103,33 @@ pub unsafe extern "C" fn tw_data_vector_add(data_vector: *mut TWDataVector, data
 
     data_vector.push(data_ref.clone());
 }
+
+/// Retrieve the number of elements
+///
+/// \param data_vector A non-null Vector of data
+/// \return the size of the given vector.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_data_vector_size(data_vector: *const TWDataVector) -> usize {
+    TWDataVector::from_ptr_as_ref(data_vector)
+        .map(TWDataVector::size)
+        .unwrap_or_default()
+}
+
+/// Retrieve the n-th element. Element is cloned
+///
+/// \param data_vector A non-null Vector of data
+/// \param index index element of the vector to be retrieved, need to be < TWDataVectorSize
+/// \note Returned element must be freed with \TWDataDelete
+/// \return A nullable block of data. Null if the `index` is out of bounds.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_data_vector_get(
+    data_vector: *const TWDataVector,
+    index: usize,
+) -> *mut TWData {
+    TWDataVector::from_ptr_as_ref(data_vector)
+        .and_then(|data_vector| data_vector.get(index))
+        .map(|data| data.clone().into_ptr())
+        .unwrap_or_else(core::ptr::null_mut)
+}
@@ -4,7 +4,10 @@
 
 use crate::ffi::c_byte_array_ref::CByteArrayRef;
 use crate::ffi::RawPtrTrait;
-use std::ffi::{c_char, CStr, CString};
+use alloc::ffi::CString;
+use alloc::string::String;
+use core::ffi::{c_char, CStr};
+use tw_macros::tw_catch_panic;
 
 /// Defines a resizable string.
 ///
@@ -63,24 +66,27 @@ impl RawPtrTrait for TWString {}
 /// Creates a `TWString` from a null-terminated UTF8 byte array. It must be deleted at the end.
 /// \param bytes a null-terminated UTF8 byte array.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_string_create_with_utf8_bytes(bytes: *const c_char) -> *mut TWString {
     TWString::from_c_str(bytes)
         .map(TWString::into_ptr)
-        .unwrap_or_else(std::ptr::null_mut)
+        .unwrap_or_else(core::ptr::null_mut)
 }
 
 /// Returns the raw pointer to the string's UTF8 bytes (null-terminated).
 /// \param str a TWString pointer.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_string_utf8_bytes(str: *const TWString) -> *const c_char {
     TWString::from_ptr_as_ref(str)
         .map(|str| str.as_c_char())
-        .unwrap_or_else(std::ptr::null)
+        .unwrap_or_else(core::ptr::null)
 }
 
 /// Checks whether the C byte array is a UTF8 string.
 /// \return true if the given C byte array is UTF-8 string, otherwise false.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_string_is_utf8_bytes(bytes: *const u8, size: usize) -> bool {
     TWString::is_utf8_string(bytes, size)
 }
@@ -88,6 +94,7 @@ pub unsafe extern "C" fn tw_string_is_utf8_bytes(bytes: *const u8, size: usize)
 /// Deletes a string created with a `TWStringCreate*` method and frees the memory.
 /// \param str a `TWString` pointer.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_string_delete(str: *mut TWString) {
     let _ = TWString::from_ptr(str);
 }
@@ -2,11 +2,22 @@
 //
 // Copyright © 2017 Trust Wallet.
 
-use std::ffi::{c_char, CString};
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+// Allows `tw_macros` to refer to `::tw_memory` from within the crate.
+extern crate self as tw_memory;
+
+use alloc::ffi::CString;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::c_char;
 
 pub type Data = Vec<u8>;
 
+pub mod constant_time;
 pub mod ffi;
+pub mod secure_bytes;
 
 #[cfg(feature = "test-utils")]
 pub mod test_utils;
@@ -14,6 +25,6 @@ pub mod test_utils;
 pub fn c_string_standalone<S: Into<String>>(input: S) -> *const c_char {
     let res = CString::new(input.into()).unwrap();
     let p = res.as_ptr();
-    std::mem::forget(res);
+    core::mem::forget(res);
     p
 }
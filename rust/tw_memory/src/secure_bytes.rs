@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! A heap buffer for key material.
+//!
+//! With the `secure-memory` feature enabled on Unix platforms, the buffer is allocated on its own pages
+//! which are locked in RAM with `mlock`, so they are never written to swap,
+//! and excluded from core dumps with `madvise(MADV_DONTDUMP)` on Linux and Android.
+//! If the pages can't be locked (e.g. `RLIMIT_MEMLOCK` is exceeded), the buffer is still allocated,
+//! but [`SecureBytes::is_locked`] returns false.
+//!
+//! The contents are always wiped before the memory is released.
+//! Copies of the bytes made outside of the buffer, e.g. passed to C++ or to the host, are not covered.
+
+use crate::constant_time::{Choice, ConstantTimeEq};
+use alloc::alloc::{alloc_zeroed, dealloc, handle_alloc_error, Layout};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A fixed-size byte buffer that is wiped on drop and, if possible, locked in RAM.
+pub struct SecureBytes {
+    ptr: NonNull<u8>,
+    len: usize,
+    layout: Option<Layout>,
+    locked: bool,
+}
+
+// `SecureBytes` uniquely owns its allocation as `Box<[u8]>` does.
+unsafe impl Send for SecureBytes {}
+unsafe impl Sync for SecureBytes {}
+
+impl SecureBytes {
+    /// Allocates a zeroed buffer of the given `len`.
+    pub fn zeroed(len: usize) -> SecureBytes {
+        if len == 0 {
+            return SecureBytes {
+                ptr: NonNull::dangling(),
+                len,
+                layout: None,
+                locked: false,
+            };
+        }
+
+        let layout = allocation_layout(len);
+        // SAFETY: `layout` has a non-zero size.
+        let ptr = unsafe { alloc_zeroed(layout) };
+        let Some(ptr) = NonNull::new(ptr) else {
+            handle_alloc_error(layout);
+        };
+        // SAFETY: `ptr` points to a valid allocation of `layout`.
+        let locked = unsafe { lock_pages(ptr.as_ptr(), layout.size()) };
+
+        SecureBytes {
+            ptr,
+            len,
+            layout: Some(layout),
+            locked,
+        }
+    }
+
+    /// Copies the given `bytes` into a new buffer.
+    pub fn from_slice(bytes: &[u8]) -> SecureBytes {
+        let mut secure = SecureBytes::zeroed(bytes.len());
+        secure.copy_from_slice(bytes);
+        secure
+    }
+
+    /// Whether the buffer is locked in RAM and can't be swapped out.
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: `ptr` is valid for `len` bytes or dangling if `len` is 0.
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: `ptr` is valid for `len` bytes or dangling if `len` is 0.
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+/// Moves the bytes into a secure buffer and wipes the original vector.
+impl From<Vec<u8>> for SecureBytes {
+    fn from(mut bytes: Vec<u8>) -> Self {
+        let secure = SecureBytes::from_slice(&bytes);
+        bytes.zeroize();
+        secure
+    }
+}
+
+impl From<&[u8]> for SecureBytes {
+    fn from(bytes: &[u8]) -> Self {
+        SecureBytes::from_slice(bytes)
+    }
+}
+
+impl Deref for SecureBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for SecureBytes {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl AsRef<[u8]> for SecureBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl Clone for SecureBytes {
+    fn clone(&self) -> Self {
+        SecureBytes::from_slice(self.as_slice())
+    }
+}
+
+impl ConstantTimeEq for SecureBytes {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
+
+/// Compares the contents in constant time.
+impl PartialEq for SecureBytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SecureBytes {}
+
+/// Never prints the contents.
+impl fmt::Debug for SecureBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecureBytes")
+            .field("len", &self.len)
+            .field("locked", &self.locked)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Zeroize for SecureBytes {
+    fn zeroize(&mut self) {
+        self.as_mut_slice().zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecureBytes {}
+
+impl Drop for SecureBytes {
+    fn drop(&mut self) {
+        self.zeroize();
+
+        let Some(layout) = self.layout else {
+            return;
+        };
+        // SAFETY: `ptr` has been allocated with `layout`.
+        unsafe {
+            unlock_pages(self.ptr.as_ptr(), layout.size());
+            dealloc(self.ptr.as_ptr(), layout);
+        }
+    }
+}
+
+/// Page-aligned whole pages, so locking doesn't affect neighbouring allocations.
+#[cfg(all(feature = "secure-memory", unix))]
+fn allocation_layout(len: usize) -> Layout {
+    // SAFETY: `sysconf` has no preconditions.
+    let page_size = match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+        size if size > 0 => size as usize,
+        _ => 4096,
+    };
+    let size = len.div_ceil(page_size) * page_size;
+    Layout::from_size_align(size, page_size).expect("Page-aligned layout must be valid")
+}
+
+#[cfg(not(all(feature = "secure-memory", unix)))]
+fn allocation_layout(len: usize) -> Layout {
+    Layout::array::<u8>(len).expect("Byte array layout must be valid")
+}
+
+#[cfg(all(feature = "secure-memory", unix))]
+unsafe fn lock_pages(ptr: *mut u8, size: usize) -> bool {
+    let addr = ptr as *mut libc::c_void;
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    libc::madvise(addr, size, libc::MADV_DONTDUMP);
+    libc::mlock(addr, size) == 0
+}
+
+#[cfg(not(all(feature = "secure-memory", unix)))]
+unsafe fn lock_pages(_ptr: *mut u8, _size: usize) -> bool {
+    false
+}
+
+#[cfg(all(feature = "secure-memory", unix))]
+unsafe fn unlock_pages(ptr: *mut u8, size: usize) {
+    let addr = ptr as *mut libc::c_void;
+    libc::munlock(addr, size);
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    libc::madvise(addr, size, libc::MADV_DODUMP);
+}
+
+#[cfg(not(all(feature = "secure-memory", unix)))]
+unsafe fn unlock_pages(_ptr: *mut u8, _size: usize) {}
@@ -3,7 +3,8 @@
 // Copyright © 2017 Trust Wallet.
 
 use crate::ffi::tw_data_vector::{
-    tw_data_vector_add, tw_data_vector_create, tw_data_vector_delete, TWDataVector,
+    tw_data_vector_add, tw_data_vector_create, tw_data_vector_delete, tw_data_vector_get,
+    tw_data_vector_size, TWDataVector,
 };
 use crate::test_utils::tw_data_helper::TWDataHelper;
 
@@ -27,9 +28,29 @@ impl TWDataVectorHelper {
         TWDataVectorHelper { ptr }
     }
 
+    pub fn wrap(ptr: *mut TWDataVector) -> TWDataVectorHelper {
+        TWDataVectorHelper { ptr }
+    }
+
     pub fn ptr(&self) -> *mut TWDataVector {
         self.ptr
     }
+
+    pub fn is_null(&self) -> bool {
+        self.ptr.is_null()
+    }
+
+    pub fn to_vec(&self) -> Option<Vec<Data>> {
+        if self.ptr.is_null() {
+            return None;
+        }
+        let size = unsafe { tw_data_vector_size(self.ptr) };
+        (0..size)
+            .map(|index| {
+                TWDataHelper::wrap(unsafe { tw_data_vector_get(self.ptr, index) }).to_vec()
+            })
+            .collect()
+    }
 }
 
 impl Default for TWDataVectorHelper {
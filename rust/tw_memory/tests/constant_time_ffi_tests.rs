@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_memory::ffi::constant_time::constant_time_eq;
+
+fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    unsafe { constant_time_eq(a.as_ptr(), a.len(), b.as_ptr(), b.len()) }
+}
+
+#[test]
+fn test_constant_time_eq() {
+    assert!(ct_eq(&[1, 2, 3], &[1, 2, 3]));
+    assert!(ct_eq(&[], &[]));
+    assert!(!ct_eq(&[1, 2, 3], &[1, 2, 4]));
+    assert!(!ct_eq(&[1, 2, 3], &[1, 2]));
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::ffi::CStr;
+use tw_macros::tw_catch_panic;
+use tw_memory::ffi::c_byte_array::{CByteArray, CByteArrayResult};
+use tw_memory::ffi::c_result::PANIC_ERROR;
+use tw_memory::ffi::free_string;
+use tw_memory::ffi::panic::{tw_clear_last_error, tw_last_error_message};
+
+#[tw_catch_panic]
+unsafe extern "C" fn tw_test_get_byte(data: *const u8, size: usize, index: usize) -> u8 {
+    std::slice::from_raw_parts(data, size)[index]
+}
+
+#[tw_catch_panic]
+unsafe extern "C" fn tw_test_check_byte(data: *const u8, size: usize, index: usize) -> i32 {
+    std::slice::from_raw_parts(data, size)[index] as i32
+}
+
+#[tw_catch_panic]
+unsafe extern "C" fn tw_test_split_off(
+    data: *const u8,
+    size: usize,
+    index: usize,
+) -> CByteArrayResult {
+    let data = std::slice::from_raw_parts(data, size);
+    CByteArrayResult::ok(CByteArray::from(data[index..].to_vec()))
+}
+
+fn last_error_message() -> Option<String> {
+    let ptr = tw_last_error_message();
+    if ptr.is_null() {
+        return None;
+    }
+    let message = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+    unsafe { free_string(ptr) };
+    Some(message)
+}
+
+#[test]
+fn test_catch_panic_ok() {
+    tw_clear_last_error();
+
+    let data = [1, 2, 3];
+    let byte = unsafe { tw_test_get_byte(data.as_ptr(), data.len(), 1) };
+    assert_eq!(byte, 2);
+    assert_eq!(last_error_message(), None);
+
+    let res = unsafe { tw_test_split_off(data.as_ptr(), data.len(), 1) };
+    assert_eq!(unsafe { res.unwrap().into_vec() }, [2, 3]);
+    assert_eq!(last_error_message(), None);
+}
+
+#[test]
+fn test_catch_panic_fallback() {
+    tw_clear_last_error();
+
+    let data = [1, 2, 3];
+    let byte = unsafe { tw_test_get_byte(data.as_ptr(), data.len(), 10) };
+    assert_eq!(byte, 0);
+    assert_eq!(
+        last_error_message().unwrap(),
+        "'tw_test_get_byte' panicked: index out of bounds: the len is 3 but the index is 10"
+    );
+
+    // `0` would be confused with `OK_CODE`.
+    let code = unsafe { tw_test_check_byte(data.as_ptr(), data.len(), 10) };
+    assert_eq!(code, PANIC_ERROR);
+    assert!(last_error_message()
+        .unwrap()
+        .starts_with("'tw_test_check_byte' panicked: "));
+
+    let res = unsafe { tw_test_split_off(data.as_ptr(), data.len(), 10) };
+    assert_eq!(res.code, PANIC_ERROR);
+    assert!(last_error_message()
+        .unwrap()
+        .starts_with("'tw_test_split_off' panicked: "));
+
+    tw_clear_last_error();
+    assert_eq!(last_error_message(), None);
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_memory::secure_bytes::SecureBytes;
+use zeroize::Zeroize;
+
+#[test]
+fn test_secure_bytes_from_vec() {
+    let bytes = SecureBytes::from(vec![1_u8, 2, 3, 4]);
+    assert_eq!(bytes.as_slice(), [1, 2, 3, 4]);
+    assert_eq!(bytes.len(), 4);
+    assert_eq!(&bytes[1..3], [2, 3]);
+
+    let cloned = bytes.clone();
+    assert_eq!(cloned.as_slice(), bytes.as_slice());
+    assert_ne!(cloned.as_ptr(), bytes.as_ptr());
+}
+
+#[test]
+fn test_secure_bytes_empty() {
+    let bytes = SecureBytes::from_slice(&[]);
+    assert!(bytes.is_empty());
+    assert!(!bytes.is_locked());
+}
+
+#[test]
+fn test_secure_bytes_zeroize() {
+    let mut bytes = SecureBytes::from_slice(&[0xff; 32]);
+    bytes.zeroize();
+    assert_eq!(bytes.as_slice(), [0; 32]);
+    assert_eq!(bytes.len(), 32);
+}
+
+#[test]
+fn test_secure_bytes_debug_redacted() {
+    let bytes = SecureBytes::from_slice(&[0xab; 4]);
+    let debug = format!("{bytes:?}");
+    assert!(debug.starts_with("SecureBytes { len: 4, locked: "));
+    assert!(!debug.contains("171"));
+}
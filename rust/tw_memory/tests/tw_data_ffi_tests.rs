@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_memory::ffi::tw_data::{
+    tw_data_bytes, tw_data_create_with_bytes, tw_data_delete, tw_data_size, TWData,
+};
+use tw_memory::ffi::tw_data_vector::{
+    tw_data_vector_add, tw_data_vector_create, tw_data_vector_create_with_data,
+    tw_data_vector_delete, tw_data_vector_get, tw_data_vector_size,
+};
+
+unsafe fn tw_data_to_vec(data: *const TWData) -> Vec<u8> {
+    std::slice::from_raw_parts(tw_data_bytes(data), tw_data_size(data)).to_vec()
+}
+
+#[test]
+fn test_tw_data() {
+    let bytes = [1, 2, 3];
+    unsafe {
+        let data = tw_data_create_with_bytes(bytes.as_ptr(), bytes.len());
+        assert!(!data.is_null());
+        assert_eq!(tw_data_size(data), 3);
+        assert_eq!(tw_data_to_vec(data), bytes);
+        tw_data_delete(data);
+
+        assert_eq!(tw_data_size(std::ptr::null()), 0);
+        assert!(tw_data_bytes(std::ptr::null()).is_null());
+    }
+}
+
+#[test]
+fn test_tw_data_vector() {
+    let first = [1, 2, 3];
+    let second = [4, 5];
+    unsafe {
+        let first_data = tw_data_create_with_bytes(first.as_ptr(), first.len());
+        let second_data = tw_data_create_with_bytes(second.as_ptr(), second.len());
+
+        let data_vector = tw_data_vector_create();
+        assert_eq!(tw_data_vector_size(data_vector), 0);
+        assert!(tw_data_vector_get(data_vector, 0).is_null());
+
+        tw_data_vector_add(data_vector, first_data);
+        tw_data_vector_add(data_vector, second_data);
+        // Elements are cloned, so the input data can be deleted.
+        tw_data_delete(first_data);
+        tw_data_delete(second_data);
+
+        assert_eq!(tw_data_vector_size(data_vector), 2);
+
+        let element = tw_data_vector_get(data_vector, 0);
+        assert_eq!(tw_data_to_vec(element), first);
+        tw_data_delete(element);
+
+        let element = tw_data_vector_get(data_vector, 1);
+        assert_eq!(tw_data_to_vec(element), second);
+        tw_data_delete(element);
+
+        assert!(tw_data_vector_get(data_vector, 2).is_null());
+        tw_data_vector_delete(data_vector);
+    }
+}
+
+#[test]
+fn test_tw_data_vector_create_with_data() {
+    let bytes = [1, 2, 3];
+    unsafe {
+        let data = tw_data_create_with_bytes(bytes.as_ptr(), bytes.len());
+        let data_vector = tw_data_vector_create_with_data(data);
+        tw_data_delete(data);
+
+        assert_eq!(tw_data_vector_size(data_vector), 1);
+        let element = tw_data_vector_get(data_vector, 0);
+        assert_eq!(tw_data_to_vec(element), bytes);
+        tw_data_delete(element);
+        tw_data_vector_delete(data_vector);
+
+        assert!(tw_data_vector_create_with_data(std::ptr::null()).is_null());
+        assert_eq!(tw_data_vector_size(std::ptr::null()), 0);
+    }
+}
@@ -2,6 +2,10 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod macros;
 #[cfg(feature = "serde")]
 pub mod serde;
@@ -2,6 +2,8 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use alloc::string::String;
+use alloc::vec::Vec;
 use zeroize::Zeroizing;
 
 pub trait ToBytesVec {
@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::aptos::test_cases::transfer_b4d62afd::aptos_sign_transfer_input;
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_aptos::multisig::{MultiEd25519PublicKey, MultiEd25519Signature};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::ToHex;
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_misc::traits::ToBytesVec;
+use tw_proto::Aptos::Proto;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const PRIVATE_KEYS: [&str; 3] = [
+    "ef92d3da0fc6c2112864fcb7b2ba1b743409264196388242c1936db259a4ea5a",
+    "cee0c00b4b5b6e0c0899a3f74dbc50426f5a9646c21de53575df41b4d220cb77",
+    "1c07ccfff08a6f25ce742966d3e2b3f467facd96e353a321d5be7ed0747ad927",
+];
+const THRESHOLD: u32 = 2;
+const MULTISIG_ADDRESS: &str = "0xa21d559d8d7efba5d6763daaf61cefbccc41d3434cdf6485284ba5d17847c5f1";
+
+fn key_pair(idx: usize) -> ed25519::sha512::KeyPair {
+    ed25519::sha512::KeyPair::try_from(PRIVATE_KEYS[idx]).unwrap()
+}
+
+fn multisig_public_key() -> Proto::MultiEd25519PublicKey<'static> {
+    Proto::MultiEd25519PublicKey {
+        public_keys: (0..PRIVATE_KEYS.len())
+            .map(|idx| key_pair(idx).public().to_vec().into())
+            .collect(),
+        threshold: THRESHOLD,
+    }
+}
+
+fn multisig_transfer_input() -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        sender: MULTISIG_ADDRESS.into(),
+        multisig: Some(multisig_public_key()),
+        ..aptos_sign_transfer_input()
+    }
+}
+
+#[test]
+fn test_aptos_multisig_address() {
+    let multisig = MultiEd25519PublicKey::from_proto(&multisig_public_key()).unwrap();
+    assert_eq!(multisig.address().unwrap().to_string(), MULTISIG_ADDRESS);
+}
+
+/// Test vector from the Aptos TypeScript SDK `multi_ed25519.test.ts`.
+#[test]
+fn test_aptos_multisig_public_key_sdk_vector() {
+    let public_keys = [
+        "b9c6ee1630ef3e711144a648db06bbb2284f7274cfbee53ffcee503cc1a49200",
+        "aef3f4a4b8eca1dfc343361bf8e436bd42de9259c04b8314eb8e2054dd6e82ab",
+        "8a5762e21ac1cdb3870442c77b4c3af58c7cedb8779d0270e6d4f1e2f7367d74",
+    ]
+    .into_iter()
+    .map(|public_key| ed25519::sha512::PublicKey::try_from(public_key).unwrap())
+    .collect();
+    let multisig = MultiEd25519PublicKey::new(public_keys, 2).unwrap();
+
+    assert_eq!(multisig.to_bytes().to_hex(), "b9c6ee1630ef3e711144a648db06bbb2284f7274cfbee53ffcee503cc1a49200aef3f4a4b8eca1dfc343361bf8e436bd42de9259c04b8314eb8e2054dd6e82ab8a5762e21ac1cdb3870442c77b4c3af58c7cedb8779d0270e6d4f1e2f7367d7402");
+    // `sha3_256(public_keys | threshold | 0x01)`, where `0x01` is the MultiEd25519 authentication key scheme.
+    assert_eq!(
+        multisig.address().unwrap().to_string(),
+        "0xa81cfac3df59920593ff417b45fc347ead3d88f8e25112c0488d34d7c9eb20af"
+    );
+}
+
+/// Test vector from the Aptos TypeScript SDK `multi_ed25519.test.ts`:
+/// the bitmap of the signers `0`, `2` and `31` is `0b10100000 0 0 0b00000001`.
+#[test]
+fn test_aptos_multisig_signature_bitmap_sdk_vector() {
+    let key_pairs: Vec<_> = (0..32_u8)
+        .map(|idx| ed25519::sha512::KeyPair::try_from([idx + 1; 32].as_slice()).unwrap())
+        .collect();
+    let public_keys = key_pairs
+        .iter()
+        .map(|key_pair| key_pair.public().clone())
+        .collect();
+    let multisig = MultiEd25519PublicKey::new(public_keys, 3).unwrap();
+
+    let signatures = [31, 0, 2]
+        .into_iter()
+        .map(|idx: usize| {
+            let key_pair = &key_pairs[idx];
+            let signature = key_pair.sign(b"any data".to_vec()).unwrap();
+            (key_pair.public().clone(), signature)
+        })
+        .collect();
+    let signature = MultiEd25519Signature::new(&multisig, signatures).unwrap();
+
+    let bytes = signature.to_bytes();
+    assert_eq!(bytes.len(), 3 * 64 + 4);
+    assert_eq!(bytes[3 * 64..].to_hex(), "a0000001");
+}
+
+#[test]
+fn test_aptos_multisig_compile() {
+    let input = multisig_transfer_input();
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Aptos, &input);
+    assert_eq!(preimage_output.error, SigningErrorType::OK);
+
+    // Simulate remote co-signers, the signatures are reordered by the key index.
+    let (signatures, public_keys): (Vec<_>, Vec<_>) = [2, 0]
+        .into_iter()
+        .map(|idx| {
+            let key_pair = key_pair(idx);
+            let signature = key_pair
+                .sign(preimage_output.data.to_vec())
+                .unwrap()
+                .to_vec();
+            (signature, key_pair.public().to_vec())
+        })
+        .unzip();
+    assert_eq!(signatures[1].to_hex(), "1914b4f7000edde6a04e16d60369ab43333cd994a98adf30d6af77e8f4d3500b611ee599327388332c8c1428ae68564d68c44cafd872b05d1f06388bf4cacc05");
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Aptos, &input, signatures, public_keys);
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+
+    assert_eq!(output.raw_txn.to_hex(), "a21d559d8d7efba5d6763daaf61cefbccc41d3434cdf6485284ba5d17847c5f163000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021");
+    assert_eq!(output.encoded.to_hex(), "a21d559d8d7efba5d6763daaf61cefbccc41d3434cdf6485284ba5d17847c5f163000000000000000200000000000000000000000000000000000000000000000000000000000000010d6170746f735f6163636f756e74087472616e7366657200022007968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f3008e803000000000000fe4d3200000000006400000000000000c2276ada0000000021016119581e356f2fed27b5f68961f27e24b1b2866a049751364a7274133f4c286dee08e25fba7a7d95c5f5cb098612faa438efbb437ac8c67b8eef08c860c7b4683c05d2c730275a89578e0012ae2a738cf577915f767628355565c19f80a27f64360284011914b4f7000edde6a04e16d60369ab43333cd994a98adf30d6af77e8f4d3500b611ee599327388332c8c1428ae68564d68c44cafd872b05d1f06388bf4cacc056bc874846172800c76972d8b908a01673576f3c9516ed9b0b1854007fafac2d8fffa84eae91dd5144aa4e30790b3d6efc88e83ab5abdb4ff6ba2670543693503a0000000");
+
+    let json: serde_json::Value = serde_json::from_str(&output.json).unwrap();
+    assert_eq!(json["signature"]["type"], "multi_ed25519_signature");
+    assert_eq!(json["signature"]["bitmap"], "0xa0000000");
+    assert_eq!(json["signature"]["threshold"], THRESHOLD);
+}
+
+#[test]
+fn test_aptos_multisig_sender_derived() {
+    // The sender is derived from the MultiEd25519 account keys if omitted.
+    let input = Proto::SigningInput {
+        sender: "".into(),
+        ..multisig_transfer_input()
+    };
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Aptos, &input);
+    assert_eq!(preimage_output.error, SigningErrorType::OK);
+
+    let mut expected_pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let expected =
+        expected_pre_imager.pre_image_hashes(CoinType::Aptos, &multisig_transfer_input());
+    assert_eq!(preimage_output.data, expected.data);
+}
+
+#[test]
+fn test_aptos_multisig_sender_mismatch() {
+    let input = Proto::SigningInput {
+        sender: "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30".into(),
+        ..multisig_transfer_input()
+    };
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Aptos, &input);
+    assert_eq!(
+        preimage_output.error,
+        SigningErrorType::Error_invalid_address
+    );
+
+    let key_pair = key_pair(0);
+    let signature = key_pair.sign(b"any data".to_vec()).unwrap().to_vec();
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Aptos,
+        &input,
+        vec![signature],
+        vec![key_pair.public().to_vec()],
+    );
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+}
+
+#[test]
+fn test_aptos_multisig_compile_not_enough_signatures() {
+    let input = multisig_transfer_input();
+
+    let key_pair = key_pair(1);
+    let signature = key_pair.sign(b"any data".to_vec()).unwrap().to_vec();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Aptos,
+        &input,
+        vec![signature],
+        vec![key_pair.public().to_vec()],
+    );
+    assert_eq!(output.error, SigningErrorType::Error_signatures_count);
+}
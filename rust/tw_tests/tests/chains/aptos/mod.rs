@@ -4,6 +4,7 @@
 
 mod aptos_address;
 mod aptos_compile;
+mod aptos_multisig;
 mod aptos_sign;
 mod aptos_transaction_util;
 mod test_cases;
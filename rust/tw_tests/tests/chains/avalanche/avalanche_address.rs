@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::address_utils::{
+    test_address_bech32_is_valid, test_address_create_bech32_with_public_key, test_address_derive,
+    test_address_get_data, test_address_invalid, test_address_normalization, test_address_valid,
+    AddressBech32IsValid, AddressCreateBech32WithPublicKey, KeyType,
+};
+use tw_coin_registry::coin_type::CoinType;
+use tw_keypair::tw::PublicKeyType;
+
+#[test]
+fn test_avalanche_address_derive() {
+    test_address_derive(
+        CoinType::AvalancheXChain,
+        KeyType::PrivateKey("56289e99c94b6912bfc12adc093c9b51124f0dc54ac7a766b2bc5ccf558d8027"),
+        "X-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5",
+    );
+    test_address_derive(
+        CoinType::AvalanchePChain,
+        KeyType::PublicKey("0327448e78ffa8cdb24cf19be0204ad954b1bdb4db8c51183534c1eecf2ebd094e"),
+        "P-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5",
+    );
+}
+
+#[test]
+fn test_avalanche_address_normalization() {
+    test_address_normalization(
+        CoinType::AvalancheXChain,
+        "X-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5",
+        "X-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5",
+    );
+}
+
+#[test]
+fn test_avalanche_address_is_valid() {
+    test_address_valid(
+        CoinType::AvalancheXChain,
+        "X-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5",
+    );
+    test_address_valid(
+        CoinType::AvalanchePChain,
+        "P-avax1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0vej24u",
+    );
+}
+
+#[test]
+fn test_avalanche_address_invalid() {
+    test_address_invalid(CoinType::AvalancheXChain, "");
+    // No chain alias.
+    test_address_invalid(
+        CoinType::AvalancheXChain,
+        "avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5",
+    );
+    // Address of another chain.
+    test_address_invalid(
+        CoinType::AvalancheXChain,
+        "P-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5",
+    );
+    test_address_invalid(
+        CoinType::AvalanchePChain,
+        "C-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5",
+    );
+    // Fuji testnet address.
+    test_address_invalid(
+        CoinType::AvalancheXChain,
+        "X-fuji18jma8ppw3nhx5r4ap8clazz0dps7rv5u6wmu4t",
+    );
+    // Invalid checksum.
+    test_address_invalid(
+        CoinType::AvalancheXChain,
+        "X-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre6",
+    );
+}
+
+#[test]
+fn test_avalanche_address_create_with_fuji_hrp() {
+    test_address_create_bech32_with_public_key(AddressCreateBech32WithPublicKey {
+        coin: CoinType::AvalancheXChain,
+        private_key: "56289e99c94b6912bfc12adc093c9b51124f0dc54ac7a766b2bc5ccf558d8027",
+        public_key_type: PublicKeyType::Secp256k1,
+        hrp: "fuji",
+        expected: "X-fuji18jma8ppw3nhx5r4ap8clazz0dps7rv5u6wmu4t",
+    });
+    test_address_bech32_is_valid(AddressBech32IsValid {
+        coin: CoinType::AvalanchePChain,
+        address: "P-fuji1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0qtk4er",
+        hrp: "fuji",
+    });
+}
+
+#[test]
+fn test_avalanche_address_get_data() {
+    test_address_get_data(
+        CoinType::AvalancheXChain,
+        "X-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5",
+        "3cb7d3842e8cee6a0ebd09f1fe884f6861e1b29c",
+    );
+}
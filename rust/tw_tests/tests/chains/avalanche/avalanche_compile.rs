@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::avalanche::{export_to_p_chain_input, PRIVATE_KEY};
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::ToHex;
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::Avalanche::Proto;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+#[test]
+fn test_avalanche_compile_x_chain_export_tx() {
+    let private_key = secp256k1::PrivateKey::try_from(PRIVATE_KEY).unwrap();
+    let public_key = private_key.public();
+
+    let input = export_to_p_chain_input();
+
+    // Step 2: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::AvalancheXChain, &input);
+
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(
+        preimage_output.data.to_hex(),
+        "00000000000400000001ed5f38341e436e5d46e2bb00b45d62ae97d1b050c64bc634ae10626739e35c4b0000000121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000070000000005e69ec0000000000000000000000001000000013cb7d3842e8cee6a0ebd09f1fe884f6861e1b29c00000001f1e1d1c1b1a191817161514131211101f0e0d0c0b0a0908070605040302010000000000021e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff00000005000000003b9aca0000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000070000000035a4e900000000000000000000000001000000013cb7d3842e8cee6a0ebd09f1fe884f6861e1b29c"
+    );
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "af79e39eb67830d1acc1341a04a4f9df177222296268e73f8c488f3588158a02"
+    );
+
+    // Step 3: Compile transaction info
+
+    // Simulate signature, normally obtained from signature server.
+    let data_hash = H256::try_from(preimage_output.data_hash.as_ref()).unwrap();
+    let signature = private_key.sign(data_hash).unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::AvalancheXChain,
+        &input,
+        vec![signature.to_vec()],
+        vec![public_key.compressed().to_vec()],
+    );
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.encoded.to_hex(),
+        "00000000000400000001ed5f38341e436e5d46e2bb00b45d62ae97d1b050c64bc634ae10626739e35c4b0000000121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000070000000005e69ec0000000000000000000000001000000013cb7d3842e8cee6a0ebd09f1fe884f6861e1b29c00000001f1e1d1c1b1a191817161514131211101f0e0d0c0b0a0908070605040302010000000000021e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff00000005000000003b9aca0000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000070000000035a4e900000000000000000000000001000000013cb7d3842e8cee6a0ebd09f1fe884f6861e1b29c000000010000000900000001775e7afc18d0d9cd0807b9db626510513c383227067ad3be53184ccf6bbf44a95b403853c482becfeb9ff72ad3ce76ce83d5ab41c0c53af6487994806581bd3c00"
+    );
+    assert_eq!(
+        output.transaction_id,
+        "23h74f3pCeYBXuMBJnoZDV1hTjw56uibevYxFXvgUacCrQ7PAW"
+    );
+}
+
+#[test]
+fn test_avalanche_compile_error_missing_signature() {
+    let input = export_to_p_chain_input();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::AvalancheXChain, &input, vec![], vec![]);
+
+    assert_eq!(output.error, SigningError::Error_signatures_count);
+}
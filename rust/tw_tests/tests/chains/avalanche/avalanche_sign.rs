@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::avalanche::{
+    avax_output, export_to_p_chain_input, utxo, AVAX_ASSET_ID, FUJI_ID, MAINNET_ID, PRIVATE_KEY,
+    P_ADDRESS, P_CHAIN_ID, X_ADDRESS, X_CHAIN_ID,
+};
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Avalanche::Proto;
+use tw_proto::Avalanche::Proto::mod_SigningInput::OneOftransaction as TransactionType;
+use tw_proto::Common::Proto::SigningError;
+
+const RECIPIENT_PRIVATE_KEY: &str =
+    "4646464646464646464646464646464646464646464646464646464646464646";
+const RECIPIENT_X_ADDRESS: &str = "X-avax1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0vej24u";
+
+/// Sends 5 AVAX spending two UTXOs of the same owner, so both credentials contain the same signature.
+fn base_tx_input() -> Proto::SigningInput<'static> {
+    let tx = Proto::BaseTx {
+        inputs: vec![
+            utxo(
+                "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+                1,
+                400_000_000,
+            ),
+            utxo(
+                "f1e1d1c1b1a191817161514131211101f0e0d0c0b0a090807060504030201000",
+                0,
+                600_000_000,
+            ),
+        ],
+        outputs: vec![
+            avax_output(500_000_000, RECIPIENT_X_ADDRESS),
+            avax_output(499_000_000, X_ADDRESS),
+        ],
+        memo: b"wallet-core".to_vec().into(),
+    };
+
+    Proto::SigningInput {
+        private_keys: vec![PRIVATE_KEY.decode_hex().unwrap().into()],
+        network_id: MAINNET_ID,
+        blockchain_id: X_CHAIN_ID.decode_hex().unwrap().into(),
+        transaction: TransactionType::base_tx(tx),
+    }
+}
+
+#[test]
+fn test_avalanche_sign_x_chain_base_tx() {
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::AvalancheXChain, base_tx_input());
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    // Inputs and outputs are sorted canonically regardless of the order they are provided in.
+    assert_eq!(
+        output.encoded.to_hex(),
+        "00000000000000000001ed5f38341e436e5d46e2bb00b45d62ae97d1b050c64bc634ae10626739e35c4b0000000221e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff00000007000000001dbe22c0000000000000000000000001000000013cb7d3842e8cee6a0ebd09f1fe884f6861e1b29c21e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff00000007000000001dcd650000000000000000000000000100000001bd92088bb7e82d611a9b94fbb74a0908152b784f000000020102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f200000000121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000050000000017d784000000000100000000f1e1d1c1b1a191817161514131211101f0e0d0c0b0a0908070605040302010000000000021e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000050000000023c3460000000001000000000000000b77616c6c65742d636f72650000000200000009000000013302c013aa4f3c96318d3cd2a78da457a36d1b57fd3f038591bdbc635c92c7f25de5eb28c553ad1ca43e8130eed7f8db569245ed628b25048c79f5584f44a3920100000009000000013302c013aa4f3c96318d3cd2a78da457a36d1b57fd3f038591bdbc635c92c7f25de5eb28c553ad1ca43e8130eed7f8db569245ed628b25048c79f5584f44a39201"
+    );
+    assert_eq!(
+        output.transaction_id,
+        "2X5PpYu4NkUuub2vnRiMstzNZuTWe2V7Ct7uxAV6NjSJbZHjgf"
+    );
+}
+
+#[test]
+fn test_avalanche_sign_x_chain_export_tx() {
+    let mut input = export_to_p_chain_input();
+    input.private_keys = vec![PRIVATE_KEY.decode_hex().unwrap().into()];
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::AvalancheXChain, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.encoded.to_hex(),
+        "00000000000400000001ed5f38341e436e5d46e2bb00b45d62ae97d1b050c64bc634ae10626739e35c4b0000000121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000070000000005e69ec0000000000000000000000001000000013cb7d3842e8cee6a0ebd09f1fe884f6861e1b29c00000001f1e1d1c1b1a191817161514131211101f0e0d0c0b0a0908070605040302010000000000021e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff00000005000000003b9aca0000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000070000000035a4e900000000000000000000000001000000013cb7d3842e8cee6a0ebd09f1fe884f6861e1b29c000000010000000900000001775e7afc18d0d9cd0807b9db626510513c383227067ad3be53184ccf6bbf44a95b403853c482becfeb9ff72ad3ce76ce83d5ab41c0c53af6487994806581bd3c00"
+    );
+    assert_eq!(
+        output.transaction_id,
+        "23h74f3pCeYBXuMBJnoZDV1hTjw56uibevYxFXvgUacCrQ7PAW"
+    );
+}
+
+#[test]
+fn test_avalanche_sign_p_chain_import_tx() {
+    // Imports the output exported in `test_avalanche_sign_x_chain_export_tx`.
+    let import = Proto::ImportTx {
+        base: Some(Proto::BaseTx {
+            outputs: vec![avax_output(899_000_000, P_ADDRESS)],
+            ..Proto::BaseTx::default()
+        }),
+        source_chain: X_CHAIN_ID.decode_hex().unwrap().into(),
+        imported_inputs: vec![utxo(
+            "89ceb060fadd767b9d2c24eaf2a521a189ef80fa28b72d137ea9c29dc09bdd13",
+            1,
+            900_000_000,
+        )],
+    };
+    let input = Proto::SigningInput {
+        private_keys: vec![PRIVATE_KEY.decode_hex().unwrap().into()],
+        network_id: MAINNET_ID,
+        blockchain_id: P_CHAIN_ID.decode_hex().unwrap().into(),
+        transaction: TransactionType::import_tx(import),
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::AvalanchePChain, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.encoded.to_hex(),
+        "0000000000110000000100000000000000000000000000000000000000000000000000000000000000000000000121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff00000007000000003595a6c0000000000000000000000001000000013cb7d3842e8cee6a0ebd09f1fe884f6861e1b29c0000000000000000ed5f38341e436e5d46e2bb00b45d62ae97d1b050c64bc634ae10626739e35c4b0000000189ceb060fadd767b9d2c24eaf2a521a189ef80fa28b72d137ea9c29dc09bdd130000000121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000050000000035a4e90000000001000000000000000100000009000000014c22fe6eea0815e2be7d8f69e0273753f3993db874a331d57c07eacbed69a7252d25b69abe549055fd5f682490b6c5bc6e9142b240f8618eb7ad3db6a933396000"
+    );
+    assert_eq!(
+        output.transaction_id,
+        "2jPRWQ2U6pwmvgA9S4y6gFjn6ef9MYRJ1yewaLgZ54xkXPk1St"
+    );
+}
+
+#[test]
+fn test_avalanche_sign_p_chain_multisig_output() {
+    // The UTXO is owned by two addresses, and only the second one is required to spend it.
+    let tx = Proto::BaseTx {
+        inputs: vec![Proto::TransferableInput {
+            tx_id: "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"
+                .decode_hex()
+                .unwrap()
+                .into(),
+            output_index: 0,
+            asset_id: AVAX_ASSET_ID.decode_hex().unwrap().into(),
+            amount: 301_000_000,
+            owners: vec![
+                "P-fuji1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0qtk4er".into(),
+                "P-fuji18jma8ppw3nhx5r4ap8clazz0dps7rv5u6wmu4t".into(),
+            ],
+            address_indices: vec![1],
+        }],
+        outputs: vec![Proto::TransferableOutput {
+            asset_id: AVAX_ASSET_ID.decode_hex().unwrap().into(),
+            amount: 300_000_000,
+            locktime: 1_800_000_000,
+            threshold: 2,
+            addresses: vec![
+                "P-fuji18jma8ppw3nhx5r4ap8clazz0dps7rv5u6wmu4t".into(),
+                "P-fuji1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0qtk4er".into(),
+            ],
+        }],
+        ..Proto::BaseTx::default()
+    };
+    let input = Proto::SigningInput {
+        private_keys: vec![
+            PRIVATE_KEY.decode_hex().unwrap().into(),
+            RECIPIENT_PRIVATE_KEY.decode_hex().unwrap().into(),
+        ],
+        network_id: FUJI_ID,
+        blockchain_id: P_CHAIN_ID.decode_hex().unwrap().into(),
+        transaction: TransactionType::base_tx(tx),
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::AvalanchePChain, input);
+
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(
+        output.encoded.to_hex(),
+        "0000000000220000000500000000000000000000000000000000000000000000000000000000000000000000000121e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000070000000011e1a300000000006b49d20000000002000000023cb7d3842e8cee6a0ebd09f1fe884f6861e1b29cbd92088bb7e82d611a9b94fbb74a0908152b784f000000010102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f200000000021e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff000000050000000011f0e540000000010000000100000000000000010000000900000001f45b039dde16adfa34a74950b190ed1b7acef0bc85ff1a8db0a240713e4415193106eb396abb9ca118e0ed339a1db642ecdeada5802c9857c8e7cd9873a7b68b00"
+    );
+    assert_eq!(
+        output.transaction_id,
+        "2MjuG9Kr6pD8h62b4PHNpWKdpLZtAM7Z8bL4Y1JfK2P7RFzRhH"
+    );
+}
+
+#[test]
+fn test_avalanche_sign_error_missing_private_key() {
+    let mut input = base_tx_input();
+    input.private_keys = vec![RECIPIENT_PRIVATE_KEY.decode_hex().unwrap().into()];
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::AvalancheXChain, input);
+
+    assert_eq!(output.error, SigningError::Error_missing_private_key);
+}
+
+#[test]
+fn test_avalanche_sign_error_not_enough_utxos() {
+    let mut input = base_tx_input();
+    let TransactionType::base_tx(ref mut tx) = input.transaction else {
+        unreachable!()
+    };
+    tx.outputs.push(avax_output(2_000_000, X_ADDRESS));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::AvalancheXChain, input);
+
+    assert_eq!(output.error, SigningError::Error_not_enough_utxos);
+}
+
+#[test]
+fn test_avalanche_sign_error_invalid_blockchain_id() {
+    let mut input = base_tx_input();
+    input.blockchain_id = "ed5f38341e436e5d46e2bb00b45d62ae"
+        .decode_hex()
+        .unwrap()
+        .into();
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::AvalancheXChain, input);
+
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_avalanche_sign_error_base_tx_address_of_another_chain() {
+    for address in [
+        "P-fuji1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0qtk4er",
+        "P-avax1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0vej24u",
+        "X-fuji1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0qtk4er",
+    ] {
+        let mut input = base_tx_input();
+        let TransactionType::base_tx(ref mut tx) = input.transaction else {
+            unreachable!()
+        };
+        tx.outputs[0] = avax_output(500_000_000, address);
+
+        let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+        let output = signer.sign(CoinType::AvalancheXChain, input);
+        assert_eq!(
+            output.error,
+            SigningError::Error_invalid_address,
+            "{address}"
+        );
+    }
+}
+
+#[test]
+fn test_avalanche_sign_error_export_tx_address_of_another_network() {
+    let mut input = export_to_p_chain_input();
+    input.private_keys = vec![PRIVATE_KEY.decode_hex().unwrap().into()];
+    let TransactionType::export_tx(ref mut tx) = input.transaction else {
+        unreachable!()
+    };
+    tx.exported_outputs[0] =
+        avax_output(900_000_000, "P-fuji18jma8ppw3nhx5r4ap8clazz0dps7rv5u6wmu4t");
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::AvalancheXChain, input);
+    assert_eq!(output.error, SigningError::Error_invalid_address);
+}
+
+#[test]
+fn test_avalanche_sign_error_import_tx_address_of_another_network() {
+    let mut imported = utxo(
+        "89ceb060fadd767b9d2c24eaf2a521a189ef80fa28b72d137ea9c29dc09bdd13",
+        1,
+        900_000_000,
+    );
+    imported.owners = vec!["X-fuji18jma8ppw3nhx5r4ap8clazz0dps7rv5u6wmu4t".into()];
+    let import = Proto::ImportTx {
+        base: Some(Proto::BaseTx {
+            outputs: vec![avax_output(899_000_000, P_ADDRESS)],
+            ..Proto::BaseTx::default()
+        }),
+        source_chain: X_CHAIN_ID.decode_hex().unwrap().into(),
+        imported_inputs: vec![imported],
+    };
+    let input = Proto::SigningInput {
+        private_keys: vec![PRIVATE_KEY.decode_hex().unwrap().into()],
+        network_id: MAINNET_ID,
+        blockchain_id: P_CHAIN_ID.decode_hex().unwrap().into(),
+        transaction: TransactionType::import_tx(import),
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::AvalanchePChain, input);
+    assert_eq!(output.error, SigningError::Error_invalid_address);
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_encoding::hex::DecodeHex;
+use tw_proto::Avalanche::Proto;
+
+mod avalanche_address;
+mod avalanche_compile;
+mod avalanche_sign;
+
+/// X-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5
+const PRIVATE_KEY: &str = "56289e99c94b6912bfc12adc093c9b51124f0dc54ac7a766b2bc5ccf558d8027";
+const X_ADDRESS: &str = "X-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5";
+const P_ADDRESS: &str = "P-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5";
+
+/// 2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM
+const X_CHAIN_ID: &str = "ed5f38341e436e5d46e2bb00b45d62ae97d1b050c64bc634ae10626739e35c4b";
+/// 11111111111111111111111111111111LpoYY
+const P_CHAIN_ID: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+/// FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z
+const AVAX_ASSET_ID: &str = "21e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff";
+
+const MAINNET_ID: u32 = 1;
+const FUJI_ID: u32 = 5;
+
+fn utxo(tx_id: &str, output_index: u32, amount: u64) -> Proto::TransferableInput<'static> {
+    Proto::TransferableInput {
+        tx_id: tx_id.decode_hex().unwrap().into(),
+        output_index,
+        asset_id: AVAX_ASSET_ID.decode_hex().unwrap().into(),
+        amount,
+        owners: vec![X_ADDRESS.into()],
+        ..Proto::TransferableInput::default()
+    }
+}
+
+fn avax_output(amount: u64, address: &str) -> Proto::TransferableOutput<'static> {
+    Proto::TransferableOutput {
+        asset_id: AVAX_ASSET_ID.decode_hex().unwrap().into(),
+        amount,
+        addresses: vec![address.to_string().into()],
+        ..Proto::TransferableOutput::default()
+    }
+}
+
+/// Exports 9 AVAX from X-Chain to P-Chain for staking, and returns 0.099 AVAX as the change.
+fn export_to_p_chain_input() -> Proto::SigningInput<'static> {
+    let export = Proto::ExportTx {
+        base: Some(Proto::BaseTx {
+            inputs: vec![utxo(
+                "f1e1d1c1b1a191817161514131211101f0e0d0c0b0a090807060504030201000",
+                0,
+                1_000_000_000,
+            )],
+            outputs: vec![avax_output(99_000_000, X_ADDRESS)],
+            ..Proto::BaseTx::default()
+        }),
+        destination_chain: P_CHAIN_ID.decode_hex().unwrap().into(),
+        exported_outputs: vec![avax_output(900_000_000, P_ADDRESS)],
+    };
+
+    Proto::SigningInput {
+        network_id: MAINNET_ID,
+        blockchain_id: X_CHAIN_ID.decode_hex().unwrap().into(),
+        transaction: Proto::mod_SigningInput::OneOftransaction::export_tx(export),
+        ..Proto::SigningInput::default()
+    }
+}
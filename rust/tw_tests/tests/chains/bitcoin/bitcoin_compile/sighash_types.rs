@@ -0,0 +1,403 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{
+    btc_info, dust_threshold, input, output, preimage, TransactionOneof, DUST, SIGHASH_ALL,
+    SIGHASH_ANYONE_CAN_PAY, SIGHASH_NONE, SIGHASH_SINGLE,
+};
+use tw_any_coin::test_utils::sign_utils::PreImageHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_keypair::{ecdsa, schnorr};
+use tw_misc::traits::ToBytesVec;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+const ALICE_PRIVATE_KEY: &str = "12ce558df23528f1aa86f1f51ac7e13a197a06bda27610fa89e13b04c40ee999";
+const BOB_PRIVATE_KEY: &str = "26c2566adcc030a1799213bfd546e615f6ab06f72085ec6806ff1761da48d227";
+
+fn p2tr_signing_input(sighash_type_0: u32, sighash_type_1: u32) -> Proto::SigningInput<'static> {
+    let alice_pubkey = schnorr::PrivateKey::try_from(ALICE_PRIVATE_KEY)
+        .unwrap()
+        .public();
+    let bob_pubkey = schnorr::PrivateKey::try_from(BOB_PRIVATE_KEY)
+        .unwrap()
+        .public();
+
+    let utxo_0 = Proto::Input {
+        out_point: input::out_point(
+            "c50563913e5a838f937c94232f5a8fc74e58b629fae41dfdffcc9a70f833b53a",
+            0,
+        ),
+        value: 100_000,
+        sighash_type: sighash_type_0,
+        claiming_script: input::p2tr_key_path(alice_pubkey.to_vec()),
+        ..Default::default()
+    };
+    let utxo_1 = Proto::Input {
+        out_point: input::out_point(
+            "9a582032f6a50cedaff77d3d5604b33adf8bc31bdaef8de977c2187e395860ac",
+            1,
+        ),
+        value: 50_000,
+        sighash_type: sighash_type_1,
+        claiming_script: input::p2tr_key_path(alice_pubkey.to_vec()),
+        ..Default::default()
+    };
+
+    let out_0 = Proto::Output {
+        value: 120_000,
+        to_recipient: output::p2tr_key_path(bob_pubkey.to_vec()),
+    };
+    let out_1 = Proto::Output {
+        value: 29_000,
+        to_recipient: output::p2tr_key_path(alice_pubkey.to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![utxo_0, utxo_1],
+        outputs: vec![out_0, out_1],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        fee_per_vb: 0,
+        ..Default::default()
+    };
+
+    Proto::SigningInput {
+        public_keys: vec![alice_pubkey.to_vec().into()],
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    }
+}
+
+/// A marketplace listing: the seller signs their UTXO together with the payment output only,
+/// so the buyer can add their inputs and outputs.
+#[test]
+fn test_bitcoin_preimage_p2tr_sighash_single_anyone_can_pay() {
+    let signing = p2tr_signing_input(SIGHASH_SINGLE | SIGHASH_ANYONE_CAN_PAY, SIGHASH_NONE);
+
+    let alice_tweaked = "a5c027857e359d19f625e52a106b8ac6ca2d6a8728f6cf2107cd7958ee0787c2"
+        .decode_hex()
+        .unwrap();
+    let sighash_0 = "130c6bcad0cfa0b7ff23dd1de0d1cd6dc3b988a53ad7afb7e2df3b0d2d05e4bd"
+        .decode_hex()
+        .unwrap();
+    let sighash_1 = "b44e0d7f7cca783ae910d192974567bc9495a5d3774e6f97341454d31f5a24bf"
+        .decode_hex()
+        .unwrap();
+
+    preimage::BitcoinPreImageHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .add_expected_taproot(alice_tweaked.clone(), sighash_0, Some(Vec::default()))
+        .add_expected_taproot(alice_tweaked, sighash_1, Some(Vec::default()))
+        .pre_image();
+}
+
+#[test]
+fn test_bitcoin_preimage_p2tr_sighash_per_input() {
+    // The sighash type of the first input must not affect the sighash of the second one.
+    let signing = p2tr_signing_input(SIGHASH_SINGLE | SIGHASH_ANYONE_CAN_PAY, SIGHASH_ALL);
+
+    let alice_tweaked = "a5c027857e359d19f625e52a106b8ac6ca2d6a8728f6cf2107cd7958ee0787c2"
+        .decode_hex()
+        .unwrap();
+    let sighash_0 = "130c6bcad0cfa0b7ff23dd1de0d1cd6dc3b988a53ad7afb7e2df3b0d2d05e4bd"
+        .decode_hex()
+        .unwrap();
+    let sighash_1 = "f9dfc74104edde41b011977cf7499ad5825eaf7dfb3bf035de3c48905c693970"
+        .decode_hex()
+        .unwrap();
+
+    preimage::BitcoinPreImageHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .add_expected_taproot(alice_tweaked.clone(), sighash_0, Some(Vec::default()))
+        .add_expected_taproot(alice_tweaked, sighash_1, Some(Vec::default()))
+        .pre_image();
+}
+
+#[test]
+fn test_bitcoin_preimage_p2tr_invalid_sighash_type() {
+    // `ANYONECANPAY` must be combined with `ALL`, `NONE` or `SINGLE`.
+    let signing = p2tr_signing_input(SIGHASH_ANYONE_CAN_PAY, SIGHASH_ALL);
+
+    let mut pre_imager = PreImageHelper::<Proto::PreSigningOutput>::default();
+    let output = pre_imager.pre_image_hashes(CoinType::Bitcoin, &signing);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_bitcoin_preimage_legacy_sighash_single_no_output() {
+    let alice_pubkey = ecdsa::secp256k1::PrivateKey::try_from(ALICE_PRIVATE_KEY)
+        .unwrap()
+        .public();
+
+    let utxo_0 = Proto::Input {
+        out_point: input::out_point(
+            "c50563913e5a838f937c94232f5a8fc74e58b629fae41dfdffcc9a70f833b53a",
+            0,
+        ),
+        value: 100_000,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2pkh(alice_pubkey.to_vec()),
+        ..Default::default()
+    };
+    let utxo_1 = Proto::Input {
+        out_point: input::out_point(
+            "9a582032f6a50cedaff77d3d5604b33adf8bc31bdaef8de977c2187e395860ac",
+            1,
+        ),
+        value: 50_000,
+        sighash_type: SIGHASH_SINGLE,
+        claiming_script: input::p2pkh(alice_pubkey.to_vec()),
+        ..Default::default()
+    };
+
+    let out_0 = Proto::Output {
+        value: 140_000,
+        to_recipient: output::p2pkh(alice_pubkey.to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![utxo_0, utxo_1],
+        outputs: vec![out_0],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        fee_per_vb: 0,
+        ..Default::default()
+    };
+
+    let signing = Proto::SigningInput {
+        public_keys: vec![alice_pubkey.to_vec().into()],
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    };
+
+    // There is no output with the same index as the second input.
+    let mut pre_imager = PreImageHelper::<Proto::PreSigningOutput>::default();
+    let output = pre_imager.pre_image_hashes(CoinType::Bitcoin, &signing);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+/// BIP-341 `keyPathSpending` test vector:
+/// https://github.com/bitcoin/bips/blob/master/bip-0341/wallet-test-vectors.json
+#[test]
+fn test_bitcoin_preimage_bip341_key_path_spending() {
+    // The P2PKH and P2WPKH inputs are not spent by the vector, the public keys are G and 3G.
+    const P2PKH_PUBKEY: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    const P2WPKH_PUBKEY: &str =
+        "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+
+    // (txid, vout, sequence, amount, sighash type, claiming script)
+    let utxos = [
+        (
+            "9c4e333b5f116359b5f5578fe4a74c6f58b3bab9d28149a583da86f6bf0ce27d",
+            1,
+            0,
+            420_000_000,
+            SIGHASH_SINGLE,
+            input::custom_script(
+                "512053a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343"
+                    .decode_hex()
+                    .unwrap(),
+            ),
+        ),
+        (
+            "99ddaf6d9b75447d5127e17312f6def68acba2d4f464d0e2ac93137bb5cab7d7",
+            0,
+            0xffffffff,
+            462_000_000,
+            SIGHASH_SINGLE | SIGHASH_ANYONE_CAN_PAY,
+            input::custom_script(
+                "5120147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3"
+                    .decode_hex()
+                    .unwrap(),
+            ),
+        ),
+        (
+            "4218a419542757d960174457dc82e06b3613ac8ed2c528926833433883f5e1f8",
+            0,
+            0xffffffff,
+            294_000_000,
+            SIGHASH_ALL,
+            input::p2pkh(P2PKH_PUBKEY.decode_hex().unwrap()),
+        ),
+        (
+            "3b8504d63a84a0fd1043e7ec832adaeeb7382a6d3ca762b10cb363aa809168f0",
+            1,
+            0xfffffffe,
+            504_000_000,
+            SIGHASH_ALL,
+            input::custom_script(
+                "5120e4d810fd50586274face62b8a807eb9719cef49c04177cc6b76a9a4251d5450e"
+                    .decode_hex()
+                    .unwrap(),
+            ),
+        ),
+        (
+            "6cbae03912ee525a3cfd5b5ea264921d46b7bbaf02020feed2ccd8f6bd0252aa",
+            0,
+            0xfffffffe,
+            630_000_000,
+            // SIGHASH_DEFAULT.
+            0,
+            input::custom_script(
+                "512091b64d5324723a985170e4dc5a0f84c041804f2cd12660fa5dec09fc21783605"
+                    .decode_hex()
+                    .unwrap(),
+            ),
+        ),
+        (
+            "50d0ac326d44a3a29358214139fecb8a7129aa2f2dbeb28e96aa6fc6bd496195",
+            0,
+            0,
+            378_000_000,
+            SIGHASH_ALL,
+            input::p2wpkh(P2WPKH_PUBKEY.decode_hex().unwrap()),
+        ),
+        (
+            "944c5f5d1dbb1b5348f8223bbab763ed0cdae4a3a270cb329cc0883b77b964e6",
+            1,
+            0,
+            672_000_000,
+            SIGHASH_NONE,
+            input::custom_script(
+                "512075169f4001aa68f15bbed28b218df1d0a62cbbcf1188c6665110c293c907b831"
+                    .decode_hex()
+                    .unwrap(),
+            ),
+        ),
+        (
+            "bfead4dfeaf74ea732a677b64b697bbb9656e24a92a3e61976e69d6c8e6baae9",
+            0,
+            0xffffffff,
+            546_000_000,
+            SIGHASH_NONE | SIGHASH_ANYONE_CAN_PAY,
+            input::custom_script(
+                "5120712447206d7a5238acc7ff53fbe94a3b64539ad291c7cdbc490b7577e4b17df5"
+                    .decode_hex()
+                    .unwrap(),
+            ),
+        ),
+        (
+            "f12ab8a18a051d836804111c0b726796a9b566c425d14c4690c03d266aeb78a7",
+            1,
+            0xffffffff,
+            588_000_000,
+            SIGHASH_ALL | SIGHASH_ANYONE_CAN_PAY,
+            input::custom_script(
+                "512077e30a5522dd9f894c3f8b8bd4c4b2cf82ca7da8a3ea6a239655c39c050ab220"
+                    .decode_hex()
+                    .unwrap(),
+            ),
+        ),
+    ];
+    let inputs = utxos
+        .into_iter()
+        .map(
+            |(txid, vout, sequence, value, sighash_type, claiming_script)| Proto::Input {
+                out_point: input::out_point(txid, vout),
+                value,
+                sighash_type,
+                sequence: input::sequence(sequence),
+                claiming_script,
+            },
+        )
+        .collect();
+
+    let outputs = vec![
+        Proto::Output {
+            value: 1_000_000_000,
+            to_recipient: output::custom_script(
+                "76a91406afd46bcdfd22ef94ac122aa11f241244a37ecc88ac"
+                    .decode_hex()
+                    .unwrap(),
+            ),
+        },
+        Proto::Output {
+            value: 3_410_000_000,
+            to_recipient: output::custom_script(
+                "ac9a87f5594be208f8532db38cff670c450ed2fea8fcdefcc9a663f78bab962b"
+                    .decode_hex()
+                    .unwrap(),
+            ),
+        },
+    ];
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        lock_time: 500_000_000,
+        inputs,
+        outputs,
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        fee_per_vb: 0,
+        ..Default::default()
+    };
+    let signing = Proto::SigningInput {
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    };
+
+    let taproot = |tweaked_pubkey: &str, sighash: &str| {
+        (
+            tweaked_pubkey.decode_hex().unwrap(),
+            sighash.decode_hex().unwrap(),
+        )
+    };
+    // `inputSpending[].intermediary.sigHash` of the vector.
+    let (pubkey_0, sighash_0) = taproot(
+        "53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343",
+        "2514a6272f85cfa0f45eb907fcb0d121b808ed37c6ea160a5a9046ed5526d555",
+    );
+    let (pubkey_1, sighash_1) = taproot(
+        "147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3",
+        "325a644af47e8a5a2591cda0ab0723978537318f10e6a63d4eed783b96a71a4d",
+    );
+    let (pubkey_3, sighash_3) = taproot(
+        "e4d810fd50586274face62b8a807eb9719cef49c04177cc6b76a9a4251d5450e",
+        "bf013ea93474aa67815b1b6cc441d23b64fa310911d991e713cd34c7f5d46669",
+    );
+    let (pubkey_4, sighash_4) = taproot(
+        "91b64d5324723a985170e4dc5a0f84c041804f2cd12660fa5dec09fc21783605",
+        "4f900a0bae3f1446fd48490c2958b5a023228f01661cda3496a11da502a7f7ef",
+    );
+    let (pubkey_6, sighash_6) = taproot(
+        "75169f4001aa68f15bbed28b218df1d0a62cbbcf1188c6665110c293c907b831",
+        "15f25c298eb5cdc7eb1d638dd2d45c97c4c59dcaec6679cfc16ad84f30876b85",
+    );
+    let (pubkey_7, sighash_7) = taproot(
+        "712447206d7a5238acc7ff53fbe94a3b64539ad291c7cdbc490b7577e4b17df5",
+        "cd292de50313804dabe4685e83f923d2969577191a3e1d2882220dca88cbeb10",
+    );
+    let (pubkey_8, sighash_8) = taproot(
+        "77e30a5522dd9f894c3f8b8bd4c4b2cf82ca7da8a3ea6a239655c39c050ab220",
+        "cccb739eca6c13a8a89e6e5cd317ffe55669bbda23f2fd37b0f18755e008edd2",
+    );
+    // The legacy and segwit sighashes are not part of the vector.
+    let sighash_2 = "52f5ef12203616d2fb6cc7ea1d813285a6f35952647b96282f30073c4fc1bee7"
+        .decode_hex()
+        .unwrap();
+    let sighash_5 = "ccfa74e43b94adb0e717991c494279170e670afa0a69d0dff4ec06ec19acee7e"
+        .decode_hex()
+        .unwrap();
+
+    let key_path = Some(Vec::default());
+    preimage::BitcoinPreImageHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .add_expected_taproot(pubkey_0, sighash_0, key_path.clone())
+        .add_expected_taproot(pubkey_1, sighash_1, key_path.clone())
+        .add_expected_legacy(P2PKH_PUBKEY.decode_hex().unwrap(), sighash_2)
+        .add_expected_taproot(pubkey_3, sighash_3, key_path.clone())
+        .add_expected_taproot(pubkey_4, sighash_4, key_path.clone())
+        .add_expected_segwit(P2WPKH_PUBKEY.decode_hex().unwrap(), sighash_5)
+        .add_expected_taproot(pubkey_6, sighash_6, key_path.clone())
+        .add_expected_taproot(pubkey_7, sighash_7, key_path.clone())
+        .add_expected_taproot(pubkey_8, sighash_8, key_path)
+        .pre_image();
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+/// BIP-322 test vector key `L3VFeEujGtevx9w18HD1fhRbCH67Az2dpCymeRE1SoPK6XQtaN2k`.
+/// Its address is `bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l`.
+const PRIVATE_KEY: &str = "bb051cd0dda0246f33c5a9e133ebd8e7bc02a92af6c41adc131ccd7826c5b004";
+const PUBLIC_KEY: &str = "02c7f12003196442943d8588e01aee840423cc54fc1521526a3b85c2b0cbd58872";
+
+fn sign_message(message: &str) -> String {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: message.into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(CoinType::Bitcoin as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    output.signature.to_string()
+}
+
+fn verify_message(message: &str, signature: &str) -> bool {
+    let input = Proto::MessageVerifyingInput {
+        message: message.into(),
+        public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: signature.into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify(CoinType::Bitcoin as u32, input_data.ptr()) }
+}
+
+#[test]
+fn test_bitcoin_message_sign_bip322() {
+    // The signatures differ from the BIP-322 vectors since Bitcoin Core grinds for a low `R`.
+    let empty = sign_message("");
+    assert_eq!(empty, "AkgwRQIhAPkJ1Q4oYS0htvyuSFHLxRQpFAY56b70UvE7Dxazen0ZAiAtZfFz1S6T6I23MWI2lK/pcNTWncuyL8UL+oMdydVgzAEhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1Yhy");
+    assert!(verify_message("", &empty));
+
+    let hello = sign_message("Hello World");
+    assert_eq!(hello, "AkgwRQIhAOzyynlqt93lOKJr+wmmxIens//zPzl9tqIOua93wO6MAiBi5n5EyAcPScOjf1lAqIUIQtr3zKNeavYabHyR8eGhowEhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1Yhy");
+    assert!(verify_message("Hello World", &hello));
+}
+
+#[test]
+fn test_bitcoin_message_pre_image_hashes_bip322() {
+    let input = Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: "Hello World".into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(CoinType::Bitcoin as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "af8a0cd31d9b0976e2aab2b82974c4388c4a3532b2ef828b96f14039ca372c14"
+    );
+}
+
+#[test]
+fn test_bitcoin_message_verify_bip322() {
+    // https://github.com/bitcoin/bips/blob/master/bip-0322.mediawiki#test-vectors
+    let signature = "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=";
+    assert!(verify_message("Hello World", signature));
+    assert!(!verify_message("", signature));
+    assert!(!verify_message("Hello World", "AkcwRAIg"));
+}
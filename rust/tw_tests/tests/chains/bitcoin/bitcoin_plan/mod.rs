@@ -7,3 +7,4 @@ mod plan_exact_error;
 mod plan_max;
 mod plan_max_error;
 mod plan_psbt;
+mod plan_transaction_planner;
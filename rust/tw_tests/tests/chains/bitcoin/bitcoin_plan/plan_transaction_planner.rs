@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{plan, DUST};
+use tw_any_coin::test_utils::transaction_planner_utils::TransactionPlannerHelper;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::TransactionPlanner::Proto as PlannerProto;
+
+#[test]
+fn test_transaction_planner_exact_with_change() {
+    let input = plan::make_planning_input(plan::PlanArgs {
+        inputs: vec![1_000, 3_000, 4_000],
+        outputs: vec![1_000],
+        change: true,
+        max: false,
+        dust_threshold: DUST,
+        order: Proto::InputSelector::SelectAscending,
+        fee_per_vb: 2,
+    });
+
+    let mut planner = TransactionPlannerHelper::default();
+    let output = planner.plan(CoinType::Bitcoin, &input);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.amount, "1000");
+    // vsize * fee_rate
+    assert_eq!(output.fee, "752");
+    assert_eq!(output.change, "2248");
+    // All UTXOs minus the fee of a transaction with 3 inputs and 1 output.
+    assert_eq!(output.max_amount, "7018");
+    assert_eq!(
+        output.fee_rate,
+        Some(PlannerProto::FeeRate {
+            unit: PlannerProto::FeeUnit::PerVirtualByte,
+            units: 376,
+            amount_per_unit: "2".into(),
+        })
+    );
+    assert!(output.warnings.is_empty());
+}
+
+#[test]
+fn test_transaction_planner_max() {
+    let input = plan::make_planning_input(plan::PlanArgs {
+        inputs: vec![1_000, 3_000, 4_000],
+        max: true,
+        dust_threshold: DUST,
+        fee_per_vb: 2,
+        ..plan::PlanArgs::default()
+    });
+
+    let mut planner = TransactionPlannerHelper::default();
+    let output = planner.plan(CoinType::Bitcoin, &input);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.amount, "7018");
+    assert_eq!(output.fee, "982");
+    assert_eq!(output.change, "0");
+    assert_eq!(output.max_amount, "7018");
+    assert_eq!(output.fee_rate.unwrap().units, 491);
+}
+
+#[test]
+fn test_transaction_planner_dust_warnings() {
+    let input = plan::make_planning_input(plan::PlanArgs {
+        inputs: vec![4_000, 500],
+        outputs: vec![3_100],
+        change: true,
+        max: false,
+        dust_threshold: DUST,
+        order: Proto::InputSelector::SelectAscending,
+        fee_per_vb: 2,
+    });
+
+    let mut planner = TransactionPlannerHelper::default();
+    let output = planner.plan(CoinType::Bitcoin, &input);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.amount, "3100");
+    // The change 446 is dust, and it's added to the fee: 900 = 227 * 2 + 446.
+    assert_eq!(output.fee, "900");
+    assert_eq!(output.change, "0");
+    assert_eq!(output.max_amount, "3614");
+    assert_eq!(output.fee_rate.unwrap().amount_per_unit, "3");
+    assert_eq!(
+        output.warnings,
+        vec![
+            PlannerProto::PlanWarning::DustChangeAddedToFee,
+            PlannerProto::PlanWarning::DustInputsSkipped
+        ]
+    );
+}
+
+#[test]
+fn test_transaction_planner_insufficient_funds() {
+    let input = plan::make_planning_input(plan::PlanArgs {
+        inputs: vec![1_000, 3_000],
+        outputs: vec![5_000],
+        change: true,
+        max: false,
+        dust_threshold: DUST,
+        order: Proto::InputSelector::SelectAscending,
+        fee_per_vb: 2,
+    });
+
+    let mut planner = TransactionPlannerHelper::default();
+    let output = planner.plan(CoinType::Bitcoin, &input);
+    assert_eq!(output.error, SigningErrorType::Error_not_enough_utxos);
+}
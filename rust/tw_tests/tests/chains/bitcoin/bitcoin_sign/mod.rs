@@ -13,5 +13,6 @@ mod p2tr_script_path;
 mod p2wpkh;
 mod p2wsh;
 mod psbt;
+mod runes;
 mod send_to_address;
 mod sighash_single;
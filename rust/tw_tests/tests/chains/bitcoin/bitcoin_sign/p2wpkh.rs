@@ -88,3 +88,67 @@ fn test_bitcoin_sign_p2wpkh_input_different_builders() {
             fee: 7_097,
         });
 }
+
+#[test]
+fn test_bitcoin_sign_p2wpkh_dummy_signatures() {
+    let my_public_key = "03a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c"
+        .decode_hex()
+        .unwrap();
+
+    let utxo_hash_0 = "b33082a5fad105c1d9712e8d503971fe4d84713065bd323fd1019636ed940e8d";
+    let utxo_0 = Proto::Input {
+        out_point: input::out_point(utxo_hash_0, 1),
+        value: 30_269,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2wpkh(my_public_key.clone()),
+        ..Default::default()
+    };
+
+    let utxo_hash_1 = "1f62c18bfc5f8293a2b7b061587c427bf830fb224289f9a806e6ad48de6a4c7d";
+    let utxo_1 = Proto::Input {
+        out_point: input::out_point(utxo_hash_1, 1),
+        value: 4_863,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2wpkh(my_public_key.clone()),
+        ..Default::default()
+    };
+
+    let out_0 = Proto::Output {
+        value: 28_035,
+        to_recipient: output::to_address("bc1q2dsdlq3343vk29runkgv4yc292hmq53jedfjmp"),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V1,
+        inputs: vec![utxo_0, utxo_1],
+        outputs: vec![out_0],
+        change_output: None,
+        input_selector: Proto::InputSelector::SelectAscending,
+        dust_policy: dust_threshold(DUST),
+        fee_per_vb: 33,
+        ..Default::default()
+    };
+
+    let signing = Proto::SigningInput {
+        // No private keys are required.
+        public_keys: vec![my_public_key.into()],
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        dummy_signatures: true,
+        ..Default::default()
+    };
+
+    // The same transaction as in `test_bitcoin_sign_p2wpkh_input_different_builders`,
+    // but the signatures are replaced with the placeholders of the same size.
+    sign::BitcoinSignHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .sign(sign::Expected {
+            encoded: "010000000001027d4c6ade48ade606a8f9894222fb30f87b427c5861b0b7a293825ffc8bc1621f0100000000ffffffff8d0e94ed369601d13f32bd653071844dfe7139508d2e71d9c105d1faa58230b30100000000ffffffff01836d0000000000001600145360df8231ac5965147c9d90ca930a2aafb052320248ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff012103a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c0248ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff012103a11506993946e20ea82686b157bf08f944759f43d91af8d84650ee73a482431c00000000",
+            txid: "5d6bf53576a54be4d92cd8abf58d28ecc9ea7956eaf970d24d6bfcb9fcfe9855",
+            inputs: vec![4_863, 30_269],
+            outputs: vec![28_035],
+            vsize: 178,
+            weight: 710,
+            fee: 7_097,
+        });
+}
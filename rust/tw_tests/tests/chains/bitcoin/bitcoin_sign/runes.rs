@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{
+    btc_info, dust_threshold, input, output, sign, TransactionOneof, DUST, SIGHASH_ALL,
+};
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_keypair::ecdsa;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto::SigningError;
+
+const ALICE_PRIVATE_KEY: &str = "e253373989199da27c48680e3a3fc0f648d50f9a727ef17a7fe6a4dc3b159129";
+const BOB_ADDRESS: &str = "bc1qazgc2zhu2kmy42py0vs8d7yff67l3zgpwfzlpk";
+
+fn signing_input(
+    runestone: Proto::mod_Output::OutputRunestone<'static>,
+) -> Proto::SigningInput<'static> {
+    let alice_private_key = ecdsa::secp256k1::PrivateKey::try_from(ALICE_PRIVATE_KEY).unwrap();
+    let alice_pubkey = alice_private_key.public().compressed();
+
+    let txid = "7046dc2689a27e143ea2ad1039710885147e9485ab6453fa7e87464aa7dd3eca";
+    let tx1 = Proto::Input {
+        out_point: input::out_point(txid, 1),
+        value: 10_000,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2wpkh(alice_pubkey.to_vec()),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: 0,
+        to_recipient: output::runestone(runestone),
+    };
+    let out2 = Proto::Output {
+        value: DUST,
+        to_recipient: output::to_address(BOB_ADDRESS),
+    };
+    let change_output = Proto::Output {
+        value: 8_454,
+        to_recipient: output::p2wpkh(alice_pubkey.to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1, out2, change_output],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    };
+
+    Proto::SigningInput {
+        private_keys: vec![ALICE_PRIVATE_KEY.decode_hex().unwrap().into()],
+        chain_info: btc_info(),
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    }
+}
+
+fn edict(output: u32) -> Proto::mod_Output::RuneEdict<'static> {
+    Proto::mod_Output::RuneEdict {
+        id: Some(Proto::mod_Output::RuneId {
+            block: 840_000,
+            tx: 3,
+        }),
+        amount: "1000".into(),
+        output,
+    }
+}
+
+#[test]
+fn test_bitcoin_sign_runes_transfer() {
+    // Transfer 1000 runes of `840000:3` to the output #1.
+    let runestone = Proto::mod_Output::OutputRunestone {
+        edicts: vec![edict(1)],
+        ..Default::default()
+    };
+    let signing = signing_input(runestone);
+
+    // The transaction has not been broadcasted.
+    sign::BitcoinSignHelper::new(&signing)
+        .coin(CoinType::Bitcoin)
+        .sign(sign::Expected {
+            encoded: "02000000000101ca3edda74a46877efa5364ab85947e148508713910ada23e147ea28926dc46700100000000ffffffff0300000000000000000b6a5d0800c0a23303e807012202000000000000160014e891850afc55b64aa8247b2076f8894ebdf889010621000000000000160014e311b8d6ddff856ce8e9a4e03bc6d4fe5050a83d0247304402207bc7e431a97ff7c4ed6ad7ebf4fd7ad21082225a01f71dfaf680bfe5b0be575a02203a86aa4c6d9e85c7958bf9d48ce00179ec3bfeaee42e5d37308dfd24da325b870121030f209b6ada5edb42c77fd2bc64ad650ae38314c8f451f3e36d80bc8e26f132cb00000000",
+            txid: "16a89c32107af671776a77f527105edaa26dacb336ca80c4ca45891971cfb85f",
+            inputs: vec![10_000],
+            outputs: vec![0, DUST, 8_454],
+            vsize: 161,
+            weight: 641,
+            fee: 1_000,
+        });
+}
+
+#[test]
+fn test_bitcoin_sign_runes_output_out_of_bounds() {
+    // The transaction has 3 outputs, so the runes would be burnt.
+    let runestone = Proto::mod_Output::OutputRunestone {
+        edicts: vec![edict(4)],
+        ..Default::default()
+    };
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Bitcoin, signing_input(runestone));
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+
+    // The pointer must refer to an existing output.
+    let runestone = Proto::mod_Output::OutputRunestone {
+        edicts: vec![edict(1)],
+        pointer: Some(Proto::mod_Output::mod_OutputRunestone::Pointer { output: 3 }),
+        ..Default::default()
+    };
+    let output = signer.sign(CoinType::Bitcoin, signing_input(runestone));
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
@@ -4,6 +4,7 @@
 
 mod bitcoin_address;
 mod bitcoin_compile;
+mod bitcoin_message_sign;
 mod bitcoin_plan;
 mod bitcoin_sign;
 mod bitcoin_transaction_util;
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::Value;
+use tw_cardano::modules::message_signer::CardanoMessageSigner;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::test_utils::tw_private_key_helper::TWPrivateKeyHelper;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+use wallet_core_rs::ffi::cardano::message_signer::{
+    tw_cardano_message_signer_sign_data, tw_cardano_message_signer_verify_data,
+};
+
+const PRIVATE_KEY: &str = "089b68e458861be0c44bf9f7967f05cc91e51ede86dc679448a3566990b7785bd48c330875b1e0d03caaed0e67cecc42075dce1c7a13b1c49240508848ac82f603391c68824881ae3fc23a56a1a75ada3b96382db502e37564e84a5413cfaf1290dbd508e5ec71afaea98da2df1533c22ef02a26bb87b31907d0b2738fb7785b38d53aa68fc01230784c9209b2b2a2faf28491b3b1f1d221e63e704bbd0403c4154425dfbb01a2c5c042da411703603f89af89e57faae2946e2a5c18b1c5ca0e";
+const ADDRESS: &str = "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23";
+const STAKING_ADDRESS: &str = "stake1u80jysjtdzqt88jt4jx93h5lumfr67d273r4vwyasfa2pxcwxllmx";
+
+fn sign_data(address: &str, payload: &str) -> String {
+    let private_key = TWPrivateKeyHelper::with_hex(PRIVATE_KEY);
+    let address = TWStringHelper::create(address);
+    let payload = TWDataHelper::create(payload.decode_hex().unwrap());
+    let result = TWStringHelper::wrap(unsafe {
+        tw_cardano_message_signer_sign_data(private_key.ptr(), address.ptr(), payload.ptr())
+    });
+    result.to_string().unwrap()
+}
+
+fn verify_data(address: &str, payload: &str, signature: &str, key: &str) -> bool {
+    let address = TWStringHelper::create(address);
+    let payload = TWDataHelper::create(payload.decode_hex().unwrap());
+    let signature = TWStringHelper::create(signature);
+    let key = TWStringHelper::create(key);
+    unsafe {
+        tw_cardano_message_signer_verify_data(
+            address.ptr(),
+            payload.ptr(),
+            signature.ptr(),
+            key.ptr(),
+        )
+    }
+}
+
+#[test]
+fn test_cardano_message_signer_sign_and_verify_data() {
+    // "Hello Cardano"
+    let payload = "48656c6c6f2043617264616e6f";
+    let result: Value = serde_json::from_str(&sign_data(ADDRESS, payload)).unwrap();
+    let signature = result["signature"].as_str().unwrap();
+    let key = result["key"].as_str().unwrap();
+
+    assert_eq!(signature, "845846a201276761646472657373583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09ba166686173686564f44d48656c6c6f2043617264616e6f5840f11350ed305aabe2bec7815e015de3c52fab53878d121ee3d01202793190b492c71c3f2c3c9a215f4481d9c464074c3d5afa1fd739d549683d3aa40192d75d05");
+    assert_eq!(
+        key,
+        "a40101032720062158206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df290"
+    );
+
+    assert!(verify_data(ADDRESS, payload, signature, key));
+    assert!(!verify_data(ADDRESS, "48656c6c6f", signature, key));
+    // The signature is bound to the address.
+    assert!(!verify_data(STAKING_ADDRESS, payload, signature, key));
+}
+
+#[test]
+fn test_cardano_message_signer_sign_data_staking_address() {
+    let payload = "48656c6c6f";
+    let result: Value = serde_json::from_str(&sign_data(STAKING_ADDRESS, payload)).unwrap();
+    let signature = result["signature"].as_str().unwrap();
+    let key = result["key"].as_str().unwrap();
+
+    assert_eq!(signature, "84582aa201276761646472657373581de1df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09ba166686173686564f44548656c6c6f58409841dfb6803f347659d1f847fb1e709a1526e5a319644fb1a33cb854a1ffb123ef07a2b2be8df99cc86732f4c244aa4ec2fa7de8b3448e2b2fde33e2fa148a00");
+    assert_eq!(
+        key,
+        "a4010103272006215820e554163344aafc2bbefe778a6953ddce0583c2f8e0a0686929c020ca33e06932"
+    );
+    assert!(verify_data(STAKING_ADDRESS, payload, signature, key));
+}
+
+#[test]
+fn test_cardano_message_signer_sign_data_foreign_address() {
+    let foreign_address = "addr1q92cmkgzv9h4e5q7mnrzsuxtgayvg4qr7y3gyx97ukmz3dfx7r9fu73vqn25377ke6r0xk97zw07dqr9y5myxlgadl2s0dgke5";
+    assert_eq!(sign_data(foreign_address, "48656c6c6f2043617264616e6f"), "");
+}
+
+#[test]
+fn test_cardano_message_signer_sig_structure() {
+    let protected_header = "a201276761646472657373583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b".decode_hex().unwrap();
+    let sig_structure =
+        CardanoMessageSigner::sig_structure(&protected_header, b"Hello Cardano").unwrap();
+    assert_eq!(sig_structure.to_hex(), "846a5369676e6174757265315846a201276761646472657373583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09b404d48656c6c6f2043617264616e6f");
+}
@@ -129,6 +129,20 @@ pub mod input {
         ))
     }
 
+    pub fn ordinal_inscribe(
+        inscribe_to: Data,
+        mime_type: &str,
+        payload: Data,
+    ) -> ClaimingScriptType<'static> {
+        claiming_script_builder(InputBuilderType::ordinal_inscribe(
+            Proto::mod_Input::InputOrdinalInscription {
+                inscribe_to: inscribe_to.into(),
+                mime_type: mime_type.to_string().into(),
+                payload: payload.into(),
+            },
+        ))
+    }
+
     pub fn receiver_address(addr: &str) -> ClaimingScriptType<'static> {
         ClaimingScriptType::receiver_address(addr.to_string().into())
     }
@@ -223,6 +237,20 @@ pub mod output {
         ))
     }
 
+    pub fn ordinal_inscribe(
+        inscribe_to: Data,
+        mime_type: &str,
+        payload: Data,
+    ) -> RecipientType<'static> {
+        receiver_builder(OutputBuilderType::ordinal_inscribe(
+            Proto::mod_Output::OutputOrdinalInscription {
+                inscribe_to: inscribe_to.into(),
+                mime_type: mime_type.to_string().into(),
+                payload: payload.into(),
+            },
+        ))
+    }
+
     pub fn to_address(addr: &str) -> RecipientType<'static> {
         RecipientType::to_address(addr.to_string().into())
     }
@@ -231,6 +259,12 @@ pub mod output {
         receiver_builder(OutputBuilderType::op_return(data.into()))
     }
 
+    pub fn runestone(
+        runestone: Proto::mod_Output::OutputRunestone<'static>,
+    ) -> RecipientType<'static> {
+        receiver_builder(OutputBuilderType::runestone(runestone))
+    }
+
     pub fn custom_script(data: Data) -> RecipientType<'static> {
         RecipientType::custom_script_pubkey(data.into())
     }
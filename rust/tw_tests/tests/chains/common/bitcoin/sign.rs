@@ -12,6 +12,7 @@ use tw_misc::traits::ToBytesVec;
 use tw_proto::BitcoinV2::Proto::mod_SigningOutput::OneOftransaction as ProtobufTransaction;
 use tw_proto::Common::Proto::SigningError;
 use tw_proto::DecredV2::Proto as DecredProto;
+use tw_proto::Liquid::Proto as LiquidProto;
 
 type UtxoMap = HashMap<OutPoint, i64>;
 
@@ -94,6 +95,7 @@ impl<'a> BitcoinSignHelper<'a> {
             ProtobufTransaction::bitcoin(btc) => transaction_out_points(&btc.inputs),
             ProtobufTransaction::zcash(zcash) => transaction_out_points(&zcash.inputs),
             ProtobufTransaction::decred(decred) => decred_transaction_out_points(&decred.inputs),
+            ProtobufTransaction::liquid(liquid) => transaction_out_points(&liquid.inputs),
             ProtobufTransaction::None => panic!("'SigningOutput.transaction' isn't set"),
         };
 
@@ -116,6 +118,9 @@ impl<'a> BitcoinSignHelper<'a> {
             ProtobufTransaction::decred(decred) => {
                 decred_transaction_output_amounts(&decred.outputs)
             },
+            ProtobufTransaction::liquid(liquid) => {
+                liquid_transaction_output_amounts(&liquid.outputs)
+            },
             ProtobufTransaction::None => panic!("'SigningOutput.transaction' isn't set"),
         }
     }
@@ -149,6 +154,10 @@ fn decred_transaction_output_amounts(outputs: &[DecredProto::TransactionOutput])
     outputs.iter().map(|output| output.value).collect()
 }
 
+fn liquid_transaction_output_amounts(outputs: &[LiquidProto::TransactionOutput]) -> Vec<i64> {
+    outputs.iter().map(|output| output.value).collect()
+}
+
 fn transaction_output_amounts(outputs: &[UtxoProto::TransactionOutput]) -> Vec<i64> {
     outputs.iter().map(|output| output.value).collect()
 }
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_pre_image_hashes, tw_message_signer_sign, tw_message_signer_verify,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Cosmos::Proto;
+use tw_proto::{deserialize, serialize, TxCompiler};
+
+const PRIVATE_KEY: &str = "8d2a3bd62d300a148c89dc8635f87b7a24a951bd1c4e78675fe40e1a640d46ed";
+const PUBLIC_KEY: &str = "02868e7e1634417db2adfd9fe38205bfa0fea01898a7fd30565d13f7056a37c065";
+const SIGNATURE: &str =
+    "x7P9GSahETwc7r/mdmwWMdEX2uDoo1HxjzMHpcRFWHMF+aSY9nmHHBhqZcXHnrvpqa77ppQyyF7Dv+DT/NSBDg==";
+
+fn signing_input() -> Proto::MessageSigningInput<'static> {
+    Proto::MessageSigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: "Hello World".into(),
+    }
+}
+
+#[test]
+fn test_cosmos_message_sign_adr36() {
+    let input_data = TWDataHelper::create(serialize(&signing_input()).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(CoinType::Cosmos as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.signature, SIGNATURE);
+}
+
+#[test]
+fn test_cosmos_message_pre_image_hashes_adr36() {
+    let input_data = TWDataHelper::create(serialize(&signing_input()).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(CoinType::Cosmos as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        String::from_utf8(output.data.to_vec()).unwrap(),
+        r#"{"account_number":"0","chain_id":"","fee":{"amount":[],"gas":"0"},"memo":"","msgs":[{"type":"sign/MsgSignData","value":{"data":"SGVsbG8gV29ybGQ=","signer":"cosmos1p05ufmhfpkjzqmc2u8humvgcqatq0esjx96rf5"}}],"sequence":"0"}"#
+    );
+    assert_eq!(
+        output.data_hash.to_hex(),
+        "b353c5e394bfd9650e4db547a3d071bf0c7ad63ce3524fc200adfa4cbb6f95a9"
+    );
+}
+
+#[test]
+fn test_cosmos_message_verify_adr36() {
+    let verify = |message: &str| {
+        let input = Proto::MessageVerifyingInput {
+            message: message.into(),
+            public_key: PUBLIC_KEY.decode_hex().unwrap().into(),
+            signature: SIGNATURE.into(),
+        };
+        let input_data = TWDataHelper::create(serialize(&input).unwrap());
+        unsafe { tw_message_signer_verify(CoinType::Cosmos as u32, input_data.ptr()) }
+    };
+
+    assert!(verify("Hello World"));
+    assert!(!verify("Hello World!"));
+}
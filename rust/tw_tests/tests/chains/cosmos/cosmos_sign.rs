@@ -5,7 +5,7 @@
 use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign;
 use tw_coin_entry::error::prelude::*;
 use tw_coin_registry::coin_type::CoinType;
-use tw_encoding::hex::DecodeHex;
+use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_memory::test_utils::tw_data_helper::TWDataHelper;
 use tw_proto::{deserialize, serialize};
 
@@ -62,6 +62,81 @@ fn test_any_signer_sign_cosmos() {
     assert_eq!(output.serialized, expected);
 }
 
+#[test]
+fn test_any_signer_sign_cosmos_skip_signing() {
+    use tw_proto::Cosmos::Proto;
+    use tw_proto::Cosmos::Proto::mod_Message::OneOfmessage_oneof as MessageEnum;
+
+    let send_msg = Proto::mod_Message::Send {
+        from_address: "cosmos1mky69cn8ektwy0845vec9upsdphktxt03gkwlx".into(),
+        to_address: "cosmos18s0hdnsllgcclweu9aymw4ngktr2k0rkygdzdp".into(),
+        amounts: vec![Proto::Amount {
+            denom: "uatom".into(),
+            amount: "400000".into(),
+        }],
+        ..Proto::mod_Message::Send::default()
+    };
+    let mut input = Proto::SigningInput {
+        signing_mode: Proto::SigningMode::Protobuf,
+        account_number: 546179,
+        chain_id: "cosmoshub-4".into(),
+        sequence: 0,
+        fee: Some(Proto::Fee {
+            gas: 200000,
+            amounts: vec![Proto::Amount {
+                denom: "uatom".into(),
+                amount: "1000".into(),
+            }],
+        }),
+        public_key: "02ecef5ce437a302c67f95468de4b31f36e911f467d7e6a52b41c1e13e1d563649"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        messages: vec![Proto::Message {
+            message_oneof: MessageEnum::send_coins_message(send_msg),
+        }],
+        skip_signing: true,
+        ..Proto::SigningInput::default()
+    };
+
+    let sign = |input: &Proto::SigningInput| -> Vec<u8> {
+        let input_data = TWDataHelper::create(serialize(input).unwrap());
+        TWDataHelper::wrap(unsafe { tw_any_signer_sign(input_data.ptr(), CoinType::Cosmos as u32) })
+            .to_vec()
+            .expect("!tw_any_signer_sign returned nullptr")
+    };
+
+    let output_data = sign(&input);
+    let output: Proto::SigningOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+
+    let expected_tx_body = "0a8f010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e64126f0a2d636f736d6f73316d6b793639636e38656b74777930383435766563397570736470686b7478743033676b776c78122d636f736d6f733138733068646e736c6c6763636c7765753961796d77346e676b7472326b30726b7967647a64701a0f0a057561746f6d1206343030303030";
+    let expected_sign_bytes = "0a92010a8f010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e64126f0a2d636f736d6f73316d6b793639636e38656b74777930383435766563397570736470686b7478743033676b776c78122d636f736d6f733138733068646e736c6c6763636c7765753961796d77346e676b7472326b30726b7967647a64701a0f0a057561746f6d120634303030303012650a4e0a460a1f2f636f736d6f732e63727970746f2e736563703235366b312e5075624b657912230a2102ecef5ce437a302c67f95468de4b31f36e911f467d7e6a52b41c1e13e1d56364912040a02080112130a0d0a057561746f6d12043130303010c09a0c1a0b636f736d6f736875622d342083ab21";
+    let expected_unsigned_tx = "0a92010a8f010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e64126f0a2d636f736d6f73316d6b793639636e38656b74777930383435766563397570736470686b7478743033676b776c78122d636f736d6f733138733068646e736c6c6763636c7765753961796d77346e676b7472326b30726b7967647a64701a0f0a057561746f6d120634303030303012650a4e0a460a1f2f636f736d6f732e63727970746f2e736563703235366b312e5075624b657912230a2102ecef5ce437a302c67f95468de4b31f36e911f467d7e6a52b41c1e13e1d56364912040a02080112130a0d0a057561746f6d12043130303010c09a0c1a00";
+
+    assert_eq!(output.tx_body.to_hex(), expected_tx_body);
+    assert_eq!(output.sign_bytes.to_hex(), expected_sign_bytes);
+    assert_eq!(output.unsigned_tx.to_hex(), expected_unsigned_tx);
+    // The transaction is not signed.
+    assert!(output.signature.is_empty());
+    assert!(output.serialized.is_empty());
+
+    // The unsigned payload is returned alongside the signed transaction too.
+    input.skip_signing = false;
+    input.private_key = "8bbec3772ddb4df68f3186440380c301af116d1422001c1877d6f5e4dba8c8af"
+        .decode_hex()
+        .unwrap()
+        .into();
+
+    let output_data = sign(&input);
+    let output: Proto::SigningOutput = deserialize(&output_data).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.sign_bytes.to_hex(), expected_sign_bytes);
+    assert_eq!(output.unsigned_tx.to_hex(), expected_unsigned_tx);
+    assert_eq!(output.signature.to_hex(), "afbd513a776f4fdf470ef7f9675f21ae9d630fc4d635d8dbaa0dc0a716434cd07e02510765d4673dfa880825bae8e67cb367396ff6b976fc6b19a31fc95e8097");
+}
+
 /// Tests if it is possible to sign a transaction for a custom chain using `CoinType::Cosmos`.
 /// Successfully broadcasted (testnet):
 /// https://explorer.zetachain.com/cosmos/tx/A2FC8816657856ED274C4418C3CAEAEE645561275F6C63AB5F8B1DCFB37341A0
@@ -3,5 +3,6 @@
 // Copyright © 2017 Trust Wallet.
 
 mod cosmos_address;
+mod cosmos_message_sign;
 mod cosmos_sign;
 mod cosmos_transaction_util;
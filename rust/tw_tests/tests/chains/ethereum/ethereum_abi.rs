@@ -10,8 +10,8 @@ use tw_number::U256;
 use tw_proto::EthereumAbi::{Proto as AbiProto, Proto};
 use tw_proto::{deserialize, serialize};
 use wallet_core_rs::ffi::ethereum::abi::{
-    tw_ethereum_abi_decode_contract_call, tw_ethereum_abi_decode_params,
-    tw_ethereum_abi_decode_value, tw_ethereum_abi_encode_function,
+    tw_ethereum_abi_decode_calldata, tw_ethereum_abi_decode_contract_call,
+    tw_ethereum_abi_decode_params, tw_ethereum_abi_decode_value, tw_ethereum_abi_encode_function,
     tw_ethereum_abi_function_get_type, tw_ethereum_abi_get_function_signature,
 };
 
@@ -70,6 +70,58 @@ fn test_ethereum_abi_decode_contract_call() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn test_ethereum_abi_decode_calldata() {
+    // ERC-1155 `safeTransferFrom(from, to, id, value, data)`.
+    let calldata = "f242432a\
+        0000000000000000000000003535353535353535353535353535353535353535\
+        0000000000000000000000001111111111111111111111111111111111111111\
+        0000000000000000000000000000000000000000000000000000000000000007\
+        0000000000000000000000000000000000000000000000000000000000000002\
+        00000000000000000000000000000000000000000000000000000000000000a0\
+        0000000000000000000000000000000000000000000000000000000000000000"
+        .decode_hex()
+        .unwrap();
+
+    let input = AbiProto::CalldataDecodingInput {
+        calldata: calldata.into(),
+        abi_json: "".into(),
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_abi_decode_calldata(CoinType::Ethereum as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_abi_decode_calldata returned nullptr");
+    let output: AbiProto::CalldataDecodingOutput = deserialize(&output_data)
+        .expect("!tw_ethereum_abi_decode_calldata returned an invalid output");
+
+    assert_eq!(output.error, AbiErrorKind::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.selector, "0xf242432a");
+    assert_eq!(
+        output.function_signature,
+        "safeTransferFrom(address,address,uint256,uint256,bytes)"
+    );
+    assert_eq!(output.standards, vec![AbiProto::AbiStandard::ERC1155]);
+
+    let expected_params = vec![
+        named_token(
+            "from",
+            TokenEnum::address("0x3535353535353535353535353535353535353535".into()),
+        ),
+        named_token(
+            "to",
+            TokenEnum::address("0x1111111111111111111111111111111111111111".into()),
+        ),
+        named_token("id", TokenEnum::number_uint(number_n::<256>(7))),
+        named_token("value", TokenEnum::number_uint(number_n::<256>(2))),
+        named_token("data", TokenEnum::byte_array(Vec::default().into())),
+    ];
+    assert_eq!(output.params, expected_params);
+}
+
 #[test]
 fn test_ethereum_abi_decode_params() {
     let abi_json = json!([
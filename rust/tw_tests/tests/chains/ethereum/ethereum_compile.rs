@@ -88,6 +88,67 @@ fn test_transaction_compiler_eth() {
     assert_eq!(output.encoded.to_hex(), expected_encoded);
 }
 
+#[test]
+fn test_transaction_compiler_eth_external_signature() {
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        data: Cow::default(),
+    };
+    let input = Proto::SigningInput {
+        nonce: U256::encode_be_compact(11),
+        chain_id: U256::encode_be_compact(1),
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(21_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        ..Proto::SigningInput::default()
+    };
+
+    let r = "360a84fb41ad07f07c845fedc34cde728421803ebbaae392fc39c116b29fc07b";
+    let s = "53bd9d1376e15a191d844db458893b928f3efbfee90c9febf51ab84c97966779";
+    let high_s = "ac4262ec891ea5e6e27bb24ba776c46c2b6fe0e7c63c004fcab7a640389fd9c8";
+    let public_key = "044bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382ce28cab79ad7119ee1ad3ebcdb98a16805211530ecc6cfefa1b88e6dff99232a";
+    let expected_encoded = "f86c0b8504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a0360a84fb41ad07f07c845fedc34cde728421803ebbaae392fc39c116b29fc07ba053bd9d1376e15a191d844db458893b928f3efbfee90c9febf51ab84c97966779";
+
+    let signatures = [
+        // EIP155 `v`.
+        format!("{r}{s}25"),
+        // Legacy `v`.
+        format!("{r}{s}1b"),
+        // `r || s` without `v`.
+        format!("{r}{s}"),
+        // DER encoded.
+        format!("30440220{r}0220{s}"),
+        // DER encoded with a high `s` value.
+        format!("30450220{r}022100{high_s}"),
+    ];
+
+    for signature in signatures {
+        let signatures = TWDataVectorHelper::create([signature.decode_hex().unwrap()]);
+        let public_keys = TWDataVectorHelper::create([public_key.decode_hex().unwrap()]);
+
+        let input_data = TWDataHelper::create(serialize(&input).unwrap());
+        let output_data = TWDataHelper::wrap(unsafe {
+            tw_transaction_compiler_compile(
+                CoinType::Ethereum as u32,
+                input_data.ptr(),
+                signatures.ptr(),
+                public_keys.ptr(),
+            )
+        })
+        .to_vec()
+        .expect("!tw_transaction_compiler_compile returned nullptr");
+
+        let output: Proto::SigningOutput =
+            deserialize(&output_data).expect("Coin entry returned an invalid output");
+
+        assert_eq!(output.error, SigningErrorType::OK, "{signature}");
+        assert_eq!(output.encoded.to_hex(), expected_encoded, "{signature}");
+    }
+}
+
 #[test]
 fn test_transaction_compiler_eip7702() {
     let transfer = Proto::mod_Transaction::Transfer {
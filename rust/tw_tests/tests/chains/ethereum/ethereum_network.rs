@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_any_coin::any_signer::AnySigner;
+use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign_evm;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::evm_network::{evm_network_by_chain_id, evm_network_by_coin};
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+use tw_proto::{deserialize, serialize};
+
+const PRIVATE_KEY: &str = "4646464646464646464646464646464646464646464646464646464646464646";
+
+fn transfer_input(chain_id: u64, tx_mode: Proto::TransactionMode) -> Proto::SigningInput<'static> {
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        data: Cow::default(),
+    };
+
+    Proto::SigningInput {
+        chain_id: U256::encode_be_compact(chain_id as u128),
+        nonce: U256::encode_be_compact(9),
+        tx_mode,
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(21_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(20_000_000_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: Proto::mod_Transaction::OneOftransaction_oneof::transfer(transfer),
+        }),
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..Proto::SigningInput::default()
+    }
+}
+
+/// Returns the encoded transaction.
+fn encoded(output: &[u8]) -> Vec<u8> {
+    let output: Proto::SigningOutput = deserialize(output).unwrap();
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    output.encoded.to_vec()
+}
+
+fn sign_evm(input: &Proto::SigningInput<'_>) -> Vec<u8> {
+    encoded(&AnySigner::sign_evm(&serialize(input).unwrap()).unwrap())
+}
+
+fn sign(input: &Proto::SigningInput<'_>, coin: CoinType) -> Vec<u8> {
+    encoded(&AnySigner::sign(&serialize(input).unwrap(), coin).unwrap())
+}
+
+#[test]
+fn test_evm_network_registry() {
+    let ethereum = evm_network_by_chain_id(1).unwrap();
+    assert_eq!(ethereum.coin, CoinType::Ethereum);
+    assert!(ethereum.eip1559);
+
+    // Smart Chain Legacy is deprecated and shares the chain ID with Smart Chain.
+    let smart_chain = evm_network_by_chain_id(56).unwrap();
+    assert_eq!(smart_chain.coin, CoinType::SmartChain);
+    assert!(!smart_chain.eip1559);
+    assert_eq!(evm_network_by_coin(CoinType::SmartChain), Some(smart_chain));
+
+    let polygon = evm_network_by_chain_id(137).unwrap();
+    assert_eq!(polygon.coin, CoinType::Polygon);
+    assert!(polygon.eip1559);
+
+    assert_eq!(evm_network_by_coin(CoinType::Bitcoin), None);
+    assert_eq!(evm_network_by_chain_id(123_456_789), None);
+}
+
+#[test]
+fn test_sign_evm_network_default_legacy() {
+    let input = transfer_input(56, Proto::TransactionMode::NetworkDefault);
+    let output = sign_evm(&input);
+
+    // Smart Chain doesn't support EIP-1559, so an EIP-155 Legacy transaction is signed.
+    assert_eq!(output.to_hex(), "f86d098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000808194a007764d8e7d4ce45afb36cb2f5bec741f96eee3b8f23304e4d35b0f389912e7f7a047c14849f32c33c269dec152d168ddeca152fec8ceeb7369177ff3a06dc72c48");
+
+    let expected = sign(
+        &transfer_input(56, Proto::TransactionMode::Legacy),
+        CoinType::SmartChain,
+    );
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_sign_evm_network_default_eip1559() {
+    let input = transfer_input(137, Proto::TransactionMode::NetworkDefault);
+    let output = sign_evm(&input);
+
+    // Polygon supports EIP-1559, so an Enveloped transaction is signed.
+    assert_eq!(output.to_hex(), "02f87481890984773594008504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080c080a0a48c25560a1424425e30b16383003ebf3bfb618e663d6decb1207f09d09e5811a02d575d9d09def251e24d90cf7a201894023c625ad610cf8a0cd35cb155912c27");
+
+    let expected = sign(
+        &transfer_input(137, Proto::TransactionMode::Enveloped),
+        CoinType::Polygon,
+    );
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_sign_evm_user_added_chain_id() {
+    let input = transfer_input(123_456_789, Proto::TransactionMode::NetworkDefault);
+    let output = sign_evm(&input);
+
+    // Unknown networks are signed as EIP-1559 Ethereum transactions.
+    assert_eq!(output.to_hex(), "02f87784075bcd150984773594008504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080c001a06bd34f982436d714110467b33799f34bc1292032e1b1f6f728a9fdb53319e890a07963f6fea7f4ce08608fe9f908cd8990fc161ad45933b654d06fabce8e88f324");
+    let expected = sign(
+        &transfer_input(123_456_789, Proto::TransactionMode::Enveloped),
+        CoinType::Ethereum,
+    );
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_sign_evm_explicit_mode() {
+    // An explicit transaction mode is not overridden by the network default.
+    let input = transfer_input(1, Proto::TransactionMode::Legacy);
+    let output = sign_evm(&input);
+    // EIP-155 example transaction.
+    assert_eq!(output.to_hex(), "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83");
+    assert_eq!(output, sign(&input, CoinType::Ethereum));
+}
+
+#[test]
+fn test_sign_evm_zero_chain_id() {
+    let input = transfer_input(0, Proto::TransactionMode::NetworkDefault);
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output = TWDataHelper::wrap(unsafe { tw_any_signer_sign_evm(input_data.ptr()) });
+    assert!(output.is_null());
+}
@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use serde_json::{json, Value as Json};
+use std::borrow::Cow;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_number::U256;
+use tw_proto::EthereumPermit::Proto;
+use tw_proto::{deserialize, serialize};
+use wallet_core_rs::ffi::ethereum::permit::tw_ethereum_sign_permit;
+use Proto::mod_SigningInput::OneOfpermit as PermitType;
+
+const PRIVATE_KEY: &str = "4646464646464646464646464646464646464646464646464646464646464646";
+const OWNER: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+const USDT: &str = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+const UNIVERSAL_ROUTER: &str = "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD";
+
+fn sign_permit(permit: PermitType<'static>) -> Proto::SigningOutput<'static> {
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        chain_id: 1,
+        permit,
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_sign_permit(CoinType::Ethereum as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_sign_permit returned nullptr");
+    let output: Proto::SigningOutput = deserialize(&output_data).unwrap();
+
+    Proto::SigningOutput {
+        typed_data: Cow::Owned(output.typed_data.into_owned()),
+        signature: Cow::Owned(output.signature.into_owned()),
+        owner: Cow::Owned(output.owner.into_owned()),
+        error: output.error,
+        error_message: Cow::Owned(output.error_message.into_owned()),
+    }
+}
+
+/// The expected signatures are computed independently of this library,
+/// by signing the EIP-712 digest `keccak256(0x1901 ++ domainSeparator ++ hashStruct(message))` with RFC 6979.
+fn assert_signed(
+    output: &Proto::SigningOutput<'_>,
+    expected_typed_data: Json,
+    expected_signature: &str,
+) {
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.owner, OWNER);
+
+    let actual_typed_data: Json = serde_json::from_str(&output.typed_data).unwrap();
+    assert_eq!(actual_typed_data, expected_typed_data);
+    assert_eq!(output.signature, expected_signature);
+}
+
+fn permit2_details(token: &str, amount: u64, nonce: u64) -> Proto::Permit2Details<'static> {
+    Proto::Permit2Details {
+        token: token.to_string().into(),
+        amount: U256::encode_be_compact(amount),
+        expiration: 1_720_000_000,
+        nonce,
+    }
+}
+
+fn permit2_types(primary_type: &str, details_type: &str) -> Json {
+    json!({
+        "EIP712Domain": [
+            { "name": "name", "type": "string" },
+            { "name": "chainId", "type": "uint256" },
+            { "name": "verifyingContract", "type": "address" }
+        ],
+        primary_type: [
+            { "name": "details", "type": details_type },
+            { "name": "spender", "type": "address" },
+            { "name": "sigDeadline", "type": "uint256" }
+        ],
+        "PermitDetails": [
+            { "name": "token", "type": "address" },
+            { "name": "amount", "type": "uint160" },
+            { "name": "expiration", "type": "uint48" },
+            { "name": "nonce", "type": "uint48" }
+        ]
+    })
+}
+
+fn permit2_domain() -> Json {
+    json!({
+        "name": "Permit2",
+        "chainId": 1,
+        "verifyingContract": "0x000000000022D473030F116dDEE9F6B43aC78BA3"
+    })
+}
+
+#[test]
+fn test_sign_erc2612_permit() {
+    let output = sign_permit(PermitType::erc2612(Proto::Erc2612Permit {
+        // Lowercase addresses are checksummed.
+        token: USDC.to_lowercase().into(),
+        token_name: "USD Coin".into(),
+        version: "2".into(),
+        spender: UNIVERSAL_ROUTER.into(),
+        value: U256::encode_be_compact(1_000_000),
+        nonce: U256::encode_be_compact(3),
+        deadline: 1_720_000_000,
+    }));
+
+    let expected = json!({
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "version", "type": "string" },
+                { "name": "chainId", "type": "uint256" },
+                { "name": "verifyingContract", "type": "address" }
+            ],
+            "Permit": [
+                { "name": "owner", "type": "address" },
+                { "name": "spender", "type": "address" },
+                { "name": "value", "type": "uint256" },
+                { "name": "nonce", "type": "uint256" },
+                { "name": "deadline", "type": "uint256" }
+            ]
+        },
+        "primaryType": "Permit",
+        "domain": {
+            "name": "USD Coin",
+            "version": "2",
+            "chainId": 1,
+            "verifyingContract": USDC
+        },
+        "message": {
+            "owner": OWNER,
+            "spender": UNIVERSAL_ROUTER,
+            "value": "1000000",
+            "nonce": "3",
+            "deadline": "1720000000"
+        }
+    });
+    // EIP-712 digest: c2fddf8fcf6e4c30863f67cce098cfa4add2ef48c973d01d414b5137973a3253
+    assert_signed(&output, expected, "d722c60068c28c9ecfa640a60b86b93f66248624445dc2e46f8522a5199e503f5afb390959cb22fddbfcdf70d0f5306b9c28f4f112c315088d4a66f40f2360af1c");
+}
+
+#[test]
+fn test_sign_erc2612_permit_no_version() {
+    // The domain version differs between tokens, so it mustn't be guessed.
+    let output = sign_permit(PermitType::erc2612(Proto::Erc2612Permit {
+        token: USDC.into(),
+        token_name: "USD Coin".into(),
+        spender: UNIVERSAL_ROUTER.into(),
+        value: U256::MAX.to_big_endian().to_vec().into(),
+        ..Proto::Erc2612Permit::default()
+    }));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_sign_permit2_single() {
+    let output = sign_permit(PermitType::permit2_single(Proto::Permit2Single {
+        details: Some(permit2_details(USDC, 1_000_000, 0)),
+        spender: UNIVERSAL_ROUTER.into(),
+        sig_deadline: 1_710_000_000,
+    }));
+
+    let expected = json!({
+        "types": permit2_types("PermitSingle", "PermitDetails"),
+        "primaryType": "PermitSingle",
+        "domain": permit2_domain(),
+        "message": {
+            "details": {
+                "token": USDC,
+                "amount": "1000000",
+                "expiration": "1720000000",
+                "nonce": "0"
+            },
+            "spender": UNIVERSAL_ROUTER,
+            "sigDeadline": "1710000000"
+        }
+    });
+    // EIP-712 digest: 4492a9ab15dc2cce5af18d69de84d31c917f3217cb42634038d322d6016b4dd5
+    assert_signed(&output, expected, "efe32077ae2f0d885ba5046ec949c3d4a171271f5224c5cd99f828ef7e50462256ce440712fde6ed2902bdb3c358711f4e068eaa78fa7948fd7ec12c804f27ad1b");
+}
+
+#[test]
+fn test_sign_permit2_batch() {
+    let output = sign_permit(PermitType::permit2_batch(Proto::Permit2Batch {
+        details: vec![
+            permit2_details(USDC, 1_000_000, 0),
+            permit2_details(USDT, 2_000_000, 5),
+        ],
+        spender: UNIVERSAL_ROUTER.into(),
+        sig_deadline: 1_710_000_000,
+    }));
+
+    let expected = json!({
+        "types": permit2_types("PermitBatch", "PermitDetails[]"),
+        "primaryType": "PermitBatch",
+        "domain": permit2_domain(),
+        "message": {
+            "details": [
+                {
+                    "token": USDC,
+                    "amount": "1000000",
+                    "expiration": "1720000000",
+                    "nonce": "0"
+                },
+                {
+                    "token": USDT,
+                    "amount": "2000000",
+                    "expiration": "1720000000",
+                    "nonce": "5"
+                }
+            ],
+            "spender": UNIVERSAL_ROUTER,
+            "sigDeadline": "1710000000"
+        }
+    });
+    // EIP-712 digest: d11418e191d4d9aeb79d47177852fce4670f50bec5e2789984ba707a740e9d91
+    assert_signed(&output, expected, "c6aff74650f78577301724514ce00c6fbce34d807af263ff950fe8214409fac07e5c76f7e649e472f4e92c9acf069f890a4991791cb3c57db4085fc0cea81e181b");
+}
+
+#[test]
+fn test_sign_permit_error() {
+    let output = sign_permit(PermitType::None);
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    let output = sign_permit(PermitType::permit2_batch(Proto::Permit2Batch {
+        spender: UNIVERSAL_ROUTER.into(),
+        ..Proto::Permit2Batch::default()
+    }));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    let output = sign_permit(PermitType::erc2612(Proto::Erc2612Permit {
+        token: USDC.into(),
+        spender: "0xinvalid".into(),
+        ..Proto::Erc2612Permit::default()
+    }));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    // The amount doesn't fit in uint160.
+    let output = sign_permit(PermitType::permit2_single(Proto::Permit2Single {
+        details: Some(Proto::Permit2Details {
+            token: USDC.into(),
+            amount: U256::MAX.to_big_endian().to_vec().into(),
+            ..Proto::Permit2Details::default()
+        }),
+        spender: UNIVERSAL_ROUTER.into(),
+        sig_deadline: 1_710_000_000,
+    }));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
@@ -0,0 +1,316 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_number::U256;
+use tw_proto::EthereumRisk::Proto;
+use tw_proto::{deserialize, serialize};
+use wallet_core_rs::ffi::ethereum::risk::tw_ethereum_analyze_risks;
+use Proto::mod_AnalysisInput::OneOfrequest as RequestType;
+
+const USDT: &str = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+const BAYC: &str = "0xBC4CA0EdA7647A8aB7C2061c2E118A18a936f13D";
+const PERMIT2: &str = "0x000000000022D473030F116dDEE9F6B43aC78BA3";
+const UNIVERSAL_ROUTER: &str = "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD";
+const OPENSEA_CONDUIT: &str = "0x1E0049783F008A0085193E00003D00cd54003c71";
+
+type Warning = (Proto::RiskType, String, String);
+
+/// Returns the error and the `(risk_type, contract, spender)` of the warnings.
+fn analyze(request: RequestType<'_>) -> (SigningErrorType, Vec<Warning>) {
+    let input = Proto::AnalysisInput { request };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+
+    let output_data = TWDataHelper::wrap(unsafe {
+        tw_ethereum_analyze_risks(CoinType::Ethereum as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_ethereum_analyze_risks returned nullptr");
+    let output: Proto::AnalysisOutput = deserialize(&output_data).unwrap();
+
+    assert!(output.warnings.iter().all(|w| !w.description.is_empty()));
+    let warnings = output
+        .warnings
+        .into_iter()
+        .map(|w| (w.risk_type, w.contract.into_owned(), w.spender.into_owned()))
+        .collect();
+    (output.error, warnings)
+}
+
+fn analyze_tx(to: &str, value: U256, data: &str) -> (SigningErrorType, Vec<Warning>) {
+    analyze(RequestType::transaction(Proto::Transaction {
+        to: to.into(),
+        value: Cow::Owned(value.to_big_endian_compact()),
+        data: data.decode_hex().unwrap().into(),
+    }))
+}
+
+fn analyze_typed_data(typed_data: &str) -> (SigningErrorType, Vec<Warning>) {
+    analyze(RequestType::typed_data(typed_data.into()))
+}
+
+fn assert_warnings(
+    output: &(SigningErrorType, Vec<Warning>),
+    expected: &[(Proto::RiskType, &str, &str)],
+) {
+    let (error, warnings) = output;
+    assert_eq!(*error, SigningErrorType::OK);
+    let actual: Vec<_> = warnings
+        .iter()
+        .map(|(risk_type, contract, spender)| (*risk_type, contract.as_str(), spender.as_str()))
+        .collect();
+    assert_eq!(actual, expected);
+}
+
+fn padded(address: &str) -> String {
+    format!("{:0>64}", address[2..].to_lowercase())
+}
+
+#[test]
+fn test_ethereum_risk_unlimited_approval() {
+    let max = "ff".repeat(32);
+    let data = format!("095ea7b3{}{max}", padded(UNIVERSAL_ROUTER));
+    let output = analyze_tx(USDT, U256::zero(), &data);
+    assert_warnings(
+        &output,
+        &[(Proto::RiskType::UnlimitedApproval, USDT, UNIVERSAL_ROUTER)],
+    );
+
+    let data = format!("39509351{}{max}", padded(UNIVERSAL_ROUTER));
+    let output = analyze_tx(USDT, U256::zero(), &data);
+    assert_warnings(
+        &output,
+        &[(Proto::RiskType::UnlimitedApproval, USDT, UNIVERSAL_ROUTER)],
+    );
+
+    // A limited approval is fine.
+    let amount = format!("{:0>64}", "0f4240");
+    let data = format!("095ea7b3{}{amount}", padded(UNIVERSAL_ROUTER));
+    let output = analyze_tx(USDT, U256::zero(), &data);
+    assert_warnings(&output, &[]);
+}
+
+#[test]
+fn test_ethereum_risk_approval_for_all() {
+    let approved = format!("{:0>64}", "1");
+    let data = format!("a22cb465{}{approved}", padded(OPENSEA_CONDUIT));
+    let output = analyze_tx(BAYC, U256::zero(), &data);
+    assert_warnings(
+        &output,
+        &[(Proto::RiskType::ApprovalForAll, BAYC, OPENSEA_CONDUIT)],
+    );
+
+    // Revoking the approval is fine.
+    let revoked = format!("{:0>64}", "0");
+    let data = format!("a22cb465{}{revoked}", padded(OPENSEA_CONDUIT));
+    let output = analyze_tx(BAYC, U256::zero(), &data);
+    assert_warnings(&output, &[]);
+}
+
+#[test]
+fn test_ethereum_risk_permit2_approve_call() {
+    let amount = "ff".repeat(20);
+    let data = format!(
+        "87517c45{}{}{:0>64}{:0>64}",
+        padded(USDC),
+        padded(UNIVERSAL_ROUTER),
+        amount,
+        "6553f100"
+    );
+    let output = analyze_tx(PERMIT2, U256::zero(), &data);
+    assert_warnings(
+        &output,
+        &[(Proto::RiskType::Permit2, USDC, UNIVERSAL_ROUTER)],
+    );
+}
+
+#[test]
+fn test_ethereum_risk_value_to_new_contract() {
+    let output = analyze_tx("", U256::from(1_000_000_u64), "6080604052");
+    assert_warnings(&output, &[(Proto::RiskType::ValueToNewContract, "", "")]);
+
+    let output = analyze_tx("", U256::zero(), "6080604052");
+    assert_warnings(&output, &[]);
+}
+
+#[test]
+fn test_ethereum_risk_transfer_no_warnings() {
+    let amount = format!("{:0>64}", "0f4240");
+    let data = format!("a9059cbb{}{amount}", padded(UNIVERSAL_ROUTER));
+    let output = analyze_tx(USDT, U256::zero(), &data);
+    assert_warnings(&output, &[]);
+
+    let output = analyze_tx(UNIVERSAL_ROUTER, U256::from(1_u64), "");
+    assert_warnings(&output, &[]);
+}
+
+#[test]
+fn test_ethereum_risk_permit2_single_typed_data() {
+    let typed_data = r#"{
+        "types": {
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"}
+            ],
+            "PermitDetails": [
+                {"name": "token", "type": "address"},
+                {"name": "amount", "type": "uint160"},
+                {"name": "expiration", "type": "uint48"},
+                {"name": "nonce", "type": "uint48"}
+            ],
+            "PermitSingle": [
+                {"name": "details", "type": "PermitDetails"},
+                {"name": "spender", "type": "address"},
+                {"name": "sigDeadline", "type": "uint256"}
+            ]
+        },
+        "domain": {
+            "name": "Permit2",
+            "chainId": "1",
+            "verifyingContract": "0x000000000022d473030f116ddee9f6b43ac78ba3"
+        },
+        "primaryType": "PermitSingle",
+        "message": {
+            "details": {
+                "token": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "amount": "1461501637330902918203684832716283019655932542975",
+                "expiration": "1700000000",
+                "nonce": "0"
+            },
+            "spender": "0x3fc91a3afd70395cd496c647d5a6cc9d4b2b7fad",
+            "sigDeadline": "1700000000"
+        }
+    }"#;
+    let output = analyze_typed_data(typed_data);
+    assert_warnings(
+        &output,
+        &[(Proto::RiskType::Permit2, USDC, UNIVERSAL_ROUTER)],
+    );
+}
+
+#[test]
+fn test_ethereum_risk_permit2_batch_typed_data() {
+    let typed_data = r#"{
+        "types": {
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"}
+            ],
+            "TokenPermissions": [
+                {"name": "token", "type": "address"},
+                {"name": "amount", "type": "uint256"}
+            ],
+            "PermitBatchTransferFrom": [
+                {"name": "permitted", "type": "TokenPermissions[]"},
+                {"name": "spender", "type": "address"},
+                {"name": "nonce", "type": "uint256"},
+                {"name": "deadline", "type": "uint256"}
+            ]
+        },
+        "domain": {
+            "name": "Permit2",
+            "chainId": "1",
+            "verifyingContract": "0x000000000022D473030F116dDEE9F6B43aC78BA3"
+        },
+        "primaryType": "PermitBatchTransferFrom",
+        "message": {
+            "permitted": [
+                {"token": "0xdAC17F958D2ee523a2206206994597C13D831ec7", "amount": "1000000"},
+                {"token": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", "amount": "1000000"}
+            ],
+            "spender": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+            "nonce": "0",
+            "deadline": "1700000000"
+        }
+    }"#;
+    let output = analyze_typed_data(typed_data);
+    assert_warnings(
+        &output,
+        &[
+            (Proto::RiskType::Permit2, USDT, UNIVERSAL_ROUTER),
+            (Proto::RiskType::Permit2, USDC, UNIVERSAL_ROUTER),
+        ],
+    );
+}
+
+fn eip2612_permit(value: &str) -> String {
+    format!(
+        r#"{{
+            "types": {{
+                "EIP712Domain": [
+                    {{"name": "name", "type": "string"}},
+                    {{"name": "version", "type": "string"}},
+                    {{"name": "chainId", "type": "uint256"}},
+                    {{"name": "verifyingContract", "type": "address"}}
+                ],
+                "Permit": [
+                    {{"name": "owner", "type": "address"}},
+                    {{"name": "spender", "type": "address"}},
+                    {{"name": "value", "type": "uint256"}},
+                    {{"name": "nonce", "type": "uint256"}},
+                    {{"name": "deadline", "type": "uint256"}}
+                ]
+            }},
+            "domain": {{
+                "name": "USD Coin",
+                "version": "2",
+                "chainId": "1",
+                "verifyingContract": "{USDC}"
+            }},
+            "primaryType": "Permit",
+            "message": {{
+                "owner": "0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37",
+                "spender": "{UNIVERSAL_ROUTER}",
+                "value": "{value}",
+                "nonce": "0",
+                "deadline": "1700000000"
+            }}
+        }}"#
+    )
+}
+
+#[test]
+fn test_ethereum_risk_eip2612_permit_typed_data() {
+    let unlimited = U256::MAX.to_string();
+    let output = analyze_typed_data(&eip2612_permit(&unlimited));
+    assert_warnings(
+        &output,
+        &[(Proto::RiskType::UnlimitedApproval, USDC, UNIVERSAL_ROUTER)],
+    );
+
+    let output = analyze_typed_data(&eip2612_permit("1000000"));
+    assert_warnings(&output, &[]);
+}
+
+#[test]
+fn test_ethereum_risk_eip2612_permit_typed_data_hex_value() {
+    let unlimited = "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+    let output = analyze_typed_data(&eip2612_permit(unlimited));
+    assert_warnings(
+        &output,
+        &[(Proto::RiskType::UnlimitedApproval, USDC, UNIVERSAL_ROUTER)],
+    );
+
+    let output = analyze_typed_data(&eip2612_permit("0xf4240"));
+    assert_warnings(&output, &[]);
+}
+
+#[test]
+fn test_ethereum_risk_invalid_input() {
+    let (error, _) = analyze_tx("0x123", U256::zero(), "");
+    assert_eq!(error, SigningErrorType::Error_invalid_address);
+
+    let (error, _) = analyze_typed_data("{}");
+    assert_ne!(error, SigningErrorType::OK);
+
+    let (error, _) = analyze(RequestType::None);
+    assert_eq!(error, SigningErrorType::Error_invalid_params);
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_any_coin::test_utils::transaction_planner_utils::TransactionPlannerHelper;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_number::U256;
+use tw_proto::Ethereum::Proto;
+use tw_proto::TransactionPlanner::Proto as PlannerProto;
+
+use Proto::mod_Transaction::OneOftransaction_oneof as TransactionType;
+
+fn transfer_input(tx_mode: Proto::TransactionMode) -> Proto::SigningInput<'static> {
+    let transfer = Proto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        data: Cow::default(),
+    };
+
+    Proto::SigningInput {
+        chain_id: U256::encode_be_compact(1),
+        nonce: U256::encode_be_compact(9),
+        tx_mode,
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(21_000),
+        max_inclusion_fee_per_gas: U256::encode_be_compact(2_000_000_000),
+        max_fee_per_gas: U256::encode_be_compact(100_000_000_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: TransactionType::transfer(transfer),
+        }),
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_ethereum_transaction_plan_legacy() {
+    let input = transfer_input(Proto::TransactionMode::Legacy);
+
+    let mut planner = TransactionPlannerHelper::default();
+    let output = planner.plan(CoinType::Ethereum, &input);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.amount, "1000000000000000000");
+    // 21000 * 20 Gwei
+    assert_eq!(output.fee, "420000000000000");
+    assert_eq!(output.change, "0");
+    // The balance is unknown.
+    assert!(output.max_amount.is_empty());
+    assert_eq!(
+        output.fee_rate,
+        Some(PlannerProto::FeeRate {
+            unit: PlannerProto::FeeUnit::PerGas,
+            units: 21_000,
+            amount_per_unit: "20000000000".into(),
+        })
+    );
+}
+
+#[test]
+fn test_ethereum_transaction_plan_eip1559() {
+    let input = transfer_input(Proto::TransactionMode::Enveloped);
+
+    let mut planner = TransactionPlannerHelper::default();
+    let output = planner.plan(CoinType::Ethereum, &input);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    // 21000 * 100 Gwei
+    assert_eq!(output.fee, "2100000000000000");
+    assert_eq!(output.fee_rate.unwrap().amount_per_unit, "100000000000");
+}
+
+#[test]
+fn test_ethereum_transaction_plan_erc20_transfer() {
+    let erc20_transfer = Proto::mod_Transaction::ERC20Transfer {
+        to: "0x5322b34c88ed0691971bf52a7047448f0f4efc84".into(),
+        amount: U256::encode_be_compact(2_000_000_000_000_000_000),
+    };
+    let input = Proto::SigningInput {
+        gas_limit: U256::encode_be_compact(78_009),
+        to_address: "0x6b175474e89094c44da98b954eedeac495271d0f".into(),
+        transaction: Some(Proto::Transaction {
+            transaction_oneof: TransactionType::erc20_transfer(erc20_transfer),
+        }),
+        ..transfer_input(Proto::TransactionMode::Enveloped)
+    };
+
+    let mut planner = TransactionPlannerHelper::default();
+    let output = planner.plan(CoinType::Ethereum, &input);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    // No native coins are sent.
+    assert_eq!(output.amount, "0");
+    // 78009 * 100 Gwei
+    assert_eq!(output.fee, "7800900000000000");
+}
+
+#[test]
+fn test_ethereum_transaction_plan_user_operation_not_supported() {
+    let input = transfer_input(Proto::TransactionMode::UserOp);
+
+    let mut planner = TransactionPlannerHelper::default();
+    let output = planner.plan(CoinType::Ethereum, &input);
+    assert_eq!(output.error, SigningErrorType::Error_not_supported);
+}
@@ -6,6 +6,10 @@ mod ethereum_abi;
 mod ethereum_address;
 mod ethereum_compile;
 mod ethereum_message_sign;
+mod ethereum_network;
+mod ethereum_permit;
+mod ethereum_risk;
 mod ethereum_rlp;
 mod ethereum_sign;
+mod ethereum_transaction_plan;
 mod ethereum_transaction_util;
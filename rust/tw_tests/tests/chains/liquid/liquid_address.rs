@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::address_utils::{
+    test_address_derive, test_address_get_data, test_address_invalid, test_address_normalization,
+    test_address_valid, KeyType,
+};
+use tw_coin_registry::coin_type::CoinType;
+
+#[test]
+fn test_liquid_address_derive() {
+    test_address_derive(
+        CoinType::Liquid,
+        KeyType::PublicKey("030f209b6ada5edb42c77fd2bc64ad650ae38314c8f451f3e36d80bc8e26f132cb"),
+        "ex1quvgm34kal7zke68f5nsrh3k5leg9p2paq6zsap",
+    );
+}
+
+#[test]
+fn test_liquid_address_normalization() {
+    test_address_normalization(
+        CoinType::Liquid,
+        "ex1quvgm34kal7zke68f5nsrh3k5leg9p2paq6zsap",
+        "ex1quvgm34kal7zke68f5nsrh3k5leg9p2paq6zsap",
+    );
+    test_address_normalization(
+        CoinType::Liquid,
+        "LQ1QQVXK052KF3QTKXMRAKX50A9GC3SMQAD2YNC54HZNTJT980KFEJ9KKFE0247RP5H4YZMDFTSAHHW64UY8PZFE7CPG4FGYKM7CV",
+        "lq1qqvxk052kf3qtkxmrakx50a9gc3smqad2ync54hzntjt980kfej9kkfe0247rp5h4yzmdftsahhw64uy8pzfe7cpg4fgykm7cv",
+    );
+}
+
+#[test]
+fn test_liquid_address_is_valid() {
+    // Unconfidential addresses.
+    test_address_valid(
+        CoinType::Liquid,
+        "ex1qyuh42lps6t6jpdk54cwmmhd27zrs3yulrc7t5a",
+    );
+    test_address_valid(CoinType::Liquid, "Q7wegLt2qMGhm28vch6VTzvpzs8KXvs4X7");
+    test_address_valid(CoinType::Liquid, "GjzwdM3QQBLizDdJPzFRaNjfufkMdbzBvr");
+    // Confidential addresses.
+    test_address_valid(
+        CoinType::Liquid,
+        "lq1qqvxk052kf3qtkxmrakx50a9gc3smqad2ync54hzntjt980kfej9kkfe0247rp5h4yzmdftsahhw64uy8pzfe7cpg4fgykm7cv",
+    );
+    test_address_valid(
+        CoinType::Liquid,
+        "VTpvKKc1SNmLG4H8CnR1fGJdHdyWGEQEvdP9gfeneJR7n81S5kiwNtgF7vrZjC8mp63HvwxM81nEbTxU",
+    );
+    test_address_valid(
+        CoinType::Liquid,
+        "VJLCbLBTCdxhWyjVLdjcSmGAksVMtabYg15maSi93zknQD2ihC38R7CUd8KbDFnV8A4hiykxnRB3Uv6d",
+    );
+}
+
+#[test]
+fn test_liquid_address_invalid() {
+    // Bitcoin addresses.
+    test_address_invalid(
+        CoinType::Liquid,
+        "bc1qazgc2zhu2kmy42py0vs8d7yff67l3zgpwfzlpk",
+    );
+    test_address_invalid(CoinType::Liquid, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2");
+    // Liquid testnet confidential address.
+    test_address_invalid(
+        CoinType::Liquid,
+        "tlq1qq2xvpcvfup5j8zscjq05u2wxxjcyewk7979f3mmz5l7uw5pqmx6xf5xy50hsn6vhkm5euwt72x878eq6zxx2z58hd7zrsg9qn",
+    );
+    // Elements regtest confidential address.
+    test_address_invalid(
+        CoinType::Liquid,
+        "el1qqw3e3mk4ng3ks43mh54udznuekaadh9lgwef3mwgzrfzakmdwcvqqve2xzutyaf7vjcap67f28q90uxec2ve95g3rpu5crapcmfr2l9xl5jzazvcpysz",
+    );
+    // Invalid checksum.
+    test_address_invalid(
+        CoinType::Liquid,
+        "lq1qqvxk052kf3qtkxmrakx50a9gc3smqad2ync54hzntjt980kfej9kkfe0247rp5h4yzmdftsahhw64uy8pzfe7cpg4fgykm7cw",
+    );
+}
+
+#[test]
+fn test_liquid_address_get_data() {
+    test_address_get_data(
+        CoinType::Liquid,
+        "ex1qyuh42lps6t6jpdk54cwmmhd27zrs3yulrc7t5a",
+        "272f557c30d2f520b6d4ae1dbdddaaf08708939f",
+    );
+    // The data of the unconfidential part.
+    test_address_get_data(
+        CoinType::Liquid,
+        "lq1qqvxk052kf3qtkxmrakx50a9gc3smqad2ync54hzntjt980kfej9kkfe0247rp5h4yzmdftsahhw64uy8pzfe7cpg4fgykm7cv",
+        "272f557c30d2f520b6d4ae1dbdddaaf08708939f",
+    );
+}
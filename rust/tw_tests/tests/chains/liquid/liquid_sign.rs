@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::common::bitcoin::{
+    dust_threshold, input, output, sign, ChainSpecific, RecipientType, TransactionOneof, DUST,
+    SIGHASH_ALL,
+};
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_keypair::ecdsa::secp256k1;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::BitcoinV2::Proto;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Liquid::Proto as LiquidProto;
+
+const ALICE_PRIVATE_KEY: &str = "e253373989199da27c48680e3a3fc0f648d50f9a727ef17a7fe6a4dc3b159129";
+const BOB_ADDRESS: &str = "ex1qazgc2zhu2kmy42py0vs8d7yff67l3zgpyql8vk";
+const BOB_CONFIDENTIAL_ADDRESS: &str = "lq1qqvxk052kf3qtkxmrakx50a9gc3smqad2ync54hzntjt980kfej9kkfe0247rp5h4yzmdftsahhw64uy8pzfe7cpg4fgykm7cv";
+const TXID: &str = "1f3e4cb7ab1d3b2e3fbbe8d2fc9037e5c9e4d0fae321f6a281d65460a29c2dc8";
+
+fn signing_input(to_recipient: RecipientType<'static>) -> Proto::SigningInput<'static> {
+    let alice_private_key = secp256k1::PrivateKey::try_from(ALICE_PRIVATE_KEY).unwrap();
+    let alice_pubkey = alice_private_key.public().compressed();
+
+    let tx1 = Proto::Input {
+        out_point: input::out_point(TXID, 1),
+        value: 100_000,
+        sighash_type: SIGHASH_ALL,
+        claiming_script: input::p2wpkh(alice_pubkey.to_vec()),
+        ..Default::default()
+    };
+
+    let out1 = Proto::Output {
+        value: 60_000,
+        to_recipient,
+    };
+    let change_output = Proto::Output {
+        value: 39_800,
+        to_recipient: output::p2wpkh(alice_pubkey.to_vec()),
+    };
+
+    let builder = Proto::TransactionBuilder {
+        version: Proto::TransactionVersion::V2,
+        inputs: vec![tx1],
+        outputs: vec![out1, change_output],
+        input_selector: Proto::InputSelector::UseAll,
+        dust_policy: dust_threshold(DUST),
+        ..Default::default()
+    };
+
+    Proto::SigningInput {
+        private_keys: vec![ALICE_PRIVATE_KEY.decode_hex().unwrap().into()],
+        transaction: TransactionOneof::builder(builder),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_liquid_sign_p2wpkh_explicit() {
+    let signing = signing_input(output::to_address(BOB_ADDRESS));
+
+    // The transaction has not been broadcasted, and the vector is produced by this implementation only.
+    // TODO replace with a transaction signed by an Elements node (`signrawtransactionwithkey`)
+    // or broadcasted to Liquid, so the encoding and the sighash are checked against the reference.
+    // The last output is the explicit fee output.
+    sign::BitcoinSignHelper::new(&signing)
+        .coin(CoinType::Liquid)
+        .sign(sign::Expected {
+            encoded: "020000000101c82d9ca26054d681a2f621e3fad0e4c9e53790fcd2e8bb3f2e3b1dabb74c3e1f0100000000ffffffff03016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f01000000000000ea6000160014e891850afc55b64aa8247b2076f8894ebdf88901016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f010000000000009b7800160014e311b8d6ddff856ce8e9a4e03bc6d4fe5050a83d016d521c38ec1ea15734ae22b7c46064412829c0d0579f0a713d1c04ede979026f0100000000000000c8000000000000000002483045022100ff14a5f751de555f7df6dc202d69c11008246a4ab41a33a696d8a409b49dab35022063f61f8126c14620f15e94ffcdf07e3bb76050df2b1f84314b301c276e9ce0e50121030f209b6ada5edb42c77fd2bc64ad650ae38314c8f451f3e36d80bc8e26f132cb00000000000000",
+            txid: "aafe8a6ecb30e74fa16eb2f9f98da42845c34d90f5721f501aafee1e46f728c0",
+            inputs: vec![100_000],
+            outputs: vec![60_000, 39_800, 200],
+            vsize: 258,
+            weight: 1029,
+            fee: 200,
+        });
+}
+
+#[test]
+fn test_liquid_sign_to_confidential_address_not_supported() {
+    let signing = signing_input(output::to_address(BOB_CONFIDENTIAL_ADDRESS));
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Liquid, signing);
+    assert_eq!(output.error, SigningError::Error_not_supported);
+}
+
+#[test]
+fn test_liquid_sign_non_policy_asset_not_supported() {
+    let mut signing = signing_input(output::to_address(BOB_ADDRESS));
+    // USDt on Liquid. The fee can be paid in L-BTC only.
+    let TransactionOneof::builder(ref mut builder) = signing.transaction else {
+        unreachable!()
+    };
+    builder.chain_specific =
+        ChainSpecific::liquid_extra_data(LiquidProto::TransactionBuilderExtraData {
+            asset_id: "ce091c998b83c78bb71a632313ba3760f1763d9cfcffae02258ffa9865a37bd2".into(),
+        });
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Liquid, signing);
+    assert_eq!(output.error, SigningError::Error_not_supported);
+}
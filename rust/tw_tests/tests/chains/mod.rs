@@ -3,9 +3,11 @@
 // Copyright © 2017 Trust Wallet.
 
 mod aptos;
+mod avalanche;
 mod binance;
 mod bitcoin;
 mod bitcoincash;
+mod cardano;
 mod common;
 mod cosmos;
 mod decred;
@@ -17,6 +19,8 @@ mod groestlcoin;
 mod internet_computer;
 mod komodo;
 mod kusama;
+mod liquid;
+mod nano;
 mod native_evmos;
 mod native_injective;
 mod pactus;
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+mod nano_address;
+mod nano_compile;
+mod nano_sign;
+
+const PRIVATE_KEY: &str = "173c40e97fe2afcd24187e74f6b603cb949a5365e72fbdd065a6b165e2189e34";
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::nano::PRIVATE_KEY;
+use tw_any_coin::test_utils::address_utils::{
+    test_address_derive, test_address_get_data, test_address_invalid, test_address_normalization,
+    test_address_valid, KeyType,
+};
+use tw_coin_registry::coin_type::CoinType;
+
+#[test]
+fn test_nano_address_derive() {
+    test_address_derive(
+        CoinType::Nano,
+        KeyType::PrivateKey(PRIVATE_KEY),
+        "nano_1bhbsc9yuh15anq3owu1izw1nk7bhhqefrkhfo954fyt8dk1q911buk1kk4c",
+    );
+}
+
+#[test]
+fn test_nano_address_normalization() {
+    test_address_normalization(
+        CoinType::Nano,
+        "xrb_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg",
+        "nano_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg",
+    );
+}
+
+#[test]
+fn test_nano_address_is_valid() {
+    test_address_valid(
+        CoinType::Nano,
+        "nano_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg",
+    );
+    test_address_valid(
+        CoinType::Nano,
+        "xrb_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg",
+    );
+    test_address_valid(
+        CoinType::Nano,
+        "nano_1111111111111111111111111111111111111111111111111111hifc8npp",
+    );
+}
+
+#[test]
+fn test_nano_address_invalid() {
+    test_address_invalid(CoinType::Nano, "");
+    test_address_invalid(
+        CoinType::Nano,
+        "xrb_1111111111111111111111111111111111111111111111111111hi111111",
+    );
+    test_address_invalid(
+        CoinType::Nano,
+        "nano1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg",
+    );
+    test_address_invalid(
+        CoinType::Nano,
+        "nano_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffe",
+    );
+}
+
+#[test]
+fn test_nano_address_get_data() {
+    test_address_get_data(
+        CoinType::Nano,
+        "nano_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg",
+        "5114aad86a390897d2a91b33b931b3a59a7df9e63eb3694f9430122f5622ae50",
+    );
+}
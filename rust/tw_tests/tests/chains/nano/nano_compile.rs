@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::nano::PRIVATE_KEY;
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_misc::traits::ToBytesVec;
+use tw_proto::Nano::Proto;
+use tw_proto::Nano::Proto::mod_SigningInput::OneOflink_oneof as LinkOneOf;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const BLOCK_HASH: &str = "f9a323153daefe041efb94d69b9669c882c935530ed953bbe8a665dfedda9696";
+const SIGNATURE: &str = "d247f6b90383b24e612569c75a12f11242f6e03b4914eadc7d941577dcf54a3a7cb7f0a4aba4246a40d9ebb5ee1e00b4a0a834ad5a1e7bef24e11f62b95a9e09";
+
+fn open_block_input(public_key: &[u8]) -> Proto::SigningInput<'static> {
+    Proto::SigningInput {
+        public_key: public_key.to_vec().into(),
+        link_oneof: LinkOneOf::link_block(
+            "491fca2c69a84607d374aaf1f6acd3ce70744c5be0721b5ed394653e85233507"
+                .decode_hex()
+                .unwrap()
+                .into(),
+        ),
+        representative: "xrb_3arg3asgtigae3xckabaaewkx3bzsh7nwz7jkmjos79ihyaxwphhm6qgjps4".into(),
+        balance: "96242336390000000000000000000".into(),
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_nano_compile() {
+    let key_pair = ed25519::blake2b::KeyPair::try_from(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public().to_vec();
+    let input = open_block_input(&public_key);
+
+    // Step 1: Obtain preimage hash
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Nano, &input);
+
+    assert_eq!(preimage_output.error, SigningErrorType::OK);
+    assert_eq!(preimage_output.data_hash.to_hex(), BLOCK_HASH);
+
+    // Step 2: Sign the block hash "externally"
+    let signature = key_pair
+        .sign(preimage_output.data.to_vec())
+        .expect("Error signing data")
+        .to_vec();
+    assert_eq!(signature.to_hex(), SIGNATURE);
+
+    // Step 3: Compile transaction info
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Nano, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.block_hash.to_hex(), BLOCK_HASH);
+    assert_eq!(output.signature.to_hex(), SIGNATURE);
+    assert!(output
+        .json
+        .contains(&format!(r#""signature":"{SIGNATURE}""#)));
+}
+
+#[test]
+fn test_nano_compile_invalid_signature() {
+    let key_pair = ed25519::blake2b::KeyPair::try_from(PRIVATE_KEY).unwrap();
+    let public_key = key_pair.public().to_vec();
+    let input = open_block_input(&public_key);
+
+    // The signature of another message.
+    let signature = key_pair.sign(b"hello".to_vec()).unwrap().to_vec();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Nano, &input, vec![signature], vec![public_key]);
+
+    assert_eq!(output.error, SigningErrorType::Error_signing);
+}
@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::nano::PRIVATE_KEY;
+use tw_any_coin::test_utils::sign_utils::AnySignerHelper;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_proto::Nano::Proto;
+use tw_proto::Nano::Proto::mod_SigningInput::OneOflink_oneof as LinkOneOf;
+
+#[test]
+fn test_nano_sign_open_block() {
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        link_oneof: LinkOneOf::link_block(
+            "491fca2c69a84607d374aaf1f6acd3ce70744c5be0721b5ed394653e85233507"
+                .decode_hex()
+                .unwrap()
+                .into(),
+        ),
+        representative: "xrb_3arg3asgtigae3xckabaaewkx3bzsh7nwz7jkmjos79ihyaxwphhm6qgjps4".into(),
+        balance: "96242336390000000000000000000".into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Nano, input);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(
+        output.block_hash.to_hex(),
+        "f9a323153daefe041efb94d69b9669c882c935530ed953bbe8a665dfedda9696"
+    );
+    assert_eq!(
+        output.signature.to_hex(),
+        "d247f6b90383b24e612569c75a12f11242f6e03b4914eadc7d941577dcf54a3a7cb7f0a4aba4246a40d9ebb5ee1e00b4a0a834ad5a1e7bef24e11f62b95a9e09"
+    );
+    assert_eq!(
+        output.json,
+        r#"{"account":"nano_1bhbsc9yuh15anq3owu1izw1nk7bhhqefrkhfo954fyt8dk1q911buk1kk4c","balance":"96242336390000000000000000000","link":"491fca2c69a84607d374aaf1f6acd3ce70744c5be0721b5ed394653e85233507","link_as_account":"nano_1kazsap8mc481zbqbcqjytpf9mmigj87qr5k5fhf97579t4k8fa94octjx6d","previous":"0000000000000000000000000000000000000000000000000000000000000000","representative":"nano_3arg3asgtigae3xckabaaewkx3bzsh7nwz7jkmjos79ihyaxwphhm6qgjps4","signature":"d247f6b90383b24e612569c75a12f11242f6e03b4914eadc7d941577dcf54a3a7cb7f0a4aba4246a40d9ebb5ee1e00b4a0a834ad5a1e7bef24e11f62b95a9e09","type":"state"}"#
+    );
+}
+
+#[test]
+fn test_nano_sign_change_block() {
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        parent_block: "f9a323153daefe041efb94d69b9669c882c935530ed953bbe8a665dfedda9696"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        representative: "xrb_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg".into(),
+        balance: "96242336390000000000000000000".into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Nano, input);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    // https://www.nanode.co/block/2568bf76336f7a415ca236dab97c1df9de951ca057a2e79df1322e647a259e7b
+    assert_eq!(
+        output.block_hash.to_hex(),
+        "2568bf76336f7a415ca236dab97c1df9de951ca057a2e79df1322e647a259e7b"
+    );
+    assert_eq!(
+        output.signature.to_hex(),
+        "3a0687542405163d5623808052042b3482360a82cc003d178a0c0d8bfbca86450975d0faec60ae5ac37feba9a8e2205c8540317b26f2c589c2a6578b03870403"
+    );
+}
+
+#[test]
+fn test_nano_sign_receive_block_with_work() {
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        parent_block: "2568bf76336f7a415ca236dab97c1df9de951ca057a2e79df1322e647a259e7b"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        link_oneof: LinkOneOf::link_block(
+            "d7384845d2ae530b45a5dd50ee50757f988329f652781767af3f1bc2322f52b9"
+                .decode_hex()
+                .unwrap()
+                .into(),
+        ),
+        representative: "xrb_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg".into(),
+        balance: "196242336390000000000000000000".into(),
+        work: "123456789".into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Nano, input);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(
+        output.block_hash.to_hex(),
+        "1ca240212838d053ecaa9dceee598c52a6080067edecaeede3319eb0b7db6525"
+    );
+    assert_eq!(
+        output.signature.to_hex(),
+        "e980d45365ae2fb291950019f7c19a3d5fa5df2736ca7e7ca1984338b4686976cb7efdda2894ddcea480f82645b50f2340c9d0fc69a05621bdc355783a21820d"
+    );
+    assert_eq!(
+        output.json,
+        r#"{"account":"nano_1bhbsc9yuh15anq3owu1izw1nk7bhhqefrkhfo954fyt8dk1q911buk1kk4c","balance":"196242336390000000000000000000","link":"d7384845d2ae530b45a5dd50ee50757f988329f652781767af3f1bc2322f52b9","link_as_account":"nano_3osrb34x7dkm3f4tdqcixsa9czwrienzenmr4xmtyhruras4ynosarg1sdiq","previous":"2568bf76336f7a415ca236dab97c1df9de951ca057a2e79df1322e647a259e7b","representative":"nano_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg","signature":"e980d45365ae2fb291950019f7c19a3d5fa5df2736ca7e7ca1984338b4686976cb7efdda2894ddcea480f82645b50f2340c9d0fc69a05621bdc355783a21820d","type":"state","work":"123456789"}"#
+    );
+}
+
+#[test]
+fn test_nano_sign_send_block() {
+    let input = Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        parent_block: "1ca240212838d053ecaa9dceee598c52a6080067edecaeede3319eb0b7db6525"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        link_oneof: LinkOneOf::link_recipient(
+            "xrb_3wm37qz19zhei7nzscjcopbrbnnachs4p1gnwo5oroi3qonw6inwgoeuufdp".into(),
+        ),
+        representative: "xrb_1nanode8ngaakzbck8smq6ru9bethqwyehomf79sae1k7xd47dkidjqzffeg".into(),
+        balance: "126242336390000000000000000000".into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::Nano, input);
+
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(
+        output.block_hash.to_hex(),
+        "32ac7d8f5a16a498abf203b8dfee623c9e111ff25e7339f8cd69ec7492b23edd"
+    );
+    assert_eq!(
+        output.signature.to_hex(),
+        "bcb806e140c9e2bc71c51ebbd941b4d99cee3d97fd50e3006eabc5e325c712662e2dc163ee32660875d67815ce4721e122389d2e64f1c9ad4555a9d3d8c33802"
+    );
+}
+
+#[test]
+fn test_nano_sign_invalid() {
+    let open_block = |link_oneof, representative: &'static str| Proto::SigningInput {
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        link_oneof,
+        representative: representative.into(),
+        balance: "96242336390000000000000000000".into(),
+        ..Proto::SigningInput::default()
+    };
+
+    // Missing link block.
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Nano,
+        open_block(
+            LinkOneOf::None,
+            "xrb_3arg3asgtigae3xckabaaewkx3bzsh7nwz7jkmjos79ihyaxwphhm6qgjps4",
+        ),
+    );
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    // Missing representative.
+    let link_block = "491fca2c69a84607d374aaf1f6acd3ce70744c5be0721b5ed394653e85233507"
+        .decode_hex()
+        .unwrap();
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Nano,
+        open_block(LinkOneOf::link_block(link_block.clone().into()), ""),
+    );
+    assert_ne!(output.error, SigningErrorType::OK);
+
+    // Invalid representative.
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Nano,
+        open_block(
+            LinkOneOf::link_block(link_block.clone().into()),
+            "xrb_4wm37qz19zhei7nzscjcopbrbnnachs4p1gnwo5oroi3qonw6inwgoeuufdp",
+        ),
+    );
+    assert_ne!(output.error, SigningErrorType::OK);
+
+    // The first block of an account can only receive funds, i.e. must have `link_block`.
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(
+        CoinType::Nano,
+        open_block(
+            LinkOneOf::link_recipient(
+                "xrb_3wm37qz19zhei7nzscjcopbrbnnachs4p1gnwo5oroi3qonw6inwgoeuufdp".into(),
+            ),
+            "xrb_3arg3asgtigae3xckabaaewkx3bzsh7nwz7jkmjos79ihyaxwphhm6qgjps4",
+        ),
+    );
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    // Missing, zero and malformed balances.
+    for balance in ["", "0", "1.2.3"] {
+        let input = Proto::SigningInput {
+            balance: balance.into(),
+            ..open_block(
+                LinkOneOf::link_block(link_block.clone().into()),
+                "xrb_3arg3asgtigae3xckabaaewkx3bzsh7nwz7jkmjos79ihyaxwphhm6qgjps4",
+            )
+        };
+        let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+        let output = signer.sign(CoinType::Nano, input);
+        assert_eq!(
+            output.error,
+            SigningErrorType::Error_invalid_params,
+            "balance: {balance}"
+        );
+    }
+}
@@ -4,4 +4,5 @@
 
 mod ripple_address;
 mod ripple_compile;
+mod ripple_multisign;
 mod ripple_sign;
@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::test_utils::sign_utils::{AnySignerHelper, CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_hash::H256;
+use tw_keypair::ecdsa::secp256k1;
+use tw_keypair::traits::SigningKeyTrait;
+use tw_misc::traits::ToBytesVec;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Ripple::Proto;
+use tw_proto::Ripple::Proto::mod_OperationPayment::OneOfamount_oneof as AmountType;
+use tw_proto::Ripple::Proto::mod_SigningInput::OneOfoperation_oneof as OperationType;
+use tw_proto::TxCompiler::Proto as CompilerProto;
+
+const ACCOUNT: &str = "rGV1v1xw23PHcRn4Km4tF8R2mfh6yTZkcP";
+const ACCOUNT_PRIVATE_KEY: &str =
+    "acf1bbf6264e699da0cc65d17ac03fcca6ded1522d19529df7762db46097ff9f";
+
+const SIGNER_PRIVATE_KEYS: [&str; 3] = [
+    "8753e78ee2963f301f82e5eeab2754f593fc242ce94273dd2fb0684e3b0f2b91",
+    "574e99f7946cfa2a6ca9368ca72fd37e42583cddb9ecc746aa4cb194ef4b2480",
+    "4ba5fd2ebf0f5d7e579b3c354c263ebb39cda4093845125786a280301af14e21",
+];
+const SIGNER_ACCOUNTS: [&str; 3] = [
+    "rnRkLPni2Q5yMxSqyJSJEkKUfQNFkaAspS",
+    "rDgEGKXWkHHr1HYq2ETnNAs9MdV4R8Gyt",
+    "raPAA61ca99bdwNiZs5JJukR5rvkHWvkBX",
+];
+const SIGNER_WEIGHTS: [u32; 3] = [1, 1, 2];
+
+fn signer_private_key(idx: usize) -> secp256k1::PrivateKey {
+    secp256k1::PrivateKey::try_from(SIGNER_PRIVATE_KEYS[idx]).unwrap()
+}
+
+fn multisigned_payment_input(signer_idx: usize) -> Proto::SigningInput<'static> {
+    let payment = Proto::OperationPayment {
+        amount_oneof: AmountType::amount(1000000),
+        destination: "rNLpgsBTCwiaZAnHe2ZViAN1GcXZtYW6rg".into(),
+        ..Proto::OperationPayment::default()
+    };
+    let public_key = signer_private_key(signer_idx).public();
+    Proto::SigningInput {
+        // The fee of a multi-signed transaction is `(N + 1) * base_fee`.
+        fee: 40,
+        sequence: 75_674_537,
+        last_ledger_sequence: 75_674_802,
+        account: ACCOUNT.into(),
+        public_key: public_key.compressed().to_vec().into(),
+        operation_oneof: OperationType::op_payment(payment),
+        multisign: true,
+        ..Proto::SigningInput::default()
+    }
+}
+
+#[test]
+fn test_ripple_sign_signer_list_set() {
+    let signer_entries = SIGNER_ACCOUNTS
+        .iter()
+        .zip(SIGNER_WEIGHTS)
+        .map(|(account, signer_weight)| Proto::SignerEntry {
+            account: (*account).into(),
+            signer_weight,
+        })
+        .collect();
+    let signer_list = Proto::OperationSignerListSet {
+        signer_quorum: 2,
+        signer_entries,
+    };
+    let input = Proto::SigningInput {
+        fee: 10,
+        sequence: 75_674_535,
+        last_ledger_sequence: 75_674_800,
+        account: ACCOUNT.into(),
+        private_key: ACCOUNT_PRIVATE_KEY.decode_hex().unwrap().into(),
+        operation_oneof: OperationType::op_signer_list_set(signer_list),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::XRP, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12000c2200000000240482b3a7201b0482b4b020230000000268400000000000000a7321027efc5f15071d2ae5e73ee09a0c17456c5d9170a41d67e3297c554829199be80b7447304502210088cd9080f2388a07357c6adef713c51ab2eadc7cf55599082cc427692dbbe584022054db84bfbde59dc6975d85da3ef8f5fb861dbd7146189a1adea556ce83aff0bc8114aa000c09c692ef1f82787e51e22833149941ea20f4eb1300018114308ea8e515b64f2e6616a33b42e1bbb9fa00bbd2e1eb13000181140265c09d122fab2a261a80ee59f1f4cd8fba8cf8e1eb13000281143b2fa4f36553e5b7a4f54ff9e6883e44b4b0dbb3e1f1"
+    );
+}
+
+#[test]
+fn test_ripple_sign_signer_list_delete() {
+    let signer_list = Proto::OperationSignerListSet {
+        signer_quorum: 0,
+        signer_entries: Vec::default(),
+    };
+    let input = Proto::SigningInput {
+        fee: 10,
+        sequence: 75_674_536,
+        last_ledger_sequence: 75_674_801,
+        account: ACCOUNT.into(),
+        private_key: ACCOUNT_PRIVATE_KEY.decode_hex().unwrap().into(),
+        operation_oneof: OperationType::op_signer_list_set(signer_list),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::XRP, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "12000c2200000000240482b3a8201b0482b4b120230000000068400000000000000a7321027efc5f15071d2ae5e73ee09a0c17456c5d9170a41d67e3297c554829199be80b74473045022100a9850ddda2511d5d124d80c28cb1bbc3b954f3ffa723a2121b4bd0460c162d7902207109c1cab5237f55b8ff0452f2486b79dd70e3c5581e3207cd3a593784aa99db8114aa000c09c692ef1f82787e51e22833149941ea20"
+    );
+}
+
+#[test]
+fn test_ripple_sign_signer_list_set_quorum_unreachable() {
+    let signer_list = Proto::OperationSignerListSet {
+        signer_quorum: 3,
+        signer_entries: vec![Proto::SignerEntry {
+            account: SIGNER_ACCOUNTS[0].into(),
+            signer_weight: 1,
+        }],
+    };
+    let input = Proto::SigningInput {
+        fee: 10,
+        sequence: 75_674_535,
+        last_ledger_sequence: 75_674_800,
+        account: ACCOUNT.into(),
+        private_key: ACCOUNT_PRIVATE_KEY.decode_hex().unwrap().into(),
+        operation_oneof: OperationType::op_signer_list_set(signer_list),
+        ..Proto::SigningInput::default()
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::XRP, input);
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
+
+#[test]
+fn test_ripple_multisign_payment() {
+    let input = Proto::SigningInput {
+        public_key: Default::default(),
+        private_key: SIGNER_PRIVATE_KEYS[0].decode_hex().unwrap().into(),
+        ..multisigned_payment_input(0)
+    };
+
+    let mut signer = AnySignerHelper::<Proto::SigningOutput>::default();
+    let output = signer.sign(CoinType::XRP, input);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "1200002200000000240482b3a9201b0482b4b26140000000000f424068400000000000002873008114aa000c09c692ef1f82787e51e22833149941ea2083149232ef60695add51f0f84534cc4084e4fdfc698ef3e010732103dc4a0dae2d550de7cace9c26c1a331a114e3e7efee5577204b476d27e2dc683a74473045022100ceeb769c2cb9396fcbe375ca4ae76a8fea9632c87227f34fe3aa434120d08de2022052a947f4e96aa27d41bc92a54d335753bd00ebe30beb7af1ca4071adec2b707d8114308ea8e515b64f2e6616a33b42e1bbb9fa00bbd2e1f1"
+    );
+}
+
+#[test]
+fn test_ripple_multisign_preimage_hashes() {
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::XRP, &multisigned_payment_input(0));
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(
+        preimage_output.data.to_hex(),
+        "534d54001200002200000000240482b3a9201b0482b4b26140000000000f424068400000000000002873008114aa000c09c692ef1f82787e51e22833149941ea2083149232ef60695add51f0f84534cc4084e4fdfc698e308ea8e515b64f2e6616a33b42e1bbb9fa00bbd2"
+    );
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "a3e2bb33370244f013a0635d34dfd74bd166c9b4166a34e2d08ae6c9a2852a73"
+    );
+
+    // Every multi-signer signs a different pre-image.
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::XRP, &multisigned_payment_input(1));
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        "16a2a189b2e36e9791367d0ed5c508ba508b711d61ae46a2a984fb8a2d7c5ed8"
+    );
+}
+
+#[test]
+fn test_ripple_multisign_compile() {
+    // Signers are given in the reverse order, but must be sorted by the account IDs.
+    let (signatures, public_keys) = [2, 0]
+        .into_iter()
+        .map(|idx| {
+            let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+            let preimage_output =
+                pre_imager.pre_image_hashes(CoinType::XRP, &multisigned_payment_input(idx));
+            assert_eq!(preimage_output.error, SigningError::OK);
+
+            // Simulate signature, normally obtained from signature server.
+            let private_key = signer_private_key(idx);
+            let data_hash = H256::try_from(preimage_output.data_hash.as_ref()).unwrap();
+            let signature = private_key.sign(data_hash).unwrap();
+            (
+                signature.to_vec(),
+                private_key.public().compressed().to_vec(),
+            )
+        })
+        .unzip();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::XRP,
+        &multisigned_payment_input(0),
+        signatures,
+        public_keys,
+    );
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+
+    assert_eq!(
+        output.encoded.to_hex(),
+        "1200002200000000240482b3a9201b0482b4b26140000000000f424068400000000000002873008114aa000c09c692ef1f82787e51e22833149941ea2083149232ef60695add51f0f84534cc4084e4fdfc698ef3e010732103dc4a0dae2d550de7cace9c26c1a331a114e3e7efee5577204b476d27e2dc683a74473045022100ceeb769c2cb9396fcbe375ca4ae76a8fea9632c87227f34fe3aa434120d08de2022052a947f4e96aa27d41bc92a54d335753bd00ebe30beb7af1ca4071adec2b707d8114308ea8e515b64f2e6616a33b42e1bbb9fa00bbd2e1e0107321020652a477b0cca8b74d6e68a6a386a836b226101617481b95180eaffbe841b32274473045022100eb8daa39cb256a9f853dd51686916d745f39439c74be29f631a691c5f0fcd83c02204e60d60f010f7303ca6240734cebba4686beca027fd25d39ccacb327b38027fa81143b2fa4f36553e5b7a4f54ff9e6883e44b4b0dbb3e1f1"
+    );
+}
+
+#[test]
+fn test_ripple_multisign_compile_invalid_signature() {
+    // Signature of the first signer pre-image is given along with the second signer public key.
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::XRP, &multisigned_payment_input(0));
+    let data_hash = H256::try_from(preimage_output.data_hash.as_ref()).unwrap();
+    let signature = signer_private_key(1).sign(data_hash).unwrap();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::XRP,
+        &multisigned_payment_input(0),
+        vec![signature.to_vec()],
+        vec![signer_private_key(1).public().compressed().to_vec()],
+    );
+    assert_eq!(output.error, SigningError::Error_signing);
+}
@@ -7,8 +7,9 @@ use tw_any_coin::ffi::tw_message_signer::{
 };
 use tw_coin_entry::error::prelude::SigningErrorType;
 use tw_coin_registry::coin_type::CoinType;
-use tw_encoding::hex::DecodeHex;
+use tw_encoding::hex::{DecodeHex, ToHex};
 use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::Solana::Proto::MessageType;
 use tw_proto::{deserialize, serialize, Solana, TxCompiler};
 
 #[test]
@@ -19,6 +20,7 @@ fn test_solana_message_signer_sign() {
             .unwrap()
             .into(),
         message: "Hello world".into(),
+        ..Solana::Proto::MessageSigningInput::default()
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
@@ -46,6 +48,7 @@ fn test_solana_message_signer_verify() {
             .into(),
         message: "Hello world".into(),
         signature: "2iBZ6zrQRKHcbD8NWmm552gU5vGvh1dk3XV4jxnyEdRKm8up8AeQk1GFr9pJokSmchw7i9gMtNyFBdDt8tBxM1cG".into(),
+        ..Solana::Proto::MessageVerifyingInput::default()
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
@@ -63,6 +66,7 @@ fn test_solana_message_signer_pre_image_hashes() {
             .unwrap()
             .into(),
         message: message.into(),
+        ..Solana::Proto::MessageSigningInput::default()
     };
 
     let input_data = TWDataHelper::create(serialize(&input).unwrap());
@@ -78,3 +82,105 @@ fn test_solana_message_signer_pre_image_hashes() {
     let actual_message = String::from_utf8(output.data.to_vec()).unwrap();
     assert_eq!(actual_message, message);
 }
+
+#[test]
+fn test_solana_message_signer_sign_offchain_v0() {
+    let input = Solana::Proto::MessageSigningInput {
+        private_key: "44f480ca27711895586074a14c552e58cc52e66a58edb6c58cf9b9b7295d4a2d"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        message: "Hello world".into(),
+        message_type: MessageType::MessageType_offchain_v0,
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(CoinType::Solana as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Solana::Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(
+        output.signature,
+        "5p1VapQVRdvkcZ962zMehpok7s9fU5YxecyK2tphSq4kFTY7TdaQ7xc9rA1ecDWWFPtmrnRsAegwkqmQehf5MzBc"
+    );
+}
+
+#[test]
+fn test_solana_message_signer_verify_offchain_v0() {
+    let input = Solana::Proto::MessageVerifyingInput {
+        public_key: "ee6d61a89fc8f9909585a996bb0d2b2ac69ae23b5acf39a19f32631239ba06f9"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        message: "Привет, мир".into(),
+        signature: "46tg3eYTXu5nZoYry5271tMFawpXJVYHLhbh3SHcSce2k5Dxj45EUKGLKW5teSW8cQukfwFJ3TZgu1bEqExzDx6Q".into(),
+        message_type: MessageType::MessageType_offchain_v0,
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let verified = unsafe { tw_message_signer_verify(CoinType::Solana as u32, input_data.ptr()) };
+    assert!(verified);
+
+    // The same signature is not valid for the raw message.
+    let input = Solana::Proto::MessageVerifyingInput {
+        message_type: MessageType::MessageType_raw,
+        ..input
+    };
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let verified = unsafe { tw_message_signer_verify(CoinType::Solana as u32, input_data.ptr()) };
+    assert!(!verified);
+}
+
+#[test]
+fn test_solana_message_signer_pre_image_hashes_offchain_v0() {
+    let input = Solana::Proto::MessageSigningInput {
+        private_key: "44f480ca27711895586074a14c552e58cc52e66a58edb6c58cf9b9b7295d4a2d"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        message: "Привет, мир".into(),
+        message_type: MessageType::MessageType_offchain_v0,
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_pre_image_hashes(CoinType::Solana as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_pre_image_hashes returned nullptr");
+
+    let output: TxCompiler::Proto::PreSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    // Signing domain, header version 0, `LimitedUtf8` format, length and the message.
+    assert_eq!(
+        output.data.to_hex(),
+        "ff736f6c616e61206f6666636861696e00011400d09fd180d0b8d0b2d0b5d1822c20d0bcd0b8d180"
+    );
+}
+
+#[test]
+fn test_solana_message_signer_sign_offchain_v0_empty() {
+    let input = Solana::Proto::MessageSigningInput {
+        private_key: "44f480ca27711895586074a14c552e58cc52e66a58edb6c58cf9b9b7295d4a2d"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        message: "".into(),
+        message_type: MessageType::MessageType_offchain_v0,
+    };
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_sign(CoinType::Solana as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_sign returned nullptr");
+
+    let output: Solana::Proto::MessageSigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+}
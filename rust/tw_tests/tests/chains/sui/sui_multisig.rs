@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::sui::test_cases::{transfer_d4ay9tdb, PRIVATE_KEY_54E80D76};
+use tw_any_coin::test_utils::sign_utils::{CompilerHelper, PreImageHelper};
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::ed25519;
+use tw_keypair::traits::{KeyPairTrait, SigningKeyTrait};
+use tw_misc::traits::ToBytesVec;
+use tw_proto::Common::Proto::SigningError;
+use tw_proto::Sui::Proto::{self, mod_SigningInput::OneOftransaction_payload as TransactionType};
+use tw_proto::TxCompiler::Proto as CompilerProto;
+use tw_sui::multisig::MultiSigPublicKey;
+
+const PRIVATE_KEYS: [&str; 3] = [
+    "ef92d3da0fc6c2112864fcb7b2ba1b743409264196388242c1936db259a4ea5a",
+    "cee0c00b4b5b6e0c0899a3f74dbc50426f5a9646c21de53575df41b4d220cb77",
+    "1c07ccfff08a6f25ce742966d3e2b3f467facd96e353a321d5be7ed0747ad927",
+];
+const WEIGHTS: [u32; 3] = [1, 1, 2];
+const THRESHOLD: u32 = 2;
+
+fn key_pair(idx: usize) -> ed25519::sha512::KeyPair {
+    ed25519::sha512::KeyPair::try_from(PRIVATE_KEYS[idx]).unwrap()
+}
+
+fn multisig_public_key() -> Proto::MultisigPublicKey<'static> {
+    let members = (0..PRIVATE_KEYS.len())
+        .map(|idx| Proto::MultisigMember {
+            public_key: key_pair(idx).public().to_vec().into(),
+            weight: WEIGHTS[idx],
+        })
+        .collect();
+    Proto::MultisigPublicKey {
+        members,
+        threshold: THRESHOLD,
+    }
+}
+
+fn direct_transfer_input() -> Proto::SigningInput<'static> {
+    let direct = Proto::SignDirect {
+        unsigned_tx_msg: transfer_d4ay9tdb::UNSIGNED_TX.into(),
+    };
+    Proto::SigningInput {
+        transaction_payload: TransactionType::sign_direct_message(direct),
+        multisig: Some(multisig_public_key()),
+        ..Proto::SigningInput::default()
+    }
+}
+
+/// Signs the transaction by the given multisig members and compiles it.
+fn compile_with_signers(signers: &[usize]) -> Proto::SigningOutput<'static> {
+    let input = direct_transfer_input();
+
+    let mut pre_imager = PreImageHelper::<CompilerProto::PreSigningOutput>::default();
+    let preimage_output = pre_imager.pre_image_hashes(CoinType::Sui, &input);
+    assert_eq!(preimage_output.error, SigningError::OK);
+    assert_eq!(
+        preimage_output.data_hash.to_hex(),
+        transfer_d4ay9tdb::TX_HASH
+    );
+
+    // Simulate remote co-signers.
+    let (signatures, public_keys) = signers
+        .iter()
+        .map(|idx| {
+            let key_pair = key_pair(*idx);
+            let signature = key_pair
+                .sign(preimage_output.data_hash.to_vec())
+                .unwrap()
+                .to_vec();
+            (signature, key_pair.public().to_vec())
+        })
+        .unzip();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(CoinType::Sui, &input, signatures, public_keys);
+    Proto::SigningOutput {
+        unsigned_tx: output.unsigned_tx.into_owned().into(),
+        signature: output.signature.into_owned().into(),
+        error: output.error,
+        error_message: output.error_message.into_owned().into(),
+    }
+}
+
+#[test]
+fn test_sui_multisig_address() {
+    let multisig = MultiSigPublicKey::from_proto(&multisig_public_key()).unwrap();
+    assert_eq!(
+        multisig.address().unwrap().to_string(),
+        "0xe53dbdcce4a2bc699e43b7f8580066feb28baa458423904b0536fae0847eedbe"
+    );
+}
+
+#[test]
+fn test_sui_multisig_compile_single_signer() {
+    // The third member weight reaches the threshold alone.
+    let output = compile_with_signers(&[2]);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.unsigned_tx, transfer_d4ay9tdb::UNSIGNED_TX);
+    assert_eq!(output.signature, "AwEA8PVM4eOKWeXOREI61zK3BcpLzIXx2FgyUM66IqYN6f1KsYw/ZG/Qtq5g8QTLDdOWsz/vZgMoLb27Toob56QJBAQAAwAZWB41by/tJ7X2iWHyfiSxsoZqBJdRNkpydBM/TCht7gEACOJfunp9lcX1ywmGEvqkOO+7Q3rIxnuO7wjIYMe0aDwBAAXSxzAnWolXjgASripzjPV3kV92dig1VWXBn4Cif2Q2AgIA");
+}
+
+#[test]
+fn test_sui_multisig_compile_unordered_signers() {
+    // Signatures are reordered by the member index.
+    let output = compile_with_signers(&[1, 0]);
+    assert_eq!(output.error, SigningError::OK, "{}", output.error_message);
+    assert_eq!(output.signature, "AwIA3boTxdr7ijtAYPY70LPMR4dVM0/OUN/J6+GNrBJmJZBMSGewxPgXDWTaySDCYRHBur+2abR/WW/jGEyLUdTRCQAmqu/vF2YTDd5FZRUCUxkIOfMQn0yXoQ+XqzzF+VfpYGv02frhZ8+KMNXf13+NjzziWDbFRgapL3+FLBy/p64FAwADABlYHjVvL+0ntfaJYfJ+JLGyhmoEl1E2SnJ0Ez9MKG3uAQAI4l+6en2VxfXLCYYS+qQ477tDesjGe47vCMhgx7RoPAEABdLHMCdaiVeOABKuKnOM9XeRX3Z2KDVVZcGfgKJ/ZDYCAgA=");
+}
+
+#[test]
+fn test_sui_multisig_compile_threshold_not_reached() {
+    let output = compile_with_signers(&[0]);
+    assert_eq!(output.error, SigningError::Error_signatures_count);
+}
+
+#[test]
+fn test_sui_multisig_compile_unknown_signer() {
+    let input = direct_transfer_input();
+    let outsider = ed25519::sha512::KeyPair::try_from(PRIVATE_KEY_54E80D76).unwrap();
+
+    let data_hash = transfer_d4ay9tdb::TX_HASH.decode_hex().unwrap();
+    let signature = outsider.sign(data_hash).unwrap().to_vec();
+
+    let mut compiler = CompilerHelper::<Proto::SigningOutput>::default();
+    let output = compiler.compile(
+        CoinType::Sui,
+        &input,
+        vec![signature],
+        vec![outsider.public().to_vec()],
+    );
+    assert_eq!(output.error, SigningError::Error_invalid_params);
+}
@@ -5,4 +5,5 @@
 mod test_cases;
 mod thorchain_address;
 mod thorchain_compile;
+mod thorchain_memo;
 mod thorchain_sign;
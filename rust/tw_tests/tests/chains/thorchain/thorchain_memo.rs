@@ -0,0 +1,395 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::chains::thorchain::test_cases::send_fd0445af::{signing_input, PRIVATE_KEY};
+use tw_any_coin::ffi::tw_any_signer::tw_any_signer_sign;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+use tw_proto::BitcoinV2::Proto as BitcoinProto;
+use tw_proto::Cosmos::Proto;
+use tw_proto::THORChainSwap::Proto as SwapProto;
+use tw_proto::{deserialize, serialize};
+use tw_thorchain::memo::{
+    op_return_output, set_cosmos_memo, AddLiquidityMemo, Affiliate, Asset, AssetKind, Memo,
+    Protocol, StreamingParams, SwapMemo, WithdrawLiquidityMemo,
+};
+use wallet_core_rs::ffi::thorchain::memo::tw_thorchain_swap_build_memo;
+use BitcoinProto::mod_Output::mod_OutputBuilder::OneOfvariant as OutputBuilderType;
+use BitcoinProto::mod_Output::OneOfto_recipient as RecipientType;
+
+const BTC_ADDRESS: &str = "bc1qpjult34k9spjfym8hss2jrwjgf0xjf40ze0pp8";
+const ETH_ADDRESS: &str = "0xb9f5771c27664bf2282d98e09d7f50cec7cb01a7";
+
+fn swap_memo(asset: &str, destination: &str) -> SwapMemo {
+    SwapMemo {
+        asset: asset.parse().unwrap(),
+        destination: destination.to_string(),
+        limit: None,
+        streaming: None,
+        affiliate: None,
+        extra: None,
+    }
+}
+
+fn affiliate(fee_bps: u16) -> Option<Affiliate> {
+    Some(Affiliate {
+        address: "t".to_string(),
+        fee_bps: Some(fee_bps),
+    })
+}
+
+fn build_memo(memo: SwapMemo) -> SigningResult<String> {
+    Memo::Swap(memo).build(Protocol::Thorchain, false)
+}
+
+#[test]
+fn test_thorchain_memo_parse_asset() {
+    let asset: Asset = "eth.usdt-0xdac17f958d2ee523a2206206994597c13d831ec7"
+        .parse()
+        .unwrap();
+    assert_eq!(asset.chain, "ETH");
+    assert_eq!(
+        asset.symbol,
+        "USDT-0XDAC17F958D2EE523A2206206994597C13D831EC7"
+    );
+    assert_eq!(asset.kind, AssetKind::Layer1);
+
+    let asset: Asset = "BTC/BTC".parse().unwrap();
+    assert_eq!(asset.kind, AssetKind::Synth);
+    assert_eq!(asset.to_string(), "BTC/BTC");
+
+    let asset: Asset = "BTC~BTC".parse().unwrap();
+    assert_eq!(asset.kind, AssetKind::Trade);
+
+    "BTC".parse::<Asset>().unwrap_err();
+}
+
+#[test]
+fn test_thorchain_memo_swap() {
+    let memo = build_memo(swap_memo("BTC.BTC", BTC_ADDRESS)).unwrap();
+    assert_eq!(memo, format!("SWAP:BTC.BTC:{BTC_ADDRESS}"));
+
+    let memo = build_memo(SwapMemo {
+        limit: Some(1_234_567),
+        affiliate: affiliate(50),
+        ..swap_memo("ETH.ETH", ETH_ADDRESS)
+    })
+    .unwrap();
+    assert_eq!(memo, format!("SWAP:ETH.ETH:{ETH_ADDRESS}:1234567:t:50"));
+
+    // The limit field is kept empty if only the affiliate is set.
+    let memo = build_memo(SwapMemo {
+        affiliate: affiliate(0),
+        ..swap_memo("ETH.ETH", ETH_ADDRESS)
+    })
+    .unwrap();
+    assert_eq!(memo, format!("SWAP:ETH.ETH:{ETH_ADDRESS}::t:0"));
+}
+
+#[test]
+fn test_thorchain_memo_streaming_swap_shortened() {
+    let memo = SwapMemo {
+        streaming: Some(StreamingParams {
+            interval: 1,
+            quantity: 0,
+        }),
+        affiliate: affiliate(10),
+        ..swap_memo("BTC.BTC", BTC_ADDRESS)
+    };
+    let memo = Memo::Swap(memo).build(Protocol::Thorchain, true).unwrap();
+    assert_eq!(memo, format!("=:BTC.BTC:{BTC_ADDRESS}:0/1/0:t:10"));
+}
+
+#[test]
+fn test_thorchain_memo_liquidity() {
+    let add = AddLiquidityMemo {
+        pool: "BTC.BTC".parse().unwrap(),
+        paired_address: Some("thor1z53wwe7md6cewz9sqwqzn0aavpaun0gw0exn2r".to_string()),
+        affiliate: None,
+    };
+    let memo = Memo::AddLiquidity(add)
+        .build(Protocol::Thorchain, false)
+        .unwrap();
+    assert_eq!(
+        memo,
+        "ADD:BTC.BTC:thor1z53wwe7md6cewz9sqwqzn0aavpaun0gw0exn2r"
+    );
+
+    let add = AddLiquidityMemo {
+        pool: "BTC.BTC".parse().unwrap(),
+        paired_address: None,
+        affiliate: affiliate(25),
+    };
+    let memo = Memo::AddLiquidity(add)
+        .build(Protocol::Thorchain, true)
+        .unwrap();
+    assert_eq!(memo, "+:BTC.BTC::t:25");
+
+    let withdraw = WithdrawLiquidityMemo {
+        pool: "BTC.BTC".parse().unwrap(),
+        basis_points: 10_000,
+        asset: Some("THOR.RUNE".parse().unwrap()),
+    };
+    let memo = Memo::WithdrawLiquidity(withdraw)
+        .build(Protocol::Thorchain, false)
+        .unwrap();
+    assert_eq!(memo, "WITHDRAW:BTC.BTC:10000:THOR.RUNE");
+}
+
+#[test]
+fn test_mayachain_memo_swap() {
+    let memo = Memo::Swap(swap_memo(
+        "MAYA.CACAO",
+        "maya1z53wwe7md6cewz9sqwqzn0aavpaun0gw0exn2r",
+    ))
+    .build(Protocol::Mayachain, true)
+    .unwrap();
+    assert_eq!(
+        memo,
+        "=:MAYA.CACAO:maya1z53wwe7md6cewz9sqwqzn0aavpaun0gw0exn2r"
+    );
+
+    // `DASH` is not supported by THORChain.
+    let err = Memo::Swap(swap_memo("DASH.DASH", "XdAUmwtig27HBG6WfYyHAzP8n6XC9jESEw"))
+        .build(Protocol::Thorchain, true)
+        .unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_params);
+}
+
+#[test]
+fn test_thorchain_memo_invalid() {
+    // A separator in the destination address would shift the other fields.
+    let err = build_memo(swap_memo("BTC.BTC", "bitcoincash:qq")).unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_address);
+
+    let err = build_memo(swap_memo("BTC.BTC", "")).unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_address);
+
+    let err = build_memo(swap_memo("BTC.BT:C", BTC_ADDRESS)).unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_params);
+
+    let err = build_memo(SwapMemo {
+        affiliate: affiliate(1_001),
+        ..swap_memo("BTC.BTC", BTC_ADDRESS)
+    })
+    .unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_params);
+
+    let err = build_memo(SwapMemo {
+        streaming: Some(StreamingParams {
+            interval: 0,
+            quantity: 10,
+        }),
+        ..swap_memo("BTC.BTC", BTC_ADDRESS)
+    })
+    .unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_params);
+
+    // Synths don't have liquidity pools.
+    let add = AddLiquidityMemo {
+        pool: "BTC/BTC".parse().unwrap(),
+        paired_address: None,
+        affiliate: None,
+    };
+    Memo::AddLiquidity(add)
+        .build(Protocol::Thorchain, false)
+        .unwrap_err();
+
+    let withdraw = WithdrawLiquidityMemo {
+        pool: "BTC.BTC".parse().unwrap(),
+        basis_points: 0,
+        asset: None,
+    };
+    Memo::WithdrawLiquidity(withdraw)
+        .build(Protocol::Thorchain, false)
+        .unwrap_err();
+}
+
+#[test]
+fn test_thorchain_memo_op_return() {
+    let memo = SwapMemo {
+        limit: Some(1_234_567),
+        ..swap_memo("ETH.ETH", ETH_ADDRESS)
+    };
+    let memo = Memo::Swap(memo).build(Protocol::Thorchain, true).unwrap();
+
+    let output = op_return_output(&memo).unwrap();
+    assert_eq!(output.value, 0);
+    let RecipientType::builder(builder) = output.to_recipient else {
+        panic!("Expected an output builder");
+    };
+    let OutputBuilderType::op_return(data) = builder.variant else {
+        panic!("Expected an OP_RETURN output");
+    };
+    assert_eq!(data.as_ref(), memo.as_bytes());
+
+    // The full `SWAP` memo with an affiliate doesn't fit into `OP_RETURN`.
+    let memo = SwapMemo {
+        limit: Some(1_234_567),
+        affiliate: affiliate(50),
+        ..swap_memo(
+            "ETH.USDT-0XDAC17F958D2EE523A2206206994597C13D831EC7",
+            ETH_ADDRESS,
+        )
+    };
+    let memo = Memo::Swap(memo).build(Protocol::Thorchain, false).unwrap();
+    let err = op_return_output(&memo).unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_memo);
+}
+
+#[test]
+fn test_thorchain_memo_sign_native() {
+    let memo = Memo::Swap(swap_memo("BTC.BTC", BTC_ADDRESS))
+        .build(Protocol::Thorchain, true)
+        .unwrap();
+
+    let mut input = Proto::SigningInput {
+        signing_mode: Proto::SigningMode::JSON,
+        private_key: PRIVATE_KEY.decode_hex().unwrap().into(),
+        ..signing_input()
+    };
+    set_cosmos_memo(&mut input, &memo).unwrap();
+
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_any_signer_sign(input_data.ptr(), CoinType::THORChain as u32)
+    })
+    .to_vec()
+    .expect("!tw_any_signer_sign returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output
+        .json
+        .contains(&format!(r#""memo":"=:BTC.BTC:{BTC_ADDRESS}""#)));
+
+    let err = set_cosmos_memo(&mut input, &"=".repeat(251)).unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_memo);
+}
+
+fn swap_input_memo(input: SwapProto::SwapInput<'_>) -> Option<String> {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    TWStringHelper::wrap(unsafe { tw_thorchain_swap_build_memo(input_data.ptr(), true) })
+        .to_string()
+}
+
+fn swap_input(chain: SwapProto::Chain, symbol: &'static str) -> SwapProto::SwapInput<'static> {
+    SwapProto::SwapInput {
+        to_asset: Some(SwapProto::Asset {
+            chain,
+            symbol: symbol.into(),
+            token_id: "".into(),
+        }),
+        to_address: "btc123".into(),
+        to_amount_limit: "1234".into(),
+        ..SwapProto::SwapInput::default()
+    }
+}
+
+#[test]
+fn test_thorchain_memo_from_swap_input() {
+    let input = swap_input(SwapProto::Chain::BTC, "BTC");
+    assert_eq!(
+        swap_input_memo(input.clone()).unwrap(),
+        "=:BTC.BTC:btc123:1234"
+    );
+
+    let input = SwapProto::SwapInput {
+        affiliate_fee_address: "feeaddr".into(),
+        ..input
+    };
+    assert_eq!(
+        swap_input_memo(input.clone()).unwrap(),
+        "=:BTC.BTC:btc123:1234:feeaddr"
+    );
+
+    let input = SwapProto::SwapInput {
+        affiliate_fee_rate_bp: "10".into(),
+        ..input
+    };
+    assert_eq!(
+        swap_input_memo(input.clone()).unwrap(),
+        "=:BTC.BTC:btc123:1234:feeaddr:10"
+    );
+
+    let extra = SwapProto::SwapInput {
+        extra_memo: "extramemo".into(),
+        ..input.clone()
+    };
+    assert_eq!(
+        swap_input_memo(extra).unwrap(),
+        "=:BTC.BTC:btc123:1234:feeaddr:10:extramemo"
+    );
+
+    // The extra memo is kept at its position without an affiliate.
+    let extra = SwapProto::SwapInput {
+        affiliate_fee_address: "".into(),
+        affiliate_fee_rate_bp: "".into(),
+        extra_memo: "extramemo".into(),
+        ..input.clone()
+    };
+    assert_eq!(
+        swap_input_memo(extra).unwrap(),
+        "=:BTC.BTC:btc123:1234:::extramemo"
+    );
+
+    let streaming = SwapProto::SwapInput {
+        to_amount_limit: "".into(),
+        stream_params: Some(SwapProto::StreamParams {
+            interval: "".into(),
+            quantity: "".into(),
+        }),
+        ..swap_input(SwapProto::Chain::BNB, "TWT-8C2")
+    };
+    assert_eq!(
+        swap_input_memo(streaming).unwrap(),
+        "=:BNB.TWT-8C2:btc123:0/1/0"
+    );
+}
+
+#[test]
+fn test_thorchain_memo_from_swap_input_token_id() {
+    let mut input = swap_input(SwapProto::Chain::ETH, "ETH");
+    input.to_address = "0xaabbccdd".into();
+    input.to_asset.as_mut().unwrap().token_id = "0x0000000000000000000000000000000000000000".into();
+    assert_eq!(
+        swap_input_memo(input.clone()).unwrap(),
+        "=:ETH.ETH:0xaabbccdd:1234"
+    );
+
+    input.to_asset.as_mut().unwrap().token_id = "0x4B0F1812e5Df2A09796481Ff14017e6005508003".into();
+    assert_eq!(
+        swap_input_memo(input).unwrap(),
+        "=:ETH.0x4B0F1812e5Df2A09796481Ff14017e6005508003:0xaabbccdd:1234"
+    );
+
+    // Cosmos Hub is named `GAIA` by THORChain.
+    let input = swap_input(SwapProto::Chain::ATOM, "ATOM");
+    assert_eq!(swap_input_memo(input).unwrap(), "=:GAIA.ATOM:btc123:1234");
+}
+
+#[test]
+fn test_thorchain_memo_from_swap_input_invalid() {
+    // An affiliate fee without an affiliate address.
+    let input = SwapProto::SwapInput {
+        affiliate_fee_rate_bp: "10".into(),
+        ..swap_input(SwapProto::Chain::BTC, "BTC")
+    };
+    assert_eq!(swap_input_memo(input), None);
+
+    let input = SwapProto::SwapInput {
+        to_amount_limit: "1.5".into(),
+        ..swap_input(SwapProto::Chain::BTC, "BTC")
+    };
+    assert_eq!(swap_input_memo(input), None);
+
+    let input = SwapProto::SwapInput {
+        to_asset: None,
+        ..swap_input(SwapProto::Chain::BTC, "BTC")
+    };
+    assert_eq!(swap_input_memo(input), None);
+}
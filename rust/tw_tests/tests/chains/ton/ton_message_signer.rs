@@ -2,9 +2,12 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+use tw_keypair::ffi::privkey::tw_private_key_disable_export;
 use tw_keypair::test_utils::tw_private_key_helper::TWPrivateKeyHelper;
 use tw_memory::test_utils::tw_string_helper::TWStringHelper;
-use wallet_core_rs::ffi::ton::message_signer::tw_ton_message_signer_sign_message;
+use wallet_core_rs::ffi::ton::message_signer::{
+    tw_ton_message_signer_sign_message, tw_ton_message_signer_sign_ton_proof,
+};
 
 #[test]
 fn test_ton_wallet_create_state_init() {
@@ -20,3 +23,86 @@ fn test_ton_wallet_create_state_init() {
     assert!(!signature.ptr().is_null());
     assert_eq!(signature.to_string().unwrap(), "2490fbaa72aec0b77b19162bbbe0b0e3f7afd42cc9ef469f0494cd4a366a4bf76643300cd5991f66bce6006336742b8d1d435d541d244dcc013d428472e89504");
 }
+
+#[test]
+fn test_ton_message_signer_sign_ton_proof() {
+    let private_key = TWPrivateKeyHelper::with_hex(
+        "112d4e2e700a468f1eae699329202f1ee671d6b665caa2d92dea038cf3868c18",
+    );
+    assert!(!private_key.is_null());
+    let address = TWStringHelper::create(
+        "0:2d29bfa071c8c62fa3398b661a842e60f04cb8a915fb3e749ef7c6c41343e16c",
+    );
+    let domain = TWStringHelper::create("ton-connect.github.io");
+    let payload = TWStringHelper::create("gems-ton-proof-payload");
+
+    let signature = TWStringHelper::wrap(unsafe {
+        tw_ton_message_signer_sign_ton_proof(
+            private_key.ptr(),
+            address.ptr(),
+            domain.ptr(),
+            1733270400,
+            payload.ptr(),
+        )
+    });
+    assert!(!signature.ptr().is_null());
+    assert_eq!(
+        signature.to_string().unwrap(),
+        "HKocvQFtJya4A+EBAgO/ECNRQvnpsRgfiRK1FTSZ7ys+80EIHzJxHI1oqWGFJ8Ywohry5vekNOxnEdpw9XaKAQ=="
+    );
+}
+
+#[test]
+fn test_ton_message_signer_sign_non_exportable_key() {
+    let private_key = TWPrivateKeyHelper::with_hex(
+        "112d4e2e700a468f1eae699329202f1ee671d6b665caa2d92dea038cf3868c18",
+    );
+    unsafe { tw_private_key_disable_export(private_key.ptr()) };
+    let message = TWStringHelper::create("Hello world");
+
+    let signature = TWStringHelper::wrap(unsafe {
+        tw_ton_message_signer_sign_message(private_key.ptr(), message.ptr())
+    });
+    assert_eq!(signature.to_string().unwrap(), "2490fbaa72aec0b77b19162bbbe0b0e3f7afd42cc9ef469f0494cd4a366a4bf76643300cd5991f66bce6006336742b8d1d435d541d244dcc013d428472e89504");
+
+    let address = TWStringHelper::create(
+        "0:2d29bfa071c8c62fa3398b661a842e60f04cb8a915fb3e749ef7c6c41343e16c",
+    );
+    let domain = TWStringHelper::create("ton-connect.github.io");
+    let payload = TWStringHelper::create("gems-ton-proof-payload");
+
+    let signature = TWStringHelper::wrap(unsafe {
+        tw_ton_message_signer_sign_ton_proof(
+            private_key.ptr(),
+            address.ptr(),
+            domain.ptr(),
+            1733270400,
+            payload.ptr(),
+        )
+    });
+    assert_eq!(
+        signature.to_string().unwrap(),
+        "HKocvQFtJya4A+EBAgO/ECNRQvnpsRgfiRK1FTSZ7ys+80EIHzJxHI1oqWGFJ8Ywohry5vekNOxnEdpw9XaKAQ=="
+    );
+}
+
+#[test]
+fn test_ton_message_signer_sign_ton_proof_invalid_address() {
+    let private_key = TWPrivateKeyHelper::with_hex(
+        "112d4e2e700a468f1eae699329202f1ee671d6b665caa2d92dea038cf3868c18",
+    );
+    let address = TWStringHelper::create("EQ_invalid");
+    let domain = TWStringHelper::create("ton-connect.github.io");
+    let payload = TWStringHelper::create("gems-ton-proof-payload");
+
+    let signature = TWStringHelper::wrap(unsafe {
+        tw_ton_message_signer_sign_ton_proof(
+            private_key.ptr(),
+            address.ptr(),
+            domain.ptr(),
+            1733270400,
+            payload.ptr(),
+        )
+    });
+    assert!(signature.ptr().is_null());
+}
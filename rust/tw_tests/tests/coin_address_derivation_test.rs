@@ -165,6 +165,9 @@ fn test_coin_address_derivation() {
             CoinType::XRP => "r9cwJ8hM13jodBBGtioB44FUZ5HwWGwqfX",
             CoinType::Groestlcoin => "grs1qten42eesehw0ktddcp0fws7d3ycsqez35034a2",
             CoinType::Decred => "DsbEmWV6ZZBsUJY2vVi5u7H62GUfBFPBfoF",
+            CoinType::Liquid => "ex1qten42eesehw0ktddcp0fws7d3ycsqez3rhsvft",
+            CoinType::AvalancheXChain => "X-avax1ten42eesehw0ktddcp0fws7d3ycsqez32x7kem",
+            CoinType::AvalanchePChain => "P-avax1ten42eesehw0ktddcp0fws7d3ycsqez32x7kem",
             // end_of_coin_address_derivation_tests_marker_do_not_modify
             _ => panic!("{:?} must be covered", coin),
         };
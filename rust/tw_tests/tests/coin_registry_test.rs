@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_registry::blockchain_type::BlockchainType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::registry::{coin_items_by_blockchain, get_coin_item, supported_coin_items};
+use tw_keypair::tw::{Curve, PublicKeyType};
+
+#[test]
+fn test_coin_registry_metadata() {
+    let bitcoin = get_coin_item(CoinType::Bitcoin).unwrap();
+    assert_eq!(bitcoin.slip44(), 0);
+    assert_eq!(bitcoin.curve, Curve::Secp256k1);
+    assert_eq!(bitcoin.public_key_type, PublicKeyType::Secp256k1);
+    assert_eq!(bitcoin.hrp.as_deref(), Some("bc"));
+    assert_eq!(bitcoin.p2pkh_prefix, Some(0));
+    assert_eq!(bitcoin.p2sh_prefix, Some(5));
+
+    let default = bitcoin.default_derivation().unwrap();
+    assert_eq!(default.path.to_string(), "m/84'/0'/0'/0/0");
+    let legacy = bitcoin.derivation(Derivation::Legacy).unwrap();
+    assert_eq!(legacy.path.to_string(), "m/44'/0'/0'/0/0");
+
+    let solana = get_coin_item(CoinType::Solana).unwrap();
+    assert_eq!(solana.curve, Curve::Ed25519);
+    assert!(solana.derivation(Derivation::Taproot).is_none());
+}
+
+#[test]
+fn test_coin_registry_slip44() {
+    let smart_chain = get_coin_item(CoinType::SmartChain).unwrap();
+    assert_eq!(smart_chain.slip44(), 714);
+    assert_ne!(smart_chain.coin_id as u32, smart_chain.slip44());
+
+    let ethereum = get_coin_item(CoinType::Ethereum).unwrap();
+    assert_eq!(ethereum.slip44(), 60);
+}
+
+#[test]
+fn test_coin_registry_default_derivations() {
+    for item in supported_coin_items() {
+        assert!(
+            item.default_derivation().is_some(),
+            "'{}' has no default derivation",
+            item.id
+        );
+    }
+}
+
+#[test]
+fn test_coin_registry_cosmos_fallback_derivations() {
+    let cosmos_118 = "m/44'/118'/0'/0/0";
+    let blockchains = [
+        BlockchainType::Cosmos,
+        BlockchainType::NativeEvmos,
+        BlockchainType::NativeInjective,
+        BlockchainType::Thorchain,
+    ];
+
+    for item in blockchains.into_iter().flat_map(coin_items_by_blockchain) {
+        let default = item.default_derivation().unwrap().path.to_string();
+        if default == cosmos_118 {
+            continue;
+        }
+        let fallback = item
+            .derivation(Derivation::Fallback)
+            .unwrap_or_else(|| panic!("'{}' has no fallback derivation", item.id));
+        assert_eq!(fallback.path.to_string(), cosmos_118);
+    }
+}
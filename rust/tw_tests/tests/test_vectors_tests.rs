@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::path::PathBuf;
+use tw_any_coin::test_utils::test_vectors::TestVectors;
+
+/// Runs every JSON fixture of the `tests/vectors` directory.
+/// See [`tw_any_coin::test_utils::test_vectors`] for the schema.
+#[test]
+fn test_json_test_vectors() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("vectors");
+    let fixtures = TestVectors::run_dir(&dir);
+    assert!(fixtures > 0, "No test vectors found in {}", dir.display());
+}
+
+#[test]
+#[should_panic]
+fn test_json_test_vectors_mismatch() {
+    let json = r#"{
+        "source": "test",
+        "curves": [{
+            "curve": "ed25519",
+            "private_key": "8dd6ef33da06e96c722b1713d8fc37c433757f0a8e0473298e3b47626d7b6484",
+            "public_key": "0000000000000000000000000000000000000000000000000000000000000000"
+        }]
+    }"#;
+    TestVectors::from_json(json).run("mismatch");
+}
+
+#[test]
+#[should_panic(expected = "unknown signing scheme 'Eip-191'")]
+fn test_json_test_vectors_unknown_scheme() {
+    let json = r#"{
+        "source": "test",
+        "coins": [{
+            "coin": "ethereum",
+            "private_key": "03a9ca895dca1623c7dfd69693f7b4111f5d819d2e145536e0b03c136025a25d",
+            "scheme": "Eip-191",
+            "message": "Foo"
+        }]
+    }"#;
+    TestVectors::from_json(json).run("unknown scheme");
+}
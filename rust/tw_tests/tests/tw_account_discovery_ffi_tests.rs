@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_any_coin::ffi::tw_account_discovery::tw_account_discovery_scan;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::tw_derivation::TWDerivation;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_proto::AccountDiscovery::Proto;
+use tw_proto::{deserialize, serialize};
+
+/// `m/84'/0'/0'` of the "ripple scissors kick mammal hire column oak again sun offer wealth tomorrow wagon turn fatal" wallet.
+const ZPUB: &str = "zpub6rNUNtxSa9Gxvm4Bdxf1MPMwrvkzwDx6vP96Hkzw3jiQKdg3fhXBStxjn12YixQB8h88B3RMSRscRstf9AEVaYr3MAqVBEWBDuEJU4PGaT9";
+
+/// Returns the serialized `ScanOutput`.
+fn scan(input: Proto::ScanInput) -> Vec<u8> {
+    let input = TWDataHelper::create(serialize(&input).unwrap());
+    TWDataHelper::wrap(unsafe { tw_account_discovery_scan(input.ptr()) })
+        .to_vec()
+        .expect("!tw_account_discovery_scan returned nullptr")
+}
+
+fn bitcoin_input(
+    external: Proto::ChainActivity,
+    internal: Proto::ChainActivity,
+) -> Proto::ScanInput<'static> {
+    Proto::ScanInput {
+        coin: CoinType::Bitcoin as u32,
+        derivation: TWDerivation::BitcoinSegwit as u32,
+        account: 0,
+        extended_public_key: ZPUB.into(),
+        gap_limit: 3,
+        scan_internal: true,
+        external: Some(external),
+        internal: Some(internal),
+    }
+}
+
+fn activity(checked: u32, used: Vec<u32>) -> Proto::ChainActivity {
+    Proto::ChainActivity { checked, used }
+}
+
+fn pending(chain: Proto::Chain, index: u32, address: &str) -> Proto::PendingAddress<'static> {
+    let chain_index = chain as u32;
+    Proto::PendingAddress {
+        chain,
+        index,
+        derivation_path: Cow::from(format!("m/84'/0'/0'/{chain_index}/{index}")),
+        address: Cow::from(address.to_string()),
+    }
+}
+
+#[test]
+fn test_account_discovery_scan_steps() {
+    use Proto::Chain::{External, Internal};
+
+    // The first step returns the first `gap_limit` addresses of both chains.
+    let output = scan(bitcoin_input(
+        Proto::ChainActivity::default(),
+        Proto::ChainActivity::default(),
+    ));
+    let output: Proto::ScanOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(
+        output.pending,
+        [
+            pending(External, 0, "bc1qpsp72plnsqe6e2dvtsetxtww2cz36ztmfxghpd"),
+            pending(External, 1, "bc1qm7td3gmpsddct487a54wqylml0t8q6np64k8za"),
+            pending(External, 2, "bc1q7zddsunzaftf4zlsg9exhzlkvc5374a6v32jf6"),
+            pending(Internal, 0, "bc1qx3hncnu3lknuejrmzse4nf2yjwpaskgn6tpnve"),
+            pending(Internal, 1, "bc1q2musnpmapynlze7lsq4jva8qf58acgkmd4txhs"),
+            pending(Internal, 2, "bc1qv6xkhv95n7tcygr9etdrstg9jf788ayjmlfj34"),
+        ]
+    );
+    assert!(!output.has_activity);
+
+    // `.../0/2` is used, so 3 more receiving addresses are checked. The change chain is complete.
+    let output = scan(bitcoin_input(activity(3, vec![2]), activity(3, vec![])));
+    let output: Proto::ScanOutput = deserialize(&output).unwrap();
+    assert_eq!(
+        output.pending,
+        [
+            pending(External, 3, "bc1qkre4gltumuvma4stqnqv3hcxl33xwum2gjz7nt"),
+            pending(External, 4, "bc1qs2lww9k08d0ym9p6karrhjukdqy0546vtcsvma"),
+            pending(External, 5, "bc1qz5tgrjxzfzh6mq9w5lf96ufk0xum8cahgfxwk0"),
+        ]
+    );
+    assert!(output.has_activity);
+
+    let output = scan(bitcoin_input(activity(6, vec![2]), activity(3, vec![])));
+    let output: Proto::ScanOutput = deserialize(&output).unwrap();
+    assert!(output.pending.is_empty());
+    assert!(output.has_activity);
+    assert_eq!(output.next_external_index, 3);
+    assert_eq!(output.next_internal_index, 0);
+}
+
+#[test]
+fn test_account_discovery_scan_empty_account() {
+    let output = scan(bitcoin_input(activity(3, vec![]), activity(3, vec![])));
+    let output: Proto::ScanOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.pending.is_empty());
+    assert!(!output.has_activity);
+}
+
+#[test]
+fn test_account_discovery_scan_external_only() {
+    let input = Proto::ScanInput {
+        scan_internal: false,
+        ..bitcoin_input(Proto::ChainActivity::default(), activity(0, vec![]))
+    };
+    let output = scan(input);
+    let output: Proto::ScanOutput = deserialize(&output).unwrap();
+    assert_eq!(output.pending.len(), 3);
+    assert!(output
+        .pending
+        .iter()
+        .all(|address| address.chain == Proto::Chain::External));
+}
+
+#[test]
+fn test_account_discovery_scan_invalid() {
+    // A used address that hasn't been checked.
+    let output = scan(bitcoin_input(activity(3, vec![3]), activity(0, vec![])));
+    let output: Proto::ScanOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    let output = scan(Proto::ScanInput {
+        extended_public_key: "zpub".into(),
+        ..bitcoin_input(activity(0, vec![]), activity(0, vec![]))
+    });
+    let output: Proto::ScanOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    let output = scan(Proto::ScanInput {
+        account: 1 << 31,
+        ..bitcoin_input(activity(0, vec![]), activity(0, vec![]))
+    });
+    let output: Proto::ScanOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    // Ed25519 keys can't derive non-hardened children.
+    let output = scan(Proto::ScanInput {
+        coin: CoinType::Solana as u32,
+        derivation: TWDerivation::Default as u32,
+        ..bitcoin_input(activity(0, vec![]), activity(0, vec![]))
+    });
+    let output: Proto::ScanOutput = deserialize(&output).unwrap();
+    assert_eq!(output.error, SigningErrorType::Error_not_supported);
+}
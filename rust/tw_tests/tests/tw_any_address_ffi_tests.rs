@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_any_address::tw_any_address_validate;
+use tw_coin_registry::coin_type::CoinType;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+use tw_proto::deserialize;
+use tw_proto::AddressValidation::Proto;
+
+/// Returns `(valid, normalized, network, detected_coins, error)`.
+fn validate(
+    coin: CoinType,
+    address: &str,
+) -> (
+    bool,
+    String,
+    Proto::Network,
+    Vec<u32>,
+    Proto::ValidationError,
+) {
+    let address = TWStringHelper::create(address);
+    let result_data =
+        TWDataHelper::wrap(unsafe { tw_any_address_validate(address.ptr(), coin as u32) })
+            .to_vec()
+            .expect("!tw_any_address_validate returned nullptr");
+    let result: Proto::ValidationResult = deserialize(&result_data).unwrap();
+    (
+        result.valid,
+        result.normalized.into_owned(),
+        result.network,
+        result.detected_coins,
+        result.error,
+    )
+}
+
+fn invalid(
+    network: Proto::Network,
+    detected_coins: Vec<u32>,
+    error: Proto::ValidationError,
+) -> (
+    bool,
+    String,
+    Proto::Network,
+    Vec<u32>,
+    Proto::ValidationError,
+) {
+    (false, String::default(), network, detected_coins, error)
+}
+
+#[test]
+fn test_any_address_validate_valid() {
+    assert_eq!(
+        validate(
+            CoinType::Bitcoin,
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"
+        ),
+        (
+            true,
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq".to_string(),
+            Proto::Network::Mainnet,
+            vec![],
+            Proto::ValidationError::NoError,
+        )
+    );
+    assert_eq!(
+        validate(
+            CoinType::Ethereum,
+            "0xb16db98b365b1f89191996942612b14f1da4bd5f"
+        ),
+        (
+            true,
+            "0xb16Db98B365B1f89191996942612B14F1Da4Bd5f".to_string(),
+            Proto::Network::Mainnet,
+            vec![],
+            Proto::ValidationError::NoError,
+        )
+    );
+}
+
+#[test]
+fn test_any_address_validate_testnet() {
+    for (coin, address) in [
+        (
+            CoinType::Bitcoin,
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+        ),
+        (CoinType::Bitcoin, "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"),
+        (CoinType::Dogecoin, "nUHVMF6vcrGd8RSK2hUZjwuGDNmPeNoBRb"),
+        (CoinType::Dash, "yLQmwB9hninHD8Ceh2UAqLFWCzirppNLik"),
+        (
+            CoinType::Groestlcoin,
+            "tgrs1qqypqxpq9qcrsszg2pvxq6rs0zqg3yyc5rmdag6",
+        ),
+    ] {
+        assert_eq!(
+            validate(coin, address),
+            invalid(
+                Proto::Network::Testnet,
+                vec![],
+                Proto::ValidationError::InvalidPrefix
+            ),
+            "{address}"
+        );
+    }
+}
+
+#[test]
+fn test_any_address_validate_wrong_chain() {
+    assert_eq!(
+        validate(
+            CoinType::Bitcoin,
+            "ltc1q3m3ujh350qrqdl33pv7pjw0d0m9qnm6qjcjpga"
+        ),
+        invalid(
+            Proto::Network::Mainnet,
+            vec![CoinType::Litecoin as u32],
+            Proto::ValidationError::InvalidPrefix
+        )
+    );
+    assert_eq!(
+        validate(
+            CoinType::Osmosis,
+            "cosmos1hsk6jryyqjfhp5dhc55tc9jtckygx0eph6dd02"
+        ),
+        invalid(
+            Proto::Network::Mainnet,
+            vec![CoinType::Cosmos as u32],
+            Proto::ValidationError::InvalidPrefix
+        )
+    );
+}
+
+#[test]
+fn test_any_address_validate_wrong_chain_cpp_coin() {
+    // Verge is not implemented in Rust, so it's detected by its address format.
+    assert_eq!(
+        validate(
+            CoinType::Bitcoin,
+            "vg1qujpe553lzgyg95g7k0w6zwscuy0ae022h4q4zg"
+        ),
+        invalid(
+            Proto::Network::Mainnet,
+            vec![CoinType::Verge as u32],
+            Proto::ValidationError::InvalidPrefix
+        )
+    );
+}
+
+#[test]
+fn test_any_address_validate_unknown() {
+    // EVM addresses don't have a prefix to detect the chain.
+    for address in ["0xb16Db98B365B1f89191996942612B14F1Da4Bd5f", "invalid"] {
+        assert_eq!(
+            validate(CoinType::Bitcoin, address),
+            invalid(
+                Proto::Network::Unknown,
+                vec![],
+                Proto::ValidationError::InvalidEncoding
+            ),
+            "{address}"
+        );
+    }
+}
+
+#[test]
+fn test_any_address_validate_error() {
+    let test_cases = [
+        (
+            CoinType::Bitcoin,
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mda",
+            Proto::ValidationError::InvalidChecksum,
+        ),
+        // The witness program is 21 bytes long.
+        (
+            CoinType::Bitcoin,
+            "bc1qqypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z53mkjx4",
+            Proto::ValidationError::InvalidLength,
+        ),
+        // The Base58 payload is 22 bytes long.
+        (
+            CoinType::Bitcoin,
+            "1QXEx2ZQ9mEdvMSaVKHznFv6iZq2LQbDz8",
+            Proto::ValidationError::InvalidLength,
+        ),
+        (
+            CoinType::Dogecoin,
+            "DLSSSUS3ex7YNDACJDxMER1ZMW579Vy8Zz",
+            Proto::ValidationError::InvalidChecksum,
+        ),
+        (
+            CoinType::Ethereum,
+            "0xb16db98b365b1f89191996942612b14f1da4bd",
+            Proto::ValidationError::InvalidLength,
+        ),
+        (
+            CoinType::Ethereum,
+            "b16db98b365b1f89191996942612b14f1da4bd5f",
+            Proto::ValidationError::InvalidPrefix,
+        ),
+    ];
+    for (coin, address, error) in test_cases {
+        assert_eq!(
+            validate(coin, address),
+            invalid(Proto::Network::Unknown, vec![], error),
+            "{address}"
+        );
+    }
+}
+
+#[test]
+fn test_any_address_validate_cpp_coin() {
+    // Zilliqa is not implemented in Rust, its addresses are checked against the registry address formats only.
+    assert_eq!(
+        validate(
+            CoinType::Zilliqa,
+            "zil1j8xae6lggm8y63m3y2r7aefu797ze7mhzulnqg"
+        ),
+        invalid(
+            Proto::Network::Unknown,
+            vec![],
+            Proto::ValidationError::NoError
+        )
+    );
+    assert_eq!(
+        validate(
+            CoinType::Zilliqa,
+            "zil1j8xae6lggm8y63m3y2r7aefu797ze7mhzulnqq"
+        ),
+        invalid(
+            Proto::Network::Unknown,
+            vec![],
+            Proto::ValidationError::InvalidChecksum
+        )
+    );
+    // Cardano doesn't declare its address formats.
+    assert_eq!(
+        validate(CoinType::Cardano, "addr1"),
+        invalid(
+            Proto::Network::Unknown,
+            vec![],
+            Proto::ValidationError::NoError
+        )
+    );
+}
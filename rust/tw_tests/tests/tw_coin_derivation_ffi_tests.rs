@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_coin_derivation::tw_coin_derivation_path;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::tw_derivation::TWDerivation;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+
+fn derivation_path(coin: CoinType, derivation: TWDerivation) -> Option<String> {
+    TWStringHelper::wrap(tw_coin_derivation_path(coin as u32, derivation as u32)).to_string()
+}
+
+#[test]
+fn test_coin_derivation_path_bitcoin() {
+    let tests = [
+        (TWDerivation::Default, "m/84'/0'/0'/0/0"),
+        (TWDerivation::BitcoinSegwit, "m/84'/0'/0'/0/0"),
+        (TWDerivation::BitcoinLegacy, "m/44'/0'/0'/0/0"),
+        (TWDerivation::BitcoinTestnet, "m/84'/1'/0'/0/0"),
+        (TWDerivation::BitcoinTaproot, "m/86'/0'/0'/0/0"),
+    ];
+    for (derivation, expected) in tests {
+        assert_eq!(
+            derivation_path(CoinType::Bitcoin, derivation).as_deref(),
+            Some(expected)
+        );
+    }
+}
+
+#[test]
+fn test_coin_derivation_path_non_default() {
+    assert_eq!(
+        derivation_path(CoinType::Solana, TWDerivation::SolanaSolana).as_deref(),
+        Some("m/44'/501'/0'/0'")
+    );
+    assert_eq!(
+        derivation_path(CoinType::Litecoin, TWDerivation::LitecoinLegacy).as_deref(),
+        Some("m/44'/2'/0'/0/0")
+    );
+    assert_eq!(
+        derivation_path(CoinType::Pactus, TWDerivation::PactusTestnet).as_deref(),
+        Some("m/44'/21777'/3'/0'")
+    );
+}
+
+#[test]
+fn test_coin_derivation_path_cosmos_fallback() {
+    let tests = [
+        (
+            CoinType::Kava,
+            TWDerivation::KavaFallback,
+            "m/44'/459'/0'/0/0",
+        ),
+        (
+            CoinType::Terra,
+            TWDerivation::TerraFallback,
+            "m/44'/330'/0'/0/0",
+        ),
+        (
+            CoinType::NativeEvmos,
+            TWDerivation::NativeEvmosFallback,
+            "m/44'/60'/0'/0/0",
+        ),
+    ];
+    for (coin, fallback, default_path) in tests {
+        assert_eq!(
+            derivation_path(coin, TWDerivation::Default).as_deref(),
+            Some(default_path)
+        );
+        assert_eq!(
+            derivation_path(coin, fallback).as_deref(),
+            Some("m/44'/118'/0'/0/0")
+        );
+    }
+
+    // Cosmos Hub uses the `118` coin type already.
+    assert_eq!(
+        derivation_path(CoinType::Cosmos, TWDerivation::KavaFallback),
+        None
+    );
+}
+
+#[test]
+fn test_coin_derivation_path_unknown() {
+    assert_eq!(
+        derivation_path(CoinType::Ethereum, TWDerivation::BitcoinTaproot),
+        None
+    );
+    assert!(TWStringHelper::wrap(tw_coin_derivation_path(u32::MAX, 0))
+        .to_string()
+        .is_none());
+    assert!(
+        TWStringHelper::wrap(tw_coin_derivation_path(CoinType::Bitcoin as u32, u32::MAX))
+            .to_string()
+            .is_none()
+    );
+}
@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_any_coin::collaborative_signing::{
+    PartialSignature, ReplayGuard, RoundMessage, RoundPayload, Scheme,
+};
+use tw_any_coin::ffi::tw_collaborative_signing::{
+    tw_collaborative_signing_decode_json, tw_collaborative_signing_encode_json,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_hash::H256;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+use tw_proto::CollaborativeSigning::Proto;
+use tw_proto::CollaborativeSigning::Proto::mod_RoundMessage::OneOfpayload as ProtoPayload;
+use tw_proto::{deserialize, serialize};
+
+const DOMAIN: &str = "wallet/btc-vault";
+const SESSION_ID: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+/// Public key of the `1` private key.
+const HIDING: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+/// Public key of the `2` private key.
+const BINDING: &str = "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5";
+const EXPIRES_AT: u64 = 1760400000;
+
+const NONCE_COMMITMENT_JSON: &str = r#"{"header":{"version":1,"scheme":"musig2","domain":"wallet/btc-vault","sessionId":"1111111111111111111111111111111111111111111111111111111111111111","round":1,"sender":2,"sequence":1,"expiresAt":1760400000},"payload":{"type":"nonceCommitment","value":{"hiding":"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798","binding":"02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5"}}}"#;
+
+fn header(round: u32, sequence: u64) -> Proto::MessageHeader<'static> {
+    Proto::MessageHeader {
+        version: 1,
+        scheme: Proto::Scheme::MuSig2,
+        domain: DOMAIN.into(),
+        session_id: SESSION_ID.decode_hex().unwrap().into(),
+        round,
+        sender: 2,
+        sequence,
+        expires_at: EXPIRES_AT,
+    }
+}
+
+fn nonce_commitment_message() -> Proto::RoundMessage<'static> {
+    Proto::RoundMessage {
+        header: Some(header(1, 1)),
+        payload: ProtoPayload::nonce_commitment(Proto::NonceCommitment {
+            hiding: HIDING.decode_hex().unwrap().into(),
+            binding: BINDING.decode_hex().unwrap().into(),
+        }),
+    }
+}
+
+fn encode_json(message: &Proto::RoundMessage<'_>) -> Option<String> {
+    let input = TWDataHelper::create(serialize(message).unwrap());
+    TWStringHelper::wrap(unsafe { tw_collaborative_signing_encode_json(input.ptr()) }).to_string()
+}
+
+fn decode_json(json: &str) -> Proto::DecodingOutput<'static> {
+    let json = TWStringHelper::create(json);
+    let output = TWDataHelper::wrap(unsafe { tw_collaborative_signing_decode_json(json.ptr()) })
+        .to_vec()
+        .expect("!tw_collaborative_signing_decode_json returned nullptr");
+
+    let output: Proto::DecodingOutput = deserialize(&output).unwrap();
+    Proto::DecodingOutput {
+        message: output
+            .message
+            .map(|message| RoundMessage::from_proto(&message).unwrap().to_proto()),
+        digest: output.digest.into_owned().into(),
+        error: output.error,
+        error_message: output.error_message.into_owned().into(),
+    }
+}
+
+#[test]
+fn test_collaborative_signing_encode_json() {
+    let json = encode_json(&nonce_commitment_message()).unwrap();
+    assert_eq!(json, NONCE_COMMITMENT_JSON);
+}
+
+#[test]
+fn test_collaborative_signing_decode_json() {
+    let output = decode_json(NONCE_COMMITMENT_JSON);
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.message, Some(nonce_commitment_message()));
+    assert_eq!(
+        output.digest.to_hex(),
+        "c6c2599d84203bda60eda656ce8c6198d0ceb3a518cb9c0db58228b19e62722f"
+    );
+}
+
+#[test]
+fn test_collaborative_signing_digest_domain_separation() {
+    let message = RoundMessage::from_proto(&nonce_commitment_message()).unwrap();
+
+    let mut other_domain = message.clone();
+    other_domain.header.domain = "wallet/eth-vault".to_string();
+    assert_ne!(message.digest().unwrap(), other_domain.digest().unwrap());
+}
+
+#[test]
+fn test_collaborative_signing_decode_json_invalid() {
+    // Nonce commitment in the partial signature round.
+    let json = NONCE_COMMITMENT_JSON.replace(r#""round":1"#, r#""round":2"#);
+    assert_eq!(
+        decode_json(&json).error,
+        SigningErrorType::Error_invalid_params
+    );
+
+    // The hiding commitment is not a valid point.
+    let json = NONCE_COMMITMENT_JSON.replace(HIDING, &format!("02{}", "ff".repeat(32)));
+    assert_eq!(
+        decode_json(&json).error,
+        SigningErrorType::Error_invalid_params
+    );
+
+    let json = NONCE_COMMITMENT_JSON.replace(r#""domain":"wallet/btc-vault""#, r#""domain":"""#);
+    assert_eq!(
+        decode_json(&json).error,
+        SigningErrorType::Error_invalid_params
+    );
+
+    let json = NONCE_COMMITMENT_JSON.replace(r#""version":1"#, r#""version":2"#);
+    assert_eq!(
+        decode_json(&json).error,
+        SigningErrorType::Error_not_supported
+    );
+}
+
+#[test]
+fn test_collaborative_signing_encode_json_invalid() {
+    let no_payload = Proto::RoundMessage {
+        header: Some(header(1, 1)),
+        payload: ProtoPayload::None,
+    };
+    assert_eq!(encode_json(&no_payload), None);
+
+    let short_session_id = Proto::RoundMessage {
+        header: Some(Proto::MessageHeader {
+            session_id: Cow::Owned(vec![0x11; 16]),
+            ..header(1, 1)
+        }),
+        ..nonce_commitment_message()
+    };
+    assert_eq!(encode_json(&short_session_id), None);
+
+    let zero_share = Proto::RoundMessage {
+        header: Some(header(2, 2)),
+        payload: ProtoPayload::partial_signature(Proto::PartialSignature {
+            share: vec![0; 32].into(),
+        }),
+    };
+    assert_eq!(encode_json(&zero_share), None);
+}
+
+#[test]
+fn test_collaborative_signing_replay_guard() {
+    let session_id = H256::from(SESSION_ID);
+    let mut guard = ReplayGuard::new(Scheme::MuSig2, DOMAIN.to_string(), session_id);
+
+    let nonce = RoundMessage::from_proto(&nonce_commitment_message()).unwrap();
+    guard.accept(&nonce, EXPIRES_AT - 1).unwrap();
+
+    // The same message can't be accepted twice.
+    let err = guard.accept(&nonce, EXPIRES_AT - 1).unwrap_err();
+    assert_eq!(*err.error_type(), SigningErrorType::Error_invalid_params);
+
+    let mut partial_signature = nonce.clone();
+    partial_signature.header.round = 2;
+    partial_signature.header.sequence = 2;
+    partial_signature.payload = RoundPayload::PartialSignature(PartialSignature {
+        share: H256::from("4bd1f5a4b308b8fcbf9c7b9a8f3a1e1e8774b2c7daf1cb20bb6fa0df6d3e3a4e"),
+    });
+
+    // Expired.
+    guard.accept(&partial_signature, EXPIRES_AT).unwrap_err();
+
+    // Another session.
+    let mut other_session = ReplayGuard::new(Scheme::Frost, DOMAIN.to_string(), session_id);
+    other_session
+        .accept(&partial_signature, EXPIRES_AT - 1)
+        .unwrap_err();
+
+    guard.accept(&partial_signature, EXPIRES_AT - 1).unwrap();
+}
@@ -0,0 +1,512 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_any_coin::ffi::tw_message_signer::{
+    tw_message_signer_prove_ownership, tw_message_signer_sign_any, tw_message_signer_verify_any,
+    tw_message_signer_verify_ownership,
+};
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::tw_derivation::TWDerivation;
+use tw_encoding::hex::DecodeHex;
+use tw_memory::test_utils::tw_data_helper::TWDataHelper;
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+use tw_proto::MessageSigner::Proto;
+use tw_proto::MessageSigner::Proto::SigningScheme;
+use tw_proto::{deserialize, serialize};
+
+const ETH_PRIVATE_KEY: &str = "03a9ca895dca1623c7dfd69693f7b4111f5d819d2e145536e0b03c136025a25d";
+const ETH_PUBLIC_KEY: &str = "0349d0134ef2c798c02879379a1760baa49c4e25e2324cd128f11e559f073bcc6f";
+const ETH_SIGNATURE: &str = "21a779d499957e7fd39392d49a079679009e60e492d9654a148829be43d2490736ec72bc4a5644047d979c3cf4ebe2c1c514044cf436b063cb89fc6676be71101b";
+const CARDANO_PRIVATE_KEY: &str = "089b68e458861be0c44bf9f7967f05cc91e51ede86dc679448a3566990b7785bd48c330875b1e0d03caaed0e67cecc42075dce1c7a13b1c49240508848ac82f603391c68824881ae3fc23a56a1a75ada3b96382db502e37564e84a5413cfaf1290dbd508e5ec71afaea98da2df1533c22ef02a26bb87b31907d0b2738fb7785b38d53aa68fc01230784c9209b2b2a2faf28491b3b1f1d221e63e704bbd0403c4154425dfbb01a2c5c042da411703603f89af89e57faae2946e2a5c18b1c5ca0e";
+const CARDANO_ADDRESS: &str = "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23";
+const PROOF_TIMESTAMP: u64 = 1760400000;
+/// Ownership proofs expire in 10 minutes.
+const PROOF_MAX_AGE: u64 = 600;
+const CARDANO_STAKING_ADDRESS: &str = "stake1u80jysjtdzqt88jt4jx93h5lumfr67d273r4vwyasfa2pxcwxllmx";
+
+fn sign_any(coin: CoinType, input: Proto::SigningInput<'_>) -> Proto::SigningOutput<'static> {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output =
+        TWDataHelper::wrap(unsafe { tw_message_signer_sign_any(coin as u32, input_data.ptr()) })
+            .to_vec()
+            .expect("!tw_message_signer_sign_any returned nullptr");
+
+    let output: Proto::SigningOutput = deserialize(&output).unwrap();
+    Proto::SigningOutput {
+        signature: output.signature.into_owned().into(),
+        scheme: output.scheme,
+        error: output.error,
+        error_message: output.error_message.into_owned().into(),
+        key: output.key.into_owned().into(),
+    }
+}
+
+fn verify_any(coin: CoinType, input: Proto::VerifyingInput<'_>) -> bool {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    unsafe { tw_message_signer_verify_any(coin as u32, input_data.ptr()) }
+}
+
+fn prove_ownership(
+    coin: CoinType,
+    input: Proto::OwnershipProofInput<'_>,
+) -> Proto::OwnershipProofOutput<'static> {
+    let input_data = TWDataHelper::create(serialize(&input).unwrap());
+    let output = TWDataHelper::wrap(unsafe {
+        tw_message_signer_prove_ownership(coin as u32, input_data.ptr())
+    })
+    .to_vec()
+    .expect("!tw_message_signer_prove_ownership returned nullptr");
+
+    let output: Proto::OwnershipProofOutput = deserialize(&output).unwrap();
+    Proto::OwnershipProofOutput {
+        envelope: output.envelope.into_owned().into(),
+        address: output.address.into_owned().into(),
+        message: output.message.into_owned().into(),
+        signature: output.signature.into_owned().into(),
+        error: output.error,
+        error_message: output.error_message.into_owned().into(),
+    }
+}
+
+fn verify_ownership(envelope: &str, challenge: &str) -> bool {
+    verify_ownership_at(envelope, challenge, PROOF_TIMESTAMP)
+}
+
+fn verify_ownership_at(envelope: &str, challenge: &str, now: u64) -> bool {
+    let envelope = TWStringHelper::create(envelope);
+    let challenge = TWStringHelper::create(challenge);
+    unsafe {
+        tw_message_signer_verify_ownership(envelope.ptr(), challenge.ptr(), now, PROOF_MAX_AGE)
+    }
+}
+
+#[test]
+fn test_message_signer_sign_any_ethereum_default() {
+    let input = Proto::SigningInput {
+        private_key: ETH_PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: "Foo".into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = sign_any(CoinType::Ethereum, input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.scheme, SigningScheme::Eip191);
+    assert_eq!(output.signature, ETH_SIGNATURE);
+}
+
+#[test]
+fn test_message_signer_sign_any_cosmos_default() {
+    let input = Proto::SigningInput {
+        private_key: "8d2a3bd62d300a148c89dc8635f87b7a24a951bd1c4e78675fe40e1a640d46ed"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        message: "Hello World".into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = sign_any(CoinType::Cosmos, input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.scheme, SigningScheme::Adr36);
+    assert_eq!(
+        output.signature,
+        "x7P9GSahETwc7r/mdmwWMdEX2uDoo1HxjzMHpcRFWHMF+aSY9nmHHBhqZcXHnrvpqa77ppQyyF7Dv+DT/NSBDg=="
+    );
+}
+
+#[test]
+fn test_message_signer_sign_any_bitcoin_bip322() {
+    let input = Proto::SigningInput {
+        private_key: "bb051cd0dda0246f33c5a9e133ebd8e7bc02a92af6c41adc131ccd7826c5b004"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        message: "Hello World".into(),
+        scheme: SigningScheme::Bip322,
+        ..Proto::SigningInput::default()
+    };
+
+    let output = sign_any(CoinType::Bitcoin, input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.scheme, SigningScheme::Bip322);
+    assert_eq!(output.signature, "AkgwRQIhAOzyynlqt93lOKJr+wmmxIens//zPzl9tqIOua93wO6MAiBi5n5EyAcPScOjf1lAqIUIQtr3zKNeavYabHyR8eGhowEhAsfxIAMZZEKUPYWI4BruhAQjzFT8FSFSajuFwrDL1Yhy");
+}
+
+#[test]
+fn test_message_signer_sign_any_solana_offchain_v0() {
+    let input = Proto::SigningInput {
+        private_key: "44f480ca27711895586074a14c552e58cc52e66a58edb6c58cf9b9b7295d4a2d"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        message: "Hello world".into(),
+        scheme: SigningScheme::SolanaOffchainV0,
+        ..Proto::SigningInput::default()
+    };
+
+    let output = sign_any(CoinType::Solana, input);
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.scheme, SigningScheme::SolanaOffchainV0);
+    assert_eq!(
+        output.signature,
+        "5p1VapQVRdvkcZ962zMehpok7s9fU5YxecyK2tphSq4kFTY7TdaQ7xc9rA1ecDWWFPtmrnRsAegwkqmQehf5MzBc"
+    );
+
+    let verifying_input = Proto::VerifyingInput {
+        message: "Hello world".into(),
+        public_key: "ee6d61a89fc8f9909585a996bb0d2b2ac69ae23b5acf39a19f32631239ba06f9"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        signature: output.signature.clone(),
+        scheme: SigningScheme::SolanaOffchainV0,
+        ..Proto::VerifyingInput::default()
+    };
+    assert!(verify_any(CoinType::Solana, verifying_input));
+}
+
+#[test]
+fn test_message_signer_sign_any_unsupported_scheme() {
+    let input = |scheme| Proto::SigningInput {
+        private_key: ETH_PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: "Foo".into(),
+        scheme,
+        ..Proto::SigningInput::default()
+    };
+
+    // The scheme is not supported by the blockchain.
+    let output = sign_any(CoinType::Ethereum, input(SigningScheme::Bip322));
+    assert_eq!(output.error, SigningErrorType::Error_not_supported);
+    assert!(output.signature.is_empty());
+
+    // The blockchain doesn't support message signing.
+    let output = sign_any(CoinType::Tron, input(SigningScheme::Default));
+    assert_eq!(output.error, SigningErrorType::Error_not_supported);
+}
+
+#[test]
+fn test_message_signer_verify_any() {
+    let input = |signature: &'static str, scheme| Proto::VerifyingInput {
+        message: "Foo".into(),
+        public_key: ETH_PUBLIC_KEY.decode_hex().unwrap().into(),
+        signature: signature.into(),
+        scheme,
+        ..Proto::VerifyingInput::default()
+    };
+
+    assert!(verify_any(
+        CoinType::Ethereum,
+        input(ETH_SIGNATURE, SigningScheme::Default)
+    ));
+    assert!(verify_any(
+        CoinType::Ethereum,
+        input(ETH_SIGNATURE, SigningScheme::Eip191)
+    ));
+    assert!(!verify_any(
+        CoinType::Ethereum,
+        input(ETH_SIGNATURE, SigningScheme::Adr36)
+    ));
+    assert!(!verify_any(
+        CoinType::Ethereum,
+        input("21a779d499957e7fd39392d49a079679009e60e492d9654a148829be43d2490736ec72bc4a5644047d979c3cf4ebe2c1c514044cf436b063cb89fc6676be71101c", SigningScheme::Default)
+    ));
+}
+
+#[test]
+fn test_message_signer_sign_any_cardano_cip8() {
+    let input = Proto::SigningInput {
+        private_key: CARDANO_PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: "Hello Cardano".into(),
+        address: CARDANO_ADDRESS.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = sign_any(CoinType::Cardano, input);
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.scheme, SigningScheme::Cip8);
+    assert_eq!(output.signature, "845846a201276761646472657373583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09ba166686173686564f44d48656c6c6f2043617264616e6f5840f11350ed305aabe2bec7815e015de3c52fab53878d121ee3d01202793190b492c71c3f2c3c9a215f4481d9c464074c3d5afa1fd739d549683d3aa40192d75d05");
+    assert_eq!(
+        output.key,
+        "a40101032720062158206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df290"
+    );
+
+    let input = |message: &'static str| Proto::VerifyingInput {
+        message: message.into(),
+        public_key: output.key.decode_hex().unwrap().into(),
+        signature: output.signature.clone(),
+        scheme: SigningScheme::Cip8,
+        address: CARDANO_ADDRESS.into(),
+    };
+    assert!(verify_any(CoinType::Cardano, input("Hello Cardano")));
+    assert!(!verify_any(CoinType::Cardano, input("Hello Cardano!")));
+}
+
+#[test]
+fn test_message_signer_sign_any_cardano_cip8_staking_address() {
+    let input = Proto::SigningInput {
+        private_key: CARDANO_PRIVATE_KEY.decode_hex().unwrap().into(),
+        message: "Hello".into(),
+        scheme: SigningScheme::Cip8,
+        address: CARDANO_STAKING_ADDRESS.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    let output = sign_any(CoinType::Cardano, input);
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.signature, "84582aa201276761646472657373581de1df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09ba166686173686564f44548656c6c6f58409841dfb6803f347659d1f847fb1e709a1526e5a319644fb1a33cb854a1ffb123ef07a2b2be8df99cc86732f4c244aa4ec2fa7de8b3448e2b2fde33e2fa148a00");
+    assert_eq!(
+        output.key,
+        "a4010103272006215820e554163344aafc2bbefe778a6953ddce0583c2f8e0a0686929c020ca33e06932"
+    );
+
+    let input = |address: &'static str| Proto::VerifyingInput {
+        message: "Hello".into(),
+        public_key: output.key.decode_hex().unwrap().into(),
+        signature: output.signature.clone(),
+        scheme: SigningScheme::Cip8,
+        address: address.into(),
+    };
+    assert!(verify_any(
+        CoinType::Cardano,
+        input(CARDANO_STAKING_ADDRESS)
+    ));
+    // The signature is bound to the staking address.
+    assert!(!verify_any(CoinType::Cardano, input(CARDANO_ADDRESS)));
+}
+
+#[test]
+fn test_message_signer_sign_any_cardano_cip8_error() {
+    let input = |private_key: &str, address: &'static str| Proto::SigningInput {
+        private_key: private_key.decode_hex().unwrap().into(),
+        message: "Hello Cardano".into(),
+        address: address.into(),
+        ..Proto::SigningInput::default()
+    };
+
+    // The address doesn't belong to the private key.
+    let output = sign_any(
+        CoinType::Cardano,
+        input(CARDANO_PRIVATE_KEY, "addr1q92cmkgzv9h4e5q7mnrzsuxtgayvg4qr7y3gyx97ukmz3dfx7r9fu73vqn25377ke6r0xk97zw07dqr9y5myxlgadl2s0dgke5"),
+    );
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+    assert!(output.signature.is_empty());
+
+    let output = sign_any(CoinType::Cardano, input(CARDANO_PRIVATE_KEY, "invalid"));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_address);
+
+    // Not an extended private key.
+    let output = sign_any(CoinType::Cardano, input(ETH_PRIVATE_KEY, CARDANO_ADDRESS));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_private_key);
+
+    // CIP-8 is supported by Cardano only.
+    let output = sign_any(
+        CoinType::Ethereum,
+        Proto::SigningInput {
+            scheme: SigningScheme::Cip8,
+            ..input(CARDANO_PRIVATE_KEY, CARDANO_ADDRESS)
+        },
+    );
+    assert_eq!(output.error, SigningErrorType::Error_not_supported);
+}
+
+#[test]
+fn test_message_signer_prove_ownership_ethereum() {
+    let input = Proto::OwnershipProofInput {
+        private_key: ETH_PRIVATE_KEY.decode_hex().unwrap().into(),
+        challenge: "travel-rule-8b2f".into(),
+        timestamp: PROOF_TIMESTAMP,
+        ..Proto::OwnershipProofInput::default()
+    };
+
+    let output = prove_ownership(CoinType::Ethereum, input);
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    assert_eq!(output.address, "0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37");
+    assert_eq!(
+        output.message,
+        "Prove ownership of 0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37 on Ethereum.\nChallenge: travel-rule-8b2f\nTimestamp: 1760400000"
+    );
+    assert_eq!(output.signature, "6f924ce08c5a0926ae70afe1df1798567e8dae8a3ee6714c785205f9cd226b323f91d47fc441ca939dfc3b812cd55ac9b21d83b5de6c5501c4d039b82d2012ee1b");
+
+    let expected_envelope = r#"{"address":"0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37","challenge":"travel-rule-8b2f","coin":60,"message":"Prove ownership of 0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37 on Ethereum.\nChallenge: travel-rule-8b2f\nTimestamp: 1760400000","publicKey":"0449d0134ef2c798c02879379a1760baa49c4e25e2324cd128f11e559f073bcc6f0bae991ff5a318683319af1ec4b0d45fa5fda5f992cf5aa421bd93f3fb2135e5","scheme":"eip191","signature":"6f924ce08c5a0926ae70afe1df1798567e8dae8a3ee6714c785205f9cd226b323f91d47fc441ca939dfc3b812cd55ac9b21d83b5de6c5501c4d039b82d2012ee1b","timestamp":1760400000,"version":1}"#;
+    assert_eq!(output.envelope, expected_envelope);
+
+    assert!(verify_ownership(expected_envelope, "travel-rule-8b2f"));
+    // The proof is issued for another challenge.
+    assert!(!verify_ownership(expected_envelope, "travel-rule-0000"));
+    // The address is not derived from the public key.
+    let tampered_envelope = expected_envelope.replacen(
+        r#""address":"0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37""#,
+        r#""address":"0x0000000000000000000000000000000000000000""#,
+        1,
+    );
+    assert!(!verify_ownership(&tampered_envelope, "travel-rule-8b2f"));
+
+    // The proof expires in `PROOF_MAX_AGE` seconds.
+    assert!(verify_ownership_at(
+        expected_envelope,
+        "travel-rule-8b2f",
+        PROOF_TIMESTAMP + PROOF_MAX_AGE
+    ));
+    assert!(!verify_ownership_at(
+        expected_envelope,
+        "travel-rule-8b2f",
+        PROOF_TIMESTAMP + PROOF_MAX_AGE + 1
+    ));
+    // The proof timestamp is too far in the future.
+    assert!(!verify_ownership_at(
+        expected_envelope,
+        "travel-rule-8b2f",
+        PROOF_TIMESTAMP - PROOF_MAX_AGE - 1
+    ));
+}
+
+#[test]
+fn test_message_signer_prove_ownership_non_exportable_key() {
+    const ETH_UNCOMPRESSED_PUBLIC_KEY: &str = "0449d0134ef2c798c02879379a1760baa49c4e25e2324cd128f11e559f073bcc6f0bae991ff5a318683319af1ec4b0d45fa5fda5f992cf5aa421bd93f3fb2135e5";
+    let input = |signature: &'static str| Proto::OwnershipProofInput {
+        public_key: ETH_UNCOMPRESSED_PUBLIC_KEY.decode_hex().unwrap().into(),
+        challenge: "travel-rule-8b2f".into(),
+        timestamp: PROOF_TIMESTAMP,
+        signature: signature.into(),
+        ..Proto::OwnershipProofInput::default()
+    };
+
+    // The host gets the message to sign by the public key.
+    let output = prove_ownership(CoinType::Ethereum, input(""));
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert_eq!(output.address, "0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37");
+    assert_eq!(
+        output.message,
+        "Prove ownership of 0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37 on Ethereum.\nChallenge: travel-rule-8b2f\nTimestamp: 1760400000"
+    );
+    assert!(output.envelope.is_empty());
+    assert!(output.signature.is_empty());
+
+    // The host signs the message with the key, and gets the same proof as if signed with the private key.
+    let output = prove_ownership(CoinType::Ethereum, input("6f924ce08c5a0926ae70afe1df1798567e8dae8a3ee6714c785205f9cd226b323f91d47fc441ca939dfc3b812cd55ac9b21d83b5de6c5501c4d039b82d2012ee1b"));
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(
+        output.envelope,
+        r#"{"address":"0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37","challenge":"travel-rule-8b2f","coin":60,"message":"Prove ownership of 0xd0972E2312518Ca15A2304D56ff9cc0b7ea0Ea37 on Ethereum.\nChallenge: travel-rule-8b2f\nTimestamp: 1760400000","publicKey":"0449d0134ef2c798c02879379a1760baa49c4e25e2324cd128f11e559f073bcc6f0bae991ff5a318683319af1ec4b0d45fa5fda5f992cf5aa421bd93f3fb2135e5","scheme":"eip191","signature":"6f924ce08c5a0926ae70afe1df1798567e8dae8a3ee6714c785205f9cd226b323f91d47fc441ca939dfc3b812cd55ac9b21d83b5de6c5501c4d039b82d2012ee1b","timestamp":1760400000,"version":1}"#
+    );
+    assert!(verify_ownership(&output.envelope, "travel-rule-8b2f"));
+
+    // The signature of another message.
+    let output = prove_ownership(CoinType::Ethereum, input(ETH_SIGNATURE));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+    assert!(output.envelope.is_empty());
+}
+
+#[test]
+fn test_message_signer_prove_ownership_derivation() {
+    let input = Proto::OwnershipProofInput {
+        private_key: "bb051cd0dda0246f33c5a9e133ebd8e7bc02a92af6c41adc131ccd7826c5b004"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        challenge: "a1b2c3".into(),
+        timestamp: PROOF_TIMESTAMP,
+        derivation: TWDerivation::BitcoinTestnet as u32,
+        ..Proto::OwnershipProofInput::default()
+    };
+
+    let output = prove_ownership(CoinType::Bitcoin, input);
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert_eq!(output.address, "tb1q9vza2e8x573nczrlzms0wvx3gsqjx7vaxwd45v");
+    assert!(output.envelope.contains(r#""scheme":"bip322""#));
+    // The address is derived using one of the coin derivations.
+    assert!(verify_ownership(&output.envelope, "a1b2c3"));
+}
+
+#[test]
+fn test_message_signer_prove_ownership_solana() {
+    let input = Proto::OwnershipProofInput {
+        private_key: "44f480ca27711895586074a14c552e58cc52e66a58edb6c58cf9b9b7295d4a2d"
+            .decode_hex()
+            .unwrap()
+            .into(),
+        challenge: "a1b2c3".into(),
+        timestamp: PROOF_TIMESTAMP,
+        scheme: SigningScheme::SolanaOffchainV0,
+        ..Proto::OwnershipProofInput::default()
+    };
+
+    let output = prove_ownership(CoinType::Solana, input);
+    assert_eq!(
+        output.error,
+        SigningErrorType::OK,
+        "{}",
+        output.error_message
+    );
+    assert!(output.envelope.contains(r#""scheme":"solana-offchain-v0""#));
+    assert!(verify_ownership(&output.envelope, "a1b2c3"));
+}
+
+#[test]
+fn test_message_signer_prove_ownership_error() {
+    let input = |challenge, scheme| Proto::OwnershipProofInput {
+        private_key: ETH_PRIVATE_KEY.decode_hex().unwrap().into(),
+        challenge,
+        timestamp: PROOF_TIMESTAMP,
+        scheme,
+        ..Proto::OwnershipProofInput::default()
+    };
+
+    let output = prove_ownership(CoinType::Ethereum, input("".into(), SigningScheme::Default));
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+    assert!(output.envelope.is_empty());
+
+    // Neither a private key nor a public key is set.
+    let output = prove_ownership(
+        CoinType::Ethereum,
+        Proto::OwnershipProofInput {
+            private_key: Default::default(),
+            ..input("travel-rule-8b2f".into(), SigningScheme::Default)
+        },
+    );
+    assert_eq!(output.error, SigningErrorType::Error_invalid_params);
+
+    let output = prove_ownership(
+        CoinType::Ethereum,
+        input("travel-rule-8b2f".into(), SigningScheme::Eip712),
+    );
+    assert_eq!(output.error, SigningErrorType::Error_not_supported);
+
+    // The blockchain doesn't support message signing.
+    let output = prove_ownership(
+        CoinType::Tron,
+        input("travel-rule-8b2f".into(), SigningScheme::Default),
+    );
+    assert_eq!(output.error, SigningErrorType::Error_not_supported);
+
+    assert!(!verify_ownership("{}", "travel-rule-8b2f"));
+}
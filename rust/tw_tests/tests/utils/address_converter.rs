@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use tw_memory::test_utils::tw_string_helper::TWStringHelper;
+use wallet_core_rs::address_converter::AddressFormat;
+use wallet_core_rs::ffi::address_converter::tw_address_converter_convert;
+
+const EVM_ADDRESS: &str = "0x8dbD6c7Ede90646a61Bbc649831b7c298BFd37A0";
+const EVMOS_ADDRESS: &str = "evmos13k7kclk7jpjx5cdmceycxxmu9x9l6daqepprmh";
+
+const TON_RAW: &str = "0:8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28ae";
+const TON_BOUNCEABLE: &str = "EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorkdl";
+const TON_NON_BOUNCEABLE: &str = "UQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorhqg";
+
+const XRP_CLASSIC: &str = "rnBFvgZphmN39GWzUJeUitaP22Fr9be75H";
+const XRP_X_ADDRESS: &str = "X76UnYEMbQfEs3mUqgtjp4zFy9exgSxWAqcQwu9z2r5d7Tm";
+const XRP_X_ADDRESS_WITH_TAG: &str = "X76UnYEMbQfEs3mUqgtjp4zFy9exgThRj7XVZ6UxsdrBptF";
+
+fn convert(
+    address: &str,
+    from: AddressFormat,
+    to: AddressFormat,
+    hrp: Option<&str>,
+) -> Option<String> {
+    let address = TWStringHelper::create(address);
+    let hrp = hrp.map(TWStringHelper::create);
+    let hrp_ptr = hrp.as_ref().map_or(std::ptr::null_mut(), |hrp| hrp.ptr());
+
+    let converted =
+        unsafe { tw_address_converter_convert(address.ptr(), from as u32, to as u32, hrp_ptr) };
+    TWStringHelper::wrap(converted).to_string()
+}
+
+fn test_convert(from_address: &str, from: AddressFormat, to_address: &str, to: AddressFormat) {
+    let hrp = match to {
+        AddressFormat::CosmosBech32 => Some("evmos"),
+        _ => None,
+    };
+    assert_eq!(
+        convert(from_address, from, to, hrp).as_deref(),
+        Some(to_address),
+        "{from:?} -> {to:?}"
+    );
+}
+
+#[test]
+fn test_address_converter_evm() {
+    let addresses = [
+        (EVM_ADDRESS, AddressFormat::EvmHex),
+        (EVMOS_ADDRESS, AddressFormat::CosmosBech32),
+    ];
+    for (from_address, from) in addresses {
+        for (to_address, to) in addresses {
+            test_convert(from_address, from, to_address, to);
+        }
+    }
+
+    // Lowercase hex is checksummed.
+    test_convert(
+        &EVM_ADDRESS.to_lowercase(),
+        AddressFormat::EvmHex,
+        EVM_ADDRESS,
+        AddressFormat::EvmHex,
+    );
+}
+
+#[test]
+fn test_address_converter_ton() {
+    let addresses = [
+        (TON_RAW, AddressFormat::TonRaw),
+        (TON_BOUNCEABLE, AddressFormat::TonBounceable),
+        (TON_NON_BOUNCEABLE, AddressFormat::TonNonBounceable),
+    ];
+    for (from_address, from) in addresses {
+        for (to_address, to) in addresses {
+            test_convert(from_address, from, to_address, to);
+        }
+    }
+}
+
+#[test]
+fn test_address_converter_xrp() {
+    test_convert(
+        XRP_CLASSIC,
+        AddressFormat::XrpClassic,
+        XRP_X_ADDRESS,
+        AddressFormat::XrpXAddress,
+    );
+    test_convert(
+        XRP_X_ADDRESS,
+        AddressFormat::XrpXAddress,
+        XRP_CLASSIC,
+        AddressFormat::XrpClassic,
+    );
+    // The destination tag is dropped.
+    test_convert(
+        XRP_X_ADDRESS_WITH_TAG,
+        AddressFormat::XrpXAddress,
+        XRP_CLASSIC,
+        AddressFormat::XrpClassic,
+    );
+}
+
+#[test]
+fn test_address_converter_invalid() {
+    // Different families.
+    assert_eq!(
+        convert(
+            EVM_ADDRESS,
+            AddressFormat::EvmHex,
+            AddressFormat::TonRaw,
+            None
+        ),
+        None
+    );
+    assert_eq!(
+        convert(
+            XRP_CLASSIC,
+            AddressFormat::XrpClassic,
+            AddressFormat::EvmHex,
+            None
+        ),
+        None
+    );
+    // Bech32 hrp is not specified.
+    assert_eq!(
+        convert(
+            EVM_ADDRESS,
+            AddressFormat::EvmHex,
+            AddressFormat::CosmosBech32,
+            None
+        ),
+        None
+    );
+    // Address doesn't match the format.
+    assert_eq!(
+        convert(
+            EVMOS_ADDRESS,
+            AddressFormat::EvmHex,
+            AddressFormat::EvmHex,
+            None
+        ),
+        None
+    );
+    assert_eq!(
+        convert(
+            TON_NON_BOUNCEABLE,
+            AddressFormat::TonBounceable,
+            AddressFormat::TonRaw,
+            None
+        ),
+        None
+    );
+    assert_eq!(
+        convert(
+            TON_BOUNCEABLE,
+            AddressFormat::TonNonBounceable,
+            AddressFormat::TonRaw,
+            None
+        ),
+        None
+    );
+    assert_eq!(
+        convert(
+            TON_RAW,
+            AddressFormat::TonBounceable,
+            AddressFormat::TonRaw,
+            None
+        ),
+        None
+    );
+    // 32 bytes Cosmos account cannot be converted to EVM.
+    assert_eq!(
+        convert(
+            "cosmos1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sxaggsw",
+            AddressFormat::CosmosBech32,
+            AddressFormat::EvmHex,
+            None
+        ),
+        None
+    );
+    // Tron and Filecoin addresses are converted on the C++ side.
+    assert_eq!(
+        convert(
+            EVM_ADDRESS,
+            AddressFormat::EvmHex,
+            AddressFormat::TronBase58,
+            None
+        ),
+        None
+    );
+    assert_eq!(
+        convert(
+            "f410frw6wy7w6sbsguyn3yzeygg34fgf72n5ao5sxyky",
+            AddressFormat::FilecoinDelegated,
+            AddressFormat::EvmHex,
+            None
+        ),
+        None
+    );
+
+    // Unknown format.
+    let address = TWStringHelper::create(EVM_ADDRESS);
+    let converted =
+        unsafe { tw_address_converter_convert(address.ptr(), 0, 100, std::ptr::null()) };
+    assert_eq!(TWStringHelper::wrap(converted).to_string(), None);
+}
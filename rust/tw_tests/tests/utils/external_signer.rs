@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::borrow::Cow;
+use tw_any_coin::transaction_compiler::TransactionCompiler;
+use tw_coin_entry::error::prelude::*;
+use tw_coin_registry::coin_type::CoinType;
+use tw_encoding::base58::{self, Alphabet};
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::external_signer::{Ed25519ExternalSigner, Secp256k1ExternalSigner};
+use tw_keypair::tw::PrivateKey;
+use tw_number::U256;
+use tw_proto::BitcoinV2::Proto as BitcoinProto;
+use tw_proto::Ethereum::Proto as EthereumProto;
+use tw_proto::Solana::Proto as SolanaProto;
+use tw_proto::{deserialize, serialize};
+
+#[test]
+fn test_compile_with_external_signer_ethereum() {
+    let transfer = EthereumProto::mod_Transaction::Transfer {
+        amount: U256::encode_be_compact(1_000_000_000_000_000_000),
+        data: Cow::default(),
+    };
+    let input = EthereumProto::SigningInput {
+        nonce: U256::encode_be_compact(9),
+        chain_id: U256::encode_be_compact(1),
+        gas_price: U256::encode_be_compact(20_000_000_000),
+        gas_limit: U256::encode_be_compact(21_000),
+        to_address: "0x3535353535353535353535353535353535353535".into(),
+        transaction: Some(EthereumProto::Transaction {
+            transaction_oneof: EthereumProto::mod_Transaction::OneOftransaction_oneof::transfer(
+                transfer,
+            ),
+        }),
+        ..EthereumProto::SigningInput::default()
+    };
+    let input = serialize(&input).unwrap();
+
+    let data_to_sign = TransactionCompiler::data_to_sign(CoinType::Ethereum, &input).unwrap();
+    assert_eq!(
+        data_to_sign.to_hex(),
+        "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53"
+    );
+
+    let private_key = PrivateKey::new(
+        "4646464646464646464646464646464646464646464646464646464646464646"
+            .decode_hex()
+            .unwrap(),
+    )
+    .unwrap();
+    let signer = Secp256k1ExternalSigner::new(private_key);
+
+    let output =
+        TransactionCompiler::compile_with_external_signer(CoinType::Ethereum, &input, &signer)
+            .unwrap();
+    let output: EthereumProto::SigningOutput = deserialize(&output).unwrap();
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    assert!(output.error_message.is_empty());
+    // https://eips.ethereum.org/EIPS/eip-155#example
+    assert_eq!(output.encoded.to_hex(), "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83");
+}
+
+#[test]
+fn test_compile_with_external_signer_solana() {
+    let transfer = SolanaProto::Transfer {
+        recipient: "EN2sCsJ1WDV8UFqsiTXHcUPUxQ4juE71eCknHYYMifkd".into(),
+        value: 42,
+        ..SolanaProto::Transfer::default()
+    };
+    let input = SolanaProto::SigningInput {
+        sender: "7v91N7iZ9mNicL8WfG6cgSCKyRXydQjLh6UYBWwm6y1Q".into(),
+        recent_blockhash: "11111111111111111111111111111111".into(),
+        transaction_type:
+            SolanaProto::mod_SigningInput::OneOftransaction_type::transfer_transaction(transfer),
+        ..SolanaProto::SigningInput::default()
+    };
+    let input = serialize(&input).unwrap();
+
+    let private_key = base58::decode(
+        "A7psj2GW7ZMdY4E5hJq14KMeYg7HFjULSsWSrTXZLvYr",
+        Alphabet::Bitcoin,
+    )
+    .unwrap();
+    let signer = Ed25519ExternalSigner::new(PrivateKey::new(private_key).unwrap());
+
+    let output =
+        TransactionCompiler::compile_with_external_signer(CoinType::Solana, &input, &signer)
+            .unwrap();
+    let output: SolanaProto::SigningOutput = deserialize(&output).unwrap();
+
+    assert_eq!(output.error, SigningErrorType::OK);
+    // The same transaction as signed by the private key in `test_solana_sign_transfer`.
+    assert_eq!(output.encoded, "3p2kzZ1DvquqC6LApPuxpTg5CCDVPqJFokGSnGhnBHrta4uq7S2EyehV1XNUVXp51D69GxGzQZUjikfDzbWBG2aFtG3gHT1QfLzyFKHM4HQtMQMNXqay1NAeiiYZjNhx9UvMX4uAQZ4Q6rx6m2AYfQ7aoMUrejq298q1wBFdtS9XVB5QTiStnzC7zs97FUEK2T4XapjF1519EyFBViTfHpGpnf5bfizDzsW9kYUtRDW1UC2LgHr7npgq5W9TBmHf9hSmRgM9XXucjXLqubNWE7HUMhbKjuBqkirRM");
+}
+
+#[test]
+fn test_compile_with_external_signer_not_supported() {
+    let input = serialize(&BitcoinProto::SigningInput::default()).unwrap();
+    let private_key = PrivateKey::new(
+        "4646464646464646464646464646464646464646464646464646464646464646"
+            .decode_hex()
+            .unwrap(),
+    )
+    .unwrap();
+    let signer = Secp256k1ExternalSigner::new(private_key);
+
+    let error =
+        TransactionCompiler::compile_with_external_signer(CoinType::Bitcoin, &input, &signer)
+            .unwrap_err();
+    assert_eq!(*error.error_type(), SigningErrorType::Error_not_supported);
+}
+
+#[test]
+fn test_data_to_sign_liquid_not_supported() {
+    let input = serialize(&BitcoinProto::SigningInput::default()).unwrap();
+
+    let error = TransactionCompiler::data_to_sign(CoinType::Liquid, &input).unwrap_err();
+    assert_eq!(*error.error_type(), SigningErrorType::Error_not_supported);
+}
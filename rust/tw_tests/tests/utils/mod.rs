@@ -2,5 +2,7 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+mod address_converter;
 mod bit_reader;
+mod external_signer;
 mod uuid;
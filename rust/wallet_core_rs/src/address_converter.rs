@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Conversion between the formats of the same address (that is, the same key hash)
+//! used by different chains or by different parts of the same chain.
+
+use std::str::FromStr;
+use tw_bech32_address::Bech32Address;
+use tw_coin_entry::error::prelude::*;
+use tw_evm::address::Address as EvmAddress;
+use tw_hash::H160;
+use tw_ripple::address::classic_address::ClassicAddress;
+use tw_ripple::address::x_address::XAddress;
+use tw_ton::address::TonAddress;
+use tw_ton_sdk::address::address_data::AddressData;
+use tw_ton_sdk::address::raw_address::RawAddress;
+
+/// Address format.
+/// The formats of the same family can be converted to each other:
+/// * [`AddressFormat::EvmHex`], [`AddressFormat::CosmosBech32`], [`AddressFormat::TronBase58`]
+///   and [`AddressFormat::FilecoinDelegated`] wrap a 20 bytes EVM address;
+/// * [`AddressFormat::TonRaw`], [`AddressFormat::TonBounceable`] and [`AddressFormat::TonNonBounceable`];
+/// * [`AddressFormat::XrpClassic`] and [`AddressFormat::XrpXAddress`].
+///
+/// Tron and Filecoin addresses are implemented in C++, so [`AddressFormat::TronBase58`]
+/// and [`AddressFormat::FilecoinDelegated`] are converted to and from [`AddressFormat::EvmHex`]
+/// by the C++ `TWAddressConverter` before and after calling [`AddressConverter::convert`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum_macros::FromRepr)]
+#[repr(u32)]
+pub enum AddressFormat {
+    /// `0x` prefixed EIP-55 checksummed hex, e.g. `0x8dbD6c7Ede90646a61Bbc649831b7c298BFd37A0`.
+    EvmHex = 0,
+    /// Bech32 encoded 20 bytes account, e.g. Evmos `evmos13k7kclk7jpjx5cdmceycxxmu9x9l6daqepprmh`.
+    CosmosBech32 = 1,
+    /// Base58Check encoded Tron address, e.g. `TNtfCWCPgcNvouNuGVP8fs2iJzi8nnbX8N`.
+    TronBase58 = 2,
+    /// Filecoin delegated (`f410`) address, e.g. `f410frw6wy7w6sbsguyn3yzeygg34fgf72n5ao5sxyky`.
+    FilecoinDelegated = 3,
+    /// TON raw address, e.g. `0:8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28ae`.
+    TonRaw = 4,
+    /// TON user-friendly bounceable address, e.g. `EQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorkdl`.
+    TonBounceable = 5,
+    /// TON user-friendly non-bounceable address, e.g. `UQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorhqg`.
+    TonNonBounceable = 6,
+    /// XRP classic address, e.g. `rnBFvgZphmN39GWzUJeUitaP22Fr9be75H`.
+    XrpClassic = 7,
+    /// XRP X-address, e.g. `X76UnYEMbQfEs3mUqgtjp4zFy9exgSxWAqcQwu9z2r5d7Tm`.
+    /// A destination tag is dropped on conversion to [`AddressFormat::XrpClassic`].
+    XrpXAddress = 8,
+}
+
+/// The address payload that is common for the formats of the same family.
+enum AddressPayload {
+    Evm(H160),
+    Ton(AddressData),
+    Xrp(H160),
+}
+
+pub struct AddressConverter;
+
+impl AddressConverter {
+    /// Converts the `address` from the `from` format to the `to` format.
+    ///
+    /// \param hrp human-readable part of the result address, required if `to` is [`AddressFormat::CosmosBech32`].
+    pub fn convert(
+        address: &str,
+        from: AddressFormat,
+        to: AddressFormat,
+        hrp: Option<&str>,
+    ) -> AddressResult<String> {
+        let payload = Self::parse(address, from)?;
+        Self::format(payload, to, hrp)
+    }
+
+    fn parse(address: &str, format: AddressFormat) -> AddressResult<AddressPayload> {
+        match format {
+            AddressFormat::EvmHex => {
+                EvmAddress::from_str(address).map(|addr| AddressPayload::Evm(addr.bytes()))
+            },
+            AddressFormat::CosmosBech32 => {
+                let addr = Bech32Address::from_str(address)?;
+                let key_hash =
+                    H160::try_from(addr.key_hash()).map_err(|_| AddressError::InvalidInput)?;
+                Ok(AddressPayload::Evm(key_hash))
+            },
+            AddressFormat::TronBase58 | AddressFormat::FilecoinDelegated => {
+                Err(AddressError::Unsupported)
+            },
+            AddressFormat::TonRaw => {
+                RawAddress::from_str(address).map(|addr| AddressPayload::Ton(addr.into_data()))
+            },
+            AddressFormat::TonBounceable | AddressFormat::TonNonBounceable => {
+                let addr = TonAddress::from_base64_url(address)
+                    .or_else(|_| TonAddress::from_base64_std(address))?;
+                // E.g. a non-bounceable `UQ...` address is not a `TonBounceable` one.
+                if addr.bounceable() != (format == AddressFormat::TonBounceable) {
+                    return Err(AddressError::InvalidInput);
+                }
+                let data: &AddressData = addr.as_ref();
+                Ok(AddressPayload::Ton(data.clone()))
+            },
+            AddressFormat::XrpClassic => ClassicAddress::from_str(address)
+                .map(|addr| AddressPayload::Xrp(addr.public_key_hash())),
+            AddressFormat::XrpXAddress => {
+                XAddress::from_str(address).map(|addr| AddressPayload::Xrp(addr.public_key_hash()))
+            },
+        }
+    }
+
+    fn format(
+        payload: AddressPayload,
+        format: AddressFormat,
+        hrp: Option<&str>,
+    ) -> AddressResult<String> {
+        match (payload, format) {
+            (AddressPayload::Evm(key_hash), AddressFormat::EvmHex) => {
+                Ok(EvmAddress::from_bytes(key_hash).into_checksum_address())
+            },
+            (AddressPayload::Evm(key_hash), AddressFormat::CosmosBech32) => {
+                let hrp = hrp.ok_or(AddressError::InvalidHrp)?;
+                Bech32Address::new(hrp.to_string(), key_hash.to_vec()).map(|addr| addr.to_string())
+            },
+            (AddressPayload::Ton(data), AddressFormat::TonRaw) => {
+                Ok(RawAddress::from(data).to_string())
+            },
+            (AddressPayload::Ton(data), AddressFormat::TonBounceable) => {
+                Ok(TonAddress::with_address_data(data)
+                    .set_bounceable(true)
+                    .to_string())
+            },
+            (AddressPayload::Ton(data), AddressFormat::TonNonBounceable) => {
+                Ok(TonAddress::with_address_data(data)
+                    .set_bounceable(false)
+                    .to_string())
+            },
+            (AddressPayload::Xrp(key_hash), AddressFormat::XrpClassic) => {
+                ClassicAddress::new(key_hash.as_slice()).map(|addr| addr.to_string())
+            },
+            (AddressPayload::Xrp(key_hash), AddressFormat::XrpXAddress) => {
+                XAddress::new(key_hash, None).map(|addr| addr.to_string())
+            },
+            // The formats belong to different families.
+            _ => Err(AddressError::Unsupported),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use crate::address_converter::{AddressConverter, AddressFormat};
+use tw_macros::tw_catch_panic;
+use tw_memory::ffi::tw_string::TWString;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+
+/// Converts an address between the formats of the same key hash,
+/// e.g. Evmos bech32 to `0x` hex, or TON raw to user-friendly.
+/// Internal, the C++ `TWAddressConverterConvert` wraps it to convert Tron and Filecoin addresses too.
+///
+/// \param address address to be converted.
+/// \param from `TWAddressFormat` of the given address.
+/// \param to `TWAddressFormat` of the result address.
+/// \param hrp human-readable part of the result address, required if `to` is `TWAddressFormatCosmosBech32`.
+/// \return the converted address. Null if the address is invalid, or the formats cannot be converted to each other.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_address_converter_convert(
+    address: *const TWString,
+    from: u32,
+    to: u32,
+    hrp: *const TWString,
+) -> *mut TWString {
+    let address = try_or_else!(TWString::from_ptr_as_ref(address), std::ptr::null_mut);
+    let address = try_or_else!(address.as_str(), std::ptr::null_mut);
+    let from = try_or_else!(AddressFormat::from_repr(from), std::ptr::null_mut);
+    let to = try_or_else!(AddressFormat::from_repr(to), std::ptr::null_mut);
+
+    let hrp = match TWString::from_ptr_as_ref(hrp) {
+        Some(hrp) => Some(try_or_else!(hrp.as_str(), std::ptr::null_mut)),
+        None => None,
+    };
+
+    let converted = try_or_else!(
+        AddressConverter::convert(address, from, to, hrp),
+        std::ptr::null_mut
+    );
+    TWString::from(converted).into_ptr()
+}
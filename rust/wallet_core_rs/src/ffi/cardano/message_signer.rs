@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_cardano::modules::message_signer::CardanoMessageSigner;
+use tw_encoding::hex::{DecodeHex, ToHex};
+use tw_keypair::ffi::privkey::TWPrivateKey;
+use tw_macros::tw_ffi;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::tw_string::TWString;
+use tw_memory::ffi::{Nonnull, NonnullMut, RawPtrTrait};
+use tw_misc::{try_or_else, try_or_false};
+
+/// Sign a payload by the key the address belongs to, as described in https://cips.cardano.org/cip/CIP-0030
+///
+/// \param private_key: Cardano extended private key used for signing
+/// \param address: the address requested by the dApp, the staking key signs for a reward address
+/// \param payload: the payload to sign
+/// \returns CIP-30 `DataSignature` as JSON: `{"key": "<COSE_Key hex>", "signature": "<COSE_Sign1 hex>"}`.
+/// On invalid input empty string is returned. Returned object needs to be deleted after use.
+#[tw_ffi(ty = static_function, class = TWCardanoMessageSigner, name = SignData)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_cardano_message_signer_sign_data(
+    private_key: Nonnull<TWPrivateKey>,
+    address: Nonnull<TWString>,
+    payload: Nonnull<TWData>,
+) -> NonnullMut<TWString> {
+    let private_key = try_or_else!(
+        TWPrivateKey::from_ptr_as_ref(private_key),
+        std::ptr::null_mut
+    );
+    let private_key_bytes = try_or_else!(
+        private_key.as_ref().extended_cardano_key(),
+        std::ptr::null_mut
+    );
+    let address = try_or_else!(TWString::from_ptr_as_ref(address), std::ptr::null_mut);
+    let address_str = try_or_else!(address.as_str(), std::ptr::null_mut);
+    let payload = try_or_else!(TWData::from_ptr_as_ref(payload), std::ptr::null_mut);
+
+    let data_signature = try_or_else!(
+        CardanoMessageSigner::sign_data(private_key_bytes, address_str, payload.as_slice()),
+        std::ptr::null_mut
+    );
+    // Hex strings don't need to be escaped.
+    let json = format!(
+        r#"{{"key":"{}","signature":"{}"}}"#,
+        data_signature.key.to_hex(),
+        data_signature.signature.to_hex()
+    );
+    TWString::from(json).into_ptr()
+}
+
+/// Verify CIP-30 `DataSignature` for a payload.
+///
+/// \param address: the address the payload is signed for
+/// \param payload: the payload signed
+/// \param signature: `COSE_Sign1` in Hex-encoded form
+/// \param key: `COSE_Key` in Hex-encoded form
+/// \returns false on any invalid input (does not throw), true if the signature is valid
+#[tw_ffi(ty = static_function, class = TWCardanoMessageSigner, name = VerifyData)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_cardano_message_signer_verify_data(
+    address: Nonnull<TWString>,
+    payload: Nonnull<TWData>,
+    signature: Nonnull<TWString>,
+    key: Nonnull<TWString>,
+) -> bool {
+    let address = try_or_false!(TWString::from_ptr_as_ref(address));
+    let address_str = try_or_false!(address.as_str());
+    let payload = try_or_false!(TWData::from_ptr_as_ref(payload));
+    let signature = try_or_false!(TWString::from_ptr_as_ref(signature));
+    let signature_str = try_or_false!(signature.as_str());
+    let signature = try_or_false!(signature_str.decode_hex());
+    let key = try_or_false!(TWString::from_ptr_as_ref(key));
+    let key_str = try_or_false!(key.as_str());
+    let key = try_or_false!(key_str.decode_hex());
+
+    CardanoMessageSigner::verify_data(address_str, payload.as_slice(), &signature, &key)
+}
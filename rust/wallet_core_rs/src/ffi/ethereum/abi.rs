@@ -6,6 +6,7 @@
 
 use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::evm_dispatcher;
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::tw_string::TWString;
 use tw_memory::ffi::RawPtrTrait;
@@ -17,6 +18,7 @@ use tw_misc::try_or_else;
 /// \param input The serialized data of `TW.EthereumAbi.Proto.ContractCallDecodingInput`.
 /// \return serialized `EthereumAbi::Proto::ContractCallDecodingOutput`.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_ethereum_abi_decode_contract_call(
     coin: u32,
     input: *const TWData,
@@ -31,12 +33,34 @@ pub unsafe extern "C" fn tw_ethereum_abi_decode_contract_call(
         .unwrap_or_else(|_| std::ptr::null_mut())
 }
 
+/// Decode function call data according to a user-supplied ABI or the built-in ERC-20, ERC-721 and ERC-1155 ABIs.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input The serialized data of `TW.EthereumAbi.Proto.CalldataDecodingInput`.
+/// \return The serialized data of a `TW.EthereumAbi.Proto.CalldataDecodingOutput` proto object.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_ethereum_abi_decode_calldata(
+    coin: u32,
+    input: *const TWData,
+) -> *mut TWData {
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+
+    evm_dispatcher
+        .decode_abi_calldata(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
+
 /// Decode a function input or output data according to a given ABI.
 ///
 /// \param coin EVM-compatible coin type.
 /// \param input The serialized data of `TW.EthereumAbi.Proto.ParamsDecodingInput`.
 /// \return The serialized data of a `TW.EthereumAbi.Proto.ParamsDecodingOutput` proto object.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_ethereum_abi_decode_params(
     coin: u32,
     input: *const TWData,
@@ -57,6 +81,7 @@ pub unsafe extern "C" fn tw_ethereum_abi_decode_params(
 /// \param input The serialized data of `TW.EthereumAbi.Proto.FunctionGetTypeInput`.
 /// \return function type signature as a Non-null string.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_ethereum_abi_function_get_type(
     coin: u32,
     input: *const TWData,
@@ -78,6 +103,7 @@ pub unsafe extern "C" fn tw_ethereum_abi_function_get_type(
 /// \param abi The function ABI json string, for example: {"inputs":[{"internalType":"bool","name":"arg1","type":"bool"}],"name":"fun1","outputs":[],"stateMutability":"nonpayable","type":"function"}
 /// \return function type signature, null if the input is invalid.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_ethereum_abi_get_function_signature(
     coin: u32,
     abi: *const TWString,
@@ -99,6 +125,7 @@ pub unsafe extern "C" fn tw_ethereum_abi_get_function_signature(
 /// \param input The serialized data of `TW.EthereumAbi.Proto.FunctionEncodingInput`.
 /// \return The serialized data of a `TW.EthereumAbi.Proto.FunctionEncodingOutput` proto object.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_ethereum_abi_encode_function(
     coin: u32,
     input: *const TWData,
@@ -119,6 +146,7 @@ pub unsafe extern "C" fn tw_ethereum_abi_encode_function(
 /// \param input The serialized data of `TW.EthereumAbi.Proto.ValueDecodingInput`.
 /// \return The serialized data of a `TW.EthereumAbi.Proto.ValueDecodingOutput` proto object.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_ethereum_abi_decode_value(
     coin: u32,
     input: *const TWData,
@@ -3,4 +3,6 @@
 // Copyright © 2017 Trust Wallet.
 
 pub mod abi;
+pub mod permit;
+pub mod risk;
 pub mod rlp;
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_any_coin::TWFFICoinType;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::dispatcher::evm_dispatcher;
+use tw_macros::tw_ffi;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::{Nonnull, NullableMut, RawPtrTrait};
+use tw_misc::try_or_else;
+
+/// Builds the EIP-712 typed data of an ERC-2612 permit, or a Uniswap Permit2 `PermitSingle` or `PermitBatch`,
+/// and signs it with the token owner private key.
+///
+/// \param coin EVM-compatible coin type.
+/// \param input Non-null serialized `EthereumPermit::Proto::SigningInput`.
+/// \return serialized `EthereumPermit::Proto::SigningOutput`, or null if the coin is not EVM-compatible.
+#[tw_ffi(ty = static_function, class = TWEthereum, name = SignPermit)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_ethereum_sign_permit(
+    coin: TWFFICoinType,
+    input: Nonnull<TWData>,
+) -> NullableMut<TWData> {
+    let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let evm_dispatcher = try_or_else!(evm_dispatcher(coin), std::ptr::null_mut);
+    evm_dispatcher
+        .sign_permit(input_data.as_slice())
+        .map(|data| TWData::from(data).into_ptr())
+        .unwrap_or_else(|_| std::ptr::null_mut())
+}
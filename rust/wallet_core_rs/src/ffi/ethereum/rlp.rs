@@ -6,6 +6,7 @@
 
 use tw_coin_registry::coin_type::CoinType;
 use tw_coin_registry::dispatcher::evm_dispatcher;
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::tw_data::TWData;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::try_or_else;
@@ -16,6 +17,7 @@ use tw_misc::try_or_else;
 /// \param input Non-null serialized `EthereumRlp::Proto::EncodingInput`.
 /// \return serialized `EthereumRlp::Proto::EncodingOutput`.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_ethereum_rlp_encode(coin: u32, input: *const TWData) -> *mut TWData {
     let coin = try_or_else!(CoinType::try_from(coin), std::ptr::null_mut);
     let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
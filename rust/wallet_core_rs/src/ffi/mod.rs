@@ -2,12 +2,18 @@
 //
 // Copyright © 2017 Trust Wallet.
 
+#[cfg(feature = "address-converter")]
+pub mod address_converter;
 #[cfg(feature = "bitcoin")]
 pub mod bitcoin;
+#[cfg(feature = "cardano")]
+pub mod cardano;
 #[cfg(feature = "ethereum")]
 pub mod ethereum;
 #[cfg(feature = "solana")]
 pub mod solana;
+#[cfg(feature = "thorchain")]
+pub mod thorchain;
 #[cfg(feature = "ton")]
 pub mod ton;
 #[cfg(feature = "utils")]
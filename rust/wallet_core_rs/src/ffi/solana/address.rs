@@ -5,6 +5,7 @@
 #![allow(clippy::missing_safety_doc)]
 
 use std::str::FromStr;
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::tw_string::TWString;
 use tw_memory::ffi::RawPtrTrait;
 use tw_misc::try_or_else;
@@ -18,6 +19,7 @@ use tw_solana::program::stake_program::StakeProgram;
 /// \param token_mint_address Non-null pointer to a token mint address as a string
 /// \return Null pointer if the Default token address for a token is not found, valid pointer otherwise
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_solana_address_default_token_address(
     address: *const TWString,
     token_mint_address: *const TWString,
@@ -31,6 +33,7 @@ pub unsafe extern "C" fn tw_solana_address_default_token_address(
 /// \param token_mint_address Non-null pointer to a token mint address as a string
 /// \return Null pointer if the token 2022 address for a token is not found, valid pointer otherwise
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_solana_address_token_2022_address(
     address: *const TWString,
     token_mint_address: *const TWString,
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#![allow(clippy::missing_safety_doc)]
+
+use tw_macros::tw_catch_panic;
+use tw_memory::ffi::tw_data::TWData;
+use tw_memory::ffi::tw_string::TWString;
+use tw_memory::ffi::RawPtrTrait;
+use tw_misc::try_or_else;
+use tw_proto::THORChainSwap::Proto;
+use tw_thorchain::memo::{Memo, Protocol, SwapMemo};
+
+/// Builds a THORChain swap memo, e.g. `=:ETH.ETH:0xb9f5771c27664bf2282d98e09d7f50cec7cb01a7:140000000000000000`.
+///
+/// \param input Non-null serialized `THORChainSwap::Proto::SwapInput`.
+/// Only the destination, limit, streaming, affiliate and extra memo fields are used.
+/// \param shortened Whether to use the `=` alias instead of the `SWAP` action.
+/// \return the memo, or null if any of the fields is invalid.
+#[no_mangle]
+#[tw_catch_panic]
+pub unsafe extern "C" fn tw_thorchain_swap_build_memo(
+    input: *const TWData,
+    shortened: bool,
+) -> *mut TWString {
+    let input_data = try_or_else!(TWData::from_ptr_as_ref(input), std::ptr::null_mut);
+    let input: Proto::SwapInput = try_or_else!(
+        tw_proto::deserialize(input_data.as_slice()),
+        std::ptr::null_mut
+    );
+    let swap = try_or_else!(SwapMemo::from_swap_input(&input), std::ptr::null_mut);
+    let memo = try_or_else!(
+        Memo::Swap(swap).build(Protocol::Thorchain, shortened),
+        std::ptr::null_mut
+    );
+    TWString::from(memo).into_ptr()
+}
@@ -4,14 +4,18 @@
 
 #![allow(clippy::missing_safety_doc)]
 
+use std::str::FromStr;
+use tw_encoding::base64::{self, STANDARD};
 use tw_encoding::hex::ToHex;
-use tw_keypair::ed25519;
 use tw_keypair::ffi::privkey::TWPrivateKey;
+use tw_keypair::tw::Curve;
 use tw_macros::tw_ffi;
 use tw_memory::ffi::tw_string::TWString;
 use tw_memory::ffi::{Nonnull, NullableMut, RawPtrTrait};
 use tw_misc::try_or_else;
+use tw_ton::address::TonAddress;
 use tw_ton::modules::personal_message_signer::PersonalMessageSigner;
+use tw_ton::modules::ton_proof::TonProof;
 
 /// Signs an arbitrary message to prove ownership of an address for off-chain services.
 /// https://github.com/ton-foundation/specs/blob/main/specs/wtf-0002.md
@@ -29,18 +33,63 @@ pub unsafe extern "C" fn tw_ton_message_signer_sign_message(
         TWPrivateKey::from_ptr_as_ref(private_key),
         std::ptr::null_mut
     );
-    let private_key_bytes = private_key.as_ref().key();
-    let ed25519_private_key = try_or_else!(
-        ed25519::sha512::PrivateKey::try_from(private_key_bytes.as_slice()),
-        std::ptr::null_mut
-    );
 
     let message = try_or_else!(TWString::from_ptr_as_ref(message), std::ptr::null_mut);
     let message_str = try_or_else!(message.as_str(), std::ptr::null_mut);
 
+    // Sign through the key, so non-exportable keys can sign too.
+    let msg_to_sign = PersonalMessageSigner::message_to_sign(message_str);
+    let signature = try_or_else!(
+        private_key.as_ref().sign(&msg_to_sign, Curve::Ed25519),
+        std::ptr::null_mut
+    );
+    TWString::from(signature.to_hex()).into_ptr()
+}
+
+/// Signs a TON Connect `ton_proof` to prove ownership of a wallet address to a dApp.
+/// https://docs.ton.org/develop/dapps/ton-connect/sign
+///
+/// \param private_key: the private key used for signing
+/// \param address: the wallet address in any supported format.
+/// \param domain: the dApp domain, e.g. "ton-connect.github.io".
+/// \param timestamp: UNIX timestamp of the signing moment in seconds.
+/// \param payload: the payload requested by the dApp.
+/// \returns the signature, Base64-encoded. On invalid input null is returned. Returned object needs to be deleted after use.
+#[tw_ffi(ty = static_function, class = TWTONMessageSigner, name = SignTonProof)]
+#[no_mangle]
+pub unsafe extern "C" fn tw_ton_message_signer_sign_ton_proof(
+    private_key: Nonnull<TWPrivateKey>,
+    address: Nonnull<TWString>,
+    domain: Nonnull<TWString>,
+    timestamp: u64,
+    payload: Nonnull<TWString>,
+) -> NullableMut<TWString> {
+    let private_key = try_or_else!(
+        TWPrivateKey::from_ptr_as_ref(private_key),
+        std::ptr::null_mut
+    );
+
+    let address = try_or_else!(TWString::from_ptr_as_ref(address), std::ptr::null_mut);
+    let address_str = try_or_else!(address.as_str(), std::ptr::null_mut);
+    let address = try_or_else!(TonAddress::from_str(address_str), std::ptr::null_mut);
+
+    let domain = try_or_else!(TWString::from_ptr_as_ref(domain), std::ptr::null_mut);
+    let domain_str = try_or_else!(domain.as_str(), std::ptr::null_mut);
+
+    let payload = try_or_else!(TWString::from_ptr_as_ref(payload), std::ptr::null_mut);
+    let payload_str = try_or_else!(payload.as_str(), std::ptr::null_mut);
+
+    let proof = TonProof {
+        address,
+        domain: domain_str.to_string(),
+        timestamp,
+        payload: payload_str.to_string(),
+    };
     let signature = try_or_else!(
-        PersonalMessageSigner::sign(&ed25519_private_key, message_str),
+        private_key
+            .as_ref()
+            .sign(&proof.message_to_sign(), Curve::Ed25519),
         std::ptr::null_mut
     );
-    TWString::from(signature.to_bytes().to_hex()).into_ptr()
+    TWString::from(base64::encode(&signature, STANDARD)).into_ptr()
 }
@@ -5,6 +5,7 @@
 #![allow(clippy::missing_safety_doc)]
 
 use bitreader::{BitReader, BitReaderError};
+use tw_macros::tw_catch_panic;
 use tw_memory::ffi::c_byte_array::{CByteArray, CByteArrayResult};
 use tw_memory::ffi::c_result::{CUInt8Result, ErrorCode};
 use tw_memory::ffi::tw_data::TWData;
@@ -98,6 +99,7 @@ impl RawPtrTrait for TWBitReader {}
 /// \param bit_len length this reader is allowed to read from the slice.
 /// \return nullable pointer to a `TWBitReader` instance.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_bit_reader_create(
     data: *const TWData,
     bit_len: u64,
@@ -109,6 +111,7 @@ pub unsafe extern "C" fn tw_bit_reader_create(
 /// Deletes a `TWBitReader` and frees the memory.
 /// \param reader a `TWBitReader` pointer.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_bit_reader_delete(reader: *mut TWBitReader) {
     // Take the ownership back to rust and drop the owner.
     let _ = TWBitReader::from_ptr(reader);
@@ -120,6 +123,7 @@ pub unsafe extern "C" fn tw_bit_reader_delete(reader: *mut TWBitReader) {
 /// \param bit_count number of bits to read. Expected from 1 to 8.
 /// \return u8 or error.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_bit_reader_read_u8(
     reader: *mut TWBitReader,
     bit_count: u8,
@@ -138,6 +142,7 @@ pub unsafe extern "C" fn tw_bit_reader_read_u8(
 /// \param byte_count number of bytes to read.
 /// \return byte array or error.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_bit_reader_read_u8_slice(
     reader: *mut TWBitReader,
     byte_count: usize,
@@ -156,6 +161,7 @@ pub unsafe extern "C" fn tw_bit_reader_read_u8_slice(
 /// \param reader a `TWBitReader` pointer.
 /// \return whether all bits were read.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_bit_reader_finished(reader: *const TWBitReader) -> bool {
     try_or_else!(TWBitReader::from_ptr_as_ref(reader), || true).is_finished()
 }
@@ -5,11 +5,13 @@
 #![allow(clippy::missing_safety_doc)]
 
 use std::ffi::{c_char, CString};
+use tw_macros::tw_catch_panic;
 
 /// Creates a random UUID.
 /// This uses the [`getrandom`] crate to utilise the operating system's RNG
 /// as the source of random numbers.
 #[no_mangle]
+#[tw_catch_panic]
 pub unsafe extern "C" fn tw_uuid_random() -> *mut c_char {
     let res = uuid::Uuid::new_v4();
     CString::new(res.to_string()).unwrap().into_raw()
@@ -17,4 +17,8 @@ pub extern crate tw_keypair;
 #[cfg(feature = "utils")]
 pub extern crate tw_memory;
 
+#[cfg(feature = "address-converter")]
+pub mod address_converter;
 pub mod ffi;
+#[cfg(feature = "wasm")]
+pub mod wasm;
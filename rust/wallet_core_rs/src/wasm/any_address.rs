@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::wasm::error::{invalid_coin_type, js_error};
+use crate::wasm::public_key::WasmPublicKey;
+use tw_any_coin::any_address::AnyAddress;
+use tw_coin_entry::derivation::Derivation;
+use tw_coin_registry::coin_type::CoinType;
+use tw_coin_registry::tw_derivation::TWDerivation;
+use wasm_bindgen::prelude::*;
+
+/// Determines if the string is a valid address of the `coin` blockchain.
+#[wasm_bindgen(js_name = isValidAddress)]
+pub fn is_valid_address(address: &str, coin: u32) -> bool {
+    CoinType::try_from(coin)
+        .map(|coin| AnyAddress::is_valid(coin, address, None))
+        .unwrap_or_default()
+}
+
+/// Derives an address of the `coin` blockchain from the public key using the given `derivation`.
+/// Pass `0` to use the default derivation.
+#[wasm_bindgen(js_name = deriveAddress)]
+pub fn derive_address(
+    public_key: &WasmPublicKey,
+    coin: u32,
+    derivation: u32,
+) -> Result<String, JsError> {
+    let coin = CoinType::try_from(coin).map_err(|_| invalid_coin_type(coin))?;
+    let derivation = TWDerivation::from_repr(derivation)
+        .ok_or_else(|| JsError::new(&format!("Unknown derivation: {derivation}")))?;
+
+    AnyAddress::with_public_key(
+        coin,
+        public_key.inner().clone(),
+        Derivation::from(derivation),
+        None,
+    )
+    .map(|address| address.description().to_string())
+    .map_err(js_error)
+}
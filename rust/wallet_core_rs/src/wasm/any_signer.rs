@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::wasm::error::{invalid_coin_type, js_error_debug};
+use tw_any_coin::any_signer::AnySigner;
+use tw_coin_registry::coin_type::CoinType;
+use wasm_bindgen::prelude::*;
+
+/// Signs a transaction specified by the serialized `SigningInput` protobuf message and the coin type.
+/// Returns a serialized `SigningOutput` protobuf message.
+#[wasm_bindgen(js_name = anySignerSign)]
+pub fn any_signer_sign(input: &[u8], coin: u32) -> Result<Vec<u8>, JsError> {
+    let coin = CoinType::try_from(coin).map_err(|_| invalid_coin_type(coin))?;
+    AnySigner::sign(input, coin).map_err(js_error_debug)
+}
+
+/// Plans a transaction (for UTXO chains only) specified by the serialized `SigningInput` protobuf message.
+/// Returns a serialized `TransactionPlan` protobuf message.
+#[wasm_bindgen(js_name = anySignerPlan)]
+pub fn any_signer_plan(input: &[u8], coin: u32) -> Result<Vec<u8>, JsError> {
+    let coin = CoinType::try_from(coin).map_err(|_| invalid_coin_type(coin))?;
+    AnySigner::plan(input, coin).map_err(js_error_debug)
+}
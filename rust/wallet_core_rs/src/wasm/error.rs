@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use std::fmt;
+use wasm_bindgen::JsError;
+
+/// Converts an error into a JavaScript `Error` using its [`fmt::Display`] representation.
+pub(crate) fn js_error<E: fmt::Display>(error: E) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+/// Converts an error into a JavaScript `Error` using its [`fmt::Debug`] representation.
+/// Used for errors that don't implement [`fmt::Display`], e.g. signing errors with a context.
+pub(crate) fn js_error_debug<E: fmt::Debug>(error: E) -> JsError {
+    JsError::new(&format!("{error:?}"))
+}
+
+pub(crate) fn invalid_coin_type(coin: u32) -> JsError {
+    JsError::new(&format!("Unknown coin type: {coin}"))
+}
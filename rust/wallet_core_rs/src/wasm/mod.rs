@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! `wasm-bindgen` exports of the keypair and signer APIs.
+//!
+//! Build the module with:
+//! ```shell
+//! cargo rustc -p wallet-core-rs --release --no-default-features --features wasm --target wasm32-unknown-unknown --crate-type cdylib
+//! ```
+//!
+//! All the fallible functions throw a JavaScript `Error` with a descriptive message.
+
+pub mod any_address;
+pub mod any_signer;
+mod error;
+pub mod private_key;
+pub mod public_key;
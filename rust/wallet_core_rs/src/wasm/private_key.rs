@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::wasm::error::js_error;
+use crate::wasm::public_key::WasmPublicKey;
+use tw_keypair::tw::{Curve, PrivateKey, PublicKeyType};
+use wasm_bindgen::prelude::*;
+
+/// Represents a private key that can be used to sign messages with different elliptic curves.
+#[wasm_bindgen(js_name = PrivateKey)]
+pub struct WasmPrivateKey(PrivateKey);
+
+#[wasm_bindgen(js_class = PrivateKey)]
+impl WasmPrivateKey {
+    /// Validates the given `bytes` secret and creates a private key.
+    #[wasm_bindgen(constructor)]
+    pub fn new(bytes: &[u8]) -> Result<WasmPrivateKey, JsError> {
+        PrivateKey::new(bytes.to_vec())
+            .map(WasmPrivateKey)
+            .map_err(js_error)
+    }
+
+    /// Checks if the given `bytes` secret is valid for the `curve`.
+    #[wasm_bindgen(js_name = isValid)]
+    pub fn is_valid(bytes: &[u8], curve: u32) -> bool {
+        Curve::from_raw(curve)
+            .map(|curve| PrivateKey::is_valid(bytes, curve))
+            .unwrap_or_default()
+    }
+
+    /// Returns the private key bytes.
+    /// Fails if the private key is non-exportable.
+    pub fn data(&self) -> Result<Vec<u8>, JsError> {
+        self.0.bytes().map(<[u8]>::to_vec).map_err(js_error)
+    }
+
+    /// Signs a `message` with using the given elliptic `curve`.
+    pub fn sign(&self, message: &[u8], curve: u32) -> Result<Vec<u8>, JsError> {
+        let curve = Curve::from_raw(curve)
+            .ok_or_else(|| JsError::new(&format!("Unknown curve: {curve}")))?;
+        self.0.sign(message, curve).map_err(js_error)
+    }
+
+    /// Returns the public key of the `pubkey_type` type associated with the private key.
+    #[wasm_bindgen(js_name = getPublicKey)]
+    pub fn get_public_key(&self, pubkey_type: u32) -> Result<WasmPublicKey, JsError> {
+        let ty = PublicKeyType::from_raw(pubkey_type)
+            .ok_or_else(|| JsError::new(&format!("Unknown public key type: {pubkey_type}")))?;
+        self.0
+            .get_public_key_by_type(ty)
+            .map(WasmPublicKey::from)
+            .map_err(js_error)
+    }
+}
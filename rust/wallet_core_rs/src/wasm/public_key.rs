@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+use crate::wasm::error::js_error;
+use tw_keypair::tw::{PublicKey, PublicKeyType};
+use wasm_bindgen::prelude::*;
+
+/// Represents a public key that can be used to verify signatures and derive addresses.
+#[wasm_bindgen(js_name = PublicKey)]
+pub struct WasmPublicKey(PublicKey);
+
+impl WasmPublicKey {
+    pub fn inner(&self) -> &PublicKey {
+        &self.0
+    }
+}
+
+impl From<PublicKey> for WasmPublicKey {
+    fn from(public: PublicKey) -> Self {
+        WasmPublicKey(public)
+    }
+}
+
+#[wasm_bindgen(js_class = PublicKey)]
+impl WasmPublicKey {
+    /// Validates the given `bytes` using the `pubkey_type` public key type and creates a public key from it.
+    #[wasm_bindgen(constructor)]
+    pub fn new(bytes: &[u8], pubkey_type: u32) -> Result<WasmPublicKey, JsError> {
+        let ty = PublicKeyType::from_raw(pubkey_type)
+            .ok_or_else(|| JsError::new(&format!("Unknown public key type: {pubkey_type}")))?;
+        PublicKey::new(bytes.to_vec(), ty)
+            .map(WasmPublicKey)
+            .map_err(js_error)
+    }
+
+    /// Returns the raw data of the public key.
+    pub fn data(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Returns the public key type.
+    #[wasm_bindgen(js_name = keyType)]
+    pub fn key_type(&self) -> u32 {
+        self.0.public_key_type() as u32
+    }
+
+    /// Verifies if the given `message` was signed using a private key associated with the public key.
+    pub fn verify(&self, signature: &[u8], message: &[u8]) -> bool {
+        self.0.verify(signature, message)
+    }
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+//! Smoke tests of the `wasm-bindgen` exports, run by `wasm-bindgen-test-runner`:
+//! ```shell
+//! CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//!   cargo test -p wallet-core-rs --no-default-features --features wasm --target wasm32-unknown-unknown
+//! ```
+
+#![cfg(all(target_arch = "wasm32", target_os = "unknown", feature = "wasm"))]
+
+use tw_encoding::hex::DecodeHex;
+use wallet_core_rs::wasm::any_address::{derive_address, is_valid_address};
+use wallet_core_rs::wasm::any_signer::any_signer_sign;
+use wallet_core_rs::wasm::private_key::WasmPrivateKey;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const ETHEREUM: u32 = 60;
+const SECP256K1: u32 = 0;
+const SECP256K1_EXTENDED: u32 = 1;
+const PRIVATE_KEY: &str = "afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5";
+const DIGEST: &str = "1fcbf0b6d3f2d5a4d6b3e0bc3f5b6c9ec5e7f5a6d3b0e4c4b2a3f5e6d7c8b9a0";
+
+#[wasm_bindgen_test]
+fn test_wasm_private_key_sign_verify() {
+    let private_key = WasmPrivateKey::new(&PRIVATE_KEY.decode_hex().unwrap()).unwrap();
+    let public_key = private_key.get_public_key(SECP256K1).unwrap();
+    assert_eq!(public_key.key_type(), SECP256K1);
+
+    let digest = DIGEST.decode_hex().unwrap();
+    let signature = private_key.sign(&digest, SECP256K1).unwrap();
+    assert!(public_key.verify(&signature, &digest));
+    assert!(!public_key.verify(&signature, &[0; 32]));
+
+    assert!(WasmPrivateKey::new(&[0; 32]).is_err());
+    assert!(private_key.sign(&digest, u32::MAX).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_wasm_derive_address() {
+    let private_key = WasmPrivateKey::new(&PRIVATE_KEY.decode_hex().unwrap()).unwrap();
+    let public_key = private_key.get_public_key(SECP256K1_EXTENDED).unwrap();
+
+    let address = derive_address(&public_key, ETHEREUM, 0).unwrap();
+    assert_eq!(address, "0xAc1ec44E4f0ca7D172B7803f6836De87Fb72b309");
+    assert!(is_valid_address(&address, ETHEREUM));
+    assert!(!is_valid_address(&address, u32::MAX));
+}
+
+#[wasm_bindgen_test]
+fn test_wasm_any_signer_unknown_coin() {
+    assert!(any_signer_sign(&[], u32::MAX).is_err());
+}
@@ -70,6 +70,8 @@
 #include "Pactus/Entry.h"
 #include "Komodo/Entry.h"
 #include "Polymesh/Entry.h"
+#include "Liquid/Entry.h"
+#include "Avalanche/Entry.h"
 // end_of_coin_includes_marker_do_not_modify
 
 using namespace TW;
@@ -133,6 +135,8 @@ BitcoinCash::Entry BitcoinCashDP;
 Pactus::Entry PactusDP;
 Komodo::Entry KomodoDP;
 Polymesh::Entry PolymeshDP;
+Liquid::Entry LiquidDP;
+Avalanche::Entry AvalancheDP;
 // end_of_coin_dipatcher_declarations_marker_do_not_modify
 
 CoinEntry* coinDispatcher(TWCoinType coinType) {
@@ -198,6 +202,9 @@ CoinEntry* coinDispatcher(TWCoinType coinType) {
         case TWBlockchainPactus: entry = &PactusDP; break;
         case TWBlockchainKomodo: entry = &KomodoDP; break;
         case TWBlockchainPolymesh: entry = &PolymeshDP; break;
+        case TWBlockchainLiquid: entry = &LiquidDP; break;
+        case TWBlockchainAvalancheX: entry = &AvalancheDP; break;
+        case TWBlockchainAvalancheP: entry = &AvalancheDP; break;
         // end_of_coin_dipatcher_switch_marker_do_not_modify
 
         default: entry = nullptr; break;
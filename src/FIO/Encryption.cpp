@@ -75,8 +75,7 @@ Data Encryption::checkDecrypt(const Data& secret, const Data& message) {
     Data hmacInput(0);
     TW::append(hmacInput, iv);
     TW::append(hmacInput, C);
-    const Data Mc = Hash::hmac256(Km, hmacInput);
-    if (M != Mc) {
+    if (!Hash::hmac256Verify(Km, hmacInput, M)) {
         throw std::invalid_argument("Decrypt failed, HMAC mismatch");
     }
 
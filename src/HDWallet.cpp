@@ -157,6 +157,35 @@ PrivateKey HDWallet<seedSize>::getMasterKeyExtension(TWCurve curve) const {
     return PrivateKey(data, curve);
 }
 
+template <std::size_t seedSize>
+uint32_t HDWallet<seedSize>::getMasterFingerprint() const {
+    const auto publicKey = getMasterKey(TWCurveSECP256k1).getPublicKey(TWPublicKeyTypeSECP256k1);
+    return publicKey.fingerprint();
+}
+
+template <std::size_t seedSize>
+Data HDWallet<seedSize>::getAccountIdentifier(TWCoinType coin, TWDerivation derivation, uint32_t account) const {
+    // The account-level node `m/purpose'/coin'/account'`, i.e. the node of the account extended public key.
+    const auto path = TW::derivationPath(coin, derivation);
+    const auto accountPath = DerivationPath({
+        DerivationPathIndex(path.purpose(), true),
+        DerivationPathIndex(path.coin(), true),
+        DerivationPathIndex(account, true),
+    });
+    const auto curve = TWCoinTypeCurve(coin);
+    if (PrivateKey::getType(curve) == TWPrivateKeyTypeCardano) {
+        // Cardano keys are derived together with the staking key, which doesn't exist at the account level.
+        // Take the ed25519 public key of the account node instead.
+        auto node = getNode(*this, curve, accountPath);
+        hdnode_fill_public_key(&node);
+        const auto publicKey = PublicKey(Data(node.public_key + 1, node.public_key + 33), TWPublicKeyTypeED25519);
+        TW::memzero(&node);
+        return publicKey.accountIdentifier();
+    }
+    const auto publicKey = getKey(coin, accountPath).getPublicKey(TW::publicKeyType(coin));
+    return publicKey.accountIdentifier();
+}
+
 template <std::size_t seedSize>
 DerivationPath HDWallet<seedSize>::cardanoStakingDerivationPath(const DerivationPath& path) {
     DerivationPath stakingPath = path;
@@ -77,6 +77,13 @@ public:
     /// Returns the master private key extension (32 byte).
     PrivateKey getMasterKeyExtension(TWCurve curve) const;
 
+    /// Returns the BIP-32 fingerprint of the secp256k1 master key, e.g. used in PSBT key origins.
+    uint32_t getMasterFingerprint() const;
+
+    /// Returns the identifier of the account at the given derivation path with the `account` index.
+    /// It's the account identifier of the account's public key, so a watch-only account gets the same one.
+    Data getAccountIdentifier(TWCoinType coin, TWDerivation derivation, uint32_t account) const;
+
     /// Returns the private key with the given derivation.
     PrivateKey getKey(const TWCoinType coin, TWDerivation derivation) const;
 
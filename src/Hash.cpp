@@ -123,3 +123,7 @@ Data Hash::hmac256(const Data& key, const Data& message) {
     Rust::CByteArrayWrapper res = Rust::hmac__sha256(key.data(), key.size(), message.data(), message.size());
     return res.data;
 }
+
+bool Hash::hmac256Verify(const Data& key, const Data& message, const Data& mac) {
+    return Rust::hmac__sha256_verify(key.data(), key.size(), message.data(), message.size(), mac.data(), mac.size());
+}
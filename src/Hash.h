@@ -217,4 +217,7 @@ inline Data groestl512d(const byte* data, size_t size) {
 /// Compute the SHA256-based HMAC of a message
 Data hmac256(const Data& key, const Data& message);
 
+/// Checks if the `mac` is a valid SHA256-based HMAC of a message, comparing it in constant time
+bool hmac256Verify(const Data& key, const Data& message, const Data& mac);
+
 } // namespace TW::Hash
@@ -5,6 +5,7 @@
 #include "EncryptionParameters.h"
 
 #include "../Hash.h"
+#include "../memory/constant_time.h"
 
 #include <TrezorCrypto/aes.h>
 #include <TrezorCrypto/pbkdf2.h>
@@ -121,7 +122,7 @@ Data EncryptedPayload::decrypt(const Data& password) const {
         throw DecryptionError::unsupportedKDF;
     }
 
-    if (mac != _mac) {
+    if (!constantTimeEqual(mac, _mac)) {
         throw DecryptionError::invalidPassword;
     }
 
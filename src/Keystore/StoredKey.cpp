@@ -8,6 +8,8 @@
 #include "HexCoding.h"
 #include "Mnemonic.h"
 #include "PrivateKey.h"
+#include "rust/bindgen/WalletCoreRSBindgen.h"
+#include "rust/Wrapper.h"
 
 #include <nlohmann/json.hpp>
 #include <TrezorCrypto/memzero.h>
@@ -482,4 +484,27 @@ StoredKey StoredKey::load(const std::string& path) {
     return createWithJson(j);
 }
 
+Data StoredKey::exportBackup(const Data& password, const Data& metadata) const {
+    const auto payload = json().dump();
+    Rust::CByteArrayResultWrapper res = Rust::tw_encrypted_backup_create(
+        reinterpret_cast<const uint8_t*>(payload.data()), payload.size(),
+        password.data(), password.size(),
+        metadata.data(), metadata.size());
+    if (!res.isOk()) {
+        throw std::runtime_error("Can't create an encrypted backup");
+    }
+    return res.unwrap().data;
+}
+
+StoredKey StoredKey::importBackup(const Data& backup, const Data& password) {
+    Rust::CByteArrayResultWrapper res = Rust::tw_encrypted_backup_open(backup.data(), backup.size(), password.data(), password.size());
+    if (!res.isOk()) {
+        throw DecryptionError::invalidPassword;
+    }
+    auto payload = res.unwrap().data;
+    const auto parsed = nlohmann::json::parse(std::string(payload.begin(), payload.end()));
+    memzero(payload.data(), payload.size());
+    return createWithJson(parsed);
+}
+
 } // namespace TW::Keystore
@@ -153,6 +153,18 @@ public:
     /// \param path file path to store in.
     void store(const std::string& path);
 
+    /// Encrypts the key JSON into a versioned backup container (Argon2id + XChaCha20-Poly1305).
+    ///
+    /// \param password backup password, it may differ from the key password.
+    /// \param metadata plaintext wallet metadata, authenticated along with the backup.
+    /// \throws std::runtime_error if the backup can't be created.
+    Data exportBackup(const Data& password, const Data& metadata) const;
+
+    /// Decrypts a backup container created with `exportBackup`.
+    ///
+    /// \throws DecryptionError if the password is wrong or the backup has been altered.
+    static StoredKey importBackup(const Data& backup, const Data& password);
+
     /// Initializes `StoredKey` with a JSON object.
     void loadJson(const nlohmann::json& json);
 
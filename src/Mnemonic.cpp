@@ -3,11 +3,13 @@
 // Copyright © 2017 Trust Wallet.
 
 #include "Mnemonic.h"
+#include "memory/memzero_wrapper.h"
 
 #include <TrezorCrypto/bip39_english.h>
 #include <TrezorCrypto/bip39.h>
 
 #include <algorithm>
+#include <array>
 #include <string>
 #include <vector>
 #include <cassert>
@@ -16,6 +18,7 @@
 namespace TW {
 
 const int Mnemonic::SuggestMaxCount = 10;
+const int Mnemonic::SearchMaxDistance = 2;
 
 bool Mnemonic::isValid(const std::string& mnemonic) {
     return mnemonic_check(mnemonic.c_str()) != 0;
@@ -23,6 +26,97 @@ bool Mnemonic::isValid(const std::string& mnemonic) {
 
 inline const char* const* mnemonicWordlist() { return wordlist; }
 
+const char* const* Mnemonic::wordlist(MnemonicLanguage language) {
+    switch (language) {
+    case MnemonicLanguage::English:
+    default:
+        return mnemonicWordlist();
+    }
+}
+
+namespace {
+
+std::string toLower(const std::string& str) {
+    std::string lower = str;
+    std::transform(lower.begin(), lower.end(), lower.begin(),
+        [](unsigned char c){ return std::tolower(c); });
+    return lower;
+}
+
+/// Optimal string alignment distance, i.e. the Levenshtein distance that counts a transposition of adjacent letters as one edit.
+std::size_t editDistance(const std::string& lhs, const std::string& rhs) {
+    std::vector<std::vector<std::size_t>> d(lhs.size() + 1, std::vector<std::size_t>(rhs.size() + 1));
+    for (std::size_t i = 0; i <= lhs.size(); ++i) {
+        d[i][0] = i;
+    }
+    for (std::size_t j = 0; j <= rhs.size(); ++j) {
+        d[0][j] = j;
+    }
+    for (std::size_t i = 1; i <= lhs.size(); ++i) {
+        for (std::size_t j = 1; j <= rhs.size(); ++j) {
+            const std::size_t cost = lhs[i - 1] == rhs[j - 1] ? 0 : 1;
+            d[i][j] = std::min({d[i - 1][j] + 1, d[i][j - 1] + 1, d[i - 1][j - 1] + cost});
+            if (i > 1 && j > 1 && lhs[i - 1] == rhs[j - 2] && lhs[i - 2] == rhs[j - 1]) {
+                d[i][j] = std::min(d[i][j], d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    return d[lhs.size()][rhs.size()];
+}
+
+} // namespace
+
+MnemonicValidation Mnemonic::validate(const std::string& mnemonic) {
+    std::vector<std::string> words;
+    std::size_t start = 0;
+    while (true) {
+        const auto end = mnemonic.find(' ', start);
+        words.emplace_back(mnemonic.substr(start, end - start));
+        if (end == std::string::npos) {
+            break;
+        }
+        start = end + 1;
+    }
+
+    for (std::size_t i = 0; i < words.size(); ++i) {
+        if (!isValidWord(words[i])) {
+            return {MnemonicError::InvalidWord, static_cast<int>(i)};
+        }
+    }
+
+    const auto count = static_cast<int>(words.size());
+    if (count < MinWords || count > MaxWords || count % 3 != 0) {
+        return {MnemonicError::InvalidWordCount};
+    }
+    if (!isValid(mnemonic)) {
+        return {MnemonicError::InvalidChecksum};
+    }
+    return {};
+}
+
+std::optional<Data> Mnemonic::toEntropy(const std::string& mnemonic) {
+    if (!isValid(mnemonic)) {
+        return std::nullopt;
+    }
+    Data entropyRaw((MaxWords * BitsPerWord) / 8);
+    // entropy is truncated to fully bytes, 4 bytes for each 3 words (=33 bits)
+    const auto entropyBytes = mnemonic_to_bits(mnemonic.c_str(), entropyRaw.data()) / 33 * 4;
+    auto entropy = data(entropyRaw.data(), entropyBytes);
+    TW::memzero(entropyRaw.data(), entropyRaw.size());
+    return entropy;
+}
+
+std::optional<std::string> Mnemonic::fromEntropy(const Data& entropy) {
+    std::array<char, MaxWords * (BIP39_MAX_WORD_LENGTH + 3) + 20> buf{};
+    const char* mnemonicChars = mnemonic_from_data(entropy.data(), static_cast<int>(entropy.size()), buf.data(), static_cast<int>(buf.size()));
+    if (mnemonicChars == nullptr) {
+        return std::nullopt;
+    }
+    std::string mnemonic = mnemonicChars;
+    TW::memzero(buf.data(), buf.size());
+    return mnemonic;
+}
+
 bool Mnemonic::isValidWord(const std::string& word) {
     const char* wordC = word.c_str();
     const auto len = word.length();
@@ -38,39 +132,72 @@ bool Mnemonic::isValidWord(const std::string& word) {
 }
 
 std::string Mnemonic::suggest(const std::string& prefix) {
-    if (prefix.size() == 0) {
-        return "";
+    const auto result = suggest(prefix, MnemonicLanguage::English, SuggestMaxCount);
+
+    // convert results to one string
+    std::string resultString;
+    for (auto& word: result) {
+        if (resultString.length() > 0) {
+            resultString += " ";
+        }
+        resultString += word;
+    }
+    return resultString;
+}
+
+std::vector<std::string> Mnemonic::suggest(const std::string& prefix, MnemonicLanguage language, std::size_t maxCount) {
+    std::vector<std::string> result;
+    if (prefix.size() == 0 || maxCount == 0) {
+        return result;
     }
-    assert(prefix.size() >= 1);
     // lowercase prefix
-    std::string prefixLo = prefix;
-    std::transform(prefixLo.begin(), prefixLo.end(), prefixLo.begin(),
-        [](unsigned char c){ return std::tolower(c); });
+    const auto prefixLo = toLower(prefix);
     const char* prefixLoC = prefixLo.c_str();
 
-    std::vector<std::string> result;
-    for (const char* const* word = mnemonicWordlist(); *word != nullptr; ++word) {
+    for (const char* const* word = wordlist(language); *word != nullptr; ++word) {
         // check first letter match (optimization)
         if ((*word)[0] == prefixLo[0]) {
             if (strncmp(*word, prefixLoC, prefixLo.length()) == 0) {
                 // we have a match
                 result.emplace_back(*word);
-                if (result.size() >= SuggestMaxCount) {
+                if (result.size() >= maxCount) {
                     break; // enough results
                 }
             }
         }
     }
+    return result;
+}
 
-    // convert results to one string
-    std::string resultString;
-    for (auto& word: result) {
-        if (resultString.length() > 0) {
-            resultString += " ";
+MnemonicWordMatch Mnemonic::search(const std::string& word, MnemonicLanguage language) {
+    MnemonicWordMatch match;
+    if (word.empty()) {
+        return match;
+    }
+    const auto wordLo = toLower(word);
+
+    match.candidates = suggest(wordLo, language, SuggestMaxCount);
+    if (!match.candidates.empty()) {
+        match.status = match.candidates.front() == wordLo ? MnemonicWordStatus::Valid : MnemonicWordStatus::Prefix;
+        return match;
+    }
+
+    // Not a prefix, look for similar words.
+    std::vector<std::pair<std::size_t, std::string>> similar;
+    for (const char* const* w = wordlist(language); *w != nullptr; ++w) {
+        const auto distance = editDistance(wordLo, *w);
+        if (distance <= static_cast<std::size_t>(SearchMaxDistance)) {
+            similar.emplace_back(distance, *w);
         }
-        resultString += word;
     }
-    return resultString;
+    std::sort(similar.begin(), similar.end());
+    for (const auto& [_, candidate] : similar) {
+        if (match.candidates.size() >= static_cast<std::size_t>(SuggestMaxCount)) {
+            break;
+        }
+        match.candidates.emplace_back(candidate);
+    }
+    return match;
 }
 
 } // namespace TW
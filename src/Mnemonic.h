@@ -4,10 +4,55 @@
 
 #pragma once
 
+#include "Data.h"
+
+#include <cstddef>
+#include <optional>
 #include <string>
+#include <vector>
 
 namespace TW {
 
+/// Reason of a BIP39 mnemonic phrase validation failure.
+enum class MnemonicError {
+    None = 0,
+    InvalidWordCount = 1,
+    InvalidWord = 2,
+    InvalidChecksum = 3,
+};
+
+/// Result of a BIP39 mnemonic phrase validation.
+struct MnemonicValidation {
+    MnemonicError error = MnemonicError::None;
+    /// Zero-based index of the first word that is not a BIP39 English word, or -1.
+    int wordIndex = -1;
+
+    bool isValid() const { return error == MnemonicError::None; }
+};
+
+/// Language of a BIP39 wordlist.
+enum class MnemonicLanguage {
+    English = 0,
+};
+
+/// Status of a (partially typed) mnemonic word.
+enum class MnemonicWordStatus {
+    /// Neither a word nor a prefix of a word.
+    Invalid = 0,
+    /// A prefix of one or more words, but not a word itself.
+    Prefix = 1,
+    /// A word of the wordlist, that may be a prefix of other words too.
+    Valid = 2,
+};
+
+/// Result of a mnemonic word search.
+struct MnemonicWordMatch {
+    MnemonicWordStatus status = MnemonicWordStatus::Invalid;
+    /// Words that start with the searched one if it's a prefix or a valid word,
+    /// and the most similar words if it's invalid (e.g. mistyped).
+    std::vector<std::string> candidates;
+};
+
 /// BIP39 Mnemonic recovery phrase handling.
 class Mnemonic {
 public:
@@ -20,6 +65,18 @@ public:
     // E.g. for a valid mnemonic: "credit expect life fade cover suit response wash pear what skull force"
     static bool isValid(const std::string& mnemonic);
 
+    /// Validates a BIP39 English mnemonic phrase, reporting the reason of a failure.
+    // Words are checked first, so that the index of a mistyped word is reported even if the word count is wrong.
+    // Words must be separated by single spaces, an empty word is reported as an invalid one.
+    static MnemonicValidation validate(const std::string& mnemonic);
+
+    /// Returns the entropy encoded by a valid BIP39 English mnemonic phrase (16 to 32 bytes), or nullopt if the mnemonic is invalid.
+    static std::optional<Data> toEntropy(const std::string& mnemonic);
+
+    /// Returns the BIP39 English mnemonic phrase of the given entropy.
+    /// Entropy size must be 16, 20, 24, 28 or 32 bytes, nullopt is returned otherwise.
+    static std::optional<std::string> fromEntropy(const Data& entropy);
+
     /// Determines whether word is a valid BIP39 English menemonic word.
     static bool isValidWord(const std::string& word);
 
@@ -38,7 +95,25 @@ public:
     // - 'a'-> 'abandon ability able about above absent absorb abstract absurd abuse'
     static std::string suggest(const std::string& prefix);
 
+    /// Returns up to `maxCount` words of the wordlist that start with the given prefix, see `suggest` above.
+    static std::vector<std::string> suggest(const std::string& prefix, MnemonicLanguage language, std::size_t maxCount);
+
+    /// Looks up a word as it's being typed.
+    // - The word is case insensitive
+    // - Up to `SuggestMaxCount` candidates are returned
+    // - Similar words are within `SearchMaxDistance` edits (insertion, deletion, substitution or transposition of adjacent letters),
+    //   ordered by the distance first, and alphabetically then
+    // Examples:
+    // - 'air' -> Valid, 'air airport'
+    // - 'airp' -> Prefix, 'airport'
+    // - 'recieve' -> Invalid, 'receive believe recipe'
+    static MnemonicWordMatch search(const std::string& word, MnemonicLanguage language = MnemonicLanguage::English);
+
+    /// Returns the null-terminated wordlist of the given language.
+    static const char* const* wordlist(MnemonicLanguage language);
+
     static const int SuggestMaxCount;
+    static const int SearchMaxDistance;
 };
 
 } // namespace TW
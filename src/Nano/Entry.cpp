@@ -3,50 +3,17 @@
 // Copyright © 2017 Trust Wallet.
 
 #include "Entry.h"
-#include "Address.h"
-#include "Signer.h"
-#include <proto/TransactionCompiler.pb.h>
+#include "proto/Nano.pb.h"
 
 namespace TW::Nano {
 
-// Note: avoid business logic from here, rather just call into classes like Address, Signer, etc.
-
-bool Entry::validateAddress([[maybe_unused]] TWCoinType coin, const std::string& address, [[maybe_unused]] const PrefixVariant& addressPrefix) const {
-    return Address::isValid(address);
-}
-
-std::string Entry::deriveAddress([[maybe_unused]] TWCoinType coin, const PublicKey& publicKey, [[maybe_unused]] TWDerivation derivation, [[maybe_unused]] const PrefixVariant& addressPrefix) const {
-    return Address(publicKey).string();
-}
-
-Data Entry::addressToData([[maybe_unused]] TWCoinType coin, const std::string& address) const {
-    const auto addr = Address(address);
-    return {addr.bytes.begin(), addr.bytes.end()};
-}
-
-void Entry::sign([[maybe_unused]] TWCoinType coin, const TW::Data& dataIn, TW::Data& dataOut) const {
-    signTemplate<Signer, Proto::SigningInput>(dataIn, dataOut);
-}
-
-std::string Entry::signJSON([[maybe_unused]] TWCoinType coin, const std::string& json, const Data& key) const {
-    return Signer::signJSON(json, key);
-}
-
-Data Entry::preImageHashes([[maybe_unused]] TWCoinType coin, const Data& txInputData) const {
-    return txCompilerTemplate<Proto::SigningInput, TxCompiler::Proto::PreSigningOutput>(
-        txInputData, [](const auto& input, auto& output) {
-
-            auto unsignedTxBytes = Signer::buildUnsignedTxBytes(input);
-            output.set_data(unsignedTxBytes.data(), unsignedTxBytes.size());
-            output.set_data_hash(unsignedTxBytes.data(), unsignedTxBytes.size()); });
-}
-
-void Entry::compile([[maybe_unused]] TWCoinType coin, const Data& txInputData, const std::vector<Data>& signatures,
-                    const std::vector<PublicKey>& publicKeys, Data& dataOut) const {
-    dataOut = txCompilerSingleTemplate<Proto::SigningInput, Proto::SigningOutput>(
-        txInputData, signatures, publicKeys,
-        [](const auto& input, auto& output, const auto& signature, [[maybe_unused]] const auto& publicKey) {
-            output = Signer::buildSigningOutput(input, signature); });
+std::string Entry::signJSON(TWCoinType coin, const std::string& json, const Data& key) const {
+    return signJSONHelper<Proto::SigningInput, Proto::SigningOutput>(
+        coin,
+        json,
+        key,
+        [](const Proto::SigningOutput& output) { return output.json(); }
+    );
 }
 
 } // namespace TW::Nano
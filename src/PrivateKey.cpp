@@ -48,9 +48,12 @@ Data rust_private_key_sign(const Data& key, const Data& hash, TWCurve curve) {
     if (priv == nullptr) {
         return {};
     }
-    Rust::CByteArrayWrapper res = Rust::tw_private_key_sign(priv, hash.data(), hash.size(), static_cast<uint32_t>(curve));
+    Rust::CByteArrayResultWrapper res = Rust::tw_private_key_sign_with_result(priv, hash.data(), hash.size(), static_cast<uint32_t>(curve));
     Rust::tw_private_key_delete(priv);
-    return res.data;
+    if (res.isErr()) {
+        return {};
+    }
+    return res.unwrap().data;
 }
 
 bool PrivateKey::isValid(const Data& data) {
@@ -269,7 +272,8 @@ Data PrivateKey::sign(const Data& digest, TWCurve curve) const {
             result.resize(65);
             success = ecdsa_sign_digest_checked(&nist256p1, key().data(), digest.data(), digest.size(), result.data(), result.data() + 64, nullptr) == 0;
         } break;
-    case TWCurveStarkex: {
+    case TWCurveStarkex:
+    case TWCurveSchnorr: {
         result = rust_private_key_sign(key(), digest, curve);
         success = result.size() == 64;
     } break;
@@ -364,6 +368,22 @@ Data PrivateKey::signZilliqa(const Data& message) const {
     return sig;
 }
 
+Data PrivateKey::signStarkex(const Data& digest, TWStarkExHashMode hashMode, TWStarkExNonceMode nonceMode) const {
+    if (_curve.has_value() && _curve.value() != TWCurveStarkex) {
+        throw std::invalid_argument("StarkEx signature is only supported for Starkex curve");
+    }
+    auto* priv = Rust::tw_private_key_create_with_data(key().data(), key().size());
+    if (priv == nullptr) {
+        return {};
+    }
+    Rust::CByteArrayResultWrapper res = Rust::tw_private_key_sign_starkex(priv, digest.data(), digest.size(), static_cast<uint32_t>(hashMode), static_cast<uint32_t>(nonceMode));
+    Rust::tw_private_key_delete(priv);
+    if (res.isErr()) {
+        return {};
+    }
+    return res.unwrap().data;
+}
+
 void PrivateKey::cleanup() {
     memzero(bytes.data(), bytes.size());
 }
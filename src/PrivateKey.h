@@ -9,6 +9,8 @@
 
 #include <TrustWalletCore/TWPrivateKeyType.h>
 #include <TrustWalletCore/TWCurve.h>
+#include <TrustWalletCore/TWStarkExHashMode.h>
+#include <TrustWalletCore/TWStarkExNonceMode.h>
 
 #include <optional>
 
@@ -112,6 +114,11 @@ class PrivateKey {
     /// If constructed with a curve, an exception will be thrown if the curve does not match SECP256k1.
     Data signZilliqa(const Data& message) const;
 
+    /// Signs a digest using the StarkEx curve with the given hash and nonce modes.
+    /// `sign(digest, TWCurveStarkex)` is equivalent to `TWStarkExHashModeStrict` and `TWStarkExNonceModeGrindExtraEntropy`.
+    /// If constructed with a curve, an exception will be thrown if the curve does not match Starkex.
+    Data signStarkex(const Data& digest, TWStarkExHashMode hashMode, TWStarkExNonceMode nonceMode) const;
+
     /// Cleanup contents (fill with 0s), called before destruction
     void cleanup();
 private:
@@ -123,4 +130,6 @@ private:
 /// Wrapper for C interface.
 struct TWPrivateKey {
     TW::PrivateKey impl;
+    /// Whether `TWPrivateKeyData` may return the key bytes, see `TWPrivateKeyDisableExport`.
+    bool exportable = true;
 };
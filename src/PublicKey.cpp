@@ -6,6 +6,7 @@
 #include "PrivateKey.h"
 #include "Data.h"
 #include "rust/bindgen/WalletCoreRSBindgen.h"
+#include "rust/Wrapper.h"
 
 #include <TrezorCrypto/ecdsa.h>
 #include <TrezorCrypto/ed25519-donna/ed25519-blake2b.h>
@@ -141,6 +142,36 @@ bool rust_public_key_verify(const Data& key, TWPublicKeyType type, const Data& s
     return verified;
 }
 
+std::shared_ptr<Rust::TWPublicKey> rust_public_key(const Data& key, TWPublicKeyType type) {
+    return Rust::wrapTWPublicKey(Rust::tw_public_key_create_with_data(key.data(), key.size(), static_cast<uint32_t>(type)));
+}
+
+Data PublicKey::keyIdentifier() const {
+    const auto pubkey = rust_public_key(bytes, type);
+    if (!pubkey) {
+        return {};
+    }
+    const Rust::CByteArrayWrapper res = Rust::tw_public_key_key_identifier(pubkey.get());
+    return res.data;
+}
+
+uint32_t PublicKey::fingerprint() const {
+    const auto pubkey = rust_public_key(bytes, type);
+    if (!pubkey) {
+        return 0;
+    }
+    return Rust::tw_public_key_fingerprint(pubkey.get());
+}
+
+Data PublicKey::accountIdentifier() const {
+    const auto pubkey = rust_public_key(bytes, type);
+    if (!pubkey) {
+        return {};
+    }
+    const Rust::CByteArrayWrapper res = Rust::tw_public_key_account_identifier(pubkey.get());
+    return res.data;
+}
+
 bool PublicKey::verify(const Data& signature, const Data& message) const {
     switch (type) {
     case TWPublicKeyTypeSECP256k1:
@@ -81,6 +81,17 @@ class PublicKey {
     /// bytes and then prepending the prefix.
     Data hash(const Data& prefix, Hash::Hasher hasher = Hash::HasherSha256ripemd, bool skipTypeByte = false) const;
 
+    /// Returns the key identifier: `RIPEMD160(SHA256(public key))`,
+    /// where ECDSA public keys are always hashed in the compressed form.
+    Data keyIdentifier() const;
+
+    /// Returns the BIP-32 fingerprint: the first 4 bytes of the key identifier as a big-endian integer.
+    uint32_t fingerprint() const;
+
+    /// Returns a 32-byte identifier of the account the public key belongs to.
+    /// It's stable across the compressed and uncompressed forms, and can be used as a database key.
+    Data accountIdentifier() const;
+
     /// Recover public key (SECP256k1Extended) from signature R, S, V values
     /// signatureRS: 2x32 bytes with the R and S values
     /// recId: the recovery ID, a.k.a. V value, 0 <= v < 4
@@ -16,6 +16,14 @@ public:
     /// \return hex signed message
     static std::string signMessage(const PrivateKey& privateKey, const std::string& message);;
 
+    /// Sign a message following StarkEx Curve with the given hash and nonce modes
+    /// \param privateKey the private key to sign with
+    /// \param message hex message to sign
+    /// \param hashMode how the message is converted to a field element
+    /// \param nonceMode how the signature nonce is generated
+    /// \return hex signed message, empty if the message can't be signed
+    static std::string signMessage(const PrivateKey& privateKey, const std::string& message, TWStarkExHashMode hashMode, TWStarkExNonceMode nonceMode);
+
     /// Verify a message following EIP-191
     /// \param publicKey publickey to verify the signed message
     /// \param message message to be verified as a string
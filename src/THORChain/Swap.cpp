@@ -6,6 +6,7 @@
 
 #include "Coin.h"
 #include "HexCoding.h"
+#include "rust/Wrapper.h"
 #include <TrustWalletCore/TWCoinType.h>
 #include <TrustWalletCore/TWHRP.h>
 
@@ -112,6 +113,9 @@ SwapBundled SwapBuilder::build(bool shortened) {
 
     uint256_t fromAmountNum = uint256_t(mFromAmount);
     const auto memo = this->buildMemo(shortened);
+    if (memo.empty()) {
+        return {.status_code = static_cast<SwapErrorCode>(Proto::ErrorCode::Error_general), .error = "Invalid memo"};
+    }
 
     switch (fromChain) {
     case Chain::THOR:
@@ -135,41 +139,23 @@ SwapBundled SwapBuilder::build(bool shortened) {
     }
 }
 std::string SwapBuilder::buildMemo(bool shortened) noexcept {
-    uint64_t toAmountLimitNum = std::stoull(mToAmountLimit);
-
     // Memo: 'SWAP', or shortened '='; see https://dev.thorchain.org/thorchain-dev/concepts/memos
-    std::string prefix = shortened ? "=" : "SWAP";
-    const auto& toChain = static_cast<Chain>(mToAsset.chain());
-    const auto& toTokenId = mToAsset.token_id();
-    const auto& toSymbol = mToAsset.symbol();
-    const auto toCoinToken = (!toTokenId.empty() && toTokenId != "0x0000000000000000000000000000000000000000") ? toTokenId : toSymbol;
-    std::stringstream memo;
-    memo << prefix + ":" + chainName(toChain) + "." + toCoinToken + ":" + mToAddress;
-
-    memo << ":" << std::to_string(toAmountLimitNum);
+    Proto::SwapInput input;
+    *input.mutable_to_asset() = mToAsset;
+    input.set_to_address(mToAddress);
+    input.set_to_amount_limit(mToAmountLimit);
     if (mStreamParams.has_value()) {
-        uint64_t intervalNum = std::stoull(mStreamParams->mInterval);
-        uint64_t quantityNum = std::stoull(mStreamParams->mQuantity);
-        memo << "/" << std::to_string(intervalNum) << "/" << std::to_string(quantityNum);
-    }
-
-    if (mAffFeeAddress.has_value() || mAffFeeRate.has_value() || mExtraMemo.has_value()) {
-        memo << ":";
-        if (mAffFeeAddress.has_value()) {
-            memo << mAffFeeAddress.value();
-        }
-        if (mAffFeeRate.has_value() || mExtraMemo.has_value()) {
-            memo << ":";
-            if (mAffFeeRate.has_value()) {
-                memo << mAffFeeRate.value();
-            }
-            if (mExtraMemo.has_value()) {
-                memo << ":" << mExtraMemo.value();
-            }
-        }
+        auto& streamParams = *input.mutable_stream_params();
+        streamParams.set_interval(mStreamParams->mInterval);
+        streamParams.set_quantity(mStreamParams->mQuantity);
     }
+    input.set_affiliate_fee_address(mAffFeeAddress.value_or(""));
+    input.set_affiliate_fee_rate_bp(mAffFeeRate.value_or(""));
+    input.set_extra_memo(mExtraMemo.value_or(""));
 
-    return memo.str();
+    Rust::TWDataWrapper inputData(data(input.SerializeAsString()));
+    Rust::TWStringWrapper memo = Rust::tw_thorchain_swap_build_memo(inputData.get(), shortened);
+    return memo.toStringOrDefault();
 }
 
 SwapBundled SwapBuilder::buildBitcoin(const uint256_t& amount, const std::string& memo, Chain fromChain) {
@@ -51,9 +51,19 @@ void Entry::compile([[maybe_unused]] TWCoinType coin, const Data& txInputData, c
                 return;
             }
 
+            Data signature = signatures[0];
+            if (input.delegated()) {
+                if (input.delegator_signature().size() != Signer::SignatureSize) {
+                    output.set_error(Common::Proto::Error_invalid_params);
+                    output.set_error_message("Delegated transaction requires the delegator signature");
+                    return;
+                }
+                append(signature, Data(input.delegator_signature().begin(), input.delegator_signature().end()));
+            }
+
             Data signedTx = Signer::buildSignedTx(input, signatures[0]);
             output.set_encoded(signedTx.data(), signedTx.size());
-            output.set_signature(signatures[0].data(), signatures[0].size());
+            output.set_signature(signature.data(), signature.size());
         });
 }
 
@@ -10,8 +10,9 @@ using namespace TW;
 
 namespace TW::VeChain {
 
-Proto::SigningOutput Signer::sign(const Proto::SigningInput& input) noexcept {
-    auto key = PrivateKey(Data(input.private_key().begin(), input.private_key().end()), TWCurveSECP256k1);
+namespace {
+
+Transaction buildTransaction(const Proto::SigningInput& input) noexcept {
     auto transaction = Transaction();
     transaction.chainTag = static_cast<uint8_t>(input.chain_tag());
     transaction.blockRef = input.block_ref();
@@ -23,10 +24,37 @@ Proto::SigningOutput Signer::sign(const Proto::SigningInput& input) noexcept {
     transaction.gas = input.gas();
     transaction.dependsOn = Data(input.depends_on().begin(), input.depends_on().end());
     transaction.nonce = input.nonce();
-    transaction.signature = sign(key, transaction);
+    if (input.delegated()) {
+        transaction.features |= Transaction::DelegatedFeature;
+    }
+    return transaction;
+}
+
+} // namespace
 
+Proto::SigningOutput Signer::sign(const Proto::SigningInput& input) noexcept {
     auto protoOutput = Proto::SigningOutput();
 
+    auto key = PrivateKey(Data(input.private_key().begin(), input.private_key().end()), TWCurveSECP256k1);
+    auto transaction = buildTransaction(input);
+    auto signature = sign(key, transaction);
+
+    if (transaction.isDelegated()) {
+        const auto sender = Ethereum::Address(key.getPublicKey(TWPublicKeyTypeSECP256k1Extended));
+        Data delegatorSignature(input.delegator_signature().begin(), input.delegator_signature().end());
+        if (!input.delegator_private_key().empty()) {
+            auto delegatorKey = PrivateKey(Data(input.delegator_private_key().begin(), input.delegator_private_key().end()), TWCurveSECP256k1);
+            delegatorSignature = signAsDelegator(delegatorKey, transaction, sender);
+        }
+        if (delegatorSignature.size() != SignatureSize) {
+            protoOutput.set_error(Common::Proto::Error_invalid_params);
+            protoOutput.set_error_message("Delegated transaction requires either the delegator private key or signature");
+            return protoOutput;
+        }
+        append(signature, delegatorSignature);
+    }
+    transaction.signature = signature;
+
     auto encoded = transaction.encode();
     protoOutput.set_encoded(encoded.data(), encoded.size());
     protoOutput.set_signature(transaction.signature.data(), transaction.signature.size());
@@ -35,41 +63,25 @@ Proto::SigningOutput Signer::sign(const Proto::SigningInput& input) noexcept {
 }
 
 Data Signer::sign(const PrivateKey& privateKey, Transaction& transaction) noexcept {
-    auto encoded = transaction.encode();
-    auto hash = Hash::blake2b(encoded, 32);
-    auto signature = privateKey.sign(hash);
+    auto signature = privateKey.sign(transaction.signingHash());
     return Data(signature.begin(), signature.end());
 }
 
-Data Signer::buildUnsignedTx(const Proto::SigningInput& input) noexcept {
-    auto transaction = Transaction();
-    transaction.chainTag = static_cast<uint8_t>(input.chain_tag());
-    transaction.blockRef = input.block_ref();
-    transaction.expiration = input.expiration();
-    for (auto& clause : input.clauses()) {
-        transaction.clauses.emplace_back(clause);
-    }
-    transaction.gasPriceCoef = static_cast<uint8_t>(input.gas_price_coef());
-    transaction.gas = input.gas();
-    transaction.dependsOn = Data(input.depends_on().begin(), input.depends_on().end());
-    transaction.nonce = input.nonce();
+Data Signer::signAsDelegator(const PrivateKey& privateKey, const Transaction& transaction, const Ethereum::Address& sender) noexcept {
+    auto signature = privateKey.sign(transaction.delegatorSigningHash(sender));
+    return Data(signature.begin(), signature.end());
+}
 
-    return transaction.encode();
+Data Signer::buildUnsignedTx(const Proto::SigningInput& input) noexcept {
+    return buildTransaction(input).encode();
 }
 
 Data Signer::buildSignedTx(const Proto::SigningInput& input, const Data& signature) noexcept {
-    auto transaction = Transaction();
-    transaction.chainTag = static_cast<uint8_t>(input.chain_tag());
-    transaction.blockRef = input.block_ref();
-    transaction.expiration = input.expiration();
-    for (auto& clause : input.clauses()) {
-        transaction.clauses.emplace_back(clause);
-    }
-    transaction.gasPriceCoef = static_cast<uint8_t>(input.gas_price_coef());
-    transaction.gas = input.gas();
-    transaction.dependsOn = Data(input.depends_on().begin(), input.depends_on().end());
-    transaction.nonce = input.nonce();
+    auto transaction = buildTransaction(input);
     transaction.signature = signature;
+    if (transaction.isDelegated()) {
+        append(transaction.signature, Data(input.delegator_signature().begin(), input.delegator_signature().end()));
+    }
 
     return transaction.encode();
 }
@@ -21,15 +21,24 @@ class Signer {
   public:
     Signer() = delete;
 
+    /// Size of a recoverable secp256k1 signature.
+    static constexpr std::size_t SignatureSize = 65;
+
     /// Signs a Proto::SigningInput transaction
     static Proto::SigningOutput sign(const Proto::SigningInput& input) noexcept;
 
     static Data buildUnsignedTx(const Proto::SigningInput& input) noexcept;
 
+    /// Builds a signed transaction with the sender signature.
+    /// The gas payer signature is taken from `delegator_signature` if the transaction is delegated.
     static Data buildSignedTx(const Proto::SigningInput& input, const Data& signature) noexcept;
 
     /// Signs the given transaction.
     static Data sign(const PrivateKey& privateKey, Transaction& transaction) noexcept;
+
+    /// Signs the given delegated transaction by the gas payer.
+    /// \param sender address of the transaction sender
+    static Data signAsDelegator(const PrivateKey& privateKey, const Transaction& transaction, const Ethereum::Address& sender) noexcept;
 };
 
 } // namespace TW::VeChain
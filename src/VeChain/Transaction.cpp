@@ -5,6 +5,7 @@
 #include "Transaction.h"
 
 #include "../Ethereum/RLP.h"
+#include "../Hash.h"
 
 namespace TW::VeChain {
 
@@ -46,8 +47,11 @@ Data Transaction::encode() const noexcept {
     rlpList->add_items()->set_number_u64(gas);
     rlpList->add_items()->set_data(dependsOn.data(), dependsOn.size());
     rlpList->add_items()->set_number_u64(nonce);
-    // Put an empty list - reserved field for backward compatibility.
-    rlpList->add_items()->mutable_list();
+    // Reserved field for backward compatibility, where the first item is the enabled features.
+    auto* reservedList = rlpList->add_items()->mutable_list();
+    if (features != 0) {
+        reservedList->add_items()->set_number_u64(features);
+    }
 
     if (!signature.empty()) {
         rlpList->add_items()->set_data(signature.data(), signature.size());
@@ -56,4 +60,16 @@ Data Transaction::encode() const noexcept {
     return RLP::encode(input);
 }
 
+Data Transaction::signingHash() const noexcept {
+    auto unsignedTx = *this;
+    unsignedTx.signature.clear();
+    return Hash::blake2b(unsignedTx.encode(), 32);
+}
+
+Data Transaction::delegatorSigningHash(const Ethereum::Address& sender) const noexcept {
+    auto preimage = signingHash();
+    append(preimage, Data(sender.bytes.begin(), sender.bytes.end()));
+    return Hash::blake2b(preimage, 32);
+}
+
 } // namespace TW::VeChain
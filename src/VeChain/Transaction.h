@@ -14,6 +14,9 @@ namespace TW::VeChain {
 
 class Transaction {
   public:
+    /// VIP-191 designated gas payer feature.
+    static constexpr std::uint32_t DelegatedFeature = 1;
+
     /// Last byte of the genesis block ID which is used to identify a blockchain
     /// to prevent the cross-chain replay attack.
     std::uint8_t chainTag;
@@ -47,7 +50,11 @@ class Transaction {
     /// otherwise the transaction will be considered invalid.
     std::vector<Data> reserved;
 
+    /// Enabled transaction features, encoded as the first item of the reserved field.
+    std::uint32_t features = 0;
+
     /// Transaction signature.
+    /// The sender signature followed by the gas payer signature if the transaction is delegated.
     Data signature;
 
     Transaction() = default;
@@ -55,6 +62,15 @@ class Transaction {
   public:
     /// Encodes the transaction.
     Data encode() const noexcept;
+
+    bool isDelegated() const noexcept { return (features & DelegatedFeature) != 0; }
+
+    /// Returns the hash signed by the transaction sender.
+    Data signingHash() const noexcept;
+
+    /// Returns the hash signed by the gas payer of a delegated transaction.
+    /// See https://github.com/vechain/VIPs/blob/master/vips/VIP-191.md
+    Data delegatorSigningHash(const Ethereum::Address& sender) const noexcept;
 };
 
 } // namespace TW::VeChain
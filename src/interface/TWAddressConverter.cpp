@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include <TrustWalletCore/TWAddressConverter.h>
+
+#include "Ethereum/Address.h"
+#include "Filecoin/AddressConverter.h"
+#include "Tron/Address.h"
+#include "rust/Wrapper.h"
+
+#include <optional>
+
+using namespace TW;
+
+namespace {
+
+/// Tron and Filecoin addresses are implemented in C++, so they are converted to `0x` hex before calling Rust.
+std::optional<std::string> toEvmHex(const std::string& address, TWAddressFormat format) {
+    switch (format) {
+    case TWAddressFormatTronBase58: {
+        if (!Tron::Address::isValid(address)) {
+            return std::nullopt;
+        }
+        const Tron::Address tronAddress(address);
+        return Ethereum::Address(Data(tronAddress.bytes.begin() + 1, tronAddress.bytes.end())).string();
+    }
+    case TWAddressFormatFilecoinDelegated: {
+        if (!Filecoin::Address::isValid(address)) {
+            return std::nullopt;
+        }
+        const Filecoin::Address filecoinAddress(address);
+        if (filecoinAddress.type != Filecoin::Address::Type::DELEGATED) {
+            return std::nullopt;
+        }
+        const auto ethAddress = Filecoin::AddressConverter::convertToEthereum(filecoinAddress);
+        if (!ethAddress.has_value()) {
+            return std::nullopt;
+        }
+        return ethAddress->string();
+    }
+    default:
+        return address;
+    }
+}
+
+std::string fromEvmHex(const std::string& evmHex, TWAddressFormat format) {
+    switch (format) {
+    case TWAddressFormatTronBase58: {
+        const Ethereum::Address ethAddress(evmHex);
+        Data bytes = {Tron::Address::prefix};
+        append(bytes, Data(ethAddress.bytes.begin(), ethAddress.bytes.end()));
+        return Tron::Address(bytes).string();
+    }
+    case TWAddressFormatFilecoinDelegated:
+        return Filecoin::AddressConverter::convertFromEthereumString(evmHex);
+    default:
+        return evmHex;
+    }
+}
+
+bool isCppFormat(TWAddressFormat format) {
+    return format == TWAddressFormatTronBase58 || format == TWAddressFormatFilecoinDelegated;
+}
+
+} // namespace
+
+TWString* _Nullable TWAddressConverterConvert(TWString* _Nonnull address, enum TWAddressFormat from, enum TWAddressFormat to, TWString* _Nullable hrp) {
+    try {
+        const auto evmAddress = toEvmHex(TWStringUTF8Bytes(address), from);
+        if (!evmAddress.has_value()) {
+            return nullptr;
+        }
+        const auto rustFrom = isCppFormat(from) ? TWAddressFormatEvmHex : from;
+        const auto rustTo = isCppFormat(to) ? TWAddressFormatEvmHex : to;
+
+        const Rust::TWStringWrapper addressStr = *evmAddress;
+        const auto hrpStr = hrp != nullptr ? Rust::TWStringWrapper(TWStringUTF8Bytes(hrp)) : Rust::TWStringWrapper();
+        const Rust::TWStringWrapper converted = Rust::tw_address_converter_convert(addressStr.get(), static_cast<uint32_t>(rustFrom), static_cast<uint32_t>(rustTo), hrpStr.get());
+        if (!converted) {
+            return nullptr;
+        }
+        const auto result = fromEvmHex(converted.toStringOrDefault(), to);
+        return TWStringCreateWithUTF8Bytes(result.c_str());
+    } catch (...) {
+        return nullptr;
+    }
+}
@@ -10,6 +10,8 @@
 #include "Coin.h"
 #include "CoinEntry.h"
 #include "AnyAddress.h"
+#include "proto/AddressValidation.pb.h"
+#include "rust/Wrapper.h"
 
 bool TWAnyAddressEqual(struct TWAnyAddress* _Nonnull lhs, struct TWAnyAddress* _Nonnull rhs) {
     return *lhs->impl == *rhs->impl;
@@ -20,6 +22,38 @@ bool TWAnyAddressIsValid(TWString* _Nonnull string, enum TWCoinType coin) {
     return TW::validateAddress(coin, address);
 }
 
+TWData* _Nonnull TWAnyAddressValidate(TWString* _Nonnull string, enum TWCoinType coin) {
+    const auto& address = *reinterpret_cast<const std::string*>(string);
+    TW::AddressValidation::Proto::ValidationResult result;
+
+    if (TW::validateAddress(coin, address)) {
+        result.set_valid(true);
+        result.set_normalized(TW::normalizeAddress(coin, address));
+        result.set_network(TW::AddressValidation::Proto::Mainnet);
+    } else {
+        const TW::Rust::TWStringWrapper addressStr = address;
+        const TW::Rust::TWDataWrapper analysis = TW::Rust::tw_any_address_validate(addressStr.get(), static_cast<uint32_t>(coin));
+        const auto analysisData = analysis.toDataOrDefault();
+        TW::AddressValidation::Proto::ValidationResult rustResult;
+        rustResult.ParseFromArray(analysisData.data(), static_cast<int>(analysisData.size()));
+
+        result.set_network(rustResult.network());
+        result.set_error(rustResult.error());
+        // Coins that are not implemented in Rust are detected by their address format only.
+        for (const auto detected : rustResult.detected_coins()) {
+            if (TW::validateAddress(static_cast<TWCoinType>(detected), address)) {
+                result.add_detected_coins(detected);
+            }
+        }
+        if (result.network() == TW::AddressValidation::Proto::Mainnet && result.detected_coins_size() == 0) {
+            result.set_network(TW::AddressValidation::Proto::Unknown);
+        }
+    }
+
+    const auto resultData = TW::data(result.SerializeAsString());
+    return TWDataCreateWithBytes(resultData.data(), resultData.size());
+}
+
 bool TWAnyAddressIsValidSS58([[maybe_unused]] TWString* string, [[maybe_unused]] enum TWCoinType coin, [[maybe_unused]] uint32_t ss58Prefix) {
     const auto& address = *reinterpret_cast<const std::string*>(string);
     return TW::validateAddress(coin, address, ss58Prefix);
@@ -5,6 +5,7 @@
 #include <TrustWalletCore/TWAnySigner.h>
 
 #include "Coin.h"
+#include "rust/Wrapper.h"
 
 using namespace TW;
 
@@ -31,3 +32,11 @@ TWData* _Nonnull TWAnySignerPlan(TWData* _Nonnull data, enum TWCoinType coin) {
     TW::anyCoinPlan(coin, dataIn, dataOut);
     return TWDataCreateWithBytes(dataOut.data(), dataOut.size());
 }
+
+TWData* _Nonnull TWAnySignerSignEvm(TWData* _Nonnull data) {
+    const Data& dataIn = *(reinterpret_cast<const Data*>(data));
+    Rust::TWDataWrapper input = dataIn;
+    Rust::TWDataWrapper output = Rust::tw_any_signer_sign_evm(input.get());
+    const auto dataOut = output.toDataOrDefault();
+    return TWDataCreateWithBytes(dataOut.data(), dataOut.size());
+}
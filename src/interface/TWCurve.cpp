@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include <TrustWalletCore/TWCurve.h>
+
+#include "rust/Wrapper.h"
+
+using namespace TW;
+
+TWString* _Nullable TWCurveName(enum TWCurve curve) {
+    const Rust::CStringWrapper name = Rust::tw_curve_name(static_cast<uint32_t>(curve));
+    if (name.str.empty()) {
+        return nullptr;
+    }
+    return TWStringCreateWithUTF8Bytes(name.str.c_str());
+}
+
+uint32_t TWCurveSigningHashMinLength(enum TWCurve curve) {
+    Rust::CUInt32ResultWrapper res = Rust::tw_curve_signing_hash_min_len(static_cast<uint32_t>(curve));
+    if (res.isErr()) {
+        return 0;
+    }
+    return res.unwrap().value;
+}
+
+uint32_t TWCurveSigningHashMaxLength(enum TWCurve curve) {
+    Rust::CUInt32ResultWrapper res = Rust::tw_curve_signing_hash_max_len(static_cast<uint32_t>(curve));
+    if (res.isErr()) {
+        return 0;
+    }
+    return res.unwrap().value;
+}
+
+bool TWCurveSupportsSigningHash(enum TWCurve curve, enum TWSigningHash hash) {
+    const auto count = Rust::tw_curve_signing_hash_count(static_cast<uint32_t>(curve));
+    for (size_t i = 0; i < count; ++i) {
+        Rust::CUInt32ResultWrapper res = Rust::tw_curve_signing_hash_at(static_cast<uint32_t>(curve), i);
+        if (res.isOk() && res.unwrap().value == static_cast<uint32_t>(hash)) {
+            return true;
+        }
+    }
+    return false;
+}
+
+bool TWCurveValidateSigningMessage(enum TWCurve curve, TWData* _Nonnull message) {
+    const auto& msg = *reinterpret_cast<const Data*>(message);
+    return Rust::tw_curve_validate_signing_message(static_cast<uint32_t>(curve), msg.data(), msg.size()) == Rust::OK_CODE;
+}
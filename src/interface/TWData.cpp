@@ -6,6 +6,9 @@
 #include <TrustWalletCore/TWString.h>
 #include "Data.h"
 #include "HexCoding.h"
+
+#include <TrezorCrypto/memzero.h>
+
 #include <algorithm>
 #include <vector>
 
@@ -95,7 +98,13 @@ void TWDataReset(TWData *_Nonnull data) {
 }
 
 void TWDataDelete(TWData *_Nonnull data) {
-    auto* v = reinterpret_cast<const Data*>(data);
+    auto* v = const_cast<Data*>(reinterpret_cast<const Data*>(data));
+    // `std::shared_ptr` calls the deleter of a null pointer too, e.g. `WRAPD` of a failed call.
+    if (v == nullptr) {
+        return;
+    }
+    // The data may hold key material, e.g. returned by `TWPrivateKeyData`.
+    memzero(v->data(), v->size());
     delete v;
 }
 
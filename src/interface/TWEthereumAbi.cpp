@@ -32,6 +32,10 @@ TWData* _Nonnull TWEthereumAbiDecodeContractCall(enum TWCoinType coin, TWData* _
     return ethereumAbiForwardToRust(Rust::tw_ethereum_abi_decode_contract_call, coin, input);
 }
 
+TWData* _Nonnull TWEthereumAbiDecodeCalldata(enum TWCoinType coin, TWData* _Nonnull input) {
+    return ethereumAbiForwardToRust(Rust::tw_ethereum_abi_decode_calldata, coin, input);
+}
+
 TWData* _Nonnull TWEthereumAbiDecodeParams(enum TWCoinType coin, TWData* _Nonnull input) {
     return ethereumAbiForwardToRust(Rust::tw_ethereum_abi_decode_params, coin, input);
 }
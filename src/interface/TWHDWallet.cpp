@@ -6,6 +6,7 @@
 
 #include "../Coin.h"
 #include "../HDWallet.h"
+#include "../HexCoding.h"
 #include "../Mnemonic.h"
 
 using namespace TW;
@@ -64,6 +65,15 @@ struct TWPrivateKey *_Nonnull TWHDWalletGetMasterKey(struct TWHDWallet *_Nonnull
     return new TWPrivateKey{ wallet->impl.getMasterKey(curve) };
 }
 
+uint32_t TWHDWalletMasterFingerprint(struct TWHDWallet *_Nonnull wallet) {
+    return wallet->impl.getMasterFingerprint();
+}
+
+TWString *_Nonnull TWHDWalletGetAccountIdentifier(struct TWHDWallet *_Nonnull wallet, enum TWCoinType coin, enum TWDerivation derivation, uint32_t account) {
+    const auto accountIdentifier = hex(wallet->impl.getAccountIdentifier(coin, derivation, account));
+    return TWStringCreateWithUTF8Bytes(accountIdentifier.c_str());
+}
+
 struct TWPrivateKey *_Nonnull TWHDWalletGetKeyForCoin(struct TWHDWallet *wallet, TWCoinType coin) {
     return TWHDWalletGetKeyDerivation(wallet, coin, TWDerivationDefault);
 }
@@ -5,6 +5,7 @@
 #include <TrustWalletCore/TWHash.h>
 #include "../Hash.h"
 #include "Data.h"
+#include "rust/Wrapper.h"
 
 #include "BinaryCoding.h"
 #include <TrezorCrypto/blake2b.h>
@@ -114,4 +115,13 @@ TWData* _Nonnull TWHashBlake256RIPEMD(TWData* _Nonnull data) {
 TWData* _Nonnull TWHashGroestl512Groestl512(TWData* _Nonnull data) {
     const auto result = Hash::groestl512d(reinterpret_cast<const byte*>(TWDataBytes(data)), TWDataSize(data));
     return TWDataCreateWithBytes(result.data(), result.size());
-}
\ No newline at end of file
+}
+
+TWData* _Nullable TWHashPoseidon(TWData* _Nonnull data) {
+    Rust::CByteArrayResultWrapper res = Rust::tw_hash_poseidon(TWDataBytes(data), TWDataSize(data));
+    if (res.isErr()) {
+        return nullptr;
+    }
+    const auto result = res.unwrap().data;
+    return TWDataCreateWithBytes(result.data(), result.size());
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include <TrustWalletCore/TWKeyVault.h>
+
+#include "../DerivationPath.h"
+#include "../PublicKey.h"
+#include "rust/Wrapper.h"
+
+using namespace TW;
+
+struct TWKeyVault {
+    std::shared_ptr<Rust::TWKeyVault> impl;
+};
+
+struct TWKeyVault* _Nonnull TWKeyVaultCreate() {
+    return new TWKeyVault{std::shared_ptr<Rust::TWKeyVault>(Rust::tw_key_vault_create(), Rust::tw_key_vault_delete)};
+}
+
+void TWKeyVaultDelete(struct TWKeyVault* _Nonnull vault) {
+    delete vault;
+}
+
+uint64_t TWKeyVaultImportPrivateKey(struct TWKeyVault* _Nonnull vault, TWData* _Nonnull key) {
+    const auto& keyData = *reinterpret_cast<const Data*>(key);
+    Rust::CUInt64ResultWrapper res = Rust::tw_key_vault_import_private_key(vault->impl.get(), keyData.data(), keyData.size());
+    return res.unwrap_or_default().value;
+}
+
+uint64_t TWKeyVaultImportSeed(struct TWKeyVault* _Nonnull vault, TWData* _Nonnull seed) {
+    const auto& seedData = *reinterpret_cast<const Data*>(seed);
+    Rust::CUInt64ResultWrapper res = Rust::tw_key_vault_import_seed(vault->impl.get(), seedData.data(), seedData.size());
+    return res.unwrap_or_default().value;
+}
+
+uint64_t TWKeyVaultDeriveSecp256k1(struct TWKeyVault* _Nonnull vault, uint64_t seed, TWString* _Nonnull derivationPath) {
+    const auto& pathStr = *reinterpret_cast<const std::string*>(derivationPath);
+    std::vector<uint32_t> indices;
+    try {
+        for (const auto& index : DerivationPath(pathStr).indices) {
+            indices.push_back(index.derivationIndex());
+        }
+    } catch (...) {
+        return 0;
+    }
+
+    Rust::CUInt64ResultWrapper res = Rust::tw_key_vault_derive_secp256k1(vault->impl.get(), seed, indices.data(), indices.size());
+    return res.unwrap_or_default().value;
+}
+
+TWData* _Nullable TWKeyVaultSign(struct TWKeyVault* _Nonnull vault, uint64_t handle, TWData* _Nonnull digest, enum TWCurve curve) {
+    const auto& digestData = *reinterpret_cast<const Data*>(digest);
+    Rust::CByteArrayResultWrapper res = Rust::tw_key_vault_sign(vault->impl.get(), handle, digestData.data(), digestData.size(), static_cast<uint32_t>(curve));
+    if (res.isErr()) {
+        return nullptr;
+    }
+    const auto signature = res.unwrap().data;
+    return TWDataCreateWithBytes(signature.data(), signature.size());
+}
+
+struct TWPublicKey* _Nullable TWKeyVaultGetPublicKey(struct TWKeyVault* _Nonnull vault, uint64_t handle, enum TWPublicKeyType type) {
+    const auto publicKey = Rust::wrapTWPublicKey(Rust::tw_key_vault_get_public_key_by_type(vault->impl.get(), handle, static_cast<uint32_t>(type)));
+    if (!publicKey) {
+        return nullptr;
+    }
+    const Rust::CByteArrayWrapper bytes = Rust::tw_public_key_data(publicKey.get());
+    return new TWPublicKey{PublicKey(bytes.data, type)};
+}
+
+bool TWKeyVaultRemove(struct TWKeyVault* _Nonnull vault, uint64_t handle) {
+    return Rust::tw_key_vault_remove(vault->impl.get(), handle);
+}
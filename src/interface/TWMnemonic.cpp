@@ -8,6 +8,21 @@
 
 using namespace TW;
 
+namespace {
+
+TWString* _Nonnull joinWords(const std::vector<std::string>& words) {
+    std::string result;
+    for (const auto& word : words) {
+        if (!result.empty()) {
+            result += " ";
+        }
+        result += word;
+    }
+    return TWStringCreateWithUTF8Bytes(result.c_str());
+}
+
+} // namespace
+
 bool TWMnemonicIsValid(TWString *_Nonnull mnemonic) {
     return Mnemonic::isValid(TWStringUTF8Bytes(mnemonic));
 }
@@ -20,3 +35,43 @@ TWString* _Nonnull TWMnemonicSuggest(TWString *_Nonnull prefix) {
     auto result = Mnemonic::suggest(std::string(TWStringUTF8Bytes(prefix)));
     return TWStringCreateWithUTF8Bytes(result.c_str());
 }
+
+TWString* _Nonnull TWMnemonicSuggestWords(TWString *_Nonnull prefix, enum TWMnemonicLanguage language, uint32_t maxCount) {
+    const auto words = Mnemonic::suggest(TWStringUTF8Bytes(prefix), static_cast<MnemonicLanguage>(language), maxCount);
+    return joinWords(words);
+}
+
+enum TWMnemonicWordStatus TWMnemonicSearch(TWString *_Nonnull word, enum TWMnemonicLanguage language) {
+    const auto match = Mnemonic::search(TWStringUTF8Bytes(word), static_cast<MnemonicLanguage>(language));
+    return static_cast<TWMnemonicWordStatus>(match.status);
+}
+
+TWString* _Nonnull TWMnemonicSearchCandidates(TWString *_Nonnull word, enum TWMnemonicLanguage language) {
+    const auto match = Mnemonic::search(TWStringUTF8Bytes(word), static_cast<MnemonicLanguage>(language));
+    return joinWords(match.candidates);
+}
+
+enum TWMnemonicError TWMnemonicValidate(TWString *_Nonnull mnemonic) {
+    const auto result = Mnemonic::validate(TWStringUTF8Bytes(mnemonic));
+    return static_cast<TWMnemonicError>(result.error);
+}
+
+int32_t TWMnemonicInvalidWordIndex(TWString *_Nonnull mnemonic) {
+    return Mnemonic::validate(TWStringUTF8Bytes(mnemonic)).wordIndex;
+}
+
+TWData* _Nullable TWMnemonicToEntropy(TWString *_Nonnull mnemonic) {
+    const auto entropy = Mnemonic::toEntropy(TWStringUTF8Bytes(mnemonic));
+    if (!entropy.has_value()) {
+        return nullptr;
+    }
+    return TWDataCreateWithBytes(entropy->data(), entropy->size());
+}
+
+TWString* _Nullable TWMnemonicFromEntropy(TWData *_Nonnull entropy) {
+    const auto mnemonic = Mnemonic::fromEntropy(*reinterpret_cast<const Data*>(entropy));
+    if (!mnemonic.has_value()) {
+        return nullptr;
+    }
+    return TWStringCreateWithUTF8Bytes(mnemonic->c_str());
+}
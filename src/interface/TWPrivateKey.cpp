@@ -40,7 +40,7 @@ struct TWPrivateKey *_Nullable TWPrivateKeyCreateWithData(TWData *_Nonnull data)
 }
 
 struct TWPrivateKey *_Nullable TWPrivateKeyCreateCopy(struct TWPrivateKey *_Nonnull key) {
-   return new TWPrivateKey{ PrivateKey(key->impl.bytes) };
+   return new TWPrivateKey{ PrivateKey(key->impl.bytes), key->exportable };
 }
 
 void TWPrivateKeyDelete(struct TWPrivateKey *_Nonnull pk) {
@@ -57,9 +57,20 @@ bool TWPrivateKeyIsValid(TWData *_Nonnull data, enum TWCurve curve) {
 }
 
 TWData *TWPrivateKeyData(struct TWPrivateKey *_Nonnull pk) {
+    if (!pk->exportable) {
+        return TWDataCreateWithSize(0);
+    }
     return TWDataCreateWithBytes(pk->impl.bytes.data(), pk->impl.bytes.size());
 }
 
+void TWPrivateKeyDisableExport(struct TWPrivateKey *_Nonnull pk) {
+    pk->exportable = false;
+}
+
+bool TWPrivateKeyIsExportable(struct TWPrivateKey *_Nonnull pk) {
+    return pk->exportable;
+}
+
 struct TWPublicKey *_Nonnull TWPrivateKeyGetPublicKeyNist256p1(struct TWPrivateKey *_Nonnull pk) {
     return TWPrivateKeyGetPublicKeyByType(pk, TWPublicKeyTypeNIST256p1);
 }
@@ -119,6 +130,16 @@ TWData *TWPrivateKeySignZilliqaSchnorr(struct TWPrivateKey *_Nonnull pk, TWData
     }
 }
 
+TWData* TWPrivateKeySignStarkex(struct TWPrivateKey* pk, TWData* digest, enum TWStarkExHashMode hashMode, enum TWStarkExNonceMode nonceMode) {
+    const auto& d = *reinterpret_cast<const Data*>(digest);
+    auto result = pk->impl.signStarkex(d, hashMode, nonceMode);
+    if (result.empty()) {
+        return nullptr;
+    } else {
+        return TWDataCreateWithBytes(result.data(), result.size());
+    }
+}
+
 struct TWPublicKey* TWPrivateKeyGetPublicKey(struct TWPrivateKey* pk, enum TWCoinType coinType) {
     return TWPrivateKeyGetPublicKeyByType(pk, TWCoinTypePublicKeyType(coinType));
 }
@@ -73,6 +73,20 @@ TWString *_Nonnull TWPublicKeyDescription(struct TWPublicKey *_Nonnull publicKey
     return TWStringCreateWithUTF8Bytes(string.c_str());
 }
 
+uint32_t TWPublicKeyFingerprint(struct TWPublicKey *_Nonnull publicKey) {
+    return publicKey->impl.fingerprint();
+}
+
+TWData *_Nonnull TWPublicKeyKeyIdentifier(struct TWPublicKey *_Nonnull publicKey) {
+    const auto keyIdentifier = publicKey->impl.keyIdentifier();
+    return TWDataCreateWithBytes(keyIdentifier.data(), keyIdentifier.size());
+}
+
+TWString *_Nonnull TWPublicKeyAccountIdentifier(struct TWPublicKey *_Nonnull publicKey) {
+    const auto string = TW::hex(publicKey->impl.accountIdentifier());
+    return TWStringCreateWithUTF8Bytes(string.c_str());
+}
+
 struct TWPublicKey *_Nullable TWPublicKeyRecover(TWData *_Nonnull signature, TWData *_Nonnull message) {
     try {
         const PublicKey publicKey = PublicKey::recover(*((TW::Data*)signature), *((TW::Data*)message));
@@ -15,6 +15,15 @@ TWString* _Nonnull TWStarkExMessageSignerSignMessage(const struct TWPrivateKey*
     }
 }
 
+TWString* _Nonnull TWStarkExMessageSignerSignMessageWithModes(const struct TWPrivateKey* _Nonnull privateKey, TWString* _Nonnull message, enum TWStarkExHashMode hashMode, enum TWStarkExNonceMode nonceMode) {
+    try {
+        const auto signature = TW::StarkEx::MessageSigner::signMessage(privateKey->impl, TWStringUTF8Bytes(message), hashMode, nonceMode);
+        return TWStringCreateWithUTF8Bytes(signature.c_str());
+    } catch (...) {
+        return TWStringCreateWithUTF8Bytes("");
+    }
+}
+
 bool TWStarkExMessageSignerVerifyMessage(const struct TWPublicKey* _Nonnull publicKey, TWString* _Nonnull message, TWString* _Nonnull signature) {
     return TW::StarkEx::MessageSigner::verifyMessage(publicKey->impl, TWStringUTF8Bytes(message), TWStringUTF8Bytes(signature));
 }
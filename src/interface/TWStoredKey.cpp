@@ -9,6 +9,7 @@
 #include "../HDWallet.h"
 #include "../Keystore/StoredKey.h"
 #include "../HexCoding.h"
+#include "../rust/Wrapper.h"
 #include <stdexcept>
 #include <cassert>
 
@@ -279,6 +280,37 @@ TWData* _Nullable TWStoredKeyExportJSON(struct TWStoredKey* _Nonnull key) {
     return TWDataCreateWithBytes(reinterpret_cast<const uint8_t*>(json.data()), json.size());
 }
 
+TWData* _Nullable TWStoredKeyExportBackup(struct TWStoredKey* _Nonnull key, TWData* _Nonnull password, TWData* _Nonnull metadata) {
+    try {
+        const auto passwordData = TW::data(TWDataBytes(password), TWDataSize(password));
+        const auto metadataData = TW::data(TWDataBytes(metadata), TWDataSize(metadata));
+        const auto backup = key->impl.exportBackup(passwordData, metadataData);
+        return TWDataCreateWithBytes(backup.data(), backup.size());
+    } catch (...) {
+        return nullptr;
+    }
+}
+
+struct TWStoredKey* _Nullable TWStoredKeyImportBackup(TWData* _Nonnull backup, TWData* _Nonnull password) {
+    try {
+        const auto backupData = TW::data(TWDataBytes(backup), TWDataSize(backup));
+        const auto passwordData = TW::data(TWDataBytes(password), TWDataSize(password));
+        return new TWStoredKey{ KeyStore::StoredKey::importBackup(backupData, passwordData) };
+    } catch (...) {
+        return nullptr;
+    }
+}
+
+TWData* _Nullable TWStoredKeyBackupMetadata(TWData* _Nonnull backup) {
+    const auto& backupData = *reinterpret_cast<const TW::Data*>(backup);
+    TW::Rust::CByteArrayResultWrapper res = TW::Rust::tw_encrypted_backup_metadata(backupData.data(), backupData.size());
+    if (!res.isOk()) {
+        return nullptr;
+    }
+    const auto metadata = res.unwrap().data;
+    return TWDataCreateWithBytes(metadata.data(), metadata.size());
+}
+
 bool TWStoredKeyFixAddresses(struct TWStoredKey* _Nonnull key, TWData* _Nonnull password) {
     try {
         const auto passwordData = TW::data(TWDataBytes(password), TWDataSize(password));
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#pragma once
+
+#include "Data.h"
+#include "rust/bindgen/WalletCoreRSBindgen.h"
+
+namespace TW {
+
+/// Compares two byte arrays in constant time.
+/// Must be used instead of `==` to compare secret data, such as MACs and shared secrets.
+static inline bool constantTimeEqual(const Data& lhs, const Data& rhs) noexcept {
+    return Rust::constant_time_eq(lhs.data(), lhs.size(), rhs.data(), rhs.size());
+}
+
+} // namespace TW
@@ -185,6 +185,20 @@ struct CUInt8Wrapper {
     uint8_t value;
 };
 
+struct CUInt32Wrapper {
+    /// Implicit move constructor.
+    CUInt32Wrapper(uint32_t c_u32) {
+        *this = c_u32;
+    }
+
+    CUInt32Wrapper& operator=(uint32_t c_u32) {
+        value = c_u32;
+        return *this;
+    }
+
+    uint32_t value;
+};
+
 struct CUInt64Wrapper {
     /// Implicit move constructor.
     CUInt64Wrapper(uint64_t c_u64) {
@@ -247,6 +261,7 @@ private:
 
 using CByteArrayResultWrapper = CResult<CByteArrayWrapper>;
 using CUInt8ResultWrapper = CResult<CUInt8Wrapper>;
+using CUInt32ResultWrapper = CResult<CUInt32Wrapper>;
 using CUInt64ResultWrapper = CResult<CUInt64Wrapper>;
 
 } // namespace TW::Rust
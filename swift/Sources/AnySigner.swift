@@ -91,4 +91,32 @@ public final class AnySigner {
         }
         return TWDataNSData(TWAnySignerPlan(inputData, TWCoinType(rawValue: coin.rawValue)))
     }
+
+    /// Signs an EVM transaction for the network specified by the chain ID of the SigningInput,
+    /// including the networks unknown to the library.
+    ///
+    /// - Parameters:
+    /// - input: The Ethereum SigningInput SwiftProtobuf message
+    /// - Returns: The Ethereum SigningOutput SwiftProtobuf message
+    public static func signEvm<SigningOutput: Message>(input: SigningInput) -> SigningOutput {
+        do {
+            let outputData = nativeSignEvm(data: try input.serializedData())
+            return try SigningOutput(serializedBytes: outputData)
+        } catch let error {
+            fatalError(error.localizedDescription)
+        }
+    }
+
+    /// Signs an EVM transaction by serialized data of an Ethereum SigningInput
+    ///
+    /// - Parameters:
+    /// - data: The serialized data of an Ethereum SigningInput
+    /// - Returns: The serialized data of an Ethereum SigningOutput
+    public static func nativeSignEvm(data: Data) -> Data {
+        let inputData = TWDataCreateWithNSData(data)
+        defer {
+            TWDataDelete(inputData)
+        }
+        return TWDataNSData(TWAnySignerSignEvm(inputData))
+    }
 }
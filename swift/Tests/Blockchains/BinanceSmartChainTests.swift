@@ -15,4 +15,51 @@ class BinanceSmartChainTests: XCTestCase {
 
         XCTAssertEqual(address.description, expected.description)
     }
+
+    func testSignEvmNetworkDefault() {
+        let input = EthereumSigningInput.with {
+            $0.chainID = Data(hexString: "38")! // 56
+            $0.nonce = Data(hexString: "09")!
+            $0.txMode = .networkDefault
+            $0.gasPrice = Data(hexString: "04a817c800")!
+            $0.gasLimit = Data(hexString: "5208")!
+            $0.toAddress = "0x3535353535353535353535353535353535353535"
+            $0.privateKey = Data(hexString: "0x4646464646464646464646464646464646464646464646464646464646464646")!
+            $0.transaction = EthereumTransaction.with {
+                $0.transfer = EthereumTransaction.Transfer.with {
+                    $0.amount = Data(hexString: "0de0b6b3a7640000")!
+                }
+            }
+        }
+
+        // Smart Chain doesn't support EIP-1559, so a Legacy transaction is signed.
+        let output: EthereumSigningOutput = AnySigner.signEvm(input: input)
+
+        XCTAssertEqual(output.error, .ok)
+        XCTAssertEqual(output.encoded.hexString, "f86d098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000808194a007764d8e7d4ce45afb36cb2f5bec741f96eee3b8f23304e4d35b0f389912e7f7a047c14849f32c33c269dec152d168ddeca152fec8ceeb7369177ff3a06dc72c48")
+    }
+
+    func testSignEvmUserAddedChainId() {
+        let input = EthereumSigningInput.with {
+            $0.chainID = Data(hexString: "075bcd15")! // 123456789
+            $0.nonce = Data(hexString: "09")!
+            $0.txMode = .networkDefault
+            $0.gasLimit = Data(hexString: "5208")!
+            $0.maxInclusionFeePerGas = Data(hexString: "77359400")!
+            $0.maxFeePerGas = Data(hexString: "04a817c800")!
+            $0.toAddress = "0x3535353535353535353535353535353535353535"
+            $0.privateKey = Data(hexString: "0x4646464646464646464646464646464646464646464646464646464646464646")!
+            $0.transaction = EthereumTransaction.with {
+                $0.transfer = EthereumTransaction.Transfer.with {
+                    $0.amount = Data(hexString: "0de0b6b3a7640000")!
+                }
+            }
+        }
+
+        // Networks unknown to the library are signed as EIP-1559 transactions.
+        let output: EthereumSigningOutput = AnySigner.signEvm(input: input)
+
+        XCTAssertEqual(output.error, .ok)
+        XCTAssertEqual(output.encoded.hexString, "02f87784075bcd150984773594008504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080c001a06bd34f982436d714110467b33799f34bc1292032e1b1f6f728a9fdb53319e890a07963f6fea7f4ce08608fe9f908cd8990fc161ad45933b654d06fabce8e88f324")
+    }
 }
@@ -407,6 +407,15 @@ class CoinAddressDerivationTests: XCTestCase {
                 case .pactus:
                     let expectedResult = "pc1r7ys2g5a4xc2qtm0t4q987m4mvs57w5g0v4pvzg"
                     assertCoinDerivation(coin, expectedResult, derivedAddress, address)
+                case .liquid:
+                    let expectedResult = "ex1qzn3tl7cvw7e9u03snfjnyp5vh5ge8vphmr88w6"
+                    assertCoinDerivation(coin, expectedResult, derivedAddress, address)
+                case .avalancheXChain:
+                    let expectedResult = "X-avax1x8nslqfqha3aywycds3jfnykp3khffrk2scya2"
+                    assertCoinDerivation(coin, expectedResult, derivedAddress, address)
+                case .avalanchePChain:
+                    let expectedResult = "P-avax1x8nslqfqha3aywycds3jfnykp3khffrk2scya2"
+                    assertCoinDerivation(coin, expectedResult, derivedAddress, address)
                 @unknown default:
                     fatalError()
                 }
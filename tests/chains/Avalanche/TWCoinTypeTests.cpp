@@ -30,3 +30,43 @@ TEST(TWAvalancheCoinType, TWCoinTypeCChain) {
     assertStringsEqual(id, "avalanchec");
     assertStringsEqual(name, "Avalanche C-Chain");
 }
+
+TEST(TWAvalancheCoinType, TWCoinTypeXChain) {
+    auto symbol = WRAPS(TWCoinTypeConfigurationGetSymbol(TWCoinTypeAvalancheXChain));
+    auto txId = WRAPS(TWStringCreateWithUTF8Bytes("2X5PpYu4NkUuub2vnRiMstzNZuTWe2V7Ct7uxAV6NjSJbZHjgf"));
+    auto txUrl = WRAPS(TWCoinTypeConfigurationGetTransactionURL(TWCoinTypeAvalancheXChain, txId.get()));
+    auto accId = WRAPS(TWStringCreateWithUTF8Bytes("X-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5"));
+    auto accUrl = WRAPS(TWCoinTypeConfigurationGetAccountURL(TWCoinTypeAvalancheXChain, accId.get()));
+    auto id = WRAPS(TWCoinTypeConfigurationGetID(TWCoinTypeAvalancheXChain));
+    auto name = WRAPS(TWCoinTypeConfigurationGetName(TWCoinTypeAvalancheXChain));
+
+    ASSERT_EQ(TWCoinTypeConfigurationGetDecimals(TWCoinTypeAvalancheXChain), 9);
+    ASSERT_EQ(TWBlockchainAvalancheX, TWCoinTypeBlockchain(TWCoinTypeAvalancheXChain));
+    ASSERT_EQ(0x0, TWCoinTypeP2shPrefix(TWCoinTypeAvalancheXChain));
+    ASSERT_EQ(0x0, TWCoinTypeStaticPrefix(TWCoinTypeAvalancheXChain));
+    assertStringsEqual(symbol, "AVAX");
+    assertStringsEqual(txUrl, "https://subnets.avax.network/x-chain/tx/2X5PpYu4NkUuub2vnRiMstzNZuTWe2V7Ct7uxAV6NjSJbZHjgf");
+    assertStringsEqual(accUrl, "https://subnets.avax.network/x-chain/address/X-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5");
+    assertStringsEqual(id, "avalanchex");
+    assertStringsEqual(name, "Avalanche X-Chain");
+}
+
+TEST(TWAvalancheCoinType, TWCoinTypePChain) {
+    auto symbol = WRAPS(TWCoinTypeConfigurationGetSymbol(TWCoinTypeAvalanchePChain));
+    auto txId = WRAPS(TWStringCreateWithUTF8Bytes("2jPRWQ2U6pwmvgA9S4y6gFjn6ef9MYRJ1yewaLgZ54xkXPk1St"));
+    auto txUrl = WRAPS(TWCoinTypeConfigurationGetTransactionURL(TWCoinTypeAvalanchePChain, txId.get()));
+    auto accId = WRAPS(TWStringCreateWithUTF8Bytes("P-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5"));
+    auto accUrl = WRAPS(TWCoinTypeConfigurationGetAccountURL(TWCoinTypeAvalanchePChain, accId.get()));
+    auto id = WRAPS(TWCoinTypeConfigurationGetID(TWCoinTypeAvalanchePChain));
+    auto name = WRAPS(TWCoinTypeConfigurationGetName(TWCoinTypeAvalanchePChain));
+
+    ASSERT_EQ(TWCoinTypeConfigurationGetDecimals(TWCoinTypeAvalanchePChain), 9);
+    ASSERT_EQ(TWBlockchainAvalancheP, TWCoinTypeBlockchain(TWCoinTypeAvalanchePChain));
+    ASSERT_EQ(0x0, TWCoinTypeP2shPrefix(TWCoinTypeAvalanchePChain));
+    ASSERT_EQ(0x0, TWCoinTypeStaticPrefix(TWCoinTypeAvalanchePChain));
+    assertStringsEqual(symbol, "AVAX");
+    assertStringsEqual(txUrl, "https://subnets.avax.network/p-chain/tx/2jPRWQ2U6pwmvgA9S4y6gFjn6ef9MYRJ1yewaLgZ54xkXPk1St");
+    assertStringsEqual(accUrl, "https://subnets.avax.network/p-chain/address/P-avax18jma8ppw3nhx5r4ap8clazz0dps7rv5ukulre5");
+    assertStringsEqual(id, "avalanchep");
+    assertStringsEqual(name, "Avalanche P-Chain");
+}
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include "TestUtilities.h"
+
+#include "HexCoding.h"
+#include "TrustWalletCore/TWCardanoMessageSigner.h"
+
+#include <gtest/gtest.h>
+#include <nlohmann/json.hpp>
+
+namespace TW::Cardano::tests {
+
+const auto privateKeyHex = "089b68e458861be0c44bf9f7967f05cc91e51ede86dc679448a3566990b7785bd48c330875b1e0d03caaed0e67cecc42075dce1c7a13b1c49240508848ac82f603391c68824881ae3fc23a56a1a75ada3b96382db502e37564e84a5413cfaf1290dbd508e5ec71afaea98da2df1533c22ef02a26bb87b31907d0b2738fb7785b38d53aa68fc01230784c9209b2b2a2faf28491b3b1f1d221e63e704bbd0403c4154425dfbb01a2c5c042da411703603f89af89e57faae2946e2a5c18b1c5ca0e";
+const auto address = "addr1q8043m5heeaydnvtmmkyuhe6qv5havvhsf0d26q3jygsspxlyfpyk6yqkw0yhtyvtr0flekj84u64az82cufmqn65zdsylzk23";
+const auto stakingAddress = "stake1u80jysjtdzqt88jt4jx93h5lumfr67d273r4vwyasfa2pxcwxllmx";
+
+TEST(TWCardanoMessageSigner, SignAndVerifyData) {
+    const auto privateKey = WRAP(TWPrivateKey, TWPrivateKeyCreateWithData(DATA(privateKeyHex).get()));
+    const auto addressString = STRING(address);
+    // "Hello Cardano"
+    const auto payload = DATA("48656c6c6f2043617264616e6f");
+
+    const auto result = WRAPS(TWCardanoMessageSignerSignData(privateKey.get(), addressString.get(), payload.get()));
+    const auto json = nlohmann::json::parse(std::string(TWStringUTF8Bytes(result.get())));
+    EXPECT_EQ(json["signature"], "845846a201276761646472657373583901df58ee97ce7a46cd8bdeec4e5f3a03297eb197825ed5681191110804df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09ba166686173686564f44d48656c6c6f2043617264616e6f5840f11350ed305aabe2bec7815e015de3c52fab53878d121ee3d01202793190b492c71c3f2c3c9a215f4481d9c464074c3d5afa1fd739d549683d3aa40192d75d05");
+    EXPECT_EQ(json["key"], "a40101032720062158206d8a0b425bd2ec9692af39b1c0cf0e51caa07a603550e22f54091e872c7df290");
+
+    const auto signature = STRING(json["signature"].get<std::string>().c_str());
+    const auto key = STRING(json["key"].get<std::string>().c_str());
+    EXPECT_TRUE(TWCardanoMessageSignerVerifyData(addressString.get(), payload.get(), signature.get(), key.get()));
+
+    const auto otherPayload = DATA("48656c6c6f");
+    EXPECT_FALSE(TWCardanoMessageSignerVerifyData(addressString.get(), otherPayload.get(), signature.get(), key.get()));
+    // The signature is bound to the address.
+    const auto otherAddress = STRING(stakingAddress);
+    EXPECT_FALSE(TWCardanoMessageSignerVerifyData(otherAddress.get(), payload.get(), signature.get(), key.get()));
+}
+
+TEST(TWCardanoMessageSigner, SignDataStakingAddress) {
+    const auto privateKey = WRAP(TWPrivateKey, TWPrivateKeyCreateWithData(DATA(privateKeyHex).get()));
+    const auto addressString = STRING(stakingAddress);
+    const auto payload = DATA("48656c6c6f");
+
+    const auto result = WRAPS(TWCardanoMessageSignerSignData(privateKey.get(), addressString.get(), payload.get()));
+    const auto json = nlohmann::json::parse(std::string(TWStringUTF8Bytes(result.get())));
+    EXPECT_EQ(json["signature"], "84582aa201276761646472657373581de1df22424b6880b39e4bac8c58de9fe6d23d79aaf44756389d827aa09ba166686173686564f44548656c6c6f58409841dfb6803f347659d1f847fb1e709a1526e5a319644fb1a33cb854a1ffb123ef07a2b2be8df99cc86732f4c244aa4ec2fa7de8b3448e2b2fde33e2fa148a00");
+    EXPECT_EQ(json["key"], "a4010103272006215820e554163344aafc2bbefe778a6953ddce0583c2f8e0a0686929c020ca33e06932");
+
+    const auto signature = STRING(json["signature"].get<std::string>().c_str());
+    const auto key = STRING(json["key"].get<std::string>().c_str());
+    EXPECT_TRUE(TWCardanoMessageSignerVerifyData(addressString.get(), payload.get(), signature.get(), key.get()));
+}
+
+TEST(TWCardanoMessageSigner, SignDataForeignAddress) {
+    const auto privateKey = WRAP(TWPrivateKey, TWPrivateKeyCreateWithData(DATA(privateKeyHex).get()));
+    const auto foreignAddress = STRING("addr1q92cmkgzv9h4e5q7mnrzsuxtgayvg4qr7y3gyx97ukmz3dfx7r9fu73vqn25377ke6r0xk97zw07dqr9y5myxlgadl2s0dgke5");
+    const auto payload = DATA("48656c6c6f2043617264616e6f");
+
+    const auto result = WRAPS(TWCardanoMessageSignerSignData(privateKey.get(), foreignAddress.get(), payload.get()));
+    EXPECT_EQ(std::string(TWStringUTF8Bytes(result.get())), "");
+}
+
+} // namespace TW::Cardano::tests
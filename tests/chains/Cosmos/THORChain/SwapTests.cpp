@@ -1151,7 +1151,8 @@ TEST(THORChainSwap, Memo) {
     EXPECT_EQ(builder.affFeeRate("10").buildMemo(), "=:BTC.BTC:btc123:1234:feeaddr:10");
     EXPECT_EQ(builder.extraMemo("extramemo").buildMemo(), "=:BTC.BTC:btc123:1234:feeaddr:10:extramemo");
     EXPECT_EQ(builder.extraMemo("").affFeeRate("0").buildMemo(), "=:BTC.BTC:btc123:1234:feeaddr:0");
-    EXPECT_EQ(builder.affFeeAddress("").affFeeRate("10").buildMemo(), "=:BTC.BTC:btc123:1234::10");
+    // An affiliate fee requires an affiliate address.
+    EXPECT_EQ(builder.affFeeAddress("").affFeeRate("10").buildMemo(), "");
     EXPECT_EQ(builder.extraMemo("extramemo").affFeeRate("").buildMemo(), "=:BTC.BTC:btc123:1234:::extramemo");
 
     Proto::Asset toAssetETH;
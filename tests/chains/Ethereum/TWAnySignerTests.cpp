@@ -581,4 +581,71 @@ TEST(TWAnySignerEthereum, UnstakeRocketPool) {
 }
 
 
+static Proto::SigningInput evmTransferInput(uint64_t chainId, Proto::TransactionMode txMode) {
+    auto chainIdData = store(uint256_t(chainId));
+    auto nonce = store(uint256_t(9));
+    auto gasPrice = store(uint256_t(20000000000));
+    auto gasLimit = store(uint256_t(21000));
+    auto maxInclusionFeePerGas = store(uint256_t(2000000000));
+    auto maxFeePerGas = store(uint256_t(20000000000));
+    auto amount = store(uint256_t(1000000000000000000));
+    auto key = parse_hex("4646464646464646464646464646464646464646464646464646464646464646");
+
+    Proto::SigningInput input;
+    input.set_chain_id(chainIdData.data(), chainIdData.size());
+    input.set_nonce(nonce.data(), nonce.size());
+    input.set_tx_mode(txMode);
+    input.set_gas_price(gasPrice.data(), gasPrice.size());
+    input.set_gas_limit(gasLimit.data(), gasLimit.size());
+    input.set_max_inclusion_fee_per_gas(maxInclusionFeePerGas.data(), maxInclusionFeePerGas.size());
+    input.set_max_fee_per_gas(maxFeePerGas.data(), maxFeePerGas.size());
+    input.set_to_address("0x3535353535353535353535353535353535353535");
+    input.set_private_key(key.data(), key.size());
+    auto& transfer = *input.mutable_transaction()->mutable_transfer();
+    transfer.set_amount(amount.data(), amount.size());
+    return input;
+}
+
+static Proto::SigningOutput signEvm(const Proto::SigningInput& input) {
+    auto inputData = input.SerializeAsString();
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t*)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWAnySignerSignEvm(inputTWData.get()));
+    Proto::SigningOutput output;
+    output.ParseFromArray(TWDataBytes(outputTWData.get()), static_cast<int>(TWDataSize(outputTWData.get())));
+    return output;
+}
+
+TEST(TWAnySignerEthereum, SignEvmNetworkDefaultLegacy) {
+    // Smart Chain doesn't support EIP-1559, so a Legacy transaction is expected.
+    auto input = evmTransferInput(56, Proto::TransactionMode::Legacy);
+    Proto::SigningOutput output;
+    ANY_SIGN(input, TWCoinTypeSmartChain);
+
+    auto evmOutput = signEvm(evmTransferInput(56, Proto::TransactionMode::NetworkDefault));
+    ASSERT_EQ(evmOutput.error(), Common::Proto::OK);
+    EXPECT_EQ(hex(evmOutput.encoded()), "f86d098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000808194a007764d8e7d4ce45afb36cb2f5bec741f96eee3b8f23304e4d35b0f389912e7f7a047c14849f32c33c269dec152d168ddeca152fec8ceeb7369177ff3a06dc72c48");
+    EXPECT_EQ(hex(evmOutput.encoded()), hex(output.encoded()));
+}
+
+TEST(TWAnySignerEthereum, SignEvmUserAddedChainId) {
+    // Unknown networks are signed as EIP-1559 Ethereum transactions.
+    auto input = evmTransferInput(123456789, Proto::TransactionMode::Enveloped);
+    Proto::SigningOutput output;
+    ANY_SIGN(input, TWCoinTypeEthereum);
+
+    auto evmOutput = signEvm(evmTransferInput(123456789, Proto::TransactionMode::NetworkDefault));
+    ASSERT_EQ(evmOutput.error(), Common::Proto::OK);
+    EXPECT_EQ(hex(evmOutput.encoded()), "02f87784075bcd150984773594008504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080c001a06bd34f982436d714110467b33799f34bc1292032e1b1f6f728a9fdb53319e890a07963f6fea7f4ce08608fe9f908cd8990fc161ad45933b654d06fabce8e88f324");
+    EXPECT_EQ(hex(evmOutput.encoded()), hex(output.encoded()));
+}
+
+TEST(TWAnySignerEthereum, SignEvmZeroChainId) {
+    auto input = evmTransferInput(0, Proto::TransactionMode::NetworkDefault);
+    auto inputData = input.SerializeAsString();
+    auto inputTWData = WRAPD(TWDataCreateWithBytes((const uint8_t*)inputData.data(), inputData.size()));
+    auto outputTWData = WRAPD(TWAnySignerSignEvm(inputTWData.get()));
+    EXPECT_EQ(TWDataSize(outputTWData.get()), 0ul);
+}
+
+
 } // namespace TW::Ethereum
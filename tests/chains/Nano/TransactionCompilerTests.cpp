@@ -13,7 +13,6 @@
 #include <TrustWalletCore/TWStellarPassphrase.h>
 
 #include "HexCoding.h"
-#include "Nano/Signer.h"
 #include "PrivateKey.h"
 #include "PublicKey.h"
 
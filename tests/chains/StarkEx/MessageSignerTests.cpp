@@ -20,6 +20,25 @@ TEST(StarkExMessageSigner, SignAndVerify) {
     ASSERT_TRUE(StarkEx::MessageSigner::verifyMessage(starkPubKey, starkMsg, starkSignature));
 }
 
+TEST(StarkExMessageSigner, SignWithModes) {
+    PrivateKey starkPrivKey(parse_hex("04be51a04e718c202e4dca60c2b72958252024cfc1070c090dd0f170298249de", true));
+    auto starkPubKey = starkPrivKey.getPublicKey(TWPublicKeyTypeStarkex);
+    auto starkMsg = "463a2240432264a3aa71a5713f2a4e4c1b9e12bbb56083cd56af6d878217cf";
+    auto expected = "04cf5f21333dd189ada3c0f2a51430d733501a9b1d5e07905273c1938cfb261e05b6013d74adde403e8953743a338c8d414bb96bf69d2ca1a91a85ed2700a528";
+
+    // The default modes are used by `signMessage`.
+    ASSERT_EQ(StarkEx::MessageSigner::signMessage(starkPrivKey, starkMsg, TWStarkExHashModeStrict, TWStarkExNonceModeGrindExtraEntropy), expected);
+    // Both nonce modes start with the same RFC6979 nonce, which is valid for this message.
+    ASSERT_EQ(StarkEx::MessageSigner::signMessage(starkPrivKey, starkMsg, TWStarkExHashModeStrict, TWStarkExNonceModeRfc6979), expected);
+
+    // A 256-bit hash doesn't fit the stark field.
+    auto fullHash = "f8a1fba7c3c1e4e7b3b0a9e7d1a7e1b0c3f0915b2e8c4a1d7e9f3b2c1a0d9e8f";
+    ASSERT_EQ(StarkEx::MessageSigner::signMessage(starkPrivKey, fullHash, TWStarkExHashModeStrict, TWStarkExNonceModeGrindExtraEntropy), "");
+    // Unless it's truncated to its 251 most significant bits.
+    auto truncatedSignature = StarkEx::MessageSigner::signMessage(starkPrivKey, fullHash, TWStarkExHashModeTruncate, TWStarkExNonceModeGrindExtraEntropy);
+    ASSERT_TRUE(StarkEx::MessageSigner::verifyMessage(starkPubKey, "07c50fdd3e1e0f273d9d854f3e8d3f0d861f848ad9746250ebf4f9d960d06cf4", truncatedSignature));
+}
+
 TEST(TWStarkExMessageSigner, SignAndVerify) {
     const auto privKeyData = "04be51a04e718c202e4dca60c2b72958252024cfc1070c090dd0f170298249de";
     const auto privateKey = WRAP(TWPrivateKey, TWPrivateKeyCreateWithData(DATA(privKeyData).get()));
@@ -32,4 +51,21 @@ TEST(TWStarkExMessageSigner, SignAndVerify) {
     delete pubKey;
 }
 
+TEST(TWStarkExMessageSigner, SignWithModes) {
+    const auto privKeyData = "04be51a04e718c202e4dca60c2b72958252024cfc1070c090dd0f170298249de";
+    const auto privateKey = WRAP(TWPrivateKey, TWPrivateKeyCreateWithData(DATA(privKeyData).get()));
+    const auto message = STRING("463a2240432264a3aa71a5713f2a4e4c1b9e12bbb56083cd56af6d878217cf");
+    const auto expected = "04cf5f21333dd189ada3c0f2a51430d733501a9b1d5e07905273c1938cfb261e05b6013d74adde403e8953743a338c8d414bb96bf69d2ca1a91a85ed2700a528";
+
+    const auto signature = WRAPS(TWStarkExMessageSignerSignMessageWithModes(privateKey.get(), message.get(), TWStarkExHashModeStrict, TWStarkExNonceModeRfc6979));
+    EXPECT_EQ(std::string(TWStringUTF8Bytes(signature.get())), expected);
+
+    const auto digest = DATA("463a2240432264a3aa71a5713f2a4e4c1b9e12bbb56083cd56af6d878217cf");
+    const auto signatureData = WRAPD(TWPrivateKeySignStarkex(privateKey.get(), digest.get(), TWStarkExHashModeStrict, TWStarkExNonceModeGrindExtraEntropy));
+    assertHexEqual(signatureData, expected);
+
+    const auto fullHash = DATA("f8a1fba7c3c1e4e7b3b0a9e7d1a7e1b0c3f0915b2e8c4a1d7e9f3b2c1a0d9e8f");
+    EXPECT_EQ(TWPrivateKeySignStarkex(privateKey.get(), fullHash.get(), TWStarkExHashModeStrict, TWStarkExNonceModeGrindExtraEntropy), nullptr);
+}
+
 }
@@ -18,4 +18,15 @@ TEST(TWTONMessageSigner, SignMessage) {
     assertStringsEqual(signature, "2490fbaa72aec0b77b19162bbbe0b0e3f7afd42cc9ef469f0494cd4a366a4bf76643300cd5991f66bce6006336742b8d1d435d541d244dcc013d428472e89504");
 }
 
+TEST(TWTONMessageSigner, SignTonProof) {
+    const auto privateKeyBytes = DATA("112d4e2e700a468f1eae699329202f1ee671d6b665caa2d92dea038cf3868c18");
+    const auto privateKey = WRAP(TWPrivateKey, TWPrivateKeyCreateWithData(privateKeyBytes.get()));
+    const auto address = STRING("0:2d29bfa071c8c62fa3398b661a842e60f04cb8a915fb3e749ef7c6c41343e16c");
+    const auto domain = STRING("ton-connect.github.io");
+    const auto payload = STRING("gems-ton-proof-payload");
+
+    const auto signature = WRAPS(TWTONMessageSignerSignTonProof(privateKey.get(), address.get(), domain.get(), 1733270400, payload.get()));
+    assertStringsEqual(signature, "HKocvQFtJya4A+EBAgO/ECNRQvnpsRgfiRK1FTSZ7ys+80EIHzJxHI1oqWGFJ8Ywohry5vekNOxnEdpw9XaKAQ==");
+}
+
 } // namespace TW::TheOpenNetwork::tests
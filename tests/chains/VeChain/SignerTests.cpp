@@ -29,4 +29,34 @@ TEST(Signer, Sign) {
     ASSERT_EQ(hex(signature), "3181b1094150f8e4f51f370b805cc9c5b107504145b9e316e846d5e5dbeedb5c1c2b5d217f197a105983dfaad6a198414d5731c7447493cb6b5169907d73dbe101");
 }
 
+TEST(Signer, SignDelegated) {
+    auto transaction = Transaction();
+    transaction.chainTag = 1;
+    transaction.blockRef = 1;
+    transaction.expiration = 1;
+    transaction.clauses.push_back(
+        Clause(Ethereum::Address("0x3535353535353535353535353535353535353535"), 1000, {})
+    );
+    transaction.gasPriceCoef = 0;
+    transaction.gas = 21000;
+    transaction.nonce = 1;
+    transaction.features = Transaction::DelegatedFeature;
+
+    auto key = PrivateKey(parse_hex("0x4646464646464646464646464646464646464646464646464646464646464646"), TWCurveSECP256k1);
+    auto delegatorKey = PrivateKey(parse_hex("0x7582be841ca040aa940fff6c05773129e135623e41acce3e0b8ba520dc1ae26a"), TWCurveSECP256k1);
+    const auto sender = Ethereum::Address("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+
+    ASSERT_EQ(hex(transaction.signingHash()), "36ac9d32d33c1793d5307a6ba7dd5492c39173d337d81ef48a108f8eb016a9c8");
+    ASSERT_EQ(hex(transaction.delegatorSigningHash(sender)), "ef12ebb3c9e65ba0b0c7ee11e92d03c387999c03bed9cb0db37d3ff29d7e487e");
+
+    auto signature = Signer::sign(key, transaction);
+    ASSERT_EQ(hex(signature), "6c096f0b6d5e245ca7a4bead6dfb7308d190ea13d0ec7167e94926e8d35257b34ebdadb653858ed46cd9f7ae98637e4c3ae440b2e15d4bfaa7e4060fb819727b01");
+    auto delegatorSignature = Signer::signAsDelegator(delegatorKey, transaction, sender);
+    ASSERT_EQ(hex(delegatorSignature), "ecabd2a64c751dbca13959e27200fc15a18d3a2b8d1e30507b36b937715d86d75b5c658e1735093dc40c54dedade5ba6b2edb35c61c6115cf6e4e9d27db5d3c800");
+
+    transaction.signature = signature;
+    append(transaction.signature, delegatorSignature);
+    ASSERT_EQ(hex(transaction.encode()), "f8aa010101dad99435353535353535353535353535353535353535358203e880808252088001c101b8826c096f0b6d5e245ca7a4bead6dfb7308d190ea13d0ec7167e94926e8d35257b34ebdadb653858ed46cd9f7ae98637e4c3ae440b2e15d4bfaa7e4060fb819727b01ecabd2a64c751dbca13959e27200fc15a18d3a2b8d1e30507b36b937715d86d75b5c658e1735093dc40c54dedade5ba6b2edb35c61c6115cf6e4e9d27db5d3c800");
+}
+
 } // namespace TW::VeChain
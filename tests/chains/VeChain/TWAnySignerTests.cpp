@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 #include "HexCoding.h"
+#include "uint256.h"
 #include "proto/VeChain.pb.h"
 #include "TestUtilities.h"
 #include <TrustWalletCore/TWAnySigner.h>
@@ -34,4 +35,65 @@ TEST(TWAnySignerVeChain, Sign) {
     ASSERT_EQ(hex(output.encoded()), "f86a010101dcdb943535353535353535353535353535353535353535843130303080808252088001c0b841bf8edf9600e645b5abd677cb52f585e7f655d1361075d511b37f707a9f31da6702d28739933b264527a1d05b046f5b74044b88c30c3f5a09d616bd7a4af4901601");
 }
 
+Proto::SigningInput delegatedInput() {
+    auto input = Proto::SigningInput();
+
+    input.set_chain_tag(1);
+    input.set_block_ref(1);
+    input.set_expiration(1);
+    input.set_gas_price_coef(0);
+    input.set_gas(21000);
+    input.set_nonce(1);
+    input.set_delegated(true);
+
+    auto key = parse_hex("0x4646464646464646464646464646464646464646464646464646464646464646");
+    input.set_private_key(key.data(), key.size());
+
+    auto& clause = *input.add_clauses();
+    auto amount = store(1000);
+    clause.set_to("0x3535353535353535353535353535353535353535");
+    clause.set_value(amount.data(), amount.size());
+
+    return input;
+}
+
+TEST(TWAnySignerVeChain, SignDelegated) {
+    const auto expected = "f8aa010101dad99435353535353535353535353535353535353535358203e880808252088001c101b8826c096f0b6d5e245ca7a4bead6dfb7308d190ea13d0ec7167e94926e8d35257b34ebdadb653858ed46cd9f7ae98637e4c3ae440b2e15d4bfaa7e4060fb819727b01ecabd2a64c751dbca13959e27200fc15a18d3a2b8d1e30507b36b937715d86d75b5c658e1735093dc40c54dedade5ba6b2edb35c61c6115cf6e4e9d27db5d3c800";
+
+    { // Signed by the gas payer at once.
+        auto input = delegatedInput();
+        auto delegatorKey = parse_hex("0x7582be841ca040aa940fff6c05773129e135623e41acce3e0b8ba520dc1ae26a");
+        input.set_delegator_private_key(delegatorKey.data(), delegatorKey.size());
+
+        Proto::SigningOutput output;
+        ANY_SIGN(input, TWCoinTypeVeChain);
+
+        ASSERT_EQ(output.error(), Common::Proto::OK);
+        ASSERT_EQ(hex(output.encoded()), expected);
+        ASSERT_EQ(hex(output.signature()), "6c096f0b6d5e245ca7a4bead6dfb7308d190ea13d0ec7167e94926e8d35257b34ebdadb653858ed46cd9f7ae98637e4c3ae440b2e15d4bfaa7e4060fb819727b01ecabd2a64c751dbca13959e27200fc15a18d3a2b8d1e30507b36b937715d86d75b5c658e1735093dc40c54dedade5ba6b2edb35c61c6115cf6e4e9d27db5d3c800");
+    }
+
+    { // The gas payer signature is provided by a fee delegation service.
+        auto input = delegatedInput();
+        auto delegatorSignature = parse_hex("ecabd2a64c751dbca13959e27200fc15a18d3a2b8d1e30507b36b937715d86d75b5c658e1735093dc40c54dedade5ba6b2edb35c61c6115cf6e4e9d27db5d3c800");
+        input.set_delegator_signature(delegatorSignature.data(), delegatorSignature.size());
+
+        Proto::SigningOutput output;
+        ANY_SIGN(input, TWCoinTypeVeChain);
+
+        ASSERT_EQ(output.error(), Common::Proto::OK);
+        ASSERT_EQ(hex(output.encoded()), expected);
+    }
+}
+
+TEST(TWAnySignerVeChain, SignDelegatedNoDelegator) {
+    auto input = delegatedInput();
+
+    Proto::SigningOutput output;
+    ANY_SIGN(input, TWCoinTypeVeChain);
+
+    ASSERT_EQ(output.error(), Common::Proto::Error_invalid_params);
+    ASSERT_TRUE(output.encoded().empty());
+}
+
 } // namespace TW::VeChain::tests
@@ -93,3 +93,77 @@ TEST(VechainCompiler, CompileWithSignatures) {
         EXPECT_EQ(output.error(), Common::Proto::Error_no_support_n2n);
     }
 }
+
+TEST(VechainCompiler, CompileDelegatedWithSignatures) {
+    const auto coin = TWCoinTypeVeChain;
+    const auto senderSignature = parse_hex("6c096f0b6d5e245ca7a4bead6dfb7308d190ea13d0ec7167e94926e8d35257b34ebdadb653858ed46cd9f7ae98637e4c3ae440b2e15d4bfaa7e4060fb819727b01");
+    const auto delegatorSignature = parse_hex("ecabd2a64c751dbca13959e27200fc15a18d3a2b8d1e30507b36b937715d86d75b5c658e1735093dc40c54dedade5ba6b2edb35c61c6115cf6e4e9d27db5d3c800");
+
+    /// Step 1: Prepare transaction input (protobuf)
+    TW::VeChain::Proto::SigningInput input;
+    PrivateKey privateKey =
+        PrivateKey(parse_hex("0x4646464646464646464646464646464646464646464646464646464646464646"));
+    auto publicKey = privateKey.getPublicKey(publicKeyType(coin));
+
+    input.set_chain_tag(1);
+    input.set_block_ref(1);
+    input.set_expiration(1);
+    input.set_gas_price_coef(0);
+    input.set_gas(21000);
+    input.set_nonce(1);
+    input.set_delegated(true);
+
+    auto& clause = *input.add_clauses();
+    auto amount = parse_hex("03e8"); // 1000
+    clause.set_to("0x3535353535353535353535353535353535353535");
+    clause.set_value(amount.data(), amount.size());
+
+    /// Step 2: Obtain preimage hash, the reserved field contains the delegation feature
+    {
+        auto stringInput = input.SerializeAsString();
+        auto dataInput = TW::Data(stringInput.begin(), stringInput.end());
+
+        const auto preImageHashData = TransactionCompiler::preImageHashes(coin, dataInput);
+        auto preSigningOutput = TW::TxCompiler::Proto::PreSigningOutput();
+        ASSERT_TRUE(
+            preSigningOutput.ParseFromArray(preImageHashData.data(), (int)preImageHashData.size()));
+        ASSERT_EQ(preSigningOutput.error(), Common::Proto::OK);
+
+        EXPECT_EQ(hex(preSigningOutput.data()),
+                  "e6010101dad99435353535353535353535353535353535353535358203e880808252088001c101");
+        EXPECT_EQ(hex(preSigningOutput.data_hash()),
+                  "36ac9d32d33c1793d5307a6ba7dd5492c39173d337d81ef48a108f8eb016a9c8");
+
+        /// Step 3: Verify the sender signature
+        ASSERT_TRUE(publicKey.verify(senderSignature, data(preSigningOutput.data_hash())));
+
+        /// Negative: the gas payer signature is not set
+        const Data outputData =
+            TransactionCompiler::compileWithSignatures(coin, dataInput, {senderSignature}, {publicKey.bytes});
+        VeChain::Proto::SigningOutput output;
+        ASSERT_TRUE(output.ParseFromArray(outputData.data(), (int)outputData.size()));
+        EXPECT_EQ(output.encoded().size(), 0ul);
+        EXPECT_EQ(output.error(), Common::Proto::Error_invalid_params);
+    }
+
+    /// Step 4: Compile with the sender signature and the gas payer signature
+    {
+        input.set_delegator_signature(delegatorSignature.data(), delegatorSignature.size());
+        auto stringInput = input.SerializeAsString();
+        auto dataInput = TW::Data(stringInput.begin(), stringInput.end());
+
+        const Data outputData =
+            TransactionCompiler::compileWithSignatures(coin, dataInput, {senderSignature}, {publicKey.bytes});
+
+        TW::VeChain::Proto::SigningOutput output;
+        ASSERT_TRUE(output.ParseFromArray(outputData.data(), (int)outputData.size()));
+        ASSERT_EQ(output.error(), Common::Proto::OK);
+        ASSERT_EQ(hex(output.encoded()),
+                  "f8aa010101dad99435353535353535353535353535353535353535358203e880808252088001c101b8"
+                  "826c096f0b6d5e245ca7a4bead6dfb7308d190ea13d0ec7167e94926e8d35257b34ebdadb653858ed4"
+                  "6cd9f7ae98637e4c3ae440b2e15d4bfaa7e4060fb819727b01ecabd2a64c751dbca13959e27200fc15"
+                  "a18d3a2b8d1e30507b36b937715d86d75b5c658e1735093dc40c54dedade5ba6b2edb35c61c6115cf6"
+                  "e4e9d27db5d3c800");
+        ASSERT_EQ(hex(output.signature()), hex(senderSignature) + hex(delegatorSignature));
+    }
+}
@@ -403,6 +403,15 @@ TEST(Coin, DeriveAddress) {
         case TWCoinTypePolymesh:
             EXPECT_EQ(address, "2HqjMm2goapWvXQBqjjEdVaTZsUmunWwEq1TSToDR1pDzQ1F");
             break;
+        case TWCoinTypeLiquid:
+            EXPECT_EQ(address, "ex1qhkfq3zahaqkkzx5mjnamwjsfpq2jk7z09gus9v");
+            break;
+        case TWCoinTypeAvalancheXChain:
+            EXPECT_EQ(address, "X-avax1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0vej24u");
+            break;
+        case TWCoinTypeAvalanchePChain:
+            EXPECT_EQ(address, "P-avax1hkfq3zahaqkkzx5mjnamwjsfpq2jk7z0vej24u");
+            break;
             // end_of_coin_address_derivation_tests_marker_do_not_modify
             // no default branch here, intentionally, to better notice any missing coins
         }
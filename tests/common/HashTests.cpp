@@ -63,6 +63,16 @@ TEST(HashTests, hmac256) {
     EXPECT_EQ(hex(hmac), expectedHmac);
 }
 
+TEST(HashTests, hmac256Verify) {
+    const Data key = parse_hex("531cbfcf12a168faff61af28bf437377397b4bf435ee732cf4ac95761a651f14");
+    const Data data = parse_hex("f300888ca4f512cebdc0020ff0f7224c7f896315e90e172bed65d005138f224d");
+    auto mac = parse_hex("a7301d5563614e3955750e4480aabf7753f44b4975308aeb8e23c31e114962ab");
+    EXPECT_TRUE(Hash::hmac256Verify(key, data, mac));
+    EXPECT_FALSE(Hash::hmac256Verify(key, data, subData(mac, 0, 31)));
+    mac[0] ^= 1;
+    EXPECT_FALSE(Hash::hmac256Verify(key, data, mac));
+}
+
 TEST(HashTests, allHashEnum) {
     const auto tests = {
         make_tuple(Hash::HasherSha1, "2fd4e1c67a2d28fced849ee1bb76e7391b93eb12"),
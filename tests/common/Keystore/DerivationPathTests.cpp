@@ -98,6 +98,12 @@ TEST(Derivation, alternativeDerivation) {
     EXPECT_EQ(TW::derivationPath(TWCoinTypePactus, TWDerivationPactusTestnet).string(), "m/44'/21777'/3'/0'");
     EXPECT_EQ(std::string(TW::derivationName(TWCoinTypePactus, TWDerivationPactusMainnet)), "mainnet");
     EXPECT_EQ(std::string(TW::derivationName(TWCoinTypePactus, TWDerivationPactusTestnet)), "testnet");
+
+    EXPECT_EQ(TW::derivationPath(TWCoinTypeKava).string(), "m/44'/459'/0'/0/0");
+    EXPECT_EQ(TW::derivationPath(TWCoinTypeKava, TWDerivationKavaFallback).string(), "m/44'/118'/0'/0/0");
+    EXPECT_EQ(std::string(TW::derivationName(TWCoinTypeKava, TWDerivationKavaFallback)), "fallback");
+    EXPECT_EQ(TW::derivationPath(TWCoinTypeNativeInjective).string(), "m/44'/60'/0'/0/0");
+    EXPECT_EQ(TW::derivationPath(TWCoinTypeNativeInjective, TWDerivationNativeInjectiveFallback).string(), "m/44'/118'/0'/0/0");
 }
 
 } // namespace TW
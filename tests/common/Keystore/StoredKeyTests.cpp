@@ -746,4 +746,23 @@ TEST(StoredKey, CreateWithMnemonicAlternativeDerivation) {
     EXPECT_EQ(hex(key.privateKey(coin, TWDerivationSolanaSolana, gPassword).bytes), "d49a5fa7f77593534c7afd2ba8dc8e9d8b007bc6ec65fe8df25ffe6fafc57151");
 }
 
+TEST(StoredKey, ExportImportBackup) {
+    auto key = StoredKey::createWithMnemonicAddDefaultAddress("name", gPassword, gMnemonic, coinTypeEth);
+    const auto backupPassword = TW::data(string("backup password"));
+    const auto metadata = TW::data(string(R"({"name":"name"})"));
+
+    const auto backup = key.exportBackup(backupPassword, metadata);
+    EXPECT_EQ(string(backup.begin(), backup.begin() + 4), "TWEB");
+
+    const auto imported = StoredKey::importBackup(backup, backupPassword);
+    EXPECT_EQ(imported.json(), key.json());
+    EXPECT_EQ(string(imported.wallet(gPassword).getMnemonic()), string(gMnemonic));
+
+    EXPECT_THROW(StoredKey::importBackup(backup, gPassword), DecryptionError);
+
+    auto tampered = backup;
+    tampered.back() ^= 1;
+    EXPECT_THROW(StoredKey::importBackup(tampered, backupPassword), DecryptionError);
+}
+
 } // namespace TW::Keystore
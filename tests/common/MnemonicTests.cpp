@@ -3,6 +3,7 @@
 // Copyright © 2017 Trust Wallet.
 
 #include "Mnemonic.h"
+#include "HexCoding.h"
 
 #include <gtest/gtest.h>
 
@@ -94,4 +95,136 @@ TEST(Mnemonic, suggest) {
     EXPECT_EQ(Mnemonic::suggest("program"), "program");
 }
 
+TEST(Mnemonic, validate) {
+    for (auto m: ValidInput) {
+        EXPECT_TRUE(Mnemonic::validate(m).isValid()) << "Expected valid " << m;
+    }
+
+    auto result = Mnemonic::validate("ripple scissors hisc mammal hire column oak again sun offer wealth tomorrow");
+    EXPECT_EQ(result.error, MnemonicError::InvalidWord);
+    EXPECT_EQ(result.wordIndex, 2);
+
+    // The mistyped word is reported even if the word count is wrong.
+    result = Mnemonic::validate("high culture ostrich wrist exist ignore interest hybridous exclude width more");
+    EXPECT_EQ(result.error, MnemonicError::InvalidWord);
+    EXPECT_EQ(result.wordIndex, 7);
+
+    // Extra spaces are empty words.
+    result = Mnemonic::validate("credit expect  life fade cover suit response wash pear what skull force");
+    EXPECT_EQ(result.error, MnemonicError::InvalidWord);
+    EXPECT_EQ(result.wordIndex, 2);
+
+    result = Mnemonic::validate("CREDIT expect life fade cover suit response wash pear what skull force");
+    EXPECT_EQ(result.error, MnemonicError::InvalidWord);
+    EXPECT_EQ(result.wordIndex, 0);
+
+    result = Mnemonic::validate("");
+    EXPECT_EQ(result.error, MnemonicError::InvalidWord);
+    EXPECT_EQ(result.wordIndex, 0);
+
+    result = Mnemonic::validate("credit expect life fade cover suit response wash what skull force");
+    EXPECT_EQ(result.error, MnemonicError::InvalidWordCount);
+    EXPECT_EQ(result.wordIndex, -1);
+
+    result = Mnemonic::validate("ripple scissors kick mammal hire column oak again sun offer wealth tomorrow");
+    EXPECT_EQ(result.error, MnemonicError::InvalidChecksum);
+    EXPECT_EQ(result.wordIndex, -1);
+}
+
+TEST(Mnemonic, toEntropy) {
+    auto entropy = Mnemonic::toEntropy("credit expect life fade cover suit response wash pear what skull force");
+    ASSERT_TRUE(entropy.has_value());
+    EXPECT_EQ(hex(*entropy), "330a0a0528e319b22defbba21f3b2b2d");
+
+    entropy = Mnemonic::toEntropy("zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote");
+    ASSERT_TRUE(entropy.has_value());
+    EXPECT_EQ(hex(*entropy), "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+
+    for (auto m: InvalidInput) {
+        EXPECT_FALSE(Mnemonic::toEntropy(m).has_value()) << "Expected invalid " << m;
+    }
+}
+
+TEST(Mnemonic, suggestWithLanguage) {
+    using Words = std::vector<std::string>;
+    EXPECT_EQ(Mnemonic::suggest("pro", MnemonicLanguage::English, 3), (Words{"problem", "process", "produce"}));
+    EXPECT_EQ(Mnemonic::suggest("PRO", MnemonicLanguage::English, 3), (Words{"problem", "process", "produce"}));
+    EXPECT_EQ(Mnemonic::suggest("rob", MnemonicLanguage::English, 10), (Words{"robot", "robust"}));
+    EXPECT_EQ(Mnemonic::suggest("a", MnemonicLanguage::English, 0), Words{});
+    EXPECT_EQ(Mnemonic::suggest("saos", MnemonicLanguage::English, 10), Words{});
+    EXPECT_EQ(Mnemonic::suggest("", MnemonicLanguage::English, 10), Words{});
+}
+
+TEST(Mnemonic, search) {
+    using Words = std::vector<std::string>;
+    {
+        const auto match = Mnemonic::search("air");
+        EXPECT_EQ(match.status, MnemonicWordStatus::Valid);
+        EXPECT_EQ(match.candidates, (Words{"air", "airport"}));
+    }
+    {
+        const auto match = Mnemonic::search("AIR");
+        EXPECT_EQ(match.status, MnemonicWordStatus::Valid);
+        EXPECT_EQ(match.candidates, (Words{"air", "airport"}));
+    }
+    {
+        const auto match = Mnemonic::search("airp");
+        EXPECT_EQ(match.status, MnemonicWordStatus::Prefix);
+        EXPECT_EQ(match.candidates, Words{"airport"});
+    }
+    {
+        // Mistyped words, the most similar words are suggested.
+        const auto match = Mnemonic::search("recieve");
+        EXPECT_EQ(match.status, MnemonicWordStatus::Invalid);
+        EXPECT_EQ(match.candidates, (Words{"receive", "believe", "recipe"}));
+    }
+    {
+        const auto match = Mnemonic::search("abandn");
+        EXPECT_EQ(match.status, MnemonicWordStatus::Invalid);
+        EXPECT_EQ(match.candidates, Words{"abandon"});
+    }
+    {
+        const auto match = Mnemonic::search("rboot");
+        EXPECT_EQ(match.status, MnemonicWordStatus::Invalid);
+        ASSERT_FALSE(match.candidates.empty());
+        EXPECT_EQ(match.candidates[0], "robot");
+    }
+    {
+        const auto match = Mnemonic::search("hisc");
+        EXPECT_EQ(match.status, MnemonicWordStatus::Invalid);
+        EXPECT_EQ(match.candidates, (Words{"dish", "fish", "high", "hill", "hint", "hip", "hire", "host", "kiss", "list"}));
+    }
+    {
+        const auto match = Mnemonic::search("hybridous");
+        EXPECT_EQ(match.status, MnemonicWordStatus::Invalid);
+        EXPECT_TRUE(match.candidates.empty());
+    }
+    {
+        const auto match = Mnemonic::search("");
+        EXPECT_EQ(match.status, MnemonicWordStatus::Invalid);
+        EXPECT_TRUE(match.candidates.empty());
+    }
+}
+
+TEST(Mnemonic, fromEntropy) {
+    EXPECT_EQ(Mnemonic::fromEntropy(parse_hex("00000000000000000000000000000000")).value_or(""),
+              "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
+    EXPECT_EQ(Mnemonic::fromEntropy(parse_hex("7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f")).value_or(""),
+              "legal winner thank year wave sausage worth useful legal winner thank year wave sausage wise");
+
+    // Invalid entropy sizes.
+    EXPECT_FALSE(Mnemonic::fromEntropy(parse_hex("000000000000000000000000000000")).has_value());
+    EXPECT_FALSE(Mnemonic::fromEntropy(parse_hex("0000000000000000000000000000000000")).has_value());
+    EXPECT_FALSE(Mnemonic::fromEntropy(Data(36)).has_value());
+    EXPECT_FALSE(Mnemonic::fromEntropy({}).has_value());
+}
+
+TEST(Mnemonic, entropyRoundTrip) {
+    for (auto m: ValidInput) {
+        const auto entropy = Mnemonic::toEntropy(m);
+        ASSERT_TRUE(entropy.has_value()) << "Expected valid " << m;
+        EXPECT_EQ(Mnemonic::fromEntropy(*entropy).value_or(""), m);
+    }
+}
+
 } // namespace
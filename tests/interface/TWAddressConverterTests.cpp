@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include "TestUtilities.h"
+
+#include <TrustWalletCore/TWAddressConverter.h>
+#include <TrustWalletCore/TWAddressFormat.h>
+
+#include <gtest/gtest.h>
+#include <vector>
+
+TEST(TWAddressConverter, EvmosToHex) {
+    const auto address = STRING("evmos13k7kclk7jpjx5cdmceycxxmu9x9l6daqepprmh");
+    const auto converted = WRAPS(TWAddressConverterConvert(address.get(), TWAddressFormatCosmosBech32, TWAddressFormatEvmHex, nullptr));
+    assertStringsEqual(converted, "0x8dbD6c7Ede90646a61Bbc649831b7c298BFd37A0");
+}
+
+TEST(TWAddressConverter, HexToEvmos) {
+    const auto address = STRING("0x8dbD6c7Ede90646a61Bbc649831b7c298BFd37A0");
+    const auto hrp = STRING("evmos");
+    const auto converted = WRAPS(TWAddressConverterConvert(address.get(), TWAddressFormatEvmHex, TWAddressFormatCosmosBech32, hrp.get()));
+    assertStringsEqual(converted, "evmos13k7kclk7jpjx5cdmceycxxmu9x9l6daqepprmh");
+}
+
+TEST(TWAddressConverter, TronToFilecoin) {
+    const auto address = STRING("TNtfCWCPgcNvouNuGVP8fs2iJzi8nnbX8N");
+    const auto converted = WRAPS(TWAddressConverterConvert(address.get(), TWAddressFormatTronBase58, TWAddressFormatFilecoinDelegated, nullptr));
+    assertStringsEqual(converted, "f410frw6wy7w6sbsguyn3yzeygg34fgf72n5ao5sxyky");
+}
+
+TEST(TWAddressConverter, EvmFamily) {
+    const auto hrp = STRING("evmos");
+    const std::vector<std::pair<const char*, TWAddressFormat>> addresses = {
+        {"0x8dbD6c7Ede90646a61Bbc649831b7c298BFd37A0", TWAddressFormatEvmHex},
+        {"evmos13k7kclk7jpjx5cdmceycxxmu9x9l6daqepprmh", TWAddressFormatCosmosBech32},
+        {"TNtfCWCPgcNvouNuGVP8fs2iJzi8nnbX8N", TWAddressFormatTronBase58},
+        {"f410frw6wy7w6sbsguyn3yzeygg34fgf72n5ao5sxyky", TWAddressFormatFilecoinDelegated},
+    };
+    for (const auto& [fromAddress, from] : addresses) {
+        for (const auto& [toAddress, to] : addresses) {
+            const auto address = STRING(fromAddress);
+            const auto converted = WRAPS(TWAddressConverterConvert(address.get(), from, to, hrp.get()));
+            assertStringsEqual(converted, toAddress);
+        }
+    }
+}
+
+TEST(TWAddressConverter, TonRawToUserFriendly) {
+    const auto address = STRING("0:8a8627861a5dd96c9db3ce0807b122da5ed473934ce7568a5b4b1c361cbb28ae");
+    const auto converted = WRAPS(TWAddressConverterConvert(address.get(), TWAddressFormatTonRaw, TWAddressFormatTonNonBounceable, nullptr));
+    assertStringsEqual(converted, "UQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorhqg");
+}
+
+TEST(TWAddressConverter, XrpClassicToXAddress) {
+    const auto address = STRING("rnBFvgZphmN39GWzUJeUitaP22Fr9be75H");
+    const auto converted = WRAPS(TWAddressConverterConvert(address.get(), TWAddressFormatXrpClassic, TWAddressFormatXrpXAddress, nullptr));
+    assertStringsEqual(converted, "X76UnYEMbQfEs3mUqgtjp4zFy9exgSxWAqcQwu9z2r5d7Tm");
+}
+
+TEST(TWAddressConverter, Invalid) {
+    const auto address = STRING("0x8dbD6c7Ede90646a61Bbc649831b7c298BFd37A0");
+    // Different address families.
+    EXPECT_EQ(TWAddressConverterConvert(address.get(), TWAddressFormatEvmHex, TWAddressFormatTonRaw, nullptr), nullptr);
+    // Bech32 hrp is required.
+    EXPECT_EQ(TWAddressConverterConvert(address.get(), TWAddressFormatEvmHex, TWAddressFormatCosmosBech32, nullptr), nullptr);
+    // The address doesn't match the given format.
+    const auto tron = STRING("TNtfCWCPgcNvouNuGVP8fs2iJzi8nnbX8N");
+    EXPECT_EQ(TWAddressConverterConvert(tron.get(), TWAddressFormatEvmHex, TWAddressFormatTronBase58, nullptr), nullptr);
+    const auto nonBounceable = STRING("UQCKhieGGl3ZbJ2zzggHsSLaXtRzk0znVopbSxw2HLsorhqg");
+    EXPECT_EQ(TWAddressConverterConvert(nonBounceable.get(), TWAddressFormatTonBounceable, TWAddressFormatTonRaw, nullptr), nullptr);
+    // Filecoin ID addresses are not delegated ones.
+    const auto filecoinId = STRING("f01024");
+    EXPECT_EQ(TWAddressConverterConvert(filecoinId.get(), TWAddressFormatFilecoinDelegated, TWAddressFormatEvmHex, nullptr), nullptr);
+    // Invalid checksum.
+    const auto filecoin = STRING("f410frw6wy7w6sbsguyn3yzeygg34fgf72n5ao5sxykq");
+    EXPECT_EQ(TWAddressConverterConvert(filecoin.get(), TWAddressFormatFilecoinDelegated, TWAddressFormatEvmHex, nullptr), nullptr);
+}
@@ -5,6 +5,7 @@
 #include "TestUtilities.h"
 
 #include "HexCoding.h"
+#include "proto/AddressValidation.pb.h"
 #include <TrustWalletCore/TWAnyAddress.h>
 #include <TrustWalletCore/TWCoinType.h>
 #include <TrustWalletCore/TWPublicKey.h>
@@ -239,3 +240,40 @@ TEST(TWAnyAddress, createFromPubKeyFilecoinAddressType) {
         ASSERT_TRUE(TWAnyAddressIsValid(actual.get(), TWCoinTypeFilecoin));
     }
 }
+
+static AddressValidation::Proto::ValidationResult validateAddress(const char* address, TWCoinType coin) {
+    const auto string = STRING(address);
+    const auto resultData = WRAPD(TWAnyAddressValidate(string.get(), coin));
+    AddressValidation::Proto::ValidationResult result;
+    EXPECT_TRUE(result.ParseFromArray(TWDataBytes(resultData.get()), static_cast<int>(TWDataSize(resultData.get()))));
+    return result;
+}
+
+TEST(TWAnyAddress, ValidateCppCoin) {
+    // Zilliqa is implemented in C++.
+    const auto valid = validateAddress("zil1j8xae6lggm8y63m3y2r7aefu797ze7mhzulnqg", TWCoinTypeZilliqa);
+    EXPECT_TRUE(valid.valid());
+    EXPECT_EQ(valid.normalized(), "zil1j8xae6lggm8y63m3y2r7aefu797ze7mhzulnqg");
+    EXPECT_EQ(valid.network(), AddressValidation::Proto::Mainnet);
+
+    const auto invalid = validateAddress("zil1j8xae6lggm8y63m3y2r7aefu797ze7mhzulnqq", TWCoinTypeZilliqa);
+    EXPECT_FALSE(invalid.valid());
+    EXPECT_EQ(invalid.network(), AddressValidation::Proto::Unknown);
+    EXPECT_EQ(invalid.error(), AddressValidation::Proto::InvalidChecksum);
+}
+
+TEST(TWAnyAddress, ValidateWrongChain) {
+    const auto result = validateAddress("vg1qujpe553lzgyg95g7k0w6zwscuy0ae022h4q4zg", TWCoinTypeBitcoin);
+    EXPECT_FALSE(result.valid());
+    EXPECT_EQ(result.network(), AddressValidation::Proto::Mainnet);
+    ASSERT_EQ(result.detected_coins_size(), 1);
+    EXPECT_EQ(result.detected_coins(0), TWCoinTypeVerge);
+    EXPECT_EQ(result.error(), AddressValidation::Proto::InvalidPrefix);
+}
+
+TEST(TWAnyAddress, ValidateTestnet) {
+    const auto result = validateAddress("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", TWCoinTypeBitcoin);
+    EXPECT_FALSE(result.valid());
+    EXPECT_EQ(result.network(), AddressValidation::Proto::Testnet);
+    EXPECT_EQ(result.detected_coins_size(), 0);
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include "TestUtilities.h"
+
+#include <TrustWalletCore/TWCurve.h>
+#include <TrustWalletCore/TWPrivateKey.h>
+
+#include <gtest/gtest.h>
+
+TEST(TWCurve, Values) {
+    // `TWCurveNone` must not be reused by the curves implemented in Rust.
+    ASSERT_EQ(TWCurveNone, 7);
+    ASSERT_EQ(TWCurveSchnorr, 8);
+}
+
+TEST(TWCurve, Name) {
+    assertStringsEqual(WRAPS(TWCurveName(TWCurveSECP256k1)), "secp256k1");
+    assertStringsEqual(WRAPS(TWCurveName(TWCurveED25519ExtendedCardano)), "ed25519ExtendedCardano");
+    assertStringsEqual(WRAPS(TWCurveName(TWCurveSchnorr)), "schnorr");
+    ASSERT_EQ(TWCurveName(TWCurveNone), nullptr);
+}
+
+TEST(TWCurve, SigningHashSpec) {
+    ASSERT_EQ(TWCurveSigningHashMinLength(TWCurveSECP256k1), 32u);
+    ASSERT_EQ(TWCurveSigningHashMaxLength(TWCurveSECP256k1), 32u);
+    ASSERT_TRUE(TWCurveSupportsSigningHash(TWCurveSECP256k1, TWSigningHashKeccak256));
+    ASSERT_FALSE(TWCurveSupportsSigningHash(TWCurveSECP256k1, TWSigningHashRaw));
+
+    ASSERT_EQ(TWCurveSigningHashMinLength(TWCurveED25519), 0u);
+    ASSERT_EQ(TWCurveSigningHashMaxLength(TWCurveED25519), 0u);
+    ASSERT_TRUE(TWCurveSupportsSigningHash(TWCurveED25519, TWSigningHashRaw));
+
+    ASSERT_TRUE(TWCurveSupportsSigningHash(TWCurveSchnorr, TWSigningHashTaggedSha256));
+    ASSERT_FALSE(TWCurveSupportsSigningHash(TWCurveNone, TWSigningHashRaw));
+}
+
+TEST(TWCurve, ValidateSigningMessage) {
+    const auto digest = DATA("0101010101010101010101010101010101010101010101010101010101010101");
+    const auto message = DATA("01010101010101010101010101010101010101010101010101010101010101");
+
+    ASSERT_TRUE(TWCurveValidateSigningMessage(TWCurveSECP256k1, digest.get()));
+    ASSERT_FALSE(TWCurveValidateSigningMessage(TWCurveSECP256k1, message.get()));
+    ASSERT_TRUE(TWCurveValidateSigningMessage(TWCurveED25519, message.get()));
+    ASSERT_FALSE(TWCurveValidateSigningMessage(TWCurveNone, digest.get()));
+}
+
+TEST(TWCurve, SignSchnorr) {
+    const auto privateKey = WRAP(TWPrivateKey, TWPrivateKeyCreateWithData(DATA("afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5").get()));
+    const auto digest = DATA("0101010101010101010101010101010101010101010101010101010101010101");
+
+    const auto signature = WRAPD(TWPrivateKeySign(privateKey.get(), digest.get(), TWCurveSchnorr));
+    ASSERT_EQ(TWDataSize(signature.get()), 64ul);
+}
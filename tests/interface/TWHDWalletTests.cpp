@@ -106,6 +106,31 @@ TEST(HDWallet, MasterPrivateKey) {
     assertHexEqual(hexKey2, "d258c2521f7802b8e83c32f2cc97bd06b69747847390c5e247a3d19faa74202e");
 }
 
+TEST(HDWallet, MasterFingerprint) {
+    const auto wallet = WRAP(TWHDWallet, TWHDWalletCreateWithMnemonic(gWords.get(), STRING("").get()));
+    EXPECT_EQ(TWHDWalletMasterFingerprint(wallet.get()), 0xf2e94636u);
+}
+
+TEST(HDWallet, AccountIdentifier) {
+    const auto wallet = WRAP(TWHDWallet, TWHDWalletCreateWithMnemonic(gWords.get(), gPassphrase.get()));
+
+    const auto account0 = WRAPS(TWHDWalletGetAccountIdentifier(wallet.get(), TWCoinTypeEthereum, TWDerivationDefault, 0));
+    assertStringsEqual(account0, "473c41797cff1c313bdfd5084d4d4795fd1807a932949abf38ffe961b2ea61eb");
+    const auto account1 = WRAPS(TWHDWalletGetAccountIdentifier(wallet.get(), TWCoinTypeEthereum, TWDerivationDefault, 1));
+    assertStringsEqual(account1, "13f75b40618763db2f89b72bc6db0f542ad611e7d83035ddc8e76450111f548d");
+
+    // Equal to the identifier of the account-level public key, e.g. of a watch-only account.
+    const auto key = WRAP(TWPrivateKey, TWHDWalletGetKey(wallet.get(), TWCoinTypeEthereum, STRING("m/44'/60'/0'").get()));
+    const auto publicKey = WRAP(TWPublicKey, TWPrivateKeyGetPublicKeySecp256k1(key.get(), false));
+    assertHexEqual(WRAPD(TWPublicKeyData(publicKey.get())), "02f704c99f75e7ea0345d021549f927a83aabf1a9e0d707099f82bba979ac211a3");
+    assertStringsEqual(WRAPS(TWPublicKeyAccountIdentifier(publicKey.get())), "473c41797cff1c313bdfd5084d4d4795fd1807a932949abf38ffe961b2ea61eb");
+
+    // The address key of the account has another identifier.
+    const auto addressKey = WRAP(TWPrivateKey, TWHDWalletGetKeyForCoin(wallet.get(), TWCoinTypeEthereum));
+    const auto addressPublicKey = WRAP(TWPublicKey, TWPrivateKeyGetPublicKeySecp256k1(addressKey.get(), false));
+    EXPECT_NE(std::string(TWStringUTF8Bytes(WRAPS(TWPublicKeyAccountIdentifier(addressPublicKey.get())).get())), "473c41797cff1c313bdfd5084d4d4795fd1807a932949abf38ffe961b2ea61eb");
+}
+
 TEST(HDWallet, Derive) {
     const auto derivationPath = TW::derivationPath(TWCoinTypeEthereum);
 
@@ -222,6 +247,28 @@ TEST(HDWallet, DeriveCosmos) {
     assertHexEqual(publicKeyData, "0257286ec3f37d33557bbbaa000b27744ac9023aa9967cae75a181d1ff91fa9dc5");
 }
 
+TEST(HDWallet, DeriveCosmosFallbackDerivation) {
+    auto wallet = WRAP(TWHDWallet, TWHDWalletCreateWithMnemonic(gWords.get(), gPassphrase.get()));
+    {
+        auto address = WRAP(TWString, TWHDWalletGetAddressForCoin(wallet.get(), TWCoinTypeKava));
+        assertStringsEqual(address, "kava1zrst72upua78pylhku9csxd5zmhsyrk7xhrdlf");
+    }
+    {
+        // m/44'/118'/0'/0/0
+        auto address = WRAP(TWString, TWHDWalletGetAddressDerivation(wallet.get(), TWCoinTypeKava, TWDerivationKavaFallback));
+        assertStringsEqual(address, "kava1gn2mlhs6zzfedvknrduazss962w7rh35k6mped");
+    }
+    {
+        auto address = WRAP(TWString, TWHDWalletGetAddressForCoin(wallet.get(), TWCoinTypeNativeEvmos));
+        assertStringsEqual(address, "evmos1ylh4eklqzamavfpc4lltd90r8lprxktepx2fnv");
+    }
+    {
+        // m/44'/118'/0'/0/0 key, but still a Keccak address as Evmos uses `eth_secp256k1` keys.
+        auto address = WRAP(TWString, TWHDWalletGetAddressDerivation(wallet.get(), TWCoinTypeNativeEvmos, TWDerivationNativeEvmosFallback));
+        assertStringsEqual(address, "evmos1s36ee5arepjqdtky2j0clw6w7gk8prahmu5ecm");
+    }
+}
+
 TEST(HDWallet, DeriveNimiq) {
     auto wallet = WRAP(TWHDWallet, TWHDWalletCreateWithMnemonic(gWords.get(), gPassphrase.get()));
     auto key = WRAP(TWPrivateKey, TWHDWalletGetKeyForCoin(wallet.get(), TWCoinTypeNimiq));
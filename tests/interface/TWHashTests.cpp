@@ -251,3 +251,18 @@ TEST(TWHashTests, Groestl512Groestl512) {
         EXPECT_EQ(hex(data(TWDataBytes(hash.get()), TWDataSize(hash.get()))), get<1>(test));
     }
 }
+
+TEST(TWHashTests, Poseidon) {
+    // circomlib `Poseidon(2)` of `[1, 2]`.
+    const auto inData = DATA("00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002");
+    const auto hash = WRAPD(TWHashPoseidon(inData.get()));
+    ASSERT_NE(hash, nullptr);
+    EXPECT_EQ(hex(data(TWDataBytes(hash.get()), TWDataSize(hash.get()))), "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a");
+
+    // Not a multiple of 32 bytes.
+    const auto shortData = DATA("000000000000000000000000000000000000000000000000000000000000000102");
+    EXPECT_EQ(TWHashPoseidon(shortData.get()), nullptr);
+    // The BN254 scalar field modulus is not a field element.
+    const auto modulus = DATA("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+    EXPECT_EQ(TWHashPoseidon(modulus.get()), nullptr);
+}
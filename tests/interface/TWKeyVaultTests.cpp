@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include "TestUtilities.h"
+#include <TrustWalletCore/TWKeyVault.h>
+#include <TrustWalletCore/TWPrivateKey.h>
+#include <TrustWalletCore/TWPublicKey.h>
+
+#include <gtest/gtest.h>
+
+/// BIP-32 test vector 1.
+const auto bip32Seed = "000102030405060708090a0b0c0d0e0f";
+const auto digestHex = "1fcbf0b6d3f2d5a4d6b3e0bc3f5b6c9ec5e7f5a6d3b0e4c4b2a3f5e6d7c8b9a0";
+
+TEST(TWKeyVault, DeriveAndSign) {
+    const auto vault = WRAP(TWKeyVault, TWKeyVaultCreate());
+    const auto seed = TWKeyVaultImportSeed(vault.get(), DATA(bip32Seed).get());
+    ASSERT_NE(seed, 0ul);
+
+    const auto account = TWKeyVaultDeriveSecp256k1(vault.get(), seed, STRING("m/0'").get());
+    ASSERT_NE(account, 0ul);
+    const auto publicKey = WRAP(TWPublicKey, TWKeyVaultGetPublicKey(vault.get(), account, TWPublicKeyTypeSECP256k1));
+    ASSERT_NE(publicKey, nullptr);
+    assertHexEqual(WRAPD(TWPublicKeyData(publicKey.get())), "035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56");
+
+    const auto child = TWKeyVaultDeriveSecp256k1(vault.get(), seed, STRING("m/0'/1").get());
+    ASSERT_NE(child, 0ul);
+
+    const auto digest = DATA(digestHex);
+    const auto privateKey = WRAP(TWPrivateKey, TWPrivateKeyCreateWithData(DATA("3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368").get()));
+    const auto expected = WRAPD(TWPrivateKeySign(privateKey.get(), digest.get(), TWCurveSECP256k1));
+    const auto signature = WRAPD(TWKeyVaultSign(vault.get(), child, digest.get(), TWCurveSECP256k1));
+    ASSERT_NE(signature, nullptr);
+    EXPECT_EQ(hex(*dataFromTWData(signature.get())), hex(*dataFromTWData(expected.get())));
+}
+
+TEST(TWKeyVault, ImportPrivateKeyAndRemove) {
+    const auto vault = WRAP(TWKeyVault, TWKeyVaultCreate());
+    const auto handle = TWKeyVaultImportPrivateKey(vault.get(), DATA("afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5").get());
+    ASSERT_NE(handle, 0ul);
+
+    const auto digest = DATA(digestHex);
+    const auto signature = WRAPD(TWKeyVaultSign(vault.get(), handle, digest.get(), TWCurveSECP256k1));
+    ASSERT_NE(signature, nullptr);
+    EXPECT_EQ(TWDataSize(signature.get()), 65ul);
+
+    EXPECT_TRUE(TWKeyVaultRemove(vault.get(), handle));
+    EXPECT_FALSE(TWKeyVaultRemove(vault.get(), handle));
+    EXPECT_EQ(TWKeyVaultSign(vault.get(), handle, digest.get(), TWCurveSECP256k1), nullptr);
+    EXPECT_EQ(TWKeyVaultGetPublicKey(vault.get(), handle, TWPublicKeyTypeSECP256k1), nullptr);
+}
+
+TEST(TWKeyVault, InvalidInput) {
+    const auto vault = WRAP(TWKeyVault, TWKeyVaultCreate());
+    EXPECT_EQ(TWKeyVaultImportPrivateKey(vault.get(), DATA("0000000000000000000000000000000000000000000000000000000000000000").get()), 0ul);
+    EXPECT_EQ(TWKeyVaultImportSeed(vault.get(), DATA("0001020304").get()), 0ul);
+
+    const auto seed = TWKeyVaultImportSeed(vault.get(), DATA(bip32Seed).get());
+    ASSERT_NE(seed, 0ul);
+    EXPECT_EQ(TWKeyVaultDeriveSecp256k1(vault.get(), seed, STRING("invalid").get()), 0ul);
+    // A seed can't sign.
+    EXPECT_EQ(TWKeyVaultSign(vault.get(), seed, DATA(digestHex).get(), TWCurveSECP256k1), nullptr);
+}
@@ -20,3 +20,49 @@ TEST(TWMnemonic, suggest) {
     EXPECT_EQ(std::string(TWStringUTF8Bytes(WRAPS(TWMnemonicSuggest(WRAPS(TWStringCreateWithUTF8Bytes("air")).get())).get())), "air airport");
     EXPECT_EQ(std::string(TWStringUTF8Bytes(WRAPS(TWMnemonicSuggest(WRAPS(TWStringCreateWithUTF8Bytes("rob")).get())).get())), "robot robust");
 }
+
+TEST(TWMnemonic, Validate) {
+    EXPECT_EQ(TWMnemonicValidate(STRING("credit expect life fade cover suit response wash pear what skull force").get()), TWMnemonicErrorNone);
+    EXPECT_EQ(TWMnemonicValidate(STRING("ripple scissors hisc mammal hire column oak again sun offer wealth tomorrow").get()), TWMnemonicErrorInvalidWord);
+    EXPECT_EQ(TWMnemonicValidate(STRING("credit expect life fade cover suit response wash what skull force").get()), TWMnemonicErrorInvalidWordCount);
+    EXPECT_EQ(TWMnemonicValidate(STRING("ripple scissors kick mammal hire column oak again sun offer wealth tomorrow").get()), TWMnemonicErrorInvalidChecksum);
+}
+
+TEST(TWMnemonic, InvalidWordIndex) {
+    EXPECT_EQ(TWMnemonicInvalidWordIndex(STRING("ripple scissors hisc mammal hire column oak again sun offer wealth tomorrow").get()), 2);
+    EXPECT_EQ(TWMnemonicInvalidWordIndex(STRING("credit expect life fade cover suit response wash pear what skull force").get()), -1);
+}
+
+TEST(TWMnemonic, ToEntropy) {
+    const auto entropy = WRAPD(TWMnemonicToEntropy(STRING("credit expect life fade cover suit response wash pear what skull force").get()));
+    ASSERT_NE(entropy.get(), nullptr);
+    assertHexEqual(entropy, "330a0a0528e319b22defbba21f3b2b2d");
+
+    EXPECT_EQ(TWMnemonicToEntropy(STRING("ripple scissors kick mammal hire column oak again sun offer wealth tomorrow").get()), nullptr);
+}
+
+TEST(TWMnemonic, FromEntropy) {
+    const auto entropy = DATA("330a0a0528e319b22defbba21f3b2b2d");
+    const auto mnemonic = WRAPS(TWMnemonicFromEntropy(entropy.get()));
+    ASSERT_NE(mnemonic.get(), nullptr);
+    assertStringsEqual(mnemonic, "credit expect life fade cover suit response wash pear what skull force");
+
+    const auto invalid = DATA("330a0a0528e319b22defbba21f3b2b");
+    EXPECT_EQ(TWMnemonicFromEntropy(invalid.get()), nullptr);
+}
+
+TEST(TWMnemonic, SuggestWords) {
+    assertStringsEqual(WRAPS(TWMnemonicSuggestWords(STRING("pro").get(), TWMnemonicLanguageEnglish, 3)), "problem process produce");
+    assertStringsEqual(WRAPS(TWMnemonicSuggestWords(STRING("saos").get(), TWMnemonicLanguageEnglish, 3)), "");
+}
+
+TEST(TWMnemonic, Search) {
+    EXPECT_EQ(TWMnemonicSearch(STRING("air").get(), TWMnemonicLanguageEnglish), TWMnemonicWordStatusValid);
+    assertStringsEqual(WRAPS(TWMnemonicSearchCandidates(STRING("air").get(), TWMnemonicLanguageEnglish)), "air airport");
+
+    EXPECT_EQ(TWMnemonicSearch(STRING("airp").get(), TWMnemonicLanguageEnglish), TWMnemonicWordStatusPrefix);
+    assertStringsEqual(WRAPS(TWMnemonicSearchCandidates(STRING("airp").get(), TWMnemonicLanguageEnglish)), "airport");
+
+    EXPECT_EQ(TWMnemonicSearch(STRING("recieve").get(), TWMnemonicLanguageEnglish), TWMnemonicWordStatusInvalid);
+    assertStringsEqual(WRAPS(TWMnemonicSearchCandidates(STRING("recieve").get(), TWMnemonicLanguageEnglish)), "receive believe recipe");
+}
@@ -113,6 +113,29 @@ TEST(TWPrivateKeyTests, Sign) {
         "8720a46b5b3963790d94bcc61ad57ca02fd153584315bfa161ed3455e336ba624d68df010ed934b8792c5b6a57ba86c3da31d039f9612b44d1bf054132254de901");
 }
 
+TEST(TWPrivateKeyTests, DisableExport) {
+    const auto privateKey = WRAP(TWPrivateKey, TWPrivateKeyCreateWithData(DATA("afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5").get()));
+    EXPECT_TRUE(TWPrivateKeyIsExportable(privateKey.get()));
+
+    TWPrivateKeyDisableExport(privateKey.get());
+    EXPECT_FALSE(TWPrivateKeyIsExportable(privateKey.get()));
+    const auto exported = WRAPD(TWPrivateKeyData(privateKey.get()));
+    EXPECT_EQ(TWDataSize(exported.get()), 0ul);
+
+    const auto copy = WRAP(TWPrivateKey, TWPrivateKeyCreateCopy(privateKey.get()));
+    EXPECT_FALSE(TWPrivateKeyIsExportable(copy.get()));
+    const auto exportedCopy = WRAPD(TWPrivateKeyData(copy.get()));
+    EXPECT_EQ(TWDataSize(exportedCopy.get()), 0ul);
+
+    // The key can still be used for signing.
+    const auto message = "hello";
+    const auto data = WRAPD(TWDataCreateWithBytes((uint8_t *)message, strlen(message)));
+    const auto hash = WRAPD(TWHashKeccak256(data.get()));
+    const auto actual = WRAPD(TWPrivateKeySign(privateKey.get(), hash.get(), TWCurveSECP256k1));
+    ASSERT_EQ(TW::hex(*((TW::Data*)actual.get())),
+        "8720a46b5b3963790d94bcc61ad57ca02fd153584315bfa161ed3455e336ba624d68df010ed934b8792c5b6a57ba86c3da31d039f9612b44d1bf054132254de901");
+}
+
 TEST(TWPrivateKeyTests, SignAsDER) {
     const auto privateKey = WRAP(TWPrivateKey, TWPrivateKeyCreateWithData(DATA("afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5").get()));
 
@@ -65,6 +65,23 @@ TEST(TWPublicKeyTests, CompressedExtended) {
     EXPECT_TRUE(TWPublicKeyIsValid(compressed.get(), TWPublicKeyTypeSECP256k1));
 }
 
+TEST(TWPublicKeyTests, Identifiers) {
+    const auto publicKey = WRAP(TWPublicKey, TWPublicKeyCreateWithData(DATA("0399c6f51ad6f98c9c583f8e92bb7758ab2ca9a04110c0a1126ec43e5453d196c1").get(), TWPublicKeyTypeSECP256k1));
+    EXPECT_EQ(TWPublicKeyFingerprint(publicKey.get()), 0x5e675567u);
+    assertHexEqual(WRAPD(TWPublicKeyKeyIdentifier(publicKey.get())), "5e67556730cddcfb2dadc05e9743cd8931006451");
+    assertStringsEqual(WRAPS(TWPublicKeyAccountIdentifier(publicKey.get())), "4d4edf5c17c676238e302c5c6dc28421cf7e4a95b67ab80dcc812328b0fc80c6");
+
+    // The uncompressed form has the same identifiers.
+    const auto extended = WRAP(TWPublicKey, TWPublicKeyUncompressed(publicKey.get()));
+    EXPECT_EQ(TWPublicKeyFingerprint(extended.get()), 0x5e675567u);
+    assertHexEqual(WRAPD(TWPublicKeyKeyIdentifier(extended.get())), "5e67556730cddcfb2dadc05e9743cd8931006451");
+    assertStringsEqual(WRAPS(TWPublicKeyAccountIdentifier(extended.get())), "4d4edf5c17c676238e302c5c6dc28421cf7e4a95b67ab80dcc812328b0fc80c6");
+
+    const auto ed25519 = WRAP(TWPublicKey, TWPublicKeyCreateWithData(DATA("4870d56d074c50e891506d78faa4fb69ca039cc5f131eb491e166b975880e867").get(), TWPublicKeyTypeED25519));
+    EXPECT_EQ(TWPublicKeyFingerprint(ed25519.get()), 0xacb81639u);
+    assertStringsEqual(WRAPS(TWPublicKeyAccountIdentifier(ed25519.get())), "6cf258f981402c0bceb51d7edb5f24c305a2b4c486a9f5bc7217f22032601484");
+}
+
 TEST(TWPublicKeyTests, Verify) {
     const PrivateKey key(parse_hex("afeefca74d9a325cf1d6b6911d61a65c32afa8e02bd5e78e2e4ac2910bab45f5"), TWCurveSECP256k1);
     const auto privateKey = WRAP(TWPrivateKey, new TWPrivateKey{ key });
@@ -400,6 +400,28 @@ TEST(TWStoredKey, exportJSON) {
     EXPECT_EQ(TWDataGet(json.get(), 0), '{');
 }
 
+TEST(TWStoredKey, exportImportBackup) {
+    const auto key = createDefaultStoredKey();
+    const auto password = WRAPD(TWDataCreateWithBytes(reinterpret_cast<const uint8_t*>("backup"), 6));
+    const auto wrongPassword = WRAPD(TWDataCreateWithBytes(reinterpret_cast<const uint8_t*>("password"), 8));
+    const auto metadata = WRAPD(TWDataCreateWithBytes(reinterpret_cast<const uint8_t*>("Test"), 4));
+
+    const auto backup = WRAPD(TWStoredKeyExportBackup(key.get(), password.get(), metadata.get()));
+    ASSERT_NE(backup.get(), nullptr);
+
+    const auto backupMetadata = WRAPD(TWStoredKeyBackupMetadata(backup.get()));
+    EXPECT_TRUE(TWDataEqual(backupMetadata.get(), metadata.get()));
+
+    const auto imported = WRAP(TWStoredKey, TWStoredKeyImportBackup(backup.get(), password.get()));
+    ASSERT_NE(imported.get(), nullptr);
+    const auto keyId = WRAPS(TWStoredKeyIdentifier(key.get()));
+    const auto importedId = WRAPS(TWStoredKeyIdentifier(imported.get()));
+    EXPECT_TRUE(TWStringEqual(keyId.get(), importedId.get()));
+
+    EXPECT_EQ(TWStoredKeyImportBackup(backup.get(), wrongPassword.get()), nullptr);
+    EXPECT_EQ(TWStoredKeyBackupMetadata(metadata.get()), nullptr);
+}
+
 TEST(TWStoredKey, storeAndImportJSONAES256) {
     const auto key = createDefaultStoredKey(TWStoredKeyEncryptionAes256Ctr);
     const auto outFileName = string(getTestTempDir() + "/TWStoredKey_store.json");
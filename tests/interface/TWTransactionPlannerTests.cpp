@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: Apache-2.0
+//
+// Copyright © 2017 Trust Wallet.
+
+#include <TrustWalletCore/TWCoinType.h>
+#include <TrustWalletCore/TWData.h>
+#include <TrustWalletCore/TWTransactionPlanner.h>
+
+#include "proto/Ethereum.pb.h"
+#include "proto/TransactionPlanner.pb.h"
+#include "uint256.h"
+
+#include "TestUtilities.h"
+#include <gtest/gtest.h>
+
+using namespace TW;
+
+TEST(TWTransactionPlanner, PlanEthereumTransfer) {
+    Ethereum::Proto::SigningInput input;
+    const auto chainId = store(uint256_t(1));
+    const auto nonce = store(uint256_t(9));
+    const auto gasPrice = store(uint256_t(20000000000));
+    const auto gasLimit = store(uint256_t(21000));
+    const auto amount = store(uint256_t(1000000000000000000));
+
+    input.set_chain_id(chainId.data(), chainId.size());
+    input.set_nonce(nonce.data(), nonce.size());
+    input.set_gas_price(gasPrice.data(), gasPrice.size());
+    input.set_gas_limit(gasLimit.data(), gasLimit.size());
+    input.set_to_address("0x3535353535353535353535353535353535353535");
+    auto& transfer = *input.mutable_transaction()->mutable_transfer();
+    transfer.set_amount(amount.data(), amount.size());
+
+    const auto inputData = data(input.SerializeAsString());
+    const auto inputPtr = WRAPD(TWDataCreateWithBytes(inputData.data(), inputData.size()));
+    const auto outputPtr = WRAPD(TWTransactionPlannerPlan(TWCoinTypeEthereum, inputPtr.get()));
+
+    TransactionPlanner::Proto::TransactionPlan plan;
+    ASSERT_TRUE(plan.ParseFromArray(TWDataBytes(outputPtr.get()), static_cast<int>(TWDataSize(outputPtr.get()))));
+    EXPECT_EQ(plan.error(), Common::Proto::SigningError::OK);
+    EXPECT_EQ(plan.amount(), "1000000000000000000");
+    EXPECT_EQ(plan.fee(), "420000000000000");
+    EXPECT_EQ(plan.max_amount(), "");
+    EXPECT_EQ(plan.fee_rate().unit(), TransactionPlanner::Proto::PerGas);
+    EXPECT_EQ(plan.fee_rate().units(), 21000ul);
+}
+
+TEST(TWTransactionPlanner, PlanNotSupported) {
+    const auto inputPtr = WRAPD(TWDataCreateWithBytes(nullptr, 0));
+    const auto outputPtr = WRAPD(TWTransactionPlannerPlan(TWCoinTypeSolana, inputPtr.get()));
+
+    TransactionPlanner::Proto::TransactionPlan plan;
+    ASSERT_TRUE(plan.ParseFromArray(TWDataBytes(outputPtr.get()), static_cast<int>(TWDataSize(outputPtr.get()))));
+    EXPECT_EQ(plan.error(), Common::Proto::SigningError::Error_not_supported);
+}